@@ -0,0 +1,53 @@
+//! Lets a tool measure the time spent running each rule of the analyzer.
+
+use crate::RuleKey;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, RwLock};
+
+/// Receives the beginning and the end of every rule run, from all the threads
+/// running the analyzer.
+pub trait RuleProfiler: Send + Sync {
+    /// Called on the thread that is about to run `rule`
+    fn rule_started(&self, rule: RuleKey);
+
+    /// Called on the thread that ran `rule` once it's done, even if the rule panicked
+    fn rule_finished(&self, rule: RuleKey);
+}
+
+/// Allows to skip the lock when there's no profiler, which is the common case
+static IS_PROFILING: AtomicBool = AtomicBool::new(false);
+
+static PROFILER: RwLock<Option<Arc<dyn RuleProfiler>>> = RwLock::new(None);
+
+/// Sets the profiler notified of the rules run in this process, or removes it with [None].
+pub fn set_rule_profiler(profiler: Option<Arc<dyn RuleProfiler>>) {
+    if let Ok(mut current) = PROFILER.write() {
+        IS_PROFILING.store(profiler.is_some(), Ordering::Relaxed);
+        *current = profiler;
+    }
+}
+
+/// Notifies the profiler, if any, that `rule` starts running.
+///
+/// The returned guard notifies the profiler that the rule finished when it's dropped.
+pub(crate) fn profile_rule(rule: RuleKey) -> Option<RuleSpan> {
+    if !IS_PROFILING.load(Ordering::Relaxed) {
+        return None;
+    }
+
+    let profiler = PROFILER.read().ok()?.clone()?;
+    profiler.rule_started(rule);
+    Some(RuleSpan { profiler, rule })
+}
+
+/// Closes the span of a rule when dropped, including while unwinding
+pub(crate) struct RuleSpan {
+    profiler: Arc<dyn RuleProfiler>,
+    rule: RuleKey,
+}
+
+impl Drop for RuleSpan {
+    fn drop(&mut self) {
+        self.profiler.rule_finished(self.rule);
+    }
+}
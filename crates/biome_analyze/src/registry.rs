@@ -1,6 +1,7 @@
 use crate::{
     context::RuleContext,
     matcher::{GroupKey, MatchQueryParams},
+    profiling,
     query::{QueryKey, Queryable},
     signals::RuleSignal,
     AddVisitor, AnalysisFilter, GroupCategory, QueryMatcher, Rule, RuleGroup, RuleKey,
@@ -406,6 +407,8 @@ impl<L: Language + Default> RegistryRule<L> {
                 }
             }
 
+            let _span = profiling::profile_rule(RuleKey::rule::<R>());
+
             // SAFETY: The rule should never get executed in the first place
             // if the query doesn't match
             let query_result = params.query.downcast_ref().unwrap();
@@ -1,6 +1,7 @@
 use crate::logging::LoggingKind;
 use crate::LoggingLevel;
 use bpaf::Bpaf;
+use std::fmt::{Display, Formatter};
 use std::str::FromStr;
 
 /// Global options applied to all commands
@@ -22,6 +23,10 @@ pub struct CliOptions {
     #[bpaf(long("config-path"), argument("PATH"), optional)]
     pub config_path: Option<String>,
 
+    /// Override a key of the configuration, e.g. `javascript.formatter.lineWidth=100`. Nested keys are separated by dots. Can be used multiple times.
+    #[bpaf(long("config-override"), argument("KEY=VALUE"), many)]
+    pub config_overrides: Vec<ConfigOverride>,
+
     /// Cap the amount of diagnostics displayed.
     #[bpaf(
         long("max-diagnostics"),
@@ -43,6 +48,10 @@ pub struct CliOptions {
     #[bpaf(long("error-on-warnings"), switch)]
     pub error_on_warnings: bool,
 
+    /// Tell Biome to exit with an error code if the number of warnings is greater than NUMBER. `--max-warnings=0` is equivalent to `--error-on-warnings`.
+    #[bpaf(long("max-warnings"), argument("NUMBER"), optional)]
+    pub max_warnings: Option<u16>,
+
     /// Reports information using the JSON format
     #[bpaf(long("json"), switch, hide_usage, hide)]
     pub json: bool,
@@ -87,3 +96,40 @@ impl FromStr for ColorsArg {
         }
     }
 }
+
+/// A configuration key overridden from the command line via `--config-override`.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ConfigOverride {
+    /// The path to the overridden key, e.g. `["javascript", "formatter", "lineWidth"]`
+    pub path: Vec<String>,
+    /// The new value of the key, as written on the command line
+    pub value: String,
+}
+
+impl Display for ConfigOverride {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}={}", self.path.join("."), self.value)
+    }
+}
+
+impl FromStr for ConfigOverride {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let Some((key, value)) = s.split_once('=') else {
+            return Err(format!(
+                "value {s:?} is not valid for the --config-override argument, expected KEY=VALUE"
+            ));
+        };
+        let path: Vec<_> = key.trim().split('.').map(str::to_string).collect();
+        if path.iter().any(|segment| segment.is_empty()) {
+            return Err(format!(
+                "key {key:?} is not valid for the --config-override argument, expected keys separated by dots"
+            ));
+        }
+        Ok(Self {
+            path,
+            value: value.to_string(),
+        })
+    }
+}
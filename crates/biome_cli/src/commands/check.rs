@@ -2,7 +2,8 @@ use crate::cli_options::CliOptions;
 use crate::configuration::{load_configuration, LoadedConfiguration};
 use crate::vcs::store_path_to_ignore_from_vcs;
 use crate::{
-    execute_mode, setup_cli_subscriber, CliDiagnostic, CliSession, Execution, TraversalMode,
+    execute_mode, setup_cli_subscriber, CliDiagnostic, CliSession, CommandOutcome, Execution,
+    TraversalMode,
 };
 use biome_service::configuration::organize_imports::OrganizeImports;
 use biome_service::configuration::{FormatterConfiguration, LinterConfiguration};
@@ -14,8 +15,9 @@ use std::path::PathBuf;
 pub(crate) struct CheckCommandPayload {
     pub(crate) apply: bool,
     pub(crate) apply_unsafe: bool,
+    pub(crate) profile: bool,
     pub(crate) cli_options: CliOptions,
-    pub(crate) configuration: Option<Configuration>,
+    pub(crate) configuration: Option<Box<Configuration>>,
     pub(crate) paths: Vec<OsString>,
     pub(crate) stdin_file_path: Option<String>,
     pub(crate) formatter_enabled: Option<bool>,
@@ -27,10 +29,11 @@ pub(crate) struct CheckCommandPayload {
 pub(crate) fn check(
     mut session: CliSession,
     payload: CheckCommandPayload,
-) -> Result<(), CliDiagnostic> {
+) -> Result<CommandOutcome, CliDiagnostic> {
     let CheckCommandPayload {
         apply,
         apply_unsafe,
+        profile,
         cli_options,
         configuration,
         paths,
@@ -88,7 +91,7 @@ pub(crate) fn check(
         organize_imports.enabled = organize_imports_enabled;
     }
 
-    fs_configuration.merge_with(configuration);
+    fs_configuration.merge_with(configuration.map(|configuration| *configuration));
 
     // check if support of git ignore files is enabled
     let vcs_base_path = configuration_path.or(session.app.fs.working_directory());
@@ -113,6 +116,10 @@ pub(crate) fn check(
         None
     };
 
+    let max_file_size = fs_configuration
+        .files
+        .as_ref()
+        .and_then(|files| files.max_size);
     session
         .app
         .workspace
@@ -123,8 +130,10 @@ pub(crate) fn check(
     execute_mode(
         Execution::new(TraversalMode::Check {
             fix_file_mode,
+            profile,
             stdin,
-        }),
+        })
+        .with_max_file_size(max_file_size),
         session,
         &cli_options,
         paths,
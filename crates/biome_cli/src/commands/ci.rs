@@ -3,7 +3,7 @@ use crate::configuration::LoadedConfiguration;
 use crate::vcs::store_path_to_ignore_from_vcs;
 use crate::{
     configuration::load_configuration, execute_mode, setup_cli_subscriber, CliDiagnostic,
-    CliSession, Execution, TraversalMode,
+    CliSession, CommandOutcome, Execution, TraversalMode,
 };
 use biome_service::configuration::organize_imports::OrganizeImports;
 use biome_service::configuration::{FormatterConfiguration, LinterConfiguration};
@@ -16,12 +16,15 @@ pub(crate) struct CiCommandPayload {
     pub(crate) linter_enabled: Option<bool>,
     pub(crate) organize_imports_enabled: Option<bool>,
     pub(crate) paths: Vec<OsString>,
-    pub(crate) rome_configuration: Configuration,
+    pub(crate) rome_configuration: Box<Configuration>,
     pub(crate) cli_options: CliOptions,
 }
 
 /// Handler for the "ci" command of the Biome CLI
-pub(crate) fn ci(mut session: CliSession, payload: CiCommandPayload) -> Result<(), CliDiagnostic> {
+pub(crate) fn ci(
+    mut session: CliSession,
+    payload: CiCommandPayload,
+) -> Result<CommandOutcome, CliDiagnostic> {
     setup_cli_subscriber(
         payload.cli_options.log_level.clone(),
         payload.cli_options.log_kind.clone(),
@@ -88,13 +91,17 @@ pub(crate) fn ci(mut session: CliSession, payload: CiCommandPayload) -> Result<(
         &payload.cli_options,
     )?;
 
+    let max_file_size = configuration
+        .files
+        .as_ref()
+        .and_then(|files| files.max_size);
     session
         .app
         .workspace
         .update_settings(UpdateSettingsParams { configuration })?;
 
     execute_mode(
-        Execution::new(TraversalMode::CI),
+        Execution::new(TraversalMode::CI).with_max_file_size(max_file_size),
         session,
         &payload.cli_options,
         payload.paths,
@@ -4,7 +4,8 @@ use crate::diagnostics::DeprecatedArgument;
 use crate::execute::ReportMode;
 use crate::vcs::store_path_to_ignore_from_vcs;
 use crate::{
-    execute_mode, setup_cli_subscriber, CliDiagnostic, CliSession, Execution, TraversalMode,
+    execute_mode, setup_cli_subscriber, CliDiagnostic, CliSession, CommandOutcome, Execution,
+    TraversalMode,
 };
 use biome_console::{markup, ConsoleExt};
 use biome_diagnostics::PrintDiagnostic;
@@ -32,7 +33,7 @@ pub(crate) struct FormatCommandPayload {
 pub(crate) fn format(
     mut session: CliSession,
     payload: FormatCommandPayload,
-) -> Result<(), CliDiagnostic> {
+) -> Result<CommandOutcome, CliDiagnostic> {
     let FormatCommandPayload {
         javascript_formatter,
         formatter_configuration,
@@ -109,6 +110,10 @@ pub(crate) fn format(
         vcs_base_path,
         &cli_options,
     )?;
+    let max_file_size = configuration
+        .files
+        .as_ref()
+        .and_then(|files| files.max_size);
     session
         .app
         .workspace
@@ -145,5 +150,10 @@ pub(crate) fn format(
         })
     };
 
-    execute_mode(execution, session, &cli_options, paths)
+    execute_mode(
+        execution.with_max_file_size(max_file_size),
+        session,
+        &cli_options,
+        paths,
+    )
 }
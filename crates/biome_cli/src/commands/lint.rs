@@ -2,7 +2,8 @@ use crate::cli_options::CliOptions;
 use crate::configuration::{load_configuration, LoadedConfiguration};
 use crate::vcs::store_path_to_ignore_from_vcs;
 use crate::{
-    execute_mode, setup_cli_subscriber, CliDiagnostic, CliSession, Execution, TraversalMode,
+    execute_mode, setup_cli_subscriber, CliDiagnostic, CliSession, CommandOutcome, Execution,
+    TraversalMode,
 };
 use biome_service::configuration::vcs::VcsConfiguration;
 use biome_service::configuration::{FilesConfiguration, LinterConfiguration};
@@ -14,8 +15,10 @@ use std::path::PathBuf;
 pub(crate) struct LintCommandPayload {
     pub(crate) apply: bool,
     pub(crate) apply_unsafe: bool,
+    pub(crate) fix_only: bool,
+    pub(crate) fix_categories: Vec<String>,
     pub(crate) cli_options: CliOptions,
-    pub(crate) linter_configuration: Option<LinterConfiguration>,
+    pub(crate) linter_configuration: Option<Box<LinterConfiguration>>,
     pub(crate) vcs_configuration: Option<VcsConfiguration>,
     pub(crate) files_configuration: Option<FilesConfiguration>,
     pub(crate) paths: Vec<OsString>,
@@ -26,10 +29,12 @@ pub(crate) struct LintCommandPayload {
 pub(crate) fn lint(
     mut session: CliSession,
     payload: LintCommandPayload,
-) -> Result<(), CliDiagnostic> {
+) -> Result<CommandOutcome, CliDiagnostic> {
     let LintCommandPayload {
         apply,
         apply_unsafe,
+        fix_only,
+        fix_categories,
         cli_options,
         linter_configuration,
         paths,
@@ -44,9 +49,9 @@ pub(crate) fn lint(
             "--apply",
             "--apply-unsafe",
         ));
-    } else if !apply && !apply_unsafe {
+    } else if !apply && !apply_unsafe && !fix_only && fix_categories.is_empty() {
         None
-    } else if apply && !apply_unsafe {
+    } else if !apply_unsafe {
         Some(FixFileMode::SafeFixes)
     } else {
         Some(FixFileMode::SafeAndUnsafeFixes)
@@ -61,7 +66,7 @@ pub(crate) fn lint(
         directory_path: configuration_path,
         ..
     } = loaded_configuration;
-    fs_configuration.merge_with(linter_configuration);
+    fs_configuration.merge_with(linter_configuration.map(|configuration| *configuration));
     fs_configuration.merge_with(files_configuration);
     fs_configuration.merge_with(vcs_configuration);
 
@@ -88,6 +93,10 @@ pub(crate) fn lint(
         None
     };
 
+    let max_file_size = fs_configuration
+        .files
+        .as_ref()
+        .and_then(|files| files.max_size);
     session
         .app
         .workspace
@@ -98,8 +107,11 @@ pub(crate) fn lint(
     execute_mode(
         Execution::new(TraversalMode::Lint {
             fix_file_mode,
+            fix_only,
+            fix_categories,
             stdin,
-        }),
+        })
+        .with_max_file_size(max_file_size),
         session,
         &cli_options,
         paths,
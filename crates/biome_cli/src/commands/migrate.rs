@@ -2,14 +2,14 @@ use crate::cli_options::CliOptions;
 use crate::configuration::{load_configuration, LoadedConfiguration};
 use crate::diagnostics::MigrationDiagnostic;
 use crate::execute::{execute_mode, Execution, TraversalMode};
-use crate::{setup_cli_subscriber, CliDiagnostic, CliSession};
+use crate::{setup_cli_subscriber, CliDiagnostic, CliSession, CommandOutcome};
 
 /// Handler for the "check" command of the Biome CLI
 pub(crate) fn migrate(
     mut session: CliSession,
     cli_options: CliOptions,
     write: bool,
-) -> Result<(), CliDiagnostic> {
+) -> Result<CommandOutcome, CliDiagnostic> {
     let LoadedConfiguration {
         configuration: _,
         diagnostics: _,
@@ -2,6 +2,7 @@ use crate::cli_options::{cli_options, CliOptions, ColorsArg};
 use crate::logging::LoggingKind;
 use crate::{LoggingLevel, VERSION};
 use biome_service::configuration::json::JsonFormatter;
+use biome_service::configuration::linter::is_rule_group;
 use biome_service::configuration::vcs::VcsConfiguration;
 use biome_service::configuration::{
     configuration, files_configuration, formatter_configuration, javascript::javascript_formatter,
@@ -20,6 +21,7 @@ pub(crate) mod init;
 pub(crate) mod lint;
 pub(crate) mod migrate;
 pub(crate) mod rage;
+pub(crate) mod search;
 pub(crate) mod version;
 
 #[derive(Debug, Clone, Bpaf)]
@@ -55,6 +57,9 @@ pub enum BiomeCommand {
         /// Apply safe fixes and unsafe fixes, formatting and import sorting
         #[bpaf(long("apply-unsafe"), switch)]
         apply_unsafe: bool,
+        /// Write the time spent processing each file to `biome-profile.json`, in the Chromium tracing format
+        #[bpaf(long("profile"), switch)]
+        profile: bool,
         /// Allow to enable or disable the formatter check.
         #[bpaf(
             long("formatter-enabled"),
@@ -74,8 +79,10 @@ pub enum BiomeCommand {
             hide_usage
         )]
         organize_imports_enabled: Option<bool>,
-        #[bpaf(external, hide_usage, optional)]
-        configuration: Option<Configuration>,
+        // The configurations are boxed to keep the command small: the parser of the commands
+        // copies it on the stack for each alternative
+        #[bpaf(external, map(Box::new), hide_usage, optional)]
+        configuration: Option<Box<Configuration>>,
         #[bpaf(external, hide_usage)]
         cli_options: CliOptions,
         /// Use this option when you want to format code piped from `stdin`, and print the output to `stdout`.
@@ -90,6 +97,8 @@ pub enum BiomeCommand {
         paths: Vec<OsString>,
     },
     /// Run various checks on a set of files.
+    ///
+    /// With fixes, the command exits with the code `2` when files were modified and no error remains.
     #[bpaf(command)]
     Lint {
         /// Apply safe fixes, formatting
@@ -98,8 +107,19 @@ pub enum BiomeCommand {
         /// Apply safe fixes and unsafe fixes, formatting and import sorting
         #[bpaf(long("apply-unsafe"), switch)]
         apply_unsafe: bool,
-        #[bpaf(external, hide_usage, optional)]
-        linter_configuration: Option<LinterConfiguration>,
+        /// Apply safe fixes, or unsafe fixes with `--apply-unsafe`, without reporting the diagnostics that can't be fixed
+        #[bpaf(long("fix-only"), switch)]
+        fix_only: bool,
+        /// Apply safe fixes, or unsafe fixes with `--apply-unsafe`, of the rules of a group only, such as `style`. It can be repeated to apply the fixes of several groups
+        #[bpaf(
+            long("fix-category"),
+            argument("CATEGORY"),
+            guard(|name: &String| is_rule_group(name), "must be the name of a group of rules, such as style"),
+            many
+        )]
+        fix_categories: Vec<String>,
+        #[bpaf(external, map(Box::new), hide_usage, optional)]
+        linter_configuration: Option<Box<LinterConfiguration>>,
 
         #[bpaf(external, optional, hide_usage)]
         vcs_configuration: Option<VcsConfiguration>,
@@ -171,8 +191,33 @@ pub enum BiomeCommand {
         #[bpaf(long("organize-imports-enabled"), argument("true|false"), optional)]
         organize_imports_enabled: Option<bool>,
 
+        #[bpaf(external, map(Box::new), hide_usage)]
+        configuration: Box<Configuration>,
         #[bpaf(external, hide_usage)]
-        configuration: Configuration,
+        cli_options: CliOptions,
+
+        /// Single file, single path or list of paths
+        #[bpaf(positional("PATH"), many)]
+        paths: Vec<OsString>,
+    },
+
+    /// Search the code for the nodes that match a structural pattern.
+    ///
+    /// Each match is printed with its location and its text.
+    #[bpaf(command)]
+    Search {
+        /// The pattern to search, written as code. `$NAME` matches any node, and `$$$` matches zero or more elements of a list.
+        ///
+        /// Example: `biome search --query 'console.log($$$)' src`
+        #[bpaf(long("query"), argument("PATTERN"))]
+        query: String,
+
+        #[bpaf(external, optional, hide_usage)]
+        vcs_configuration: Option<VcsConfiguration>,
+
+        #[bpaf(external, optional, hide_usage)]
+        files_configuration: Option<FilesConfiguration>,
+
         #[bpaf(external, hide_usage)]
         cli_options: CliOptions,
 
@@ -216,6 +261,7 @@ impl BiomeCommand {
             BiomeCommand::Lint { cli_options, .. } => cli_options.colors.as_ref(),
             BiomeCommand::Ci { cli_options, .. } => cli_options.colors.as_ref(),
             BiomeCommand::Format { cli_options, .. } => cli_options.colors.as_ref(),
+            BiomeCommand::Search { cli_options, .. } => cli_options.colors.as_ref(),
             BiomeCommand::Init => None,
             BiomeCommand::LspProxy(cli_options) => cli_options.colors.as_ref(),
             BiomeCommand::Migrate(cli_options, _) => cli_options.colors.as_ref(),
@@ -234,6 +280,7 @@ impl BiomeCommand {
             BiomeCommand::Lint { cli_options, .. } => cli_options.use_server,
             BiomeCommand::Ci { cli_options, .. } => cli_options.use_server,
             BiomeCommand::Format { cli_options, .. } => cli_options.use_server,
+            BiomeCommand::Search { cli_options, .. } => cli_options.use_server,
             BiomeCommand::Init => false,
             BiomeCommand::LspProxy(cli_options) => cli_options.use_server,
             BiomeCommand::Migrate(cli_options, _) => cli_options.use_server,
@@ -256,6 +303,7 @@ impl BiomeCommand {
             BiomeCommand::Lint { cli_options, .. } => cli_options.verbose,
             BiomeCommand::Format { cli_options, .. } => cli_options.verbose,
             BiomeCommand::Ci { cli_options, .. } => cli_options.verbose,
+            BiomeCommand::Search { cli_options, .. } => cli_options.verbose,
             BiomeCommand::Init => false,
             BiomeCommand::LspProxy(cli_options) => cli_options.verbose,
             BiomeCommand::Migrate(cli_options, _) => cli_options.verbose,
@@ -270,6 +318,7 @@ impl BiomeCommand {
             | BiomeCommand::Lint { cli_options, .. }
             | BiomeCommand::Format { cli_options, .. }
             | BiomeCommand::Ci { cli_options, .. }
+            | BiomeCommand::Search { cli_options, .. }
             | BiomeCommand::LspProxy(cli_options)
             | BiomeCommand::Migrate(cli_options, _) => cli_options.log_level.clone(),
             BiomeCommand::Version(_)
@@ -287,6 +336,7 @@ impl BiomeCommand {
             | BiomeCommand::Lint { cli_options, .. }
             | BiomeCommand::Format { cli_options, .. }
             | BiomeCommand::Ci { cli_options, .. }
+            | BiomeCommand::Search { cli_options, .. }
             | BiomeCommand::LspProxy(cli_options)
             | BiomeCommand::Migrate(cli_options, _) => cli_options.log_kind.clone(),
             BiomeCommand::Version(_)
@@ -0,0 +1,257 @@
+use crate::cli_options::CliOptions;
+use crate::configuration::{load_configuration, LoadedConfiguration};
+use crate::vcs::store_path_to_ignore_from_vcs;
+use crate::{
+    execute_mode, setup_cli_subscriber, CliDiagnostic, CliSession, CommandOutcome, Execution,
+    TraversalMode,
+};
+use biome_js_parser::{parse, JsParserOptions};
+use biome_js_syntax::{AnyJsRoot, JsExpressionStatement, JsFileSource, JsSyntaxNode, TextRange};
+use biome_rowan::{AstNode, AstNodeList, NodeOrToken};
+use biome_service::configuration::vcs::VcsConfiguration;
+use biome_service::configuration::FilesConfiguration;
+use biome_service::workspace::UpdateSettingsParams;
+use biome_service::MergeWith;
+use rustc_hash::FxHashMap;
+use std::collections::hash_map::Entry;
+use std::ffi::OsString;
+
+pub(crate) struct SearchCommandPayload {
+    pub(crate) query: String,
+    pub(crate) cli_options: CliOptions,
+    pub(crate) vcs_configuration: Option<VcsConfiguration>,
+    pub(crate) files_configuration: Option<FilesConfiguration>,
+    pub(crate) paths: Vec<OsString>,
+}
+
+/// Handler for the "search" command of the Biome CLI
+pub(crate) fn search(
+    mut session: CliSession,
+    payload: SearchCommandPayload,
+) -> Result<CommandOutcome, CliDiagnostic> {
+    let SearchCommandPayload {
+        query,
+        cli_options,
+        vcs_configuration,
+        files_configuration,
+        paths,
+    } = payload;
+    setup_cli_subscriber(cli_options.log_level.clone(), cli_options.log_kind.clone());
+
+    let pattern = SearchPattern::new(query)?;
+
+    let loaded_configuration = load_configuration(&mut session, &cli_options)?.with_file_path();
+
+    loaded_configuration.check_for_errors(session.app.console, cli_options.verbose)?;
+
+    let LoadedConfiguration {
+        configuration: mut fs_configuration,
+        directory_path: configuration_path,
+        ..
+    } = loaded_configuration;
+    fs_configuration.merge_with(files_configuration);
+    fs_configuration.merge_with(vcs_configuration);
+
+    // check if support of git ignore files is enabled
+    let vcs_base_path = configuration_path.or(session.app.fs.working_directory());
+    store_path_to_ignore_from_vcs(
+        &mut session,
+        &mut fs_configuration,
+        vcs_base_path,
+        &cli_options,
+    )?;
+
+    let max_file_size = fs_configuration
+        .files
+        .as_ref()
+        .and_then(|files| files.max_size);
+    session
+        .app
+        .workspace
+        .update_settings(UpdateSettingsParams {
+            configuration: fs_configuration,
+        })?;
+
+    execute_mode(
+        Execution::new(TraversalMode::Search { pattern }).with_max_file_size(max_file_size),
+        session,
+        &cli_options,
+        paths,
+    )
+}
+
+/// A structural pattern, written as JavaScript code.
+///
+/// The pattern matches the nodes that have the same syntax, ignoring the trivia.
+/// It supports two kinds of placeholders:
+/// - `$NAME` matches any node. All the occurrences of the same placeholder must match the same text;
+/// - `$$$` matches zero or more elements of a list, such as arguments, parameters, or statements.
+///
+/// For example, `console.log($$$)` matches all the calls to `console.log`,
+/// and `$A === $A` matches all the comparisons of an expression with itself.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub(crate) struct SearchPattern {
+    query: String,
+}
+
+impl SearchPattern {
+    /// Creates a pattern from `query`, and emits a diagnostic if the query isn't a single valid
+    /// expression or statement.
+    pub(crate) fn new(query: String) -> Result<Self, CliDiagnostic> {
+        let pattern = Self { query };
+        if pattern.parse(JsFileSource::tsx()).is_none() {
+            return Err(CliDiagnostic::invalid_search_pattern(
+                pattern.query,
+                "the pattern must be a single valid expression or statement",
+            ));
+        }
+        Ok(pattern)
+    }
+
+    /// Returns the ranges of all the nodes of `root` that match the pattern, in source order.
+    ///
+    /// The pattern is parsed with the source type of the searched file,
+    /// so the syntax of the pattern can depend on the language of the file.
+    pub(crate) fn find_matches(
+        &self,
+        root: &AnyJsRoot,
+        source_type: JsFileSource,
+    ) -> Vec<TextRange> {
+        let Some(pattern) = self.parse(source_type) else {
+            return Vec::new();
+        };
+        let mut matches: Vec<TextRange> = Vec::new();
+        for node in root.syntax().descendants() {
+            let range = node.text_trimmed_range();
+            // A node and its only child share the same range: report the match once
+            if matches.last() == Some(&range) {
+                continue;
+            }
+            if match_node(&pattern, &node, &mut FxHashMap::default()) {
+                matches.push(range);
+            }
+        }
+        matches
+    }
+
+    /// Parses the query, and returns the root node of the pattern.
+    fn parse(&self, source_type: JsFileSource) -> Option<JsSyntaxNode> {
+        let parsed = parse(&self.query, source_type, JsParserOptions::default());
+        if parsed.has_errors() {
+            return None;
+        }
+        let items: Vec<JsSyntaxNode> = match parsed.tree() {
+            AnyJsRoot::JsModule(module) => module
+                .items()
+                .iter()
+                .map(|item| item.into_syntax())
+                .collect(),
+            AnyJsRoot::JsScript(script) => script
+                .statements()
+                .iter()
+                .map(|statement| statement.into_syntax())
+                .collect(),
+            AnyJsRoot::JsExpressionSnipped(_) => return None,
+        };
+        let [item] = items.as_slice() else {
+            return None;
+        };
+        // The pattern `f()` should match the call expression, not an expression statement
+        match JsExpressionStatement::cast_ref(item) {
+            Some(statement) => Some(statement.expression().ok()?.into_syntax()),
+            None => Some(item.clone()),
+        }
+    }
+}
+
+/// Returns `true` if `node` matches `pattern`.
+///
+/// `bindings` maps the placeholders `$NAME` to the text of the nodes they already matched.
+fn match_node(
+    pattern: &JsSyntaxNode,
+    node: &JsSyntaxNode,
+    bindings: &mut FxHashMap<String, String>,
+) -> bool {
+    if let Some(name) = placeholder_name(pattern) {
+        let text = node.text_trimmed().to_string();
+        return match bindings.entry(name) {
+            Entry::Occupied(entry) => *entry.get() == text,
+            Entry::Vacant(entry) => {
+                entry.insert(text);
+                true
+            }
+        };
+    }
+    if pattern.kind() != node.kind() {
+        return false;
+    }
+    if pattern.kind().is_list() {
+        // Separators are ignored, so that a trailing comma doesn't prevent a match
+        let patterns: Vec<_> = pattern.children().collect();
+        let nodes: Vec<_> = node.children().collect();
+        return match_list(&patterns, &nodes, bindings);
+    }
+    let mut pattern_children = pattern.children_with_tokens();
+    let mut node_children = node.children_with_tokens();
+    loop {
+        match (pattern_children.next(), node_children.next()) {
+            (None, None) => return true,
+            (Some(NodeOrToken::Node(pattern)), Some(NodeOrToken::Node(node))) => {
+                if !match_node(&pattern, &node, bindings) {
+                    return false;
+                }
+            }
+            (Some(NodeOrToken::Token(pattern)), Some(NodeOrToken::Token(token))) => {
+                if pattern.kind() != token.kind() || pattern.text_trimmed() != token.text_trimmed()
+                {
+                    return false;
+                }
+            }
+            _ => return false,
+        }
+    }
+}
+
+/// Returns `true` if the elements of a list match the elements of a pattern list.
+fn match_list(
+    patterns: &[JsSyntaxNode],
+    nodes: &[JsSyntaxNode],
+    bindings: &mut FxHashMap<String, String>,
+) -> bool {
+    let Some((pattern, rest)) = patterns.split_first() else {
+        return nodes.is_empty();
+    };
+    if pattern.text_trimmed() == "$$$" {
+        // Try the shortest sequence first, restoring the bindings after every failed attempt
+        return (0..=nodes.len()).any(|skipped| {
+            let mut attempt = bindings.clone();
+            let matched = match_list(rest, &nodes[skipped..], &mut attempt);
+            if matched {
+                *bindings = attempt;
+            }
+            matched
+        });
+    }
+    match nodes.split_first() {
+        Some((node, nodes)) => {
+            match_node(pattern, node, bindings) && match_list(rest, nodes, bindings)
+        }
+        None => false,
+    }
+}
+
+/// Returns the name of the placeholder if `pattern` is a placeholder such as `$A` or `$NAME`.
+///
+/// A list is never a placeholder, even when its only element is a placeholder.
+fn placeholder_name(pattern: &JsSyntaxNode) -> Option<String> {
+    if pattern.kind().is_list() {
+        return None;
+    }
+    let text = pattern.text_trimmed().to_string();
+    let name = text.strip_prefix('$')?;
+    let is_placeholder = name.starts_with(|c: char| c.is_ascii_uppercase() || c == '_')
+        && name
+            .chars()
+            .all(|c| c.is_ascii_uppercase() || c.is_ascii_digit() || c == '_');
+    is_placeholder.then_some(text)
+}
@@ -1,18 +1,17 @@
-use crate::cli_options::CliOptions;
+use crate::cli_options::{CliOptions, ConfigOverride};
 use crate::diagnostics::DeprecatedConfigurationFile;
 use crate::{CliDiagnostic, CliSession};
 use biome_console::{markup, Console, ConsoleExt};
 use biome_deserialize::json::deserialize_from_json_str;
-use biome_deserialize::Deserialized;
-use biome_diagnostics::{DiagnosticExt, Error, PrintDiagnostic, Severity};
-use biome_fs::{FileSystem, OpenOptions};
+use biome_diagnostics::{DiagnosticExt, Error, PrintDescription, PrintDiagnostic, Severity};
+use biome_fs::FileSystem;
 use biome_json_parser::JsonParserOptions;
-use biome_service::configuration::diagnostics::CantLoadExtendFile;
 use biome_service::configuration::ConfigurationPayload;
 use biome_service::{
-    load_config, Configuration, ConfigurationBasePath, ConfigurationDiagnostic, DynRef, MergeWith,
-    WorkspaceError,
+    apply_extends, load_config, Configuration, ConfigurationBasePath, ConfigurationDiagnostic,
+    DynRef, WorkspaceError,
 };
+use serde_json::{Map, Value};
 use std::path::PathBuf;
 
 #[derive(Default, Debug)]
@@ -29,76 +28,77 @@ impl LoadedConfiguration {
     /// applied is itself.
     ///
     /// If a configuration can't be resolved from the file system, the operation will fail.
-    pub fn apply_extends(mut self, fs: &DynRef<dyn FileSystem>) -> Result<Self, WorkspaceError> {
-        let deserialized = self.deserialize_extends(fs)?;
-        let (configurations, errors): (Vec<_>, Vec<_>) =
-            deserialized.into_iter().map(|d| d.consume()).unzip();
-
-        let extended_configuration = configurations.into_iter().reduce(
-            |mut previous_configuration, current_configuration| {
-                previous_configuration.merge_with(current_configuration);
-                previous_configuration
-            },
-        );
-        let configuration = if let Some(mut extended_configuration) = extended_configuration {
-            // Here we want to keep only the values that aren't a default
-            extended_configuration.merge_with_if_not_default(self.configuration);
-            extended_configuration
-        } else {
-            self.configuration
+    pub fn apply_extends(self, fs: &DynRef<dyn FileSystem>) -> Result<Self, WorkspaceError> {
+        let Some(file_path) = &self.file_path else {
+            return Ok(self);
         };
-        self.diagnostics
-            .extend(errors.into_iter().flatten().collect::<Vec<_>>());
-
-        Ok(Self {
+        let (configuration, diagnostics) = apply_extends(fs, file_path, self.configuration)?;
+        let mut loaded_configuration = Self {
             configuration,
             diagnostics: self.diagnostics,
             file_path: self.file_path,
             directory_path: self.directory_path,
-        })
+        };
+        loaded_configuration.diagnostics.extend(diagnostics);
+
+        Ok(loaded_configuration)
     }
 
-    fn deserialize_extends(
-        &mut self,
-        fs: &DynRef<dyn FileSystem>,
-    ) -> Result<Vec<Deserialized<Configuration>>, WorkspaceError> {
-        let Some(extends) = &self.configuration.extends else {
-            return Ok(vec![]);
-        };
+    /// Consumes itself to generate a new [LoadedConfiguration] where the keys passed via
+    /// `--config-override` are applied, from left to right, on top of the loaded configuration.
+    ///
+    /// If a key doesn't exist or its value is invalid, the operation will fail.
+    pub fn apply_config_overrides(
+        mut self,
+        config_overrides: &[ConfigOverride],
+    ) -> Result<Self, CliDiagnostic> {
+        if config_overrides.is_empty() {
+            return Ok(self);
+        }
 
-        let directory_path = self
-            .directory_path
-            .as_ref()
-            .cloned()
-            .unwrap_or(fs.working_directory().unwrap_or(PathBuf::from("./")));
-        let mut deserialized_configurations = vec![];
-        for path in extends.index_set() {
-            let config_path = directory_path.join(path);
-            let mut file = fs
-					.open_with_options(config_path.as_path(), OpenOptions::default().read(true))
-					.map_err(|err| {
-						CantLoadExtendFile::new(config_path.display().to_string(), err.to_string()).with_verbose_advice(
-							markup!{
-								"Biome tried to load the configuration file "<Emphasis>{directory_path.display().to_string()}</Emphasis>" using "<Emphasis>{config_path.display().to_string()}</Emphasis>" as base path."
-							}
-						)
-					})?;
-            let mut content = String::new();
-            file.read_to_string(&mut content).map_err(|err| {
-					CantLoadExtendFile::new(config_path.display().to_string(), err.to_string()).with_verbose_advice(
-						markup!{
-							"It's possible that the file was created with a different user/group. Make sure you have the rights to read the file."
-						}
-					)
-
-				})?;
+        let mut configuration = serde_json::to_value(&self.configuration).map_err(|_| {
+            CliDiagnostic::workspace_error(WorkspaceError::Configuration(
+                ConfigurationDiagnostic::invalid_configuration(
+                    "Biome couldn't apply the configuration overrides.",
+                ),
+            ))
+        })?;
+        for config_override in config_overrides {
+            // A value that isn't valid JSON is treated as a string, so that
+            // `--config-override formatter.indentStyle=space` works without quotes.
+            let value = serde_json::from_str(&config_override.value)
+                .unwrap_or_else(|_| Value::String(config_override.value.clone()));
+
+            // Each override is validated on its own, so that the diagnostic can point to it
+            let mut overridden = Value::Object(Map::new());
+            insert_at_path(&mut overridden, &config_override.path, value.clone());
             let deserialized = deserialize_from_json_str::<Configuration>(
-                content.as_str(),
+                &overridden.to_string(),
                 JsonParserOptions::default(),
             );
-            deserialized_configurations.push(deserialized)
+            if let Some(error) = deserialized
+                .diagnostics()
+                .iter()
+                .find(|diagnostic| diagnostic.severity() == Severity::Error)
+            {
+                return Err(CliDiagnostic::invalid_config_override(
+                    config_override.to_string(),
+                    PrintDescription(error).to_string(),
+                ));
+            }
+
+            insert_at_path(&mut configuration, &config_override.path, value);
         }
-        Ok(deserialized_configurations)
+
+        let (configuration, diagnostics) = deserialize_from_json_str::<Configuration>(
+            &configuration.to_string(),
+            JsonParserOptions::default(),
+        )
+        .consume();
+        self.configuration = configuration;
+        self.diagnostics.extend(diagnostics);
+
+        Ok(self)
     }
 
     /// It re
@@ -108,10 +108,13 @@ impl LoadedConfiguration {
             .diagnostics
             .into_iter()
             .map(|diagnostic| {
-                if let Some(file_path) = &self.file_path {
-                    diagnostic.with_file_path(file_path.display().to_string())
-                } else {
-                    diagnostic
+                // The diagnostics of the extended configuration files already have their path
+                let has_file_path = diagnostic.location().resource.is_some();
+                match &self.file_path {
+                    Some(file_path) if !has_file_path => {
+                        diagnostic.with_file_path(file_path.display().to_string())
+                    }
+                    _ => diagnostic,
                 }
             })
             .collect::<Vec<_>>();
@@ -197,5 +200,22 @@ pub(crate) fn load_configuration(
     let fs = &session.app.fs;
     let config = load_config(fs, base_path)?;
     let loaded_configuration = LoadedConfiguration::from(config);
-    Ok(loaded_configuration.apply_extends(fs)?)
+    loaded_configuration
+        .apply_extends(fs)?
+        .apply_config_overrides(&cli_options.config_overrides)
+}
+
+/// Sets `value` at `path` inside `json`, replacing the intermediate values that aren't objects.
+fn insert_at_path(json: &mut Value, path: &[String], value: Value) {
+    let mut current = json;
+    for key in path {
+        if !current.is_object() {
+            *current = Value::Object(Map::new());
+        }
+        current = match current {
+            Value::Object(object) => object.entry(key.as_str()).or_insert(Value::Null),
+            _ => return,
+        };
+    }
+    *current = value;
 }
@@ -57,6 +57,10 @@ pub enum CliDiagnostic {
     MigrateError(MigrationDiagnostic),
     /// When the VCS folder couldn't be found
     NoVcsFolderFound(NoVcsFolderFound),
+    /// When a configuration key passed via `--config-override` can't be applied
+    InvalidConfigOverride(InvalidConfigOverride),
+    /// When the pattern passed to `biome search --query` can't be parsed
+    InvalidSearchPattern(InvalidSearchPattern),
 }
 
 #[derive(Debug, Diagnostic)]
@@ -225,6 +229,34 @@ pub struct IncompatibleEndConfiguration {
     reason: String,
 }
 
+#[derive(Debug, Diagnostic)]
+#[diagnostic(
+    category = "flags/invalid",
+    severity = Error,
+    message(
+        description = "Invalid search pattern {pattern}: {reason}",
+        message("Invalid search pattern "<Emphasis>{self.pattern}</Emphasis>": "{{&self.reason}})
+    )
+)]
+pub struct InvalidSearchPattern {
+    pattern: String,
+    reason: String,
+}
+
+#[derive(Debug, Diagnostic)]
+#[diagnostic(
+    category = "flags/invalid",
+    severity = Error,
+    message(
+        description = "Invalid configuration override {config_override}: {reason}",
+        message("Invalid configuration override "<Emphasis>{self.config_override}</Emphasis>": "{{&self.reason}})
+    )
+)]
+pub struct InvalidConfigOverride {
+    config_override: String,
+    reason: String,
+}
+
 #[derive(Debug, Diagnostic)]
 #[diagnostic(
     category = "internalError/io",
@@ -455,6 +487,23 @@ impl CliDiagnostic {
         })
     }
 
+    /// Emitted when more warnings than allowed by `--max-warnings` were emitted
+    pub fn max_warnings_exceeded(
+        category: &'static Category,
+        warnings: usize,
+        max_warnings: u16,
+    ) -> Self {
+        Self::CheckError(CheckError {
+            category,
+            message: MessageAndDescription::from(
+                markup! {
+                    "Found "{warnings}" "<Emphasis>"warnings"</Emphasis>", but "<Emphasis>"--max-warnings"</Emphasis>" allows at most "{max_warnings}"."
+                }
+                .to_owned(),
+            ),
+        })
+    }
+
     /// Emitted for a file that has code fixes, but still has diagnostics to address
     pub fn file_check_apply_error(
         file_path: impl Into<String>,
@@ -509,6 +558,26 @@ impl CliDiagnostic {
         })
     }
 
+    /// Emitted when a key passed via `--config-override` doesn't exist in the configuration,
+    /// or when its value isn't valid
+    pub fn invalid_config_override(
+        config_override: impl Into<String>,
+        reason: impl Into<String>,
+    ) -> Self {
+        Self::InvalidConfigOverride(InvalidConfigOverride {
+            config_override: config_override.into(),
+            reason: reason.into(),
+        })
+    }
+
+    /// Emitted when the pattern of `biome search --query` isn't a valid pattern
+    pub fn invalid_search_pattern(pattern: impl Into<String>, reason: impl Into<String>) -> Self {
+        Self::InvalidSearchPattern(InvalidSearchPattern {
+            pattern: pattern.into(),
+            reason: reason.into(),
+        })
+    }
+
     /// Return by the help command when it is called with a subcommand it doesn't know
     pub fn new_unknown_help(command: impl Into<String>) -> Self {
         Self::UnknownCommandHelp(UnknownCommandHelp {
@@ -537,6 +606,8 @@ impl Diagnostic for CliDiagnostic {
             CliDiagnostic::FileCheck(diagnostic) => diagnostic.category(),
             CliDiagnostic::MigrateError(diagnostic) => diagnostic.category(),
             CliDiagnostic::NoVcsFolderFound(diagnostic) => diagnostic.category(),
+            CliDiagnostic::InvalidConfigOverride(diagnostic) => diagnostic.category(),
+            CliDiagnostic::InvalidSearchPattern(diagnostic) => diagnostic.category(),
         }
     }
 
@@ -559,6 +630,8 @@ impl Diagnostic for CliDiagnostic {
             CliDiagnostic::FileCheck(diagnostic) => diagnostic.tags(),
             CliDiagnostic::MigrateError(diagnostic) => diagnostic.tags(),
             CliDiagnostic::NoVcsFolderFound(diagnostic) => diagnostic.tags(),
+            CliDiagnostic::InvalidConfigOverride(diagnostic) => diagnostic.tags(),
+            CliDiagnostic::InvalidSearchPattern(diagnostic) => diagnostic.tags(),
         }
     }
 
@@ -581,6 +654,8 @@ impl Diagnostic for CliDiagnostic {
             CliDiagnostic::FileCheck(diagnostic) => diagnostic.severity(),
             CliDiagnostic::MigrateError(diagnostic) => diagnostic.severity(),
             CliDiagnostic::NoVcsFolderFound(diagnostic) => diagnostic.severity(),
+            CliDiagnostic::InvalidConfigOverride(diagnostic) => diagnostic.severity(),
+            CliDiagnostic::InvalidSearchPattern(diagnostic) => diagnostic.severity(),
         }
     }
 
@@ -603,6 +678,8 @@ impl Diagnostic for CliDiagnostic {
             CliDiagnostic::FileCheck(diagnostic) => diagnostic.location(),
             CliDiagnostic::MigrateError(diagnostic) => diagnostic.location(),
             CliDiagnostic::NoVcsFolderFound(diagnostic) => diagnostic.location(),
+            CliDiagnostic::InvalidConfigOverride(diagnostic) => diagnostic.location(),
+            CliDiagnostic::InvalidSearchPattern(diagnostic) => diagnostic.location(),
         }
     }
 
@@ -625,6 +702,8 @@ impl Diagnostic for CliDiagnostic {
             CliDiagnostic::FileCheck(diagnostic) => diagnostic.message(fmt),
             CliDiagnostic::MigrateError(diagnostic) => diagnostic.message(fmt),
             CliDiagnostic::NoVcsFolderFound(diagnostic) => diagnostic.message(fmt),
+            CliDiagnostic::InvalidConfigOverride(diagnostic) => diagnostic.message(fmt),
+            CliDiagnostic::InvalidSearchPattern(diagnostic) => diagnostic.message(fmt),
         }
     }
 
@@ -647,6 +726,8 @@ impl Diagnostic for CliDiagnostic {
             CliDiagnostic::FileCheck(diagnostic) => diagnostic.description(fmt),
             CliDiagnostic::MigrateError(diagnostic) => diagnostic.description(fmt),
             CliDiagnostic::NoVcsFolderFound(diagnostic) => diagnostic.description(fmt),
+            CliDiagnostic::InvalidConfigOverride(diagnostic) => diagnostic.description(fmt),
+            CliDiagnostic::InvalidSearchPattern(diagnostic) => diagnostic.description(fmt),
         }
     }
 
@@ -669,6 +750,8 @@ impl Diagnostic for CliDiagnostic {
             CliDiagnostic::FileCheck(diagnostic) => diagnostic.advices(visitor),
             CliDiagnostic::MigrateError(diagnostic) => diagnostic.advices(visitor),
             CliDiagnostic::NoVcsFolderFound(diagnostic) => diagnostic.advices(visitor),
+            CliDiagnostic::InvalidConfigOverride(diagnostic) => diagnostic.advices(visitor),
+            CliDiagnostic::InvalidSearchPattern(diagnostic) => diagnostic.advices(visitor),
         }
     }
 
@@ -695,6 +778,8 @@ impl Diagnostic for CliDiagnostic {
             CliDiagnostic::FileCheck(diagnostic) => diagnostic.verbose_advices(visitor),
             CliDiagnostic::MigrateError(diagnostic) => diagnostic.verbose_advices(visitor),
             CliDiagnostic::NoVcsFolderFound(diagnostic) => diagnostic.verbose_advices(visitor),
+            CliDiagnostic::InvalidConfigOverride(diagnostic) => diagnostic.verbose_advices(visitor),
+            CliDiagnostic::InvalidSearchPattern(diagnostic) => diagnostic.verbose_advices(visitor),
         }
     }
 
@@ -717,6 +802,8 @@ impl Diagnostic for CliDiagnostic {
             CliDiagnostic::FileCheck(diagnostic) => diagnostic.source(),
             CliDiagnostic::MigrateError(diagnostic) => diagnostic.source(),
             CliDiagnostic::NoVcsFolderFound(diagnostic) => diagnostic.source(),
+            CliDiagnostic::InvalidConfigOverride(diagnostic) => diagnostic.source(),
+            CliDiagnostic::InvalidSearchPattern(diagnostic) => diagnostic.source(),
         }
     }
 }
@@ -5,13 +5,16 @@ mod std_in;
 mod traverse;
 
 use crate::cli_options::CliOptions;
+use crate::commands::search::SearchPattern;
 use crate::execute::traverse::traverse;
-use crate::{CliDiagnostic, CliSession};
+use crate::{CliDiagnostic, CliSession, CommandOutcome};
 use biome_diagnostics::{category, Category, MAXIMUM_DISPLAYABLE_DIAGNOSTICS};
 use biome_fs::RomePath;
+use biome_service::settings::DEFAULT_FILE_SIZE_LIMIT;
 use biome_service::workspace::{FeatureName, FixFileMode};
 use std::ffi::OsString;
 use std::fmt::{Display, Formatter};
+use std::num::NonZeroU64;
 use std::path::PathBuf;
 
 /// Useful information during the traversal of files and virtual content
@@ -24,6 +27,9 @@ pub(crate) struct Execution {
 
     /// The maximum number of diagnostics that can be printed in console
     max_diagnostics: u16,
+
+    /// The maximum size of the processed files, set by the option `files.maxSize`
+    max_file_size: NonZeroU64,
 }
 
 impl Execution {
@@ -44,6 +50,10 @@ pub(crate) enum TraversalMode {
         /// It's [None] if the `check` command is called without `--apply` or `--apply-suggested`
         /// arguments.
         fix_file_mode: Option<FixFileMode>,
+        /// Whether the time spent processing each file is recorded.
+        ///
+        /// It's `true` when the `check` command is called with `--profile`.
+        profile: bool,
         /// An optional tuple.
         /// 1. The virtual path to the file
         /// 2. The content of the file
@@ -56,6 +66,15 @@ pub(crate) enum TraversalMode {
         /// It's [None] if the `check` command is called without `--apply` or `--apply-suggested`
         /// arguments.
         fix_file_mode: Option<FixFileMode>,
+        /// Whether the diagnostics that remain after applying the fixes aren't printed.
+        /// The errors among them still make the command fail.
+        ///
+        /// It's `true` when the `lint` command is called with `--fix-only`.
+        fix_only: bool,
+        /// The groups of rules whose fixes are applied, or all the groups if it's empty.
+        ///
+        /// They are passed to the `lint` command with `--fix-category`.
+        fix_categories: Vec<String>,
         /// An optional tuple.
         /// 1. The virtual path to the file
         /// 2. The content of the file
@@ -74,6 +93,11 @@ pub(crate) enum TraversalMode {
         /// 2. The content of the file
         stdin: Option<(PathBuf, String)>,
     },
+    /// This mode is enabled when running the command `biome search`
+    Search {
+        /// The structural pattern to search
+        pattern: SearchPattern,
+    },
     /// This mode is enabled when running the command `biome migrate`
     Migrate {
         write: bool,
@@ -90,6 +114,7 @@ impl Display for TraversalMode {
             TraversalMode::Format { .. } => write!(f, "format"),
             TraversalMode::Migrate { .. } => write!(f, "migrate"),
             TraversalMode::Lint { .. } => write!(f, "lint"),
+            TraversalMode::Search { .. } => write!(f, "search"),
         }
     }
 }
@@ -110,6 +135,7 @@ impl Execution {
             report_mode: ReportMode::default(),
             traversal_mode: mode,
             max_diagnostics: MAXIMUM_DISPLAYABLE_DIAGNOSTICS,
+            max_file_size: DEFAULT_FILE_SIZE_LIMIT,
         }
     }
 
@@ -119,9 +145,18 @@ impl Execution {
             traversal_mode,
             report_mode,
             max_diagnostics: MAXIMUM_DISPLAYABLE_DIAGNOSTICS,
+            max_file_size: DEFAULT_FILE_SIZE_LIMIT,
         }
     }
 
+    /// Sets the maximum size of the processed files, when the option `files.maxSize` is set
+    pub(crate) fn with_max_file_size(mut self, max_file_size: Option<NonZeroU64>) -> Self {
+        if let Some(max_file_size) = max_file_size {
+            self.max_file_size = max_file_size;
+        }
+        self
+    }
+
     /// Tells if the reporting is happening straight to terminal
     pub(crate) fn should_report_to_terminal(&self) -> bool {
         matches!(self.report_mode, ReportMode::Terminal)
@@ -135,14 +170,31 @@ impl Execution {
         self.max_diagnostics
     }
 
+    pub(crate) fn get_max_file_size(&self) -> NonZeroU64 {
+        self.max_file_size
+    }
+
     /// `true` only when running the traversal in [TraversalMode::Check] and `should_fix` is `true`
     pub(crate) fn as_fix_file_mode(&self) -> Option<&FixFileMode> {
         match &self.traversal_mode {
             TraversalMode::Check { fix_file_mode, .. }
             | TraversalMode::Lint { fix_file_mode, .. } => fix_file_mode.as_ref(),
-            TraversalMode::Format { .. } | TraversalMode::CI | TraversalMode::Migrate { .. } => {
-                None
-            }
+            TraversalMode::Format { .. }
+            | TraversalMode::CI
+            | TraversalMode::Search { .. }
+            | TraversalMode::Migrate { .. } => None,
+        }
+    }
+
+    /// The groups of rules whose fixes are applied. All the fixes are applied when it's empty.
+    pub(crate) fn fix_categories(&self) -> &[String] {
+        match &self.traversal_mode {
+            TraversalMode::Lint { fix_categories, .. } => fix_categories,
+            TraversalMode::Check { .. }
+            | TraversalMode::Format { .. }
+            | TraversalMode::CI
+            | TraversalMode::Search { .. }
+            | TraversalMode::Migrate { .. } => &[],
         }
     }
 
@@ -152,6 +204,7 @@ impl Execution {
             TraversalMode::Lint { .. } => category!("lint"),
             TraversalMode::CI => category!("ci"),
             TraversalMode::Format { .. } => category!("format"),
+            TraversalMode::Search { .. } => category!("search"),
             TraversalMode::Migrate { .. } => category!("migrate"),
         }
     }
@@ -168,6 +221,20 @@ impl Execution {
         matches!(self.traversal_mode, TraversalMode::Lint { .. })
     }
 
+    pub(crate) const fn is_lint_fix_only(&self) -> bool {
+        matches!(
+            self.traversal_mode,
+            TraversalMode::Lint { fix_only: true, .. }
+        )
+    }
+
+    pub(crate) const fn is_profiled(&self) -> bool {
+        matches!(
+            self.traversal_mode,
+            TraversalMode::Check { profile: true, .. }
+        )
+    }
+
     pub(crate) const fn is_check_apply(&self) -> bool {
         matches!(
             self.traversal_mode,
@@ -197,7 +264,7 @@ impl Execution {
         match self.traversal_mode {
             TraversalMode::Check { fix_file_mode, .. }
             | TraversalMode::Lint { fix_file_mode, .. } => fix_file_mode.is_some(),
-            TraversalMode::CI => false,
+            TraversalMode::CI | TraversalMode::Search { .. } => false,
             TraversalMode::Format { write, .. } => write,
             TraversalMode::Migrate { write: dry_run, .. } => dry_run,
         }
@@ -208,7 +275,9 @@ impl Execution {
             TraversalMode::Format { stdin, .. }
             | TraversalMode::Lint { stdin, .. }
             | TraversalMode::Check { stdin, .. } => stdin.as_ref(),
-            TraversalMode::CI { .. } | TraversalMode::Migrate { .. } => None,
+            TraversalMode::CI { .. }
+            | TraversalMode::Search { .. }
+            | TraversalMode::Migrate { .. } => None,
         }
     }
 }
@@ -220,7 +289,7 @@ pub(crate) fn execute_mode(
     session: CliSession,
     cli_options: &CliOptions,
     paths: Vec<OsString>,
-) -> Result<(), CliDiagnostic> {
+) -> Result<CommandOutcome, CliDiagnostic> {
     if cli_options.max_diagnostics > MAXIMUM_DISPLAYABLE_DIAGNOSTICS {
         return Err(CliDiagnostic::overflown_argument(
             "--max-diagnostics",
@@ -233,7 +302,8 @@ pub(crate) fn execute_mode(
     // don't do any traversal if there's some content coming from stdin
     if let Some((path, content)) = mode.as_stdin_file() {
         let rome_path = RomePath::new(path);
-        std_in::run(session, &mode, rome_path, content.as_str())
+        std_in::run(session, &mode, rome_path, content.as_str())?;
+        Ok(CommandOutcome::Success)
     } else if let TraversalMode::Migrate {
         write,
         configuration_file_path,
@@ -246,7 +316,8 @@ pub(crate) fn execute_mode(
             configuration_file_path,
             configuration_directory_path,
             cli_options.verbose,
-        )
+        )?;
+        Ok(CommandOutcome::Success)
     } else {
         traverse(mode, session, cli_options, paths)
     }
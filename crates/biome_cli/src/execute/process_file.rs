@@ -2,18 +2,21 @@ mod check;
 mod format;
 mod lint;
 mod organize_imports;
+mod search;
 mod workspace_file;
 
 use crate::execute::diagnostics::{ResultExt, UnhandledDiagnostic};
 use crate::execute::process_file::check::check_file;
 use crate::execute::process_file::format::format;
 use crate::execute::process_file::lint::lint;
+use crate::execute::process_file::search::search;
 use crate::execute::traverse::TraversalOptions;
 use crate::execute::TraversalMode;
 use crate::CliDiagnostic;
 use biome_diagnostics::{category, DiagnosticExt, Error};
 use biome_fs::RomePath;
 use biome_service::workspace::{FeatureName, FeaturesBuilder, SupportKind, SupportsFeatureParams};
+use biome_service::WorkspaceError;
 use std::marker::PhantomData;
 use std::ops::Deref;
 use std::path::Path;
@@ -46,6 +49,22 @@ pub(crate) enum Message {
         new: String,
         diff_kind: DiffKind,
     },
+    /// The nodes of a file that match the pattern of `biome search`
+    SearchMatches {
+        file_name: String,
+        matches: Vec<SearchMatch>,
+    },
+}
+
+/// A node that matches the pattern of `biome search`
+#[derive(Debug)]
+pub(crate) struct SearchMatch {
+    /// The line of the start of the node, starting from 1
+    pub(crate) line: usize,
+    /// The column of the start of the node, starting from 1
+    pub(crate) column: usize,
+    /// The source text of the node
+    pub(crate) text: String,
 }
 
 impl Message {
@@ -105,6 +124,22 @@ impl<'ctx, 'app> Deref for SharedTraversalOptions<'ctx, 'app> {
     }
 }
 
+/// Returns a diagnostic if `input` exceeds the maximum size set by the option `files.maxSize`.
+/// Such a file is skipped before it's parsed.
+pub(crate) fn check_file_size(
+    ctx: &TraversalOptions,
+    path: &Path,
+    input: &str,
+) -> Result<(), Error> {
+    let size = input.len();
+    let limit = usize::try_from(ctx.execution.get_max_file_size().get()).unwrap_or(usize::MAX);
+    if size >= limit {
+        let path = path.display().to_string();
+        return Err(WorkspaceError::file_too_large(path.clone(), size, limit).with_file_path(path));
+    }
+    Ok(())
+}
+
 /// This function performs the actual processing: it reads the file from disk
 /// and parse it; analyze and / or format it; then it either fails if error
 /// diagnostics were emitted, or compare the formatted code with the original
@@ -193,6 +228,17 @@ pub(crate) fn process_file(ctx: &TraversalOptions, path: &Path) -> FileResult {
                 ),
             TraversalMode::Format { .. } => file_features.support_kind_for(&FeatureName::Format),
             TraversalMode::Lint { .. } => file_features.support_kind_for(&FeatureName::Lint),
+            // The search doesn't depend on a feature, it only skips the files ignored by `files.ignore`
+            TraversalMode::Search { .. } => {
+                let is_ignored = |feature| {
+                    matches!(
+                        file_features.support_kind_for(feature),
+                        Some(SupportKind::Ignored)
+                    )
+                };
+                (is_ignored(&FeatureName::Lint) && is_ignored(&FeatureName::Format))
+                    .then_some(&SupportKind::Ignored)
+            }
             TraversalMode::Migrate { .. } => None,
         };
 
@@ -225,6 +271,7 @@ pub(crate) fn process_file(ctx: &TraversalOptions, path: &Path) -> FileResult {
                 check_file(shared_context, path, &file_features, category!("check"))
             }
             TraversalMode::CI => check_file(shared_context, path, &file_features, category!("ci")),
+            TraversalMode::Search { ref pattern } => search(shared_context, path, pattern),
             TraversalMode::Migrate { .. } => {
                 unreachable!("The migration should not be called for this file")
             }
@@ -19,7 +19,9 @@ pub(crate) fn check_file<'ctx>(
     tracing::info_span!("Process check", path =? workspace_file.path.display()).in_scope(
         move || {
             if file_features.supports_for(&FeatureName::Lint) {
-                let lint_result = lint_with_guard(ctx, &mut workspace_file);
+                let lint_result = ctx.profile("lint", "feature", || {
+                    lint_with_guard(ctx, &mut workspace_file)
+                });
                 match lint_result {
                     Ok(status) => {
                         if let FileStatus::Message(msg) = status {
@@ -36,7 +38,9 @@ pub(crate) fn check_file<'ctx>(
                 }
             }
             if file_features.supports_for(&FeatureName::OrganizeImports) {
-                let organize_imports_result = organize_imports_with_guard(ctx, &mut workspace_file);
+                let organize_imports_result = ctx.profile("organizeImports", "feature", || {
+                    organize_imports_with_guard(ctx, &mut workspace_file)
+                });
                 match organize_imports_result {
                     Ok(status) => {
                         if let FileStatus::Message(msg) = status {
@@ -54,7 +58,9 @@ pub(crate) fn check_file<'ctx>(
             }
 
             if file_features.supports_for(&FeatureName::Format) {
-                let format_result = format_with_guard(ctx, &mut workspace_file);
+                let format_result = ctx.profile("format", "feature", || {
+                    format_with_guard(ctx, &mut workspace_file)
+                });
                 match format_result {
                     Ok(status) => {
                         if let FileStatus::Message(msg) = status {
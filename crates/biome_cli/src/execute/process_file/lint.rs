@@ -25,7 +25,7 @@ pub(crate) fn lint_with_guard<'ctx>(
             if let Some(fix_mode) = ctx.execution.as_fix_file_mode() {
                 let fixed = workspace_file
                     .guard()
-                    .fix_file(*fix_mode, false)
+                    .fix_file(*fix_mode, false, ctx.execution.fix_categories().to_vec())
                     .with_file_path_and_code(
                         workspace_file.path.display().to_string(),
                         category!("lint"),
@@ -38,6 +38,7 @@ pub(crate) fn lint_with_guard<'ctx>(
                 if fixed.code != input {
                     workspace_file.update_file(fixed.code)?;
                     input = workspace_file.input()?;
+                    ctx.increment_fixed();
                 }
                 errors = fixed.errors;
             }
@@ -55,7 +56,8 @@ pub(crate) fn lint_with_guard<'ctx>(
                 && pull_diagnostics_result.skipped_diagnostics == 0;
             errors += pull_diagnostics_result.errors;
 
-            if !no_diagnostics {
+            // With `--fix-only`, the diagnostics that can't be fixed are still counted but not printed
+            if !no_diagnostics && !ctx.execution.is_lint_fix_only() {
                 ctx.push_message(Message::Diagnostics {
                     name: workspace_file.path.display().to_string(),
                     content: input,
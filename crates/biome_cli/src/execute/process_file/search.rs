@@ -0,0 +1,55 @@
+use crate::commands::search::SearchPattern;
+use crate::execute::diagnostics::ResultIoExt;
+use crate::execute::process_file::{
+    check_file_size, FileResult, FileStatus, Message, SearchMatch, SharedTraversalOptions,
+};
+use biome_fs::OpenOptions;
+use biome_js_parser::{parse, JsParserOptions};
+use biome_js_syntax::JsFileSource;
+use std::path::Path;
+
+/// Searches the nodes of a single file that match `pattern`, and returns a [FileResult]
+pub(crate) fn search<'ctx>(
+    ctx: &'ctx SharedTraversalOptions<'ctx, '_>,
+    path: &Path,
+    pattern: &SearchPattern,
+) -> FileResult {
+    tracing::info_span!("Searches the file", path =? path.display()).in_scope(move || {
+        let Ok(source_type) = JsFileSource::try_from(path) else {
+            return Ok(FileStatus::Ignored);
+        };
+
+        let mut file = ctx
+            .fs
+            .open_with_options(path, OpenOptions::default().read(true))
+            .with_file_path(path.display().to_string())?;
+        let mut input = String::new();
+        file.read_to_string(&mut input)
+            .with_file_path(path.display().to_string())?;
+        check_file_size(ctx, path, &input)?;
+
+        let parsed = parse(&input, source_type, JsParserOptions::default());
+        let matches: Vec<_> = pattern
+            .find_matches(&parsed.tree(), source_type)
+            .into_iter()
+            .map(|range| {
+                let before = &input[..usize::from(range.start())];
+                let line_start = before.rfind('\n').map_or(0, |index| index + 1);
+                SearchMatch {
+                    line: before.matches('\n').count() + 1,
+                    column: before[line_start..].chars().count() + 1,
+                    text: input[range].to_string(),
+                }
+            })
+            .collect();
+
+        if matches.is_empty() {
+            Ok(FileStatus::Success)
+        } else {
+            Ok(FileStatus::Message(Message::SearchMatches {
+                file_name: path.display().to_string(),
+                matches,
+            }))
+        }
+    })
+}
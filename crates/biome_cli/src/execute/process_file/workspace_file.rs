@@ -1,5 +1,5 @@
 use crate::execute::diagnostics::{ResultExt, ResultIoExt};
-use crate::execute::process_file::SharedTraversalOptions;
+use crate::execute::process_file::{check_file_size, SharedTraversalOptions};
 use biome_diagnostics::{category, Error};
 use biome_fs::{File, OpenOptions, RomePath};
 use biome_service::file_handlers::Language;
@@ -33,6 +33,7 @@ impl<'ctx, 'app> WorkspaceFile<'ctx, 'app> {
         let mut input = String::new();
         file.read_to_string(&mut input)
             .with_file_path(path.display().to_string())?;
+        check_file_size(ctx, path, &input)?;
 
         let guard = FileGuard::open(
             ctx.workspace,
@@ -74,6 +74,7 @@ pub(crate) fn run<'a>(
                     path: rome_path.clone(),
                     should_format: mode.is_check()
                         && file_features.supports_for(&FeatureName::Format),
+                    rule_groups: mode.fix_categories().to_vec(),
                 })?;
                 if fix_file_result.code != new_content {
                     version += 1;
@@ -1,13 +1,16 @@
-use super::process_file::{process_file, DiffKind, FileStatus, Message};
+use super::process_file::{process_file, DiffKind, FileStatus, Message, SearchMatch};
 use crate::cli_options::CliOptions;
 use crate::execute::diagnostics::{
     CIFormatDiffDiagnostic, CIOrganizeImportsDiffDiagnostic, ContentDiffAdvice,
     FormatDiffDiagnostic, OrganizeImportsDiffDiagnostic, PanicDiagnostic,
 };
+use crate::profiling::{Profiler, PROFILE_FILE_NAME};
 use crate::{
-    CliDiagnostic, CliSession, Execution, FormatterReportFileDetail, FormatterReportSummary,
-    Report, ReportDiagnostic, ReportDiff, ReportErrorKind, ReportKind, TraversalMode,
+    CliDiagnostic, CliSession, CommandOutcome, Execution, FormatterReportFileDetail,
+    FormatterReportSummary, Report, ReportDiagnostic, ReportDiff, ReportErrorKind, ReportKind,
+    TraversalMode,
 };
+use biome_analyze::profiling::set_rule_profiler;
 use biome_console::{fmt, markup, Console, ConsoleExt};
 use biome_diagnostics::{
     adapters::StdError, category, DiagnosticExt, Error, PrintDescription, PrintDiagnostic,
@@ -15,6 +18,7 @@ use biome_diagnostics::{
 };
 use biome_fs::{FileSystem, PathInterner, RomePath};
 use biome_fs::{TraversalContext, TraversalScope};
+use biome_js_syntax::JsFileSource;
 use biome_service::workspace::{FeaturesBuilder, IsPathIgnoredParams};
 use biome_service::{
     workspace::{FeatureName, SupportsFeatureParams},
@@ -27,12 +31,13 @@ use crossbeam::{
 use rustc_hash::FxHashSet;
 use std::{
     ffi::OsString,
+    fmt::Display,
     io,
     panic::catch_unwind,
     path::{Path, PathBuf},
     sync::{
         atomic::{AtomicU16, AtomicUsize, Ordering},
-        Once,
+        Arc, Once,
     },
     thread,
     time::{Duration, Instant},
@@ -60,7 +65,7 @@ pub(crate) fn traverse(
     session: CliSession,
     cli_options: &CliOptions,
     inputs: Vec<OsString>,
-) -> Result<(), CliDiagnostic> {
+) -> Result<CommandOutcome, CliDiagnostic> {
     init_thread_pool();
     if inputs.is_empty() && execution.as_stdin_file().is_none() {
         return Err(CliDiagnostic::missing_argument(
@@ -75,6 +80,8 @@ pub(crate) fn traverse(
 
     let processed = AtomicUsize::new(0);
     let skipped = AtomicUsize::new(0);
+    let too_large = AtomicUsize::new(0);
+    let fixed = AtomicUsize::new(0);
 
     let fs = &*session.app.fs;
     let workspace = &*session.app.workspace;
@@ -86,6 +93,10 @@ pub(crate) fn traverse(
     let mut errors: usize = 0;
     let mut warnings: usize = 0;
     let mut report = Report::default();
+    let profiler = execution.is_profiled().then(|| Arc::new(Profiler::new()));
+    if let Some(profiler) = &profiler {
+        set_rule_profiler(Some(profiler.clone()));
+    }
 
     let duration = thread::scope(|s| {
         thread::Builder::new()
@@ -119,15 +130,42 @@ pub(crate) fn traverse(
                 interner,
                 processed: &processed,
                 skipped: &skipped,
+                too_large: &too_large,
+                fixed: &fixed,
                 messages: send_msgs,
                 sender_reports,
                 remaining_diagnostics: &remaining_diagnostics,
+                profiler: profiler.as_deref(),
             },
         )
     });
 
-    let count = processed.load(Ordering::Relaxed);
+    if profiler.is_some() {
+        set_rule_profiler(None);
+    }
+
+    if let Some(profiler) = &profiler {
+        let profile_path = fs
+            .working_directory()
+            .unwrap_or_default()
+            .join(PROFILE_FILE_NAME);
+        profiler
+            .write(fs, &profile_path)
+            .map_err(CliDiagnostic::io_error)?;
+        console.log(markup! {
+            <Info>"Wrote the profile to "{profile_path.display().to_string()}</Info>
+        });
+    }
+
+    let too_large = too_large.load(Ordering::Relaxed);
+    // The files that are too large aren't processed, even if they were opened
+    let count = processed.load(Ordering::Relaxed).saturating_sub(too_large);
     let skipped = skipped.load(Ordering::Relaxed);
+    let outcome = if execution.is_lint() && fixed.load(Ordering::Relaxed) > 0 {
+        CommandOutcome::FixesApplied
+    } else {
+        CommandOutcome::Success
+    };
 
     if execution.should_report_to_terminal() {
         match execution.traversal_mode() {
@@ -166,6 +204,12 @@ pub(crate) fn traverse(
                 });
             }
 
+            TraversalMode::Search { .. } => {
+                console.log(markup! {
+                    <Info>"Searched "{count}" file(s) in "{duration}</Info>
+                });
+            }
+
             TraversalMode::Migrate { write: false, .. } => {
                 console.log(markup! {
                     <Info>"Checked your configuration file in "{duration}</Info>
@@ -193,7 +237,7 @@ pub(crate) fn traverse(
         console.log(markup! {
             {to_print}
         });
-        return Ok(());
+        return Ok(outcome);
     }
 
     if skipped > 0 {
@@ -202,11 +246,20 @@ pub(crate) fn traverse(
         });
     }
 
+    if too_large > 0 {
+        console.log(markup! {
+            <Warn>"Skipped "{too_large}" file(s) larger than the maximum size set by files.maxSize"</Warn>
+        });
+    }
+
     let should_exit_on_warnings = warnings > 0 && cli_options.error_on_warnings;
+    let exceeded_max_warnings = cli_options
+        .max_warnings
+        .filter(|max_warnings| warnings > usize::from(*max_warnings));
     // Processing emitted error diagnostics, exit with a non-zero code
     if count.saturating_sub(skipped) == 0 && !cli_options.no_errors_on_unmatched {
         Err(CliDiagnostic::no_files_processed())
-    } else if errors > 0 || should_exit_on_warnings {
+    } else if errors > 0 || should_exit_on_warnings || exceeded_max_warnings.is_some() {
         let category = execution.as_diagnostic_category();
         if should_exit_on_warnings {
             if execution.is_check_apply() {
@@ -214,13 +267,19 @@ pub(crate) fn traverse(
             } else {
                 Err(CliDiagnostic::check_warnings(category))
             }
+        } else if let Some(max_warnings) = exceeded_max_warnings {
+            Err(CliDiagnostic::max_warnings_exceeded(
+                category,
+                warnings,
+                max_warnings,
+            ))
         } else if execution.is_check_apply() {
             Err(CliDiagnostic::apply_error(category))
         } else {
             Err(CliDiagnostic::check_error(category))
         }
     } else {
-        Ok(())
+        Ok(outcome)
     }
 }
 
@@ -305,6 +364,7 @@ fn process_messages(options: ProcessMessagesOptions) {
     let mut is_msg_open = true;
     let mut is_report_open = true;
     let mut diagnostics_to_print = vec![];
+    let mut search_matches = vec![];
     while is_msg_open || is_report_open {
         let msg = select! {
             recv(recv_msgs) -> msg => match msg {
@@ -564,6 +624,19 @@ fn process_messages(options: ProcessMessagesOptions) {
                     ));
                 }
             }
+            Message::SearchMatches { file_name, matches } => {
+                search_matches.push((file_name, matches));
+            }
+        }
+    }
+
+    // The files are processed in parallel: sort the matches to print them in a stable order
+    search_matches.sort_by(|(a, _), (b, _)| a.cmp(b));
+    for (file_name, matches) in search_matches {
+        for SearchMatch { line, column, text } in matches {
+            console.log(markup! {
+                <Emphasis>{file_name}":"{line}":"{column}</Emphasis>" "{text}
+            });
         }
     }
 
@@ -602,6 +675,10 @@ pub(crate) struct TraversalOptions<'ctx, 'app> {
     processed: &'ctx AtomicUsize,
     /// Shared atomic counter storing the number of skipped files
     skipped: &'ctx AtomicUsize,
+    /// Shared atomic counter storing the number of files skipped because they're too large
+    too_large: &'ctx AtomicUsize,
+    /// Shared atomic counter storing the number of files modified by fixes
+    fixed: &'ctx AtomicUsize,
     /// Channel sending messages to the display thread
     pub(crate) messages: Sender<Message>,
     /// Channel sending reports to the reports thread
@@ -609,6 +686,8 @@ pub(crate) struct TraversalOptions<'ctx, 'app> {
     /// The approximate number of diagnostics the console will print before
     /// folding the rest into the "skipped diagnostics" counter
     pub(crate) remaining_diagnostics: &'ctx AtomicU16,
+    /// Records the time spent processing each file, when the profiling is enabled
+    profiler: Option<&'ctx Profiler>,
 }
 
 impl<'ctx, 'app> TraversalOptions<'ctx, 'app> {
@@ -616,6 +695,23 @@ impl<'ctx, 'app> TraversalOptions<'ctx, 'app> {
         self.processed.fetch_add(1, Ordering::Relaxed);
     }
 
+    pub(crate) fn increment_fixed(&self) {
+        self.fixed.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Runs `func`, and records the time it took under `name` when the profiling is enabled
+    pub(crate) fn profile<T>(
+        &self,
+        name: impl Display,
+        category: &'static str,
+        func: impl FnOnce() -> T,
+    ) -> T {
+        match self.profiler {
+            Some(profiler) => profiler.profile(name.to_string(), category, func),
+            None => func(),
+        }
+    }
+
     /// Send a message to the display thread
     pub(crate) fn push_message(&self, msg: impl Into<Message>) {
         self.messages.send(msg.into()).ok();
@@ -691,6 +787,7 @@ impl<'ctx, 'app> TraversalContext for TraversalOptions<'ctx, 'app> {
             }
             TraversalMode::Format { .. } => file_features.supports_for(&FeatureName::Format),
             TraversalMode::Lint { .. } => file_features.supports_for(&FeatureName::Lint),
+            TraversalMode::Search { .. } => JsFileSource::try_from(rome_path.as_path()).is_ok(),
             // Imagine if Biome can't handle its own configuration file...
             TraversalMode::Migrate { .. } => true,
         }
@@ -705,12 +802,16 @@ impl<'ctx, 'app> TraversalContext for TraversalOptions<'ctx, 'app> {
 /// in a [catch_unwind] block and emit diagnostics in case of error (either the
 /// traversal function returns Err or panics)
 fn handle_file(ctx: &TraversalOptions, path: &Path) {
-    match catch_unwind(move || process_file(ctx, path)) {
+    match catch_unwind(move || ctx.profile(path.display(), "file", || process_file(ctx, path))) {
         Ok(Ok(FileStatus::Success)) => {}
         Ok(Ok(FileStatus::Message(msg))) => {
             ctx.push_message(msg);
         }
         Ok(Ok(FileStatus::Ignored)) => {}
+        Ok(Err(Message::Error(err))) if err.category() == Some(category!("files/tooLarge")) => {
+            ctx.too_large.fetch_add(1, Ordering::Relaxed);
+            ctx.push_message(err);
+        }
         Ok(Err(err)) => {
             ctx.skipped.fetch_add(1, Ordering::Relaxed);
             ctx.push_message(err);
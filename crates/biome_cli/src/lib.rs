@@ -10,6 +10,7 @@ use biome_console::{ColorMode, Console};
 use biome_fs::OsFileSystem;
 use biome_service::{App, DynRef, Workspace, WorkspaceRef};
 use std::env;
+use std::process::{ExitCode, Termination};
 
 mod cli_options;
 mod commands;
@@ -19,6 +20,7 @@ mod execute;
 mod logging;
 mod metrics;
 mod panic;
+mod profiling;
 mod reports;
 mod service;
 mod vcs;
@@ -28,6 +30,7 @@ use crate::commands::check::CheckCommandPayload;
 use crate::commands::ci::CiCommandPayload;
 use crate::commands::format::FormatCommandPayload;
 use crate::commands::lint::LintCommandPayload;
+use crate::commands::search::SearchCommandPayload;
 pub use crate::commands::{biome_command, BiomeCommand};
 pub use crate::logging::{setup_cli_subscriber, LoggingLevel};
 pub use diagnostics::CliDiagnostic;
@@ -44,6 +47,26 @@ pub(crate) const VERSION: &str = match option_env!("BIOME_VERSION") {
     None => env!("CARGO_PKG_VERSION"),
 };
 
+/// The outcome of a command that completed without errors
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq)]
+pub enum CommandOutcome {
+    /// Nothing worth reporting to the caller happened
+    #[default]
+    Success,
+    /// The command modified some files, e.g. `lint --apply`
+    FixesApplied,
+}
+
+impl Termination for CommandOutcome {
+    fn report(self) -> ExitCode {
+        match self {
+            CommandOutcome::Success => ExitCode::SUCCESS,
+            // Tell the caller that files were modified by exiting with the code `2`
+            CommandOutcome::FixesApplied => ExitCode::from(2),
+        }
+    }
+}
+
 /// Global context for an execution of the CLI
 pub struct CliSession<'app> {
     /// Instance of [App] used by this run of the CLI
@@ -65,20 +88,25 @@ impl<'app> CliSession<'app> {
     }
 
     /// Main function to run Biome CLI
-    pub fn run(self, command: BiomeCommand) -> Result<(), CliDiagnostic> {
+    pub fn run(self, command: BiomeCommand) -> Result<CommandOutcome, CliDiagnostic> {
         let has_metrics = command.has_metrics();
         if has_metrics {
             crate::metrics::init_metrics();
         }
 
         let result = match command {
-            BiomeCommand::Version(_) => commands::version::full_version(self),
-            BiomeCommand::Rage(_, daemon_logs) => commands::rage::rage(self, daemon_logs),
-            BiomeCommand::Start => commands::daemon::start(self),
-            BiomeCommand::Stop => commands::daemon::stop(self),
+            BiomeCommand::Version(_) => {
+                commands::version::full_version(self).map(|()| CommandOutcome::Success)
+            }
+            BiomeCommand::Rage(_, daemon_logs) => {
+                commands::rage::rage(self, daemon_logs).map(|()| CommandOutcome::Success)
+            }
+            BiomeCommand::Start => commands::daemon::start(self).map(|()| CommandOutcome::Success),
+            BiomeCommand::Stop => commands::daemon::stop(self).map(|()| CommandOutcome::Success),
             BiomeCommand::Check {
                 apply,
                 apply_unsafe,
+                profile,
                 cli_options,
                 configuration: rome_configuration,
                 paths,
@@ -91,6 +119,7 @@ impl<'app> CliSession<'app> {
                 CheckCommandPayload {
                     apply_unsafe,
                     apply,
+                    profile,
                     cli_options,
                     configuration: rome_configuration,
                     paths,
@@ -103,6 +132,8 @@ impl<'app> CliSession<'app> {
             BiomeCommand::Lint {
                 apply,
                 apply_unsafe,
+                fix_only,
+                fix_categories,
                 cli_options,
                 linter_configuration,
                 paths,
@@ -114,6 +145,8 @@ impl<'app> CliSession<'app> {
                 LintCommandPayload {
                     apply_unsafe,
                     apply,
+                    fix_only,
+                    fix_categories,
                     cli_options,
                     linter_configuration,
                     paths,
@@ -164,15 +197,35 @@ impl<'app> CliSession<'app> {
                     json_formatter,
                 },
             ),
-            BiomeCommand::Init => commands::init::init(self),
-            BiomeCommand::LspProxy(_) => commands::daemon::lsp_proxy(),
+            BiomeCommand::Search {
+                query,
+                vcs_configuration,
+                files_configuration,
+                cli_options,
+                paths,
+            } => commands::search::search(
+                self,
+                SearchCommandPayload {
+                    query,
+                    cli_options,
+                    vcs_configuration,
+                    files_configuration,
+                    paths,
+                },
+            ),
+            BiomeCommand::Init => commands::init::init(self).map(|()| CommandOutcome::Success),
+            BiomeCommand::LspProxy(_) => {
+                commands::daemon::lsp_proxy().map(|()| CommandOutcome::Success)
+            }
             BiomeCommand::Migrate(cli_options, write) => {
                 commands::migrate::migrate(self, cli_options, write)
             }
             BiomeCommand::RunServer { stop_on_disconnect } => {
-                commands::daemon::run_server(stop_on_disconnect)
+                commands::daemon::run_server(stop_on_disconnect).map(|()| CommandOutcome::Success)
+            }
+            BiomeCommand::PrintSocket => {
+                commands::daemon::print_socket().map(|()| CommandOutcome::Success)
             }
-            BiomeCommand::PrintSocket => commands::daemon::print_socket(),
         };
 
         if has_metrics {
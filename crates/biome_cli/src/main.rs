@@ -6,7 +6,7 @@
 
 use biome_cli::{
     biome_command, open_transport, setup_panic_handler, to_color_mode, BiomeCommand, CliDiagnostic,
-    CliSession,
+    CliSession, CommandOutcome,
 };
 use biome_console::{markup, ConsoleExt, EnvConsole};
 use biome_diagnostics::{set_bottom_frame, PrintDiagnostic};
@@ -43,7 +43,7 @@ fn main() -> ExitCode {
                     });
                     termination.report()
                 }
-                Ok(_) => ExitCode::SUCCESS,
+                Ok(outcome) => outcome.report(),
             }
         }
         Err(failure) => {
@@ -59,7 +59,10 @@ fn main() -> ExitCode {
     }
 }
 
-fn run_workspace(console: &mut EnvConsole, command: BiomeCommand) -> Result<(), CliDiagnostic> {
+fn run_workspace(
+    console: &mut EnvConsole,
+    command: BiomeCommand,
+) -> Result<CommandOutcome, CliDiagnostic> {
     // If the `--use-server` CLI flag is set, try to open a connection to an
     // existing Biome server socket
     let workspace = if command.should_use_server() {
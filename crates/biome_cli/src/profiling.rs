@@ -0,0 +1,151 @@
+//! Collects the time spent processing the files of a traversal, when the
+//! `--profile` argument is passed.
+//!
+//! The timings are emitted as a trace in the Chromium tracing format, that can be
+//! loaded in `chrome://tracing` or in [speedscope](https://www.speedscope.app/).
+
+use biome_analyze::profiling::RuleProfiler;
+use biome_analyze::RuleKey;
+use biome_fs::{FileSystem, FileSystemExt};
+use serde::Serialize;
+use std::io;
+use std::path::Path;
+use std::sync::Mutex;
+use std::time::Instant;
+
+/// The name of the file the trace is written to, relative to the working directory
+pub(crate) const PROFILE_FILE_NAME: &str = "biome-profile.json";
+
+/// A single event of the trace
+#[derive(Debug, Serialize)]
+struct TraceEvent {
+    /// The name of the span, e.g. the path of the file
+    name: String,
+    /// The category of the span
+    cat: &'static str,
+    /// The phase of the event: `B` when the span begins, `E` when it ends
+    ph: &'static str,
+    /// The time of the event, in microseconds since the start of the traversal
+    ts: u128,
+    pid: u32,
+    /// The index of the worker thread that processes the span
+    tid: usize,
+}
+
+/// Records the beginning and the end of spans, from all the worker threads.
+///
+/// The rules are recorded too when the workspace runs in the same process,
+/// because the profiler is registered as the [RuleProfiler] of the analyzer.
+pub(crate) struct Profiler {
+    start: Instant,
+    pid: u32,
+    events: Mutex<Vec<TraceEvent>>,
+}
+
+impl Profiler {
+    pub(crate) fn new() -> Self {
+        Self {
+            start: Instant::now(),
+            pid: std::process::id(),
+            events: Mutex::default(),
+        }
+    }
+
+    /// Runs `func` inside a span called `name`
+    pub(crate) fn profile<T>(
+        &self,
+        name: String,
+        category: &'static str,
+        func: impl FnOnce() -> T,
+    ) -> T {
+        let _span = self.enter(name, category);
+        func()
+    }
+
+    /// Begins a span called `name`, that ends when the returned guard is dropped
+    fn enter(&self, name: String, category: &'static str) -> Span {
+        let tid = rayon::current_thread_index().unwrap_or_default();
+        self.push_event(name.clone(), category, "B", tid);
+        Span {
+            profiler: self,
+            name,
+            category,
+            tid,
+        }
+    }
+
+    fn push_event(&self, name: String, cat: &'static str, ph: &'static str, tid: usize) {
+        let event = TraceEvent {
+            name,
+            cat,
+            ph,
+            ts: self.start.elapsed().as_micros(),
+            pid: self.pid,
+            tid,
+        };
+        if let Ok(mut events) = self.events.lock() {
+            events.push(event);
+        }
+    }
+
+    /// Writes the events recorded so far to the file at `path`
+    pub(crate) fn write(&self, fs: &dyn FileSystem, path: &Path) -> io::Result<()> {
+        let content = match self.events.lock() {
+            Ok(events) => serde_json::to_vec(&*events)?,
+            Err(_) => return Err(io::Error::new(io::ErrorKind::Other, "poisoned profiler")),
+        };
+        fs.create(path)?.set_content(&content)
+    }
+}
+
+impl RuleProfiler for Profiler {
+    fn rule_started(&self, rule: RuleKey) {
+        let tid = rayon::current_thread_index().unwrap_or_default();
+        self.push_event(rule_span_name(rule), "rule", "B", tid);
+    }
+
+    fn rule_finished(&self, rule: RuleKey) {
+        let tid = rayon::current_thread_index().unwrap_or_default();
+        self.push_event(rule_span_name(rule), "rule", "E", tid);
+    }
+}
+
+fn rule_span_name(rule: RuleKey) -> String {
+    format!("{}/{}", rule.group(), rule.rule_name())
+}
+
+/// Ends its span when dropped, so that the span is ended even if the profiled code panics
+struct Span<'a> {
+    profiler: &'a Profiler,
+    name: String,
+    category: &'static str,
+    tid: usize,
+}
+
+impl Drop for Span<'_> {
+    fn drop(&mut self) {
+        let name = std::mem::take(&mut self.name);
+        self.profiler.push_event(name, self.category, "E", self.tid);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Profiler;
+    use std::panic::{catch_unwind, AssertUnwindSafe};
+
+    #[test]
+    fn ends_the_span_of_a_panicking_function() {
+        let profiler = Profiler::new();
+        let result = catch_unwind(AssertUnwindSafe(|| {
+            profiler.profile("file.js".to_string(), "file", || {
+                panic!("processing failed")
+            })
+        }));
+        assert!(result.is_err());
+
+        let events = profiler.events.lock().unwrap();
+        let phases: Vec<_> = events.iter().map(|event| event.ph).collect();
+        assert_eq!(phases, ["B", "E"]);
+    }
+}
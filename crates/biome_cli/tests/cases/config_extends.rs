@@ -266,3 +266,302 @@ fn applies_extended_values_in_current_config() {
         result,
     ));
 }
+
+#[test]
+fn extends_resolves_nested_configurations() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let rome_json = Path::new("biome.json");
+    fs.insert(rome_json.into(), r#"{ "extends": ["configs/base.json"] }"#);
+    let base = Path::new("configs/base.json");
+    fs.insert(
+        base.into(),
+        r#"{ "extends": ["./format.json"], "formatter": { "indentStyle": "space" } }"#,
+    );
+    let format = Path::new("configs/format.json");
+    fs.insert(
+        format.into(),
+        r#"{ "javascript": { "formatter": { "quoteStyle": "single" } } }"#,
+    );
+
+    let test_file = Path::new("test.js");
+    fs.insert(test_file.into(), r#"function f() { return "string"; }"#);
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("format"),
+                "--write",
+                test_file.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "extends_resolves_nested_configurations",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn extends_resolves_package_specifiers() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let rome_json = Path::new("packages/app/biome.json");
+    fs.insert(
+        rome_json.into(),
+        r#"{ "extends": ["@company/biome-config", "@company/biome-config/strict.json"] }"#,
+    );
+    let package = Path::new("node_modules/@company/biome-config/biome.json");
+    fs.insert(
+        package.into(),
+        r#"{ "javascript": { "formatter": { "quoteStyle": "single" } } }"#,
+    );
+    let strict = Path::new("node_modules/@company/biome-config/strict.json");
+    fs.insert(
+        strict.into(),
+        r#"{ "linter": { "rules": { "suspicious": { "noDebugger": "error" } } } }"#,
+    );
+
+    let test_file = Path::new("test.js");
+    fs.insert(test_file.into(), r#"debugger; console.log("string"); "#);
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("check"),
+                "--config-path=packages/app/",
+                test_file.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "extends_resolves_package_specifiers",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn extends_should_raise_an_error_for_unresolved_package() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let rome_json = Path::new("biome.json");
+    fs.insert(
+        rome_json.into(),
+        r#"{ "extends": ["@company/biome-config"] }"#,
+    );
+
+    let test_file = Path::new("test.js");
+    fs.insert(test_file.into(), r#"debugger; console.log("string"); "#);
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("format"), test_file.as_os_str().to_str().unwrap()].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "extends_should_raise_an_error_for_unresolved_package",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn extends_should_raise_an_error_for_circular_configurations() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let rome_json = Path::new("biome.json");
+    fs.insert(rome_json.into(), r#"{ "extends": ["a.json"] }"#);
+    let a = Path::new("a.json");
+    fs.insert(a.into(), r#"{ "extends": ["b.json"] }"#);
+    let b = Path::new("b.json");
+    fs.insert(b.into(), r#"{ "extends": ["./a.json"] }"#);
+
+    let test_file = Path::new("test.js");
+    fs.insert(test_file.into(), r#"debugger; console.log("string"); "#);
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("format"), test_file.as_os_str().to_str().unwrap()].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "extends_should_raise_an_error_for_circular_configurations",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn extends_overrides_formatter_options_key_by_key() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let base = Path::new("base.json");
+    fs.insert(
+        base.into(),
+        r#"{ "formatter": { "indentStyle": "space", "indentWidth": 4, "lineWidth": 120 } }"#,
+    );
+
+    let rome_json = Path::new("biome.json");
+    fs.insert(
+        rome_json.into(),
+        r#"{ "extends": ["base.json"], "formatter": { "lineWidth": 20 } }"#,
+    );
+
+    let test_file = Path::new("test.js");
+    fs.insert(
+        test_file.into(),
+        r#"function f() { const a = ["lorem", "ipsum"]; }"#,
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("format"),
+                "--write",
+                test_file.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "extends_overrides_formatter_options_key_by_key",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn extends_merges_linter_rules() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let base = Path::new("base.json");
+    fs.insert(
+        base.into(),
+        r#"{
+  "linter": {
+    "rules": {
+      "suspicious": { "noDebugger": "off" },
+      "style": { "noVar": "error", "useConst": "error" }
+    }
+  }
+}"#,
+    );
+
+    let rome_json = Path::new("biome.json");
+    fs.insert(
+        rome_json.into(),
+        r#"{ "extends": ["base.json"], "linter": { "rules": { "style": { "noVar": "warn" } } } }"#,
+    );
+
+    let test_file = Path::new("test.js");
+    fs.insert(test_file.into(), r#"debugger; var a = 1; let b = a;"#);
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("lint"), test_file.as_os_str().to_str().unwrap()].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "extends_merges_linter_rules",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn extends_concatenates_overrides() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let base = Path::new("base.json");
+    fs.insert(
+        base.into(),
+        r#"{ "overrides": [{ "include": ["a.js"], "linter": { "enabled": false } }] }"#,
+    );
+
+    let rome_json = Path::new("biome.json");
+    fs.insert(
+        rome_json.into(),
+        r#"{
+  "extends": ["base.json"],
+  "overrides": [{ "include": ["b.js"], "linter": { "enabled": false } }]
+}"#,
+    );
+
+    let a = Path::new("a.js");
+    fs.insert(a.into(), r#"debugger;"#);
+    let b = Path::new("b.js");
+    fs.insert(b.into(), r#"debugger;"#);
+    let c = Path::new("c.js");
+    fs.insert(c.into(), r#"debugger;"#);
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("lint"),
+                a.as_os_str().to_str().unwrap(),
+                b.as_os_str().to_str().unwrap(),
+                c.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "extends_concatenates_overrides",
+        fs,
+        console,
+        result,
+    ));
+}
@@ -0,0 +1,184 @@
+use crate::run_cli;
+use crate::snap_test::{assert_cli_snapshot, SnapshotPayload};
+use biome_console::BufferConsole;
+use biome_fs::MemoryFileSystem;
+use biome_service::DynRef;
+use bpaf::Args;
+use std::path::Path;
+
+#[test]
+fn config_override_formatter_key() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let biome_json = Path::new("biome.json");
+    fs.insert(
+        biome_json.into(),
+        r#"{ "javascript": { "formatter": { "quoteStyle": "double" } } }"#,
+    );
+
+    let test_file = Path::new("test.js");
+    fs.insert(test_file.into(), r#"const a = "string";"#);
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("format"),
+                "--config-override=javascript.formatter.quoteStyle=single",
+                "--write",
+                test_file.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "config_override_formatter_key",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn config_override_linter_rule() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let biome_json = Path::new("biome.json");
+    fs.insert(
+        biome_json.into(),
+        r#"{ "linter": { "rules": { "suspicious": { "noDebugger": "error" } } } }"#,
+    );
+
+    let test_file = Path::new("test.js");
+    fs.insert(test_file.into(), r#"debugger;"#);
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("lint"),
+                "--config-override",
+                "linter.rules.suspicious.noDebugger=off",
+                test_file.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "config_override_linter_rule",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn config_override_applies_overrides_in_order() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let test_file = Path::new("test.js");
+    fs.insert(test_file.into(), r#"function f() { return 1; }"#);
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("format"),
+                "--config-override=formatter.indentStyle=space",
+                "--config-override=formatter.indentWidth=2",
+                "--config-override=formatter.indentWidth=8",
+                "--write",
+                test_file.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "config_override_applies_overrides_in_order",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn config_override_unknown_key() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let test_file = Path::new("test.js");
+    fs.insert(test_file.into(), r#"const a = "string";"#);
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("format"),
+                "--config-override=javascript.formatter.lineWidthh=100",
+                test_file.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "config_override_unknown_key",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn config_override_invalid_value() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let test_file = Path::new("test.js");
+    fs.insert(test_file.into(), r#"const a = "string";"#);
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("format"),
+                "--config-override=formatter.indentStyle=tabs",
+                test_file.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "config_override_invalid_value",
+        fs,
+        console,
+        result,
+    ));
+}
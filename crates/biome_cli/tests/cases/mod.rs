@@ -3,6 +3,7 @@
 
 mod biome_json_support;
 mod config_extends;
+mod config_override;
 mod included_files;
 mod overrides_formatter;
 mod overrides_linter;
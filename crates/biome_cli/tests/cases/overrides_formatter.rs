@@ -449,3 +449,62 @@ fn does_include_file_with_different_languages_and_files() {
         result,
     ));
 }
+
+#[test]
+fn does_include_file_with_different_trailing_newline() {
+    let mut console = BufferConsole::default();
+    let mut fs = MemoryFileSystem::default();
+    let file_path = Path::new("biome.json");
+    fs.insert(
+        file_path.into(),
+        r#"{
+  "formatter": { "trailingNewline": "never" },
+  "overrides": [{ "include": ["special/**"], "formatter": { "trailingNewline": "always" } }]
+}
+
+"#
+        .as_bytes(),
+    );
+
+    let test = Path::new("test.js");
+    fs.insert(test.into(), UNFORMATTED.as_bytes());
+
+    let test2 = Path::new("special/test2.js");
+    fs.insert(test2.into(), UNFORMATTED.as_bytes());
+
+    let json_file = Path::new("test3.json");
+    fs.insert(json_file.into(), UNFORMATTED_JSON.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("format"),
+                ("--write"),
+                test.as_os_str().to_str().unwrap(),
+                test2.as_os_str().to_str().unwrap(),
+                json_file.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, test, FORMATTED.trim_end());
+    assert_file_contents(&fs, test2, FORMATTED);
+    assert_file_contents(
+        &fs,
+        json_file,
+        "{ \"asta\": [\"lorem\", \"ipsum\", \"first\", \"second\"] }",
+    );
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "does_include_file_with_different_trailing_newline",
+        fs,
+        console,
+        result,
+    ));
+}
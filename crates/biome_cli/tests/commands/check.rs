@@ -1622,6 +1622,51 @@ import * as something from "../something";
     ));
 }
 
+#[test]
+fn shows_trailing_newline_diff_on_check() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("check.js");
+    fs.insert(file_path.into(), FORMATTED.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("check"),
+                ("--trailing-newline"),
+                ("never"),
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    let mut file = fs
+        .open(file_path)
+        .expect("formatting target file was removed by the CLI");
+
+    let mut content = String::new();
+    file.read_to_string(&mut content)
+        .expect("failed to read file from memory FS");
+
+    assert_eq!(content, FORMATTED);
+
+    drop(file);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "shows_trailing_newline_diff_on_check",
+        fs,
+        console,
+        result,
+    ));
+}
+
 #[test]
 fn should_organize_imports_diff_on_check() {
     let mut fs = MemoryFileSystem::default();
@@ -2818,3 +2863,92 @@ fn use_literal_keys_should_emit_correct_ast_issue_266() {
         result,
     ));
 }
+
+#[test]
+fn profile_writes_trace() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("check.js");
+    fs.insert(file_path.into(), FORMATTED.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("check"),
+                ("--profile"),
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    let mut buffer = String::new();
+    fs.open(Path::new("biome-profile.json"))
+        .expect("the profile should be written")
+        .read_to_string(&mut buffer)
+        .unwrap();
+
+    let events: Vec<serde_json::Value> = serde_json::from_str(&buffer).unwrap();
+    let spans: Vec<_> = events
+        .iter()
+        .map(|event| {
+            assert!(event["ts"].is_u64());
+            assert!(event["pid"].is_u64());
+            assert!(event["tid"].is_u64());
+            (
+                event["name"].as_str().unwrap(),
+                event["cat"].as_str().unwrap(),
+                event["ph"].as_str().unwrap(),
+            )
+        })
+        .collect();
+
+    // Each rule run is a span nested in the step that runs the analyzer
+    for pair in spans
+        .iter()
+        .filter(|(_, category, _)| *category == "rule")
+        .collect::<Vec<_>>()
+        .chunks(2)
+    {
+        assert!(
+            matches!(pair, [(begin, _, "B"), (end, _, "E")] if begin == end),
+            "unbalanced rule spans {pair:?}"
+        );
+    }
+    let organize_imports_start = spans
+        .iter()
+        .position(|span| *span == ("organizeImports", "feature", "B"))
+        .unwrap();
+    assert_eq!(
+        spans[organize_imports_start..organize_imports_start + 4],
+        [
+            ("organizeImports", "feature", "B"),
+            ("correctness/organizeImports", "rule", "B"),
+            ("correctness/organizeImports", "rule", "E"),
+            ("organizeImports", "feature", "E"),
+        ]
+    );
+
+    let spans: Vec<_> = spans
+        .into_iter()
+        .filter(|(_, category, _)| *category != "rule")
+        .collect();
+    assert_eq!(
+        spans,
+        [
+            ("check.js", "file", "B"),
+            ("lint", "feature", "B"),
+            ("lint", "feature", "E"),
+            ("organizeImports", "feature", "B"),
+            ("organizeImports", "feature", "E"),
+            ("format", "feature", "B"),
+            ("format", "feature", "E"),
+            ("check.js", "file", "E"),
+        ]
+    );
+}
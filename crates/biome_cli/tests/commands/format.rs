@@ -69,6 +69,171 @@ action => {};
 (action = 1) => {};
 "#;
 
+const APPLY_TEMPLATE_LITERAL_INDENTATION_BEFORE: &str = r#"const message = `Hello ${user.firstName},
+  you have ${notifications.filter((notification) => !notification.read).length} notifications`;
+"#;
+
+const APPLY_JSX_ATTRIBUTE_ALIGNMENT_BEFORE: &str = r#"<input type="text" value={value} onChange={handleChange} placeholder="First name" />;
+"#;
+
+const APPLY_JSX_ATTRIBUTE_ALIGNMENT_AFTER: &str = r#"<input type="text"
+       value={value}
+       onChange={handleChange}
+       placeholder="First name"
+/>;
+"#;
+
+const HONOR_PRETTIER_IGNORE_BEFORE: &str = r#"// prettier-ignore
+const matrix = [
+    1, 0,
+    0, 1,
+];
+"#;
+
+const HONOR_PRETTIER_IGNORE_AFTER: &str = r#"// prettier-ignore
+const matrix = [1, 0, 0, 1];
+"#;
+
+const APPLY_FUNCTION_CALL_ARGUMENTS_ON_ONE_LINE_BEFORE: &str = r#"createUserAccount(firstNameOfTheNewUser, lastNameOfTheNewUser, emailAddressOfTheNewUser);
+"#;
+
+const APPLY_FUNCTION_CALL_ARGUMENTS_ON_ONE_LINE_AFTER: &str = r#"createUserAccount(firstNameOfTheNewUser, lastNameOfTheNewUser, emailAddressOfTheNewUser);
+"#;
+
+const APPLY_WRAP_LONG_STRINGS_BEFORE: &str = r#"const errorMessage =
+	"The configuration file could not be loaded from the project";
+"#;
+
+const APPLY_WRAP_LONG_STRINGS_AFTER: &str = r#"const errorMessage = "The configuration file could not be loaded from the project";
+"#;
+
+const APPLY_LONG_COMMENT_STYLE_BEFORE: &str = r#"/* Formats the input,
+   and prints the result. */
+format(input);
+"#;
+
+const APPLY_LONG_COMMENT_STYLE_AFTER: &str = r#"// Formats the input,
+// and prints the result.
+format(input);
+"#;
+
+const APPLY_OBJECT_DESTRUCTURING_SPACING_BEFORE: &str = r#"import { format } from "mod";
+const { input } = options;
+export { input };
+"#;
+
+const APPLY_OBJECT_DESTRUCTURING_SPACING_AFTER: &str = r#"import {format} from "mod";
+const {input} = options;
+export {input};
+"#;
+
+const APPLY_BLANK_LINES_AFTER_IMPORTS_BEFORE: &str = r#"import { format } from "mod";
+format(input);
+"#;
+
+const APPLY_BLANK_LINES_AFTER_IMPORTS_AFTER: &str = r#"import { format } from "mod";
+
+
+format(input);
+"#;
+
+const APPLY_ALIGN_CONSECUTIVE_ASSIGNMENTS_BEFORE: &str = r#"const a = 1;
+const bbb = 2;
+let cc = 3;
+"#;
+
+const APPLY_ALIGN_CONSECUTIVE_ASSIGNMENTS_AFTER: &str = r#"const a   = 1;
+const bbb = 2;
+let cc = 3;
+"#;
+
+const APPLY_UNDEFINED_STYLE_BEFORE: &str = r#"let value = void 0;
+void 0;
+void run();
+"#;
+
+const APPLY_UNDEFINED_STYLE_AFTER: &str = r#"let value = undefined;
+void 0;
+void run();
+"#;
+
+const APPLY_NESTED_TERNARY_STYLE_BEFORE: &str = r#"const animal = isBird ? "bird" : isCat ? "cat" : isDog ? "dog" : isFish ? "fish" : "unknown animal";
+"#;
+
+const APPLY_NESTED_TERNARY_STYLE_AFTER: &str = r#"const animal =
+	  isBird ? "bird"
+	: isCat ? "cat"
+	: isDog ? "dog"
+	: isFish ? "fish"
+	: "unknown animal";
+"#;
+
+const APPLY_ELSE_ON_SAME_LINE_BEFORE: &str = r#"if (a) { b(); } else { c(); }
+try { a(); } catch { b(); } finally { c(); }
+"#;
+
+const APPLY_ELSE_ON_SAME_LINE_AFTER: &str = r#"if (a) {
+	b();
+}
+else {
+	c();
+}
+try {
+	a();
+}
+catch {
+	b();
+}
+finally {
+	c();
+}
+"#;
+
+const APPLY_KEEP_IMPORT_META_ON_ONE_LINE_BEFORE: &str = r#"const directory = new URL("./fixtures/very/deeply/nested/directory/", import.meta.dirname);
+"#;
+
+const APPLY_KEEP_IMPORT_META_ON_ONE_LINE_AFTER: &str = r#"const directory = new URL(
+	"./fixtures/very/deeply/nested/directory/",
+	import.meta.dirname,
+);
+"#;
+
+const APPLY_BREAK_TEMPLATE_EXPRESSIONS_BEFORE: &str = r#"const message = `Hello ${firstName} ${lastName}, your order #${orderId} is ready to be shipped`;
+"#;
+
+const APPLY_BREAK_TEMPLATE_EXPRESSIONS_AFTER: &str = r#"const message = `Hello ${firstName} ${lastName}, your order #${
+	orderId
+} is ready to be shipped`;
+"#;
+
+const APPLY_DO_WHILE_STYLE_BEFORE: &str = r#"do {
+  attempts++;
+} while (!connected && attempts < 3);
+"#;
+
+const APPLY_DO_WHILE_STYLE_AFTER: &str = r#"do {
+	attempts++;
+}
+while (!connected && attempts < 3);
+"#;
+
+const APPLY_NUMERIC_LITERAL_STYLE_BEFORE: &str = r#"const timeout = 3e4;
+const mask = 0xff_ff;
+const budget = 2500000;
+"#;
+
+const APPLY_NUMERIC_LITERAL_STYLE_AFTER: &str = r#"const timeout = 30_000;
+const mask = 0xFF_FF;
+const budget = 2_500_000;
+"#;
+
+const APPLY_TYPE_ASSERTION_STYLE_BEFORE: &str = r#"const configuration = someConfigurationLoader.loadedConfiguration as ApplicationConfiguration;
+"#;
+
+const APPLY_TYPE_ASSERTION_STYLE_AFTER: &str = r#"const configuration = someConfigurationLoader.loadedConfiguration as
+	ApplicationConfiguration;
+"#;
+
 // Without this, Test (windows-latest) fails with: `warning: constant `DEFAULT_CONFIGURATION_BEFORE` is never used`
 #[allow(dead_code)]
 const DEFAULT_CONFIGURATION_BEFORE: &str = r#"function f() {
@@ -105,11 +270,813 @@ fn format_help() {
         Args::from([("format"), "--help"].as_slice()),
     );
 
-    assert!(result.is_ok(), "run_cli returned {result:?}");
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "format_help",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn print() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("format.js");
+    fs.insert(file_path.into(), UNFORMATTED.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("format"), file_path.as_os_str().to_str().unwrap()].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    let mut file = fs
+        .open(file_path)
+        .expect("formatting target file was removed by the CLI");
+
+    let mut content = String::new();
+    file.read_to_string(&mut content)
+        .expect("failed to read file from memory FS");
+
+    assert_eq!(content, UNFORMATTED);
+
+    drop(file);
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "formatter_print",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn write() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("format.js");
+    fs.insert(file_path.into(), UNFORMATTED.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("format"),
+                ("--write"),
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    let mut file = fs
+        .open(file_path)
+        .expect("formatting target file was removed by the CLI");
+
+    let mut content = String::new();
+    file.read_to_string(&mut content)
+        .expect("failed to read file from memory FS");
+
+    assert_eq!(content, FORMATTED);
+
+    assert_eq!(console.out_buffer.len(), 1);
+
+    drop(file);
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "formatter_write",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn write_only_files_in_correct_base() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_to_format = Path::new("src/format.js");
+    fs.insert(
+        file_to_format.into(),
+        <&str>::clone(&UNFORMATTED).as_bytes(),
+    );
+
+    let file_to_not_format = Path::new("scripts/format.js");
+    fs.insert(file_to_not_format.into(), UNFORMATTED.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("format"), ("--write"), ("./src")].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    let mut file = fs
+        .open(file_to_format)
+        .expect("formatting target file was removed by the CLI");
+
+    let mut content = String::new();
+    file.read_to_string(&mut content)
+        .expect("failed to read file from memory FS");
+
+    assert_eq!(content, FORMATTED, "we test the file is formatted");
+    drop(file);
+    let mut file = fs
+        .open(file_to_not_format)
+        .expect("formatting target file was removed by the CLI");
+
+    let mut content = String::new();
+    file.read_to_string(&mut content)
+        .expect("failed to read file from memory FS");
+
+    assert_eq!(content, UNFORMATTED, "we test the file is not formatted");
+    drop(file);
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "write_only_files_in_correct_base",
+        fs,
+        console,
+        result,
+    ));
+}
+
+// Ensures lint warnings are not printed in format mode
+#[test]
+fn lint_warning() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("format.js");
+    fs.insert(file_path.into(), LINT_ERROR.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("format"), file_path.as_os_str().to_str().unwrap()].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    let mut file = fs
+        .open(file_path)
+        .expect("formatting target file was removed by the CLI");
+
+    let mut content = String::new();
+    file.read_to_string(&mut content)
+        .expect("failed to read file from memory FS");
+
+    assert_eq!(content, LINT_ERROR);
+
+    // The console buffer is expected to contain the following message:
+    // 0: "Formatter would have printed the following content"
+    // 1: "Compared 1 files"
+    assert_eq!(
+        console.out_buffer.len(),
+        2,
+        "console {:#?}",
+        console.out_buffer
+    );
+
+    drop(file);
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "formatter_lint_warning",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+// FIXME: redact snapshot for custom paths in configuration
+#[cfg(not(windows))]
+fn custom_config_file_path() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let config_path = Path::new("/test/rome.json");
+    fs.insert(config_path.into(), CONFIG_FORMAT.as_bytes());
+
+    let file_path = Path::new("file.js");
+    fs.insert(file_path.into(), DEFAULT_CONFIGURATION_BEFORE.as_bytes());
+
+    let mut config_path = PathBuf::from(config_path);
+    config_path.pop();
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("format"),
+                format!(
+                    "--config-path={}",
+                    config_path.display().to_string().as_str()
+                )
+                .as_str(),
+                ("--write"),
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    let mut file = fs
+        .open(file_path)
+        .expect("formatting target file was removed by the CLI");
+
+    let mut content = String::new();
+    file.read_to_string(&mut content)
+        .expect("failed to read file from memory FS");
+
+    assert_eq!(content, DEFAULT_CONFIGURATION_AFTER);
+
+    drop(file);
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "custom_config_file_path",
+        fs,
+        console,
+        result,
+    ));
+}
+
+// Should throw an error when an invalid configuration path is specified
+#[test]
+// FIXME: redact snapshot for custom paths in configuration
+#[cfg(not(windows))]
+fn invalid_config_file_path() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let config_path = Path::new("test");
+    let file_path = Path::new("file.js");
+    fs.insert(file_path.into(), *b"content");
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("format"),
+                ("--config-path"),
+                (config_path.display().to_string().as_str()),
+                ("--write"),
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "invalid_config_file_path",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn applies_custom_configuration() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("file.js");
+    fs.insert(file_path.into(), CUSTOM_CONFIGURATION_BEFORE.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("format"),
+                ("--line-width"),
+                ("10"),
+                ("--indent-style"),
+                ("space"),
+                ("--indent-size"),
+                ("8"),
+                ("--write"),
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    let mut file = fs
+        .open(file_path)
+        .expect("formatting target file was removed by the CLI");
+
+    let mut content = String::new();
+    file.read_to_string(&mut content)
+        .expect("failed to read file from memory FS");
+
+    assert_eq!(content, CUSTOM_CONFIGURATION_AFTER);
+
+    drop(file);
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "applies_custom_configuration",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn applies_custom_configuration_over_config_file() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("biome.json");
+    fs.insert(file_path.into(), CONFIG_FORMAT.as_bytes());
+
+    let file_path = Path::new("file.js");
+    fs.insert(file_path.into(), CUSTOM_CONFIGURATION_BEFORE.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("format"),
+                ("--line-width"),
+                ("10"),
+                ("--indent-style"),
+                ("space"),
+                ("--indent-size"),
+                ("8"),
+                ("--write"),
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    let mut file = fs
+        .open(file_path)
+        .expect("formatting target file was removed by the CLI");
+
+    let mut content = String::new();
+    file.read_to_string(&mut content)
+        .expect("failed to read file from memory FS");
+
+    assert_eq!(content, CUSTOM_CONFIGURATION_AFTER);
+
+    drop(file);
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "applies_custom_configuration_over_config_file",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn applies_custom_configuration_over_config_file_issue_3175_v1() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("biome.json");
+    fs.insert(file_path.into(), CONFIG_ISSUE_3175_1.as_bytes());
+
+    let file_path = Path::new("file.js");
+    fs.insert(file_path.into(), "import React from 'react';\n".as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("format"),
+                ("--quote-style"),
+                ("single"),
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    let mut file = fs
+        .open(file_path)
+        .expect("formatting target file was removed by the CLI");
+
+    let mut content = String::new();
+    file.read_to_string(&mut content)
+        .expect("failed to read file from memory FS");
+
+    assert_eq!(content, "import React from 'react';\n");
+
+    drop(file);
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "applies_custom_configuration_over_config_file_issue_3175_v1",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn applies_custom_configuration_over_config_file_issue_3175_v2() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let source = r#"function f() {
+  return 'hey';
+}
+"#;
+
+    let file_path = Path::new("biome.json");
+    fs.insert(file_path.into(), CONFIG_ISSUE_3175_2.as_bytes());
+
+    let file_path = Path::new("file.js");
+    fs.insert(file_path.into(), source.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("format"),
+                ("--indent-style"),
+                ("space"),
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    let mut file = fs
+        .open(file_path)
+        .expect("formatting target file was removed by the CLI");
+
+    let mut content = String::new();
+    file.read_to_string(&mut content)
+        .expect("failed to read file from memory FS");
+
+    assert_eq!(content, source);
+
+    drop(file);
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "applies_custom_configuration_over_config_file_issue_3175_v2",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn applies_custom_jsx_quote_style() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("file.js");
+    fs.insert(file_path.into(), APPLY_JSX_QUOTE_STYLE_BEFORE.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("format"),
+                ("--jsx-quote-style"),
+                ("single"),
+                ("--quote-properties"),
+                ("preserve"),
+                ("--write"),
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    let mut file = fs
+        .open(file_path)
+        .expect("formatting target file was removed by the CLI");
+
+    let mut content = String::new();
+    file.read_to_string(&mut content)
+        .expect("failed to read file from memory FS");
+
+    assert_eq!(content, APPLY_JSX_QUOTE_STYLE_AFTER);
+
+    drop(file);
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "applies_custom_jsx_quote_style",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn applies_custom_quote_style() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("file.js");
+    fs.insert(file_path.into(), APPLY_QUOTE_STYLE_BEFORE.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("format"),
+                ("--quote-style"),
+                ("single"),
+                ("--quote-properties"),
+                ("preserve"),
+                ("--write"),
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    let mut file = fs
+        .open(file_path)
+        .expect("formatting target file was removed by the CLI");
+
+    let mut content = String::new();
+    file.read_to_string(&mut content)
+        .expect("failed to read file from memory FS");
+
+    assert_eq!(content, APPLY_QUOTE_STYLE_AFTER);
+
+    drop(file);
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "applies_custom_quote_style",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn applies_custom_trailing_comma() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("file.js");
+    fs.insert(file_path.into(), APPLY_TRAILING_COMMA_BEFORE.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("format"),
+                ("--trailing-comma"),
+                ("none"),
+                ("--write"),
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    let mut file = fs
+        .open(file_path)
+        .expect("formatting target file was removed by the CLI");
+
+    let mut content = String::new();
+    file.read_to_string(&mut content)
+        .expect("failed to read file from memory FS");
+
+    assert_eq!(content, APPLY_TRAILING_COMMA_AFTER);
+
+    drop(file);
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "applies_custom_trailing_comma",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn applies_custom_template_literal_indentation() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("file.js");
+    fs.insert(
+        file_path.into(),
+        APPLY_TEMPLATE_LITERAL_INDENTATION_BEFORE.as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("format"),
+                ("--template-literal-indentation"),
+                ("preserve"),
+                ("--write"),
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    let mut file = fs
+        .open(file_path)
+        .expect("formatting target file was removed by the CLI");
+
+    let mut content = String::new();
+    file.read_to_string(&mut content)
+        .expect("failed to read file from memory FS");
+
+    assert_eq!(content, APPLY_TEMPLATE_LITERAL_INDENTATION_BEFORE);
+
+    drop(file);
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "applies_custom_template_literal_indentation",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn applies_custom_jsx_attribute_alignment() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("file.jsx");
+    fs.insert(
+        file_path.into(),
+        APPLY_JSX_ATTRIBUTE_ALIGNMENT_BEFORE.as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("format"),
+                ("--jsx-attribute-alignment"),
+                ("firstAttr"),
+                ("--indent-style"),
+                ("space"),
+                ("--write"),
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    let mut file = fs
+        .open(file_path)
+        .expect("formatting target file was removed by the CLI");
+
+    let mut content = String::new();
+    file.read_to_string(&mut content)
+        .expect("failed to read file from memory FS");
+
+    assert_eq!(content, APPLY_JSX_ATTRIBUTE_ALIGNMENT_AFTER);
+
+    drop(file);
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "applies_custom_jsx_attribute_alignment",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn applies_custom_honor_prettier_ignore() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("file.js");
+    fs.insert(file_path.into(), HONOR_PRETTIER_IGNORE_BEFORE.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("format"),
+                ("--honor-prettier-ignore=false"),
+                ("--write"),
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    let mut file = fs
+        .open(file_path)
+        .expect("formatting target file was removed by the CLI");
+
+    let mut content = String::new();
+    file.read_to_string(&mut content)
+        .expect("failed to read file from memory FS");
+
+    assert_eq!(content, HONOR_PRETTIER_IGNORE_AFTER);
+
+    drop(file);
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "applies_custom_honor_prettier_ignore",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn applies_custom_function_call_arguments_on_one_line() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("file.js");
+    fs.insert(
+        file_path.into(),
+        APPLY_FUNCTION_CALL_ARGUMENTS_ON_ONE_LINE_BEFORE.as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("format"),
+                ("--function-call-arguments-on-one-line"),
+                ("3"),
+                ("--write"),
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    let mut file = fs
+        .open(file_path)
+        .expect("formatting target file was removed by the CLI");
+
+    let mut content = String::new();
+    file.read_to_string(&mut content)
+        .expect("failed to read file from memory FS");
+
+    assert_eq!(content, APPLY_FUNCTION_CALL_ARGUMENTS_ON_ONE_LINE_AFTER);
 
+    drop(file);
     assert_cli_snapshot(SnapshotPayload::new(
         module_path!(),
-        "format_help",
+        "applies_custom_function_call_arguments_on_one_line",
         fs,
         console,
         result,
@@ -117,17 +1084,26 @@ fn format_help() {
 }
 
 #[test]
-fn print() {
+fn applies_custom_wrap_long_strings() {
     let mut fs = MemoryFileSystem::default();
     let mut console = BufferConsole::default();
 
-    let file_path = Path::new("format.js");
-    fs.insert(file_path.into(), UNFORMATTED.as_bytes());
+    let file_path = Path::new("file.js");
+    fs.insert(file_path.into(), APPLY_WRAP_LONG_STRINGS_BEFORE.as_bytes());
 
     let result = run_cli(
         DynRef::Borrowed(&mut fs),
         &mut console,
-        Args::from([("format"), file_path.as_os_str().to_str().unwrap()].as_slice()),
+        Args::from(
+            [
+                ("format"),
+                ("--wrap-long-strings"),
+                ("never"),
+                ("--write"),
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
     );
 
     assert!(result.is_ok(), "run_cli returned {result:?}");
@@ -140,12 +1116,12 @@ fn print() {
     file.read_to_string(&mut content)
         .expect("failed to read file from memory FS");
 
-    assert_eq!(content, UNFORMATTED);
+    assert_eq!(content, APPLY_WRAP_LONG_STRINGS_AFTER);
 
     drop(file);
     assert_cli_snapshot(SnapshotPayload::new(
         module_path!(),
-        "formatter_print",
+        "applies_custom_wrap_long_strings",
         fs,
         console,
         result,
@@ -153,12 +1129,12 @@ fn print() {
 }
 
 #[test]
-fn write() {
+fn applies_custom_long_comment_style() {
     let mut fs = MemoryFileSystem::default();
     let mut console = BufferConsole::default();
 
-    let file_path = Path::new("format.js");
-    fs.insert(file_path.into(), UNFORMATTED.as_bytes());
+    let file_path = Path::new("file.js");
+    fs.insert(file_path.into(), APPLY_LONG_COMMENT_STYLE_BEFORE.as_bytes());
 
     let result = run_cli(
         DynRef::Borrowed(&mut fs),
@@ -166,6 +1142,8 @@ fn write() {
         Args::from(
             [
                 ("format"),
+                ("--long-comment-style"),
+                ("line"),
                 ("--write"),
                 file_path.as_os_str().to_str().unwrap(),
             ]
@@ -183,14 +1161,12 @@ fn write() {
     file.read_to_string(&mut content)
         .expect("failed to read file from memory FS");
 
-    assert_eq!(content, FORMATTED);
-
-    assert_eq!(console.out_buffer.len(), 1);
+    assert_eq!(content, APPLY_LONG_COMMENT_STYLE_AFTER);
 
     drop(file);
     assert_cli_snapshot(SnapshotPayload::new(
         module_path!(),
-        "formatter_write",
+        "applies_custom_long_comment_style",
         fs,
         console,
         result,
@@ -198,69 +1174,77 @@ fn write() {
 }
 
 #[test]
-fn write_only_files_in_correct_base() {
+fn applies_custom_object_destructuring_spacing() {
     let mut fs = MemoryFileSystem::default();
     let mut console = BufferConsole::default();
 
-    let file_to_format = Path::new("src/format.js");
+    let file_path = Path::new("file.js");
     fs.insert(
-        file_to_format.into(),
-        <&str>::clone(&UNFORMATTED).as_bytes(),
+        file_path.into(),
+        APPLY_OBJECT_DESTRUCTURING_SPACING_BEFORE.as_bytes(),
     );
 
-    let file_to_not_format = Path::new("scripts/format.js");
-    fs.insert(file_to_not_format.into(), UNFORMATTED.as_bytes());
-
     let result = run_cli(
         DynRef::Borrowed(&mut fs),
         &mut console,
-        Args::from([("format"), ("--write"), ("./src")].as_slice()),
+        Args::from(
+            [
+                ("format"),
+                ("--object-destructuring-spacing"),
+                ("never"),
+                ("--write"),
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
     );
 
     assert!(result.is_ok(), "run_cli returned {result:?}");
 
     let mut file = fs
-        .open(file_to_format)
+        .open(file_path)
         .expect("formatting target file was removed by the CLI");
 
     let mut content = String::new();
     file.read_to_string(&mut content)
         .expect("failed to read file from memory FS");
 
-    assert_eq!(content, FORMATTED, "we test the file is formatted");
-    drop(file);
-    let mut file = fs
-        .open(file_to_not_format)
-        .expect("formatting target file was removed by the CLI");
-
-    let mut content = String::new();
-    file.read_to_string(&mut content)
-        .expect("failed to read file from memory FS");
+    assert_eq!(content, APPLY_OBJECT_DESTRUCTURING_SPACING_AFTER);
 
-    assert_eq!(content, UNFORMATTED, "we test the file is not formatted");
     drop(file);
     assert_cli_snapshot(SnapshotPayload::new(
         module_path!(),
-        "write_only_files_in_correct_base",
+        "applies_custom_object_destructuring_spacing",
         fs,
         console,
         result,
     ));
 }
 
-// Ensures lint warnings are not printed in format mode
 #[test]
-fn lint_warning() {
+fn applies_custom_blank_lines_after_imports() {
     let mut fs = MemoryFileSystem::default();
     let mut console = BufferConsole::default();
 
-    let file_path = Path::new("format.js");
-    fs.insert(file_path.into(), LINT_ERROR.as_bytes());
+    let file_path = Path::new("file.js");
+    fs.insert(
+        file_path.into(),
+        APPLY_BLANK_LINES_AFTER_IMPORTS_BEFORE.as_bytes(),
+    );
 
     let result = run_cli(
         DynRef::Borrowed(&mut fs),
         &mut console,
-        Args::from([("format"), file_path.as_os_str().to_str().unwrap()].as_slice()),
+        Args::from(
+            [
+                ("format"),
+                ("--blank-lines-after-imports"),
+                ("2"),
+                ("--write"),
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
     );
 
     assert!(result.is_ok(), "run_cli returned {result:?}");
@@ -273,22 +1257,12 @@ fn lint_warning() {
     file.read_to_string(&mut content)
         .expect("failed to read file from memory FS");
 
-    assert_eq!(content, LINT_ERROR);
-
-    // The console buffer is expected to contain the following message:
-    // 0: "Formatter would have printed the following content"
-    // 1: "Compared 1 files"
-    assert_eq!(
-        console.out_buffer.len(),
-        2,
-        "console {:#?}",
-        console.out_buffer
-    );
+    assert_eq!(content, APPLY_BLANK_LINES_AFTER_IMPORTS_AFTER);
 
     drop(file);
     assert_cli_snapshot(SnapshotPayload::new(
         module_path!(),
-        "formatter_lint_warning",
+        "applies_custom_blank_lines_after_imports",
         fs,
         console,
         result,
@@ -296,20 +1270,15 @@ fn lint_warning() {
 }
 
 #[test]
-// FIXME: redact snapshot for custom paths in configuration
-#[cfg(not(windows))]
-fn custom_config_file_path() {
+fn applies_custom_align_consecutive_assignments() {
     let mut fs = MemoryFileSystem::default();
     let mut console = BufferConsole::default();
 
-    let config_path = Path::new("/test/rome.json");
-    fs.insert(config_path.into(), CONFIG_FORMAT.as_bytes());
-
     let file_path = Path::new("file.js");
-    fs.insert(file_path.into(), DEFAULT_CONFIGURATION_BEFORE.as_bytes());
-
-    let mut config_path = PathBuf::from(config_path);
-    config_path.pop();
+    fs.insert(
+        file_path.into(),
+        APPLY_ALIGN_CONSECUTIVE_ASSIGNMENTS_BEFORE.as_bytes(),
+    );
 
     let result = run_cli(
         DynRef::Borrowed(&mut fs),
@@ -317,11 +1286,8 @@ fn custom_config_file_path() {
         Args::from(
             [
                 ("format"),
-                format!(
-                    "--config-path={}",
-                    config_path.display().to_string().as_str()
-                )
-                .as_str(),
+                ("--align-consecutive-assignments"),
+                ("true"),
                 ("--write"),
                 file_path.as_os_str().to_str().unwrap(),
             ]
@@ -339,29 +1305,25 @@ fn custom_config_file_path() {
     file.read_to_string(&mut content)
         .expect("failed to read file from memory FS");
 
-    assert_eq!(content, DEFAULT_CONFIGURATION_AFTER);
+    assert_eq!(content, APPLY_ALIGN_CONSECUTIVE_ASSIGNMENTS_AFTER);
 
     drop(file);
     assert_cli_snapshot(SnapshotPayload::new(
         module_path!(),
-        "custom_config_file_path",
+        "applies_custom_align_consecutive_assignments",
         fs,
         console,
         result,
     ));
 }
 
-// Should throw an error when an invalid configuration path is specified
 #[test]
-// FIXME: redact snapshot for custom paths in configuration
-#[cfg(not(windows))]
-fn invalid_config_file_path() {
+fn applies_custom_undefined_style() {
     let mut fs = MemoryFileSystem::default();
     let mut console = BufferConsole::default();
 
-    let config_path = Path::new("test");
     let file_path = Path::new("file.js");
-    fs.insert(file_path.into(), *b"content");
+    fs.insert(file_path.into(), APPLY_UNDEFINED_STYLE_BEFORE.as_bytes());
 
     let result = run_cli(
         DynRef::Borrowed(&mut fs),
@@ -369,8 +1331,8 @@ fn invalid_config_file_path() {
         Args::from(
             [
                 ("format"),
-                ("--config-path"),
-                (config_path.display().to_string().as_str()),
+                ("--undefined-style"),
+                ("undefined"),
                 ("--write"),
                 file_path.as_os_str().to_str().unwrap(),
             ]
@@ -378,11 +1340,22 @@ fn invalid_config_file_path() {
         ),
     );
 
-    assert!(result.is_err(), "run_cli returned {result:?}");
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    let mut file = fs
+        .open(file_path)
+        .expect("formatting target file was removed by the CLI");
+
+    let mut content = String::new();
+    file.read_to_string(&mut content)
+        .expect("failed to read file from memory FS");
+
+    assert_eq!(content, APPLY_UNDEFINED_STYLE_AFTER);
 
+    drop(file);
     assert_cli_snapshot(SnapshotPayload::new(
         module_path!(),
-        "invalid_config_file_path",
+        "applies_custom_undefined_style",
         fs,
         console,
         result,
@@ -390,12 +1363,15 @@ fn invalid_config_file_path() {
 }
 
 #[test]
-fn applies_custom_configuration() {
+fn applies_custom_nested_ternary_style() {
     let mut fs = MemoryFileSystem::default();
     let mut console = BufferConsole::default();
 
     let file_path = Path::new("file.js");
-    fs.insert(file_path.into(), CUSTOM_CONFIGURATION_BEFORE.as_bytes());
+    fs.insert(
+        file_path.into(),
+        APPLY_NESTED_TERNARY_STYLE_BEFORE.as_bytes(),
+    );
 
     let result = run_cli(
         DynRef::Borrowed(&mut fs),
@@ -403,12 +1379,8 @@ fn applies_custom_configuration() {
         Args::from(
             [
                 ("format"),
-                ("--line-width"),
-                ("10"),
-                ("--indent-style"),
-                ("space"),
-                ("--indent-size"),
-                ("8"),
+                ("--nested-ternary-style"),
+                ("ladder"),
                 ("--write"),
                 file_path.as_os_str().to_str().unwrap(),
             ]
@@ -426,12 +1398,12 @@ fn applies_custom_configuration() {
     file.read_to_string(&mut content)
         .expect("failed to read file from memory FS");
 
-    assert_eq!(content, CUSTOM_CONFIGURATION_AFTER);
+    assert_eq!(content, APPLY_NESTED_TERNARY_STYLE_AFTER);
 
     drop(file);
     assert_cli_snapshot(SnapshotPayload::new(
         module_path!(),
-        "applies_custom_configuration",
+        "applies_custom_nested_ternary_style",
         fs,
         console,
         result,
@@ -439,15 +1411,12 @@ fn applies_custom_configuration() {
 }
 
 #[test]
-fn applies_custom_configuration_over_config_file() {
+fn applies_custom_else_on_same_line() {
     let mut fs = MemoryFileSystem::default();
     let mut console = BufferConsole::default();
 
-    let file_path = Path::new("biome.json");
-    fs.insert(file_path.into(), CONFIG_FORMAT.as_bytes());
-
     let file_path = Path::new("file.js");
-    fs.insert(file_path.into(), CUSTOM_CONFIGURATION_BEFORE.as_bytes());
+    fs.insert(file_path.into(), APPLY_ELSE_ON_SAME_LINE_BEFORE.as_bytes());
 
     let result = run_cli(
         DynRef::Borrowed(&mut fs),
@@ -455,12 +1424,8 @@ fn applies_custom_configuration_over_config_file() {
         Args::from(
             [
                 ("format"),
-                ("--line-width"),
-                ("10"),
-                ("--indent-style"),
-                ("space"),
-                ("--indent-size"),
-                ("8"),
+                ("--else-on-same-line"),
+                ("false"),
                 ("--write"),
                 file_path.as_os_str().to_str().unwrap(),
             ]
@@ -478,12 +1443,12 @@ fn applies_custom_configuration_over_config_file() {
     file.read_to_string(&mut content)
         .expect("failed to read file from memory FS");
 
-    assert_eq!(content, CUSTOM_CONFIGURATION_AFTER);
+    assert_eq!(content, APPLY_ELSE_ON_SAME_LINE_AFTER);
 
     drop(file);
     assert_cli_snapshot(SnapshotPayload::new(
         module_path!(),
-        "applies_custom_configuration_over_config_file",
+        "applies_custom_else_on_same_line",
         fs,
         console,
         result,
@@ -491,15 +1456,15 @@ fn applies_custom_configuration_over_config_file() {
 }
 
 #[test]
-fn applies_custom_configuration_over_config_file_issue_3175_v1() {
+fn applies_custom_keep_import_meta_on_one_line() {
     let mut fs = MemoryFileSystem::default();
     let mut console = BufferConsole::default();
 
-    let file_path = Path::new("biome.json");
-    fs.insert(file_path.into(), CONFIG_ISSUE_3175_1.as_bytes());
-
     let file_path = Path::new("file.js");
-    fs.insert(file_path.into(), "import React from 'react';\n".as_bytes());
+    fs.insert(
+        file_path.into(),
+        APPLY_KEEP_IMPORT_META_ON_ONE_LINE_BEFORE.as_bytes(),
+    );
 
     let result = run_cli(
         DynRef::Borrowed(&mut fs),
@@ -507,8 +1472,9 @@ fn applies_custom_configuration_over_config_file_issue_3175_v1() {
         Args::from(
             [
                 ("format"),
-                ("--quote-style"),
-                ("single"),
+                ("--keep-import-meta-on-one-line"),
+                ("false"),
+                ("--write"),
                 file_path.as_os_str().to_str().unwrap(),
             ]
             .as_slice(),
@@ -525,12 +1491,12 @@ fn applies_custom_configuration_over_config_file_issue_3175_v1() {
     file.read_to_string(&mut content)
         .expect("failed to read file from memory FS");
 
-    assert_eq!(content, "import React from 'react';\n");
+    assert_eq!(content, APPLY_KEEP_IMPORT_META_ON_ONE_LINE_AFTER);
 
     drop(file);
     assert_cli_snapshot(SnapshotPayload::new(
         module_path!(),
-        "applies_custom_configuration_over_config_file_issue_3175_v1",
+        "applies_custom_keep_import_meta_on_one_line",
         fs,
         console,
         result,
@@ -538,20 +1504,15 @@ fn applies_custom_configuration_over_config_file_issue_3175_v1() {
 }
 
 #[test]
-fn applies_custom_configuration_over_config_file_issue_3175_v2() {
+fn applies_custom_break_template_expressions() {
     let mut fs = MemoryFileSystem::default();
     let mut console = BufferConsole::default();
 
-    let source = r#"function f() {
-  return 'hey';
-}
-"#;
-
-    let file_path = Path::new("biome.json");
-    fs.insert(file_path.into(), CONFIG_ISSUE_3175_2.as_bytes());
-
     let file_path = Path::new("file.js");
-    fs.insert(file_path.into(), source.as_bytes());
+    fs.insert(
+        file_path.into(),
+        APPLY_BREAK_TEMPLATE_EXPRESSIONS_BEFORE.as_bytes(),
+    );
 
     let result = run_cli(
         DynRef::Borrowed(&mut fs),
@@ -559,8 +1520,9 @@ fn applies_custom_configuration_over_config_file_issue_3175_v2() {
         Args::from(
             [
                 ("format"),
-                ("--indent-style"),
-                ("space"),
+                ("--break-template-expressions"),
+                ("true"),
+                ("--write"),
                 file_path.as_os_str().to_str().unwrap(),
             ]
             .as_slice(),
@@ -577,12 +1539,12 @@ fn applies_custom_configuration_over_config_file_issue_3175_v2() {
     file.read_to_string(&mut content)
         .expect("failed to read file from memory FS");
 
-    assert_eq!(content, source);
+    assert_eq!(content, APPLY_BREAK_TEMPLATE_EXPRESSIONS_AFTER);
 
     drop(file);
     assert_cli_snapshot(SnapshotPayload::new(
         module_path!(),
-        "applies_custom_configuration_over_config_file_issue_3175_v2",
+        "applies_custom_break_template_expressions",
         fs,
         console,
         result,
@@ -590,12 +1552,12 @@ fn applies_custom_configuration_over_config_file_issue_3175_v2() {
 }
 
 #[test]
-fn applies_custom_jsx_quote_style() {
+fn applies_custom_do_while_style() {
     let mut fs = MemoryFileSystem::default();
     let mut console = BufferConsole::default();
 
     let file_path = Path::new("file.js");
-    fs.insert(file_path.into(), APPLY_JSX_QUOTE_STYLE_BEFORE.as_bytes());
+    fs.insert(file_path.into(), APPLY_DO_WHILE_STYLE_BEFORE.as_bytes());
 
     let result = run_cli(
         DynRef::Borrowed(&mut fs),
@@ -603,10 +1565,8 @@ fn applies_custom_jsx_quote_style() {
         Args::from(
             [
                 ("format"),
-                ("--jsx-quote-style"),
-                ("single"),
-                ("--quote-properties"),
-                ("preserve"),
+                ("--do-while-style"),
+                ("newLine"),
                 ("--write"),
                 file_path.as_os_str().to_str().unwrap(),
             ]
@@ -624,12 +1584,12 @@ fn applies_custom_jsx_quote_style() {
     file.read_to_string(&mut content)
         .expect("failed to read file from memory FS");
 
-    assert_eq!(content, APPLY_JSX_QUOTE_STYLE_AFTER);
+    assert_eq!(content, APPLY_DO_WHILE_STYLE_AFTER);
 
     drop(file);
     assert_cli_snapshot(SnapshotPayload::new(
         module_path!(),
-        "applies_custom_jsx_quote_style",
+        "applies_custom_do_while_style",
         fs,
         console,
         result,
@@ -637,12 +1597,31 @@ fn applies_custom_jsx_quote_style() {
 }
 
 #[test]
-fn applies_custom_quote_style() {
+fn applies_custom_numeric_literal_style() {
     let mut fs = MemoryFileSystem::default();
     let mut console = BufferConsole::default();
 
+    let biome_json = Path::new("biome.json");
+    fs.insert(
+        biome_json.into(),
+        r#"{
+        "javascript": {
+            "formatter": {
+                "numericLiteralStyle": {
+                    "underscore": "addThousands",
+                    "hexCase": "upper",
+                    "exponent": "avoid"
+                }
+            }
+        }
+    }"#,
+    );
+
     let file_path = Path::new("file.js");
-    fs.insert(file_path.into(), APPLY_QUOTE_STYLE_BEFORE.as_bytes());
+    fs.insert(
+        file_path.into(),
+        APPLY_NUMERIC_LITERAL_STYLE_BEFORE.as_bytes(),
+    );
 
     let result = run_cli(
         DynRef::Borrowed(&mut fs),
@@ -650,10 +1629,6 @@ fn applies_custom_quote_style() {
         Args::from(
             [
                 ("format"),
-                ("--quote-style"),
-                ("single"),
-                ("--quote-properties"),
-                ("preserve"),
                 ("--write"),
                 file_path.as_os_str().to_str().unwrap(),
             ]
@@ -671,12 +1646,12 @@ fn applies_custom_quote_style() {
     file.read_to_string(&mut content)
         .expect("failed to read file from memory FS");
 
-    assert_eq!(content, APPLY_QUOTE_STYLE_AFTER);
+    assert_eq!(content, APPLY_NUMERIC_LITERAL_STYLE_AFTER);
 
     drop(file);
     assert_cli_snapshot(SnapshotPayload::new(
         module_path!(),
-        "applies_custom_quote_style",
+        "applies_custom_numeric_literal_style",
         fs,
         console,
         result,
@@ -684,12 +1659,15 @@ fn applies_custom_quote_style() {
 }
 
 #[test]
-fn applies_custom_trailing_comma() {
+fn applies_custom_type_assertion_style() {
     let mut fs = MemoryFileSystem::default();
     let mut console = BufferConsole::default();
 
-    let file_path = Path::new("file.js");
-    fs.insert(file_path.into(), APPLY_TRAILING_COMMA_BEFORE.as_bytes());
+    let file_path = Path::new("file.ts");
+    fs.insert(
+        file_path.into(),
+        APPLY_TYPE_ASSERTION_STYLE_BEFORE.as_bytes(),
+    );
 
     let result = run_cli(
         DynRef::Borrowed(&mut fs),
@@ -697,8 +1675,8 @@ fn applies_custom_trailing_comma() {
         Args::from(
             [
                 ("format"),
-                ("--trailing-comma"),
-                ("none"),
+                ("--type-assertion-style"),
+                ("nextLine"),
                 ("--write"),
                 file_path.as_os_str().to_str().unwrap(),
             ]
@@ -716,12 +1694,12 @@ fn applies_custom_trailing_comma() {
     file.read_to_string(&mut content)
         .expect("failed to read file from memory FS");
 
-    assert_eq!(content, APPLY_TRAILING_COMMA_AFTER);
+    assert_eq!(content, APPLY_TYPE_ASSERTION_STYLE_AFTER);
 
     drop(file);
     assert_cli_snapshot(SnapshotPayload::new(
         module_path!(),
-        "applies_custom_trailing_comma",
+        "applies_custom_type_assertion_style",
         fs,
         console,
         result,
@@ -1466,6 +2444,43 @@ fn file_too_large_cli_limit() {
     ));
 }
 
+#[test]
+fn file_too_large_is_skipped() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(PathBuf::from("biome.json"), CONFIG_FILE_SIZE_LIMIT);
+
+    let file_path = Path::new("format.js");
+    fs.insert(file_path.into(), "statement();\n");
+
+    let large_file_path = Path::new("large.js");
+    fs.insert(large_file_path.into(), "statement1();\nstatement2();");
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("format"),
+                file_path.as_os_str().to_str().unwrap(),
+                large_file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "file_too_large_is_skipped",
+        fs,
+        console,
+        result,
+    ));
+}
+
 #[test]
 fn files_max_size_parse_error() {
     let mut fs = MemoryFileSystem::default();
@@ -14,8 +14,9 @@ use crate::configs::{
     CONFIG_LINTER_SUPPRESSED_GROUP, CONFIG_LINTER_SUPPRESSED_RULE,
     CONFIG_LINTER_UPGRADE_DIAGNOSTIC, CONFIG_RECOMMENDED_GROUP,
 };
-use crate::snap_test::{markup_to_string, SnapshotPayload};
+use crate::snap_test::{assert_file_contents, markup_to_string, SnapshotPayload};
 use crate::{assert_cli_snapshot, run_cli, FORMATTED, LINT_ERROR, PARSE_ERROR};
+use biome_cli::CommandOutcome;
 use biome_console::{markup, BufferConsole, LogLevel, MarkupBuf};
 use biome_fs::{ErrorEntry, FileSystemExt, MemoryFileSystem, OsFileSystem};
 use biome_service::DynRef;
@@ -51,6 +52,8 @@ const UPGRADE_SEVERITY_CODE: &str = r#"if(!cond) { exprA(); } else { exprB() }"#
 
 const NURSERY_UNSTABLE: &str = r#"if(a = b) {}"#;
 
+const FIX_CATEGORIES_BEFORE: &str = "for (; x; ) {}\n(1 >= -0);\n";
+
 #[test]
 fn lint_help() {
     let mut fs = MemoryFileSystem::default();
@@ -221,7 +224,10 @@ fn apply_ok() {
         ),
     );
 
-    assert!(result.is_ok(), "run_cli returned {result:?}");
+    assert!(
+        matches!(result, Ok(CommandOutcome::FixesApplied)),
+        "run_cli returned {result:?}"
+    );
 
     let mut buffer = String::new();
     fs.open(file_path)
@@ -272,6 +278,189 @@ fn apply_noop() {
     ));
 }
 
+#[test]
+fn fix_only_ok() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("fix.js");
+    fs.insert(file_path.into(), FIX_BEFORE.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("lint"),
+                ("--fix-only"),
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(
+        matches!(result, Ok(CommandOutcome::FixesApplied)),
+        "run_cli returned {result:?}"
+    );
+
+    assert_file_contents(&fs, file_path, FIX_AFTER);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "fix_only_ok",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn fix_only_fails_with_unfixable_errors() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("fix.js");
+    fs.insert(file_path.into(), NO_DEBUGGER.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("lint"),
+                ("--fix-only"),
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, file_path, NO_DEBUGGER);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "fix_only_fails_with_unfixable_errors",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn fix_category_applies_only_fixes_of_group() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("fix.js");
+    fs.insert(file_path.into(), FIX_CATEGORIES_BEFORE.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("lint"),
+                ("--apply"),
+                ("--fix-category"),
+                ("style"),
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    // The diagnostic of `noCompareNegZero` is still reported
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, file_path, "while (x) {}\n(1 >= -0);\n");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "fix_category_applies_only_fixes_of_group",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn fix_category_applies_fixes_of_several_groups() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("fix.js");
+    fs.insert(file_path.into(), FIX_CATEGORIES_BEFORE.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("lint"),
+                ("--fix-category"),
+                ("style"),
+                ("--fix-category"),
+                ("suspicious"),
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(
+        matches!(result, Ok(CommandOutcome::FixesApplied)),
+        "run_cli returned {result:?}"
+    );
+
+    assert_file_contents(&fs, file_path, "while (x) {}\n(1 >= 0);\n");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "fix_category_applies_fixes_of_several_groups",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn fix_category_unknown_group() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("fix.js");
+    fs.insert(file_path.into(), FIX_CATEGORIES_BEFORE.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("lint"),
+                ("--fix-category"),
+                ("styles"),
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, file_path, FIX_CATEGORIES_BEFORE);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "fix_category_unknown_group",
+        fs,
+        console,
+        result,
+    ));
+}
+
 #[test]
 fn apply_suggested_error() {
     let mut fs = MemoryFileSystem::default();
@@ -326,7 +515,10 @@ fn apply_suggested() {
         ),
     );
 
-    assert!(result.is_ok(), "run_cli returned {result:?}");
+    assert!(
+        matches!(result, Ok(CommandOutcome::FixesApplied)),
+        "run_cli returned {result:?}"
+    );
 
     let mut buffer = String::new();
     fs.open(file_path)
@@ -1100,7 +1292,10 @@ fn fs_files_ignore_symlink() {
 
     remove_dir_all(root_path).unwrap();
 
-    assert!(result.is_ok(), "run_cli returned {result:?}");
+    assert!(
+        matches!(result, Ok(CommandOutcome::FixesApplied)),
+        "run_cli returned {result:?}"
+    );
 
     assert_cli_snapshot(SnapshotPayload::new(
         module_path!(),
@@ -2347,3 +2542,117 @@ A = 0;
         result,
     ));
 }
+
+#[test]
+fn max_warnings_exceeded() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("biome.json");
+    fs.insert(
+        file_path.into(),
+        r#"{ "linter": { "rules": { "suspicious": { "noDebugger": "warn" } } } }"#.as_bytes(),
+    );
+
+    let file_path = Path::new("file.js");
+    fs.insert(file_path.into(), "debugger;\ndebugger;\n".as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("lint"),
+                "--max-warnings=1",
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "max_warnings_exceeded",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn max_warnings_not_exceeded() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("biome.json");
+    fs.insert(
+        file_path.into(),
+        r#"{ "linter": { "rules": { "suspicious": { "noDebugger": "warn" } } } }"#.as_bytes(),
+    );
+
+    let file_path = Path::new("file.js");
+    fs.insert(file_path.into(), "debugger;\ndebugger;\n".as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("lint"),
+                "--max-warnings=2",
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "max_warnings_not_exceeded",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn max_warnings_zero() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("biome.json");
+    fs.insert(
+        file_path.into(),
+        r#"{ "linter": { "rules": { "suspicious": { "noDebugger": "warn" } } } }"#.as_bytes(),
+    );
+
+    let file_path = Path::new("file.js");
+    fs.insert(file_path.into(), "debugger;\ndebugger;\n".as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("lint"),
+                "--max-warnings=0",
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "max_warnings_zero",
+        fs,
+        console,
+        result,
+    ));
+}
@@ -6,4 +6,5 @@ mod lint;
 mod lsp_proxy;
 mod migrate;
 mod rage;
+mod search;
 mod version;
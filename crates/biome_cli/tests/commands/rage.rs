@@ -1,6 +1,6 @@
 use crate::run_cli;
 use crate::snap_test::{assert_cli_snapshot, CliSnapshot, SnapshotPayload};
-use biome_cli::CliDiagnostic;
+use biome_cli::{CliDiagnostic, CommandOutcome};
 use biome_console::{BufferConsole, Console};
 use biome_fs::{FileSystem, MemoryFileSystem};
 use biome_service::DynRef;
@@ -186,7 +186,7 @@ fn run_rage<'app>(
     fs: DynRef<'app, dyn FileSystem>,
     console: &'app mut dyn Console,
     args: Args,
-) -> Result<(), CliDiagnostic> {
+) -> Result<CommandOutcome, CliDiagnostic> {
     let _test_dir = TestLogDir::new("biome-rage-test");
     run_cli(fs, console, args)
 }
@@ -10,7 +10,7 @@ use snap_test::assert_cli_snapshot;
 use bpaf::ParseFailure;
 use std::path::Path;
 
-use biome_cli::{biome_command, CliDiagnostic, CliSession};
+use biome_cli::{biome_command, CliDiagnostic, CliSession, CommandOutcome};
 use biome_console::{markup, BufferConsole, Console, ConsoleExt};
 use biome_fs::{FileSystem, MemoryFileSystem};
 use biome_service::{App, DynRef};
@@ -397,7 +397,7 @@ pub(crate) fn run_cli<'app>(
     fs: DynRef<'app, dyn FileSystem>,
     console: &'app mut dyn Console,
     args: bpaf::Args,
-) -> Result<(), CliDiagnostic> {
+) -> Result<CommandOutcome, CliDiagnostic> {
     use biome_cli::SocketTransport;
     use biome_lsp::ServerFactory;
     use biome_service::{workspace, WorkspaceRef};
@@ -429,7 +429,7 @@ pub(crate) fn run_cli<'app>(
             if let ParseFailure::Stdout(help, _) = &failure {
                 let console = &mut session.app.console;
                 console.log(markup! {{help.to_string()}});
-                Ok(())
+                Ok(CommandOutcome::Success)
             } else {
                 Err(CliDiagnostic::parse_error_bpaf(failure))
             }
@@ -1,4 +1,4 @@
-use biome_cli::CliDiagnostic;
+use biome_cli::{CliDiagnostic, CommandOutcome};
 use biome_console::fmt::{Formatter, Termcolor};
 use biome_console::{markup, BufferConsole, Markup};
 use biome_diagnostics::termcolor::NoColor;
@@ -33,7 +33,7 @@ pub(crate) struct CliSnapshot {
 }
 
 impl CliSnapshot {
-    pub fn from_result(result: Result<(), CliDiagnostic>) -> Self {
+    pub fn from_result(result: Result<CommandOutcome, CliDiagnostic>) -> Self {
         Self {
             in_messages: InMessages::default(),
             configuration: None,
@@ -312,7 +312,7 @@ pub struct SnapshotPayload<'a> {
     pub test_name: &'a str,
     pub fs: MemoryFileSystem,
     pub console: BufferConsole,
-    pub result: Result<(), CliDiagnostic>,
+    pub result: Result<CommandOutcome, CliDiagnostic>,
 }
 
 impl<'a> SnapshotPayload<'a> {
@@ -321,7 +321,7 @@ impl<'a> SnapshotPayload<'a> {
         test_name: &'a str,
         fs: MemoryFileSystem,
         console: BufferConsole,
-        result: Result<(), CliDiagnostic>,
+        result: Result<CommandOutcome, CliDiagnostic>,
     ) -> Self {
         Self {
             module_path,
@@ -92,24 +92,62 @@ define_categories! {
     "lint/correctness/useIsNan": "https://biomejs.dev/linter/rules/use-is-nan",
     "lint/correctness/useValidForDirection": "https://biomejs.dev/linter/rules/use-valid-for-direction",
     "lint/correctness/useYield": "https://biomejs.dev/linter/rules/use-yield",
+    "lint/nursery/noAbsoluteImportPath": "https://biomejs.dev/lint/rules/no-absolute-import-path",
     "lint/nursery/noApproximativeNumericConstant": "https://biomejs.dev/lint/rules/no-approximative-numeric-constant",
+    "lint/nursery/noAsyncWithoutAwait": "https://biomejs.dev/lint/rules/no-async-without-await",
+    "lint/nursery/noConditionalAssignment": "https://biomejs.dev/lint/rules/no-conditional-assignment",
+    "lint/nursery/noDirectMutation": "https://biomejs.dev/lint/rules/no-direct-mutation",
+    "lint/nursery/noDoubleSlashComments": "https://biomejs.dev/lint/rules/no-double-slash-comments",
     "lint/nursery/noDuplicateJsonKeys": "https://biomejs.dev/linter/rules/no-duplicate-json-keys",
     "lint/nursery/noEmptyBlockStatements": "https://biomejs.dev/lint/rules/no-empty-block-statements",
     "lint/nursery/noEmptyCharacterClassInRegex": "https://biomejs.dev/lint/rules/no-empty-character-class-in-regex",
+    "lint/nursery/noEnumMemberValueOverlap": "https://biomejs.dev/lint/rules/no-enum-member-value-overlap",
+    "lint/nursery/noExcessivePropertyAccess": "https://biomejs.dev/lint/rules/no-excessive-property-access",
+    "lint/nursery/noExcessiveSwitchCases": "https://biomejs.dev/lint/rules/no-excessive-switch-cases",
+    "lint/nursery/noExpressionInStrictEquality": "https://biomejs.dev/lint/rules/no-expression-in-strict-equality",
+    "lint/nursery/noImplicitCoercion": "https://biomejs.dev/lint/rules/no-implicit-coercion",
     "lint/nursery/noInteractiveElementToNoninteractiveRole": "https://biomejs.dev/lint/rules/no-interactive-element-to-noninteractive-role",
     "lint/nursery/noInvalidNewBuiltin": "https://biomejs.dev/lint/rules/no-invalid-new-builtin",
     "lint/nursery/noMisleadingInstantiator": "https://biomejs.dev/linter/rules/no-misleading-instantiator",
     "lint/nursery/noMisrefactoredShorthandAssign": "https://biomejs.dev/lint/rules/no-misrefactored-shorthand-assign",
+    "lint/nursery/noMixedImportStyle": "https://biomejs.dev/lint/rules/no-mixed-import-style",
+    "lint/nursery/noMutableExports": "https://biomejs.dev/lint/rules/no-mutable-exports",
+    "lint/nursery/noObjectSpreadInLoop": "https://biomejs.dev/lint/rules/no-object-spread-in-loop",
+    "lint/nursery/noPrettierIgnore": "https://biomejs.dev/lint/rules/no-prettier-ignore",
+    "lint/nursery/noReExportAll": "https://biomejs.dev/lint/rules/no-re-export-all",
+    "lint/nursery/noStandaloneBlocks": "https://biomejs.dev/lint/rules/no-standalone-blocks",
+    "lint/nursery/noStringBooleanParameter": "https://biomejs.dev/lint/rules/no-string-boolean-parameter",
+    "lint/nursery/noStringLiteralType": "https://biomejs.dev/lint/rules/no-string-literal-type",
+    "lint/nursery/noStringOracle": "https://biomejs.dev/lint/rules/no-string-oracle",
+    "lint/nursery/noThrowLiteral": "https://biomejs.dev/lint/rules/no-throw-literal",
+    "lint/nursery/noUnnecessaryAwait": "https://biomejs.dev/lint/rules/no-unnecessary-await",
+    "lint/nursery/noUnsafeOptionalChain": "https://biomejs.dev/lint/rules/no-unsafe-optional-chain",
+    "lint/nursery/noUnsafeRegex": "https://biomejs.dev/lint/rules/no-unsafe-regex",
+    "lint/nursery/noUnterminatedMultilineComment": "https://biomejs.dev/lint/rules/no-unterminated-multiline-comment",
     "lint/nursery/noUnusedImports": "https://biomejs.dev/lint/rules/no-unused-imports",
+    "lint/nursery/noUnusedPrivateClassMembers": "https://biomejs.dev/lint/rules/no-unused-private-class-members",
     "lint/nursery/noUselessElse": "https://biomejs.dev/lint/rules/no-useless-else",
+    "lint/nursery/noUselessFormatSuppressionRegion": "https://biomejs.dev/lint/rules/no-useless-format-suppression-region",
     "lint/nursery/noUselessLoneBlockStatements": "https://biomejs.dev/lint/rules/no-useless-lone-block-statements",
     "lint/nursery/useAriaActivedescendantWithTabindex": "https://biomejs.dev/lint/rules/use-aria-activedescendant-with-tabindex",
+    "lint/nursery/useArrayLiteralSpread": "https://biomejs.dev/lint/rules/use-array-literal-spread",
     "lint/nursery/useArrowFunction": "https://biomejs.dev/linter/rules/use-arrow-function",
     "lint/nursery/useAsConstAssertion": "https://biomejs.dev/lint/rules/use-as-const-assertion",
     "lint/nursery/useBiomeSuppressionComment": "https://biomejs.dev/lint/rules/use-biome-suppression-comment",
+    "lint/nursery/useConsistentEmptyLineBetweenClassMembers": "https://biomejs.dev/lint/rules/use-consistent-empty-line-between-class-members",
+    "lint/nursery/useConsistentObjectDestructuring": "https://biomejs.dev/lint/rules/use-consistent-object-destructuring",
+    "lint/nursery/useExplicitReturnType": "https://biomejs.dev/lint/rules/use-explicit-return-type",
     "lint/nursery/useGroupedTypeImport": "https://biomejs.dev/linter/rules/use-grouped-type-import",
+    "lint/nursery/useImportMeta": "https://biomejs.dev/lint/rules/use-import-meta",
     "lint/nursery/useImportRestrictions": "https://biomejs.dev/linter/rules/use-import-restrictions",
+    "lint/nursery/useIteratorProtocol": "https://biomejs.dev/lint/rules/use-iterator-protocol",
+    "lint/nursery/useNullishCoalescingAssignment": "https://biomejs.dev/lint/rules/use-nullish-coalescing-assignment",
+    "lint/nursery/useNumberToFixed": "https://biomejs.dev/lint/rules/use-number-to-fixed",
+    "lint/nursery/useObjectShorthand": "https://biomejs.dev/lint/rules/use-object-shorthand",
+    "lint/nursery/useReadonlyParameters": "https://biomejs.dev/lint/rules/use-readonly-parameters",
     "lint/nursery/useShorthandAssign": "https://biomejs.dev/lint/rules/use-shorthand-assign",
+    "lint/nursery/useStructuredClone": "https://biomejs.dev/lint/rules/use-structured-clone",
+    "lint/nursery/useTaskDestructuring": "https://biomejs.dev/lint/rules/use-task-destructuring",
     "lint/performance/noAccumulatingSpread": "https://biomejs.dev/linter/rules/no-accumulating-spread",
     "lint/performance/noDelete": "https://biomejs.dev/linter/rules/no-delete",
     "lint/security/noDangerouslySetInnerHtml": "https://biomejs.dev/linter/rules/no-dangerously-set-inner-html",
@@ -187,12 +225,14 @@ define_categories! {
     ;
     // General categories
     "files/missingHandler",
+    "files/tooLarge",
     "format",
     "check",
     "ci",
     "configuration",
     "organizeImports",
     "migrate",
+    "search",
     "deserialize",
     "internalError/io",
     "internalError/fs",
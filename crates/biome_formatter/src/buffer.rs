@@ -1,5 +1,6 @@
 use super::{write, Arguments, FormatElement};
-use crate::format_element::Interned;
+use crate::format_element::tag::Tag;
+use crate::format_element::{BestFittingElement, Interned, PrintMode};
 use crate::prelude::LineMode;
 use crate::{Format, FormatResult, FormatState};
 use rustc_hash::FxHashMap;
@@ -435,6 +436,8 @@ where
 ///
 /// * Removes [`lines`](FormatElement::Line) with the mode [`Soft`](LineMode::Soft).
 /// * Replaces [`lines`](FormatElement::Line) with the mode [`Soft`](LineMode::SoftOrSpace) with a [`Space`](FormatElement::Space)
+/// * Removes the content that is only printed when its group breaks, and keeps the content that is only printed when its group is flat.
+/// * Replaces [`best fitting elements`](FormatElement::BestFitting) with their most flat variant.
 ///
 /// # Examples
 ///
@@ -487,6 +490,9 @@ pub struct RemoveSoftLinesBuffer<'a, Context> {
     /// It's fine to not snapshot the cache. The worst that can happen is that it holds on interned elements
     /// that are now unused. But there's little harm in that and the cache is cleaned when dropping the buffer.
     interned_cache: FxHashMap<Interned, Interned>,
+
+    /// The modes of the conditional contents that are being written.
+    conditional_content_stack: Vec<PrintMode>,
 }
 
 impl<'a, Context> RemoveSoftLinesBuffer<'a, Context> {
@@ -495,6 +501,7 @@ impl<'a, Context> RemoveSoftLinesBuffer<'a, Context> {
         Self {
             inner,
             interned_cache: FxHashMap::default(),
+            conditional_content_stack: Vec::new(),
         }
     }
 
@@ -517,7 +524,11 @@ fn clean_interned(
                 .iter()
                 .enumerate()
                 .find_map(|(index, element)| match element {
-                    FormatElement::Line(LineMode::Soft | LineMode::SoftOrSpace) => {
+                    FormatElement::Line(LineMode::Soft | LineMode::SoftOrSpace)
+                    | FormatElement::Tag(
+                        Tag::StartConditionalContent(_) | Tag::EndConditionalContent,
+                    )
+                    | FormatElement::BestFitting(_) => {
                         let mut cleaned = Vec::new();
                         cleaned.extend_from_slice(&interned[..index]);
                         Some((cleaned, &interned[index..]))
@@ -541,18 +552,7 @@ fn clean_interned(
             let result = match result {
                 // Copy the whole interned buffer so that becomes possible to change the necessary elements.
                 Some((mut cleaned, rest)) => {
-                    for element in rest {
-                        let element = match element {
-                            FormatElement::Line(LineMode::Soft) => continue,
-                            FormatElement::Line(LineMode::SoftOrSpace) => FormatElement::Space,
-                            FormatElement::Interned(interned) => {
-                                FormatElement::Interned(clean_interned(interned, interned_cache))
-                            }
-                            element => element.clone(),
-                        };
-                        cleaned.push(element)
-                    }
-
+                    clean_elements(rest, &mut Vec::new(), interned_cache, &mut cleaned);
                     Interned::new(cleaned)
                 }
                 // No change necessary, return existing interned element
@@ -565,16 +565,90 @@ fn clean_interned(
     }
 }
 
+/// Pushes `elements` into `cleaned`, after removing the soft line breaks,
+/// the content that is only printed when its group breaks, and the best fitting variants other than the most flat.
+fn clean_elements(
+    elements: &[FormatElement],
+    conditional_content_stack: &mut Vec<PrintMode>,
+    interned_cache: &mut FxHashMap<Interned, Interned>,
+    cleaned: &mut Vec<FormatElement>,
+) {
+    for element in elements {
+        match element {
+            FormatElement::Tag(Tag::StartConditionalContent(condition)) => {
+                conditional_content_stack.push(condition.mode);
+            }
+            FormatElement::Tag(Tag::EndConditionalContent) => {
+                conditional_content_stack.pop();
+            }
+            _ if conditional_content_stack.contains(&PrintMode::Expanded) => {}
+            FormatElement::Line(LineMode::Soft) => {}
+            FormatElement::Line(LineMode::SoftOrSpace) => cleaned.push(FormatElement::Space),
+            FormatElement::Interned(interned) => {
+                cleaned.push(FormatElement::Interned(clean_interned(
+                    interned,
+                    interned_cache,
+                )));
+            }
+            FormatElement::BestFitting(best_fitting) => clean_elements(
+                most_flat_content(best_fitting),
+                conditional_content_stack,
+                interned_cache,
+                cleaned,
+            ),
+            element => cleaned.push(element.clone()),
+        }
+    }
+}
+
+/// Returns the content of the most flat variant of `best_fitting`, without its entry tags.
+fn most_flat_content(best_fitting: &BestFittingElement) -> &[FormatElement] {
+    match best_fitting.most_flat() {
+        [FormatElement::Tag(Tag::StartEntry), content @ .., FormatElement::Tag(Tag::EndEntry)] => {
+            content
+        }
+        content => content,
+    }
+}
+
 impl<Context> Buffer for RemoveSoftLinesBuffer<'_, Context> {
     type Context = Context;
 
     fn write_element(&mut self, element: FormatElement) -> FormatResult<()> {
         let element = match element {
+            FormatElement::Tag(Tag::StartConditionalContent(condition)) => {
+                self.conditional_content_stack.push(condition.mode);
+                return Ok(());
+            }
+            FormatElement::Tag(Tag::EndConditionalContent) => {
+                self.conditional_content_stack.pop();
+                return Ok(());
+            }
+            // The content that is only printed when its group breaks is removed
+            _ if self
+                .conditional_content_stack
+                .contains(&PrintMode::Expanded) =>
+            {
+                return Ok(())
+            }
             FormatElement::Line(LineMode::Soft) => return Ok(()),
             FormatElement::Line(LineMode::SoftOrSpace) => FormatElement::Space,
             FormatElement::Interned(interned) => {
                 FormatElement::Interned(self.clean_interned(&interned))
             }
+            FormatElement::BestFitting(best_fitting) => {
+                let mut cleaned = Vec::new();
+                clean_elements(
+                    most_flat_content(&best_fitting),
+                    &mut self.conditional_content_stack,
+                    &mut self.interned_cache,
+                    &mut cleaned,
+                );
+                for element in cleaned {
+                    self.inner.write_element(element)?;
+                }
+                return Ok(());
+            }
             element => element,
         };
 
@@ -83,7 +83,9 @@ use crate::formatter::Formatter;
 use crate::{buffer::Buffer, write};
 use crate::{CstFormatContext, FormatResult, FormatRule, TextSize, TransformSourceMap};
 use biome_rowan::syntax::SyntaxElementKey;
-use biome_rowan::{Language, SyntaxNode, SyntaxToken, SyntaxTriviaPieceComments};
+use biome_rowan::{
+    Direction, Language, SyntaxNode, SyntaxToken, SyntaxTriviaPieceComments, TextRange,
+};
 use rustc_hash::FxHashSet;
 #[cfg(debug_assertions)]
 use std::cell::{Cell, RefCell};
@@ -120,11 +122,47 @@ pub enum CommentKind {
     /// a // test
     /// ```
     Line,
+
+    /// A [line](CommentKind::Line) comment that suppresses the diagnostics of another tool on the line that follows it.
+    /// The comment stays on the line immediately preceding the node it suppresses.
+    ///
+    /// ## Examples
+    ///
+    /// ```ignore
+    /// // @ts-expect-error
+    /// const a: string = 1;
+    /// ```
+    Suppression,
+
+    /// A [line](CommentKind::Line) comment that marks the start or the end of a foldable region of code.
+    /// The comment stays in front of the node that follows it.
+    ///
+    /// ## Examples
+    ///
+    /// ```ignore
+    /// // #region Helpers
+    /// function helper() {}
+    /// // #endregion
+    /// ```
+    Region,
 }
 
 impl CommentKind {
+    /// Returns `true` for line comments, including [suppression](CommentKind::Suppression)
+    /// and [region](CommentKind::Region) comments.
     pub const fn is_line(&self) -> bool {
-        matches!(self, CommentKind::Line)
+        matches!(
+            self,
+            CommentKind::Line | CommentKind::Suppression | CommentKind::Region
+        )
+    }
+
+    pub const fn is_suppression(&self) -> bool {
+        matches!(self, CommentKind::Suppression)
+    }
+
+    pub const fn is_region(&self) -> bool {
+        matches!(self, CommentKind::Region)
     }
 
     pub const fn is_block(&self) -> bool {
@@ -768,6 +806,26 @@ pub trait CommentStyle: Default {
         false
     }
 
+    /// Returns the function that tests if a comment is a suppression comment,
+    /// for the options of this style.
+    ///
+    /// Defaults to [CommentStyle::is_suppression].
+    fn suppression_test(&self) -> fn(&str) -> bool {
+        Self::is_suppression
+    }
+
+    /// Returns `true` if a comment with the given `text` starts a region of code that isn't formatted.
+    ///
+    /// The region ends at the next comment for which [CommentStyle::is_suppression_end] returns `true`.
+    fn is_suppression_start(_text: &str) -> bool {
+        false
+    }
+
+    /// Returns `true` if a comment with the given `text` ends a region of code that isn't formatted.
+    fn is_suppression_end(_text: &str) -> bool {
+        false
+    }
+
     /// Returns the (kind)[CommentKind] of the comment
     fn get_comment_kind(comment: &SyntaxTriviaPieceComments<Self::Language>) -> CommentKind;
 
@@ -822,7 +880,8 @@ impl<L: Language> Comments<L> {
         Self {
             data: Rc::new(CommentsData {
                 root: Some(root.clone()),
-                is_suppression: Style::is_suppression,
+                is_suppression: style.suppression_test(),
+                suppressed_regions: suppressed_regions::<Style>(root),
 
                 comments,
                 with_skipped: skipped,
@@ -912,7 +971,8 @@ impl<L: Language> Comments<L> {
         self.data.with_skipped.contains(&token.key())
     }
 
-    /// Returns `true` if `node` has a [leading](self#leading-comments), [dangling](self#dangling-comments), or [trailing](self#trailing-comments) suppression comment.
+    /// Returns `true` if `node` has a [leading](self#leading-comments), [dangling](self#dangling-comments), or [trailing](self#trailing-comments) suppression comment,
+    /// or if `node` is entirely inside a suppressed region.
     ///
     /// # Examples
     ///
@@ -929,6 +989,23 @@ impl<L: Language> Comments<L> {
 
         self.leading_dangling_trailing_comments(node)
             .any(|comment| is_suppression(comment.piece().text()))
+            || self.is_in_suppressed_region(node)
+    }
+
+    /// Returns `true` if `comment` is a suppression comment,
+    /// as defined by the [CommentStyle] the comments were extracted with.
+    pub fn is_suppression(&self, comment: &SourceComment<L>) -> bool {
+        (self.data.is_suppression)(comment.piece().text())
+    }
+
+    /// Returns `true` if `node` is entirely inside a region delimited by a [suppression start](CommentStyle::is_suppression_start)
+    /// and a [suppression end](CommentStyle::is_suppression_end) comment.
+    fn is_in_suppressed_region(&self, node: &SyntaxNode<L>) -> bool {
+        let range = node.text_trimmed_range();
+        self.data
+            .suppressed_regions
+            .iter()
+            .any(|region| region.contains_range(range))
     }
 
     #[cfg(not(debug_assertions))]
@@ -1029,11 +1106,42 @@ Node:
     }
 }
 
+/// Returns the ranges between the [suppression start](CommentStyle::is_suppression_start) comments
+/// and their matching [suppression end](CommentStyle::is_suppression_end) comments.
+///
+/// An end comment closes the innermost region that is still open.
+/// Start comments without a matching end comment, and end comments without a matching start comment, are ignored.
+fn suppressed_regions<Style: CommentStyle>(root: &SyntaxNode<Style::Language>) -> Vec<TextRange> {
+    let mut regions = Vec::new();
+    let mut open_regions = Vec::new();
+    for token in root.descendants_tokens(Direction::Next) {
+        let comments = token
+            .leading_trivia()
+            .pieces()
+            .chain(token.trailing_trivia().pieces())
+            .filter_map(|piece| piece.as_comments());
+        for comment in comments {
+            let text = comment.text();
+            if Style::is_suppression_start(text) {
+                open_regions.push(comment.text_range().end());
+            } else if Style::is_suppression_end(text) {
+                if let Some(start) = open_regions.pop() {
+                    regions.push(TextRange::new(start, comment.text_range().start()));
+                }
+            }
+        }
+    }
+    regions
+}
+
 struct CommentsData<L: Language> {
     root: Option<SyntaxNode<L>>,
 
     is_suppression: fn(&str) -> bool,
 
+    /// The ranges between the matching suppression start and end comments.
+    suppressed_regions: Vec<TextRange>,
+
     /// Stores all leading node comments by node
     comments: CommentsMap<SyntaxElementKey, SourceComment<L>>,
     with_skipped: FxHashSet<SyntaxElementKey>,
@@ -1055,6 +1163,7 @@ impl<L: Language> Default for CommentsData<L> {
         Self {
             root: None,
             is_suppression: |_| false,
+            suppressed_regions: Vec::new(),
             comments: Default::default(),
             with_skipped: Default::default(),
             #[cfg(debug_assertions)]
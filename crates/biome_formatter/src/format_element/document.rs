@@ -169,6 +169,7 @@ impl FormatOptions for IrFormatOptions {
             print_width: self.line_width().into(),
             line_ending: LineEnding::LineFeed,
             indent_style: IndentStyle::Space,
+            trailing_newline: None,
         }
     }
 }
@@ -241,6 +241,52 @@ impl From<LineWidth> for u16 {
     }
 }
 
+/// Whether the formatted code of a file ends with a newline
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize, schemars::JsonSchema),
+    serde(rename_all = "camelCase")
+)]
+pub enum TrailingNewline {
+    /// The formatted code always ends with a newline
+    #[default]
+    Always,
+    /// The formatted code never ends with a newline
+    Never,
+}
+
+impl TrailingNewline {
+    pub const KNOWN_VALUES: &'static [&'static str] = &["always", "never"];
+
+    /// Returns `true` if this is an [TrailingNewline::Always].
+    pub const fn is_always(&self) -> bool {
+        matches!(self, TrailingNewline::Always)
+    }
+}
+
+impl FromStr for TrailingNewline {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "always" => Ok(Self::Always),
+            "never" => Ok(Self::Never),
+            // TODO: replace this error with a diagnostic
+            _ => Err("Value not supported for TrailingNewline"),
+        }
+    }
+}
+
+impl std::fmt::Display for TrailingNewline {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TrailingNewline::Always => std::write!(f, "Always"),
+            TrailingNewline::Never => std::write!(f, "Never"),
+        }
+    }
+}
+
 /// Context object storing data relevant when formatting an object.
 pub trait FormatContext {
     type Options: FormatOptions;
@@ -9,7 +9,7 @@ pub use printer_options::*;
 use crate::format_element::{BestFittingElement, LineMode, PrintMode};
 use crate::{
     ActualStart, FormatElement, GroupId, IndentStyle, InvalidDocumentError, PrintError,
-    PrintResult, Printed, SourceMarker, TextRange,
+    PrintResult, Printed, SourceMarker, TextRange, TrailingNewline,
 };
 
 use crate::format_element::document::Document;
@@ -44,8 +44,13 @@ impl<'a> Printer<'a> {
     }
 
     /// Prints the passed in element as well as all its content
-    pub fn print(self, document: &'a Document) -> PrintResult<Printed> {
-        self.print_with_indent(document, 0)
+    pub fn print(mut self, document: &'a Document) -> PrintResult<Printed> {
+        tracing::debug_span!("Printer::print").in_scope(move || {
+            self.print_document(document, 0)?;
+            self.print_trailing_newline();
+
+            Ok(self.into_printed())
+        })
     }
 
     /// Prints the passed in element as well as all its content,
@@ -56,24 +61,59 @@ impl<'a> Printer<'a> {
         indent: u16,
     ) -> PrintResult<Printed> {
         tracing::debug_span!("Printer::print").in_scope(move || {
-            let mut stack = PrintCallStack::new(PrintElementArgs::new(Indention::Level(indent)));
-            let mut queue: PrintQueue<'a> = PrintQueue::new(document.as_ref());
+            self.print_document(document, indent)?;
+
+            Ok(self.into_printed())
+        })
+    }
 
-            while let Some(element) = queue.pop() {
-                self.print_element(&mut stack, &mut queue, element)?;
+    fn print_document(&mut self, document: &'a Document, indent: u16) -> PrintResult<()> {
+        let mut stack = PrintCallStack::new(PrintElementArgs::new(Indention::Level(indent)));
+        let mut queue: PrintQueue<'a> = PrintQueue::new(document.as_ref());
 
-                if queue.is_empty() {
-                    self.flush_line_suffixes(&mut queue, &mut stack, None);
-                }
+        while let Some(element) = queue.pop() {
+            self.print_element(&mut stack, &mut queue, element)?;
+
+            if queue.is_empty() {
+                self.flush_line_suffixes(&mut queue, &mut stack, None);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Adds or removes the newline at the end of the printed document, as configured by
+    /// [PrinterOptions::trailing_newline].
+    ///
+    /// An empty document stays empty.
+    fn print_trailing_newline(&mut self) {
+        let buffer = &mut self.state.buffer;
+        match self.options.trailing_newline {
+            Some(TrailingNewline::Always)
+                if !buffer.is_empty() && !buffer.ends_with(['\n', '\r']) =>
+            {
+                buffer.push_str(self.options.line_ending.as_str());
             }
+            Some(TrailingNewline::Never) => {
+                let len = buffer.trim_end_matches(['\n', '\r']).len();
+                buffer.truncate(len);
 
-            Ok(Printed::new(
-                self.state.buffer,
-                None,
-                self.state.source_markers,
-                self.state.verbatim_markers,
-            ))
-        })
+                let len = buffer.text_len();
+                self.state
+                    .source_markers
+                    .retain(|marker| marker.dest <= len);
+            }
+            _ => {}
+        }
+    }
+
+    fn into_printed(self) -> Printed {
+        Printed::new(
+            self.state.buffer,
+            None,
+            self.state.source_markers,
+            self.state.verbatim_markers,
+        )
     }
 
     /// Prints a single element and push the following elements to queue
@@ -1261,7 +1301,9 @@ struct FitsState {
 mod tests {
     use crate::prelude::*;
     use crate::printer::{LineEnding, PrintWidth, Printer, PrinterOptions};
-    use crate::{format_args, write, Document, FormatState, IndentStyle, Printed, VecBuffer};
+    use crate::{
+        format_args, write, Document, FormatState, IndentStyle, Printed, TrailingNewline, VecBuffer,
+    };
 
     fn format(root: &dyn Format<SimpleFormatContext>) -> Printed {
         format_with_options(
@@ -1408,6 +1450,32 @@ two lines`,
         );
     }
 
+    #[test]
+    fn it_removes_the_trailing_newline() {
+        let options = PrinterOptions::default().with_trailing_newline(TrailingNewline::Never);
+
+        let result = format_with_options(
+            &format_args![text("a;"), hard_line_break(), empty_line()],
+            options,
+        );
+
+        assert_eq!("a;", result.as_code());
+    }
+
+    #[test]
+    fn it_adds_the_trailing_newline() {
+        let options = PrinterOptions {
+            line_ending: LineEnding::CarriageReturnLineFeed,
+            ..PrinterOptions::default().with_trailing_newline(TrailingNewline::Always)
+        };
+
+        let result = format_with_options(&text("a;"), options.clone());
+        assert_eq!("a;\r\n", result.as_code());
+
+        let result = format_with_options(&text(""), options);
+        assert_eq!("", result.as_code());
+    }
+
     #[test]
     fn it_use_the_indent_character_specified_in_the_options() {
         let options = PrinterOptions {
@@ -1,4 +1,4 @@
-use crate::{FormatOptions, IndentStyle, IndentWidth, LineWidth};
+use crate::{FormatOptions, IndentStyle, IndentWidth, LineWidth, TrailingNewline};
 
 /// Options that affect how the [crate::Printer] prints the format tokens
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -15,6 +15,10 @@ pub struct PrinterOptions {
 
     /// Whether the printer should use tabs or spaces to indent code and if spaces, by how many.
     pub indent_style: IndentStyle,
+
+    /// Whether the printer adds or removes the newline at the end of the document.
+    /// The end of the document is printed as is when it's [None].
+    pub trailing_newline: Option<TrailingNewline>,
 }
 
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
@@ -74,6 +78,12 @@ impl PrinterOptions {
         self
     }
 
+    pub fn with_trailing_newline(mut self, trailing_newline: TrailingNewline) -> Self {
+        self.trailing_newline = Some(trailing_newline);
+
+        self
+    }
+
     pub(crate) fn indent_style(&self) -> IndentStyle {
         self.indent_style
     }
@@ -115,6 +125,7 @@ impl Default for PrinterOptions {
             print_width: PrintWidth::default(),
             indent_style: Default::default(),
             line_ending: LineEnding::LineFeed,
+            trailing_newline: None,
         }
     }
 }
@@ -54,8 +54,9 @@ struct FormatNumberLiteralExponent {
     first_digit_index: Option<NonZeroUsize>,
     first_non_zero_index: Option<NonZeroUsize>,
 }
+/// Returns the text of a number literal in lowercase, without unnecessary zeros, dots, and plus signs.
 // Regex-free version of https://github.com/prettier/prettier/blob/ca246afacee8e6d5db508dae01730c9523bbff1d/src/common/util.js#L341-L356
-fn format_trimmed_number(text: &str) -> Cow<str> {
+pub fn format_trimmed_number(text: &str) -> Cow<str> {
     use FormatNumberLiteralState::*;
 
     let text = text.to_ascii_lowercase_cow();
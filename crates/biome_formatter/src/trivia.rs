@@ -37,7 +37,8 @@ where
             FormatLeadingComments::Comments(comments) => comments,
         };
 
-        for comment in leading_comments {
+        for (index, comment) in leading_comments.iter().enumerate() {
+            let is_last = index + 1 == leading_comments.len();
             let format_comment = FormatRefWithRule::new(comment, Context::CommentRule::default());
             write!(f, [format_comment])?;
 
@@ -55,10 +56,16 @@ where
                         _ => write!(f, [empty_line()])?,
                     };
                 }
-                CommentKind::Line => match comment.lines_after() {
-                    0 | 1 => write!(f, [hard_line_break()])?,
-                    _ => write!(f, [empty_line()])?,
-                },
+                CommentKind::Line | CommentKind::Suppression | CommentKind::Region => {
+                    match comment.lines_after() {
+                        0 | 1 => write!(f, [hard_line_break()])?,
+                        // Keep a suppression comment on the line immediately preceding the node it suppresses
+                        _ if comment.kind().is_suppression() && is_last => {
+                            write!(f, [hard_line_break()])?
+                        }
+                        _ => write!(f, [empty_line()])?,
+                    }
+                }
             }
 
             comment.mark_formatted()
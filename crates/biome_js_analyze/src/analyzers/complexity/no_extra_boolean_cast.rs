@@ -71,7 +71,7 @@ declare_rule! {
 ///     ^^^ this is a boolean context
 /// }
 /// ```
-fn is_in_boolean_context(node: &JsSyntaxNode) -> Option<bool> {
+pub(crate) fn is_in_boolean_context(node: &JsSyntaxNode) -> Option<bool> {
     let parent = node.parent()?;
     match parent.kind() {
         JsSyntaxKind::JS_IF_STATEMENT => {
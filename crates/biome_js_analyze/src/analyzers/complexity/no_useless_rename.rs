@@ -4,9 +4,10 @@ use biome_console::markup;
 use biome_diagnostics::Applicability;
 use biome_js_factory::make;
 use biome_js_syntax::{
-    AnyJsExportNamedSpecifier, AnyJsNamedImportSpecifier, AnyJsObjectBindingPatternMember,
+    inner_string_text, AnyJsExportNamedSpecifier, AnyJsNamedImportSpecifier,
+    AnyJsObjectAssignmentPatternMember, AnyJsObjectBindingPatternMember,
     JsExportNamedFromSpecifier, JsExportNamedSpecifier, JsNamedImportSpecifier,
-    JsObjectBindingPatternProperty,
+    JsObjectAssignmentPatternProperty, JsObjectBindingPatternProperty,
 };
 use biome_rowan::{declare_node_union, trim_leading_trivia_pieces, AstNode, BatchMutationExt};
 
@@ -22,6 +23,7 @@ declare_rule! {
     /// import { foo as bar } from "baz";
     /// export { foo as bar };
     /// let { foo: bar } = baz;
+    /// ({ foo: bar } = baz);
     /// ```
     ///
     /// With this syntax, it is possible to rename a reference to the same name.
@@ -45,6 +47,14 @@ declare_rule! {
     /// let { foo: foo } = bar;
     /// ```
     ///
+    /// ```js,expect_diagnostic
+    /// ({ foo: foo } = bar);
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// import { "foo" as foo } from "bar";
+    /// ```
+    ///
     /// ### Valid
     ///
     /// ```js
@@ -68,7 +78,7 @@ declare_rule! {
 }
 
 declare_node_union! {
-    pub(crate) JsRenaming = JsExportNamedFromSpecifier | JsExportNamedSpecifier | JsNamedImportSpecifier | JsObjectBindingPatternProperty
+    pub(crate) JsRenaming = JsExportNamedFromSpecifier | JsExportNamedSpecifier | JsNamedImportSpecifier | JsObjectBindingPatternProperty | JsObjectAssignmentPatternProperty
 }
 
 impl Rule for NoUselessRename {
@@ -105,8 +115,18 @@ impl Rule for NoUselessRename {
                     .name_token()
                     .ok()?,
             ),
+            JsRenaming::JsObjectAssignmentPatternProperty(x) => (
+                x.member().ok()?.as_js_literal_member_name()?.value().ok()?,
+                x.pattern()
+                    .ok()?
+                    .as_any_js_assignment()?
+                    .as_js_identifier_assignment()?
+                    .name_token()
+                    .ok()?,
+            ),
         };
-        (old_name.text_trimmed() == new_name.text_trimmed()).then_some(())
+        // `"foo"` and `foo` are the same name
+        (inner_string_text(&old_name) == inner_string_text(&new_name)).then_some(())
     }
 
     fn diagnostic(ctx: &RuleContext<Self>, _: &Self::State) -> Option<RuleDiagnostic> {
@@ -156,6 +176,22 @@ impl Rule for NoUselessRename {
                     replacing_builder.build().into(),
                 );
             }
+            JsRenaming::JsObjectAssignmentPatternProperty(x) => {
+                let mut replacing_builder = make::js_object_assignment_pattern_shorthand_property(
+                    x.pattern()
+                        .ok()?
+                        .as_any_js_assignment()?
+                        .as_js_identifier_assignment()?
+                        .clone(),
+                );
+                if let Some(init) = x.init() {
+                    replacing_builder = replacing_builder.with_init(init);
+                }
+                mutation.replace_node(
+                    AnyJsObjectAssignmentPatternMember::from(x.clone()),
+                    replacing_builder.build().into(),
+                );
+            }
         }
         Some(JsRuleAction {
             category: ActionCategory::QuickFix,
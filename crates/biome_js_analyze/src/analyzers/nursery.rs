@@ -2,35 +2,79 @@
 
 use biome_analyze::declare_group;
 
+pub(crate) mod no_absolute_import_path;
 pub(crate) mod no_approximative_numeric_constant;
+pub(crate) mod no_conditional_assignment;
+pub(crate) mod no_double_slash_comments;
 pub(crate) mod no_empty_block_statements;
 pub(crate) mod no_empty_character_class_in_regex;
+pub(crate) mod no_enum_member_value_overlap;
+pub(crate) mod no_excessive_property_access;
+pub(crate) mod no_excessive_switch_cases;
+pub(crate) mod no_expression_in_strict_equality;
 pub(crate) mod no_misleading_instantiator;
 pub(crate) mod no_misrefactored_shorthand_assign;
+pub(crate) mod no_mixed_import_style;
+pub(crate) mod no_prettier_ignore;
+pub(crate) mod no_re_export_all;
+pub(crate) mod no_standalone_blocks;
+pub(crate) mod no_string_boolean_parameter;
+pub(crate) mod no_string_literal_type;
+pub(crate) mod no_unsafe_regex;
+pub(crate) mod no_unterminated_multiline_comment;
+pub(crate) mod no_unused_private_class_members;
 pub(crate) mod no_useless_else;
+pub(crate) mod no_useless_format_suppression_region;
 pub(crate) mod no_useless_lone_block_statements;
 pub(crate) mod use_arrow_function;
 pub(crate) mod use_as_const_assertion;
+pub(crate) mod use_consistent_empty_line_between_class_members;
+pub(crate) mod use_explicit_return_type;
 pub(crate) mod use_grouped_type_import;
 pub(crate) mod use_import_restrictions;
+pub(crate) mod use_nullish_coalescing_assignment;
+pub(crate) mod use_object_shorthand;
 pub(crate) mod use_shorthand_assign;
+pub(crate) mod use_task_destructuring;
 
 declare_group! {
     pub (crate) Nursery {
         name : "nursery" ,
         rules : [
+            self :: no_absolute_import_path :: NoAbsoluteImportPath ,
             self :: no_approximative_numeric_constant :: NoApproximativeNumericConstant ,
+            self :: no_conditional_assignment :: NoConditionalAssignment ,
+            self :: no_double_slash_comments :: NoDoubleSlashComments ,
             self :: no_empty_block_statements :: NoEmptyBlockStatements ,
             self :: no_empty_character_class_in_regex :: NoEmptyCharacterClassInRegex ,
+            self :: no_enum_member_value_overlap :: NoEnumMemberValueOverlap ,
+            self :: no_excessive_property_access :: NoExcessivePropertyAccess ,
+            self :: no_excessive_switch_cases :: NoExcessiveSwitchCases ,
+            self :: no_expression_in_strict_equality :: NoExpressionInStrictEquality ,
             self :: no_misleading_instantiator :: NoMisleadingInstantiator ,
             self :: no_misrefactored_shorthand_assign :: NoMisrefactoredShorthandAssign ,
+            self :: no_mixed_import_style :: NoMixedImportStyle ,
+            self :: no_prettier_ignore :: NoPrettierIgnore ,
+            self :: no_re_export_all :: NoReExportAll ,
+            self :: no_standalone_blocks :: NoStandaloneBlocks ,
+            self :: no_string_boolean_parameter :: NoStringBooleanParameter ,
+            self :: no_string_literal_type :: NoStringLiteralType ,
+            self :: no_unsafe_regex :: NoUnsafeRegex ,
+            self :: no_unterminated_multiline_comment :: NoUnterminatedMultilineComment ,
+            self :: no_unused_private_class_members :: NoUnusedPrivateClassMembers ,
             self :: no_useless_else :: NoUselessElse ,
+            self :: no_useless_format_suppression_region :: NoUselessFormatSuppressionRegion ,
             self :: no_useless_lone_block_statements :: NoUselessLoneBlockStatements ,
             self :: use_arrow_function :: UseArrowFunction ,
             self :: use_as_const_assertion :: UseAsConstAssertion ,
+            self :: use_consistent_empty_line_between_class_members :: UseConsistentEmptyLineBetweenClassMembers ,
+            self :: use_explicit_return_type :: UseExplicitReturnType ,
             self :: use_grouped_type_import :: UseGroupedTypeImport ,
             self :: use_import_restrictions :: UseImportRestrictions ,
+            self :: use_nullish_coalescing_assignment :: UseNullishCoalescingAssignment ,
+            self :: use_object_shorthand :: UseObjectShorthand ,
             self :: use_shorthand_assign :: UseShorthandAssign ,
+            self :: use_task_destructuring :: UseTaskDestructuring ,
         ]
      }
 }
@@ -0,0 +1,174 @@
+use biome_analyze::{context::RuleContext, declare_rule, Ast, Rule, RuleDiagnostic};
+use biome_console::markup;
+use biome_deserialize::json::{has_only_known_keys, VisitJsonNode};
+use biome_deserialize::{DeserializationDiagnostic, VisitNode};
+use biome_js_syntax::JsModuleSource;
+use biome_json_syntax::JsonLanguage;
+use biome_rowan::{AstNode, SyntaxNode};
+use bpaf::Bpaf;
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+declare_rule! {
+    /// Disallow absolute file system paths in import and export sources.
+    ///
+    /// A path such as `/Users/alice/projects/foo` or `C:\projects\bar` only exists on the machine
+    /// where the code was written. The import breaks as soon as the project is moved or cloned elsewhere.
+    ///
+    /// The rule reports sources that start with `/`, and sources that start with a Windows drive letter such as `C:\` or `C:/`.
+    /// Protocol-relative URLs such as `//cdn.example.com/lib.js` are ignored.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,expect_diagnostic
+    /// import foo from "/Users/alice/projects/foo";
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// import bar from "C:\\projects\\bar";
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// export * from "/home/bob/lib";
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// import foo from "./foo";
+    /// import bar from "../lib/bar";
+    /// import baz from "baz";
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// Some frameworks map an absolute path, such as `/app`, to the root of the project.
+    /// The option `allowedPrefixes` allows sources that are equal to one of the given prefixes, or that start with one of them followed by `/`.
+    ///
+    /// ```json
+    /// {
+    ///     "//": "...",
+    ///     "options": {
+    ///         "allowedPrefixes": ["/app"]
+    ///     }
+    /// }
+    /// ```
+    ///
+    pub(crate) NoAbsoluteImportPath {
+        version: "next",
+        name: "noAbsoluteImportPath",
+        recommended: false,
+    }
+}
+
+impl Rule for NoAbsoluteImportPath {
+    type Query = Ast<JsModuleSource>;
+    type State = ();
+    type Signals = Option<Self::State>;
+    type Options = AbsoluteImportPathOptions;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let source = ctx.query().inner_string_text().ok()?;
+        let source = source.text();
+        if !is_absolute_path(source) {
+            return None;
+        }
+        let is_allowed = ctx
+            .options()
+            .allowed_prefixes
+            .iter()
+            .flatten()
+            .any(|prefix| has_path_prefix(source, prefix));
+        (!is_allowed).then_some(())
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, _: &Self::State) -> Option<RuleDiagnostic> {
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                ctx.query().range(),
+                markup! {
+                    "Avoid absolute paths in import sources."
+                },
+            )
+            .note(markup! {
+                "Absolute paths depend on the file system of the machine, and break when the project is moved to another location."
+            })
+            .note(markup! {
+                "Convert this path to a path relative to the current file."
+            }),
+        )
+    }
+}
+
+/// Returns `true` if `path` is a Unix absolute path or starts with a Windows drive letter.
+fn is_absolute_path(path: &str) -> bool {
+    match path.as_bytes() {
+        [b'/', b'/', ..] => false,
+        [b'/', ..] => true,
+        [drive, b':', b'\\' | b'/', ..] => drive.is_ascii_alphabetic(),
+        _ => false,
+    }
+}
+
+/// Returns `true` if `path` is equal to `prefix`, or continues it with a new path segment.
+fn has_path_prefix(path: &str, prefix: &str) -> bool {
+    match path.strip_prefix(prefix) {
+        Some(rest) => {
+            rest.is_empty() || rest.starts_with(['/', '\\']) || prefix.ends_with(['/', '\\'])
+        }
+        None => false,
+    }
+}
+
+/// Options for the rule `noAbsoluteImportPath`.
+#[derive(Default, Deserialize, Serialize, Eq, PartialEq, Debug, Clone, Bpaf)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct AbsoluteImportPathOptions {
+    /// A list of absolute paths that are allowed, along with the paths they contain
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[bpaf(hide, argument::<String>("PREFIX"), many, optional)]
+    allowed_prefixes: Option<Vec<String>>,
+}
+
+impl AbsoluteImportPathOptions {
+    pub(crate) const KNOWN_KEYS: &'static [&'static str] = &["allowedPrefixes"];
+}
+
+// Required by [Bpaf].
+impl FromStr for AbsoluteImportPathOptions {
+    type Err = &'static str;
+
+    fn from_str(_s: &str) -> Result<Self, Self::Err> {
+        // WARNING: should not be used.
+        Ok(Self::default())
+    }
+}
+
+impl VisitNode<JsonLanguage> for AbsoluteImportPathOptions {
+    fn visit_member_name(
+        &mut self,
+        node: &SyntaxNode<JsonLanguage>,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<()> {
+        has_only_known_keys(node, Self::KNOWN_KEYS, diagnostics)
+    }
+
+    fn visit_map(
+        &mut self,
+        key: &SyntaxNode<JsonLanguage>,
+        value: &SyntaxNode<JsonLanguage>,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<()> {
+        let (name, value) = self.get_key_and_value(key, value, diagnostics)?;
+        let name_text = name.text();
+        if name_text == "allowedPrefixes" {
+            self.allowed_prefixes = self.map_to_array_of_strings(&value, name_text, diagnostics);
+        }
+
+        Some(())
+    }
+}
@@ -0,0 +1,103 @@
+use biome_analyze::context::RuleContext;
+use biome_analyze::{declare_rule, Ast, Rule, RuleDiagnostic};
+use biome_console::markup;
+use biome_js_syntax::{
+    AnyJsExpression, JsAssignmentExpression, JsAssignmentOperator, JsDoWhileStatement,
+    JsForStatement, JsIfStatement, JsSyntaxNode, JsWhileStatement,
+};
+use biome_rowan::{AstNode, SyntaxResult};
+
+declare_rule! {
+    /// Disallow assignments in the conditions of `if`, `while`, `do...while`, and `for` statements.
+    ///
+    /// `if (x = foo())` is most of the time a typo for the comparison `if (x === foo())`.
+    ///
+    /// An assignment wrapped in an extra pair of parentheses, such as `if ((x = foo()))`,
+    /// is considered intentional and is allowed.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,expect_diagnostic
+    /// if (x = foo()) {
+    /// }
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// while (node = node.parent) {
+    /// }
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// for (let i = 0; i = 10; i++) {
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// if (x === foo()) {
+    /// }
+    /// ```
+    ///
+    /// ```js
+    /// while ((node = node.parent)) {
+    /// }
+    /// ```
+    ///
+    pub(crate) NoConditionalAssignment {
+        version: "next",
+        name: "noConditionalAssignment",
+        recommended: false,
+    }
+}
+
+impl Rule for NoConditionalAssignment {
+    type Query = Ast<JsAssignmentExpression>;
+    type State = &'static str;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let assignment = ctx.query();
+        let parent = assignment.syntax().parent()?;
+        // In `if ((x = foo()))`, the parent of the assignment is a parenthesized expression
+        // and not the statement, so the assignment isn't reported.
+        let (statement, test) = condition_of(&parent)?;
+        (test.ok()?.syntax() == assignment.syntax()).then_some(statement)
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, statement: &Self::State) -> Option<RuleDiagnostic> {
+        let assignment = ctx.query();
+        let mut diagnostic = RuleDiagnostic::new(
+            rule_category!(),
+            assignment.range(),
+            markup! {
+                "Unexpected assignment in the condition of this "<Emphasis>{statement}</Emphasis>" statement."
+            },
+        );
+        if assignment.operator().ok()? == JsAssignmentOperator::Assign {
+            diagnostic = diagnostic.note(markup! {
+                "Did you mean to compare the values with "<Emphasis>"==="</Emphasis>"?"
+            });
+        }
+        Some(diagnostic.note(markup! {
+            "If the assignment is intentional, wrap it in parentheses, such as "<Emphasis>"if ((x = foo()))"</Emphasis>"."
+        }))
+    }
+}
+
+/// Returns the keyword and the condition of `node` if it's an `if`, `while`, `do...while`, or `for` statement.
+fn condition_of(node: &JsSyntaxNode) -> Option<(&'static str, SyntaxResult<AnyJsExpression>)> {
+    if let Some(statement) = JsIfStatement::cast_ref(node) {
+        Some(("if", statement.test()))
+    } else if let Some(statement) = JsWhileStatement::cast_ref(node) {
+        Some(("while", statement.test()))
+    } else if let Some(statement) = JsDoWhileStatement::cast_ref(node) {
+        Some(("do...while", statement.test()))
+    } else {
+        let statement = JsForStatement::cast_ref(node)?;
+        Some(("for", Ok(statement.test()?)))
+    }
+}
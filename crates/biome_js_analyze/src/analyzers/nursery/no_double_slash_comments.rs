@@ -0,0 +1,274 @@
+use crate::JsRuleAction;
+use biome_analyze::{
+    context::RuleContext, declare_rule, ActionCategory, Ast, FixKind, Rule, RuleDiagnostic,
+};
+use biome_console::markup;
+use biome_diagnostics::Applicability;
+use biome_js_syntax::{
+    JsClassDeclaration, JsClassExportDefaultDeclaration, JsExport,
+    JsExportDefaultDeclarationClause, JsFileSource, JsFunctionDeclaration,
+    JsFunctionExportDefaultDeclaration, JsSyntaxNode, JsSyntaxToken, JsVariableDeclarationClause,
+    JsVariableStatement, TextRange, TriviaPieceKind, TsEnumDeclaration, TsInterfaceDeclaration,
+    TsTypeAliasDeclaration,
+};
+use biome_rowan::{declare_node_union, AstNode, BatchMutationExt};
+
+declare_rule! {
+    /// Enforce JSDoc comments on declarations instead of `//` comments.
+    ///
+    /// Editors show the `/** */` comment that precedes a declaration when hovering the declaration
+    /// or one of its uses, while they ignore `//` comments.
+    ///
+    /// The rule reports the `//` comments that immediately precede a function, a class, an interface,
+    /// a type alias, an enum, or a `const` declaration.
+    /// Comments separated from the declaration by an empty line are ignored,
+    /// as well as directives such as `// @ts-expect-error` or `// biome-ignore`,
+    /// and the declarations that already have a JSDoc comment.
+    ///
+    /// The rule only applies to TypeScript files.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```ts,expect_diagnostic
+    /// // Returns the sum of `a` and `b`.
+    /// function add(a: number, b: number): number {
+    ///     return a + b;
+    /// }
+    /// ```
+    ///
+    /// ```ts,expect_diagnostic
+    /// // The user of the session.
+    /// // `undefined` until the user logs in.
+    /// export interface User {
+    ///     name: string;
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```ts
+    /// /** Returns the sum of `a` and `b`. */
+    /// function add(a: number, b: number): number {
+    ///     return a + b;
+    /// }
+    /// ```
+    ///
+    /// ```ts
+    /// // Section: users
+    ///
+    /// interface User {
+    ///     name: string;
+    /// }
+    /// ```
+    ///
+    pub(crate) NoDoubleSlashComments {
+        version: "next",
+        name: "noDoubleSlashComments",
+        recommended: false,
+        fix_kind: FixKind::Unsafe,
+    }
+}
+
+declare_node_union! {
+    pub(crate) AnyDocumentableDeclaration =
+        JsFunctionDeclaration
+        | JsFunctionExportDefaultDeclaration
+        | JsClassDeclaration
+        | JsClassExportDefaultDeclaration
+        | TsInterfaceDeclaration
+        | TsTypeAliasDeclaration
+        | TsEnumDeclaration
+        | JsVariableStatement
+        | JsVariableDeclarationClause
+}
+
+/// The `//` comments that precede a declaration
+pub(crate) struct DoubleSlashComments {
+    /// The first token of the declaration, that holds the comments in its leading trivia
+    token: JsSyntaxToken,
+    /// Index of the first comment in the leading trivia
+    first: usize,
+    /// Index of the last comment in the leading trivia
+    last: usize,
+    range: TextRange,
+}
+
+impl Rule for NoDoubleSlashComments {
+    type Query = Ast<AnyDocumentableDeclaration>;
+    type State = DoubleSlashComments;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        if !ctx.source_type::<JsFileSource>().language().is_typescript() {
+            return None;
+        }
+        let declaration = ctx.query();
+        let is_const = match declaration {
+            AnyDocumentableDeclaration::JsVariableStatement(statement) => {
+                statement.declaration().ok()?.is_const()
+            }
+            AnyDocumentableDeclaration::JsVariableDeclarationClause(clause) => {
+                clause.declaration().ok()?.is_const()
+            }
+            _ => true,
+        };
+        if !is_const {
+            return None;
+        }
+
+        let token = outermost_export(declaration.syntax()).first_token()?;
+        let pieces: Vec<_> = token.leading_trivia().pieces().collect();
+        if pieces
+            .iter()
+            .any(|piece| piece.is_comments() && piece.text().starts_with("/**"))
+        {
+            return None;
+        }
+
+        // Collect the `//` comments on the lines that directly precede the token
+        let mut comments = None;
+        let mut newlines = 0;
+        for (index, piece) in pieces.iter().enumerate().rev() {
+            if piece.is_newline() {
+                newlines += 1;
+            } else if piece.kind() == TriviaPieceKind::SingleLineComment
+                && piece.text().starts_with("//")
+                && newlines == 1
+                && !is_directive(piece.text())
+            {
+                comments = match comments {
+                    Some((_, last)) => Some((index, last)),
+                    None => Some((index, index)),
+                };
+                newlines = 0;
+                continue;
+            } else if !piece.is_whitespace() {
+                break;
+            }
+            if newlines > 1 {
+                break;
+            }
+        }
+        let (first, last) = comments?;
+        let range = pieces[first].text_range().cover(pieces[last].text_range());
+        Some(DoubleSlashComments {
+            token,
+            first,
+            last,
+            range,
+        })
+    }
+
+    fn diagnostic(_: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                state.range,
+                markup! {
+                    "This comment documents the declaration, but it isn't a JSDoc comment."
+                },
+            )
+            .note(markup! {
+                "Editors only show the "<Emphasis>"/** */"</Emphasis>" comments when hovering a declaration."
+            }),
+        )
+    }
+
+    fn action(ctx: &RuleContext<Self>, state: &Self::State) -> Option<JsRuleAction> {
+        let DoubleSlashComments {
+            token, first, last, ..
+        } = state;
+        let pieces: Vec<_> = token.leading_trivia().pieces().collect();
+        let lines: Vec<&str> = pieces[*first..=*last]
+            .iter()
+            .filter(|piece| piece.is_comments())
+            .map(|piece| {
+                let text = piece.text().trim_start_matches("//");
+                text.strip_prefix(' ').unwrap_or(text).trim_end()
+            })
+            .collect();
+        // The text can't end the JSDoc comment early
+        if lines.iter().any(|line| line.contains("*/")) {
+            return None;
+        }
+        let indentation = first
+            .checked_sub(1)
+            .map(|index| &pieces[index])
+            .filter(|piece| piece.is_whitespace())
+            .map_or("", |piece| piece.text());
+        let doc_comment = to_doc_comment(&lines, indentation);
+
+        let new_pieces: Vec<(TriviaPieceKind, &str)> = pieces[..*first]
+            .iter()
+            .map(|piece| (piece.kind(), piece.text()))
+            .chain([(TriviaPieceKind::MultiLineComment, doc_comment.as_str())])
+            .chain(
+                pieces[*last + 1..]
+                    .iter()
+                    .map(|piece| (piece.kind(), piece.text())),
+            )
+            .collect();
+        let new_token = token.with_leading_trivia(new_pieces);
+        let mut mutation = ctx.root().begin();
+        mutation.replace_token_discard_trivia(token.clone(), new_token);
+        Some(JsRuleAction {
+            category: ActionCategory::QuickFix,
+            applicability: Applicability::MaybeIncorrect,
+            message: markup! {
+                "Convert the comment to a JSDoc comment."
+            }
+            .to_owned(),
+            mutation,
+        })
+    }
+}
+
+/// Returns the `export` statement that contains the declaration `node`, or `node` itself.
+fn outermost_export(node: &JsSyntaxNode) -> JsSyntaxNode {
+    let mut node = node.clone();
+    while let Some(parent) = node.parent().filter(|parent| {
+        JsExport::can_cast(parent.kind())
+            || JsExportDefaultDeclarationClause::can_cast(parent.kind())
+    }) {
+        node = parent;
+    }
+    node
+}
+
+/// Returns `true` if the `//` comment is a directive for a tool, rather than a description.
+fn is_directive(comment: &str) -> bool {
+    let text = comment.trim_start_matches("//").trim_start();
+    // `/// <reference path="..." />` and `//#region`
+    comment.starts_with("///")
+        || comment.starts_with("//#")
+        || [
+            "@ts-",
+            "biome-ignore",
+            "eslint-",
+            "prettier-ignore",
+            "#region",
+            "#endregion",
+        ]
+        .iter()
+        .any(|prefix| text.starts_with(prefix))
+}
+
+/// Returns a JSDoc comment with the `lines`, indented with `indentation`.
+fn to_doc_comment(lines: &[&str], indentation: &str) -> String {
+    if let [line] = lines {
+        return format!("/** {line} */");
+    }
+    let mut doc_comment = String::from("/**\n");
+    for line in lines {
+        if line.is_empty() {
+            doc_comment.push_str(&format!("{indentation} *\n"));
+        } else {
+            doc_comment.push_str(&format!("{indentation} * {line}\n"));
+        }
+    }
+    doc_comment.push_str(&format!("{indentation} */"));
+    doc_comment
+}
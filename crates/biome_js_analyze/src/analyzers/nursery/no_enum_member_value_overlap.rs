@@ -0,0 +1,286 @@
+use biome_analyze::context::RuleContext;
+use biome_analyze::{declare_rule, Ast, Rule, RuleDiagnostic};
+use biome_console::markup;
+use biome_deserialize::json::{has_only_known_keys, VisitJsonNode};
+use biome_deserialize::{DeserializationDiagnostic, VisitNode};
+use biome_js_syntax::numbers::parse_js_number;
+use biome_js_syntax::static_value::StaticValue;
+use biome_js_syntax::{
+    AnyJsExpression, JsBinaryOperator, JsUnaryOperator, TsEnumDeclaration, TsEnumMember,
+};
+use biome_json_syntax::JsonLanguage;
+use biome_rowan::{AstNode, SyntaxNode};
+use bpaf::Bpaf;
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+declare_rule! {
+    /// Disallow enum members that resolve to the same value.
+    ///
+    /// _TypeScript_ accepts several members of an enum sharing a value.
+    /// For numeric enums, the reverse mapping then resolves to the last declared member:
+    /// in `enum Foo { A = 1, B = 1 }`, `Foo[1]` is `"B"` and `Foo.A === Foo.B` is `true`.
+    /// This is rarely intended and is usually the result of a copy-paste mistake.
+    ///
+    /// The rule evaluates numeric literals, string literals, and implicitly initialized members
+    /// that follow a numeric member (auto-increment).
+    /// Members with other initializers are ignored.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```ts,expect_diagnostic
+    /// enum Status {
+    ///     Open = 1,
+    ///     Closed = 1,
+    /// }
+    /// ```
+    ///
+    /// ```ts,expect_diagnostic
+    /// enum Direction {
+    ///     Up = 1,
+    ///     Down = 0,
+    ///     Left,
+    /// }
+    /// ```
+    ///
+    /// ```ts,expect_diagnostic
+    /// enum Color {
+    ///     Red = "red",
+    ///     Crimson = "red",
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```ts
+    /// enum Status {
+    ///     Open = 1,
+    ///     Closed = 2,
+    /// }
+    /// ```
+    ///
+    /// ```ts
+    /// enum Direction {
+    ///     Up,
+    ///     Down,
+    ///     Left,
+    /// }
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// Enums used as bit flags sometimes declare aliases on purpose.
+    /// The option `allowBitFlags` ignores enums where every member resolves to `0` or to a power of two.
+    ///
+    /// ```json
+    /// {
+    ///     "//": "...",
+    ///     "options": {
+    ///         "allowBitFlags": true
+    ///     }
+    /// }
+    /// ```
+    ///
+    pub(crate) NoEnumMemberValueOverlap {
+        version: "next",
+        name: "noEnumMemberValueOverlap",
+        recommended: false,
+    }
+}
+
+impl Rule for NoEnumMemberValueOverlap {
+    type Query = Ast<TsEnumDeclaration>;
+    type State = EnumMemberOverlap;
+    type Signals = Vec<Self::State>;
+    type Options = EnumMemberValueOverlapOptions;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let enum_declaration = ctx.query();
+        let mut members: Vec<(TsEnumMember, Option<EnumMemberValue>)> = Vec::new();
+        let mut next_value = Some(0.0);
+        for member in enum_declaration.members() {
+            let Ok(member) = member else {
+                return Vec::new();
+            };
+            let value = match member.initializer() {
+                Some(initializer) => initializer
+                    .expression()
+                    .ok()
+                    .and_then(|expression| evaluate_expression(&expression)),
+                None => next_value.map(EnumMemberValue::Number),
+            };
+            next_value = match &value {
+                Some(EnumMemberValue::Number(n)) => Some(n + 1.0),
+                _ => None,
+            };
+            members.push((member, value));
+        }
+
+        if ctx.options().allow_bit_flags && is_bit_flag_enum(&members) {
+            return Vec::new();
+        }
+
+        let mut overlaps = Vec::new();
+        for (index, (member, value)) in members.iter().enumerate() {
+            let Some(value) = value else {
+                continue;
+            };
+            let first = members[..index]
+                .iter()
+                .find(|(_, other)| other.as_ref() == Some(value));
+            if let Some((first, _)) = first {
+                overlaps.push(EnumMemberOverlap {
+                    member: member.clone(),
+                    first: first.clone(),
+                });
+            }
+        }
+        overlaps
+    }
+
+    fn diagnostic(_: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let EnumMemberOverlap { member, first } = state;
+        let member_name = member.name().ok()?;
+        let member_name = member_name.syntax().text_trimmed();
+        let first_name = first.name().ok()?;
+        let first_name = first_name.syntax().text_trimmed();
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                member.range(),
+                markup! {
+                    "The enum member "<Emphasis>{member_name.to_string()}</Emphasis>" has the same value as "<Emphasis>{first_name.to_string()}</Emphasis>"."
+                },
+            )
+            .detail(
+                first.range(),
+                markup! {
+                    "The value is first used here."
+                },
+            )
+            .note(markup! {
+                "Reverse lookups and comparisons can no longer distinguish these members."
+            }),
+        )
+    }
+}
+
+pub(crate) struct EnumMemberOverlap {
+    /// The member that reuses a value.
+    member: TsEnumMember,
+    /// The first member that resolved to the same value.
+    first: TsEnumMember,
+}
+
+#[derive(Debug, PartialEq)]
+enum EnumMemberValue {
+    Number(f64),
+    String(String),
+}
+
+/// Evaluates the constant initializer of an enum member.
+///
+/// Returns `None` when the value cannot be computed without type information.
+fn evaluate_expression(expression: &AnyJsExpression) -> Option<EnumMemberValue> {
+    match expression.clone().omit_parentheses() {
+        AnyJsExpression::JsUnaryExpression(unary) => {
+            let argument = evaluate_number(&unary.argument().ok()?)?;
+            match unary.operator().ok()? {
+                JsUnaryOperator::Minus => Some(EnumMemberValue::Number(-argument)),
+                JsUnaryOperator::Plus => Some(EnumMemberValue::Number(argument)),
+                _ => None,
+            }
+        }
+        AnyJsExpression::JsBinaryExpression(binary) => {
+            if binary.operator().ok()? != JsBinaryOperator::LeftShift {
+                return None;
+            }
+            let left = evaluate_number(&binary.left().ok()?)?;
+            let right = evaluate_number(&binary.right().ok()?)?;
+            let shifted = (left as i32).checked_shl(right as u32 & 31)?;
+            Some(EnumMemberValue::Number(f64::from(shifted)))
+        }
+        expression => match expression.as_static_value()? {
+            StaticValue::Number(token) => {
+                parse_js_number(token.text_trimmed()).map(EnumMemberValue::Number)
+            }
+            value @ (StaticValue::String(_) | StaticValue::EmptyString(_)) => {
+                Some(EnumMemberValue::String(value.text().to_string()))
+            }
+            _ => None,
+        },
+    }
+}
+
+fn evaluate_number(expression: &AnyJsExpression) -> Option<f64> {
+    match evaluate_expression(expression)? {
+        EnumMemberValue::Number(n) => Some(n),
+        EnumMemberValue::String(_) => None,
+    }
+}
+
+/// Returns `true` if every member of the enum resolves to `0` or to a power of two.
+fn is_bit_flag_enum(members: &[(TsEnumMember, Option<EnumMemberValue>)]) -> bool {
+    members.iter().all(|(_, value)| match value {
+        Some(EnumMemberValue::Number(n)) => {
+            n.fract() == 0.0 && (*n == 0.0 || (*n > 0.0 && (*n as u64).is_power_of_two()))
+        }
+        _ => false,
+    })
+}
+
+/// Options for the rule `noEnumMemberValueOverlap`.
+#[derive(Default, Deserialize, Serialize, Eq, PartialEq, Debug, Clone, Bpaf)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct EnumMemberValueOverlapOptions {
+    /// If `true`, enums whose members are all `0` or powers of two are ignored.
+    #[bpaf(hide)]
+    #[serde(default, skip_serializing_if = "is_false")]
+    pub allow_bit_flags: bool,
+}
+
+const fn is_false(value: &bool) -> bool {
+    !*value
+}
+
+impl EnumMemberValueOverlapOptions {
+    pub(crate) const KNOWN_KEYS: &'static [&'static str] = &["allowBitFlags"];
+}
+
+// Required by [Bpaf].
+impl FromStr for EnumMemberValueOverlapOptions {
+    type Err = &'static str;
+
+    fn from_str(_s: &str) -> Result<Self, Self::Err> {
+        // WARNING: should not be used.
+        Ok(Self::default())
+    }
+}
+
+impl VisitNode<JsonLanguage> for EnumMemberValueOverlapOptions {
+    fn visit_member_name(
+        &mut self,
+        node: &SyntaxNode<JsonLanguage>,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<()> {
+        has_only_known_keys(node, Self::KNOWN_KEYS, diagnostics)
+    }
+
+    fn visit_map(
+        &mut self,
+        key: &SyntaxNode<JsonLanguage>,
+        value: &SyntaxNode<JsonLanguage>,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<()> {
+        let (name, value) = self.get_key_and_value(key, value, diagnostics)?;
+        let name_text = name.text();
+        if name_text == "allowBitFlags" {
+            self.allow_bit_flags = self.map_to_boolean(&value, name_text, diagnostics)?;
+        }
+
+        Some(())
+    }
+}
@@ -0,0 +1,203 @@
+use biome_analyze::context::RuleContext;
+use biome_analyze::{declare_rule, Ast, Rule, RuleDiagnostic};
+use biome_console::markup;
+use biome_deserialize::json::{has_only_known_keys, VisitJsonNode};
+use biome_deserialize::{DeserializationDiagnostic, VisitNode};
+use biome_js_syntax::{AnyJsExpression, AnyJsMemberExpression};
+use biome_json_syntax::JsonLanguage;
+use biome_rowan::{AstNode, SyntaxNode};
+use bpaf::Bpaf;
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+declare_rule! {
+    /// Disallow deeply chained property accesses.
+    ///
+    /// A long chain of property accesses such as `a.b.c.d.e.f` throws at runtime as soon as
+    /// one of the intermediate values is `null` or `undefined`.
+    /// It also couples the code to the internal structure of the accessed object.
+    ///
+    /// The rule reports chains with more than `maxDepth` consecutive property accesses.
+    /// A chain that contains an optional chaining operator (`?.`) is ignored,
+    /// because it already guards against missing intermediate values.
+    ///
+    /// Property accesses on the result of a call start a new chain.
+    /// This allows fluent APIs such as builders, where every method returns `this`.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,expect_diagnostic
+    /// const city = response.data.user.profile.address.city;
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// const email = this.state.form.fields.email.value;
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// const city = response.data.user.address.city;
+    /// ```
+    ///
+    /// ```js
+    /// const city = response?.data.user.profile.address.city;
+    /// ```
+    ///
+    /// ```js
+    /// query.select("a").from("b").where("c").orderBy("d").limit(1);
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// The maximum number of consecutive property accesses can be set with the option `maxDepth`.
+    /// It defaults to `4`.
+    ///
+    /// ```json
+    /// {
+    ///     "//": "...",
+    ///     "options": {
+    ///         "maxDepth": 6
+    ///     }
+    /// }
+    /// ```
+    ///
+    pub(crate) NoExcessivePropertyAccess {
+        version: "next",
+        name: "noExcessivePropertyAccess",
+        recommended: false,
+    }
+}
+
+impl Rule for NoExcessivePropertyAccess {
+    type Query = Ast<AnyJsMemberExpression>;
+    type State = usize;
+    type Signals = Option<Self::State>;
+    type Options = PropertyAccessDepthOptions;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let member_expression = ctx.query();
+        // Only the outermost member expression of a chain is checked
+        let is_object_of_parent = member_expression
+            .syntax()
+            .parent()
+            .and_then(AnyJsMemberExpression::cast)
+            .and_then(|parent| parent.object().ok())
+            .is_some_and(|object| object.syntax() == member_expression.syntax());
+        if is_object_of_parent {
+            return None;
+        }
+
+        let mut depth = 0;
+        let mut current = member_expression.clone();
+        loop {
+            if is_optional(&current) {
+                return None;
+            }
+            depth += 1;
+            match current.object().ok()? {
+                AnyJsExpression::JsStaticMemberExpression(object) => current = object.into(),
+                AnyJsExpression::JsComputedMemberExpression(object) => current = object.into(),
+                AnyJsExpression::JsCallExpression(call) if call.is_optional_chain() => {
+                    return None;
+                }
+                _ => break,
+            }
+        }
+
+        (depth > usize::from(ctx.options().max_depth)).then_some(depth)
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, depth: &Self::State) -> Option<RuleDiagnostic> {
+        let max_depth = ctx.options().max_depth;
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                ctx.query().range(),
+                markup! {
+                    "This chain accesses "{depth}" properties in a row, but the maximum allowed is "{max_depth}"."
+                },
+            )
+            .note(markup! {
+                "The access throws at runtime if any intermediate value is "<Emphasis>"null"</Emphasis>" or "<Emphasis>"undefined"</Emphasis>"."
+            })
+            .note(markup! {
+                "Consider storing intermediate values in variables, or using the optional chaining operator "<Emphasis>"?."</Emphasis>"."
+            }),
+        )
+    }
+}
+
+fn is_optional(member_expression: &AnyJsMemberExpression) -> bool {
+    match member_expression {
+        AnyJsMemberExpression::JsStaticMemberExpression(expression) => expression.is_optional(),
+        AnyJsMemberExpression::JsComputedMemberExpression(expression) => expression.is_optional(),
+    }
+}
+
+/// Options for the rule `noExcessivePropertyAccess`.
+#[derive(Deserialize, Serialize, Eq, PartialEq, Debug, Clone, Bpaf)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct PropertyAccessDepthOptions {
+    /// The maximum number of consecutive property accesses. Anything higher is reported.
+    pub max_depth: u8,
+}
+
+impl Default for PropertyAccessDepthOptions {
+    fn default() -> Self {
+        Self { max_depth: 4 }
+    }
+}
+
+impl PropertyAccessDepthOptions {
+    pub(crate) const KNOWN_KEYS: &'static [&'static str] = &["maxDepth"];
+}
+
+// Required by [Bpaf].
+impl FromStr for PropertyAccessDepthOptions {
+    type Err = &'static str;
+
+    fn from_str(_s: &str) -> Result<Self, Self::Err> {
+        // WARNING: should not be used.
+        Ok(Self::default())
+    }
+}
+
+impl VisitNode<JsonLanguage> for PropertyAccessDepthOptions {
+    fn visit_member_name(
+        &mut self,
+        node: &SyntaxNode<JsonLanguage>,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<()> {
+        has_only_known_keys(node, Self::KNOWN_KEYS, diagnostics)
+    }
+
+    fn visit_map(
+        &mut self,
+        key: &SyntaxNode<JsonLanguage>,
+        value: &SyntaxNode<JsonLanguage>,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<()> {
+        let (name, value) = self.get_key_and_value(key, value, diagnostics)?;
+        let name_text = name.text();
+        if name_text == "maxDepth" {
+            let max_depth = self.map_to_u8(&value, name_text, u8::MAX, diagnostics)?;
+            // Don't allow 0 or every property access would be reported.
+            if max_depth == 0 {
+                diagnostics.push(
+                    DeserializationDiagnostic::new(markup! {
+                        "The field "<Emphasis>"maxDepth"</Emphasis>" must be greater than 0"
+                    })
+                    .with_range(value.range()),
+                );
+                return None;
+            }
+            self.max_depth = max_depth;
+        }
+
+        Some(())
+    }
+}
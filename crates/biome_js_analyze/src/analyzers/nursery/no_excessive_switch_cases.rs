@@ -0,0 +1,193 @@
+use biome_analyze::context::RuleContext;
+use biome_analyze::{declare_rule, Ast, Rule, RuleDiagnostic};
+use biome_console::markup;
+use biome_deserialize::json::{has_only_known_keys, VisitJsonNode};
+use biome_deserialize::{DeserializationDiagnostic, VisitNode};
+use biome_js_syntax::{AnyJsSwitchClause, JsSwitchStatement};
+use biome_json_syntax::JsonLanguage;
+use biome_rowan::{AstNode, AstNodeList, SyntaxNode};
+use bpaf::Bpaf;
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+declare_rule! {
+    /// Disallow `switch` statements with too many cases.
+    ///
+    /// A `switch` statement with many cases is hard to read and to maintain.
+    /// It's often better replaced by a lookup table, such as an object or a `Map`,
+    /// or by polymorphism.
+    ///
+    /// The rule reports the `switch` statements with more than `maxCases` `case` clauses.
+    /// The `default` clause isn't counted.
+    /// By default, the empty cases that fall through to the next clause, such as `case "a":` in
+    /// `case "a": case "b": f();`, aren't counted either.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,expect_diagnostic
+    /// switch (key) {
+    ///     case 1: one(); break;
+    ///     case 2: two(); break;
+    ///     case 3: three(); break;
+    ///     case 4: four(); break;
+    ///     case 5: five(); break;
+    ///     case 6: six(); break;
+    ///     case 7: seven(); break;
+    ///     case 8: eight(); break;
+    ///     case 9: nine(); break;
+    ///     case 10: ten(); break;
+    ///     case 11: eleven(); break;
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// switch (key) {
+    ///     case 1: one(); break;
+    ///     case 2: two(); break;
+    ///     default: other();
+    /// }
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// The maximum number of cases can be set with the option `maxCases`. It defaults to `10`.
+    ///
+    /// The empty cases that fall through to the next clause are counted when the option
+    /// `countFallthroughs` is `true`. It defaults to `false`.
+    ///
+    /// ```json
+    /// {
+    ///     "//": "...",
+    ///     "options": {
+    ///         "maxCases": 20,
+    ///         "countFallthroughs": true
+    ///     }
+    /// }
+    /// ```
+    ///
+    pub(crate) NoExcessiveSwitchCases {
+        version: "next",
+        name: "noExcessiveSwitchCases",
+        recommended: false,
+    }
+}
+
+impl Rule for NoExcessiveSwitchCases {
+    type Query = Ast<JsSwitchStatement>;
+    type State = usize;
+    type Signals = Option<Self::State>;
+    type Options = SwitchCasesOptions;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let options = ctx.options();
+        let mut clauses = ctx.query().cases().into_iter().peekable();
+        let mut count = 0;
+        while let Some(clause) = clauses.next() {
+            let AnyJsSwitchClause::JsCaseClause(clause) = clause else {
+                continue;
+            };
+            let is_fallthrough = clause.consequent().is_empty() && clauses.peek().is_some();
+            if options.count_fallthroughs || !is_fallthrough {
+                count += 1;
+            }
+        }
+        (count > usize::from(options.max_cases)).then_some(count)
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, count: &Self::State) -> Option<RuleDiagnostic> {
+        let max_cases = ctx.options().max_cases;
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                ctx.query().switch_token().ok()?.text_trimmed_range(),
+                markup! {
+                    "This "<Emphasis>"switch"</Emphasis>" statement has "{count}" cases, but the maximum allowed is "{max_cases}"."
+                },
+            )
+            .note(markup! {
+                "Consider replacing it with a lookup table, such as an object or a "<Emphasis>"Map"</Emphasis>"."
+            }),
+        )
+    }
+}
+
+/// Options for the rule `noExcessiveSwitchCases`.
+#[derive(Deserialize, Serialize, Eq, PartialEq, Debug, Clone, Bpaf)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct SwitchCasesOptions {
+    /// The maximum number of cases of a `switch` statement. Anything higher is reported.
+    pub max_cases: u8,
+    /// Whether the empty cases that fall through to the next clause are counted.
+    #[serde(default)]
+    pub count_fallthroughs: bool,
+}
+
+impl Default for SwitchCasesOptions {
+    fn default() -> Self {
+        Self {
+            max_cases: 10,
+            count_fallthroughs: false,
+        }
+    }
+}
+
+impl SwitchCasesOptions {
+    pub(crate) const KNOWN_KEYS: &'static [&'static str] = &["maxCases", "countFallthroughs"];
+}
+
+// Required by [Bpaf].
+impl FromStr for SwitchCasesOptions {
+    type Err = &'static str;
+
+    fn from_str(_s: &str) -> Result<Self, Self::Err> {
+        // WARNING: should not be used.
+        Ok(Self::default())
+    }
+}
+
+impl VisitNode<JsonLanguage> for SwitchCasesOptions {
+    fn visit_member_name(
+        &mut self,
+        node: &SyntaxNode<JsonLanguage>,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<()> {
+        has_only_known_keys(node, Self::KNOWN_KEYS, diagnostics)
+    }
+
+    fn visit_map(
+        &mut self,
+        key: &SyntaxNode<JsonLanguage>,
+        value: &SyntaxNode<JsonLanguage>,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<()> {
+        let (name, value) = self.get_key_and_value(key, value, diagnostics)?;
+        let name_text = name.text();
+        match name_text {
+            "maxCases" => {
+                let max_cases = self.map_to_u8(&value, name_text, u8::MAX, diagnostics)?;
+                // Don't allow 0 or every switch statement with a case would be reported.
+                if max_cases == 0 {
+                    diagnostics.push(
+                        DeserializationDiagnostic::new(markup! {
+                            "The field "<Emphasis>"maxCases"</Emphasis>" must be greater than 0"
+                        })
+                        .with_range(value.range()),
+                    );
+                    return None;
+                }
+                self.max_cases = max_cases;
+            }
+            "countFallthroughs" => {
+                self.count_fallthroughs = self.map_to_boolean(&value, name_text, diagnostics)?;
+            }
+            _ => {}
+        }
+
+        Some(())
+    }
+}
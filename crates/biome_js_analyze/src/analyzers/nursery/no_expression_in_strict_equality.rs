@@ -0,0 +1,205 @@
+use crate::JsRuleAction;
+use biome_analyze::context::RuleContext;
+use biome_analyze::{declare_rule, ActionCategory, Ast, FixKind, Rule, RuleDiagnostic};
+use biome_console::markup;
+use biome_diagnostics::Applicability;
+use biome_js_factory::make;
+use biome_js_syntax::{
+    AnyJsExpression, AnyJsLiteralExpression, JsBinaryExpression, JsBinaryOperator,
+    JsSyntaxKind::{EQ2, NEQ},
+    JsSyntaxToken, JsUnaryOperator, TextRange, T,
+};
+use biome_rowan::{AstNode, BatchMutationExt};
+
+declare_rule! {
+    /// Require `typeof` comparisons to use strict equality and a valid type name.
+    ///
+    /// The result of `typeof` is always a string, so comparing it with `==` or `!=` relies on type coercion
+    /// when the other operand isn't a string, e.g. `typeof foo == { toString() { return "undefined" } }` is `true`.
+    /// The rule requires `===` and `!==` in `typeof` comparisons.
+    ///
+    /// The result of `typeof` is also one of eight known strings:
+    /// `"bigint"`, `"boolean"`, `"function"`, `"number"`, `"object"`, `"string"`, `"symbol"`, and `"undefined"`.
+    /// A comparison with any other string literal, such as a typo like `"undefied"`, is always `false`.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,expect_diagnostic
+    /// typeof foo == "undefined";
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// typeof foo !== "undefied";
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// "String" === typeof foo;
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// typeof foo === "undefined";
+    /// ```
+    ///
+    /// ```js
+    /// typeof foo !== typeof bar;
+    /// ```
+    ///
+    pub(crate) NoExpressionInStrictEquality {
+        version: "next",
+        name: "noExpressionInStrictEquality",
+        recommended: false,
+        fix_kind: FixKind::Safe,
+    }
+}
+
+impl Rule for NoExpressionInStrictEquality {
+    type Query = Ast<JsBinaryExpression>;
+    type State = TypeofComparison;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let node = ctx.query();
+        if !matches!(
+            node.operator().ok()?,
+            JsBinaryOperator::Equality
+                | JsBinaryOperator::StrictEquality
+                | JsBinaryOperator::Inequality
+                | JsBinaryOperator::StrictInequality
+        ) {
+            return None;
+        }
+
+        let left = node.left().ok()?.omit_parentheses();
+        let right = node.right().ok()?.omit_parentheses();
+        let other = if is_typeof(&left) {
+            right
+        } else if is_typeof(&right) {
+            left
+        } else {
+            return None;
+        };
+
+        if let AnyJsExpression::AnyJsLiteralExpression(
+            AnyJsLiteralExpression::JsStringLiteralExpression(literal),
+        ) = &other
+        {
+            let type_name = literal.inner_string_text().ok()?;
+            if !TYPEOF_RESULTS.contains(&type_name.text()) {
+                return Some(TypeofComparison::InvalidTypeName(
+                    literal.range(),
+                    type_name.to_string(),
+                ));
+            }
+        }
+
+        let operator = node.operator_token().ok()?;
+        if matches!(operator.kind(), EQ2 | NEQ) {
+            return Some(TypeofComparison::LooseEquality(operator));
+        }
+
+        None
+    }
+
+    fn diagnostic(_ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        Some(match state {
+            TypeofComparison::LooseEquality(operator) => {
+                let operator_text = operator.text_trimmed();
+                let suggestion = if operator.kind() == EQ2 { "===" } else { "!==" };
+                RuleDiagnostic::new(
+                    rule_category!(),
+                    operator.text_trimmed_range(),
+                    markup! {
+                        "Use "<Emphasis>{suggestion}</Emphasis>" instead of "<Emphasis>{operator_text}</Emphasis>" to compare the result of "<Emphasis>"typeof"</Emphasis>"."
+                    },
+                )
+                .note(markup! {
+                    "A loose comparison relies on type coercion when the other operand isn't a string."
+                })
+            }
+            TypeofComparison::InvalidTypeName(range, type_name) => RuleDiagnostic::new(
+                rule_category!(),
+                range,
+                markup! {
+                    "The result of "<Emphasis>"typeof"</Emphasis>" is never "<Emphasis>"\""{type_name}"\""</Emphasis>", so this comparison always has the same result."
+                },
+            )
+            .note(markup! {
+                "The valid type names are "<Emphasis>"\"bigint\""</Emphasis>", "<Emphasis>"\"boolean\""</Emphasis>", "<Emphasis>"\"function\""</Emphasis>", "<Emphasis>"\"number\""</Emphasis>", "<Emphasis>"\"object\""</Emphasis>", "<Emphasis>"\"string\""</Emphasis>", "<Emphasis>"\"symbol\""</Emphasis>", and "<Emphasis>"\"undefined\""</Emphasis>"."
+            }),
+        })
+    }
+
+    fn action(ctx: &RuleContext<Self>, state: &Self::State) -> Option<JsRuleAction> {
+        let TypeofComparison::LooseEquality(operator) = state else {
+            return None;
+        };
+        let node = ctx.query();
+        let left = node.left().ok()?.omit_parentheses();
+        let right = node.right().ok()?.omit_parentheses();
+        // The strict comparison has the same result only when both operands are strings
+        if !(is_string_operand(&left) && is_string_operand(&right)) {
+            return None;
+        }
+
+        let suggestion = if operator.kind() == EQ2 {
+            T![===]
+        } else {
+            T![!==]
+        };
+        let mut mutation = ctx.root().begin();
+        mutation.replace_token(operator.clone(), make::token(suggestion));
+
+        Some(JsRuleAction {
+            category: ActionCategory::QuickFix,
+            applicability: Applicability::Always,
+            // SAFETY: `suggestion` is either `===` or `!==`, which always have a text
+            message:
+                markup! { "Use "<Emphasis>{suggestion.to_string().unwrap()}</Emphasis>" instead." }
+                    .to_owned(),
+            mutation,
+        })
+    }
+}
+
+pub(crate) enum TypeofComparison {
+    /// `typeof` is compared with `==` or `!=`
+    LooseEquality(JsSyntaxToken),
+    /// `typeof` is compared with a string literal that isn't a valid type name
+    InvalidTypeName(TextRange, String),
+}
+
+/// The strings that `typeof` can return
+const TYPEOF_RESULTS: [&str; 8] = [
+    "bigint",
+    "boolean",
+    "function",
+    "number",
+    "object",
+    "string",
+    "symbol",
+    "undefined",
+];
+
+fn is_typeof(expression: &AnyJsExpression) -> bool {
+    matches!(
+        expression,
+        AnyJsExpression::JsUnaryExpression(unary)
+            if matches!(unary.operator(), Ok(JsUnaryOperator::Typeof))
+    )
+}
+
+/// Returns `true` if `expression` is always a string
+fn is_string_operand(expression: &AnyJsExpression) -> bool {
+    is_typeof(expression)
+        || matches!(
+            expression,
+            AnyJsExpression::AnyJsLiteralExpression(
+                AnyJsLiteralExpression::JsStringLiteralExpression(_)
+            )
+        )
+}
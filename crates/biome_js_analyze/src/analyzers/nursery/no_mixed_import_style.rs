@@ -0,0 +1,340 @@
+use crate::JsRuleAction;
+use biome_analyze::{
+    context::RuleContext, declare_rule, ActionCategory, Ast, FixKind, Rule, RuleDiagnostic,
+};
+use biome_console::markup;
+use biome_deserialize::json::{has_only_known_keys, VisitJsonNode};
+use biome_deserialize::{DeserializationDiagnostic, VisitNode};
+use biome_diagnostics::Applicability;
+use biome_js_factory::make;
+use biome_js_syntax::{
+    AnyJsImportClause, AnyJsModuleItem, AnyJsNamedImport, AnyJsNamedImportSpecifier, JsImport,
+    JsImportNamedClause, JsModuleItemList, JsNamedImportSpecifierList, JsSyntaxToken,
+    TriviaPieceKind, T,
+};
+use biome_json_syntax::JsonLanguage;
+use biome_rowan::{AstNode, AstNodeList, AstSeparatedList, BatchMutationExt, SyntaxNode};
+use bpaf::Bpaf;
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+declare_rule! {
+    /// Disallow mixing type imports and value imports in the same `import` statement.
+    ///
+    /// An `import` statement that imports both types and values, using inline `type` qualifiers,
+    /// makes it harder to see at a glance which imports are erased at compile time.
+    /// This rule requires an `import` to import either only types or only values.
+    ///
+    /// An `import` that imports only types with inline `type` qualifiers is reported by [useGroupedTypeImport](https://biomejs.dev/linter/rules/use-grouped-type-import) instead.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```ts,expect_diagnostic
+    /// import { A, type B } from "mod";
+    /// ```
+    ///
+    /// ```ts,expect_diagnostic
+    /// import A, { type B } from "mod";
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```ts
+    /// import type { B } from "mod";
+    /// import { A } from "mod";
+    /// ```
+    ///
+    /// ```ts
+    /// import A, { B } from "mod";
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// The code fix splits the `import` into an `import type` and a value `import`.
+    /// By default, the `import type` is placed first.
+    /// Set the option `typeFirst` to `false` to place it after the value `import`.
+    ///
+    /// ```json
+    /// {
+    ///     "//": "...",
+    ///     "options": {
+    ///         "typeFirst": false
+    ///     }
+    /// }
+    /// ```
+    ///
+    pub(crate) NoMixedImportStyle {
+        version: "next",
+        name: "noMixedImportStyle",
+        recommended: false,
+        fix_kind: FixKind::Safe,
+    }
+}
+
+impl Rule for NoMixedImportStyle {
+    type Query = Ast<JsImportNamedClause>;
+    type State = ();
+    type Signals = Option<Self::State>;
+    type Options = MixedImportStyleOptions;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let node = ctx.query();
+        if node.type_token().is_some() {
+            return None;
+        }
+        let named_import = node.named_import().ok()?;
+        let specifiers = named_import.as_js_named_import_specifiers()?.specifiers();
+        let mut has_type_specifier = false;
+        let mut has_value_specifier = node.default_specifier().is_some();
+        for specifier in specifiers.iter() {
+            if is_type_specifier(&specifier.ok()?)? {
+                has_type_specifier = true;
+            } else {
+                has_value_specifier = true;
+            }
+        }
+        (has_type_specifier && has_value_specifier).then_some(())
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, _: &Self::State) -> Option<RuleDiagnostic> {
+        let node = ctx.query();
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                node.range(),
+                markup! {
+                    "This "<Emphasis>"import"</Emphasis>" mixes type imports and value imports."
+                },
+            )
+            .note(markup! {
+                "Import types with a separate "<Emphasis>"import type"</Emphasis>"."
+            }),
+        )
+    }
+
+    fn action(ctx: &RuleContext<Self>, _: &Self::State) -> Option<JsRuleAction> {
+        let node = ctx.query();
+        let import = node.parent::<JsImport>()?;
+        let module_items = import.parent::<JsModuleItemList>()?;
+        let named_import = node.named_import().ok()?;
+        let named_import_specifiers = named_import.as_js_named_import_specifiers()?;
+        let specifiers = named_import_specifiers.specifiers();
+
+        let mut type_specifiers = Vec::new();
+        let mut value_specifiers = Vec::new();
+        for element in specifiers.elements() {
+            let specifier = element.node().ok()?.clone();
+            let separator = element.trailing_separator().ok()?.cloned();
+            if is_type_specifier(&specifier)? {
+                type_specifiers.push((without_type_token(specifier)?, separator));
+            } else {
+                value_specifiers.push((specifier, separator));
+            }
+        }
+
+        let type_clause = node
+            .clone()
+            .with_type_token(Some(
+                make::token(T![type]).with_trailing_trivia([(TriviaPieceKind::Whitespace, " ")]),
+            ))
+            .with_default_specifier(None)
+            .with_named_import(AnyJsNamedImport::JsNamedImportSpecifiers(
+                named_import_specifiers
+                    .clone()
+                    .with_specifiers(make_specifier_list(type_specifiers, &specifiers)?),
+            ));
+        let value_clause = if value_specifiers.is_empty() {
+            // import A, { type B } from "mod"
+            let default_specifier = node.default_specifier()?;
+            let from_token = node.from_token().ok()?;
+            let clause = make::js_import_default_clause(
+                default_specifier.local_name().ok()?,
+                from_token.with_leading_trivia([(TriviaPieceKind::Whitespace, " ")]),
+                node.source().ok()?,
+            );
+            let clause = match node.assertion() {
+                Some(assertion) => clause.with_assertion(assertion),
+                None => clause,
+            };
+            AnyJsImportClause::JsImportDefaultClause(clause.build())
+        } else {
+            AnyJsImportClause::JsImportNamedClause(
+                node.clone()
+                    .with_named_import(AnyJsNamedImport::JsNamedImportSpecifiers(
+                        named_import_specifiers
+                            .clone()
+                            .with_specifiers(make_specifier_list(value_specifiers, &specifiers)?),
+                    )),
+            )
+        };
+        let (first_clause, second_clause) = if ctx.options().type_first {
+            (
+                AnyJsImportClause::JsImportNamedClause(type_clause),
+                value_clause,
+            )
+        } else {
+            (
+                value_clause,
+                AnyJsImportClause::JsImportNamedClause(type_clause),
+            )
+        };
+
+        // The first import keeps the leading trivia of the original import,
+        // the second import keeps its trailing trivia.
+        let semicolon_token = import.semicolon_token();
+        let first_import = import
+            .clone()
+            .with_import_clause(first_clause)
+            .with_semicolon_token(
+                semicolon_token
+                    .as_ref()
+                    .map(|token| token.trim_trailing_trivia()),
+            );
+        let second_import = import
+            .clone()
+            .with_import_token(
+                import
+                    .import_token()
+                    .ok()?
+                    .with_leading_trivia([(TriviaPieceKind::Newline, "\n")]),
+            )
+            .with_import_clause(second_clause);
+
+        let mut new_items = Vec::with_capacity(module_items.len() + 1);
+        for item in module_items.iter() {
+            if item.syntax() == import.syntax() {
+                new_items.push(AnyJsModuleItem::JsImport(first_import.clone()));
+                new_items.push(AnyJsModuleItem::JsImport(second_import.clone()));
+            } else {
+                new_items.push(item);
+            }
+        }
+
+        let mut mutation = ctx.root().begin();
+        mutation.replace_node(module_items, make::js_module_item_list(new_items));
+        Some(JsRuleAction {
+            category: ActionCategory::QuickFix,
+            applicability: Applicability::Always,
+            message: markup! { "Split into an "<Emphasis>"import type"</Emphasis>" and a value "<Emphasis>"import"</Emphasis>"." }.to_owned(),
+            mutation,
+        })
+    }
+}
+
+/// Returns `true` if `specifier` has a `type` qualifier, and `None` for bogus specifiers.
+fn is_type_specifier(specifier: &AnyJsNamedImportSpecifier) -> Option<bool> {
+    match specifier {
+        AnyJsNamedImportSpecifier::JsBogusNamedImportSpecifier(_) => None,
+        AnyJsNamedImportSpecifier::JsNamedImportSpecifier(specifier) => {
+            Some(specifier.type_token().is_some())
+        }
+        AnyJsNamedImportSpecifier::JsShorthandNamedImportSpecifier(specifier) => {
+            Some(specifier.type_token().is_some())
+        }
+    }
+}
+
+/// Removes the `type` qualifier of `specifier`, and keeps its leading trivia.
+fn without_type_token(specifier: AnyJsNamedImportSpecifier) -> Option<AnyJsNamedImportSpecifier> {
+    let (specifier, type_token) = match specifier {
+        AnyJsNamedImportSpecifier::JsNamedImportSpecifier(specifier) => {
+            let type_token = specifier.type_token()?;
+            (
+                AnyJsNamedImportSpecifier::JsNamedImportSpecifier(specifier.with_type_token(None)),
+                type_token,
+            )
+        }
+        AnyJsNamedImportSpecifier::JsShorthandNamedImportSpecifier(specifier) => {
+            let type_token = specifier.type_token()?;
+            (
+                AnyJsNamedImportSpecifier::JsShorthandNamedImportSpecifier(
+                    specifier.with_type_token(None),
+                ),
+                type_token,
+            )
+        }
+        AnyJsNamedImportSpecifier::JsBogusNamedImportSpecifier(_) => return None,
+    };
+    specifier.prepend_trivia_pieces(type_token.leading_trivia().pieces())
+}
+
+/// Creates a list from a subset of the specifiers of `original`.
+/// The trailing separator and the trivia before the closing brace are preserved.
+fn make_specifier_list(
+    specifiers: Vec<(AnyJsNamedImportSpecifier, Option<JsSyntaxToken>)>,
+    original: &JsNamedImportSpecifierList,
+) -> Option<JsNamedImportSpecifierList> {
+    let keep_trailing_separator = original.trailing_separator().is_some();
+    let last_trailing_trivia = original.syntax().last_trailing_trivia()?;
+    let last_index = specifiers.len().saturating_sub(1);
+    let mut separators = Vec::with_capacity(specifiers.len());
+    let mut nodes = Vec::with_capacity(specifiers.len());
+    for (index, (specifier, separator)) in specifiers.into_iter().enumerate() {
+        if index < last_index || keep_trailing_separator {
+            separators.push(separator.unwrap_or_else(|| {
+                make::token(T![,]).with_trailing_trivia([(TriviaPieceKind::Whitespace, " ")])
+            }));
+            nodes.push(specifier);
+        } else {
+            nodes.push(specifier.with_trailing_trivia_pieces(last_trailing_trivia.pieces())?);
+        }
+    }
+    Some(make::js_named_import_specifier_list(nodes, separators))
+}
+
+/// Options for the rule `noMixedImportStyle`.
+#[derive(Deserialize, Serialize, Eq, PartialEq, Debug, Clone, Bpaf)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct MixedImportStyleOptions {
+    /// If `true`, the code fix places the `import type` before the value `import`.
+    #[bpaf(hide)]
+    pub type_first: bool,
+}
+
+impl Default for MixedImportStyleOptions {
+    fn default() -> Self {
+        Self { type_first: true }
+    }
+}
+
+impl MixedImportStyleOptions {
+    pub(crate) const KNOWN_KEYS: &'static [&'static str] = &["typeFirst"];
+}
+
+// Required by [Bpaf].
+impl FromStr for MixedImportStyleOptions {
+    type Err = &'static str;
+
+    fn from_str(_s: &str) -> Result<Self, Self::Err> {
+        // WARNING: should not be used.
+        Ok(Self::default())
+    }
+}
+
+impl VisitNode<JsonLanguage> for MixedImportStyleOptions {
+    fn visit_member_name(
+        &mut self,
+        node: &SyntaxNode<JsonLanguage>,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<()> {
+        has_only_known_keys(node, Self::KNOWN_KEYS, diagnostics)
+    }
+
+    fn visit_map(
+        &mut self,
+        key: &SyntaxNode<JsonLanguage>,
+        value: &SyntaxNode<JsonLanguage>,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<()> {
+        let (name, value) = self.get_key_and_value(key, value, diagnostics)?;
+        let name_text = name.text();
+        if name_text == "typeFirst" {
+            self.type_first = self.map_to_boolean(&value, name_text, diagnostics)?;
+        }
+
+        Some(())
+    }
+}
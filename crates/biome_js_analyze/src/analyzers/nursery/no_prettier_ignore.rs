@@ -0,0 +1,172 @@
+use crate::JsRuleAction;
+use biome_analyze::{
+    context::RuleContext, declare_rule, ActionCategory, Ast, FixKind, Rule, RuleDiagnostic,
+};
+use biome_console::markup;
+use biome_diagnostics::Applicability;
+use biome_js_syntax::suppression::is_prettier_ignore;
+use biome_js_syntax::{AnyJsRoot, JsSyntaxToken, TextRange, TriviaPieceKind};
+use biome_rowan::{AstNode, BatchMutationExt, Direction};
+
+declare_rule! {
+    /// Disallow `// prettier-ignore` comments.
+    ///
+    /// The formatter honors `// prettier-ignore` comments like `// biome-ignore format` comments,
+    /// to ease the migration from Prettier.
+    /// This rule helps to complete the migration by reporting the remaining `// prettier-ignore` comments.
+    ///
+    /// The fix replaces the comment with a `// biome-ignore format` comment.
+    /// The text that follows `prettier-ignore` is kept as the reason of the suppression.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,expect_diagnostic
+    /// // prettier-ignore
+    /// const matrix = [
+    ///     1, 0,
+    ///     0, 1,
+    /// ];
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// const a = {
+    ///     /* prettier-ignore */
+    ///     b:   1,
+    /// };
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// // biome-ignore format: the matrix is aligned
+    /// const matrix = [
+    ///     1, 0,
+    ///     0, 1,
+    /// ];
+    /// ```
+    pub(crate) NoPrettierIgnore {
+        version: "next",
+        name: "noPrettierIgnore",
+        recommended: false,
+        fix_kind: FixKind::Safe,
+    }
+}
+
+/// A `prettier-ignore` comment in the trivia of a token
+pub(crate) struct PrettierIgnoreComment {
+    token: JsSyntaxToken,
+    /// Whether the comment is in the leading trivia of the token
+    is_leading: bool,
+    range: TextRange,
+}
+
+impl Rule for NoPrettierIgnore {
+    type Query = Ast<AnyJsRoot>;
+    type State = PrettierIgnoreComment;
+    type Signals = Vec<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let mut signals = Vec::new();
+        for token in ctx.query().syntax().descendants_tokens(Direction::Next) {
+            let leading = token.leading_trivia().pieces().map(|piece| (true, piece));
+            let trailing = token.trailing_trivia().pieces().map(|piece| (false, piece));
+            for (is_leading, piece) in leading.chain(trailing) {
+                if piece.is_comments() && is_prettier_ignore(piece.text()) {
+                    signals.push(PrettierIgnoreComment {
+                        token: token.clone(),
+                        is_leading,
+                        range: piece.text_range(),
+                    });
+                }
+            }
+        }
+        signals
+    }
+
+    fn diagnostic(_: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                state.range,
+                markup! {
+                    "Unexpected "<Emphasis>"prettier-ignore"</Emphasis>" comment."
+                },
+            )
+            .note(markup! {
+                "Use a "<Emphasis>"biome-ignore format"</Emphasis>" comment to prevent the formatting of the following code."
+            }),
+        )
+    }
+
+    fn action(ctx: &RuleContext<Self>, state: &Self::State) -> Option<JsRuleAction> {
+        let PrettierIgnoreComment {
+            token,
+            is_leading,
+            range,
+        } = state;
+        let trivia = if *is_leading {
+            token.leading_trivia()
+        } else {
+            token.trailing_trivia()
+        };
+        let pieces: Vec<(TriviaPieceKind, String)> = trivia
+            .pieces()
+            .map(|piece| {
+                if piece.text_range() == *range {
+                    // The new comment always fits on a single line
+                    (
+                        TriviaPieceKind::SingleLineComment,
+                        to_biome_ignore(piece.text()),
+                    )
+                } else {
+                    (piece.kind(), piece.text().to_string())
+                }
+            })
+            .collect();
+        let pieces = pieces.iter().map(|(kind, text)| (*kind, text.as_str()));
+        let new_token = if *is_leading {
+            token.with_leading_trivia(pieces)
+        } else {
+            token.with_trailing_trivia(pieces)
+        };
+        let mut mutation = ctx.root().begin();
+        mutation.replace_token_discard_trivia(token.clone(), new_token);
+        Some(JsRuleAction {
+            category: ActionCategory::QuickFix,
+            applicability: Applicability::Always,
+            message: markup! {
+                "Use a "<Emphasis>"biome-ignore format"</Emphasis>" comment instead."
+            }
+            .to_owned(),
+            mutation,
+        })
+    }
+}
+
+/// Converts a `prettier-ignore` comment to a `biome-ignore format` comment,
+/// keeping the text after the directive as the reason of the suppression.
+fn to_biome_ignore(comment: &str) -> String {
+    let (content, is_block) = match comment.strip_prefix("/*") {
+        Some(content) => (content.strip_suffix("*/").unwrap_or(content), true),
+        None => (comment.strip_prefix("//").unwrap_or(comment), false),
+    };
+    let reason = content
+        .trim()
+        .strip_prefix("prettier-ignore")
+        .unwrap_or_default()
+        .trim_start_matches(':')
+        .trim();
+    let reason = if reason.is_empty() {
+        "migrated from prettier-ignore"
+    } else {
+        reason
+    };
+    if is_block {
+        format!("/* biome-ignore format: {reason} */")
+    } else {
+        format!("// biome-ignore format: {reason}")
+    }
+}
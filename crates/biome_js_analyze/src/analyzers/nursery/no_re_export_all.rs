@@ -0,0 +1,202 @@
+use biome_analyze::{context::RuleContext, declare_rule, Ast, Rule, RuleDiagnostic};
+use biome_console::markup;
+use biome_deserialize::json::{has_only_known_keys, VisitJsonNode};
+use biome_deserialize::{DeserializationDiagnostic, VisitNode};
+use biome_js_syntax::JsExportFromClause;
+use biome_json_syntax::JsonLanguage;
+use biome_rowan::{AstNode, SyntaxNode};
+use bpaf::Bpaf;
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+use std::str::FromStr;
+
+declare_rule! {
+    /// Disallow `export *` re-exports in the entry files of a library.
+    ///
+    /// `export * from "./module"` re-exports everything that a module exports.
+    /// Bundlers can't statically determine the exports of an entry file that contains such a re-export,
+    /// which prevents them from removing the unused exports of the library (tree-shaking).
+    ///
+    /// The rule only reports the files that match one of the glob patterns of the option `entryFiles`.
+    /// In the other files, `export *` is a legitimate way of grouping the exports of a directory.
+    /// Namespace re-exports such as `export * as ns from "./module"` and type re-exports are allowed.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// In a file that matches `entryFiles`:
+    ///
+    /// ```js
+    /// export * from "./components";
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// export { Button, Input } from "./components";
+    /// export * as utils from "./utils";
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// The option `entryFiles` is a list of glob patterns.
+    /// `*` matches any sequence of characters in a path segment, `**` matches any number of path segments,
+    /// and `?` matches a single character.
+    /// A pattern matches a file when it matches the end of its path.
+    ///
+    /// ```json
+    /// {
+    ///     "//": "...",
+    ///     "options": {
+    ///         "entryFiles": ["src/index.ts", "src/*/index.ts"]
+    ///     }
+    /// }
+    /// ```
+    ///
+    pub(crate) NoReExportAll {
+        version: "next",
+        name: "noReExportAll",
+        recommended: false,
+    }
+}
+
+impl Rule for NoReExportAll {
+    type Query = Ast<JsExportFromClause>;
+    type State = ();
+    type Signals = Option<Self::State>;
+    type Options = ReExportAllOptions;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let clause = ctx.query();
+        if clause.type_token().is_some() || clause.export_as().is_some() {
+            return None;
+        }
+        let is_entry_file = ctx
+            .options()
+            .entry_files
+            .iter()
+            .flatten()
+            .any(|pattern| matches_path(pattern, ctx.file_path()));
+        is_entry_file.then_some(())
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, _: &Self::State) -> Option<RuleDiagnostic> {
+        let clause = ctx.query();
+        let range = clause
+            .syntax()
+            .parent()
+            .map_or(clause.range(), |export| export.text_trimmed_range());
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                range,
+                markup! {
+                    "Avoid re-exporting all the exports of a module from an entry file."
+                },
+            )
+            .note(markup! {
+                "Bundlers can't statically determine the exports of this file, which prevents tree-shaking."
+            })
+            .note(markup! {
+                "List the re-exported names explicitly, such as "<Emphasis>"export { a, b } from \"./module\""</Emphasis>"."
+            }),
+        )
+    }
+}
+
+/// Returns `true` if `pattern` matches `path`, or the end of `path` starting at a path segment.
+fn matches_path(pattern: &str, path: &Path) -> bool {
+    let Some(path) = path.to_str() else {
+        return false;
+    };
+    let path = path.replace('\\', "/");
+    let path = path.as_bytes();
+    let pattern = pattern.strip_prefix("./").unwrap_or(pattern).as_bytes();
+    matches_glob(pattern, path)
+        || path
+            .iter()
+            .enumerate()
+            .any(|(index, byte)| *byte == b'/' && matches_glob(pattern, &path[index + 1..]))
+}
+
+/// Returns `true` if the glob `pattern` matches the whole `path`.
+fn matches_glob(pattern: &[u8], path: &[u8]) -> bool {
+    match pattern {
+        [] => path.is_empty(),
+        [b'*', b'*', b'/', rest @ ..] => {
+            matches_glob(rest, path)
+                || path
+                    .iter()
+                    .enumerate()
+                    .any(|(index, byte)| *byte == b'/' && matches_glob(rest, &path[index + 1..]))
+        }
+        [b'*', b'*', rest @ ..] => (0..=path.len()).any(|index| matches_glob(rest, &path[index..])),
+        [b'*', rest @ ..] => {
+            // `*` doesn't match the separators of path segments
+            let segment_len = path
+                .iter()
+                .position(|byte| *byte == b'/')
+                .unwrap_or(path.len());
+            (0..=segment_len).any(|index| matches_glob(rest, &path[index..]))
+        }
+        [b'?', rest @ ..] => match path {
+            [byte, path @ ..] => *byte != b'/' && matches_glob(rest, path),
+            [] => false,
+        },
+        [expected, rest @ ..] => match path {
+            [byte, path @ ..] => byte == expected && matches_glob(rest, path),
+            [] => false,
+        },
+    }
+}
+
+/// Options for the rule `noReExportAll`.
+#[derive(Default, Deserialize, Serialize, Eq, PartialEq, Debug, Clone, Bpaf)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct ReExportAllOptions {
+    /// A list of glob patterns that match the entry files of the library
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[bpaf(hide, argument::<String>("GLOB"), many, optional)]
+    entry_files: Option<Vec<String>>,
+}
+
+impl ReExportAllOptions {
+    pub(crate) const KNOWN_KEYS: &'static [&'static str] = &["entryFiles"];
+}
+
+// Required by [Bpaf].
+impl FromStr for ReExportAllOptions {
+    type Err = &'static str;
+
+    fn from_str(_s: &str) -> Result<Self, Self::Err> {
+        // WARNING: should not be used.
+        Ok(Self::default())
+    }
+}
+
+impl VisitNode<JsonLanguage> for ReExportAllOptions {
+    fn visit_member_name(
+        &mut self,
+        node: &SyntaxNode<JsonLanguage>,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<()> {
+        has_only_known_keys(node, Self::KNOWN_KEYS, diagnostics)
+    }
+
+    fn visit_map(
+        &mut self,
+        key: &SyntaxNode<JsonLanguage>,
+        value: &SyntaxNode<JsonLanguage>,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<()> {
+        let (name, value) = self.get_key_and_value(key, value, diagnostics)?;
+        let name_text = name.text();
+        if name_text == "entryFiles" {
+            self.entry_files = self.map_to_array_of_strings(&value, name_text, diagnostics);
+        }
+
+        Some(())
+    }
+}
@@ -0,0 +1,209 @@
+use biome_analyze::{context::RuleContext, declare_rule, Ast, Rule, RuleDiagnostic};
+use biome_console::markup;
+use biome_deserialize::json::{has_only_known_keys, VisitJsonNode};
+use biome_deserialize::{DeserializationDiagnostic, VisitNode};
+use biome_js_syntax::{
+    AnyJsStatement, JsBlockStatement, JsCaseClause, JsDefaultClause, JsModuleItemList,
+    JsStatementList,
+};
+use biome_json_syntax::JsonLanguage;
+use biome_rowan::{AstNode, SyntaxNode};
+use bpaf::Bpaf;
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+declare_rule! {
+    /// Disallow block statements that aren't attached to a control structure.
+    ///
+    /// A block that isn't the body of an `if`, a loop, a `try`, or a function
+    /// doesn't change how the code is executed, and is easily mistaken for an object literal
+    /// or for the body of a statement that was removed.
+    ///
+    /// Blocks that are the body of a labeled statement are ignored,
+    /// as well as blocks that are the body of a `case` or `default` clause of a `switch` statement.
+    ///
+    /// By default, blocks that declare a variable with `let` or `const`, or a class, are also ignored,
+    /// because the block clearly limits the scope of these declarations.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,expect_diagnostic
+    /// {
+    ///     foo();
+    /// }
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// function f() {
+    ///     {
+    ///         var x = 1;
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// if (foo) {
+    ///     bar();
+    /// }
+    /// ```
+    ///
+    /// ```js
+    /// {
+    ///     const x = compute();
+    ///     use(x);
+    /// }
+    /// ```
+    ///
+    /// ```js
+    /// label: {
+    ///     break label;
+    /// }
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// Blocks that contain `let`, `const`, or `class` declarations are reported too when the option `allowScoping` is set to `false`.
+    /// It defaults to `true`.
+    ///
+    /// ```json
+    /// {
+    ///     "//": "...",
+    ///     "options": {
+    ///         "allowScoping": false
+    ///     }
+    /// }
+    /// ```
+    ///
+    pub(crate) NoStandaloneBlocks {
+        version: "next",
+        name: "noStandaloneBlocks",
+        recommended: false,
+    }
+}
+
+impl Rule for NoStandaloneBlocks {
+    type Query = Ast<JsBlockStatement>;
+    type State = ();
+    type Signals = Option<Self::State>;
+    type Options = StandaloneBlocksOptions;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let block = ctx.query();
+        if !is_standalone(block) {
+            return None;
+        }
+        if ctx.options().allow_scoping && has_scoped_declaration(block) {
+            return None;
+        }
+        Some(())
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, _: &Self::State) -> Option<RuleDiagnostic> {
+        let block = ctx.query();
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                block.range(),
+                markup! {
+                    "This block isn't attached to any control structure."
+                },
+            )
+            .note(markup! {
+                "Remove the braces, or move the statements into a function if they belong together."
+            }),
+        )
+    }
+}
+
+/// Returns `true` if `block` is a statement of a statement list that isn't the body of a `switch` clause.
+///
+/// Blocks that are the body of a control structure or of a labeled statement have another parent.
+fn is_standalone(block: &JsBlockStatement) -> bool {
+    let Some(parent) = block.syntax().parent() else {
+        return false;
+    };
+    if JsModuleItemList::can_cast(parent.kind()) {
+        return true;
+    }
+    if !JsStatementList::can_cast(parent.kind()) {
+        return false;
+    }
+    // `case "a": { ... }` is a common way to delimit the body of a clause
+    !parent.parent().is_some_and(|clause| {
+        JsCaseClause::can_cast(clause.kind()) || JsDefaultClause::can_cast(clause.kind())
+    })
+}
+
+/// Returns `true` if `block` directly contains a `let`, `const`, or `class` declaration.
+fn has_scoped_declaration(block: &JsBlockStatement) -> bool {
+    block
+        .statements()
+        .into_iter()
+        .any(|statement| match statement {
+            AnyJsStatement::JsVariableStatement(variable) => variable
+                .declaration()
+                .is_ok_and(|declaration| !declaration.is_var()),
+            AnyJsStatement::JsClassDeclaration(_) => true,
+            _ => false,
+        })
+}
+
+/// Options for the rule `noStandaloneBlocks`.
+#[derive(Deserialize, Serialize, Eq, PartialEq, Debug, Clone, Bpaf)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct StandaloneBlocksOptions {
+    /// If `true`, blocks that contain `let`, `const`, or `class` declarations are ignored.
+    pub allow_scoping: bool,
+}
+
+impl Default for StandaloneBlocksOptions {
+    fn default() -> Self {
+        Self {
+            allow_scoping: true,
+        }
+    }
+}
+
+impl StandaloneBlocksOptions {
+    pub(crate) const KNOWN_KEYS: &'static [&'static str] = &["allowScoping"];
+}
+
+// Required by [Bpaf].
+impl FromStr for StandaloneBlocksOptions {
+    type Err = &'static str;
+
+    fn from_str(_s: &str) -> Result<Self, Self::Err> {
+        // WARNING: should not be used.
+        Ok(Self::default())
+    }
+}
+
+impl VisitNode<JsonLanguage> for StandaloneBlocksOptions {
+    fn visit_member_name(
+        &mut self,
+        node: &SyntaxNode<JsonLanguage>,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<()> {
+        has_only_known_keys(node, Self::KNOWN_KEYS, diagnostics)
+    }
+
+    fn visit_map(
+        &mut self,
+        key: &SyntaxNode<JsonLanguage>,
+        value: &SyntaxNode<JsonLanguage>,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<()> {
+        let (name, value) = self.get_key_and_value(key, value, diagnostics)?;
+        let name_text = name.text();
+        if name_text == "allowScoping" {
+            self.allow_scoping = self.map_to_boolean(&value, name_text, diagnostics)?;
+        }
+
+        Some(())
+    }
+}
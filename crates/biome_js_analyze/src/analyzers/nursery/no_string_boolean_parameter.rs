@@ -0,0 +1,172 @@
+use biome_analyze::context::RuleContext;
+use biome_analyze::{declare_rule, Ast, Rule, RuleDiagnostic};
+use biome_console::markup;
+use biome_deserialize::json::{has_only_known_keys, VisitJsonNode};
+use biome_deserialize::{DeserializationDiagnostic, VisitNode};
+use biome_js_syntax::{
+    AnyJsCallArgument, AnyJsExpression, AnyJsLiteralExpression, JsCallExpression,
+};
+use biome_json_syntax::JsonLanguage;
+use biome_rowan::{AstNode, AstSeparatedList, SyntaxNode};
+use bpaf::Bpaf;
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+declare_rule! {
+    /// Disallow calls with several boolean literal arguments.
+    ///
+    /// A call such as `createUser("alice", true, false)` doesn't tell what `true` and `false` mean,
+    /// and it's easy to swap them by mistake.
+    /// The rule reports the calls with at least `minBoolArgs` arguments that are `true` or `false` literals.
+    ///
+    /// The names of the parameters aren't known without type information,
+    /// so the rule doesn't provide a fix.
+    /// The booleans can be extracted in named constants, such as `const isAdmin = true;`,
+    /// or the function can take an options object, such as `createUser("alice", { isAdmin: true, isActive: false })`.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,expect_diagnostic
+    /// createUser("alice", true, false);
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// setFlag(true);
+    /// ```
+    ///
+    /// ```js
+    /// const isAdmin = true;
+    /// const isActive = false;
+    /// createUser("alice", isAdmin, isActive);
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// The minimum number of boolean literal arguments of a reported call can be set with the option `minBoolArgs`.
+    /// It defaults to `2`.
+    ///
+    /// ```json
+    /// {
+    ///     "//": "...",
+    ///     "options": {
+    ///         "minBoolArgs": 3
+    ///     }
+    /// }
+    /// ```
+    ///
+    pub(crate) NoStringBooleanParameter {
+        version: "next",
+        name: "noStringBooleanParameter",
+        recommended: false,
+    }
+}
+
+impl Rule for NoStringBooleanParameter {
+    type Query = Ast<JsCallExpression>;
+    type State = usize;
+    type Signals = Option<Self::State>;
+    type Options = BooleanParametersOptions;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let arguments = ctx.query().arguments().ok()?;
+        let count = arguments
+            .args()
+            .iter()
+            .filter(|argument| {
+                matches!(
+                    argument,
+                    Ok(AnyJsCallArgument::AnyJsExpression(
+                        AnyJsExpression::AnyJsLiteralExpression(
+                            AnyJsLiteralExpression::JsBooleanLiteralExpression(_)
+                        )
+                    ))
+                )
+            })
+            .count();
+        (count >= usize::from(ctx.options().min_bool_args)).then_some(count)
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, count: &Self::State) -> Option<RuleDiagnostic> {
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                ctx.query().arguments().ok()?.range(),
+                markup! {
+                    "This call has "{count}" boolean literal arguments, whose meaning isn't clear."
+                },
+            )
+            .note(markup! {
+                "Consider extracting them in named constants, such as "<Emphasis>"const isAdmin = true"</Emphasis>", or passing an options object instead."
+            }),
+        )
+    }
+}
+
+/// Options for the rule `noStringBooleanParameter`.
+#[derive(Deserialize, Serialize, Eq, PartialEq, Debug, Clone, Bpaf)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct BooleanParametersOptions {
+    /// The minimum number of boolean literal arguments of a call. Anything lower isn't reported.
+    pub min_bool_args: u8,
+}
+
+impl Default for BooleanParametersOptions {
+    fn default() -> Self {
+        Self { min_bool_args: 2 }
+    }
+}
+
+impl BooleanParametersOptions {
+    pub(crate) const KNOWN_KEYS: &'static [&'static str] = &["minBoolArgs"];
+}
+
+// Required by [Bpaf].
+impl FromStr for BooleanParametersOptions {
+    type Err = &'static str;
+
+    fn from_str(_s: &str) -> Result<Self, Self::Err> {
+        // WARNING: should not be used.
+        Ok(Self::default())
+    }
+}
+
+impl VisitNode<JsonLanguage> for BooleanParametersOptions {
+    fn visit_member_name(
+        &mut self,
+        node: &SyntaxNode<JsonLanguage>,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<()> {
+        has_only_known_keys(node, Self::KNOWN_KEYS, diagnostics)
+    }
+
+    fn visit_map(
+        &mut self,
+        key: &SyntaxNode<JsonLanguage>,
+        value: &SyntaxNode<JsonLanguage>,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<()> {
+        let (name, value) = self.get_key_and_value(key, value, diagnostics)?;
+        let name_text = name.text();
+        if name_text == "minBoolArgs" {
+            let min_bool_args = self.map_to_u8(&value, name_text, u8::MAX, diagnostics)?;
+            // Don't allow 0 or every call would be reported.
+            if min_bool_args == 0 {
+                diagnostics.push(
+                    DeserializationDiagnostic::new(markup! {
+                        "The field "<Emphasis>"minBoolArgs"</Emphasis>" must be greater than 0"
+                    })
+                    .with_range(value.range()),
+                );
+                return None;
+            }
+            self.min_bool_args = min_bool_args;
+        }
+
+        Some(())
+    }
+}
@@ -0,0 +1,236 @@
+use biome_analyze::context::RuleContext;
+use biome_analyze::{declare_rule, Ast, Rule, RuleDiagnostic};
+use biome_console::markup;
+use biome_deserialize::json::{has_only_known_keys, VisitJsonNode};
+use biome_deserialize::{DeserializationDiagnostic, VisitNode};
+use biome_js_syntax::{AnyTsType, JsFileSource, JsFormalParameter};
+use biome_json_syntax::{JsonLanguage, JsonObjectValue};
+use biome_rowan::{AstNode, SyntaxNode};
+use bpaf::Bpaf;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::str::FromStr;
+
+declare_rule! {
+    /// Disallow the `string` type on parameters whose name suggests a constrained value.
+    ///
+    /// A parameter named `url` or `color` rarely accepts any string.
+    /// A template literal type, such as `` `https://${string}` `` or `` `#${string}` ``,
+    /// documents the expected format and lets TypeScript reject the values that don't match it.
+    ///
+    /// The rule reports the parameters annotated with `string` when their name, or the last word of their name,
+    /// is one of the keys of the option `patternHints`.
+    /// The diagnostic shows the hint associated with the name.
+    /// By default, the rule checks the parameters named `url`, `path`, `mime`, and `color`.
+    ///
+    /// The rule only applies to TypeScript files.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```ts,expect_diagnostic
+    /// function fetchJson(url: string) {}
+    /// ```
+    ///
+    /// ```ts,expect_diagnostic
+    /// function paint(backgroundColor: string) {}
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```ts
+    /// function fetchJson(url: `https://${string}`) {}
+    /// ```
+    ///
+    /// ```ts
+    /// function greet(name: string) {}
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// The option `patternHints` maps parameter names to a hint of the expected format.
+    /// It replaces the default names.
+    ///
+    /// ```json
+    /// {
+    ///     "//": "...",
+    ///     "options": {
+    ///         "patternHints": {
+    ///             "url": "https?://...",
+    ///             "color": "#[0-9a-fA-F]{3,6}"
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    ///
+    pub(crate) NoStringLiteralType {
+        version: "next",
+        name: "noStringLiteralType",
+        recommended: false,
+    }
+}
+
+/// The parameter name that matched a key of `patternHints`, and its hint
+pub(crate) struct MatchedHint {
+    name: String,
+    hint: String,
+}
+
+impl Rule for NoStringLiteralType {
+    type Query = Ast<JsFormalParameter>;
+    type State = MatchedHint;
+    type Signals = Option<Self::State>;
+    type Options = StringLiteralTypeOptions;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        if !ctx.source_type::<JsFileSource>().language().is_typescript() {
+            return None;
+        }
+        let parameter = ctx.query();
+        let ty = parameter.type_annotation()?.ty().ok()?;
+        if !matches!(ty, AnyTsType::TsStringType(_)) {
+            return None;
+        }
+        let name = parameter
+            .binding()
+            .ok()?
+            .as_any_js_binding()?
+            .as_js_identifier_binding()?
+            .name_token()
+            .ok()?;
+        let name = name.text_trimmed();
+        let (_, hint) = ctx
+            .options()
+            .pattern_hints
+            .iter()
+            .find(|(key, _)| has_last_word(name, key))?;
+        Some(MatchedHint {
+            name: name.to_string(),
+            hint: hint.clone(),
+        })
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let MatchedHint { name, hint } = state;
+        let ty = ctx.query().type_annotation()?.ty().ok()?;
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                ty.range(),
+                markup! {
+                    "The parameter "<Emphasis>{name}</Emphasis>" accepts any string, but its name suggests a constrained value."
+                },
+            )
+            .note(markup! {
+                "Expected format: "<Emphasis>{hint}</Emphasis>
+            })
+            .note(markup! {
+                "Consider using a template literal type that describes this format."
+            }),
+        )
+    }
+}
+
+/// Returns `true` if `word` is `name`, or the last word of `name` in camelCase or snake_case.
+///
+/// The comparison ignores the case: `url`, `imageUrl`, `IMAGE_URL`, and `image_url` all end with the word `url`.
+fn has_last_word(name: &str, word: &str) -> bool {
+    if word.is_empty() || name.len() < word.len() {
+        return false;
+    }
+    let (prefix, suffix) = name.split_at(name.len() - word.len());
+    if !suffix.eq_ignore_ascii_case(word) {
+        return false;
+    }
+    prefix.is_empty()
+        || prefix.ends_with('_')
+        || (suffix.starts_with(|c: char| c.is_ascii_uppercase())
+            && prefix.ends_with(|c: char| c.is_ascii_lowercase() || c.is_ascii_digit()))
+}
+
+/// Options for the rule `noStringLiteralType`.
+#[derive(Deserialize, Serialize, Eq, PartialEq, Debug, Clone, Bpaf)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct StringLiteralTypeOptions {
+    /// Maps the parameter names to a hint of their expected format.
+    #[bpaf(pure(default_pattern_hints()), hide)]
+    #[serde(default = "default_pattern_hints")]
+    pub pattern_hints: BTreeMap<String, String>,
+}
+
+fn default_pattern_hints() -> BTreeMap<String, String> {
+    [
+        ("color", "#[0-9a-fA-F]{3,6}"),
+        ("mime", "type/subtype"),
+        ("path", "/..."),
+        ("url", "https?://..."),
+    ]
+    .into_iter()
+    .map(|(name, hint)| (name.to_string(), hint.to_string()))
+    .collect()
+}
+
+impl Default for StringLiteralTypeOptions {
+    fn default() -> Self {
+        Self {
+            pattern_hints: default_pattern_hints(),
+        }
+    }
+}
+
+impl StringLiteralTypeOptions {
+    pub(crate) const KNOWN_KEYS: &'static [&'static str] = &["patternHints"];
+}
+
+// Required by [Bpaf].
+impl FromStr for StringLiteralTypeOptions {
+    type Err = &'static str;
+
+    fn from_str(_s: &str) -> Result<Self, Self::Err> {
+        // WARNING: should not be used.
+        Ok(Self::default())
+    }
+}
+
+impl VisitNode<JsonLanguage> for StringLiteralTypeOptions {
+    fn visit_member_name(
+        &mut self,
+        node: &SyntaxNode<JsonLanguage>,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<()> {
+        has_only_known_keys(node, Self::KNOWN_KEYS, diagnostics)
+    }
+
+    fn visit_map(
+        &mut self,
+        key: &SyntaxNode<JsonLanguage>,
+        value: &SyntaxNode<JsonLanguage>,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<()> {
+        let (name, value) = self.get_key_and_value(key, value, diagnostics)?;
+        let name_text = name.text();
+        if name_text == "patternHints" {
+            let object = JsonObjectValue::cast_ref(value.syntax()).or_else(|| {
+                diagnostics.push(DeserializationDiagnostic::new_incorrect_type_for_value(
+                    name_text,
+                    "object",
+                    value.range(),
+                ));
+                None
+            })?;
+            let mut pattern_hints = BTreeMap::new();
+            for member in object.json_member_list() {
+                let member = member.ok()?;
+                let parameter_name = member.name().ok()?.inner_string_text().ok()?;
+                let hint =
+                    self.map_to_string(&member.value().ok()?, parameter_name.text(), diagnostics)?;
+                pattern_hints.insert(parameter_name.to_string(), hint);
+            }
+            self.pattern_hints = pattern_hints;
+        }
+
+        Some(())
+    }
+}
@@ -0,0 +1,1132 @@
+use biome_analyze::context::RuleContext;
+use biome_analyze::{declare_rule, Ast, Rule, RuleDiagnostic};
+use biome_console::markup;
+use biome_deserialize::json::{has_only_known_keys, VisitJsonNode};
+use biome_deserialize::{DeserializationDiagnostic, VisitNode};
+use biome_js_syntax::JsRegexLiteralExpression;
+use biome_json_syntax::JsonLanguage;
+use biome_rowan::{AstNode, SyntaxNode};
+use bpaf::Bpaf;
+use rustc_hash::FxHashSet;
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use std::str::FromStr;
+
+declare_rule! {
+    /// Disallow regular expressions that are vulnerable to catastrophic backtracking.
+    ///
+    /// The regular expression engine of JavaScript uses backtracking.
+    /// When a pattern can match the same string in many different ways,
+    /// the engine may try all of them before rejecting an input.
+    /// A crafted input can then block the event loop for a very long time.
+    /// This is known as Regular expression Denial of Service (ReDoS).
+    ///
+    /// The rule builds an automaton from the pattern and looks for ambiguous loops:
+    /// - nested quantifiers, such as `(a+)+`, and quantified alternatives that overlap, such as `(a|a)+`,
+    ///   take an _exponential_ time to reject some inputs;
+    /// - consecutive quantifiers that overlap, such as `\d+\d+`, take a _polynomial_ time to reject some inputs.
+    ///
+    /// Lookarounds and backreferences are ignored by the analysis.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,expect_diagnostic
+    /// /(a+)+$/;
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// /^(a|a)+$/;
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// /([a-zA-Z0-9])+([a-zA-Z0-9])+/;
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// /^a+$/;
+    /// ```
+    ///
+    /// ```js
+    /// /^(a+b)+$/;
+    /// ```
+    ///
+    /// ```js
+    /// /^\d{1,3}(\.\d{1,3}){3}$/;
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// The complexity of a pattern is the degree of the polynomial that bounds the time taken to reject an input.
+    /// A pattern such as `a+` has a complexity of `1`: the time grows linearly with the length of the input.
+    /// A pattern such as `\d+\d+` has a complexity of `2`: the time grows quadratically.
+    ///
+    /// The option `maxSafeComplexity` sets the highest complexity that is allowed.
+    /// It defaults to `1`.
+    /// Patterns with an exponential complexity are always reported.
+    ///
+    /// ```json
+    /// {
+    ///     "//": "...",
+    ///     "options": {
+    ///         "maxSafeComplexity": 2
+    ///     }
+    /// }
+    /// ```
+    ///
+    pub(crate) NoUnsafeRegex {
+        version: "next",
+        name: "noUnsafeRegex",
+        recommended: false,
+    }
+}
+
+impl Rule for NoUnsafeRegex {
+    type Query = Ast<JsRegexLiteralExpression>;
+    type State = Complexity;
+    type Signals = Option<Self::State>;
+    type Options = UnsafeRegexOptions;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let (pattern, flags) = ctx.query().decompose().ok()?;
+        let regex = Parser::new(pattern.text(), flags.text()).parse()?;
+        let automaton = Automaton::from_regex(&regex)?;
+        match automaton.complexity()? {
+            Complexity::Polynomial(degree)
+                if degree <= u32::from(ctx.options().max_safe_complexity) =>
+            {
+                None
+            }
+            complexity => Some(complexity),
+        }
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, complexity: &Self::State) -> Option<RuleDiagnostic> {
+        let diagnostic = match complexity {
+            Complexity::Exponential => RuleDiagnostic::new(
+                rule_category!(),
+                ctx.query().range(),
+                markup! {
+                    "This regular expression can take an "<Emphasis>"exponential"</Emphasis>" time to reject some inputs."
+                },
+            ),
+            Complexity::Polynomial(degree) => RuleDiagnostic::new(
+                rule_category!(),
+                ctx.query().range(),
+                markup! {
+                    "This regular expression can take a "<Emphasis>"polynomial"</Emphasis>" time of degree "{degree}" to reject some inputs."
+                },
+            ),
+        };
+        Some(
+            diagnostic
+                .note(markup! {
+                    "A crafted input can block the event loop while the pattern backtracks."
+                })
+                .note(markup! {
+                    "Avoid nested quantifiers, such as "<Emphasis>"(a+)+"</Emphasis>", and quantified patterns that can match the same characters, such as "<Emphasis>"(a|a)+"</Emphasis>" or "<Emphasis>"\\d+\\d+"</Emphasis>"."
+                }),
+        )
+    }
+}
+
+/// The time that a backtracking engine may take to reject an input, depending on its length.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub(crate) enum Complexity {
+    /// The time is bounded by a polynomial of the given degree.
+    Polynomial(u32),
+    Exponential,
+}
+
+/// The highest code point.
+const MAX_CHAR: u32 = char::MAX as u32;
+
+/// A set of code points, stored as sorted and disjoint inclusive ranges.
+#[derive(Debug, Clone, Default)]
+struct CharSet(Vec<(u32, u32)>);
+
+impl CharSet {
+    fn new(mut ranges: Vec<(u32, u32)>) -> Self {
+        ranges.sort_unstable();
+        let mut merged: Vec<(u32, u32)> = Vec::with_capacity(ranges.len());
+        for (start, end) in ranges {
+            match merged.last_mut() {
+                Some(last) if start <= last.1.saturating_add(1) => last.1 = last.1.max(end),
+                _ => merged.push((start, end)),
+            }
+        }
+        Self(merged)
+    }
+
+    fn single(c: u32) -> Self {
+        Self(vec![(c, c)])
+    }
+
+    fn any() -> Self {
+        Self(vec![(0, MAX_CHAR)])
+    }
+
+    fn digit() -> Self {
+        Self::new(vec![(b'0'.into(), b'9'.into())])
+    }
+
+    fn word() -> Self {
+        Self::new(vec![
+            (b'0'.into(), b'9'.into()),
+            (b'A'.into(), b'Z'.into()),
+            (b'_'.into(), b'_'.into()),
+            (b'a'.into(), b'z'.into()),
+        ])
+    }
+
+    fn space() -> Self {
+        Self::new(vec![
+            (0x09, 0x0D),
+            (0x20, 0x20),
+            (0xA0, 0xA0),
+            (0x1680, 0x1680),
+            (0x2000, 0x200A),
+            (0x2028, 0x2029),
+            (0x202F, 0x202F),
+            (0x205F, 0x205F),
+            (0x3000, 0x3000),
+            (0xFEFF, 0xFEFF),
+        ])
+    }
+
+    fn line_terminator() -> Self {
+        Self::new(vec![(0x0A, 0x0A), (0x0D, 0x0D), (0x2028, 0x2029)])
+    }
+
+    fn union(&self, other: &Self) -> Self {
+        Self::new(self.0.iter().chain(&other.0).copied().collect())
+    }
+
+    fn negate(&self) -> Self {
+        let mut ranges = Vec::with_capacity(self.0.len() + 1);
+        let mut start = 0;
+        for &(range_start, range_end) in &self.0 {
+            if range_start > start {
+                ranges.push((start, range_start - 1));
+            }
+            start = range_end.saturating_add(1);
+        }
+        if start <= MAX_CHAR {
+            ranges.push((start, MAX_CHAR));
+        }
+        Self(ranges)
+    }
+
+    fn intersection(&self, other: &Self) -> Self {
+        let mut ranges = Vec::new();
+        let (mut i, mut j) = (0, 0);
+        while i < self.0.len() && j < other.0.len() {
+            let (a_start, a_end) = self.0[i];
+            let (b_start, b_end) = other.0[j];
+            let start = a_start.max(b_start);
+            let end = a_end.min(b_end);
+            if start <= end {
+                ranges.push((start, end));
+            }
+            if a_end < b_end {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+        Self(ranges)
+    }
+
+    fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Adds the other case of the ASCII letters of the set.
+    fn fold_ascii_case(&self) -> Self {
+        let lowercase = Self::new(vec![(b'a'.into(), b'z'.into())]);
+        let uppercase = Self::new(vec![(b'A'.into(), b'Z'.into())]);
+        let shift = |set: Self, to_uppercase: bool| {
+            Self(
+                set.0
+                    .into_iter()
+                    .map(|(start, end)| {
+                        if to_uppercase {
+                            (start - 0x20, end - 0x20)
+                        } else {
+                            (start + 0x20, end + 0x20)
+                        }
+                    })
+                    .collect(),
+            )
+        };
+        self.union(&shift(self.intersection(&lowercase), true))
+            .union(&shift(self.intersection(&uppercase), false))
+    }
+}
+
+/// A simplified regular expression, where the zero-width assertions and the backreferences are
+/// replaced with [Regex::Empty].
+#[derive(Debug)]
+enum Regex {
+    Empty,
+    Char(CharSet),
+    Concat(Vec<Regex>),
+    Alternation(Vec<Regex>),
+    Repeat {
+        regex: Box<Regex>,
+        min: u32,
+        max: Option<u32>,
+    },
+}
+
+enum Escape {
+    Assertion,
+    Backreference,
+    Set(CharSet),
+}
+
+/// Parses the pattern of a regular expression literal.
+/// Returns `None` for invalid or unsupported patterns.
+struct Parser {
+    chars: Vec<char>,
+    position: usize,
+    ignore_case: bool,
+    dot_all: bool,
+    unicode: bool,
+    unicode_sets: bool,
+}
+
+impl Parser {
+    fn new(pattern: &str, flags: &str) -> Self {
+        Self {
+            chars: pattern.chars().collect(),
+            position: 0,
+            ignore_case: flags.contains('i'),
+            dot_all: flags.contains('s'),
+            unicode: flags.contains('u') || flags.contains('v'),
+            unicode_sets: flags.contains('v'),
+        }
+    }
+
+    fn parse(mut self) -> Option<Regex> {
+        let regex = self.parse_disjunction()?;
+        (self.position == self.chars.len()).then_some(regex)
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.position).copied()
+    }
+
+    fn peek_nth(&self, n: usize) -> Option<char> {
+        self.chars.get(self.position + n).copied()
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.position += 1;
+        Some(c)
+    }
+
+    fn eat(&mut self, c: char) -> bool {
+        let is_present = self.peek() == Some(c);
+        if is_present {
+            self.position += 1;
+        }
+        is_present
+    }
+
+    fn parse_disjunction(&mut self) -> Option<Regex> {
+        let mut alternatives = vec![self.parse_alternative()?];
+        while self.eat('|') {
+            alternatives.push(self.parse_alternative()?);
+        }
+        Some(if alternatives.len() == 1 {
+            alternatives.pop()?
+        } else {
+            Regex::Alternation(alternatives)
+        })
+    }
+
+    fn parse_alternative(&mut self) -> Option<Regex> {
+        let mut terms = Vec::new();
+        while !matches!(self.peek(), None | Some('|' | ')')) {
+            terms.push(self.parse_term()?);
+        }
+        Some(Regex::Concat(terms))
+    }
+
+    fn parse_term(&mut self) -> Option<Regex> {
+        let atom = match self.peek()? {
+            '^' | '$' => {
+                self.bump();
+                return Some(Regex::Empty);
+            }
+            '*' | '+' | '?' => return None,
+            '{' if self.parse_braces_quantifier().is_some() => return None,
+            '(' => {
+                self.bump();
+                self.parse_group()?
+            }
+            '[' => {
+                self.bump();
+                let set = self.parse_class()?;
+                self.make_char(set)
+            }
+            '.' => {
+                self.bump();
+                if self.dot_all {
+                    Regex::Char(CharSet::any())
+                } else {
+                    Regex::Char(CharSet::line_terminator().negate())
+                }
+            }
+            '\\' => {
+                self.bump();
+                match self.parse_atom_escape()? {
+                    Escape::Assertion => return Some(Regex::Empty),
+                    Escape::Backreference => Regex::Empty,
+                    Escape::Set(set) => self.make_char(set),
+                }
+            }
+            c => {
+                self.bump();
+                self.make_char(CharSet::single(c.into()))
+            }
+        };
+        self.parse_quantifier(atom)
+    }
+
+    fn make_char(&self, set: CharSet) -> Regex {
+        if self.ignore_case {
+            Regex::Char(set.fold_ascii_case())
+        } else {
+            Regex::Char(set)
+        }
+    }
+
+    fn parse_quantifier(&mut self, atom: Regex) -> Option<Regex> {
+        let (min, max) = match self.peek() {
+            Some('*') => {
+                self.bump();
+                (0, None)
+            }
+            Some('+') => {
+                self.bump();
+                (1, None)
+            }
+            Some('?') => {
+                self.bump();
+                (0, Some(1))
+            }
+            Some('{') => match self.parse_braces_quantifier() {
+                Some((quantifier, length)) => {
+                    self.position += length;
+                    quantifier
+                }
+                None => return Some(atom),
+            },
+            _ => return Some(atom),
+        };
+        if max.is_some_and(|max| max < min) {
+            return None;
+        }
+        // Lazy quantifiers backtrack in the same way
+        self.eat('?');
+        Some(Regex::Repeat {
+            regex: Box::new(atom),
+            min,
+            max,
+        })
+    }
+
+    /// Parses a quantifier such as `{2}`, `{2,}` or `{2,3}` without consuming it.
+    /// Returns the bounds of the quantifier and its length.
+    fn parse_braces_quantifier(&self) -> Option<((u32, Option<u32>), usize)> {
+        let rest = self.chars.get(self.position..)?;
+        let end = rest.iter().position(|&c| c == '}')?;
+        let content: String = rest.get(1..end)?.iter().collect();
+        let parse_bound = |bound: &str| -> Option<u32> {
+            if bound.is_empty() || !bound.chars().all(|c| c.is_ascii_digit()) {
+                return None;
+            }
+            Some(bound.parse().unwrap_or(u32::MAX))
+        };
+        let bounds = match content.split_once(',') {
+            None => {
+                let min = parse_bound(&content)?;
+                (min, Some(min))
+            }
+            Some((min, "")) => (parse_bound(min)?, None),
+            Some((min, max)) => (parse_bound(min)?, Some(parse_bound(max)?)),
+        };
+        Some((bounds, end + 1))
+    }
+
+    fn parse_group(&mut self) -> Option<Regex> {
+        let is_lookaround = if self.peek() == Some('?') {
+            match (self.peek_nth(1), self.peek_nth(2)) {
+                (Some(':'), _) => {
+                    self.position += 2;
+                    false
+                }
+                (Some('=' | '!'), _) => {
+                    self.position += 2;
+                    true
+                }
+                (Some('<'), Some('=' | '!')) => {
+                    self.position += 3;
+                    true
+                }
+                (Some('<'), _) => {
+                    // Named capturing group
+                    while self.bump()? != '>' {}
+                    false
+                }
+                _ => return None,
+            }
+        } else {
+            false
+        };
+        let regex = self.parse_disjunction()?;
+        if !self.eat(')') {
+            return None;
+        }
+        Some(if is_lookaround { Regex::Empty } else { regex })
+    }
+
+    fn parse_atom_escape(&mut self) -> Option<Escape> {
+        let c = self.bump()?;
+        Some(match c {
+            'b' | 'B' => Escape::Assertion,
+            '1'..='9' => {
+                while self.peek().is_some_and(|c| c.is_ascii_digit()) {
+                    self.bump();
+                }
+                Escape::Backreference
+            }
+            'k' if self.peek() == Some('<') => {
+                while self.bump()? != '>' {}
+                Escape::Backreference
+            }
+            c => Escape::Set(self.parse_class_escape(c)?),
+        })
+    }
+
+    /// Parses an escape that is allowed in a character class, after the backslash.
+    fn parse_class_escape(&mut self, c: char) -> Option<CharSet> {
+        Some(match c {
+            'd' => CharSet::digit(),
+            'D' => CharSet::digit().negate(),
+            'w' => CharSet::word(),
+            'W' => CharSet::word().negate(),
+            's' => CharSet::space(),
+            'S' => CharSet::space().negate(),
+            'p' | 'P' if self.unicode && self.peek() == Some('{') => {
+                while self.bump()? != '}' {}
+                CharSet::any()
+            }
+            c => CharSet::single(self.parse_character_escape(c)),
+        })
+    }
+
+    /// Returns the code point of a character escape, after the backslash.
+    fn parse_character_escape(&mut self, c: char) -> u32 {
+        match c {
+            't' => 0x09,
+            'n' => 0x0A,
+            'v' => 0x0B,
+            'f' => 0x0C,
+            'r' => 0x0D,
+            '0' if !self.peek().is_some_and(|c| c.is_ascii_digit()) => 0,
+            'c' => match self.peek() {
+                Some(letter) if letter.is_ascii_alphabetic() => {
+                    self.bump();
+                    u32::from(letter) % 32
+                }
+                _ => c.into(),
+            },
+            'x' => self.parse_hex_digits(2).unwrap_or(c.into()),
+            'u' if self.unicode && self.peek() == Some('{') => {
+                let start = self.position;
+                self.bump();
+                let mut value: u32 = 0;
+                while let Some(digit) = self.peek().and_then(|c| c.to_digit(16)) {
+                    self.bump();
+                    value = value.saturating_mul(16).saturating_add(digit);
+                }
+                if self.eat('}') {
+                    value.min(MAX_CHAR)
+                } else {
+                    self.position = start;
+                    c.into()
+                }
+            }
+            'u' => self.parse_hex_digits(4).unwrap_or(c.into()),
+            c => c.into(),
+        }
+    }
+
+    fn parse_hex_digits(&mut self, count: usize) -> Option<u32> {
+        let mut value = 0;
+        for n in 0..count {
+            value = value * 16 + self.peek_nth(n)?.to_digit(16)?;
+        }
+        self.position += count;
+        Some(value)
+    }
+
+    fn parse_class(&mut self) -> Option<CharSet> {
+        let is_negated = self.eat('^');
+        if self.unicode_sets {
+            // Set operations and nested classes are not analyzed
+            let mut depth = 1;
+            while depth > 0 {
+                match self.bump()? {
+                    '\\' => {
+                        self.bump()?;
+                    }
+                    '[' => depth += 1,
+                    ']' => depth -= 1,
+                    _ => {}
+                }
+            }
+            return Some(CharSet::any());
+        }
+
+        let mut set = CharSet::default();
+        loop {
+            let start = match self.bump()? {
+                ']' => break,
+                '\\' => self.parse_class_atom_escape()?,
+                c => CharSet::single(c.into()),
+            };
+            if self.peek() == Some('-') && !matches!(self.peek_nth(1), None | Some(']')) {
+                self.bump();
+                let end = match self.bump()? {
+                    '\\' => self.parse_class_atom_escape()?,
+                    c => CharSet::single(c.into()),
+                };
+                match (start.0.as_slice(), end.0.as_slice()) {
+                    ([(start, start_end)], [(end, end_end)])
+                        if start == start_end && end == end_end =>
+                    {
+                        if start > end {
+                            return None;
+                        }
+                        set = set.union(&CharSet::new(vec![(*start, *end)]));
+                    }
+                    // A class escape can't be the bound of a range, `-` is then a literal
+                    _ => {
+                        set = set
+                            .union(&start)
+                            .union(&CharSet::single('-'.into()))
+                            .union(&end);
+                    }
+                }
+            } else {
+                set = set.union(&start);
+            }
+        }
+        Some(if is_negated { set.negate() } else { set })
+    }
+
+    fn parse_class_atom_escape(&mut self) -> Option<CharSet> {
+        match self.bump()? {
+            'b' => Some(CharSet::single(0x08)),
+            '-' => Some(CharSet::single('-'.into())),
+            c => self.parse_class_escape(c),
+        }
+    }
+}
+
+/// The maximum number of characters of a pattern that are analyzed.
+/// Larger patterns are ignored.
+const MAX_POSITIONS: usize = 128;
+/// Repetitions such as `a{100}` are approximated with a smaller number of mandatory copies.
+const MAX_MANDATORY_COPIES: u32 = 4;
+/// Repetitions such as `a{1,100}`, with more optional copies, are approximated with `a+`.
+const MAX_OPTIONAL_COPIES: u32 = 4;
+/// The maximum number of transitions of the product automata that are explored.
+/// Patterns that require more work are ignored.
+const MAX_PRODUCT_TRANSITIONS: usize = 200_000;
+
+/// A [Glushkov automaton](https://en.wikipedia.org/wiki/Glushkov%27s_construction_algorithm):
+/// every state is a character of the pattern, and entering a state consumes one of its characters.
+///
+/// A transition is recorded once for every way the pattern allows it.
+/// For example, `(a+)+` has two transitions from `a` to itself.
+#[derive(Default)]
+struct Automaton {
+    sets: Vec<CharSet>,
+    transitions: Vec<Vec<usize>>,
+}
+
+struct Fragment {
+    nullable: bool,
+    first: Vec<usize>,
+    last: Vec<usize>,
+}
+
+impl Fragment {
+    fn empty() -> Self {
+        Self {
+            nullable: true,
+            first: Vec::new(),
+            last: Vec::new(),
+        }
+    }
+}
+
+impl Automaton {
+    fn from_regex(regex: &Regex) -> Option<Self> {
+        let mut automaton = Self::default();
+        automaton.build(regex)?;
+        Some(automaton)
+    }
+
+    fn build(&mut self, regex: &Regex) -> Option<Fragment> {
+        match regex {
+            Regex::Empty => Some(Fragment::empty()),
+            Regex::Char(set) => {
+                if self.sets.len() == MAX_POSITIONS {
+                    return None;
+                }
+                let position = self.sets.len();
+                self.sets.push(set.clone());
+                self.transitions.push(Vec::new());
+                Some(Fragment {
+                    nullable: false,
+                    first: vec![position],
+                    last: vec![position],
+                })
+            }
+            Regex::Concat(regexes) => {
+                let mut fragment = Fragment::empty();
+                for regex in regexes {
+                    let next = self.build(regex)?;
+                    fragment = self.concat(fragment, next);
+                }
+                Some(fragment)
+            }
+            Regex::Alternation(regexes) => {
+                let mut fragment = Fragment {
+                    nullable: false,
+                    first: Vec::new(),
+                    last: Vec::new(),
+                };
+                for regex in regexes {
+                    let alternative = self.build(regex)?;
+                    fragment.nullable |= alternative.nullable;
+                    fragment.first.extend(alternative.first);
+                    fragment.last.extend(alternative.last);
+                }
+                Some(fragment)
+            }
+            Regex::Repeat { regex, min, max } => {
+                let is_unbounded = max.map_or(true, |max| max - min > MAX_OPTIONAL_COPIES);
+                let mandatory = (*min).min(MAX_MANDATORY_COPIES);
+                let mut fragment = Fragment::empty();
+                for index in 0..mandatory {
+                    let copy = self.build(regex)?;
+                    if is_unbounded && index + 1 == mandatory {
+                        self.add_loop(&copy);
+                    }
+                    fragment = self.concat(fragment, copy);
+                }
+                if is_unbounded {
+                    if mandatory == 0 {
+                        let copy = self.build(regex)?;
+                        self.add_loop(&copy);
+                        fragment = Fragment {
+                            nullable: true,
+                            ..copy
+                        };
+                    }
+                } else if let Some(max) = max {
+                    // `a{0,2}` is built as `(a(a)?)?` rather than `a?a?`,
+                    // which would be ambiguous.
+                    let mut optional: Option<Fragment> = None;
+                    for _ in *min..*max {
+                        let copy = self.build(regex)?;
+                        let mut next = match optional {
+                            Some(optional) => self.concat(copy, optional),
+                            None => copy,
+                        };
+                        next.nullable = true;
+                        optional = Some(next);
+                    }
+                    if let Some(optional) = optional {
+                        fragment = self.concat(fragment, optional);
+                    }
+                }
+                Some(fragment)
+            }
+        }
+    }
+
+    fn concat(&mut self, left: Fragment, right: Fragment) -> Fragment {
+        for &from in &left.last {
+            self.transitions[from].extend(&right.first);
+        }
+        let mut first = left.first;
+        if left.nullable {
+            first.extend(&right.first);
+        }
+        let mut last = right.last;
+        if right.nullable {
+            last.extend(&left.last);
+        }
+        Fragment {
+            nullable: left.nullable && right.nullable,
+            first,
+            last,
+        }
+    }
+
+    fn add_loop(&mut self, fragment: &Fragment) {
+        for &from in &fragment.last {
+            self.transitions[from].extend(&fragment.first);
+        }
+    }
+
+    /// Returns `None` if the analysis is too expensive.
+    fn complexity(&self) -> Option<Complexity> {
+        let state_count = self.sets.len();
+        let successors: Vec<Vec<usize>> = self
+            .transitions
+            .iter()
+            .map(|targets| {
+                let mut targets = targets.clone();
+                targets.sort_unstable();
+                targets.dedup();
+                targets
+            })
+            .collect();
+        let components = strongly_connected_components(&successors);
+        let mut component_of = vec![0; state_count];
+        for (index, component) in components.iter().enumerate() {
+            for &state in component {
+                component_of[state] = index;
+            }
+        }
+        // A component without any internal transition doesn't loop
+        let is_looping: Vec<bool> = components
+            .iter()
+            .map(|component| {
+                component.iter().any(|&state| {
+                    successors[state]
+                        .iter()
+                        .any(|&target| component_of[target] == component_of[state])
+                })
+            })
+            .collect();
+
+        let mut budget = MAX_PRODUCT_TRANSITIONS;
+        for (index, component) in components.iter().enumerate() {
+            if is_looping[index]
+                && self.has_exponential_ambiguity(component, &component_of, &mut budget)?
+            {
+                return Some(Complexity::Exponential);
+            }
+        }
+
+        // The components are sorted in reverse topological order:
+        // the longest chain of components with polynomial ambiguity is computed from the end.
+        let predecessors = reverse(&successors);
+        let mut degrees = vec![0u32; components.len()];
+        for (index, component) in components.iter().enumerate() {
+            if !is_looping[index] {
+                continue;
+            }
+            degrees[index] = 1;
+            for (other_index, other) in components.iter().enumerate().take(index) {
+                if is_looping[other_index]
+                    && degrees[other_index] + 1 > degrees[index]
+                    && self.has_polynomial_ambiguity(
+                        component,
+                        other,
+                        &successors,
+                        &predecessors,
+                        &mut budget,
+                    )?
+                {
+                    degrees[index] = degrees[other_index] + 1;
+                }
+            }
+        }
+        Some(Complexity::Polynomial(
+            degrees.into_iter().max().unwrap_or(0).max(1),
+        ))
+    }
+
+    fn overlaps(&self, a: usize, b: usize) -> bool {
+        !self.sets[a].intersection(&self.sets[b]).is_empty()
+    }
+
+    /// Returns `true` if a state of `component` can loop back to itself in two different ways
+    /// while consuming the same characters.
+    fn has_exponential_ambiguity(
+        &self,
+        component: &[usize],
+        component_of: &[usize],
+        budget: &mut usize,
+    ) -> Option<bool> {
+        // The pair `(a, b)` has the index `a * size + b`, where `a` and `b` are indexes in the component.
+        let component_index = component_of[component[0]];
+        let index_of = |state: usize| component.iter().position(|&s| s == state);
+        let size = component.len();
+        let mut pair_successors = vec![Vec::new(); size * size];
+        // Transitions from `(a, a)` to `(b, b)` that go through two different transitions from `a` to `b`
+        let mut diverging_transitions = Vec::new();
+        for (i, &a) in component.iter().enumerate() {
+            for (j, &b) in component.iter().enumerate() {
+                let from = i * size + j;
+                for (index_a, &target_a) in self.transitions[a].iter().enumerate() {
+                    let Some(k) = index_of(target_a) else {
+                        continue;
+                    };
+                    for (index_b, &target_b) in self.transitions[b].iter().enumerate() {
+                        *budget = budget.checked_sub(1)?;
+                        if component_of[target_b] != component_index
+                            || !self.overlaps(target_a, target_b)
+                        {
+                            continue;
+                        }
+                        let Some(l) = index_of(target_b) else {
+                            continue;
+                        };
+                        let to = k * size + l;
+                        if i == j && k == l {
+                            if index_a < index_b {
+                                diverging_transitions.push((from, to));
+                            }
+                            if index_a != index_b {
+                                continue;
+                            }
+                        }
+                        pair_successors[from].push(to);
+                    }
+                }
+            }
+        }
+
+        let mut pair_component = vec![0; size * size];
+        let pair_components = strongly_connected_components(&pair_successors);
+        for (index, pairs) in pair_components.iter().enumerate() {
+            for &pair in pairs {
+                pair_component[pair] = index;
+            }
+        }
+        let is_diagonal = |pair: usize| pair / size == pair % size;
+        Some(pair_components.iter().enumerate().any(|(index, pairs)| {
+            pairs.iter().any(|&pair| is_diagonal(pair))
+                && (pairs.iter().any(|&pair| !is_diagonal(pair))
+                    || diverging_transitions.iter().any(|&(from, to)| {
+                        pair_component[from] == index && pair_component[to] == index
+                    }))
+        }))
+    }
+
+    /// Returns `true` if there are two states `p` in `from` and `q` in `to`, and a word `w`,
+    /// such that `w` loops on `p`, leads from `p` to `q`, and loops on `q`.
+    fn has_polynomial_ambiguity(
+        &self,
+        from: &[usize],
+        to: &[usize],
+        successors: &[Vec<usize>],
+        predecessors: &[Vec<usize>],
+        budget: &mut usize,
+    ) -> Option<bool> {
+        let reachable_from = reachable(successors, from);
+        if !to.iter().any(|state| reachable_from.contains(state)) {
+            return Some(false);
+        }
+        let reaching_to = reachable(predecessors, to);
+        let from_set: FxHashSet<usize> = from.iter().copied().collect();
+        let to_set: FxHashSet<usize> = to.iter().copied().collect();
+
+        for &p in from {
+            for &q in to {
+                let start = (p, p, q);
+                let mut visited = FxHashSet::default();
+                let mut queue = VecDeque::from([start]);
+                visited.insert(start);
+                while let Some((x, y, z)) = queue.pop_front() {
+                    for &next_x in successors[x].iter().filter(|s| from_set.contains(s)) {
+                        for &next_y in successors[y]
+                            .iter()
+                            .filter(|s| reachable_from.contains(s) && reaching_to.contains(s))
+                        {
+                            let common = self.sets[next_x].intersection(&self.sets[next_y]);
+                            if common.is_empty() {
+                                continue;
+                            }
+                            for &next_z in successors[z].iter().filter(|s| to_set.contains(s)) {
+                                *budget = budget.checked_sub(1)?;
+                                if common.intersection(&self.sets[next_z]).is_empty() {
+                                    continue;
+                                }
+                                let next = (next_x, next_y, next_z);
+                                if next == (p, q, q) {
+                                    return Some(true);
+                                }
+                                if visited.insert(next) {
+                                    queue.push_back(next);
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        Some(false)
+    }
+}
+
+/// Returns the states reachable from `starts` with at least one transition, and `starts`.
+fn reachable(successors: &[Vec<usize>], starts: &[usize]) -> FxHashSet<usize> {
+    let mut visited: FxHashSet<usize> = starts.iter().copied().collect();
+    let mut stack = starts.to_vec();
+    while let Some(state) = stack.pop() {
+        for &next in &successors[state] {
+            if visited.insert(next) {
+                stack.push(next);
+            }
+        }
+    }
+    visited
+}
+
+fn reverse(successors: &[Vec<usize>]) -> Vec<Vec<usize>> {
+    let mut predecessors = vec![Vec::new(); successors.len()];
+    for (state, targets) in successors.iter().enumerate() {
+        for &target in targets {
+            predecessors[target].push(state);
+        }
+    }
+    predecessors
+}
+
+/// Returns the strongly connected components of the graph, in reverse topological order.
+/// This is an iterative version of Tarjan's algorithm.
+fn strongly_connected_components(successors: &[Vec<usize>]) -> Vec<Vec<usize>> {
+    let node_count = successors.len();
+    let mut index_of = vec![usize::MAX; node_count];
+    let mut low_link = vec![0; node_count];
+    let mut on_stack = vec![false; node_count];
+    let mut stack = Vec::new();
+    let mut components = Vec::new();
+    let mut next_index = 0;
+
+    for root in 0..node_count {
+        if index_of[root] != usize::MAX {
+            continue;
+        }
+        // Each frame holds a node and the index of its next successor to visit
+        let mut frames = vec![(root, 0)];
+        while let Some(&mut (node, ref mut successor_index)) = frames.last_mut() {
+            if *successor_index == 0 && index_of[node] == usize::MAX {
+                index_of[node] = next_index;
+                low_link[node] = next_index;
+                next_index += 1;
+                stack.push(node);
+                on_stack[node] = true;
+            }
+            if let Some(&successor) = successors[node].get(*successor_index) {
+                *successor_index += 1;
+                if index_of[successor] == usize::MAX {
+                    frames.push((successor, 0));
+                } else if on_stack[successor] {
+                    low_link[node] = low_link[node].min(index_of[successor]);
+                }
+                continue;
+            }
+            frames.pop();
+            if let Some(&(parent, _)) = frames.last() {
+                low_link[parent] = low_link[parent].min(low_link[node]);
+            }
+            if low_link[node] == index_of[node] {
+                let mut component = Vec::new();
+                while let Some(member) = stack.pop() {
+                    on_stack[member] = false;
+                    component.push(member);
+                    if member == node {
+                        break;
+                    }
+                }
+                components.push(component);
+            }
+        }
+    }
+    components
+}
+
+/// Options for the rule `noUnsafeRegex`.
+#[derive(Deserialize, Serialize, Eq, PartialEq, Debug, Clone, Bpaf)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct UnsafeRegexOptions {
+    /// The highest complexity that is allowed. Anything higher is reported.
+    pub max_safe_complexity: u8,
+}
+
+impl Default for UnsafeRegexOptions {
+    fn default() -> Self {
+        Self {
+            max_safe_complexity: 1,
+        }
+    }
+}
+
+impl UnsafeRegexOptions {
+    pub(crate) const KNOWN_KEYS: &'static [&'static str] = &["maxSafeComplexity"];
+}
+
+// Required by [Bpaf].
+impl FromStr for UnsafeRegexOptions {
+    type Err = &'static str;
+
+    fn from_str(_s: &str) -> Result<Self, Self::Err> {
+        // WARNING: should not be used.
+        Ok(Self::default())
+    }
+}
+
+impl VisitNode<JsonLanguage> for UnsafeRegexOptions {
+    fn visit_member_name(
+        &mut self,
+        node: &SyntaxNode<JsonLanguage>,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<()> {
+        has_only_known_keys(node, Self::KNOWN_KEYS, diagnostics)
+    }
+
+    fn visit_map(
+        &mut self,
+        key: &SyntaxNode<JsonLanguage>,
+        value: &SyntaxNode<JsonLanguage>,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<()> {
+        let (name, value) = self.get_key_and_value(key, value, diagnostics)?;
+        let name_text = name.text();
+        if name_text == "maxSafeComplexity" {
+            let max_safe_complexity = self.map_to_u8(&value, name_text, u8::MAX, diagnostics)?;
+            // Every pattern has at least a linear complexity
+            if max_safe_complexity == 0 {
+                diagnostics.push(
+                    DeserializationDiagnostic::new(markup! {
+                        "The field "<Emphasis>"maxSafeComplexity"</Emphasis>" must be greater than 0"
+                    })
+                    .with_range(value.range()),
+                );
+                return None;
+            }
+            self.max_safe_complexity = max_safe_complexity;
+        }
+
+        Some(())
+    }
+}
@@ -0,0 +1,91 @@
+use biome_analyze::{context::RuleContext, declare_rule, Ast, Rule, RuleDiagnostic};
+use biome_console::markup;
+use biome_js_syntax::{AnyJsRoot, TextRange, TextSize};
+use biome_rowan::{AstNode, Direction};
+
+declare_rule! {
+    /// Disallow block comments that aren't closed.
+    ///
+    /// A block comment that starts with `/*` and has no matching `*/` extends to the end of the file,
+    /// and thus comments out all the code that follows it.
+    /// The parser reports the unterminated comment as an error,
+    /// but this error can be overlooked when the command is configured to continue despite errors.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// The parser also reports the following code as invalid, so the example isn't checked.
+    ///
+    /// ```js,ignore
+    /// const a = 1;
+    /// /* The following code is commented out
+    /// const b = 2;
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// const a = 1;
+    /// /* A block comment */
+    /// const b = 2;
+    /// ```
+    pub(crate) NoUnterminatedMultilineComment {
+        version: "next",
+        name: "noUnterminatedMultilineComment",
+        recommended: false,
+    }
+}
+
+impl Rule for NoUnterminatedMultilineComment {
+    type Query = Ast<AnyJsRoot>;
+    type State = TextRange;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        // An unterminated comment extends to the end of the file: it's always the last comment
+        ctx.query()
+            .syntax()
+            .descendants_tokens(Direction::Next)
+            .flat_map(|token| {
+                token
+                    .leading_trivia()
+                    .pieces()
+                    .chain(token.trailing_trivia().pieces())
+            })
+            .filter(|piece| piece.is_comments())
+            .last()
+            .filter(|comment| is_unterminated_block_comment(comment.text()))
+            .map(|comment| comment.text_range())
+    }
+
+    fn diagnostic(_: &RuleContext<Self>, range: &Self::State) -> Option<RuleDiagnostic> {
+        let opening = TextRange::at(range.start(), TextSize::from(2));
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                opening,
+                markup! {
+                    "This block comment is never closed."
+                },
+            )
+            .detail(
+                TextRange::empty(range.end()),
+                markup! {
+                    "The comment extends to the end of the file, and comments out all the code that follows it."
+                },
+            )
+            .note(markup! {
+                "Close the comment with "<Emphasis>"*/"</Emphasis>", or convert it to "<Emphasis>"//"</Emphasis>" comments."
+            }),
+        )
+    }
+}
+
+/// Returns `true` if `comment` starts with `/*` and doesn't end with a matching `*/`.
+///
+/// The comment `/*/` isn't closed: its `*/` overlaps with the opening `/*`.
+fn is_unterminated_block_comment(comment: &str) -> bool {
+    comment.starts_with("/*") && (comment.len() < 4 || !comment.ends_with("*/"))
+}
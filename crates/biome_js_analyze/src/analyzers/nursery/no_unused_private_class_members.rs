@@ -0,0 +1,280 @@
+use biome_analyze::{context::RuleContext, declare_rule, Ast, Rule, RuleDiagnostic};
+use biome_console::markup;
+use biome_js_syntax::{
+    AnyJsClassMember, AnyJsClassMemberName, JsAssignmentExpression, JsAssignmentOperator,
+    JsClassMemberList, JsExpressionStatement, JsForStatement, JsPostUpdateExpression,
+    JsPreUpdateExpression, JsPrivateName, JsStaticMemberAssignment, JsSyntaxKind, JsSyntaxNode,
+    TextRange,
+};
+use biome_rowan::{AstNode, AstNodeList};
+use rustc_hash::FxHashMap;
+
+declare_rule! {
+    /// Disallow private class members that are never used.
+    ///
+    /// A private field, method, or accessor can only be used inside the class body that declares it.
+    /// A private member that is never used is dead code, and is often a leftover of a refactoring.
+    ///
+    /// A private field is considered as unused when its value is never read, even if it is written.
+    /// A private getter is unused when it is never read, and a private setter is unused when it is never written.
+    /// Thus, the rule reports the half of a getter and setter pair that is never used.
+    /// Reading a private member only to update it, such as in `this.#count++;`, doesn't count as a use.
+    ///
+    /// Source: https://eslint.org/docs/latest/rules/no-unused-private-class-members
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,expect_diagnostic
+    /// class Counter {
+    ///     #count = 0;
+    /// }
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// class Counter {
+    ///     #count = 0;
+    ///     increment() {
+    ///         this.#count++;
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// class Counter {
+    ///     #reset() {}
+    /// }
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// class Counter {
+    ///     get #value() { return 0; }
+    ///     set #value(value) {}
+    ///     read() {
+    ///         return this.#value;
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// class Counter {
+    ///     #count = 0;
+    ///     increment() {
+    ///         return ++this.#count;
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ```js
+    /// class Counter {
+    ///     static #instances = 0;
+    ///     static isCounter(value) {
+    ///         return #instances in value || Counter.#instances > 0;
+    ///     }
+    /// }
+    /// ```
+    ///
+    pub(crate) NoUnusedPrivateClassMembers {
+        version: "next",
+        name: "noUnusedPrivateClassMembers",
+        recommended: false,
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub(crate) enum PrivateMemberKind {
+    Field,
+    Method,
+    Getter,
+    Setter,
+}
+
+pub(crate) struct UnusedPrivateMember {
+    name: String,
+    range: TextRange,
+    kind: PrivateMemberKind,
+    /// Whether the member is referenced in the class, without being used.
+    is_referenced: bool,
+}
+
+/// How the value of a private member is accessed.
+#[derive(Debug, Default)]
+struct PrivateNameUsages {
+    reads: usize,
+    writes: usize,
+    /// References that are neither reads nor writes, such as `#name in object`.
+    checks: usize,
+}
+
+impl Rule for NoUnusedPrivateClassMembers {
+    type Query = Ast<JsClassMemberList>;
+    type State = UnusedPrivateMember;
+    type Signals = Vec<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let members = ctx.query();
+        let declarations = private_members(members);
+        if declarations.is_empty() {
+            return Vec::new();
+        }
+        let mut usages: FxHashMap<String, PrivateNameUsages> = FxHashMap::default();
+        for reference in members
+            .syntax()
+            .descendants()
+            .filter_map(JsPrivateName::cast)
+        {
+            let Ok(name) = reference.value_token() else {
+                continue;
+            };
+            let name = name.text_trimmed();
+            if !declarations.iter().any(|(member, ..)| member == name)
+                || is_shadowed(reference.syntax(), members.syntax(), name)
+            {
+                continue;
+            }
+            let usage = usages.entry(name.to_string()).or_default();
+            match access_kind(reference.syntax()) {
+                AccessKind::Read => usage.reads += 1,
+                AccessKind::Write => usage.writes += 1,
+                AccessKind::Check => usage.checks += 1,
+            }
+        }
+        declarations
+            .into_iter()
+            .filter_map(|(name, range, kind)| {
+                let usage = usages.get(&name);
+                let is_used = usage.is_some_and(|usage| {
+                    usage.checks > 0
+                        || match kind {
+                            PrivateMemberKind::Setter => usage.writes > 0,
+                            _ => usage.reads > 0,
+                        }
+                });
+                (!is_used).then_some(UnusedPrivateMember {
+                    name,
+                    range,
+                    kind,
+                    is_referenced: usage.is_some(),
+                })
+            })
+            .collect()
+    }
+
+    fn diagnostic(_: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let name = format!("#{}", state.name);
+        let kind = match state.kind {
+            PrivateMemberKind::Field => "field",
+            PrivateMemberKind::Method => "method",
+            PrivateMemberKind::Getter => "getter",
+            PrivateMemberKind::Setter => "setter",
+        };
+        let message = match (state.kind, state.is_referenced) {
+            (_, false) => markup! {
+                "The private "{kind}" "<Emphasis>{name}</Emphasis>" is never used."
+            }
+            .to_owned(),
+            (PrivateMemberKind::Setter, true) => markup! {
+                "The private "{kind}" "<Emphasis>{name}</Emphasis>" is never assigned."
+            }
+            .to_owned(),
+            (_, true) => markup! {
+                "The private "{kind}" "<Emphasis>{name}</Emphasis>" is never read."
+            }
+            .to_owned(),
+        };
+        Some(
+            RuleDiagnostic::new(rule_category!(), state.range, message).note(markup! {
+                "Private members can only be used inside their class: remove this member if it isn't needed."
+            }),
+        )
+    }
+}
+
+/// Returns the name, the range of the name, and the kind of every private member declared in `members`.
+fn private_members(members: &JsClassMemberList) -> Vec<(String, TextRange, PrivateMemberKind)> {
+    members
+        .iter()
+        .filter_map(|member| {
+            let kind = match &member {
+                AnyJsClassMember::JsPropertyClassMember(_) => PrivateMemberKind::Field,
+                AnyJsClassMember::JsMethodClassMember(_) => PrivateMemberKind::Method,
+                AnyJsClassMember::JsGetterClassMember(_) => PrivateMemberKind::Getter,
+                AnyJsClassMember::JsSetterClassMember(_) => PrivateMemberKind::Setter,
+                _ => return None,
+            };
+            let AnyJsClassMemberName::JsPrivateClassMemberName(name) = member.name().ok()?? else {
+                return None;
+            };
+            let id = name.id_token().ok()?;
+            Some((id.text_trimmed().to_string(), name.range(), kind))
+        })
+        .collect()
+}
+
+/// Returns `true` if `reference` refers to a private member named `name`
+/// of a class nested in the class of `members`.
+fn is_shadowed(reference: &JsSyntaxNode, members: &JsSyntaxNode, name: &str) -> bool {
+    reference
+        .ancestors()
+        .take_while(|ancestor| ancestor != members)
+        .filter_map(JsClassMemberList::cast)
+        .any(|nested| {
+            private_members(&nested)
+                .iter()
+                .any(|(member, ..)| member == name)
+        })
+}
+
+enum AccessKind {
+    Read,
+    Write,
+    Check,
+}
+
+/// Returns how the value of the private member is accessed by `reference`.
+fn access_kind(reference: &JsSyntaxNode) -> AccessKind {
+    let Some(parent) = reference.parent() else {
+        return AccessKind::Read;
+    };
+    if parent.kind() == JsSyntaxKind::JS_IN_EXPRESSION {
+        return AccessKind::Check;
+    }
+    if !JsStaticMemberAssignment::can_cast(parent.kind()) {
+        return AccessKind::Read;
+    }
+    let Some(grand_parent) = parent.parent() else {
+        return AccessKind::Write;
+    };
+    // `this.#name += 1` and `this.#name++` read the value only to update it,
+    // unless the result of the expression is used.
+    let is_update = if let Some(assignment) = JsAssignmentExpression::cast_ref(&grand_parent) {
+        assignment.operator().ok() != Some(JsAssignmentOperator::Assign)
+    } else {
+        JsPreUpdateExpression::can_cast(grand_parent.kind())
+            || JsPostUpdateExpression::can_cast(grand_parent.kind())
+    };
+    if is_update && !is_result_unused(&grand_parent) {
+        AccessKind::Read
+    } else {
+        AccessKind::Write
+    }
+}
+
+/// Returns `true` if the value of `expression` is discarded.
+fn is_result_unused(expression: &JsSyntaxNode) -> bool {
+    let mut node = expression.clone();
+    while let Some(parent) = node.parent() {
+        if parent.kind() != JsSyntaxKind::JS_PARENTHESIZED_EXPRESSION {
+            return JsExpressionStatement::can_cast(parent.kind())
+                || JsForStatement::cast(parent)
+                    .and_then(|statement| statement.update())
+                    .is_some_and(|update| update.syntax() == &node);
+        }
+        node = parent;
+    }
+    false
+}
@@ -0,0 +1,140 @@
+use biome_analyze::{context::RuleContext, declare_rule, Ast, Rule, RuleDiagnostic};
+use biome_console::markup;
+use biome_js_syntax::suppression::{is_format_suppression_end, is_format_suppression_start};
+use biome_js_syntax::{AnyJsRoot, TextRange};
+use biome_rowan::{AstNode, Direction};
+
+declare_rule! {
+    /// Disallow format suppression regions that have no effect.
+    ///
+    /// The formatter preserves the code between a `// biome-ignore-format-start` comment
+    /// and the next `// biome-ignore-format-end` comment.
+    /// An end comment closes the innermost region that is still open.
+    ///
+    /// The rule reports:
+    ///
+    /// - regions that don't contain any code that the formatter could preserve;
+    /// - start comments without a matching end comment, which the formatter ignores;
+    /// - end comments without a matching start comment.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,expect_diagnostic
+    /// // biome-ignore-format-start
+    /// // biome-ignore-format-end
+    /// const a = 1;
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// // biome-ignore-format-start
+    /// const   table   =   [];
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// const a = 1;
+    /// // biome-ignore-format-end
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// // biome-ignore-format-start
+    /// const   table   =   [];
+    /// // biome-ignore-format-end
+    /// ```
+    ///
+    pub(crate) NoUselessFormatSuppressionRegion {
+        version: "next",
+        name: "noUselessFormatSuppressionRegion",
+        recommended: false,
+    }
+}
+
+pub(crate) enum UselessRegion {
+    /// The region between the start and the end comments doesn't contain any node.
+    Empty { start: TextRange, end: TextRange },
+    /// A start comment without a matching end comment.
+    UnmatchedStart(TextRange),
+    /// An end comment without a matching start comment.
+    UnmatchedEnd(TextRange),
+}
+
+impl Rule for NoUselessFormatSuppressionRegion {
+    type Query = Ast<AnyJsRoot>;
+    type State = UselessRegion;
+    type Signals = Vec<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let root = ctx.query().syntax();
+        let mut signals = Vec::new();
+        let mut open_regions = Vec::new();
+        for token in root.descendants_tokens(Direction::Next) {
+            let comments = token
+                .leading_trivia()
+                .pieces()
+                .chain(token.trailing_trivia().pieces())
+                .filter_map(|piece| piece.as_comments());
+            for comment in comments {
+                let text = comment.text();
+                if is_format_suppression_start(text) {
+                    open_regions.push(comment.text_range());
+                } else if is_format_suppression_end(text) {
+                    let end = comment.text_range();
+                    let Some(start) = open_regions.pop() else {
+                        signals.push(UselessRegion::UnmatchedEnd(end));
+                        continue;
+                    };
+                    let region = TextRange::new(start.end(), end.start());
+                    let has_node = root.descendants().any(|node| {
+                        let range = node.text_trimmed_range();
+                        !range.is_empty() && region.contains_range(range)
+                    });
+                    if !has_node {
+                        signals.push(UselessRegion::Empty { start, end });
+                    }
+                }
+            }
+        }
+        signals.extend(open_regions.into_iter().map(UselessRegion::UnmatchedStart));
+        signals
+    }
+
+    fn diagnostic(_: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let diagnostic = match state {
+            UselessRegion::Empty { start, end } => RuleDiagnostic::new(
+                rule_category!(),
+                start.cover(*end),
+                markup! {
+                    "This format suppression region doesn't contain any code."
+                },
+            )
+            .note(markup! {
+                "Remove the suppression comments, or move them around the code that shouldn't be formatted."
+            }),
+            UselessRegion::UnmatchedStart(start) => RuleDiagnostic::new(
+                rule_category!(),
+                start,
+                markup! {
+                    "This format suppression region is never closed."
+                },
+            )
+            .note(markup! {
+                "The formatter ignores the region until a "<Emphasis>"// biome-ignore-format-end"</Emphasis>" comment follows the code that shouldn't be formatted."
+            }),
+            UselessRegion::UnmatchedEnd(end) => RuleDiagnostic::new(
+                rule_category!(),
+                end,
+                markup! {
+                    "This comment doesn't close any format suppression region."
+                },
+            )
+            .note(markup! {
+                "Remove this comment, or add a "<Emphasis>"// biome-ignore-format-start"</Emphasis>" comment before the code that shouldn't be formatted."
+            }),
+        };
+        Some(diagnostic)
+    }
+}
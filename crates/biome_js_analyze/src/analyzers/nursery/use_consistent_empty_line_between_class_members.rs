@@ -0,0 +1,438 @@
+use crate::JsRuleAction;
+use biome_analyze::context::RuleContext;
+use biome_analyze::{declare_rule, ActionCategory, Ast, FixKind, Rule, RuleDiagnostic};
+use biome_console::markup;
+use biome_deserialize::json::{has_only_known_keys, with_only_known_variants, VisitJsonNode};
+use biome_deserialize::{DeserializationDiagnostic, VisitNode};
+use biome_diagnostics::Applicability;
+use biome_js_syntax::{
+    AnyJsClassMember, JsClassMemberList, JsLanguage, JsSyntaxToken, TextRange, TriviaPieceKind, T,
+};
+use biome_json_syntax::JsonLanguage;
+use biome_rowan::{AstNode, BatchMutationExt, Direction, SyntaxNode, SyntaxTriviaPiece};
+use bpaf::Bpaf;
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+declare_rule! {
+    /// Enforce or disallow empty lines between class members.
+    ///
+    /// An empty line between class members makes them easier to tell apart,
+    /// while some code bases prefer compact classes.
+    /// This rule reports class members that are not separated from the previous member
+    /// according to the configured policy.
+    ///
+    /// An empty line is always expected before the decorators of a member, not between the decorators and the member.
+    /// The rule doesn't check a getter and a setter that share the same name,
+    /// and the overload signatures of a method.
+    ///
+    /// Source: https://eslint.org/docs/latest/rules/lines-between-class-members
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,expect_diagnostic
+    /// class Point {
+    ///     x = 0;
+    ///     y = 0;
+    /// }
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// class Counter {
+    ///     #count = 0;
+    ///     increment() {
+    ///         this.#count++;
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// class Point {
+    ///     x = 0;
+    ///
+    ///     y = 0;
+    /// }
+    /// ```
+    ///
+    /// ```js
+    /// class Temperature {
+    ///     get celsius() {
+    ///         return this.value;
+    ///     }
+    ///     set celsius(value) {
+    ///         this.value = value;
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// The option `emptyLine` accepts `"always"` (default) or `"never"`.
+    /// With `"never"`, the rule reports empty lines between class members.
+    ///
+    /// ```json
+    /// {
+    ///     "//": "...",
+    ///     "options": {
+    ///         "emptyLine": "never"
+    ///     }
+    /// }
+    /// ```
+    ///
+    pub(crate) UseConsistentEmptyLineBetweenClassMembers {
+        version: "next",
+        name: "useConsistentEmptyLineBetweenClassMembers",
+        recommended: false,
+        fix_kind: FixKind::Safe,
+    }
+}
+
+impl Rule for UseConsistentEmptyLineBetweenClassMembers {
+    type Query = Ast<JsClassMemberList>;
+    type State = ClassMemberSeparation;
+    type Signals = Vec<Self::State>;
+    type Options = EmptyLineBetweenClassMembersOptions;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let policy = ctx.options().empty_line;
+        let mut signals = Vec::new();
+        let mut previous: Option<AnyJsClassMember> = None;
+        for member in ctx.query() {
+            if matches!(member, AnyJsClassMember::JsEmptyClassMember(_)) {
+                continue;
+            }
+            let Some(previous) = previous.replace(member.clone()) else {
+                continue;
+            };
+            if is_accessor_pair(&previous, &member) || is_overload(&previous, &member) {
+                continue;
+            }
+            let Some(first_token) = member.syntax().first_token() else {
+                continue;
+            };
+            let has_empty_line = has_empty_line_before(&first_token);
+            if has_empty_line != (policy == EmptyLinePolicy::Always) {
+                signals.push(ClassMemberSeparation {
+                    previous,
+                    member,
+                    policy,
+                });
+            }
+        }
+        signals
+    }
+
+    fn diagnostic(_: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let range = member_range(&state.member)?;
+        let message = match state.policy {
+            EmptyLinePolicy::Always => markup! {
+                "Expected an empty line before this class member."
+            },
+            EmptyLinePolicy::Never => markup! {
+                "Unexpected empty line before this class member."
+            },
+        };
+        Some(RuleDiagnostic::new(rule_category!(), range, message))
+    }
+
+    fn action(ctx: &RuleContext<Self>, state: &Self::State) -> Option<JsRuleAction> {
+        let first_token = state.member.syntax().first_token()?;
+        let pieces: Vec<_> = first_token.leading_trivia().pieces().collect();
+        let (new_trivia, message) = match state.policy {
+            EmptyLinePolicy::Always => {
+                let previous_token = state.previous.syntax().first_token()?;
+                (
+                    add_empty_line(&pieces, &previous_token),
+                    markup! { "Add an empty line before this class member." }.to_owned(),
+                )
+            }
+            EmptyLinePolicy::Never => (
+                remove_empty_lines(&pieces),
+                markup! { "Remove the empty lines before this class member." }.to_owned(),
+            ),
+        };
+        let new_token = first_token.with_leading_trivia(
+            new_trivia
+                .iter()
+                .map(|(kind, text)| (*kind, text.as_str()))
+                .collect::<Vec<_>>(),
+        );
+        let mut mutation = ctx.root().begin();
+        if !pieces.iter().any(|piece| piece.is_newline()) {
+            // The member is moved to its own line: trim the whitespaces left after the previous member.
+            let last_token = state.previous.syntax().last_token()?;
+            let mut trailing_trivia: Vec<_> = last_token.trailing_trivia().pieces().collect();
+            while trailing_trivia
+                .last()
+                .map_or(false, |piece| piece.is_whitespace())
+            {
+                trailing_trivia.pop();
+            }
+            let new_last_token = last_token.with_trailing_trivia_pieces(trailing_trivia);
+            mutation.replace_token_discard_trivia(last_token, new_last_token);
+        }
+        mutation.replace_token_discard_trivia(first_token, new_token);
+        Some(JsRuleAction {
+            category: ActionCategory::QuickFix,
+            applicability: Applicability::Always,
+            message,
+            mutation,
+        })
+    }
+}
+
+pub(crate) struct ClassMemberSeparation {
+    /// The member that precedes [Self::member]
+    previous: AnyJsClassMember,
+    /// The reported member
+    member: AnyJsClassMember,
+    policy: EmptyLinePolicy,
+}
+
+/// Returns the range of the name of `member`, or the range of its first token.
+fn member_range(member: &AnyJsClassMember) -> Option<TextRange> {
+    match member.name().ok().flatten() {
+        Some(name) => Some(name.range()),
+        None => Some(member.syntax().first_token()?.text_trimmed_range()),
+    }
+}
+
+/// Returns `true` if the leading trivia of `token` contains at least two consecutive line breaks.
+fn has_empty_line_before(token: &JsSyntaxToken) -> bool {
+    let mut newlines = 0;
+    for piece in token.leading_trivia().pieces() {
+        if piece.is_newline() {
+            newlines += 1;
+            if newlines > 1 {
+                return true;
+            }
+        } else if !piece.is_whitespace() {
+            newlines = 0;
+        }
+    }
+    false
+}
+
+type OwnedTrivia = Vec<(TriviaPieceKind, String)>;
+
+fn to_owned_trivia<'a>(
+    pieces: impl IntoIterator<Item = &'a SyntaxTriviaPiece<JsLanguage>>,
+) -> OwnedTrivia {
+    pieces
+        .into_iter()
+        .map(|piece| (piece.kind(), piece.text().to_string()))
+        .collect()
+}
+
+/// Inserts an empty line at the start of `pieces`.
+///
+/// When the member is on the same line as the previous one,
+/// it is moved to a new line with the indentation of `previous_token`.
+fn add_empty_line(
+    pieces: &[SyntaxTriviaPiece<JsLanguage>],
+    previous_token: &JsSyntaxToken,
+) -> OwnedTrivia {
+    if let Some(index) = pieces.iter().position(|piece| piece.is_newline()) {
+        let mut trivia = to_owned_trivia(&pieces[..index]);
+        trivia.push((TriviaPieceKind::Newline, pieces[index].text().to_string()));
+        trivia.extend(to_owned_trivia(&pieces[index..]));
+        return trivia;
+    }
+    let previous_pieces: Vec<_> = previous_token.leading_trivia().pieces().collect();
+    let newline = previous_pieces
+        .iter()
+        .find(|piece| piece.is_newline())
+        .map_or("\n", |piece| piece.text());
+    let indentation = previous_pieces
+        .iter()
+        .rev()
+        .take_while(|piece| piece.is_whitespace())
+        .collect::<Vec<_>>();
+    let mut trivia = vec![
+        (TriviaPieceKind::Newline, newline.to_string()),
+        (TriviaPieceKind::Newline, newline.to_string()),
+    ];
+    trivia.extend(to_owned_trivia(indentation.into_iter().rev()));
+    trivia.extend(to_owned_trivia(
+        pieces.iter().skip_while(|piece| piece.is_whitespace()),
+    ));
+    trivia
+}
+
+/// Removes every line break that follows another line break, and the whitespace between them.
+fn remove_empty_lines(pieces: &[SyntaxTriviaPiece<JsLanguage>]) -> OwnedTrivia {
+    let mut trivia = Vec::with_capacity(pieces.len());
+    let mut pending_whitespaces = Vec::new();
+    let mut last_is_newline = false;
+    for piece in pieces {
+        if piece.is_newline() {
+            if last_is_newline {
+                pending_whitespaces.clear();
+                continue;
+            }
+            trivia.append(&mut pending_whitespaces);
+            last_is_newline = true;
+        } else if piece.is_whitespace() {
+            pending_whitespaces.push((piece.kind(), piece.text().to_string()));
+            continue;
+        } else {
+            trivia.append(&mut pending_whitespaces);
+            last_is_newline = false;
+        }
+        trivia.push((piece.kind(), piece.text().to_string()));
+    }
+    trivia.append(&mut pending_whitespaces);
+    trivia
+}
+
+/// Returns `true` if `first` and `second` are a getter and a setter of the same property.
+fn is_accessor_pair(first: &AnyJsClassMember, second: &AnyJsClassMember) -> bool {
+    let is_getter = |member: &AnyJsClassMember| {
+        matches!(
+            member,
+            AnyJsClassMember::JsGetterClassMember(_)
+                | AnyJsClassMember::TsGetterSignatureClassMember(_)
+        )
+    };
+    let is_setter = |member: &AnyJsClassMember| {
+        matches!(
+            member,
+            AnyJsClassMember::JsSetterClassMember(_)
+                | AnyJsClassMember::TsSetterSignatureClassMember(_)
+        )
+    };
+    ((is_getter(first) && is_setter(second)) || (is_setter(first) && is_getter(second)))
+        && have_same_name(first, second)
+}
+
+/// Returns `true` if `first` is an overload signature of the method `second`.
+fn is_overload(first: &AnyJsClassMember, second: &AnyJsClassMember) -> bool {
+    matches!(first, AnyJsClassMember::TsMethodSignatureClassMember(_))
+        && matches!(
+            second,
+            AnyJsClassMember::JsMethodClassMember(_)
+                | AnyJsClassMember::TsMethodSignatureClassMember(_)
+        )
+        && have_same_name(first, second)
+}
+
+fn have_same_name(first: &AnyJsClassMember, second: &AnyJsClassMember) -> bool {
+    let name = |member: &AnyJsClassMember| {
+        member
+            .name()
+            .ok()
+            .flatten()
+            .map(|name| name.syntax().text_trimmed().to_string())
+    };
+    let first_name = name(first);
+    first_name.is_some() && first_name == name(second) && is_static(first) == is_static(second)
+}
+
+fn is_static(member: &AnyJsClassMember) -> bool {
+    // The modifiers are the first child of the members that have a name.
+    member
+        .syntax()
+        .first_child()
+        .map_or(false, |modifiers: SyntaxNode<JsLanguage>| {
+            modifiers
+                .descendants_tokens(Direction::Next)
+                .any(|token| token.kind() == T![static])
+        })
+}
+
+/// Options for the rule `useConsistentEmptyLineBetweenClassMembers`.
+#[derive(Default, Deserialize, Serialize, Eq, PartialEq, Debug, Clone, Bpaf)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct EmptyLineBetweenClassMembersOptions {
+    /// Whether class members must be separated by an empty line.
+    #[bpaf(hide)]
+    #[serde(default)]
+    pub empty_line: EmptyLinePolicy,
+}
+
+impl EmptyLineBetweenClassMembersOptions {
+    pub(crate) const KNOWN_KEYS: &'static [&'static str] = &["emptyLine"];
+}
+
+// Required by [Bpaf].
+impl FromStr for EmptyLineBetweenClassMembersOptions {
+    type Err = &'static str;
+
+    fn from_str(_s: &str) -> Result<Self, Self::Err> {
+        // WARNING: should not be used.
+        Ok(Self::default())
+    }
+}
+
+impl VisitNode<JsonLanguage> for EmptyLineBetweenClassMembersOptions {
+    fn visit_member_name(
+        &mut self,
+        node: &SyntaxNode<JsonLanguage>,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<()> {
+        has_only_known_keys(node, Self::KNOWN_KEYS, diagnostics)
+    }
+
+    fn visit_map(
+        &mut self,
+        key: &SyntaxNode<JsonLanguage>,
+        value: &SyntaxNode<JsonLanguage>,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<()> {
+        let (name, value) = self.get_key_and_value(key, value, diagnostics)?;
+        let name_text = name.text();
+        if name_text == "emptyLine" {
+            let mut empty_line = EmptyLinePolicy::default();
+            self.map_to_known_string(&value, name_text, &mut empty_line, diagnostics)?;
+            self.empty_line = empty_line;
+        }
+        Some(())
+    }
+}
+
+/// Whether an empty line is required or forbidden between class members.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub enum EmptyLinePolicy {
+    /// Class members must be separated by an empty line
+    #[default]
+    Always,
+    /// Class members must not be separated by empty lines
+    Never,
+}
+
+impl EmptyLinePolicy {
+    pub const KNOWN_VALUES: &'static [&'static str] = &["always", "never"];
+}
+
+// Required by [Bpaf].
+impl FromStr for EmptyLinePolicy {
+    type Err = &'static str;
+
+    fn from_str(_s: &str) -> Result<Self, Self::Err> {
+        // WARNING: should not be used.
+        Ok(Self::default())
+    }
+}
+
+impl VisitNode<JsonLanguage> for EmptyLinePolicy {
+    fn visit_member_value(
+        &mut self,
+        node: &SyntaxNode<JsonLanguage>,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<()> {
+        let node = with_only_known_variants(node, Self::KNOWN_VALUES, diagnostics)?;
+        match node.inner_string_text().ok()?.text() {
+            "always" => *self = Self::Always,
+            "never" => *self = Self::Never,
+            _ => (),
+        }
+        Some(())
+    }
+}
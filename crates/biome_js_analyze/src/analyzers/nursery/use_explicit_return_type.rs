@@ -0,0 +1,658 @@
+use crate::JsRuleAction;
+use biome_analyze::{
+    context::RuleContext, declare_rule, ActionCategory, Ast, FixKind, Rule, RuleDiagnostic,
+};
+use biome_console::markup;
+use biome_deserialize::json::{has_only_known_keys, VisitJsonNode};
+use biome_deserialize::{DeserializationDiagnostic, VisitNode};
+use biome_diagnostics::Applicability;
+use biome_js_factory::make;
+use biome_js_syntax::{
+    AnyJsArrowFunctionParameters, AnyJsClassMemberName, AnyJsExpression, AnyJsFunctionBody,
+    AnyJsMethodModifier, AnyTsMethodSignatureModifier, AnyTsName, AnyTsReturnType, AnyTsType,
+    JsArrowFunctionExpression, JsFileSource, JsFunctionDeclaration,
+    JsFunctionExportDefaultDeclaration, JsFunctionExpression, JsMethodClassMember, JsParameters,
+    JsReturnStatement, JsSyntaxKind, JsSyntaxNode, JsUnaryOperator, TextRange,
+    TsDeclareFunctionDeclaration, TsDeclareFunctionExportDefaultDeclaration,
+    TsMethodSignatureClassMember, TsReturnTypeAnnotation, T,
+};
+use biome_json_syntax::JsonLanguage;
+use biome_rowan::{
+    declare_node_union, AstNode, AstNodeList, BatchMutationExt, SyntaxNode, TriviaPieceKind,
+};
+use bpaf::Bpaf;
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+declare_rule! {
+    /// Require explicit return types on the functions and methods of the public API.
+    ///
+    /// Without an explicit return type, the type seen by the callers of a function is inferred from its implementation.
+    /// A change in the implementation can then silently change the public API of a module.
+    ///
+    /// The rule reports the following functions when they don't declare a return type:
+    ///
+    /// - functions declared with `export` or `export default`,
+    ///   including the functions assigned to an exported variable;
+    /// - the public methods of exported classes;
+    /// - the overload signatures of these functions and methods.
+    ///
+    /// The return type of an `async` function is a `Promise<T>`,
+    /// and the return type of a generator function is a `Generator<T>`.
+    ///
+    /// The fix adds the return type when it can be trivially inferred:
+    /// `void` when the function doesn't return a value, and `boolean` when it only returns comparisons or negations.
+    ///
+    /// The rule only applies to TypeScript files.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```ts,expect_diagnostic
+    /// export function isEmpty(list: unknown[]) {
+    ///     return list.length === 0;
+    /// }
+    /// ```
+    ///
+    /// ```ts,expect_diagnostic
+    /// export const log = async (message: string) => {
+    ///     console.log(message);
+    /// };
+    /// ```
+    ///
+    /// ```ts,expect_diagnostic
+    /// export class Cache {
+    ///     get(key: string) {
+    ///         return this.map.get(key);
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ```ts,expect_diagnostic
+    /// export function parse(input: string): number;
+    /// export function parse(input: number);
+    /// export function parse(input: string | number): number {
+    ///     return Number(input);
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```ts
+    /// export function isEmpty(list: unknown[]): boolean {
+    ///     return list.length === 0;
+    /// }
+    /// ```
+    ///
+    /// ```ts
+    /// export async function load(url: string): Promise<Response> {
+    ///     return fetch(url);
+    /// }
+    /// ```
+    ///
+    /// ```ts
+    /// function helper() {
+    ///     return 42;
+    /// }
+    /// ```
+    ///
+    /// ```ts
+    /// export class Cache {
+    ///     private clear() {
+    ///         this.map.clear();
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// The functions and methods that aren't exported, and the private and protected methods,
+    /// are checked when the option `publicOnly` is set to `false`.
+    /// It defaults to `true`.
+    ///
+    /// ```json
+    /// {
+    ///     "//": "...",
+    ///     "options": {
+    ///         "publicOnly": false
+    ///     }
+    /// }
+    /// ```
+    ///
+    pub(crate) UseExplicitReturnType {
+        version: "next",
+        name: "useExplicitReturnType",
+        recommended: false,
+        fix_kind: FixKind::Safe,
+    }
+}
+
+declare_node_union! {
+    pub(crate) AnyJsFunctionSignature =
+        JsFunctionDeclaration
+        | JsFunctionExportDefaultDeclaration
+        | JsFunctionExpression
+        | JsArrowFunctionExpression
+        | JsMethodClassMember
+        | TsMethodSignatureClassMember
+        | TsDeclareFunctionDeclaration
+        | TsDeclareFunctionExportDefaultDeclaration
+}
+
+impl AnyJsFunctionSignature {
+    fn return_type_annotation(&self) -> Option<TsReturnTypeAnnotation> {
+        match self {
+            AnyJsFunctionSignature::JsFunctionDeclaration(node) => node.return_type_annotation(),
+            AnyJsFunctionSignature::JsFunctionExportDefaultDeclaration(node) => {
+                node.return_type_annotation()
+            }
+            AnyJsFunctionSignature::JsFunctionExpression(node) => node.return_type_annotation(),
+            AnyJsFunctionSignature::JsArrowFunctionExpression(node) => {
+                node.return_type_annotation()
+            }
+            AnyJsFunctionSignature::JsMethodClassMember(node) => node.return_type_annotation(),
+            AnyJsFunctionSignature::TsMethodSignatureClassMember(node) => {
+                node.return_type_annotation()
+            }
+            AnyJsFunctionSignature::TsDeclareFunctionDeclaration(node) => {
+                node.return_type_annotation()
+            }
+            AnyJsFunctionSignature::TsDeclareFunctionExportDefaultDeclaration(node) => {
+                node.return_type_annotation()
+            }
+        }
+    }
+
+    fn is_async(&self) -> bool {
+        match self {
+            AnyJsFunctionSignature::JsFunctionDeclaration(node) => node.async_token().is_some(),
+            AnyJsFunctionSignature::JsFunctionExportDefaultDeclaration(node) => {
+                node.async_token().is_some()
+            }
+            AnyJsFunctionSignature::JsFunctionExpression(node) => node.async_token().is_some(),
+            AnyJsFunctionSignature::JsArrowFunctionExpression(node) => node.async_token().is_some(),
+            AnyJsFunctionSignature::JsMethodClassMember(node) => node.async_token().is_some(),
+            AnyJsFunctionSignature::TsMethodSignatureClassMember(node) => {
+                node.async_token().is_some()
+            }
+            AnyJsFunctionSignature::TsDeclareFunctionDeclaration(node) => {
+                node.async_token().is_some()
+            }
+            AnyJsFunctionSignature::TsDeclareFunctionExportDefaultDeclaration(node) => {
+                node.async_token().is_some()
+            }
+        }
+    }
+
+    fn is_generator(&self) -> bool {
+        match self {
+            AnyJsFunctionSignature::JsFunctionDeclaration(node) => node.star_token().is_some(),
+            AnyJsFunctionSignature::JsFunctionExportDefaultDeclaration(node) => {
+                node.star_token().is_some()
+            }
+            AnyJsFunctionSignature::JsFunctionExpression(node) => node.star_token().is_some(),
+            AnyJsFunctionSignature::JsMethodClassMember(node) => node.star_token().is_some(),
+            AnyJsFunctionSignature::JsArrowFunctionExpression(_)
+            | AnyJsFunctionSignature::TsMethodSignatureClassMember(_)
+            | AnyJsFunctionSignature::TsDeclareFunctionDeclaration(_)
+            | AnyJsFunctionSignature::TsDeclareFunctionExportDefaultDeclaration(_) => false,
+        }
+    }
+
+    /// Returns the parameters of the function, if they are enclosed in parentheses
+    fn parameters(&self) -> Option<JsParameters> {
+        match self {
+            AnyJsFunctionSignature::JsFunctionDeclaration(node) => node.parameters().ok(),
+            AnyJsFunctionSignature::JsFunctionExportDefaultDeclaration(node) => {
+                node.parameters().ok()
+            }
+            AnyJsFunctionSignature::JsFunctionExpression(node) => node.parameters().ok(),
+            AnyJsFunctionSignature::JsArrowFunctionExpression(node) => {
+                match node.parameters().ok()? {
+                    AnyJsArrowFunctionParameters::JsParameters(parameters) => Some(parameters),
+                    AnyJsArrowFunctionParameters::AnyJsBinding(_) => None,
+                }
+            }
+            AnyJsFunctionSignature::JsMethodClassMember(node) => node.parameters().ok(),
+            AnyJsFunctionSignature::TsMethodSignatureClassMember(node) => node.parameters().ok(),
+            AnyJsFunctionSignature::TsDeclareFunctionDeclaration(node) => node.parameters().ok(),
+            AnyJsFunctionSignature::TsDeclareFunctionExportDefaultDeclaration(node) => {
+                node.parameters().ok()
+            }
+        }
+    }
+
+    /// Returns the range from the start of the function to the end of its parameters
+    fn signature_range(&self) -> TextRange {
+        let range = self.range();
+        let end = match self {
+            AnyJsFunctionSignature::JsArrowFunctionExpression(node) => node
+                .parameters()
+                .ok()
+                .map(|parameters| parameters.range().end()),
+            _ => self.parameters().map(|parameters| parameters.range().end()),
+        };
+        end.map_or(range, |end| TextRange::new(range.start(), end))
+    }
+
+    fn body(&self) -> Option<AnyJsFunctionBody> {
+        match self {
+            AnyJsFunctionSignature::JsFunctionDeclaration(node) => {
+                node.body().ok().map(AnyJsFunctionBody::from)
+            }
+            AnyJsFunctionSignature::JsFunctionExportDefaultDeclaration(node) => {
+                node.body().ok().map(AnyJsFunctionBody::from)
+            }
+            AnyJsFunctionSignature::JsFunctionExpression(node) => {
+                node.body().ok().map(AnyJsFunctionBody::from)
+            }
+            AnyJsFunctionSignature::JsArrowFunctionExpression(node) => node.body().ok(),
+            AnyJsFunctionSignature::JsMethodClassMember(node) => {
+                node.body().ok().map(AnyJsFunctionBody::from)
+            }
+            AnyJsFunctionSignature::TsMethodSignatureClassMember(_)
+            | AnyJsFunctionSignature::TsDeclareFunctionDeclaration(_)
+            | AnyJsFunctionSignature::TsDeclareFunctionExportDefaultDeclaration(_) => None,
+        }
+    }
+
+    /// Returns `true` if the function is part of the public API of the module
+    fn is_public(&self) -> bool {
+        match self {
+            AnyJsFunctionSignature::JsFunctionDeclaration(_)
+            | AnyJsFunctionSignature::TsDeclareFunctionDeclaration(_) => {
+                is_exported_declaration(self.syntax())
+            }
+            AnyJsFunctionSignature::JsFunctionExportDefaultDeclaration(_)
+            | AnyJsFunctionSignature::TsDeclareFunctionExportDefaultDeclaration(_) => true,
+            AnyJsFunctionSignature::JsFunctionExpression(_)
+            | AnyJsFunctionSignature::JsArrowFunctionExpression(_) => {
+                is_exported_expression(self.syntax())
+            }
+            AnyJsFunctionSignature::JsMethodClassMember(method) => {
+                let is_public_method = !is_private_name(method.name().ok().as_ref())
+                    && method.modifiers().iter().all(|modifier| {
+                        !matches!(
+                            modifier,
+                            AnyJsMethodModifier::TsAccessibilityModifier(modifier)
+                                if modifier.is_private() || modifier.is_protected()
+                        )
+                    });
+                is_public_method && is_exported_class_member(method.syntax())
+            }
+            AnyJsFunctionSignature::TsMethodSignatureClassMember(method) => {
+                let is_public_method = !is_private_name(method.name().ok().as_ref())
+                    && method.modifiers().iter().all(|modifier| {
+                        !matches!(
+                            modifier,
+                            AnyTsMethodSignatureModifier::TsAccessibilityModifier(modifier)
+                                if modifier.is_private() || modifier.is_protected()
+                        )
+                    });
+                is_public_method && is_exported_class_member(method.syntax())
+            }
+        }
+    }
+
+    /// Returns `true` if the rule checks the function when the option `publicOnly` is disabled.
+    ///
+    /// Function expressions that aren't assigned to a variable, such as callbacks, are never checked.
+    fn is_named(&self) -> bool {
+        match self {
+            AnyJsFunctionSignature::JsFunctionExpression(_)
+            | AnyJsFunctionSignature::JsArrowFunctionExpression(_) => {
+                is_exported_expression(self.syntax()) || is_variable_initializer(self.syntax())
+            }
+            _ => true,
+        }
+    }
+}
+
+/// The return type that is added by the fix
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub(crate) enum InferredReturnType {
+    Void,
+    Boolean,
+}
+
+impl Rule for UseExplicitReturnType {
+    type Query = Ast<AnyJsFunctionSignature>;
+    type State = Option<InferredReturnType>;
+    type Signals = Option<Self::State>;
+    type Options = ExplicitReturnTypeOptions;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        if !ctx.source_type::<JsFileSource>().language().is_typescript() {
+            return None;
+        }
+        let node = ctx.query();
+        if node.return_type_annotation().is_some() {
+            return None;
+        }
+        let is_checked = if ctx.options().public_only {
+            node.is_public()
+        } else {
+            node.is_named()
+        };
+        if !is_checked {
+            return None;
+        }
+        let inferred_type = if node.is_generator() {
+            None
+        } else {
+            node.body().and_then(|body| infer_return_type(&body))
+        };
+        Some(inferred_type)
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, _: &Self::State) -> Option<RuleDiagnostic> {
+        let node = ctx.query();
+        let diagnostic = RuleDiagnostic::new(
+            rule_category!(),
+            node.signature_range(),
+            markup! {
+                "This function doesn't declare its return type."
+            },
+        )
+        .note(markup! {
+            "Without an explicit return type, a change in the implementation can silently change the type seen by the callers."
+        });
+        let diagnostic = if node.is_generator() {
+            diagnostic.note(markup! {
+                "The return type of a generator function is a "<Emphasis>"Generator<T>"</Emphasis>", or an "<Emphasis>"AsyncGenerator<T>"</Emphasis>" when the function is "<Emphasis>"async"</Emphasis>"."
+            })
+        } else if node.is_async() {
+            diagnostic.note(markup! {
+                "The return type of an "<Emphasis>"async"</Emphasis>" function is a "<Emphasis>"Promise<T>"</Emphasis>"."
+            })
+        } else {
+            diagnostic
+        };
+        Some(diagnostic)
+    }
+
+    fn action(ctx: &RuleContext<Self>, inferred_type: &Self::State) -> Option<JsRuleAction> {
+        let inferred_type = (*inferred_type)?;
+        let node = ctx.query();
+        let parameters = node.parameters()?;
+        let r_paren_token = parameters.r_paren_token().ok()?;
+
+        let ty = match inferred_type {
+            InferredReturnType::Void => AnyTsType::from(make::ts_void_type(make::token(T![void]))),
+            InferredReturnType::Boolean => {
+                AnyTsType::from(make::ts_boolean_type(make::token(T![boolean])))
+            }
+        };
+        let ty = if node.is_async() {
+            AnyTsType::from(
+                make::ts_reference_type(AnyTsName::JsReferenceIdentifier(
+                    make::js_reference_identifier(make::ident("Promise")),
+                ))
+                .with_type_arguments(make::ts_type_arguments(
+                    make::token(T![<]),
+                    make::ts_type_argument_list([ty], []),
+                    make::token(T![>]),
+                ))
+                .build(),
+            )
+        } else {
+            ty
+        };
+        let type_text = ty.text();
+        // The trivia that follows `)` is moved after the return type
+        let annotation = make::ts_return_type_annotation(
+            make::token(T![:]).with_trailing_trivia([(TriviaPieceKind::Whitespace, " ")]),
+            AnyTsReturnType::AnyTsType(ty),
+        )
+        .with_trailing_trivia_pieces(r_paren_token.trailing_trivia().pieces())?;
+        let parameters =
+            parameters.with_r_paren_token(r_paren_token.with_trailing_trivia_pieces([]));
+
+        let new_node = match node {
+            AnyJsFunctionSignature::JsFunctionDeclaration(node) => AnyJsFunctionSignature::from(
+                node.clone()
+                    .with_parameters(parameters)
+                    .with_return_type_annotation(Some(annotation)),
+            ),
+            AnyJsFunctionSignature::JsFunctionExportDefaultDeclaration(node) => {
+                AnyJsFunctionSignature::from(
+                    node.clone()
+                        .with_parameters(parameters)
+                        .with_return_type_annotation(Some(annotation)),
+                )
+            }
+            AnyJsFunctionSignature::JsFunctionExpression(node) => AnyJsFunctionSignature::from(
+                node.clone()
+                    .with_parameters(parameters)
+                    .with_return_type_annotation(Some(annotation)),
+            ),
+            AnyJsFunctionSignature::JsArrowFunctionExpression(node) => {
+                AnyJsFunctionSignature::from(
+                    node.clone()
+                        .with_parameters(AnyJsArrowFunctionParameters::from(parameters))
+                        .with_return_type_annotation(Some(annotation)),
+                )
+            }
+            AnyJsFunctionSignature::JsMethodClassMember(node) => AnyJsFunctionSignature::from(
+                node.clone()
+                    .with_parameters(parameters)
+                    .with_return_type_annotation(Some(annotation)),
+            ),
+            AnyJsFunctionSignature::TsMethodSignatureClassMember(_)
+            | AnyJsFunctionSignature::TsDeclareFunctionDeclaration(_)
+            | AnyJsFunctionSignature::TsDeclareFunctionExportDefaultDeclaration(_) => return None,
+        };
+
+        let mut mutation = ctx.root().begin();
+        mutation.replace_node(node.clone(), new_node);
+        Some(JsRuleAction {
+            category: ActionCategory::QuickFix,
+            applicability: Applicability::Always,
+            message: markup! { "Add the return type "<Emphasis>{type_text}</Emphasis>"." }
+                .to_owned(),
+            mutation,
+        })
+    }
+}
+
+/// Returns `true` if `node` is a declaration preceded by `export`.
+fn is_exported_declaration(node: &JsSyntaxNode) -> bool {
+    let Some(parent) = node.parent() else {
+        return false;
+    };
+    match parent.kind() {
+        JsSyntaxKind::JS_EXPORT => true,
+        // `export declare function f(): void;`
+        JsSyntaxKind::TS_DECLARE_STATEMENT => parent
+            .parent()
+            .is_some_and(|grand_parent| grand_parent.kind() == JsSyntaxKind::JS_EXPORT),
+        _ => false,
+    }
+}
+
+/// Returns `true` if `node` is an expression that is exported with `export default`,
+/// or that initializes an exported variable.
+fn is_exported_expression(node: &JsSyntaxNode) -> bool {
+    let Some(parent) = node.parent() else {
+        return false;
+    };
+    match parent.kind() {
+        JsSyntaxKind::JS_EXPORT_DEFAULT_EXPRESSION_CLAUSE => true,
+        JsSyntaxKind::JS_INITIALIZER_CLAUSE => {
+            // initializer > declarator > declarator list > declaration > declaration clause > export
+            let mut ancestors = parent.ancestors().skip(4);
+            ancestors
+                .next()
+                .is_some_and(|clause| clause.kind() == JsSyntaxKind::JS_VARIABLE_DECLARATION_CLAUSE)
+                && ancestors
+                    .next()
+                    .is_some_and(|export| export.kind() == JsSyntaxKind::JS_EXPORT)
+        }
+        _ => false,
+    }
+}
+
+/// Returns `true` if `node` is an expression that initializes a variable.
+fn is_variable_initializer(node: &JsSyntaxNode) -> bool {
+    node.parent()
+        .filter(|parent| parent.kind() == JsSyntaxKind::JS_INITIALIZER_CLAUSE)
+        .and_then(|parent| parent.parent())
+        .is_some_and(|grand_parent| grand_parent.kind() == JsSyntaxKind::JS_VARIABLE_DECLARATOR)
+}
+
+/// Returns `true` if `member` belongs to an exported class.
+fn is_exported_class_member(member: &JsSyntaxNode) -> bool {
+    // member > member list > class
+    let Some(class) = member.grand_parent() else {
+        return false;
+    };
+    match class.kind() {
+        JsSyntaxKind::JS_CLASS_DECLARATION => is_exported_declaration(&class),
+        JsSyntaxKind::JS_CLASS_EXPORT_DEFAULT_DECLARATION => true,
+        JsSyntaxKind::JS_CLASS_EXPRESSION => is_exported_expression(&class),
+        _ => false,
+    }
+}
+
+fn is_private_name(name: Option<&AnyJsClassMemberName>) -> bool {
+    matches!(
+        name,
+        Some(AnyJsClassMemberName::JsPrivateClassMemberName(_))
+    )
+}
+
+/// Infers the return type of a function from its body,
+/// if every returned value is trivially a boolean, or if no value is returned.
+fn infer_return_type(body: &AnyJsFunctionBody) -> Option<InferredReturnType> {
+    let block = match body {
+        AnyJsFunctionBody::AnyJsExpression(expression) => {
+            return is_boolean_expression(expression).then_some(InferredReturnType::Boolean);
+        }
+        AnyJsFunctionBody::JsFunctionBody(block) => block,
+    };
+    let function = block.syntax().parent();
+    let mut inferred_type = InferredReturnType::Void;
+    let mut has_empty_return = false;
+    for return_statement in block
+        .syntax()
+        .descendants()
+        .filter_map(JsReturnStatement::cast)
+    {
+        let is_own_return = return_statement
+            .syntax()
+            .ancestors()
+            .find(|ancestor| is_function_boundary(ancestor.kind()))
+            == function;
+        if !is_own_return {
+            continue;
+        }
+        match return_statement.argument() {
+            Some(argument) if is_boolean_expression(&argument) => {
+                inferred_type = InferredReturnType::Boolean;
+            }
+            Some(_) => return None,
+            None => has_empty_return = true,
+        }
+    }
+    // `return;` can't be mixed with returned values
+    if has_empty_return && inferred_type != InferredReturnType::Void {
+        return None;
+    }
+    Some(inferred_type)
+}
+
+/// Returns `true` if a `return` statement in a node of this kind doesn't return from the enclosing function
+fn is_function_boundary(kind: JsSyntaxKind) -> bool {
+    matches!(
+        kind,
+        JsSyntaxKind::JS_FUNCTION_DECLARATION
+            | JsSyntaxKind::JS_FUNCTION_EXPORT_DEFAULT_DECLARATION
+            | JsSyntaxKind::JS_FUNCTION_EXPRESSION
+            | JsSyntaxKind::JS_ARROW_FUNCTION_EXPRESSION
+            | JsSyntaxKind::JS_METHOD_CLASS_MEMBER
+            | JsSyntaxKind::JS_METHOD_OBJECT_MEMBER
+            | JsSyntaxKind::JS_GETTER_CLASS_MEMBER
+            | JsSyntaxKind::JS_GETTER_OBJECT_MEMBER
+            | JsSyntaxKind::JS_SETTER_CLASS_MEMBER
+            | JsSyntaxKind::JS_SETTER_OBJECT_MEMBER
+            | JsSyntaxKind::JS_CONSTRUCTOR_CLASS_MEMBER
+    )
+}
+
+/// Returns `true` if `expression` always evaluates to a boolean.
+fn is_boolean_expression(expression: &AnyJsExpression) -> bool {
+    match expression.clone().omit_parentheses() {
+        AnyJsExpression::AnyJsLiteralExpression(literal) => {
+            literal.as_js_boolean_literal_expression().is_some()
+        }
+        AnyJsExpression::JsUnaryExpression(expression) => {
+            expression.operator() == Ok(JsUnaryOperator::LogicalNot)
+        }
+        AnyJsExpression::JsBinaryExpression(expression) => expression.is_comparison_operator(),
+        AnyJsExpression::JsInExpression(_) | AnyJsExpression::JsInstanceofExpression(_) => true,
+        _ => false,
+    }
+}
+
+/// Options for the rule `useExplicitReturnType`.
+#[derive(Deserialize, Serialize, Eq, PartialEq, Debug, Clone, Bpaf)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct ExplicitReturnTypeOptions {
+    /// If `true`, only the functions and methods of the public API are checked.
+    #[bpaf(hide)]
+    #[serde(default = "default_public_only")]
+    pub public_only: bool,
+}
+
+const fn default_public_only() -> bool {
+    true
+}
+
+impl Default for ExplicitReturnTypeOptions {
+    fn default() -> Self {
+        Self {
+            public_only: default_public_only(),
+        }
+    }
+}
+
+impl ExplicitReturnTypeOptions {
+    pub(crate) const KNOWN_KEYS: &'static [&'static str] = &["publicOnly"];
+}
+
+// Required by [Bpaf].
+impl FromStr for ExplicitReturnTypeOptions {
+    type Err = &'static str;
+
+    fn from_str(_s: &str) -> Result<Self, Self::Err> {
+        // WARNING: should not be used.
+        Ok(Self::default())
+    }
+}
+
+impl VisitNode<JsonLanguage> for ExplicitReturnTypeOptions {
+    fn visit_member_name(
+        &mut self,
+        node: &SyntaxNode<JsonLanguage>,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<()> {
+        has_only_known_keys(node, Self::KNOWN_KEYS, diagnostics)
+    }
+
+    fn visit_map(
+        &mut self,
+        key: &SyntaxNode<JsonLanguage>,
+        value: &SyntaxNode<JsonLanguage>,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<()> {
+        let (name, value) = self.get_key_and_value(key, value, diagnostics)?;
+        let name_text = name.text();
+        if name_text == "publicOnly" {
+            self.public_only = self.map_to_boolean(&value, name_text, diagnostics)?;
+        }
+
+        Some(())
+    }
+}
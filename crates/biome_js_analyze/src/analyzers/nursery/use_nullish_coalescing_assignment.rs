@@ -0,0 +1,188 @@
+use crate::JsRuleAction;
+use biome_analyze::{
+    context::RuleContext, declare_rule, ActionCategory, Ast, FixKind, Rule, RuleDiagnostic,
+};
+use biome_console::markup;
+use biome_diagnostics::Applicability;
+use biome_js_factory::make;
+use biome_js_syntax::{
+    AnyJsAssignment, AnyJsExpression, JsAssignmentExpression, JsAssignmentOperator,
+    JsLogicalExpression, JsLogicalOperator, JsSyntaxKind, JsSyntaxNode, T,
+};
+use biome_rowan::{AstNode, BatchMutationExt, Direction};
+
+declare_rule! {
+    /// Require logical assignment operators where possible.
+    ///
+    /// ES2021 introduced the logical assignment operators `??=`, `||=`, and `&&=`.
+    /// An assignment such as `x = x ?? y` can be shortened to `x ??= y`.
+    ///
+    /// The rule reports assignments whose right-hand side is a logical expression
+    /// that has the assigned identifier or member as its left operand.
+    /// Assignments to a target with possible side effects, such as `obj[f()]`, are ignored.
+    ///
+    /// Note that `x ??= y` only assigns `x` when `x` is nullish, whereas `x = x ?? y` always assigns `x`.
+    /// This makes a difference when `x` is a property with a setter.
+    ///
+    /// Logical assignment operators are not supported by runtimes older than ES2021.
+    /// Don't enable this rule if your code must run in such runtimes without being transpiled.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,expect_diagnostic
+    /// x = x ?? y;
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// obj.prop = obj.prop || defaultValue;
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// flags[0] = flags[0] && isEnabled;
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// x ??= y;
+    /// ```
+    ///
+    /// ```js
+    /// x = y ?? x;
+    /// ```
+    ///
+    /// ```js
+    /// obj[f()] = obj[f()] ?? y;
+    /// ```
+    ///
+    pub(crate) UseNullishCoalescingAssignment {
+        version: "next",
+        name: "useNullishCoalescingAssignment",
+        recommended: false,
+        fix_kind: FixKind::Unsafe,
+    }
+}
+
+pub(crate) struct RuleState {
+    shorthand_operator: JsSyntaxKind,
+    logical_expression: JsLogicalExpression,
+}
+
+impl Rule for UseNullishCoalescingAssignment {
+    type Query = Ast<JsAssignmentExpression>;
+    type State = RuleState;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let node = ctx.query();
+        if !matches!(node.operator(), Ok(JsAssignmentOperator::Assign)) {
+            return None;
+        }
+
+        let left = node.left().ok()?;
+        let assignment = left.as_any_js_assignment()?;
+        if !matches!(
+            assignment,
+            AnyJsAssignment::JsIdentifierAssignment(_)
+                | AnyJsAssignment::JsStaticMemberAssignment(_)
+                | AnyJsAssignment::JsComputedMemberAssignment(_)
+        ) || has_side_effects(assignment.syntax())
+        {
+            return None;
+        }
+
+        let logical_expression = match node.right().ok()?.omit_parentheses() {
+            AnyJsExpression::JsLogicalExpression(logical_expression) => logical_expression,
+            _ => return None,
+        };
+        let shorthand_operator = match logical_expression.operator().ok()? {
+            JsLogicalOperator::NullishCoalescing => T![??=],
+            JsLogicalOperator::LogicalOr => T![||=],
+            JsLogicalOperator::LogicalAnd => T![&&=],
+        };
+
+        let operand = logical_expression.left().ok()?.omit_parentheses();
+        is_same_reference(assignment.syntax(), operand.syntax()).then_some(RuleState {
+            shorthand_operator,
+            logical_expression,
+        })
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let node = ctx.query();
+        let shorthand_operator = state.shorthand_operator.to_string()?;
+
+        Some(RuleDiagnostic::new(
+            rule_category!(),
+            node.range(),
+            markup! {
+                "Assignment "<Emphasis>"(=)"</Emphasis>" can be replaced with the logical assignment "<Emphasis>{shorthand_operator}</Emphasis>"."
+            },
+        ))
+    }
+
+    fn action(ctx: &RuleContext<Self>, state: &Self::State) -> Option<JsRuleAction> {
+        let node = ctx.query();
+        let mut mutation = ctx.root().begin();
+        let shorthand_operator = state.shorthand_operator;
+
+        let operator_token = node.operator_token().ok()?;
+        let token = make::token(shorthand_operator)
+            .with_leading_trivia_pieces(operator_token.leading_trivia().pieces())
+            .with_trailing_trivia_pieces(operator_token.trailing_trivia().pieces());
+
+        let shorthand_node = node
+            .clone()
+            .with_operator_token_token(token)
+            .with_right(state.logical_expression.right().ok()?);
+
+        mutation.replace_node(node.clone(), shorthand_node);
+
+        Some(JsRuleAction {
+            category: ActionCategory::QuickFix,
+            applicability: Applicability::MaybeIncorrect,
+            message:
+                markup! { "Use "<Emphasis>{shorthand_operator.to_string()?}</Emphasis>" instead." }
+                    .to_owned(),
+            mutation,
+        })
+    }
+}
+
+/// Returns `true` if evaluating `assignment` twice may have side effects.
+fn has_side_effects(assignment: &JsSyntaxNode) -> bool {
+    assignment.descendants().any(|node| {
+        matches!(
+            node.kind(),
+            JsSyntaxKind::JS_CALL_EXPRESSION
+                | JsSyntaxKind::JS_NEW_EXPRESSION
+                | JsSyntaxKind::JS_IMPORT_CALL_EXPRESSION
+                | JsSyntaxKind::JS_TEMPLATE_EXPRESSION
+                | JsSyntaxKind::JS_ASSIGNMENT_EXPRESSION
+                | JsSyntaxKind::JS_PRE_UPDATE_EXPRESSION
+                | JsSyntaxKind::JS_POST_UPDATE_EXPRESSION
+                | JsSyntaxKind::JS_AWAIT_EXPRESSION
+                | JsSyntaxKind::JS_YIELD_EXPRESSION
+        )
+    })
+}
+
+/// Returns `true` if the assignment target and the expression are made of the same tokens.
+fn is_same_reference(assignment: &JsSyntaxNode, expression: &JsSyntaxNode) -> bool {
+    let mut assignment_tokens = assignment.descendants_tokens(Direction::Next);
+    let mut expression_tokens = expression.descendants_tokens(Direction::Next);
+    loop {
+        match (assignment_tokens.next(), expression_tokens.next()) {
+            (Some(left), Some(right)) => {
+                if left.text_trimmed() != right.text_trimmed() {
+                    return false;
+                }
+            }
+            (None, None) => return true,
+            _ => return false,
+        }
+    }
+}
@@ -0,0 +1,194 @@
+use crate::JsRuleAction;
+use biome_analyze::{
+    context::RuleContext, declare_rule, ActionCategory, Ast, FixKind, Rule, RuleDiagnostic,
+};
+use biome_console::markup;
+use biome_diagnostics::Applicability;
+use biome_js_factory::make;
+use biome_js_syntax::{
+    AnyJsExpression, AnyJsObjectMember, AnyJsObjectMemberName, JsFunctionExpression,
+    JsPropertyObjectMember, JsReferenceIdentifier, JsSyntaxKind, TriviaPieceKind, T,
+};
+use biome_rowan::{AstNode, BatchMutationExt};
+
+declare_rule! {
+    /// Require the shorthand syntax for the properties and the methods of object literals.
+    ///
+    /// A property whose value is a variable of the same name can be written `{ foo }`
+    /// instead of `{ foo: foo }`.
+    /// A property whose value is an anonymous function expression can be written as a method,
+    /// such as `{ foo() {} }` instead of `{ foo: function() {} }`.
+    /// The `async` functions and the generators become `async` and generator methods,
+    /// and the methods with a computed name keep their name, such as `{ [key]() {} }`.
+    ///
+    /// The following properties aren't reported:
+    /// - the arrow functions, which don't have their own `this`;
+    /// - the named function expressions, whose name can be referenced in their body;
+    /// - the properties with a computed name whose value is a variable, such as `{ [key]: key }`,
+    ///   because `{ key }` would use the name of the variable as key instead of its value;
+    /// - the `__proto__` properties, which set the prototype of the object instead of defining a property.
+    ///
+    /// The property shorthand is a safe fix.
+    /// The method shorthand is unsafe, because a method can't be called with `new`.
+    ///
+    /// Source: https://eslint.org/docs/latest/rules/object-shorthand
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,expect_diagnostic
+    /// const user = { name: name };
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// const handlers = { click: function(event) {} };
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// const api = { load: async function() {} };
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// const iterable = { values: function*() {} };
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// const user = { name, greet() {} };
+    /// ```
+    ///
+    /// ```js
+    /// const handlers = { click: (event) => {}, reset: function reset() {} };
+    /// ```
+    ///
+    /// ```js
+    /// const lookup = { [key]: key };
+    /// ```
+    ///
+    pub(crate) UseObjectShorthand {
+        version: "next",
+        name: "useObjectShorthand",
+        recommended: false,
+        fix_kind: FixKind::Safe,
+    }
+}
+
+pub(crate) enum ObjectShorthand {
+    /// The value is a variable with the same name as the property.
+    Property(JsReferenceIdentifier),
+    /// The value is an anonymous function expression.
+    Method(JsFunctionExpression),
+}
+
+impl Rule for UseObjectShorthand {
+    type Query = Ast<JsPropertyObjectMember>;
+    type State = ObjectShorthand;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let member = ctx.query();
+        let name = member.name().ok()?;
+        if let AnyJsObjectMemberName::JsLiteralMemberName(literal) = &name {
+            let key = literal.value().ok()?;
+            if key.text_trimmed() == "__proto__" {
+                return None;
+            }
+        }
+        match member.value().ok()? {
+            AnyJsExpression::JsIdentifierExpression(identifier) => {
+                let AnyJsObjectMemberName::JsLiteralMemberName(literal) = name else {
+                    return None;
+                };
+                let key = literal.value().ok()?;
+                let reference = identifier.name().ok()?;
+                (key.kind() == JsSyntaxKind::IDENT
+                    && key.text_trimmed() == reference.value_token().ok()?.text_trimmed())
+                .then_some(ObjectShorthand::Property(reference))
+            }
+            AnyJsExpression::JsFunctionExpression(function) if function.id().is_none() => {
+                Some(ObjectShorthand::Method(function))
+            }
+            _ => None,
+        }
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let message = match state {
+            ObjectShorthand::Property(_) => markup! {
+                "This property can be written with the property shorthand."
+            },
+            ObjectShorthand::Method(_) => markup! {
+                "This function can be written with the method shorthand."
+            },
+        };
+        Some(RuleDiagnostic::new(
+            rule_category!(),
+            ctx.query().range(),
+            message,
+        ))
+    }
+
+    fn action(ctx: &RuleContext<Self>, state: &Self::State) -> Option<JsRuleAction> {
+        let member = ctx.query();
+        let mut mutation = ctx.root().begin();
+        match state {
+            ObjectShorthand::Property(reference) => {
+                let shorthand = make::js_shorthand_property_object_member(reference.clone());
+                mutation.replace_node(
+                    AnyJsObjectMember::from(member.clone()),
+                    AnyJsObjectMember::from(shorthand),
+                );
+                Some(JsRuleAction {
+                    category: ActionCategory::QuickFix,
+                    applicability: Applicability::Always,
+                    message: markup! { "Use the property shorthand." }.to_owned(),
+                    mutation,
+                })
+            }
+            ObjectShorthand::Method(function) => {
+                // The trivia of the member is transferred to the first token of the method.
+                let name = AnyJsObjectMemberName::cast(
+                    member
+                        .name()
+                        .ok()?
+                        .into_syntax()
+                        .with_leading_trivia_pieces([])?
+                        .with_trailing_trivia_pieces([])?,
+                )?;
+                let mut method = make::js_method_object_member(
+                    name,
+                    function.parameters().ok()?,
+                    function.body().ok()?,
+                );
+                if function.async_token().is_some() {
+                    method = method.with_async_token(
+                        make::token(T![async])
+                            .with_trailing_trivia([(TriviaPieceKind::Whitespace, " ")]),
+                    );
+                }
+                if function.star_token().is_some() {
+                    method = method.with_star_token(make::token(T![*]));
+                }
+                if let Some(type_parameters) = function.type_parameters() {
+                    method = method.with_type_parameters(type_parameters);
+                }
+                if let Some(return_type_annotation) = function.return_type_annotation() {
+                    method = method.with_return_type_annotation(return_type_annotation);
+                }
+                mutation.replace_node(
+                    AnyJsObjectMember::from(member.clone()),
+                    AnyJsObjectMember::from(method.build()),
+                );
+                Some(JsRuleAction {
+                    category: ActionCategory::QuickFix,
+                    applicability: Applicability::MaybeIncorrect,
+                    message: markup! { "Use the method shorthand." }.to_owned(),
+                    mutation,
+                })
+            }
+        }
+    }
+}
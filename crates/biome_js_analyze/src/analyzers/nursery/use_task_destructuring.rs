@@ -0,0 +1,626 @@
+use crate::control_flow::AnyJsControlFlowRoot;
+use crate::JsRuleAction;
+use biome_analyze::{
+    context::RuleContext, declare_rule, ActionCategory, Ast, FixKind, Rule, RuleDiagnostic,
+};
+use biome_console::markup;
+use biome_diagnostics::Applicability;
+use biome_js_factory::make;
+use biome_js_syntax::{
+    AnyFunctionLike, AnyJsArrowFunctionParameters, AnyJsBindingPattern, AnyJsCallArgument,
+    AnyJsExpression, AnyJsFormalParameter, AnyJsFunctionBody, AnyJsParameter, AnyJsStatement,
+    JsArrowFunctionExpression, JsBlockStatement, JsCallExpression, JsExpressionStatement,
+    JsIdentifierBinding, JsReturnStatement, JsStatementList, JsSyntaxKind, JsSyntaxNode,
+    JsSyntaxToken, T,
+};
+use biome_rowan::{
+    AstNode, AstNodeExt, AstNodeList, AstSeparatedList, BatchMutationExt, Direction,
+    TriviaPieceKind,
+};
+
+declare_rule! {
+    /// Enforce the use of `await` instead of `then` callbacks.
+    ///
+    /// A chain of `then`, `catch`, and `finally` callbacks is harder to read than the equivalent
+    /// `try` statement that awaits the promise:
+    /// the code that handles the result is nested in a callback, instead of following the `await`.
+    ///
+    /// The rule reports the chains that start with a call to `then` and are used as a statement.
+    /// It ignores the chains whose result is used, for example when the chain is returned,
+    /// awaited, or passed to another function.
+    ///
+    /// The code fix awaits the promise, in a `try` statement when the chain calls `catch` or `finally`.
+    /// The fix is only available when the chain is in an `async` function,
+    /// and when its callbacks are arrow functions that don't return early.
+    /// The fix is unsafe for several reasons:
+    /// the statements that follow the chain now wait for the promise,
+    /// the promises returned by the callbacks are no longer awaited,
+    /// and the `catch` clause also catches the errors thrown by the callback of `then`,
+    /// even when this callback was written as `.then(onFulfilled, onRejected)`.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,expect_diagnostic
+    /// async function f() {
+    ///     fetchUser().then((user) => {
+    ///         render(user);
+    ///     });
+    /// }
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// async function f() {
+    ///     fetchUser().then((user) => render(user)).catch((error) => report(error));
+    /// }
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// async function f() {
+    ///     fetchUser().then(render, report).finally(() => hideSpinner());
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// async function f() {
+    ///     try {
+    ///         const user = await fetchUser();
+    ///         render(user);
+    ///     } catch (error) {
+    ///         report(error);
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ```js
+    /// function f() {
+    ///     return fetchUser().then((user) => user.name);
+    /// }
+    /// ```
+    ///
+    /// ```js
+    /// Promise.all([fetchUser().then((user) => user.name), fetchSettings()]);
+    /// ```
+    ///
+    pub(crate) UseTaskDestructuring {
+        version: "next",
+        name: "useTaskDestructuring",
+        recommended: false,
+        fix_kind: FixKind::Unsafe,
+    }
+}
+
+impl Rule for UseTaskDestructuring {
+    type Query = Ast<JsExpressionStatement>;
+    type State = PromiseChain;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let expression = ctx.query().expression().ok()?;
+        let chain = PromiseChain::from_expression(expression)?;
+        chain
+            .calls
+            .iter()
+            .any(|(method, _)| *method == PromiseMethod::Then)
+            .then_some(chain)
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, _: &Self::State) -> Option<RuleDiagnostic> {
+        let expression = ctx.query().expression().ok()?;
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                expression.range(),
+                markup! {
+                    "Prefer "<Emphasis>"async"</Emphasis>"/"<Emphasis>"await"</Emphasis>" over "<Emphasis>"then"</Emphasis>" callbacks."
+                },
+            )
+            .note(markup! {
+                "Awaiting the promise in a "<Emphasis>"try"</Emphasis>" statement keeps the code that handles its result in sequence."
+            }),
+        )
+    }
+
+    fn action(ctx: &RuleContext<Self>, chain: &Self::State) -> Option<JsRuleAction> {
+        let statement = ctx.query();
+        let function = statement
+            .syntax()
+            .ancestors()
+            .find(|ancestor| AnyJsControlFlowRoot::can_cast(ancestor.kind()))
+            .and_then(AnyFunctionLike::cast)?;
+        if !function.is_async() {
+            return None;
+        }
+        let handlers = chain.handlers()?;
+        if handlers
+            .callbacks()
+            .any(|callback| !is_convertible(callback))
+        {
+            return None;
+        }
+        let fulfilled_parameter = callback_parameter(&handlers.on_fulfilled)?;
+        if fulfilled_parameter.as_ref().is_some_and(|parameter| {
+            binding_names(parameter.syntax())
+                .any(|name| has_identifier(chain.promise.syntax(), &name))
+        }) {
+            return None;
+        }
+        let outer_indentation = indentation(&statement.syntax().first_token()?);
+
+        let mut mutation = ctx.root().begin();
+        if handlers.on_rejected.is_none() && handlers.on_finally.is_none() {
+            // Without `catch` and `finally`, the statements of the callback
+            // replace the chain in the enclosing list of statements.
+            let list = JsStatementList::cast(statement.syntax().parent()?)?;
+            let callback = handlers.on_fulfilled.syntax();
+            let is_name_used_outside = binding_names(callback).any(|name| {
+                function
+                    .syntax()
+                    .descendants_tokens(Direction::Next)
+                    .any(|token| {
+                        is_identifier(&token, &name)
+                            && !callback.text_range().contains_range(token.text_range())
+                    })
+            });
+            if is_name_used_outside {
+                return None;
+            }
+            let mut statements = vec![await_statement(
+                &chain.promise,
+                fulfilled_parameter,
+                &outer_indentation,
+            )?];
+            for body_statement in callback_statements(&handlers.on_fulfilled, &outer_indentation)? {
+                statements.push(with_indentation(body_statement, &outer_indentation)?);
+            }
+            let first = statements.first_mut()?;
+            *first = first.clone().with_leading_trivia_pieces(
+                statement.syntax().first_token()?.leading_trivia().pieces(),
+            )?;
+            let items = list
+                .iter()
+                .flat_map(|item| {
+                    if item.syntax() == statement.syntax() {
+                        statements.clone()
+                    } else {
+                        vec![item]
+                    }
+                })
+                .collect::<Vec<_>>();
+            mutation.replace_node(list, make::js_statement_list(items));
+        } else {
+            let inner_indentation = block_indentation(&handlers.on_fulfilled)
+                .unwrap_or_else(|| format!("{outer_indentation}\t"));
+            let indentation = Indentation {
+                outer: outer_indentation,
+                inner: inner_indentation,
+            };
+            let try_body = block(
+                Some(await_statement(
+                    &chain.promise,
+                    fulfilled_parameter,
+                    &indentation.inner,
+                )?),
+                &handlers.on_fulfilled,
+                &indentation,
+            )?;
+            let try_token = make::token(T![try]).with_trailing_trivia([SPACE]);
+            let catch_clause = match &handlers.on_rejected {
+                Some(on_rejected) => {
+                    let catch_token = make::token(T![catch]).with_trailing_trivia([SPACE]);
+                    let body = block(None, on_rejected, &indentation)?;
+                    let catch_clause = match callback_parameter(on_rejected)? {
+                        Some(binding) => make::js_catch_clause(catch_token, body).with_declaration(
+                            make::js_catch_declaration(
+                                make::token(T!['(']),
+                                binding,
+                                make::token(T![')']).with_trailing_trivia([SPACE]),
+                            )
+                            .build(),
+                        ),
+                        None => make::js_catch_clause(catch_token, body),
+                    };
+                    Some(catch_clause.build())
+                }
+                None => None,
+            };
+            let finally_clause = match &handlers.on_finally {
+                Some(on_finally) => Some(make::js_finally_clause(
+                    make::token(T![finally]).with_trailing_trivia([SPACE]),
+                    block(None, on_finally, &indentation)?,
+                )),
+                None => None,
+            };
+            let try_statement = match (catch_clause, finally_clause) {
+                (Some(catch_clause), Some(finally_clause)) => AnyJsStatement::from(
+                    make::js_try_finally_statement(try_token, try_body, finally_clause)
+                        .with_catch_clause(catch_clause)
+                        .build(),
+                ),
+                (Some(catch_clause), None) => {
+                    AnyJsStatement::from(make::js_try_statement(try_token, try_body, catch_clause))
+                }
+                (None, Some(finally_clause)) => AnyJsStatement::from(
+                    make::js_try_finally_statement(try_token, try_body, finally_clause).build(),
+                ),
+                (None, None) => return None,
+            };
+            mutation.replace_node(AnyJsStatement::from(statement.clone()), try_statement);
+        }
+        Some(JsRuleAction {
+            category: ActionCategory::QuickFix,
+            applicability: Applicability::MaybeIncorrect,
+            message: markup! { "Use "<Emphasis>"await"</Emphasis>" instead." }.to_owned(),
+            mutation,
+        })
+    }
+}
+
+const SPACE: (TriviaPieceKind, &str) = (TriviaPieceKind::Whitespace, " ");
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub(crate) enum PromiseMethod {
+    Then,
+    Catch,
+    Finally,
+}
+
+impl PromiseMethod {
+    fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "then" => Some(Self::Then),
+            "catch" => Some(Self::Catch),
+            "finally" => Some(Self::Finally),
+            _ => None,
+        }
+    }
+}
+
+/// A chain of calls to `then`, `catch`, and `finally`.
+pub(crate) struct PromiseChain {
+    /// The expression on which the first method is called.
+    promise: AnyJsExpression,
+    /// The calls, in the order of execution.
+    calls: Vec<(PromiseMethod, JsCallExpression)>,
+}
+
+impl PromiseChain {
+    fn from_expression(expression: AnyJsExpression) -> Option<Self> {
+        let mut calls = Vec::new();
+        let mut current = expression;
+        while let AnyJsExpression::JsCallExpression(call) = &current {
+            let Some(member) = call
+                .callee()
+                .ok()
+                .and_then(|callee| callee.as_js_static_member_expression().cloned())
+            else {
+                break;
+            };
+            let Some(method) = member
+                .member()
+                .ok()
+                .and_then(|member| member.as_js_name()?.value_token().ok())
+                .and_then(|name| PromiseMethod::from_name(name.text_trimmed()))
+            else {
+                break;
+            };
+            // An optional call may not happen: the chain can't be awaited
+            if call.is_optional() || member.is_optional() {
+                return None;
+            }
+            calls.push((method, call.clone()));
+            current = member.object().ok()?;
+        }
+        calls.reverse();
+        (!calls.is_empty()).then_some(Self {
+            promise: current,
+            calls,
+        })
+    }
+
+    /// Returns the callbacks of a chain that can be converted to a `try` statement.
+    ///
+    /// The chain must contain a single `then`,
+    /// followed by an optional `catch` and an optional `finally`.
+    fn handlers(&self) -> Option<Handlers> {
+        let mut calls = self.calls.iter();
+        let (PromiseMethod::Then, then_call) = calls.next()? else {
+            return None;
+        };
+        let mut then_arguments = call_arguments(then_call)?.into_iter();
+        let on_fulfilled = then_arguments.next()?;
+        let mut on_rejected = then_arguments.next();
+        if then_arguments.next().is_some() {
+            return None;
+        }
+        let mut on_finally = None;
+        for (method, call) in calls {
+            let [callback] = <[_; 1]>::try_from(call_arguments(call)?).ok()?;
+            match method {
+                PromiseMethod::Catch if on_rejected.is_none() && on_finally.is_none() => {
+                    on_rejected = Some(callback);
+                }
+                PromiseMethod::Finally if on_finally.is_none() => {
+                    on_finally = Some(callback);
+                }
+                _ => return None,
+            }
+        }
+        Some(Handlers {
+            on_fulfilled,
+            on_rejected,
+            on_finally,
+        })
+    }
+}
+
+struct Handlers {
+    on_fulfilled: JsArrowFunctionExpression,
+    on_rejected: Option<JsArrowFunctionExpression>,
+    on_finally: Option<JsArrowFunctionExpression>,
+}
+
+impl Handlers {
+    fn callbacks(&self) -> impl Iterator<Item = &JsArrowFunctionExpression> {
+        std::iter::once(&self.on_fulfilled)
+            .chain(self.on_rejected.as_ref())
+            .chain(self.on_finally.as_ref())
+    }
+}
+
+struct Indentation {
+    /// The indentation of the chain.
+    outer: String,
+    /// The indentation of the statements of the new blocks.
+    inner: String,
+}
+
+/// Returns the arguments of `call`, if they are all arrow functions.
+fn call_arguments(call: &JsCallExpression) -> Option<Vec<JsArrowFunctionExpression>> {
+    call.arguments()
+        .ok()?
+        .args()
+        .iter()
+        .map(|argument| match argument.ok()? {
+            AnyJsCallArgument::AnyJsExpression(AnyJsExpression::JsArrowFunctionExpression(
+                arrow,
+            )) => Some(arrow),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Returns `true` if the body of `callback` can be moved out of the callback.
+fn is_convertible(callback: &JsArrowFunctionExpression) -> bool {
+    if callback.async_token().is_some()
+        || callback.type_parameters().is_some()
+        || callback.return_type_annotation().is_some()
+    {
+        return false;
+    }
+    match callback.body() {
+        Ok(AnyJsFunctionBody::AnyJsExpression(_)) => {}
+        Ok(AnyJsFunctionBody::JsFunctionBody(body)) => {
+            // A `return` would now exit the enclosing function
+            let has_return = body
+                .syntax()
+                .descendants()
+                .filter(|node| JsReturnStatement::can_cast(node.kind()))
+                .any(|node| {
+                    node.ancestors()
+                        .find(|ancestor| AnyJsControlFlowRoot::can_cast(ancestor.kind()))
+                        .as_ref()
+                        == Some(callback.syntax())
+                });
+            if has_return || !body.directives().is_empty() {
+                return false;
+            }
+        }
+        Err(_) => return false,
+    }
+    true
+}
+
+/// Returns the binding of the only parameter of `callback`.
+///
+/// Returns `Some(None)` if the callback has no parameter, and `None` if its parameters can't be converted.
+fn callback_parameter(callback: &JsArrowFunctionExpression) -> Option<Option<AnyJsBindingPattern>> {
+    match callback.parameters().ok()? {
+        AnyJsArrowFunctionParameters::AnyJsBinding(binding) => Some(Some(
+            AnyJsBindingPattern::AnyJsBinding(binding.trim_trivia()?),
+        )),
+        AnyJsArrowFunctionParameters::JsParameters(parameters) => {
+            let mut items = parameters.items().iter();
+            let Some(parameter) = items.next() else {
+                return Some(None);
+            };
+            if items.next().is_some() {
+                return None;
+            }
+            let AnyJsParameter::AnyJsFormalParameter(AnyJsFormalParameter::JsFormalParameter(
+                parameter,
+            )) = parameter.ok()?
+            else {
+                return None;
+            };
+            if parameter.initializer().is_some()
+                || parameter.type_annotation().is_some()
+                || parameter.question_mark_token().is_some()
+                || !parameter.decorators().is_empty()
+            {
+                return None;
+            }
+            Some(Some(parameter.binding().ok()?.trim_trivia()?))
+        }
+    }
+}
+
+/// Returns `const <binding> = await <promise>;`, or `await <promise>;` without binding.
+fn await_statement(
+    promise: &AnyJsExpression,
+    binding: Option<AnyJsBindingPattern>,
+    indentation: &str,
+) -> Option<AnyJsStatement> {
+    let leading_trivia = [
+        (TriviaPieceKind::Newline, "\n"),
+        (TriviaPieceKind::Whitespace, indentation),
+    ];
+    let await_token = make::token(T![await]).with_trailing_trivia([SPACE]);
+    let promise = promise.clone().trim_trivia()?;
+    Some(match binding {
+        Some(binding) => AnyJsStatement::from(
+            make::js_variable_statement(
+                make::js_variable_declaration(
+                    make::token(T![const])
+                        .with_leading_trivia(leading_trivia)
+                        .with_trailing_trivia([SPACE]),
+                    make::js_variable_declarator_list(
+                        [make::js_variable_declarator(binding)
+                            .with_initializer(make::js_initializer_clause(
+                                make::token_decorated_with_space(T![=]),
+                                make::js_await_expression(await_token, promise).into(),
+                            ))
+                            .build()],
+                        [],
+                    ),
+                )
+                .build(),
+            )
+            .with_semicolon_token(make::token(T![;]))
+            .build(),
+        ),
+        None => AnyJsStatement::from(
+            make::js_expression_statement(
+                make::js_await_expression(await_token.with_leading_trivia(leading_trivia), promise)
+                    .into(),
+            )
+            .with_semicolon_token(make::token(T![;]))
+            .build(),
+        ),
+    })
+}
+
+/// Returns the statements of the body of `callback`.
+///
+/// An expression body becomes an expression statement, indented with `indentation`.
+fn callback_statements(
+    callback: &JsArrowFunctionExpression,
+    indentation: &str,
+) -> Option<Vec<AnyJsStatement>> {
+    Some(match callback.body().ok()? {
+        AnyJsFunctionBody::AnyJsExpression(expression) => {
+            let expression = expression.trim_trivia()?;
+            let statement = AnyJsStatement::from(
+                make::js_expression_statement(expression)
+                    .with_semicolon_token(make::token(T![;]))
+                    .build(),
+            );
+            vec![with_indentation(statement, indentation)?]
+        }
+        AnyJsFunctionBody::JsFunctionBody(body) => body.statements().iter().collect(),
+    })
+}
+
+/// Returns a block with the statements of `callback`, preceded by `first_statement`.
+///
+/// The closing brace is followed by a space, for the next clause of the `try` statement.
+fn block(
+    first_statement: Option<AnyJsStatement>,
+    callback: &JsArrowFunctionExpression,
+    indentation: &Indentation,
+) -> Option<JsBlockStatement> {
+    let statements = first_statement
+        .into_iter()
+        .chain(callback_statements(callback, &indentation.inner)?)
+        .collect::<Vec<_>>();
+    let new_line = [
+        (TriviaPieceKind::Newline, "\n"),
+        (TriviaPieceKind::Whitespace, indentation.outer.as_str()),
+    ];
+    let (l_curly_token, r_curly_token) = match callback.body().ok()? {
+        AnyJsFunctionBody::JsFunctionBody(body) => {
+            let r_curly_token = body.r_curly_token().ok()?;
+            // Keep the comments that precede the closing brace
+            let has_comments = r_curly_token
+                .leading_trivia()
+                .pieces()
+                .any(|piece| piece.is_comments());
+            (
+                body.l_curly_token().ok()?.with_leading_trivia([]),
+                if has_comments {
+                    r_curly_token
+                } else {
+                    r_curly_token.with_leading_trivia(new_line)
+                },
+            )
+        }
+        AnyJsFunctionBody::AnyJsExpression(_) => (
+            make::token(T!['{']),
+            make::token(T!['}']).with_leading_trivia(new_line),
+        ),
+    };
+    Some(make::js_block_statement(
+        l_curly_token,
+        make::js_statement_list(statements),
+        r_curly_token.with_trailing_trivia([SPACE]),
+    ))
+}
+
+/// Returns the indentation of the first statement in the body of `callback`.
+fn block_indentation(callback: &JsArrowFunctionExpression) -> Option<String> {
+    let body = callback.body().ok()?;
+    let first_statement = body.as_js_function_body()?.statements().first()?;
+    Some(indentation(&first_statement.syntax().first_token()?))
+}
+
+/// Returns the whitespace that precedes `token` on its line.
+fn indentation(token: &JsSyntaxToken) -> String {
+    let leading_trivia = token.leading_trivia().text().to_string();
+    let line_start = leading_trivia.rfind('\n').map_or(0, |index| index + 1);
+    let indentation = &leading_trivia[line_start..];
+    if indentation.trim().is_empty() {
+        indentation.to_string()
+    } else {
+        String::new()
+    }
+}
+
+/// Replaces the leading whitespace of `statement` with a new line indented with `indentation`.
+///
+/// The comments that precede the statement are preserved.
+fn with_indentation(statement: AnyJsStatement, indentation: &str) -> Option<AnyJsStatement> {
+    let first_token = statement.syntax().first_token()?;
+    if first_token
+        .leading_trivia()
+        .pieces()
+        .any(|piece| piece.is_comments())
+    {
+        return Some(statement);
+    }
+    let new_first_token = first_token.with_leading_trivia([
+        (TriviaPieceKind::Newline, "\n"),
+        (TriviaPieceKind::Whitespace, indentation),
+    ]);
+    statement.replace_token_discard_trivia(first_token, new_first_token)
+}
+
+/// Returns the names of the bindings declared in `node`.
+fn binding_names(node: &JsSyntaxNode) -> impl Iterator<Item = String> {
+    node.descendants()
+        .filter_map(JsIdentifierBinding::cast)
+        .filter_map(|binding| Some(binding.name_token().ok()?.text_trimmed().to_string()))
+}
+
+/// Returns `true` if `node` contains an identifier named `name`.
+fn has_identifier(node: &JsSyntaxNode, name: &str) -> bool {
+    node.descendants_tokens(Direction::Next)
+        .any(|token| is_identifier(&token, name))
+}
+
+fn is_identifier(token: &JsSyntaxToken, name: &str) -> bool {
+    token.kind() == JsSyntaxKind::IDENT && token.text_trimmed() == name
+}
@@ -3,15 +3,75 @@
 use crate::analyzers::complexity::no_excessive_cognitive_complexity::{
     complexity_options, ComplexityOptions,
 };
+use crate::analyzers::nursery::no_absolute_import_path::{
+    absolute_import_path_options, AbsoluteImportPathOptions,
+};
+use crate::analyzers::nursery::no_enum_member_value_overlap::{
+    enum_member_value_overlap_options, EnumMemberValueOverlapOptions,
+};
+use crate::analyzers::nursery::no_excessive_property_access::{
+    property_access_depth_options, PropertyAccessDepthOptions,
+};
+use crate::analyzers::nursery::no_excessive_switch_cases::{
+    switch_cases_options, SwitchCasesOptions,
+};
+use crate::analyzers::nursery::no_mixed_import_style::{
+    mixed_import_style_options, MixedImportStyleOptions,
+};
+use crate::analyzers::nursery::no_re_export_all::{re_export_all_options, ReExportAllOptions};
+use crate::analyzers::nursery::no_standalone_blocks::{
+    standalone_blocks_options, StandaloneBlocksOptions,
+};
+use crate::analyzers::nursery::no_string_boolean_parameter::{
+    boolean_parameters_options, BooleanParametersOptions,
+};
+use crate::analyzers::nursery::no_string_literal_type::{
+    string_literal_type_options, StringLiteralTypeOptions,
+};
+use crate::analyzers::nursery::no_unsafe_regex::{unsafe_regex_options, UnsafeRegexOptions};
+use crate::analyzers::nursery::use_consistent_empty_line_between_class_members::{
+    empty_line_between_class_members_options, EmptyLineBetweenClassMembersOptions,
+};
+use crate::analyzers::nursery::use_explicit_return_type::{
+    explicit_return_type_options, ExplicitReturnTypeOptions,
+};
 use crate::semantic_analyzers::correctness::use_exhaustive_dependencies::{
     hooks_options, HooksOptions,
 };
+use crate::semantic_analyzers::nursery::no_implicit_coercion::{
+    implicit_coercion_options, ImplicitCoercionOptions,
+};
+use crate::semantic_analyzers::nursery::no_string_oracle::{
+    string_oracle_options, StringOracleOptions,
+};
+use crate::semantic_analyzers::nursery::no_unnecessary_await::{
+    unnecessary_await_options, UnnecessaryAwaitOptions,
+};
+use crate::semantic_analyzers::nursery::use_consistent_object_destructuring::{
+    object_destructuring_options, ObjectDestructuringOptions,
+};
+use crate::semantic_analyzers::nursery::use_import_meta::{import_meta_options, ImportMetaOptions};
+use crate::semantic_analyzers::nursery::use_number_to_fixed::{
+    number_to_fixed_options, NumberToFixedOptions,
+};
+use crate::semantic_analyzers::nursery::use_readonly_parameters::{
+    readonly_parameters_options, ReadonlyParametersOptions,
+};
+use crate::semantic_analyzers::nursery::use_structured_clone::{
+    structured_clone_options, StructuredCloneOptions,
+};
+use crate::semantic_analyzers::style::no_parameter_assign::{
+    parameter_assign_options, ParameterAssignOptions,
+};
 use crate::semantic_analyzers::style::no_restricted_globals::{
     restricted_globals_options, RestrictedGlobalsOptions,
 };
 use crate::semantic_analyzers::style::use_naming_convention::{
     naming_convention_options, NamingConventionOptions,
 };
+use crate::semantic_analyzers::suspicious::no_array_index_key::{
+    array_index_key_options, ArrayIndexKeyOptions,
+};
 use biome_analyze::options::RuleOptions;
 use biome_analyze::RuleKey;
 use biome_deserialize::json::VisitJsonNode;
@@ -30,12 +90,73 @@ use std::str::FromStr;
 pub enum PossibleOptions {
     /// Options for `noExcessiveComplexity` rule
     Complexity(#[bpaf(external(complexity_options), hide)] ComplexityOptions),
+    /// Options for `useConsistentEmptyLineBetweenClassMembers` rule
+    EmptyLineBetweenClassMembers(
+        #[bpaf(external(empty_line_between_class_members_options), hide)]
+        EmptyLineBetweenClassMembersOptions,
+    ),
+    /// Options for `noEnumMemberValueOverlap` rule
+    EnumMemberValueOverlap(
+        #[bpaf(external(enum_member_value_overlap_options), hide)] EnumMemberValueOverlapOptions,
+    ),
+    /// Options for `noExcessivePropertyAccess` rule
+    PropertyAccessDepth(
+        #[bpaf(external(property_access_depth_options), hide)] PropertyAccessDepthOptions,
+    ),
+    /// Options for `noAbsoluteImportPath` rule
+    AbsoluteImportPath(
+        #[bpaf(external(absolute_import_path_options), hide)] AbsoluteImportPathOptions,
+    ),
+    /// Options for `noMixedImportStyle` rule
+    MixedImportStyle(#[bpaf(external(mixed_import_style_options), hide)] MixedImportStyleOptions),
+    /// Options for `noStringLiteralType` rule
+    StringLiteralType(
+        #[bpaf(external(string_literal_type_options), hide)] StringLiteralTypeOptions,
+    ),
+    /// Options for `noUnsafeRegex` rule
+    UnsafeRegex(#[bpaf(external(unsafe_regex_options), hide)] UnsafeRegexOptions),
+    /// Options for `noImplicitCoercion` rule
+    ImplicitCoercion(#[bpaf(external(implicit_coercion_options), hide)] ImplicitCoercionOptions),
+    /// Options for `useConsistentObjectDestructuring` rule
+    ObjectDestructuring(
+        #[bpaf(external(object_destructuring_options), hide)] ObjectDestructuringOptions,
+    ),
+    /// Options for `useReadonlyParameters` rule
+    ReadonlyParameters(
+        #[bpaf(external(readonly_parameters_options), hide)] ReadonlyParametersOptions,
+    ),
+    /// Options for `useExplicitReturnType` rule
+    ExplicitReturnType(
+        #[bpaf(external(explicit_return_type_options), hide)] ExplicitReturnTypeOptions,
+    ),
     /// Options for `useExhaustiveDependencies` and `useHookAtTopLevel` rule
     Hooks(#[bpaf(external(hooks_options), hide)] HooksOptions),
     /// Options for `useNamingConvention` rule
     NamingConvention(#[bpaf(external(naming_convention_options), hide)] NamingConventionOptions),
     /// Options for `noRestrictedGlobals` rule
     RestrictedGlobals(#[bpaf(external(restricted_globals_options), hide)] RestrictedGlobalsOptions),
+    /// Options for `noParameterAssign` rule
+    ParameterAssign(#[bpaf(external(parameter_assign_options), hide)] ParameterAssignOptions),
+    /// Options for `useStructuredClone` rule
+    StructuredClone(#[bpaf(external(structured_clone_options), hide)] StructuredCloneOptions),
+    /// Options for `noReExportAll` rule
+    ReExportAll(#[bpaf(external(re_export_all_options), hide)] ReExportAllOptions),
+    /// Options for `noExcessiveSwitchCases` rule
+    SwitchCases(#[bpaf(external(switch_cases_options), hide)] SwitchCasesOptions),
+    /// Options for `noStringBooleanParameter` rule
+    BooleanParameters(#[bpaf(external(boolean_parameters_options), hide)] BooleanParametersOptions),
+    /// Options for `noUnnecessaryAwait` rule
+    UnnecessaryAwait(#[bpaf(external(unnecessary_await_options), hide)] UnnecessaryAwaitOptions),
+    /// Options for `noStringOracle` rule
+    StringOracle(#[bpaf(external(string_oracle_options), hide)] StringOracleOptions),
+    /// Options for `useImportMeta` rule
+    ImportMeta(#[bpaf(external(import_meta_options), hide)] ImportMetaOptions),
+    /// Options for `noStandaloneBlocks` rule
+    StandaloneBlocks(#[bpaf(external(standalone_blocks_options), hide)] StandaloneBlocksOptions),
+    /// Options for `noArrayIndexKey` rule
+    ArrayIndexKey(#[bpaf(external(array_index_key_options), hide)] ArrayIndexKeyOptions),
+    /// Options for `useNumberToFixed` rule
+    NumberToFixed(#[bpaf(external(number_to_fixed_options), hide)] NumberToFixedOptions),
     /// No options available
     #[default]
     NoOptions,
@@ -59,6 +180,83 @@ impl PossibleOptions {
                 };
                 RuleOptions::new(options)
             }
+            "noEnumMemberValueOverlap" => {
+                let options = match self {
+                    PossibleOptions::EnumMemberValueOverlap(options) => options.clone(),
+                    _ => EnumMemberValueOverlapOptions::default(),
+                };
+                RuleOptions::new(options)
+            }
+            "noExcessivePropertyAccess" => {
+                let options = match self {
+                    PossibleOptions::PropertyAccessDepth(options) => options.clone(),
+                    _ => PropertyAccessDepthOptions::default(),
+                };
+                RuleOptions::new(options)
+            }
+            "noAbsoluteImportPath" => {
+                let options = match self {
+                    PossibleOptions::AbsoluteImportPath(options) => options.clone(),
+                    _ => AbsoluteImportPathOptions::default(),
+                };
+                RuleOptions::new(options)
+            }
+            "noMixedImportStyle" => {
+                let options = match self {
+                    PossibleOptions::MixedImportStyle(options) => options.clone(),
+                    _ => MixedImportStyleOptions::default(),
+                };
+                RuleOptions::new(options)
+            }
+            "noStringLiteralType" => {
+                let options = match self {
+                    PossibleOptions::StringLiteralType(options) => options.clone(),
+                    _ => StringLiteralTypeOptions::default(),
+                };
+                RuleOptions::new(options)
+            }
+            "noUnsafeRegex" => {
+                let options = match self {
+                    PossibleOptions::UnsafeRegex(options) => options.clone(),
+                    _ => UnsafeRegexOptions::default(),
+                };
+                RuleOptions::new(options)
+            }
+            "noImplicitCoercion" => {
+                let options = match self {
+                    PossibleOptions::ImplicitCoercion(options) => options.clone(),
+                    _ => ImplicitCoercionOptions::default(),
+                };
+                RuleOptions::new(options)
+            }
+            "useConsistentEmptyLineBetweenClassMembers" => {
+                let options = match self {
+                    PossibleOptions::EmptyLineBetweenClassMembers(options) => options.clone(),
+                    _ => EmptyLineBetweenClassMembersOptions::default(),
+                };
+                RuleOptions::new(options)
+            }
+            "useConsistentObjectDestructuring" => {
+                let options = match self {
+                    PossibleOptions::ObjectDestructuring(options) => options.clone(),
+                    _ => ObjectDestructuringOptions::default(),
+                };
+                RuleOptions::new(options)
+            }
+            "useReadonlyParameters" => {
+                let options = match self {
+                    PossibleOptions::ReadonlyParameters(options) => options.clone(),
+                    _ => ReadonlyParametersOptions::default(),
+                };
+                RuleOptions::new(options)
+            }
+            "useExplicitReturnType" => {
+                let options = match self {
+                    PossibleOptions::ExplicitReturnType(options) => options.clone(),
+                    _ => ExplicitReturnTypeOptions::default(),
+                };
+                RuleOptions::new(options)
+            }
             "useExhaustiveDependencies" | "useHookAtTopLevel" => {
                 let options = match self {
                     PossibleOptions::Hooks(options) => options.clone(),
@@ -80,6 +278,83 @@ impl PossibleOptions {
                 };
                 RuleOptions::new(options)
             }
+            "noParameterAssign" => {
+                let options = match self {
+                    PossibleOptions::ParameterAssign(options) => options.clone(),
+                    _ => ParameterAssignOptions::default(),
+                };
+                RuleOptions::new(options)
+            }
+            "useStructuredClone" => {
+                let options = match self {
+                    PossibleOptions::StructuredClone(options) => options.clone(),
+                    _ => StructuredCloneOptions::default(),
+                };
+                RuleOptions::new(options)
+            }
+            "noReExportAll" => {
+                let options = match self {
+                    PossibleOptions::ReExportAll(options) => options.clone(),
+                    _ => ReExportAllOptions::default(),
+                };
+                RuleOptions::new(options)
+            }
+            "noExcessiveSwitchCases" => {
+                let options = match self {
+                    PossibleOptions::SwitchCases(options) => options.clone(),
+                    _ => SwitchCasesOptions::default(),
+                };
+                RuleOptions::new(options)
+            }
+            "noStringBooleanParameter" => {
+                let options = match self {
+                    PossibleOptions::BooleanParameters(options) => options.clone(),
+                    _ => BooleanParametersOptions::default(),
+                };
+                RuleOptions::new(options)
+            }
+            "noUnnecessaryAwait" => {
+                let options = match self {
+                    PossibleOptions::UnnecessaryAwait(options) => options.clone(),
+                    _ => UnnecessaryAwaitOptions::default(),
+                };
+                RuleOptions::new(options)
+            }
+            "noStringOracle" => {
+                let options = match self {
+                    PossibleOptions::StringOracle(options) => options.clone(),
+                    _ => StringOracleOptions::default(),
+                };
+                RuleOptions::new(options)
+            }
+            "useImportMeta" => {
+                let options = match self {
+                    PossibleOptions::ImportMeta(options) => options.clone(),
+                    _ => ImportMetaOptions::default(),
+                };
+                RuleOptions::new(options)
+            }
+            "noStandaloneBlocks" => {
+                let options = match self {
+                    PossibleOptions::StandaloneBlocks(options) => options.clone(),
+                    _ => StandaloneBlocksOptions::default(),
+                };
+                RuleOptions::new(options)
+            }
+            "noArrayIndexKey" => {
+                let options = match self {
+                    PossibleOptions::ArrayIndexKey(options) => options.clone(),
+                    _ => ArrayIndexKeyOptions::default(),
+                };
+                RuleOptions::new(options)
+            }
+            "useNumberToFixed" => {
+                let options = match self {
+                    PossibleOptions::NumberToFixed(options) => options.clone(),
+                    _ => NumberToFixedOptions::default(),
+                };
+                RuleOptions::new(options)
+            }
             // TODO: review error
             _ => panic!("This rule {:?} doesn't have options", rule_key),
         }
@@ -119,6 +394,61 @@ impl PossibleOptions {
                     options.visit_map(key.syntax(), value.syntax(), diagnostics)?;
                     *self = PossibleOptions::Complexity(options);
                 }
+                "allowBitFlags" => {
+                    let mut options = EnumMemberValueOverlapOptions::default();
+                    options.visit_map(key.syntax(), value.syntax(), diagnostics)?;
+                    *self = PossibleOptions::EnumMemberValueOverlap(options);
+                }
+                "maxDepth" => {
+                    let mut options = PropertyAccessDepthOptions::default();
+                    options.visit_map(key.syntax(), value.syntax(), diagnostics)?;
+                    *self = PossibleOptions::PropertyAccessDepth(options);
+                }
+                "allowedPrefixes" => {
+                    let mut options = AbsoluteImportPathOptions::default();
+                    options.visit_map(key.syntax(), value.syntax(), diagnostics)?;
+                    *self = PossibleOptions::AbsoluteImportPath(options);
+                }
+                "typeFirst" => {
+                    let mut options = MixedImportStyleOptions::default();
+                    options.visit_map(key.syntax(), value.syntax(), diagnostics)?;
+                    *self = PossibleOptions::MixedImportStyle(options);
+                }
+                "patternHints" => {
+                    let mut options = StringLiteralTypeOptions::default();
+                    options.visit_map(key.syntax(), value.syntax(), diagnostics)?;
+                    *self = PossibleOptions::StringLiteralType(options);
+                }
+                "maxSafeComplexity" => {
+                    let mut options = UnsafeRegexOptions::default();
+                    options.visit_map(key.syntax(), value.syntax(), diagnostics)?;
+                    *self = PossibleOptions::UnsafeRegex(options);
+                }
+                "allow" => {
+                    let mut options = ImplicitCoercionOptions::default();
+                    options.visit_map(key.syntax(), value.syntax(), diagnostics)?;
+                    *self = PossibleOptions::ImplicitCoercion(options);
+                }
+                "emptyLine" => {
+                    let mut options = EmptyLineBetweenClassMembersOptions::default();
+                    options.visit_map(key.syntax(), value.syntax(), diagnostics)?;
+                    *self = PossibleOptions::EmptyLineBetweenClassMembers(options);
+                }
+                "minProperties" => {
+                    let mut options = ObjectDestructuringOptions::default();
+                    options.visit_map(key.syntax(), value.syntax(), diagnostics)?;
+                    *self = PossibleOptions::ObjectDestructuring(options);
+                }
+                "onlyObjectTypes" => {
+                    let mut options = ReadonlyParametersOptions::default();
+                    options.visit_map(key.syntax(), value.syntax(), diagnostics)?;
+                    *self = PossibleOptions::ReadonlyParameters(options);
+                }
+                "publicOnly" => {
+                    let mut options = ExplicitReturnTypeOptions::default();
+                    options.visit_map(key.syntax(), value.syntax(), diagnostics)?;
+                    *self = PossibleOptions::ExplicitReturnType(options);
+                }
                 "strictCase" | "enumMemberCase" => {
                     let mut options = match self {
                         PossibleOptions::NamingConvention(options) => options.clone(),
@@ -136,6 +466,64 @@ impl PossibleOptions {
                     options.visit_map(key.syntax(), value.syntax(), diagnostics)?;
                     *self = PossibleOptions::RestrictedGlobals(options);
                 }
+                "props" => {
+                    let mut options = ParameterAssignOptions::default();
+                    options.visit_map(key.syntax(), value.syntax(), diagnostics)?;
+                    *self = PossibleOptions::ParameterAssign(options);
+                }
+                "targetRuntime" => {
+                    let mut options = StructuredCloneOptions::default();
+                    options.visit_map(key.syntax(), value.syntax(), diagnostics)?;
+                    *self = PossibleOptions::StructuredClone(options);
+                }
+                "entryFiles" => {
+                    let mut options = ReExportAllOptions::default();
+                    options.visit_map(key.syntax(), value.syntax(), diagnostics)?;
+                    *self = PossibleOptions::ReExportAll(options);
+                }
+                "maxCases" | "countFallthroughs" => {
+                    let mut options = match self {
+                        PossibleOptions::SwitchCases(options) => options.clone(),
+                        _ => SwitchCasesOptions::default(),
+                    };
+                    options.visit_map(key.syntax(), value.syntax(), diagnostics)?;
+                    *self = PossibleOptions::SwitchCases(options);
+                }
+                "minBoolArgs" => {
+                    let mut options = BooleanParametersOptions::default();
+                    options.visit_map(key.syntax(), value.syntax(), diagnostics)?;
+                    *self = PossibleOptions::BooleanParameters(options);
+                }
+                "allowedPatterns" => {
+                    let mut options = UnnecessaryAwaitOptions::default();
+                    options.visit_map(key.syntax(), value.syntax(), diagnostics)?;
+                    *self = PossibleOptions::UnnecessaryAwait(options);
+                }
+                "minComparisons" => {
+                    let mut options = StringOracleOptions::default();
+                    options.visit_map(key.syntax(), value.syntax(), diagnostics)?;
+                    *self = PossibleOptions::StringOracle(options);
+                }
+                "runtime" => {
+                    let mut options = ImportMetaOptions::default();
+                    options.visit_map(key.syntax(), value.syntax(), diagnostics)?;
+                    *self = PossibleOptions::ImportMeta(options);
+                }
+                "allowScoping" => {
+                    let mut options = StandaloneBlocksOptions::default();
+                    options.visit_map(key.syntax(), value.syntax(), diagnostics)?;
+                    *self = PossibleOptions::StandaloneBlocks(options);
+                }
+                "checkShorthand" => {
+                    let mut options = ArrayIndexKeyOptions::default();
+                    options.visit_map(key.syntax(), value.syntax(), diagnostics)?;
+                    *self = PossibleOptions::ArrayIndexKey(options);
+                }
+                "warnAboutToFixedPrecision" => {
+                    let mut options = NumberToFixedOptions::default();
+                    options.visit_map(key.syntax(), value.syntax(), diagnostics)?;
+                    *self = PossibleOptions::NumberToFixed(options);
+                }
                 _ => (),
             }
         }
@@ -179,6 +567,105 @@ impl PossibleOptions {
                     ));
                 }
             }
+            "noEnumMemberValueOverlap" => {
+                if !EnumMemberValueOverlapOptions::KNOWN_KEYS.contains(&key_name) {
+                    diagnostics.push(DeserializationDiagnostic::new_unknown_key(
+                        key_name,
+                        node.range(),
+                        EnumMemberValueOverlapOptions::KNOWN_KEYS,
+                    ));
+                }
+            }
+            "noExcessivePropertyAccess" => {
+                if !PropertyAccessDepthOptions::KNOWN_KEYS.contains(&key_name) {
+                    diagnostics.push(DeserializationDiagnostic::new_unknown_key(
+                        key_name,
+                        node.range(),
+                        PropertyAccessDepthOptions::KNOWN_KEYS,
+                    ));
+                }
+            }
+            "noAbsoluteImportPath" => {
+                if !AbsoluteImportPathOptions::KNOWN_KEYS.contains(&key_name) {
+                    diagnostics.push(DeserializationDiagnostic::new_unknown_key(
+                        key_name,
+                        node.range(),
+                        AbsoluteImportPathOptions::KNOWN_KEYS,
+                    ));
+                }
+            }
+            "noMixedImportStyle" => {
+                if !MixedImportStyleOptions::KNOWN_KEYS.contains(&key_name) {
+                    diagnostics.push(DeserializationDiagnostic::new_unknown_key(
+                        key_name,
+                        node.range(),
+                        MixedImportStyleOptions::KNOWN_KEYS,
+                    ));
+                }
+            }
+            "noStringLiteralType" => {
+                if !StringLiteralTypeOptions::KNOWN_KEYS.contains(&key_name) {
+                    diagnostics.push(DeserializationDiagnostic::new_unknown_key(
+                        key_name,
+                        node.range(),
+                        StringLiteralTypeOptions::KNOWN_KEYS,
+                    ));
+                }
+            }
+            "noUnsafeRegex" => {
+                if !UnsafeRegexOptions::KNOWN_KEYS.contains(&key_name) {
+                    diagnostics.push(DeserializationDiagnostic::new_unknown_key(
+                        key_name,
+                        node.range(),
+                        UnsafeRegexOptions::KNOWN_KEYS,
+                    ));
+                }
+            }
+            "noImplicitCoercion" => {
+                if !ImplicitCoercionOptions::KNOWN_KEYS.contains(&key_name) {
+                    diagnostics.push(DeserializationDiagnostic::new_unknown_key(
+                        key_name,
+                        node.range(),
+                        ImplicitCoercionOptions::KNOWN_KEYS,
+                    ));
+                }
+            }
+            "useConsistentEmptyLineBetweenClassMembers" => {
+                if !EmptyLineBetweenClassMembersOptions::KNOWN_KEYS.contains(&key_name) {
+                    diagnostics.push(DeserializationDiagnostic::new_unknown_key(
+                        key_name,
+                        node.range(),
+                        EmptyLineBetweenClassMembersOptions::KNOWN_KEYS,
+                    ));
+                }
+            }
+            "useConsistentObjectDestructuring" => {
+                if !ObjectDestructuringOptions::KNOWN_KEYS.contains(&key_name) {
+                    diagnostics.push(DeserializationDiagnostic::new_unknown_key(
+                        key_name,
+                        node.range(),
+                        ObjectDestructuringOptions::KNOWN_KEYS,
+                    ));
+                }
+            }
+            "useReadonlyParameters" => {
+                if !ReadonlyParametersOptions::KNOWN_KEYS.contains(&key_name) {
+                    diagnostics.push(DeserializationDiagnostic::new_unknown_key(
+                        key_name,
+                        node.range(),
+                        ReadonlyParametersOptions::KNOWN_KEYS,
+                    ));
+                }
+            }
+            "useExplicitReturnType" => {
+                if !ExplicitReturnTypeOptions::KNOWN_KEYS.contains(&key_name) {
+                    diagnostics.push(DeserializationDiagnostic::new_unknown_key(
+                        key_name,
+                        node.range(),
+                        ExplicitReturnTypeOptions::KNOWN_KEYS,
+                    ));
+                }
+            }
             "noRestrictedGlobals" => {
                 if !matches!(key_name, "deniedGlobals") {
                     diagnostics.push(DeserializationDiagnostic::new_unknown_key(
@@ -188,6 +675,105 @@ impl PossibleOptions {
                     ));
                 }
             }
+            "noParameterAssign" => {
+                if !ParameterAssignOptions::KNOWN_KEYS.contains(&key_name) {
+                    diagnostics.push(DeserializationDiagnostic::new_unknown_key(
+                        key_name,
+                        node.range(),
+                        ParameterAssignOptions::KNOWN_KEYS,
+                    ));
+                }
+            }
+            "useStructuredClone" => {
+                if !StructuredCloneOptions::KNOWN_KEYS.contains(&key_name) {
+                    diagnostics.push(DeserializationDiagnostic::new_unknown_key(
+                        key_name,
+                        node.range(),
+                        StructuredCloneOptions::KNOWN_KEYS,
+                    ));
+                }
+            }
+            "noReExportAll" => {
+                if !ReExportAllOptions::KNOWN_KEYS.contains(&key_name) {
+                    diagnostics.push(DeserializationDiagnostic::new_unknown_key(
+                        key_name,
+                        node.range(),
+                        ReExportAllOptions::KNOWN_KEYS,
+                    ));
+                }
+            }
+            "noExcessiveSwitchCases" => {
+                if !SwitchCasesOptions::KNOWN_KEYS.contains(&key_name) {
+                    diagnostics.push(DeserializationDiagnostic::new_unknown_key(
+                        key_name,
+                        node.range(),
+                        SwitchCasesOptions::KNOWN_KEYS,
+                    ));
+                }
+            }
+            "noStringBooleanParameter" => {
+                if !BooleanParametersOptions::KNOWN_KEYS.contains(&key_name) {
+                    diagnostics.push(DeserializationDiagnostic::new_unknown_key(
+                        key_name,
+                        node.range(),
+                        BooleanParametersOptions::KNOWN_KEYS,
+                    ));
+                }
+            }
+            "noUnnecessaryAwait" => {
+                if !UnnecessaryAwaitOptions::KNOWN_KEYS.contains(&key_name) {
+                    diagnostics.push(DeserializationDiagnostic::new_unknown_key(
+                        key_name,
+                        node.range(),
+                        UnnecessaryAwaitOptions::KNOWN_KEYS,
+                    ));
+                }
+            }
+            "noStringOracle" => {
+                if !StringOracleOptions::KNOWN_KEYS.contains(&key_name) {
+                    diagnostics.push(DeserializationDiagnostic::new_unknown_key(
+                        key_name,
+                        node.range(),
+                        StringOracleOptions::KNOWN_KEYS,
+                    ));
+                }
+            }
+            "useImportMeta" => {
+                if !ImportMetaOptions::KNOWN_KEYS.contains(&key_name) {
+                    diagnostics.push(DeserializationDiagnostic::new_unknown_key(
+                        key_name,
+                        node.range(),
+                        ImportMetaOptions::KNOWN_KEYS,
+                    ));
+                }
+            }
+            "noStandaloneBlocks" => {
+                if !StandaloneBlocksOptions::KNOWN_KEYS.contains(&key_name) {
+                    diagnostics.push(DeserializationDiagnostic::new_unknown_key(
+                        key_name,
+                        node.range(),
+                        StandaloneBlocksOptions::KNOWN_KEYS,
+                    ));
+                }
+            }
+            "noArrayIndexKey" => {
+                if !ArrayIndexKeyOptions::KNOWN_KEYS.contains(&key_name) {
+                    diagnostics.push(DeserializationDiagnostic::new_unknown_key(
+                        key_name,
+                        node.range(),
+                        ArrayIndexKeyOptions::KNOWN_KEYS,
+                    ));
+                }
+            }
+            "useNumberToFixed" => {
+                if !NumberToFixedOptions::KNOWN_KEYS.contains(&key_name) {
+                    diagnostics.push(DeserializationDiagnostic::new_unknown_key(
+                        key_name,
+                        node.range(),
+                        NumberToFixedOptions::KNOWN_KEYS,
+                    ));
+                }
+            }
             _ => {}
         }
 
@@ -2,15 +2,47 @@
 
 use biome_analyze::declare_group;
 
+pub(crate) mod no_async_without_await;
+pub(crate) mod no_direct_mutation;
+pub(crate) mod no_implicit_coercion;
 pub(crate) mod no_invalid_new_builtin;
+pub(crate) mod no_mutable_exports;
+pub(crate) mod no_object_spread_in_loop;
+pub(crate) mod no_string_oracle;
+pub(crate) mod no_throw_literal;
+pub(crate) mod no_unnecessary_await;
+pub(crate) mod no_unsafe_optional_chain;
 pub(crate) mod no_unused_imports;
+pub(crate) mod use_array_literal_spread;
+pub(crate) mod use_consistent_object_destructuring;
+pub(crate) mod use_import_meta;
+pub(crate) mod use_iterator_protocol;
+pub(crate) mod use_number_to_fixed;
+pub(crate) mod use_readonly_parameters;
+pub(crate) mod use_structured_clone;
 
 declare_group! {
     pub (crate) Nursery {
         name : "nursery" ,
         rules : [
+            self :: no_async_without_await :: NoAsyncWithoutAwait ,
+            self :: no_direct_mutation :: NoDirectMutation ,
+            self :: no_implicit_coercion :: NoImplicitCoercion ,
             self :: no_invalid_new_builtin :: NoInvalidNewBuiltin ,
+            self :: no_mutable_exports :: NoMutableExports ,
+            self :: no_object_spread_in_loop :: NoObjectSpreadInLoop ,
+            self :: no_string_oracle :: NoStringOracle ,
+            self :: no_throw_literal :: NoThrowLiteral ,
+            self :: no_unnecessary_await :: NoUnnecessaryAwait ,
+            self :: no_unsafe_optional_chain :: NoUnsafeOptionalChain ,
             self :: no_unused_imports :: NoUnusedImports ,
+            self :: use_array_literal_spread :: UseArrayLiteralSpread ,
+            self :: use_consistent_object_destructuring :: UseConsistentObjectDestructuring ,
+            self :: use_import_meta :: UseImportMeta ,
+            self :: use_iterator_protocol :: UseIteratorProtocol ,
+            self :: use_number_to_fixed :: UseNumberToFixed ,
+            self :: use_readonly_parameters :: UseReadonlyParameters ,
+            self :: use_structured_clone :: UseStructuredClone ,
         ]
      }
 }
@@ -0,0 +1,249 @@
+use crate::semantic_services::Semantic;
+use biome_analyze::context::RuleContext;
+use biome_analyze::{declare_rule, Rule, RuleDiagnostic};
+use biome_console::markup;
+use biome_js_semantic::SemanticModel;
+use biome_js_syntax::binding_ext::AnyJsBindingDeclaration;
+use biome_js_syntax::{
+    AnyFunctionLike, AnyJsClass, AnyJsExpression, AnyJsFunctionBody, AnyJsObjectMemberName,
+    AnyTsName, AnyTsReturnType, AnyTsType, JsForOfStatement, JsMethodClassMember,
+    JsReturnStatement, JsSyntaxNode, JsSyntaxToken, TsInterfaceDeclaration, WalkEvent,
+};
+use biome_rowan::{AstNode, AstNodeList, AstSeparatedList};
+
+declare_rule! {
+    /// Disallow `async` functions that don't use `await`.
+    ///
+    /// An `async` function always wraps its return value in a `Promise`.
+    /// When it never uses `await`, the `async` keyword is often a leftover of a refactoring,
+    /// and it misleads the callers that expect the function to wait for something.
+    ///
+    /// The rule reports the `async` functions, methods, and arrow functions whose body
+    /// contains neither an `await` expression nor a `for await...of` loop.
+    /// The `await` expressions of the nested functions aren't counted.
+    ///
+    /// The following functions aren't reported:
+    /// - `async` generators, which can use `yield` instead;
+    /// - functions with an empty body;
+    /// - functions that return a `new Promise(...)`, which are intentionally asynchronous;
+    /// - methods of a class that implements an interface of the same file, when the interface
+    ///   declares that the method returns a `Promise`.
+    ///
+    /// Source: https://eslint.org/docs/latest/rules/require-await
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,expect_diagnostic
+    /// async function fetchUser() {
+    ///     return cache.get("user");
+    /// }
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// const handler = async () => {
+    ///     log("done");
+    /// };
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// async function fetchUser() {
+    ///     return await fetch("/user");
+    /// }
+    /// ```
+    ///
+    /// ```js
+    /// async function* lines(stream) {
+    ///     yield stream.read();
+    /// }
+    /// ```
+    ///
+    /// ```ts
+    /// interface Loader {
+    ///     load(): Promise<string>;
+    /// }
+    /// class MemoryLoader implements Loader {
+    ///     async load() {
+    ///         return "";
+    ///     }
+    /// }
+    /// ```
+    ///
+    pub(crate) NoAsyncWithoutAwait {
+        version: "next",
+        name: "noAsyncWithoutAwait",
+        recommended: false,
+    }
+}
+
+impl Rule for NoAsyncWithoutAwait {
+    type Query = Semantic<AnyFunctionLike>;
+    type State = JsSyntaxToken;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let function = ctx.query();
+        let model = ctx.model();
+        let async_token = async_token(function)?;
+        if function.is_generator() {
+            return None;
+        }
+        let range = function.syntax().text_range();
+        if model
+            .await_points()
+            .iter()
+            .any(|await_point| await_point.function_range() == range)
+        {
+            return None;
+        }
+        let body = function.body().ok()?;
+        match &body {
+            AnyJsFunctionBody::AnyJsExpression(expression) => {
+                if is_new_promise(expression) {
+                    return None;
+                }
+            }
+            AnyJsFunctionBody::JsFunctionBody(body) => {
+                if body.statements().is_empty() || has_for_await_or_returns_promise(body.syntax()) {
+                    return None;
+                }
+            }
+        }
+        if let AnyFunctionLike::JsMethodClassMember(method) = function {
+            if is_promise_method_of_implemented_interface(method, model) {
+                return None;
+            }
+        }
+        Some(async_token)
+    }
+
+    fn diagnostic(_: &RuleContext<Self>, async_token: &Self::State) -> Option<RuleDiagnostic> {
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                async_token.text_trimmed_range(),
+                markup! {
+                    "This "<Emphasis>"async"</Emphasis>" function doesn't use "<Emphasis>"await"</Emphasis>"."
+                },
+            )
+            .note(markup! {
+                "Remove the "<Emphasis>"async"</Emphasis>" keyword, or return a "<Emphasis>"Promise"</Emphasis>" explicitly if the function is intended to be asynchronous."
+            }),
+        )
+    }
+}
+
+fn async_token(function: &AnyFunctionLike) -> Option<JsSyntaxToken> {
+    match function {
+        AnyFunctionLike::AnyJsFunction(function) => function.async_token(),
+        AnyFunctionLike::JsMethodObjectMember(method) => method.async_token(),
+        AnyFunctionLike::JsMethodClassMember(method) => method.async_token(),
+    }
+}
+
+/// Returns `true` if `expression` is a `new Promise(...)` expression.
+fn is_new_promise(expression: &AnyJsExpression) -> bool {
+    let AnyJsExpression::JsNewExpression(expression) = expression.clone().omit_parentheses() else {
+        return false;
+    };
+    expression
+        .callee()
+        .ok()
+        .and_then(|callee| callee.as_js_identifier_expression()?.name().ok())
+        .is_some_and(|name| name.syntax().text_trimmed() == "Promise")
+}
+
+/// Returns `true` if the function `body` contains a `for await...of` loop,
+/// or a `return` statement with a `new Promise(...)` expression.
+/// The nested functions aren't visited.
+fn has_for_await_or_returns_promise(body: &JsSyntaxNode) -> bool {
+    let mut iter = body.preorder();
+    while let Some(event) = iter.next() {
+        let WalkEvent::Enter(node) = event else {
+            continue;
+        };
+        if AnyFunctionLike::can_cast(node.kind()) {
+            iter.skip_subtree();
+        } else if let Some(for_of) = JsForOfStatement::cast_ref(&node) {
+            if for_of.await_token().is_some() {
+                return true;
+            }
+        } else if let Some(return_statement) = JsReturnStatement::cast(node) {
+            if return_statement
+                .argument()
+                .is_some_and(|argument| is_new_promise(&argument))
+            {
+                return true;
+            }
+        }
+    }
+    false
+}
+
+/// Returns `true` if `method` belongs to a class that implements an interface,
+/// which declares a method of the same name that returns a `Promise`.
+fn is_promise_method_of_implemented_interface(
+    method: &JsMethodClassMember,
+    model: &SemanticModel,
+) -> bool {
+    let Some(name) = method
+        .name()
+        .ok()
+        .and_then(|name| name.as_js_literal_member_name()?.name().ok())
+    else {
+        return false;
+    };
+    let Some(implements_clause) = method
+        .syntax()
+        .ancestors()
+        .find_map(AnyJsClass::cast)
+        .and_then(|class| class.implements_clause())
+    else {
+        return false;
+    };
+    implements_clause.types().iter().any(|implemented| {
+        let Some(interface) = implemented
+            .ok()
+            .and_then(|implemented| match implemented.name().ok()? {
+                AnyTsName::JsReferenceIdentifier(reference) => model.binding(&reference),
+                AnyTsName::TsQualifiedName(_) => None,
+            })
+            .and_then(|binding| binding.tree().declaration())
+        else {
+            return false;
+        };
+        let AnyJsBindingDeclaration::TsInterfaceDeclaration(interface) = interface else {
+            return false;
+        };
+        declares_promise_method(&interface, name.text())
+    })
+}
+
+/// Returns `true` if `interface` declares a method named `name` that returns a `Promise`.
+fn declares_promise_method(interface: &TsInterfaceDeclaration, name: &str) -> bool {
+    interface.members().iter().any(|member| {
+        let Some(signature) = member.as_ts_method_signature_type_member() else {
+            return false;
+        };
+        let is_same_name = matches!(
+            signature.name(),
+            Ok(AnyJsObjectMemberName::JsLiteralMemberName(member_name))
+                if member_name.name().is_ok_and(|member_name| member_name.text() == name)
+        );
+        is_same_name
+            && signature
+                .return_type_annotation()
+                .and_then(|annotation| annotation.ty().ok())
+                .is_some_and(|return_type| {
+                    matches!(
+                        return_type,
+                        AnyTsReturnType::AnyTsType(AnyTsType::TsReferenceType(reference))
+                            if reference.name().is_ok_and(|name| name.syntax().text_trimmed() == "Promise")
+                    )
+                })
+    })
+}
@@ -0,0 +1,382 @@
+use crate::react::{is_react_call_api, ReactLibrary};
+use crate::semantic_services::Semantic;
+use biome_analyze::{context::RuleContext, declare_rule, Rule, RuleDiagnostic};
+use biome_console::markup;
+use biome_js_semantic::SemanticModel;
+use biome_js_syntax::{
+    global_identifier, AnyJsAssignment, AnyJsAssignmentPattern, AnyJsBinding, AnyJsBindingPattern,
+    AnyJsCallArgument, AnyJsClass, AnyJsExpression, AnyJsFunction, AnyJsMemberExpression,
+    JsAssignmentExpression, JsCallExpression, JsPostUpdateExpression, JsPreUpdateExpression,
+    JsSyntaxKind, JsSyntaxNode, JsUnaryExpression, JsUnaryOperator, JsVariableDeclarator,
+};
+use biome_rowan::{declare_node_union, AstNode, AstSeparatedList};
+
+declare_rule! {
+    /// Disallow the direct mutation of the state and the props of React components.
+    ///
+    /// React doesn't know that a component must be rendered again when its state is mutated directly,
+    /// and a later call to `setState()` may overwrite the mutation.
+    /// The props of a component belong to its parent, and must be treated as read-only.
+    ///
+    /// The rule reports:
+    /// - the assignments, updates, and deletions of the members of `this.state` and `this.props` in class components;
+    /// - the calls of array methods that mutate their array, such as `push()` or `splice()`, on the members of `this.state`;
+    /// - the calls of `Object.assign()` that modify `this.state`, `this.props`, or one of their members;
+    /// - the assignments, updates, and deletions of the members of the props of function components,
+    ///   including the props that are destructured in the parameter of the component.
+    ///
+    /// A class is considered as a component when it extends `Component` or `PureComponent` from the `react` package.
+    /// A function is considered as a component when its name starts with an uppercase letter,
+    /// and the props are its first parameter.
+    /// The state can be initialized in the constructor.
+    ///
+    /// Array methods called on the members of the props aren't reported,
+    /// because the props often contain objects with methods of the same name, such as `history.push()`.
+    ///
+    /// Source: https://github.com/jsx-eslint/eslint-plugin-react/blob/master/docs/rules/no-direct-mutation-state.md
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```jsx,expect_diagnostic
+    /// import { Component } from "react";
+    /// class Counter extends Component {
+    ///     increment() {
+    ///         this.state.count += 1;
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ```jsx,expect_diagnostic
+    /// import { Component } from "react";
+    /// class List extends Component {
+    ///     add(item) {
+    ///         this.state.items.push(item);
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ```jsx,expect_diagnostic
+    /// function Profile({ user }) {
+    ///     user.name = user.name.trim();
+    ///     return <p>{user.name}</p>;
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```jsx
+    /// import { Component } from "react";
+    /// class Counter extends Component {
+    ///     constructor(props) {
+    ///         super(props);
+    ///         this.state = { count: 0 };
+    ///     }
+    ///     increment() {
+    ///         this.setState({ ...this.state, count: this.state.count + 1 });
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ```jsx
+    /// function Profile(props) {
+    ///     const user = { ...props.user, name: props.user.name.trim() };
+    ///     return <p>{user.name}</p>;
+    /// }
+    /// ```
+    ///
+    pub(crate) NoDirectMutation {
+        version: "next",
+        name: "noDirectMutation",
+        recommended: false,
+    }
+}
+
+declare_node_union! {
+    pub(crate) AnyJsMutation =
+        JsAssignmentExpression
+        | JsPreUpdateExpression
+        | JsPostUpdateExpression
+        | JsUnaryExpression
+        | JsCallExpression
+}
+
+/// The value mutated by an [AnyJsMutation]
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub(crate) enum MutatedValue {
+    /// `this.state` in a class component
+    State,
+    /// `this.props` in a class component, or the first parameter of a function component
+    Props,
+}
+
+/// Array methods that modify the array they are called on
+const MUTATING_ARRAY_METHODS: [&str; 9] = [
+    "copyWithin",
+    "fill",
+    "pop",
+    "push",
+    "reverse",
+    "shift",
+    "sort",
+    "splice",
+    "unshift",
+];
+
+impl Rule for NoDirectMutation {
+    type Query = Semantic<AnyJsMutation>;
+    type State = MutatedValue;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let model = ctx.model();
+        match ctx.query() {
+            AnyJsMutation::JsAssignmentExpression(assignment) => {
+                let AnyJsAssignmentPattern::AnyJsAssignment(left) = assignment.left().ok()? else {
+                    return None;
+                };
+                assignment_mutated_value(left, model)
+            }
+            AnyJsMutation::JsPreUpdateExpression(update) => {
+                assignment_mutated_value(update.operand().ok()?, model)
+            }
+            AnyJsMutation::JsPostUpdateExpression(update) => {
+                assignment_mutated_value(update.operand().ok()?, model)
+            }
+            AnyJsMutation::JsUnaryExpression(unary) => {
+                if unary.operator().ok()? != JsUnaryOperator::Delete {
+                    return None;
+                }
+                let argument = unary.argument().ok()?;
+                let member = AnyJsMemberExpression::cast_ref(argument.omit_parentheses().syntax())?;
+                mutated_value(member.object().ok()?, model)
+            }
+            AnyJsMutation::JsCallExpression(call) => {
+                let callee = call.callee().ok()?;
+                let callee = AnyJsMemberExpression::cast_ref(callee.omit_parentheses().syntax())?;
+                let method_name = callee.member_name()?;
+                let object = callee.object().ok()?;
+                if method_name.text() == "assign" {
+                    let (reference, name) = global_identifier(&object)?;
+                    if name.text() != "Object" || model.binding(&reference).is_some() {
+                        return None;
+                    }
+                    let first_argument = call.arguments().ok()?.args().iter().next()?.ok()?;
+                    let AnyJsCallArgument::AnyJsExpression(target) = first_argument else {
+                        return None;
+                    };
+                    let target = target.omit_parentheses();
+                    // `Object.assign(props, ...)` mutates the props themselves
+                    if let Some(reference) = target.as_js_reference_identifier() {
+                        let binding = model.binding(&reference)?;
+                        return is_component_props(binding.syntax(), model)
+                            .then_some(MutatedValue::Props);
+                    }
+                    mutated_value(target, model)
+                } else if MUTATING_ARRAY_METHODS.contains(&method_name.text()) {
+                    mutated_value(object, model)
+                        .filter(|mutated_value| *mutated_value == MutatedValue::State)
+                } else {
+                    None
+                }
+            }
+        }
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let diagnostic = match state {
+            MutatedValue::State => RuleDiagnostic::new(
+                rule_category!(),
+                ctx.query().range(),
+                markup! {
+                    "Do not mutate "<Emphasis>"this.state"</Emphasis>" directly."
+                },
+            )
+            .note(markup! {
+                "React doesn't render the component again when its state is mutated, and a later call to "<Emphasis>"setState()"</Emphasis>" may overwrite the mutation."
+            })
+            .note(markup! {
+                "Call "<Emphasis>"this.setState()"</Emphasis>" with a new value instead."
+            }),
+            MutatedValue::Props => RuleDiagnostic::new(
+                rule_category!(),
+                ctx.query().range(),
+                markup! {
+                    "Do not mutate the props of a component."
+                },
+            )
+            .note(markup! {
+                "The props belong to the parent component, and must be treated as read-only."
+            })
+            .note(markup! {
+                "Copy the value before changing it, or move the state to the parent component."
+            }),
+        };
+        Some(diagnostic)
+    }
+}
+
+/// Returns the value mutated by assigning `assignment`, if any
+fn assignment_mutated_value(
+    mut assignment: AnyJsAssignment,
+    model: &SemanticModel,
+) -> Option<MutatedValue> {
+    loop {
+        match assignment {
+            AnyJsAssignment::JsParenthesizedAssignment(parenthesized) => {
+                assignment = parenthesized.assignment().ok()?;
+            }
+            AnyJsAssignment::JsStaticMemberAssignment(member) => {
+                return mutated_value(member.object().ok()?, model);
+            }
+            AnyJsAssignment::JsComputedMemberAssignment(member) => {
+                return mutated_value(member.object().ok()?, model);
+            }
+            _ => return None,
+        }
+    }
+}
+
+/// Returns the value that `object` is a part of, when `object` is `this.state`, `this.props`,
+/// the props of a function component, or one of their members.
+fn mutated_value(object: AnyJsExpression, model: &SemanticModel) -> Option<MutatedValue> {
+    let mut object = object.omit_parentheses();
+    loop {
+        if let Some(reference) = object.as_js_reference_identifier() {
+            let binding = model.binding(&reference)?;
+            return is_component_props(binding.syntax(), model).then_some(MutatedValue::Props);
+        }
+        let member = AnyJsMemberExpression::cast(object.into_syntax())?;
+        let member_object = member.object().ok()?.omit_parentheses();
+        if let AnyJsExpression::JsThisExpression(this) = member_object {
+            let mutated_value = match member.member_name()?.text() {
+                "state" => MutatedValue::State,
+                "props" => MutatedValue::Props,
+                _ => return None,
+            };
+            let class_member = this_class_member(this.syntax())?;
+            if mutated_value == MutatedValue::State
+                && class_member.kind() == JsSyntaxKind::JS_CONSTRUCTOR_CLASS_MEMBER
+            {
+                return None;
+            }
+            let class = class_member.grand_parent().and_then(AnyJsClass::cast)?;
+            return is_react_component_class(&class, model).then_some(mutated_value);
+        }
+        object = member_object;
+    }
+}
+
+/// Returns the class member that `this` refers to the instance of
+fn this_class_member(this: &JsSyntaxNode) -> Option<JsSyntaxNode> {
+    this.ancestors()
+        .skip(1)
+        .find_map(|ancestor| match ancestor.kind() {
+            JsSyntaxKind::JS_CONSTRUCTOR_CLASS_MEMBER
+            | JsSyntaxKind::JS_METHOD_CLASS_MEMBER
+            | JsSyntaxKind::JS_GETTER_CLASS_MEMBER
+            | JsSyntaxKind::JS_SETTER_CLASS_MEMBER
+            | JsSyntaxKind::JS_PROPERTY_CLASS_MEMBER => Some(Some(ancestor)),
+            JsSyntaxKind::JS_FUNCTION_DECLARATION
+            | JsSyntaxKind::JS_FUNCTION_EXPRESSION
+            | JsSyntaxKind::JS_FUNCTION_EXPORT_DEFAULT_DECLARATION
+            | JsSyntaxKind::JS_METHOD_OBJECT_MEMBER
+            | JsSyntaxKind::JS_GETTER_OBJECT_MEMBER
+            | JsSyntaxKind::JS_SETTER_OBJECT_MEMBER
+            | JsSyntaxKind::JS_STATIC_INITIALIZATION_BLOCK_CLASS_MEMBER => Some(None),
+            _ => None,
+        })?
+}
+
+/// Returns `true` if `class` extends `Component` or `PureComponent` from React
+fn is_react_component_class(class: &AnyJsClass, model: &SemanticModel) -> bool {
+    let extends_clause = match class {
+        AnyJsClass::JsClassDeclaration(class) => class.extends_clause(),
+        AnyJsClass::JsClassExportDefaultDeclaration(class) => class.extends_clause(),
+        AnyJsClass::JsClassExpression(class) => class.extends_clause(),
+    };
+    extends_clause
+        .and_then(|extends_clause| extends_clause.super_class().ok())
+        .is_some_and(|super_class| {
+            is_react_call_api(super_class.clone(), model, ReactLibrary::React, "Component")
+                || is_react_call_api(super_class, model, ReactLibrary::React, "PureComponent")
+        })
+}
+
+/// Returns `true` if `binding` is the first parameter of a function component,
+/// or a binding destructured from it
+fn is_component_props(binding: &JsSyntaxNode, model: &SemanticModel) -> bool {
+    for ancestor in binding.ancestors().skip(1) {
+        match ancestor.kind() {
+            JsSyntaxKind::JS_OBJECT_BINDING_PATTERN
+            | JsSyntaxKind::JS_OBJECT_BINDING_PATTERN_PROPERTY_LIST
+            | JsSyntaxKind::JS_OBJECT_BINDING_PATTERN_PROPERTY
+            | JsSyntaxKind::JS_OBJECT_BINDING_PATTERN_SHORTHAND_PROPERTY => {}
+            JsSyntaxKind::JS_FORMAL_PARAMETER => {
+                return ancestor.prev_sibling().is_none()
+                    && ancestor
+                        .grand_parent()
+                        .and_then(|parameters| parameters.parent())
+                        .and_then(AnyJsFunction::cast)
+                        .is_some_and(|function| is_component(&function, model));
+            }
+            // `props => {}`
+            JsSyntaxKind::JS_ARROW_FUNCTION_EXPRESSION => {
+                return AnyJsFunction::cast(ancestor)
+                    .is_some_and(|function| is_component(&function, model));
+            }
+            _ => return false,
+        }
+    }
+    false
+}
+
+/// Returns `true` if `function` is named like a component.
+///
+/// The name of a function expression is the name of the variable it's assigned to,
+/// possibly through a call to `memo()` or `forwardRef()`.
+fn is_component(function: &AnyJsFunction, model: &SemanticModel) -> bool {
+    let id = match function {
+        AnyJsFunction::JsFunctionDeclaration(_)
+        | AnyJsFunction::JsFunctionExportDefaultDeclaration(_) => function.id().ok().flatten(),
+        AnyJsFunction::JsFunctionExpression(_) | AnyJsFunction::JsArrowFunctionExpression(_) => {
+            declarator_id(function.syntax(), model)
+        }
+    };
+    id.and_then(|id| id.as_js_identifier_binding()?.name_token().ok())
+        .is_some_and(|name| {
+            name.text_trimmed()
+                .starts_with(|c: char| c.is_ascii_uppercase())
+        })
+}
+
+/// Returns the binding of the variable that `expression` is assigned to
+fn declarator_id(expression: &JsSyntaxNode, model: &SemanticModel) -> Option<AnyJsBinding> {
+    let mut expression = expression.clone();
+    loop {
+        let parent = expression.parent()?;
+        match parent.kind() {
+            JsSyntaxKind::JS_PARENTHESIZED_EXPRESSION => expression = parent,
+            JsSyntaxKind::JS_CALL_ARGUMENT_LIST => {
+                let call = parent.grand_parent().and_then(JsCallExpression::cast)?;
+                let callee = call.callee().ok()?;
+                if !is_react_call_api(callee.clone(), model, ReactLibrary::React, "memo")
+                    && !is_react_call_api(callee, model, ReactLibrary::React, "forwardRef")
+                {
+                    return None;
+                }
+                expression = call.into_syntax();
+            }
+            JsSyntaxKind::JS_INITIALIZER_CLAUSE => {
+                let declarator = parent.parent().and_then(JsVariableDeclarator::cast)?;
+                let AnyJsBindingPattern::AnyJsBinding(id) = declarator.id().ok()? else {
+                    return None;
+                };
+                return Some(id);
+            }
+            _ => return None,
+        }
+    }
+}
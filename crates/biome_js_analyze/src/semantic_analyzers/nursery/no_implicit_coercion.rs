@@ -0,0 +1,538 @@
+use crate::analyzers::complexity::no_extra_boolean_cast::is_in_boolean_context;
+use crate::{semantic_services::Semantic, JsRuleAction};
+use biome_analyze::{
+    context::RuleContext, declare_rule, ActionCategory, FixKind, Rule, RuleDiagnostic,
+};
+use biome_console::markup;
+use biome_deserialize::json::{has_only_known_keys, with_only_known_variants, VisitJsonNode};
+use biome_deserialize::{DeserializationDiagnostic, VisitNode};
+use biome_diagnostics::Applicability;
+use biome_js_factory::make;
+use biome_js_semantic::SemanticModel;
+use biome_js_syntax::{
+    function_ext::AnyFunctionLike, AnyJsCallArgument, AnyJsExpression, AnyJsLiteralExpression,
+    AnyJsMemberExpression, AnyTsReturnType, AnyTsType, JsArrowFunctionExpression,
+    JsBinaryExpression, JsBinaryOperator, JsCallExpression, JsReturnStatement, JsSyntaxKind,
+    JsSyntaxNode, JsUnaryExpression, JsUnaryOperator, T,
+};
+use biome_json_syntax::{JsonArrayValue, JsonLanguage};
+use biome_rowan::{declare_node_union, AstNode, BatchMutationExt, SyntaxNode};
+use bpaf::Bpaf;
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+declare_rule! {
+    /// Disallow shorthand type conversions.
+    ///
+    /// Some operators convert their operand to another type as a side effect.
+    /// Using them only for this conversion is easy to overlook, and often accidental.
+    /// The rule reports the following patterns, and suggests the explicit conversion instead:
+    ///
+    /// - `!!foo`, that can be replaced with `Boolean(foo)`;
+    /// - `+foo`, that can be replaced with `Number(foo)`;
+    /// - `"" + foo` and `foo + ""`, that can be replaced with `String(foo)`;
+    /// - `foo | 0`, that can be replaced with `Math.trunc(foo)`;
+    /// - `~foo.indexOf(bar)`, that checks if `bar` was found.
+    ///
+    /// The rule ignores `!!foo` where a boolean is already expected,
+    /// such as in the test of an `if` statement, in a value returned by a function with the `boolean` return type,
+    /// or in a value returned by the callback of an array method such as `filter`.
+    ///
+    /// The fixes are unsafe because the explicit conversions don't always behave as the operators.
+    /// For example, `"" + foo` uses the `valueOf` method of objects, while `String(foo)` uses their `toString` method,
+    /// and `foo | 0` converts `foo` to a 32-bit integer, while `Math.trunc(foo)` doesn't.
+    ///
+    /// Source: https://eslint.org/docs/latest/rules/no-implicit-coercion
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,expect_diagnostic
+    /// const isEnabled = !!flag;
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// const count = +input;
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// const label = "" + id;
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// const index = ratio | 0;
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// const isEnabled = Boolean(flag);
+    /// const count = Number(input);
+    /// const label = String(id);
+    /// const index = Math.trunc(ratio);
+    /// ```
+    ///
+    /// ```js
+    /// if (!!flag) {}
+    /// const defined = values.filter((value) => !!value);
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// The option `allow` lists the operators that are allowed.
+    /// The accepted values are `"!!"`, `"+"`, `"|"`, and `"~"`.
+    /// Note that `"+"` allows both `+foo` and `"" + foo`.
+    ///
+    /// ```json
+    /// {
+    ///     "//": "...",
+    ///     "options": {
+    ///         "allow": ["!!", "~"]
+    ///     }
+    /// }
+    /// ```
+    ///
+    pub(crate) NoImplicitCoercion {
+        version: "next",
+        name: "noImplicitCoercion",
+        recommended: false,
+        fix_kind: FixKind::Unsafe,
+    }
+}
+
+declare_node_union! {
+    pub(crate) AnyCoercionExpression = JsUnaryExpression | JsBinaryExpression
+}
+
+pub(crate) enum Coercion {
+    /// `!!operand`
+    Boolean(AnyJsExpression),
+    /// `+operand`
+    Number(AnyJsExpression),
+    /// `"" + operand`
+    String(AnyJsExpression),
+    /// `operand | 0`
+    Integer(AnyJsExpression),
+    /// `~operand.indexOf(value)`
+    IndexOf,
+}
+
+impl Coercion {
+    fn operator(&self) -> CoercionOperator {
+        match self {
+            Self::Boolean(_) => CoercionOperator::DoubleNegation,
+            Self::Number(_) | Self::String(_) => CoercionOperator::Plus,
+            Self::Integer(_) => CoercionOperator::BitwiseOr,
+            Self::IndexOf => CoercionOperator::BitwiseNot,
+        }
+    }
+
+    /// Returns the global object and the name of its member that convert explicitly.
+    fn conversion(&self) -> Option<(&'static str, Option<&'static str>)> {
+        match self {
+            Self::Boolean(_) => Some(("Boolean", None)),
+            Self::Number(_) => Some(("Number", None)),
+            Self::String(_) => Some(("String", None)),
+            Self::Integer(_) => Some(("Math", Some("trunc"))),
+            Self::IndexOf => None,
+        }
+    }
+}
+
+impl Rule for NoImplicitCoercion {
+    type Query = Semantic<AnyCoercionExpression>;
+    type State = Coercion;
+    type Signals = Option<Self::State>;
+    type Options = ImplicitCoercionOptions;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let coercion = match ctx.query() {
+            AnyCoercionExpression::JsUnaryExpression(node) => unary_coercion(node)?,
+            AnyCoercionExpression::JsBinaryExpression(node) => binary_coercion(node)?,
+        };
+        let is_allowed = ctx
+            .options()
+            .allow
+            .iter()
+            .flatten()
+            .any(|operator| *operator == coercion.operator());
+        (!is_allowed).then_some(coercion)
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let operator = state.operator().as_str();
+        let message = match state.conversion() {
+            Some((object, None)) => markup! {
+                "Use "<Emphasis>{object}"()"</Emphasis>" instead of the implicit conversion with "<Emphasis>{operator}</Emphasis>"."
+            }
+            .to_owned(),
+            Some((object, Some(member))) => markup! {
+                "Use "<Emphasis>{object}"."{member}"()"</Emphasis>" instead of the implicit conversion with "<Emphasis>{operator}</Emphasis>"."
+            }
+            .to_owned(),
+            None => markup! {
+                "Compare the result of "<Emphasis>"indexOf()"</Emphasis>" with "<Emphasis>"-1"</Emphasis>", or use "<Emphasis>"includes()"</Emphasis>", instead of the implicit conversion with "<Emphasis>{operator}</Emphasis>"."
+            }
+            .to_owned(),
+        };
+        Some(
+            RuleDiagnostic::new(rule_category!(), ctx.query().range(), message).note(markup! {
+                "Implicit conversions are easy to overlook, and make the intent of the code unclear."
+            }),
+        )
+    }
+
+    fn action(ctx: &RuleContext<Self>, state: &Self::State) -> Option<JsRuleAction> {
+        let node = ctx.query();
+        let (object, member) = state.conversion()?;
+        if is_shadowed(object, node.syntax(), ctx.model()) {
+            return None;
+        }
+        let (Coercion::Boolean(operand)
+        | Coercion::Number(operand)
+        | Coercion::String(operand)
+        | Coercion::Integer(operand)) = state
+        else {
+            return None;
+        };
+
+        // A sequence expression must keep its parentheses to remain a single argument
+        let argument = match operand.clone().omit_parentheses() {
+            AnyJsExpression::JsSequenceExpression(_) => operand.clone(),
+            argument => argument,
+        };
+        let mut callee = AnyJsExpression::JsIdentifierExpression(make::js_identifier_expression(
+            make::js_reference_identifier(make::ident(object)),
+        ));
+        if let Some(member) = member {
+            callee = AnyJsExpression::JsStaticMemberExpression(make::js_static_member_expression(
+                callee,
+                make::token(T![.]),
+                make::js_name(make::ident(member)).into(),
+            ));
+        }
+        let call = make::js_call_expression(
+            callee,
+            make::js_call_arguments(
+                make::token(T!['(']),
+                make::js_call_argument_list(
+                    [AnyJsCallArgument::AnyJsExpression(argument.trim_trivia()?)],
+                    [],
+                ),
+                make::token(T![')']),
+            ),
+        )
+        .build();
+
+        let mut mutation = ctx.root().begin();
+        mutation.replace_node(AnyJsExpression::cast(node.syntax().clone())?, call.into());
+        let call_text = match member {
+            Some(member) => format!("{object}.{member}()"),
+            None => format!("{object}()"),
+        };
+        Some(JsRuleAction {
+            category: ActionCategory::QuickFix,
+            applicability: Applicability::MaybeIncorrect,
+            message: markup! { "Use "<Emphasis>{call_text}</Emphasis>" instead." }.to_owned(),
+            mutation,
+        })
+    }
+}
+
+/// Returns the coercion of `!!operand`, `+operand`, and `~operand.indexOf(value)`.
+fn unary_coercion(node: &JsUnaryExpression) -> Option<Coercion> {
+    let argument = node.argument().ok()?;
+    match node.operator().ok()? {
+        JsUnaryOperator::LogicalNot => {
+            let inner = argument.omit_parentheses();
+            let inner = inner.as_js_unary_expression()?;
+            let operand = inner.argument().ok()?;
+            // `!!!foo` is a negation
+            let is_triple_negation = operand
+                .clone()
+                .omit_parentheses()
+                .as_js_unary_expression()
+                .is_some_and(|operand| operand.operator() == Ok(JsUnaryOperator::LogicalNot));
+            if inner.operator().ok()? != JsUnaryOperator::LogicalNot
+                || is_triple_negation
+                || is_negated(node.syntax())
+                || is_boolean_expected(node.syntax())
+            {
+                return None;
+            }
+            Some(Coercion::Boolean(operand))
+        }
+        JsUnaryOperator::Plus => {
+            let is_number = matches!(
+                argument.clone().omit_parentheses(),
+                AnyJsExpression::AnyJsLiteralExpression(
+                    AnyJsLiteralExpression::JsNumberLiteralExpression(_)
+                )
+            );
+            (!is_number).then_some(Coercion::Number(argument))
+        }
+        JsUnaryOperator::BitwiseNot => {
+            let call = JsCallExpression::cast(argument.omit_parentheses().into_syntax())?;
+            let callee = AnyJsMemberExpression::cast(call.callee().ok()?.into_syntax())?;
+            let name = callee.member_name()?;
+            matches!(name.text(), "indexOf" | "lastIndexOf").then_some(Coercion::IndexOf)
+        }
+        _ => None,
+    }
+}
+
+/// Returns the coercion of `"" + operand`, `operand + ""`, and `operand | 0`.
+fn binary_coercion(node: &JsBinaryExpression) -> Option<Coercion> {
+    let left = node.left().ok()?;
+    let right = node.right().ok()?;
+    match node.operator().ok()? {
+        JsBinaryOperator::Plus => {
+            let operand = if is_empty_string(&left) {
+                right
+            } else if is_empty_string(&right) {
+                left
+            } else {
+                return None;
+            };
+            let is_string = matches!(
+                operand.clone().omit_parentheses(),
+                AnyJsExpression::AnyJsLiteralExpression(
+                    AnyJsLiteralExpression::JsStringLiteralExpression(_)
+                ) | AnyJsExpression::JsTemplateExpression(_)
+            );
+            (!is_string).then_some(Coercion::String(operand))
+        }
+        JsBinaryOperator::BitwiseOr => {
+            let is_zero = |expression: &AnyJsExpression| {
+                expression
+                    .as_any_js_literal_expression()
+                    .and_then(|literal| literal.as_js_number_literal_expression())
+                    .and_then(|number| number.value_token().ok())
+                    .is_some_and(|token| token.text_trimmed() == "0")
+            };
+            let is_number = matches!(
+                left,
+                AnyJsExpression::AnyJsLiteralExpression(
+                    AnyJsLiteralExpression::JsNumberLiteralExpression(_)
+                )
+            );
+            (is_zero(&right) && !is_number).then_some(Coercion::Integer(left))
+        }
+        _ => None,
+    }
+}
+
+fn is_empty_string(expression: &AnyJsExpression) -> bool {
+    expression
+        .as_any_js_literal_expression()
+        .and_then(|literal| literal.as_js_string_literal_expression())
+        .and_then(|string| string.inner_string_text().ok())
+        .is_some_and(|text| text.is_empty())
+}
+
+/// Returns `true` if `node` is the operand of a `!` operator, such as the inner part of `!!!foo`.
+fn is_negated(node: &JsSyntaxNode) -> bool {
+    let parent = skip_parentheses(node).parent();
+    parent
+        .and_then(JsUnaryExpression::cast)
+        .is_some_and(|parent| parent.operator() == Ok(JsUnaryOperator::LogicalNot))
+}
+
+/// Returns `true` if the value of `node` is already used as a boolean.
+fn is_boolean_expected(node: &JsSyntaxNode) -> bool {
+    let node = skip_parentheses(node);
+    if is_in_boolean_context(&node).unwrap_or(false) {
+        return true;
+    }
+    let Some(parent) = node.parent() else {
+        return false;
+    };
+    // Find the function that returns the value of `node`
+    let function = if JsReturnStatement::can_cast(parent.kind()) {
+        parent.ancestors().find_map(AnyFunctionLike::cast)
+    } else if let Some(arrow) = JsArrowFunctionExpression::cast(parent) {
+        AnyFunctionLike::cast(arrow.into_syntax())
+    } else {
+        None
+    };
+    let Some(function) = function else {
+        return false;
+    };
+    let returns_boolean = function
+        .return_type_annotation()
+        .and_then(|annotation| annotation.ty().ok())
+        .is_some_and(|ty| {
+            matches!(
+                ty,
+                AnyTsReturnType::AnyTsType(AnyTsType::TsBooleanType(_))
+                    | AnyTsReturnType::TsPredicateReturnType(_)
+            )
+        });
+    returns_boolean || is_predicate_callback(function.syntax())
+}
+
+/// Returns `true` if `function` is passed to an array method that expects a predicate, such as `filter`.
+fn is_predicate_callback(function: &JsSyntaxNode) -> bool {
+    let call = skip_parentheses(function)
+        .parent()
+        .filter(|parent| parent.kind() == JsSyntaxKind::JS_CALL_ARGUMENT_LIST)
+        .and_then(|list| list.grand_parent())
+        .and_then(JsCallExpression::cast);
+    let Some(callee) = call
+        .and_then(|call| call.callee().ok())
+        .and_then(|callee| AnyJsMemberExpression::cast(callee.into_syntax()))
+    else {
+        return false;
+    };
+    callee.member_name().is_some_and(|name| {
+        matches!(
+            name.text(),
+            "every" | "filter" | "find" | "findIndex" | "findLast" | "findLastIndex" | "some"
+        )
+    })
+}
+
+/// Returns the outermost parenthesized expression that wraps `node`, or `node` itself.
+fn skip_parentheses(node: &JsSyntaxNode) -> JsSyntaxNode {
+    let mut node = node.clone();
+    while let Some(parent) = node
+        .parent()
+        .filter(|parent| parent.kind() == JsSyntaxKind::JS_PARENTHESIZED_EXPRESSION)
+    {
+        node = parent;
+    }
+    node
+}
+
+/// Returns `true` if a binding named `name` is declared in a scope that contains `node`.
+fn is_shadowed(name: &str, node: &JsSyntaxNode, model: &SemanticModel) -> bool {
+    model
+        .scope(node)
+        .ancestors()
+        .any(|scope| scope.get_binding(name).is_some())
+}
+
+/// Options for the rule `noImplicitCoercion`.
+#[derive(Default, Deserialize, Serialize, Eq, PartialEq, Debug, Clone, Bpaf)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct ImplicitCoercionOptions {
+    /// The operators that are allowed to convert a value
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[bpaf(hide, argument::<CoercionOperator>("OPERATOR"), many, optional)]
+    allow: Option<Vec<CoercionOperator>>,
+}
+
+impl ImplicitCoercionOptions {
+    pub(crate) const KNOWN_KEYS: &'static [&'static str] = &["allow"];
+}
+
+// Required by [Bpaf].
+impl FromStr for ImplicitCoercionOptions {
+    type Err = &'static str;
+
+    fn from_str(_s: &str) -> Result<Self, Self::Err> {
+        // WARNING: should not be used.
+        Ok(Self::default())
+    }
+}
+
+impl VisitNode<JsonLanguage> for ImplicitCoercionOptions {
+    fn visit_member_name(
+        &mut self,
+        node: &SyntaxNode<JsonLanguage>,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<()> {
+        has_only_known_keys(node, Self::KNOWN_KEYS, diagnostics)
+    }
+
+    fn visit_map(
+        &mut self,
+        key: &SyntaxNode<JsonLanguage>,
+        value: &SyntaxNode<JsonLanguage>,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<()> {
+        let (name, value) = self.get_key_and_value(key, value, diagnostics)?;
+        let name_text = name.text();
+        if name_text == "allow" {
+            let Some(array) = JsonArrayValue::cast_ref(value.syntax()) else {
+                diagnostics.push(DeserializationDiagnostic::new_incorrect_type_for_value(
+                    name_text,
+                    "array",
+                    value.range(),
+                ));
+                return Some(());
+            };
+            let mut allow = Vec::new();
+            for element in array.elements() {
+                let element = element.ok()?;
+                let Some(element) = element.as_json_string_value() else {
+                    diagnostics.push(DeserializationDiagnostic::new_incorrect_type(
+                        "string",
+                        element.range(),
+                    ));
+                    continue;
+                };
+                let Some(element) = with_only_known_variants(
+                    element.syntax(),
+                    CoercionOperator::KNOWN_VALUES,
+                    diagnostics,
+                ) else {
+                    continue;
+                };
+                if let Ok(operator) = element.inner_string_text().ok()?.text().parse() {
+                    allow.push(operator);
+                }
+            }
+            self.allow = Some(allow);
+        }
+
+        Some(())
+    }
+}
+
+/// An operator that implicitly converts its operand.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum CoercionOperator {
+    /// `!!foo`
+    #[serde(rename = "!!")]
+    DoubleNegation,
+    /// `+foo` and `"" + foo`
+    #[serde(rename = "+")]
+    Plus,
+    /// `foo | 0`
+    #[serde(rename = "|")]
+    BitwiseOr,
+    /// `~foo.indexOf(bar)`
+    #[serde(rename = "~")]
+    BitwiseNot,
+}
+
+impl CoercionOperator {
+    pub(crate) const KNOWN_VALUES: &'static [&'static str] = &["!!", "+", "|", "~"];
+
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::DoubleNegation => "!!",
+            Self::Plus => "+",
+            Self::BitwiseOr => "|",
+            Self::BitwiseNot => "~",
+        }
+    }
+}
+
+impl FromStr for CoercionOperator {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "!!" => Ok(Self::DoubleNegation),
+            "+" => Ok(Self::Plus),
+            "|" => Ok(Self::BitwiseOr),
+            "~" => Ok(Self::BitwiseNot),
+            _ => Err("Value not supported for a coercion operator"),
+        }
+    }
+}
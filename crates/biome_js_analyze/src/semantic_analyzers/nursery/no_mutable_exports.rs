@@ -0,0 +1,196 @@
+use crate::semantic_analyzers::style::use_const::{with_binding_pat_identifiers, ConstBindings};
+use crate::{semantic_services::Semantic, JsRuleAction};
+use biome_analyze::{
+    context::RuleContext, declare_rule, ActionCategory, FixKind, Rule, RuleDiagnostic,
+};
+use biome_console::markup;
+use biome_diagnostics::Applicability;
+use biome_js_factory::make;
+use biome_js_semantic::ReferencesExtensions;
+use biome_js_syntax::{
+    AnyJsVariableDeclaration, JsExport, JsIdentifierBinding, JsSyntaxKind, JsVariableDeclaration,
+    JsVariableDeclarationClause, TextRange,
+};
+use biome_rowan::{AstNode, AstSeparatedList, BatchMutationExt};
+
+declare_rule! {
+    /// Disallow exporting variables declared with `let` or `var`.
+    ///
+    /// An exported binding is live: the modules that import it observe every reassignment.
+    /// Exporting a variable that can be reassigned creates a hidden coupling between the module
+    /// and its consumers, which depend on the current value of the variable when they read it.
+    ///
+    /// The rule reports the `let` and `var` variables that are exported with their declaration,
+    /// such as `export let count = 0`, or with an export clause, such as `export { count }`.
+    /// A default export of a variable, such as `export default count`, exports its current value
+    /// and isn't reported.
+    ///
+    /// A `let` declaration whose variables are never reassigned can be declared with `const`.
+    /// Otherwise, export a function that returns the current value of the variable.
+    ///
+    /// Source: https://github.com/import-js/eslint-plugin-import/blob/main/docs/rules/no-mutable-exports.md
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,expect_diagnostic
+    /// export let count = 0;
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// let count = 0;
+    /// export function increment() {
+    ///     count++;
+    /// }
+    /// export { count };
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// var name = "biome";
+    /// export { name as default };
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// export const count = 0;
+    /// ```
+    ///
+    /// ```js
+    /// let count = 0;
+    /// export function increment() {
+    ///     count++;
+    /// }
+    /// export function getCount() {
+    ///     return count;
+    /// }
+    /// ```
+    ///
+    /// ```js
+    /// let count = 0;
+    /// export default count;
+    /// ```
+    ///
+    pub(crate) NoMutableExports {
+        version: "next",
+        name: "noMutableExports",
+        recommended: false,
+        fix_kind: FixKind::Safe,
+    }
+}
+
+pub(crate) struct MutableExport {
+    /// The exported variable
+    binding: JsIdentifierBinding,
+    /// The range of the export specifier, if the variable is exported with an export clause
+    specifier_range: Option<TextRange>,
+    /// Whether the variable has an initializer and is never reassigned
+    can_be_const: bool,
+}
+
+impl Rule for NoMutableExports {
+    type Query = Semantic<JsVariableDeclaration>;
+    type State = MutableExport;
+    type Signals = Vec<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let declaration = ctx.query();
+        let model = ctx.model();
+        if !(declaration.is_let() || declaration.is_var()) {
+            return Vec::new();
+        }
+        let is_exported_declaration = declaration
+            .parent::<JsVariableDeclarationClause>()
+            .and_then(|clause| clause.parent::<JsExport>())
+            .is_some();
+        let mut signals = Vec::new();
+        for declarator in declaration.declarators().iter().flatten() {
+            let Ok(id) = declarator.id() else {
+                continue;
+            };
+            let has_initializer = declarator.initializer().is_some();
+            with_binding_pat_identifiers(id, &mut |binding| {
+                let specifier_range = binding.all_references(model).find_map(|reference| {
+                    let parent = reference.syntax().parent()?;
+                    matches!(
+                        parent.kind(),
+                        JsSyntaxKind::JS_EXPORT_NAMED_SHORTHAND_SPECIFIER
+                            | JsSyntaxKind::JS_EXPORT_NAMED_SPECIFIER
+                    )
+                    .then(|| parent.text_trimmed_range())
+                });
+                if is_exported_declaration || specifier_range.is_some() {
+                    let can_be_const =
+                        has_initializer && binding.all_writes(model).next().is_none();
+                    signals.push(MutableExport {
+                        binding,
+                        specifier_range,
+                        can_be_const,
+                    });
+                }
+                false
+            });
+        }
+        signals
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let declaration = ctx.query();
+        let kind = declaration.kind().ok()?;
+        let name = state.binding.name_token().ok()?;
+        let mut diagnostic = RuleDiagnostic::new(
+            rule_category!(),
+            state.binding.range(),
+            markup! {
+                "Do not export the mutable variable "<Emphasis>{name.text_trimmed()}</Emphasis>"."
+            },
+        );
+        if let Some(specifier_range) = state.specifier_range {
+            diagnostic = diagnostic.detail(
+                specifier_range,
+                markup! { "The variable is exported here." },
+            );
+        }
+        let diagnostic = diagnostic.note(markup! {
+            "The modules that import this variable observe its reassignments, and depend on its current value."
+        });
+        let diagnostic = if state.can_be_const {
+            diagnostic.note(markup! {
+                "The variable is never reassigned. Declare it with "<Emphasis>"const"</Emphasis>" instead of "<Emphasis>{kind.text_trimmed()}</Emphasis>"."
+            })
+        } else {
+            diagnostic.note(markup! {
+                "Export a function that returns the current value of the variable instead."
+            })
+        };
+        Some(diagnostic)
+    }
+
+    fn action(ctx: &RuleContext<Self>, _: &Self::State) -> Option<JsRuleAction> {
+        let declaration = ctx.query();
+        // A `var` can be redeclared and is hoisted, and is left to `noVar`.
+        if !declaration.is_let() {
+            return None;
+        }
+        let bindings = ConstBindings::new(
+            &AnyJsVariableDeclaration::from(declaration.clone()),
+            ctx.model(),
+        )?;
+        if !bindings.can_fix {
+            return None;
+        }
+        let mut mutation = ctx.root().begin();
+        mutation.replace_token(
+            declaration.kind().ok()?,
+            make::token(JsSyntaxKind::CONST_KW),
+        );
+        Some(JsRuleAction {
+            category: ActionCategory::QuickFix,
+            applicability: Applicability::Always,
+            message: markup! { "Use "<Emphasis>"const"</Emphasis>" instead." }.to_owned(),
+            mutation,
+        })
+    }
+}
@@ -0,0 +1,273 @@
+use crate::{control_flow::AnyJsControlFlowRoot, semantic_services::Semantic, JsRuleAction};
+use biome_analyze::{
+    context::RuleContext, declare_rule, ActionCategory, FixKind, Rule, RuleDiagnostic,
+};
+use biome_console::markup;
+use biome_diagnostics::Applicability;
+use biome_js_factory::make;
+use biome_js_semantic::SemanticModel;
+use biome_js_syntax::{
+    AnyJsAssignment, AnyJsAssignmentPattern, AnyJsCallArgument, AnyJsExpression, AnyJsObjectMember,
+    JsAssignmentExpression, JsAssignmentOperator, JsForInStatement, JsForOfStatement,
+    JsForStatement, JsObjectExpression, JsSpread, JsSyntaxNode, JsSyntaxToken, JsWhileStatement,
+    TextRange, TriviaPieceKind, T,
+};
+use biome_rowan::{AstNode, AstSeparatedList, BatchMutationExt};
+
+declare_rule! {
+    /// Disallow accumulating the properties of objects in a loop with object spread.
+    ///
+    /// `result = { ...result, ...item }` copies all the properties of `result` on every iteration,
+    /// so building an object this way takes a time of `O(n^2)`.
+    /// Adding the properties to the existing object with `Object.assign` takes a time of `O(n)`.
+    ///
+    /// The rule reports the assignments in the body of a `for`, `for...of`, `for...in`, or `while` loop,
+    /// that spread the assigned variable in the new object.
+    /// The variable must be declared before the loop.
+    ///
+    /// The fix is unsafe: `Object.assign` modifies the existing object,
+    /// which can be shared with other variables.
+    /// It's only suggested when the variable is the first spread of the object.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,expect_diagnostic
+    /// let result = {};
+    /// for (const item of items) {
+    ///     result = { ...result, ...item };
+    /// }
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// let index = {};
+    /// for (const item of items) {
+    ///     index = { ...index, [item.id]: item };
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// const result = {};
+    /// for (const item of items) {
+    ///     Object.assign(result, item);
+    /// }
+    /// ```
+    ///
+    /// ```js
+    /// for (const item of items) {
+    ///     let copy = {};
+    ///     copy = { ...copy, ...item };
+    /// }
+    /// ```
+    ///
+    pub(crate) NoObjectSpreadInLoop {
+        version: "next",
+        name: "noObjectSpreadInLoop",
+        recommended: false,
+        fix_kind: FixKind::Unsafe,
+    }
+}
+
+pub(crate) struct RuleState {
+    object: JsObjectExpression,
+    /// Spread of the assigned variable in `object`
+    spread: JsSpread,
+    /// Range of the keyword of the loop
+    loop_range: TextRange,
+}
+
+impl Rule for NoObjectSpreadInLoop {
+    type Query = Semantic<JsAssignmentExpression>;
+    type State = RuleState;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let assignment = ctx.query();
+        let model = ctx.model();
+        if assignment.operator().ok()? != JsAssignmentOperator::Assign {
+            return None;
+        }
+        let AnyJsAssignmentPattern::AnyJsAssignment(AnyJsAssignment::JsIdentifierAssignment(
+            identifier,
+        )) = assignment.left().ok()?
+        else {
+            return None;
+        };
+        let AnyJsExpression::JsObjectExpression(object) =
+            assignment.right().ok()?.omit_parentheses()
+        else {
+            return None;
+        };
+        let binding = model.binding(&identifier)?;
+        let spread = object.members().iter().find_map(|member| {
+            let AnyJsObjectMember::JsSpread(spread) = member.ok()? else {
+                return None;
+            };
+            let AnyJsExpression::JsIdentifierExpression(argument) =
+                spread.argument().ok()?.omit_parentheses()
+            else {
+                return None;
+            };
+            let spread_binding = model.binding(&argument.name().ok()?)?;
+            (spread_binding.syntax() == binding.syntax()).then_some(spread)
+        })?;
+        let loop_range = enclosing_loop(assignment.syntax())?;
+        // A variable declared in the loop is a new object on every iteration
+        if binding.syntax().text_trimmed_range().end() > loop_range.start() {
+            return None;
+        }
+        Some(RuleState {
+            object,
+            spread,
+            loop_range,
+        })
+    }
+
+    fn diagnostic(_: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let name = state
+            .spread
+            .argument()
+            .ok()?
+            .syntax()
+            .text_trimmed()
+            .to_string();
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                state.spread.range(),
+                markup! {
+                    "Avoid spreading "<Emphasis>{name}</Emphasis>" into a new object on every iteration of the loop."
+                },
+            )
+            .detail(
+                state.loop_range,
+                markup! {
+                    "All the properties of the object are copied on every iteration of this loop, which takes a time of "<Emphasis>"O(n^2)"</Emphasis>"."
+                },
+            )
+            .note(markup! {
+                "Add the properties to the existing object with "<Emphasis>"Object.assign"</Emphasis>" instead."
+            }),
+        )
+    }
+
+    fn action(ctx: &RuleContext<Self>, state: &Self::State) -> Option<JsRuleAction> {
+        let assignment = ctx.query();
+        let RuleState { object, spread, .. } = state;
+        let mut members = object.members().iter();
+        if members.next()?.ok()?.syntax() != spread.syntax()
+            || object.syntax().has_comments_descendants()
+            || is_shadowed("Object", assignment.syntax(), ctx.model())
+        {
+            return None;
+        }
+
+        let mut arguments = vec![AnyJsCallArgument::AnyJsExpression(
+            spread.argument().ok()?.trim_trivia()?,
+        )];
+        // Consecutive properties are grouped in a new object literal
+        let mut properties = Vec::new();
+        for member in members {
+            match member.ok()? {
+                AnyJsObjectMember::JsSpread(spread) => {
+                    if !properties.is_empty() {
+                        arguments.push(object_argument(std::mem::take(&mut properties)));
+                    }
+                    arguments.push(AnyJsCallArgument::AnyJsExpression(
+                        spread.argument().ok()?.trim_trivia()?,
+                    ));
+                }
+                member => properties.push(member.trim_trivia()?),
+            }
+        }
+        if !properties.is_empty() {
+            arguments.push(object_argument(properties));
+        }
+
+        let separators = (1..arguments.len()).map(|_| comma());
+        let call = make::js_call_expression(
+            make::js_static_member_expression(
+                make::js_identifier_expression(make::js_reference_identifier(make::ident(
+                    "Object",
+                )))
+                .into(),
+                make::token(T![.]),
+                make::js_name(make::ident("assign")).into(),
+            )
+            .into(),
+            make::js_call_arguments(
+                make::token(T!['(']),
+                make::js_call_argument_list(arguments, separators),
+                make::token(T![')']),
+            ),
+        )
+        .build();
+
+        let mut mutation = ctx.root().begin();
+        mutation.replace_node::<AnyJsExpression>(assignment.clone().into(), call.into());
+        Some(JsRuleAction {
+            category: ActionCategory::QuickFix,
+            applicability: Applicability::MaybeIncorrect,
+            message: markup! { "Use "<Emphasis>"Object.assign"</Emphasis>" instead." }.to_owned(),
+            mutation,
+        })
+    }
+}
+
+/// Returns the range of the keyword of the innermost loop that evaluates `node` on every iteration.
+///
+/// The search stops at the enclosing function.
+fn enclosing_loop(node: &JsSyntaxNode) -> Option<TextRange> {
+    let mut child = node.clone();
+    for ancestor in node.ancestors().skip(1) {
+        if AnyJsControlFlowRoot::can_cast(ancestor.kind()) {
+            return None;
+        }
+        let is_evaluated_on_every_iteration =
+            if let Some(for_statement) = JsForStatement::cast_ref(&ancestor) {
+                for_statement
+                    .initializer()
+                    .map_or(true, |initializer| initializer.syntax() != &child)
+            } else if let Some(for_of) = JsForOfStatement::cast_ref(&ancestor) {
+                for_of.body().ok()?.syntax() == &child
+            } else if let Some(for_in) = JsForInStatement::cast_ref(&ancestor) {
+                for_in.body().ok()?.syntax() == &child
+            } else {
+                JsWhileStatement::can_cast(ancestor.kind())
+            };
+        if is_evaluated_on_every_iteration {
+            return Some(ancestor.first_token()?.text_trimmed_range());
+        }
+        child = ancestor;
+    }
+    None
+}
+
+/// Returns `true` if a binding named `name` is declared in a scope that contains `node`.
+fn is_shadowed(name: &str, node: &JsSyntaxNode, model: &SemanticModel) -> bool {
+    model
+        .scope(node)
+        .ancestors()
+        .any(|scope| scope.get_binding(name).is_some())
+}
+
+/// Returns the object literal `{ <properties> }` as a call argument.
+fn object_argument(properties: Vec<AnyJsObjectMember>) -> AnyJsCallArgument {
+    let separators = (1..properties.len()).map(|_| comma());
+    AnyJsCallArgument::AnyJsExpression(
+        make::js_object_expression(
+            make::token(T!['{']).with_trailing_trivia([(TriviaPieceKind::Whitespace, " ")]),
+            make::js_object_member_list(properties, separators),
+            make::token(T!['}']).with_leading_trivia([(TriviaPieceKind::Whitespace, " ")]),
+        )
+        .into(),
+    )
+}
+
+fn comma() -> JsSyntaxToken {
+    make::token(T![,]).with_trailing_trivia([(TriviaPieceKind::Whitespace, " ")])
+}
@@ -0,0 +1,264 @@
+use crate::semantic_services::Semantic;
+use biome_analyze::{context::RuleContext, declare_rule, Rule, RuleDiagnostic};
+use biome_console::markup;
+use biome_deserialize::json::{has_only_known_keys, VisitJsonNode};
+use biome_deserialize::{DeserializationDiagnostic, VisitNode};
+use biome_js_semantic::ReferencesExtensions;
+use biome_js_syntax::{
+    binding_ext::AnyJsBindingDeclaration, AnyJsExpression, AnyJsLiteralExpression, AnyTsType,
+    JsBinaryExpression, JsBinaryOperator, JsIdentifierBinding, JsIdentifierExpression,
+    JsParenthesizedExpression, JsSyntaxNode, TextRange,
+};
+use biome_json_syntax::JsonLanguage;
+use biome_rowan::{AstNode, SyntaxNode};
+use bpaf::Bpaf;
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+declare_rule! {
+    /// Disallow comparing a variable with string literals in many places.
+    ///
+    /// A variable that is compared with string literals throughout a file,
+    /// such as `status === "active"` in a place and `status === "inactive"` in another,
+    /// encodes a set of states in plain strings.
+    /// The possible values aren't declared anywhere, and a typo in one of the literals isn't caught.
+    /// A TypeScript union type, such as `type Status = "active" | "inactive"`, or an enum makes them explicit.
+    ///
+    /// The rule reports the variables that are compared with a string literal with `==`, `===`, `!=`, or `!==`
+    /// in at least `minComparisons` places.
+    /// This is a heuristic: a report is a hint that the values could be extracted, not necessarily a mistake.
+    ///
+    /// Variables declared with a type annotation other than `string` are ignored,
+    /// because their type already constrains their values.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,expect_diagnostic
+    /// function render(status) {
+    ///     if (status === "active" || status === "pending") {
+    ///         show();
+    ///     }
+    ///     if (status === "inactive") {
+    ///         hide();
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// function render(status) {
+    ///     if (status === "active" || status === "pending") {
+    ///         show();
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ```ts
+    /// type Status = "active" | "inactive" | "pending";
+    /// function render(status: Status) {
+    ///     if (status === "active" || status === "pending") {
+    ///         show();
+    ///     }
+    ///     if (status === "inactive") {
+    ///         hide();
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// The minimum number of comparisons can be set with the option `minComparisons`. It defaults to `3`.
+    ///
+    /// ```json
+    /// {
+    ///     "//": "...",
+    ///     "options": {
+    ///         "minComparisons": 5
+    ///     }
+    /// }
+    /// ```
+    ///
+    pub(crate) NoStringOracle {
+        version: "next",
+        name: "noStringOracle",
+        recommended: false,
+    }
+}
+
+pub(crate) struct StringComparison {
+    /// Range of the comparison
+    range: TextRange,
+    /// The compared string, without its quotes
+    value: String,
+}
+
+impl Rule for NoStringOracle {
+    type Query = Semantic<JsIdentifierBinding>;
+    type State = Vec<StringComparison>;
+    type Signals = Option<Self::State>;
+    type Options = StringOracleOptions;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let binding = ctx.query();
+        if has_non_string_type_annotation(binding) {
+            return None;
+        }
+        let comparisons: Vec<_> = binding
+            .all_reads(ctx.model())
+            .filter_map(|reference| string_comparison(reference.syntax()))
+            .collect();
+        (comparisons.len() >= usize::from(ctx.options().min_comparisons)).then_some(comparisons)
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, comparisons: &Self::State) -> Option<RuleDiagnostic> {
+        let binding = ctx.query();
+        let name = binding.name_token().ok()?;
+        let name = name.text_trimmed();
+        let count = comparisons.len();
+        let mut diagnostic = RuleDiagnostic::new(
+            rule_category!(),
+            binding.range(),
+            markup! {
+                <Emphasis>{name}</Emphasis>" is compared with string literals in "{count}" places."
+            },
+        );
+        let mut values: Vec<&str> = Vec::new();
+        for comparison in comparisons {
+            let value = comparison.value.as_str();
+            diagnostic = diagnostic.detail(
+                comparison.range,
+                markup! {
+                    "Compared with "<Emphasis>"\""{value}"\""</Emphasis>" here."
+                },
+            );
+            if !values.contains(&value) {
+                values.push(value);
+            }
+        }
+        let union = values
+            .iter()
+            .map(|value| format!("\"{value}\""))
+            .collect::<Vec<_>>()
+            .join(" | ");
+        Some(diagnostic.note(markup! {
+            "Consider declaring the possible values with a union type, such as "<Emphasis>{union}</Emphasis>", or with an enum."
+        }))
+    }
+}
+
+/// Returns the comparison between the identifier `reference` and a string literal, if any.
+fn string_comparison(reference: &JsSyntaxNode) -> Option<StringComparison> {
+    let expression = JsIdentifierExpression::cast(reference.parent()?)?;
+    let mut parent = expression.syntax().parent()?;
+    while JsParenthesizedExpression::can_cast(parent.kind()) {
+        parent = parent.parent()?;
+    }
+    let binary = JsBinaryExpression::cast(parent)?;
+    if !matches!(
+        binary.operator().ok()?,
+        JsBinaryOperator::Equality
+            | JsBinaryOperator::StrictEquality
+            | JsBinaryOperator::Inequality
+            | JsBinaryOperator::StrictInequality
+    ) {
+        return None;
+    }
+    let left = binary.left().ok()?.omit_parentheses();
+    let other = if left.syntax() == expression.syntax() {
+        binary.right().ok()?.omit_parentheses()
+    } else {
+        left
+    };
+    let AnyJsExpression::AnyJsLiteralExpression(AnyJsLiteralExpression::JsStringLiteralExpression(
+        literal,
+    )) = other
+    else {
+        return None;
+    };
+    Some(StringComparison {
+        range: binary.range(),
+        value: literal.inner_string_text().ok()?.to_string(),
+    })
+}
+
+/// Returns `true` if `binding` is declared with a type annotation other than `string`
+fn has_non_string_type_annotation(binding: &JsIdentifierBinding) -> bool {
+    let annotation = match binding.declaration() {
+        Some(AnyJsBindingDeclaration::JsVariableDeclarator(declarator)) => declarator
+            .variable_annotation()
+            .and_then(|annotation| annotation.type_annotation().ok()?),
+        Some(AnyJsBindingDeclaration::JsFormalParameter(parameter)) => parameter.type_annotation(),
+        _ => None,
+    };
+    annotation
+        .and_then(|annotation| annotation.ty().ok())
+        .is_some_and(|ty| !matches!(ty, AnyTsType::TsStringType(_)))
+}
+
+/// Options for the rule `noStringOracle`.
+#[derive(Deserialize, Serialize, Eq, PartialEq, Debug, Clone, Bpaf)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct StringOracleOptions {
+    /// The minimum number of comparisons of a variable with string literals. Anything lower isn't reported.
+    pub min_comparisons: u8,
+}
+
+impl Default for StringOracleOptions {
+    fn default() -> Self {
+        Self { min_comparisons: 3 }
+    }
+}
+
+impl StringOracleOptions {
+    pub(crate) const KNOWN_KEYS: &'static [&'static str] = &["minComparisons"];
+}
+
+// Required by [Bpaf].
+impl FromStr for StringOracleOptions {
+    type Err = &'static str;
+
+    fn from_str(_s: &str) -> Result<Self, Self::Err> {
+        // WARNING: should not be used.
+        Ok(Self::default())
+    }
+}
+
+impl VisitNode<JsonLanguage> for StringOracleOptions {
+    fn visit_member_name(
+        &mut self,
+        node: &SyntaxNode<JsonLanguage>,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<()> {
+        has_only_known_keys(node, Self::KNOWN_KEYS, diagnostics)
+    }
+
+    fn visit_map(
+        &mut self,
+        key: &SyntaxNode<JsonLanguage>,
+        value: &SyntaxNode<JsonLanguage>,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<()> {
+        let (name, value) = self.get_key_and_value(key, value, diagnostics)?;
+        let name_text = name.text();
+        if name_text == "minComparisons" {
+            let min_comparisons = self.map_to_u8(&value, name_text, u8::MAX, diagnostics)?;
+            // Don't allow 0 or every variable would be reported.
+            if min_comparisons == 0 {
+                diagnostics.push(
+                    DeserializationDiagnostic::new(markup! {
+                        "The field "<Emphasis>"minComparisons"</Emphasis>" must be greater than 0"
+                    })
+                    .with_range(value.range()),
+                );
+                return None;
+            }
+            self.min_comparisons = min_comparisons;
+        }
+
+        Some(())
+    }
+}
@@ -0,0 +1,192 @@
+use crate::{semantic_services::Semantic, JsRuleAction};
+use biome_analyze::{
+    context::RuleContext, declare_rule, ActionCategory, FixKind, Rule, RuleDiagnostic,
+};
+use biome_console::markup;
+use biome_diagnostics::Applicability;
+use biome_js_factory::make;
+use biome_js_syntax::binding_ext::AnyJsBindingDeclaration;
+use biome_js_syntax::{
+    AnyJsCallArgument, AnyJsExpression, AnyJsLiteralExpression, JsThrowStatement,
+    JsVariableDeclaration, TriviaPieceKind, T,
+};
+use biome_rowan::{AstNode, BatchMutationExt};
+
+declare_rule! {
+    /// Disallow throwing values that aren't `Error` objects.
+    ///
+    /// An `Error` object captures the stack trace of the place where it's created.
+    /// Throwing a string or any other literal loses this information,
+    /// and the code that catches the value can't rely on its `message` and `stack` properties.
+    ///
+    /// The rule reports the `throw` statements that throw a literal, a template literal,
+    /// an object or array literal, `undefined`, or the result of an operator such as `"a" + b`.
+    /// It also reports the `const` variables initialized with one of these values, such as
+    /// `const message = "oops"; throw message;`.
+    ///
+    /// The `new` expressions, the calls, and the other variables aren't reported,
+    /// because they may evaluate to an `Error` object.
+    /// This includes the re-throw of the parameter of a `catch` clause.
+    ///
+    /// The thrown literals can be wrapped in `new Error(...)`.
+    /// The fix is safe for the string literals, which become the message of the error.
+    /// It's unsafe for the other literals, such as `null` or `42`, because the message changes.
+    ///
+    /// Source: https://eslint.org/docs/latest/rules/no-throw-literal
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,expect_diagnostic
+    /// throw "Something went wrong";
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// throw { message: "oops" };
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// throw null;
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// throw new Error("Something went wrong");
+    /// ```
+    ///
+    /// ```js
+    /// try {
+    ///     run();
+    /// } catch (error) {
+    ///     throw error;
+    /// }
+    /// ```
+    ///
+    pub(crate) NoThrowLiteral {
+        version: "next",
+        name: "noThrowLiteral",
+        recommended: false,
+        fix_kind: FixKind::Safe,
+    }
+}
+
+impl Rule for NoThrowLiteral {
+    type Query = Semantic<JsThrowStatement>;
+    type State = ();
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let argument = ctx.query().argument().ok()?;
+        match argument.omit_parentheses() {
+            AnyJsExpression::JsIdentifierExpression(identifier) => {
+                let reference = identifier.name().ok()?;
+                match ctx.model().binding(&reference) {
+                    // `undefined`
+                    None => (reference.name().ok()?.text() == "undefined").then_some(()),
+                    // A `const` variable initialized with a value that isn't an `Error` object
+                    Some(binding) => {
+                        let AnyJsBindingDeclaration::JsVariableDeclarator(declarator) =
+                            binding.tree().declaration()?
+                        else {
+                            return None;
+                        };
+                        let is_const = declarator
+                            .syntax()
+                            .ancestors()
+                            .find_map(JsVariableDeclaration::cast)
+                            .is_some_and(|declaration| declaration.is_const());
+                        let initializer = declarator.initializer()?.expression().ok()?;
+                        (is_const && is_never_error(&initializer)).then_some(())
+                    }
+                }
+            }
+            argument => is_never_error(&argument).then_some(()),
+        }
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, _: &Self::State) -> Option<RuleDiagnostic> {
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                ctx.query().argument().ok()?.range(),
+                markup! {
+                    "This "<Emphasis>"throw"</Emphasis>" statement throws a value that isn't an "<Emphasis>"Error"</Emphasis>" object."
+                },
+            )
+            .note(markup! {
+                "The stack trace of the error is lost. Throw an "<Emphasis>"Error"</Emphasis>" object instead, such as "<Emphasis>"new Error(message)"</Emphasis>"."
+            }),
+        )
+    }
+
+    fn action(ctx: &RuleContext<Self>, _: &Self::State) -> Option<JsRuleAction> {
+        let argument = ctx.query().argument().ok()?;
+        let AnyJsExpression::AnyJsLiteralExpression(literal) = &argument else {
+            return None;
+        };
+        // The trivia of the argument is kept around the `new` expression.
+        let literal = AnyJsLiteralExpression::cast(
+            literal
+                .syntax()
+                .clone()
+                .with_leading_trivia_pieces([])?
+                .with_trailing_trivia_pieces([])?,
+        )?;
+        // Only a string literal keeps its value as the message of the error.
+        let is_string = matches!(
+            literal,
+            AnyJsLiteralExpression::JsStringLiteralExpression(_)
+        );
+        let error = make::js_new_expression(
+            make::token(T![new]).with_trailing_trivia([(TriviaPieceKind::Whitespace, " ")]),
+            make::js_identifier_expression(make::js_reference_identifier(make::ident("Error")))
+                .into(),
+        )
+        .with_arguments(make::js_call_arguments(
+            make::token(T!['(']),
+            make::js_call_argument_list(
+                [AnyJsCallArgument::AnyJsExpression(
+                    AnyJsExpression::AnyJsLiteralExpression(literal),
+                )],
+                [],
+            ),
+            make::token(T![')']),
+        ))
+        .build();
+
+        let mut mutation = ctx.root().begin();
+        mutation.replace_node(argument, error.into());
+        Some(JsRuleAction {
+            category: ActionCategory::QuickFix,
+            applicability: if is_string {
+                Applicability::Always
+            } else {
+                Applicability::MaybeIncorrect
+            },
+            message: if is_string {
+                markup! { "Throw an "<Emphasis>"Error"</Emphasis>" object with this message." }
+                    .to_owned()
+            } else {
+                markup! { "Wrap this value in an "<Emphasis>"Error"</Emphasis>" object." }
+                    .to_owned()
+            },
+            mutation,
+        })
+    }
+}
+
+/// Returns `true` if `expression` never evaluates to an `Error` object.
+fn is_never_error(expression: &AnyJsExpression) -> bool {
+    match expression.clone().omit_parentheses() {
+        AnyJsExpression::AnyJsLiteralExpression(_)
+        | AnyJsExpression::JsArrayExpression(_)
+        | AnyJsExpression::JsBinaryExpression(_)
+        | AnyJsExpression::JsObjectExpression(_)
+        | AnyJsExpression::JsUnaryExpression(_) => true,
+        AnyJsExpression::JsTemplateExpression(template) => template.tag().is_none(),
+        _ => false,
+    }
+}
@@ -0,0 +1,401 @@
+use crate::semantic_services::Semantic;
+use biome_analyze::context::RuleContext;
+use biome_analyze::{declare_rule, Rule, RuleDiagnostic};
+use biome_console::markup;
+use biome_deserialize::json::{has_only_known_keys, VisitJsonNode};
+use biome_deserialize::{DeserializationDiagnostic, VisitNode};
+use biome_js_semantic::SemanticModel;
+use biome_js_syntax::binding_ext::AnyJsBindingDeclaration;
+use biome_js_syntax::{
+    AnyJsExpression, AnyJsFunctionBody, AnyTsReturnType, AnyTsType, JsAwaitExpression,
+    JsClassDeclaration, JsReturnStatement, JsSyntaxNode, JsVariableDeclaration, TextRange,
+    TsReturnTypeAnnotation, WalkEvent,
+};
+use biome_json_syntax::JsonLanguage;
+use biome_rowan::{AstNode, SyntaxNode, TokenText};
+use bpaf::Bpaf;
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+declare_rule! {
+    /// Disallow `await` on values that are never a `Promise`.
+    ///
+    /// Awaiting a value that isn't a `Promise`, nor an object with a `then` method,
+    /// only delays the rest of the function until the next microtask.
+    /// It's often a leftover of a refactoring, or a sign that the awaited function
+    /// was expected to be asynchronous.
+    ///
+    /// The rule reports the `await` expressions whose operand is:
+    /// - a literal, such as `await 42`, or a template literal without tag;
+    /// - a `new` expression of a class of the same file that doesn't extend another class,
+    ///   and that doesn't define a `then` method;
+    /// - a call to a function of the same file that isn't `async`, and that never returns a `Promise`.
+    ///
+    /// A function never returns a `Promise` when its return type annotation is a primitive type,
+    /// a literal type, an array, or a tuple, such as `number` or `string[]`.
+    /// When the function doesn't have a return type annotation, it never returns a `Promise`
+    /// when all its `return` statements return nothing or a literal.
+    /// The functions that return the result of another call aren't reported,
+    /// because this result may be a `Promise`.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,expect_diagnostic
+    /// async function f() {
+    ///     await 42;
+    /// }
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// function compute() {
+    ///     return 1;
+    /// }
+    /// async function f() {
+    ///     await compute();
+    /// }
+    /// ```
+    ///
+    /// ```ts,expect_diagnostic
+    /// function count(): number {
+    ///     return items.length;
+    /// }
+    /// async function f() {
+    ///     await count();
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// async function load() {
+    ///     return fetch("/data");
+    /// }
+    /// async function f() {
+    ///     await load();
+    /// }
+    /// ```
+    ///
+    /// ```js
+    /// function request() {
+    ///     return fetch("/data");
+    /// }
+    /// async function f() {
+    ///     await request();
+    /// }
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// The names of the functions and the classes that are never reported can be set with the option `allowedPatterns`.
+    /// This is useful for the utilities whose result is awaited on purpose, such as a `sleep` function
+    /// that is sometimes replaced by a synchronous stub.
+    ///
+    /// ```json
+    /// {
+    ///     "//": "...",
+    ///     "options": {
+    ///         "allowedPatterns": ["sleep", "delay"]
+    ///     }
+    /// }
+    /// ```
+    ///
+    pub(crate) NoUnnecessaryAwait {
+        version: "next",
+        name: "noUnnecessaryAwait",
+        recommended: false,
+    }
+}
+
+pub(crate) enum UnnecessaryAwait {
+    /// The operand is a literal.
+    Literal,
+    /// The operand is a new instance of a class that isn't thenable.
+    Instance { name: TokenText, range: TextRange },
+    /// The operand is the result of a function that never returns a `Promise`.
+    Call { name: TokenText, range: TextRange },
+}
+
+impl Rule for NoUnnecessaryAwait {
+    type Query = Semantic<JsAwaitExpression>;
+    type State = UnnecessaryAwait;
+    type Signals = Option<Self::State>;
+    type Options = UnnecessaryAwaitOptions;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let argument = ctx.query().argument().ok()?.omit_parentheses();
+        let model = ctx.model();
+        let allowed_patterns = ctx
+            .options()
+            .allowed_patterns
+            .as_deref()
+            .unwrap_or_default();
+        let is_allowed = |name: &TokenText| {
+            allowed_patterns
+                .iter()
+                .any(|pattern| pattern == name.text())
+        };
+        match argument {
+            AnyJsExpression::AnyJsLiteralExpression(_) => Some(UnnecessaryAwait::Literal),
+            AnyJsExpression::JsTemplateExpression(template) => template
+                .tag()
+                .is_none()
+                .then_some(UnnecessaryAwait::Literal),
+            AnyJsExpression::JsNewExpression(expression) => {
+                let (name, declaration) = callee_declaration(&expression.callee().ok()?, model)?;
+                let AnyJsBindingDeclaration::JsClassDeclaration(class) = declaration else {
+                    return None;
+                };
+                if is_allowed(&name) || is_thenable_class(&class) {
+                    return None;
+                }
+                Some(UnnecessaryAwait::Instance {
+                    name,
+                    range: class.id().ok()?.range(),
+                })
+            }
+            AnyJsExpression::JsCallExpression(expression) => {
+                let (name, declaration) = callee_declaration(&expression.callee().ok()?, model)?;
+                if is_allowed(&name) {
+                    return None;
+                }
+                let range = non_promise_function_range(declaration)?;
+                Some(UnnecessaryAwait::Call { name, range })
+            }
+            _ => None,
+        }
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let diagnostic = RuleDiagnostic::new(
+            rule_category!(),
+            ctx.query().range(),
+            markup! {
+                "This "<Emphasis>"await"</Emphasis>" is unnecessary, because its operand is never a "<Emphasis>"Promise"</Emphasis>"."
+            },
+        );
+        let diagnostic = match state {
+            UnnecessaryAwait::Literal => diagnostic,
+            UnnecessaryAwait::Instance { name, range } => diagnostic.detail(
+                range,
+                markup! {
+                    "The class "<Emphasis>{name.text()}</Emphasis>" doesn't define a "<Emphasis>"then"</Emphasis>" method."
+                },
+            ),
+            UnnecessaryAwait::Call { name, range } => diagnostic.detail(
+                range,
+                markup! {
+                    "The function "<Emphasis>{name.text()}</Emphasis>" isn't "<Emphasis>"async"</Emphasis>" and doesn't return a "<Emphasis>"Promise"</Emphasis>"."
+                },
+            ),
+        };
+        Some(diagnostic.note(markup! {
+            "Awaiting a value that isn't a "<Emphasis>"Promise"</Emphasis>" only delays the execution until the next microtask. Remove the "<Emphasis>"await"</Emphasis>" keyword."
+        }))
+    }
+}
+
+/// Returns the name and the declaration of `callee` when it's a reference to a binding of the file.
+fn callee_declaration(
+    callee: &AnyJsExpression,
+    model: &SemanticModel,
+) -> Option<(TokenText, AnyJsBindingDeclaration)> {
+    let AnyJsExpression::JsIdentifierExpression(identifier) = callee.clone().omit_parentheses()
+    else {
+        return None;
+    };
+    let reference = identifier.name().ok()?;
+    let declaration = model.binding(&reference)?.tree().declaration()?;
+    Some((reference.name().ok()?, declaration))
+}
+
+/// Returns the range of the name of the function declared by `declaration`,
+/// if this function isn't `async` and never returns a `Promise`.
+fn non_promise_function_range(declaration: AnyJsBindingDeclaration) -> Option<TextRange> {
+    let (may_be_async, range) = match declaration {
+        AnyJsBindingDeclaration::JsFunctionDeclaration(function) => {
+            let may_be_async = function.async_token().is_some()
+                || function.star_token().is_some()
+                || may_return_promise(
+                    function.return_type_annotation(),
+                    function.body().ok()?.syntax(),
+                );
+            (may_be_async, function.id().ok()?.range())
+        }
+        AnyJsBindingDeclaration::JsVariableDeclarator(declarator) => {
+            // The value of a `let` or `var` variable can be replaced by an async function.
+            let is_const = declarator
+                .syntax()
+                .ancestors()
+                .find_map(JsVariableDeclaration::cast)
+                .is_some_and(|declaration| declaration.is_const());
+            let initializer = declarator.initializer()?.expression().ok()?;
+            let may_be_async = !is_const
+                || match initializer.omit_parentheses() {
+                    AnyJsExpression::JsArrowFunctionExpression(function) => {
+                        function.async_token().is_some()
+                            || match function.body().ok()? {
+                                AnyJsFunctionBody::AnyJsExpression(body) => {
+                                    function.return_type_annotation().map_or_else(
+                                        || !is_literal(&body),
+                                        |annotation| may_be_promise(&annotation),
+                                    )
+                                }
+                                AnyJsFunctionBody::JsFunctionBody(body) => may_return_promise(
+                                    function.return_type_annotation(),
+                                    body.syntax(),
+                                ),
+                            }
+                    }
+                    AnyJsExpression::JsFunctionExpression(function) => {
+                        function.async_token().is_some()
+                            || function.star_token().is_some()
+                            || may_return_promise(
+                                function.return_type_annotation(),
+                                function.body().ok()?.syntax(),
+                            )
+                    }
+                    _ => true,
+                };
+            (may_be_async, declarator.id().ok()?.range())
+        }
+        _ => return None,
+    };
+    (!may_be_async).then_some(range)
+}
+
+/// Returns `true` if the instances of `class` may be awaited,
+/// because it extends another class or it defines a `then` method.
+fn is_thenable_class(class: &JsClassDeclaration) -> bool {
+    class.extends_clause().is_some()
+        || class
+            .members()
+            .into_iter()
+            .any(|member| member.has_name("then").unwrap_or(true))
+}
+
+/// Returns `true` if a function with this return type `annotation` and this `body` may return a `Promise`.
+fn may_return_promise(annotation: Option<TsReturnTypeAnnotation>, body: &JsSyntaxNode) -> bool {
+    match annotation {
+        Some(annotation) => may_be_promise(&annotation),
+        None => returns_non_literal(body),
+    }
+}
+
+/// Returns `true` unless the return type `annotation` is a type that is never a `Promise`,
+/// such as a primitive type, a literal type, an array, or a tuple.
+fn may_be_promise(annotation: &TsReturnTypeAnnotation) -> bool {
+    match annotation.ty() {
+        Ok(AnyTsReturnType::AnyTsType(ty)) => !is_non_thenable_type(&ty),
+        // A type predicate is a boolean
+        Ok(AnyTsReturnType::TsPredicateReturnType(_)) => false,
+        _ => true,
+    }
+}
+
+fn is_non_thenable_type(ty: &AnyTsType) -> bool {
+    match ty {
+        AnyTsType::TsArrayType(_)
+        | AnyTsType::TsBigintLiteralType(_)
+        | AnyTsType::TsBigintType(_)
+        | AnyTsType::TsBooleanLiteralType(_)
+        | AnyTsType::TsBooleanType(_)
+        | AnyTsType::TsNullLiteralType(_)
+        | AnyTsType::TsNumberLiteralType(_)
+        | AnyTsType::TsNumberType(_)
+        | AnyTsType::TsStringLiteralType(_)
+        | AnyTsType::TsStringType(_)
+        | AnyTsType::TsSymbolType(_)
+        | AnyTsType::TsTemplateLiteralType(_)
+        | AnyTsType::TsTupleType(_)
+        | AnyTsType::TsUndefinedType(_)
+        | AnyTsType::TsVoidType(_) => true,
+        AnyTsType::TsParenthesizedType(ty) => ty.ty().is_ok_and(|ty| is_non_thenable_type(&ty)),
+        AnyTsType::TsUnionType(union) => union
+            .types()
+            .into_iter()
+            .all(|ty| ty.is_ok_and(|ty| is_non_thenable_type(&ty))),
+        _ => false,
+    }
+}
+
+/// Returns `true` if the function `body` has a `return` statement with an argument that isn't a literal.
+/// The nested functions aren't visited.
+fn returns_non_literal(body: &JsSyntaxNode) -> bool {
+    let mut iter = body.preorder();
+    while let Some(event) = iter.next() {
+        let WalkEvent::Enter(node) = event else {
+            continue;
+        };
+        if node != *body && AnyJsFunctionBody::can_cast(node.kind()) {
+            iter.skip_subtree();
+        } else if let Some(return_statement) = JsReturnStatement::cast(node) {
+            if return_statement
+                .argument()
+                .is_some_and(|argument| !is_literal(&argument))
+            {
+                return true;
+            }
+        }
+    }
+    false
+}
+
+fn is_literal(expression: &AnyJsExpression) -> bool {
+    match expression.clone().omit_parentheses() {
+        AnyJsExpression::AnyJsLiteralExpression(_) => true,
+        AnyJsExpression::JsTemplateExpression(template) => template.tag().is_none(),
+        _ => false,
+    }
+}
+
+/// Options for the rule `noUnnecessaryAwait`.
+#[derive(Default, Deserialize, Serialize, Eq, PartialEq, Debug, Clone, Bpaf)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct UnnecessaryAwaitOptions {
+    /// The names of the functions and the classes whose results can be awaited
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[bpaf(hide, argument::<String>("NAME"), many, optional)]
+    allowed_patterns: Option<Vec<String>>,
+}
+
+impl UnnecessaryAwaitOptions {
+    pub const KNOWN_KEYS: &'static [&'static str] = &["allowedPatterns"];
+}
+
+// Required by [Bpaf].
+impl FromStr for UnnecessaryAwaitOptions {
+    type Err = &'static str;
+
+    fn from_str(_s: &str) -> Result<Self, Self::Err> {
+        // WARNING: should not be used.
+        Ok(Self::default())
+    }
+}
+
+impl VisitNode<JsonLanguage> for UnnecessaryAwaitOptions {
+    fn visit_member_name(
+        &mut self,
+        node: &SyntaxNode<JsonLanguage>,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<()> {
+        has_only_known_keys(node, Self::KNOWN_KEYS, diagnostics)
+    }
+
+    fn visit_map(
+        &mut self,
+        key: &SyntaxNode<JsonLanguage>,
+        value: &SyntaxNode<JsonLanguage>,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<()> {
+        let (name, value) = self.get_key_and_value(key, value, diagnostics)?;
+        let name_text = name.text();
+        if name_text == "allowedPatterns" {
+            self.allowed_patterns = self.map_to_array_of_strings(&value, name_text, diagnostics);
+        }
+
+        Some(())
+    }
+}
@@ -0,0 +1,325 @@
+use crate::{semantic_services::Semantic, JsRuleAction};
+use biome_analyze::{
+    context::RuleContext, declare_rule, ActionCategory, FixKind, Rule, RuleDiagnostic,
+};
+use biome_console::markup;
+use biome_diagnostics::Applicability;
+use biome_js_factory::make;
+use biome_js_semantic::SemanticModel;
+use biome_js_syntax::{
+    binding_ext::AnyJsBindingDeclaration, AnyJsBindingPattern, AnyJsExpression,
+    AnyJsLiteralExpression, AnyTsName, AnyTsType, JsCallExpression, JsComputedMemberExpression,
+    JsStaticMemberExpression, JsSyntaxKind, JsSyntaxToken, T,
+};
+use biome_rowan::{declare_node_union, AstNode, AstSeparatedList, BatchMutationExt};
+
+declare_rule! {
+    /// Disallow optional chaining on values that are never `null` or `undefined`.
+    ///
+    /// The optional chaining operator `?.` short-circuits when the value on its left is `null` or `undefined`.
+    /// Using it on a value that is always defined adds a useless runtime check,
+    /// and misleads readers into thinking that the value can be missing.
+    ///
+    /// The rule reports optional chaining on:
+    ///
+    /// - object and array literals, functions, and classes;
+    /// - string, number, bigint, boolean, and regular expression literals, and untagged templates;
+    /// - `new` expressions, which always return an object;
+    /// - functions, classes, and enums that are referenced by their name;
+    /// - variables and parameters that are never reassigned, and that are initialized with one of the values above,
+    ///   or annotated with a TypeScript type that doesn't include `null` or `undefined`.
+    ///
+    /// Type annotations are trusted as written:
+    /// a project that disables `strictNullChecks` can still assign `null` to an annotated variable.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,expect_diagnostic
+    /// ({ a: 1 })?.a;
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// "text"?.length;
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// new Map()?.get("key");
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// function f() {}
+    /// f?.();
+    /// ```
+    ///
+    /// ```ts,expect_diagnostic
+    /// function g(options: { verbose: boolean }) {
+    ///     return options?.verbose;
+    /// }
+    /// ```
+    ///
+    /// ## Valid
+    ///
+    /// ```js
+    /// foo?.bar;
+    /// ```
+    ///
+    /// ```js
+    /// let f = function () {};
+    /// f = undefined;
+    /// f?.();
+    /// ```
+    ///
+    /// ```ts
+    /// function g(options: { verbose: boolean } | undefined) {
+    ///     return options?.verbose;
+    /// }
+    /// ```
+    pub(crate) NoUnsafeOptionalChain {
+        version: "next",
+        name: "noUnsafeOptionalChain",
+        recommended: false,
+        fix_kind: FixKind::Unsafe,
+    }
+}
+
+declare_node_union! {
+    pub(crate) AnyJsOptionalChain = JsStaticMemberExpression | JsComputedMemberExpression | JsCallExpression
+}
+
+impl AnyJsOptionalChain {
+    /// Returns the `?.` token of the expression, if any
+    fn optional_chain_token(&self) -> Option<JsSyntaxToken> {
+        match self {
+            AnyJsOptionalChain::JsStaticMemberExpression(expression) => expression
+                .operator_token()
+                .ok()
+                .filter(|token| token.kind() == JsSyntaxKind::QUESTIONDOT),
+            AnyJsOptionalChain::JsComputedMemberExpression(expression) => {
+                expression.optional_chain_token()
+            }
+            AnyJsOptionalChain::JsCallExpression(expression) => expression.optional_chain_token(),
+        }
+    }
+
+    /// Returns the expression on the left of the `?.` token
+    fn object(&self) -> Option<AnyJsExpression> {
+        match self {
+            AnyJsOptionalChain::JsStaticMemberExpression(expression) => expression.object().ok(),
+            AnyJsOptionalChain::JsComputedMemberExpression(expression) => expression.object().ok(),
+            AnyJsOptionalChain::JsCallExpression(expression) => expression.callee().ok(),
+        }
+    }
+}
+
+impl Rule for NoUnsafeOptionalChain {
+    type Query = Semantic<AnyJsOptionalChain>;
+    type State = JsSyntaxToken;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let node = ctx.query();
+        let optional_chain_token = node.optional_chain_token()?;
+        let object = node.object()?.omit_parentheses();
+        is_never_nullish(&object, ctx.model()).then_some(optional_chain_token)
+    }
+
+    fn diagnostic(
+        ctx: &RuleContext<Self>,
+        optional_chain_token: &Self::State,
+    ) -> Option<RuleDiagnostic> {
+        let object = ctx.query().object()?;
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                optional_chain_token.text_trimmed_range(),
+                markup! {
+                    "Unnecessary optional chain on a value that is never "<Emphasis>"null"</Emphasis>" or "<Emphasis>"undefined"</Emphasis>"."
+                },
+            )
+            .detail(
+                object.range(),
+                markup! {
+                    "This expression always evaluates to a defined value."
+                },
+            ),
+        )
+    }
+
+    fn action(ctx: &RuleContext<Self>, optional_chain_token: &Self::State) -> Option<JsRuleAction> {
+        let node = ctx.query();
+        // `1?.toString()` can't be turned into `1.toString()`
+        if matches!(
+            node.object()?,
+            AnyJsExpression::AnyJsLiteralExpression(
+                AnyJsLiteralExpression::JsNumberLiteralExpression(_)
+            )
+        ) {
+            return None;
+        }
+        let mut mutation = ctx.root().begin();
+        if matches!(node, AnyJsOptionalChain::JsStaticMemberExpression(_)) {
+            mutation
+                .replace_token_transfer_trivia(optional_chain_token.clone(), make::token(T![.]));
+        } else {
+            mutation.remove_token(optional_chain_token.clone());
+        }
+        Some(JsRuleAction {
+            category: ActionCategory::QuickFix,
+            applicability: Applicability::MaybeIncorrect,
+            message: markup! { "Remove the optional chain." }.to_owned(),
+            mutation,
+        })
+    }
+}
+
+/// Returns `true` if `expression` never evaluates to `null` or `undefined`.
+fn is_never_nullish(expression: &AnyJsExpression, model: &SemanticModel) -> bool {
+    if let AnyJsExpression::JsIdentifierExpression(identifier) = expression {
+        let Some(binding) = identifier
+            .name()
+            .ok()
+            .and_then(|reference| model.binding(&reference))
+        else {
+            return false;
+        };
+        // A binding that is reassigned can hold any value
+        if binding.all_writes().next().is_some() {
+            return false;
+        }
+        binding
+            .tree()
+            .declaration()
+            .is_some_and(|declaration| is_never_nullish_declaration(&declaration, model))
+    } else {
+        is_never_nullish_value(expression)
+    }
+}
+
+/// Returns `true` if `expression` creates a value that is neither `null` nor `undefined`.
+fn is_never_nullish_value(expression: &AnyJsExpression) -> bool {
+    match expression.clone().omit_parentheses() {
+        AnyJsExpression::AnyJsLiteralExpression(literal) => {
+            !matches!(literal, AnyJsLiteralExpression::JsNullLiteralExpression(_))
+        }
+        AnyJsExpression::JsTemplateExpression(template) => template.tag().is_none(),
+        AnyJsExpression::JsObjectExpression(_)
+        | AnyJsExpression::JsArrayExpression(_)
+        | AnyJsExpression::JsFunctionExpression(_)
+        | AnyJsExpression::JsArrowFunctionExpression(_)
+        | AnyJsExpression::JsClassExpression(_)
+        | AnyJsExpression::JsNewExpression(_) => true,
+        _ => false,
+    }
+}
+
+/// Returns `true` if the binding declared by `declaration` is never `null` or `undefined`,
+/// assuming that the binding is never reassigned.
+fn is_never_nullish_declaration(
+    declaration: &AnyJsBindingDeclaration,
+    model: &SemanticModel,
+) -> bool {
+    match declaration {
+        AnyJsBindingDeclaration::JsFunctionDeclaration(_)
+        | AnyJsBindingDeclaration::JsFunctionExportDefaultDeclaration(_)
+        | AnyJsBindingDeclaration::JsFunctionExpression(_)
+        | AnyJsBindingDeclaration::JsClassDeclaration(_)
+        | AnyJsBindingDeclaration::JsClassExportDefaultDeclaration(_)
+        | AnyJsBindingDeclaration::JsClassExpression(_)
+        | AnyJsBindingDeclaration::TsEnumDeclaration(_) => true,
+        AnyJsBindingDeclaration::JsVariableDeclarator(declarator) => {
+            // The annotation of `const { a }: T` describes the object, not `a`
+            if !matches!(declarator.id(), Ok(AnyJsBindingPattern::AnyJsBinding(_))) {
+                return false;
+            }
+            let annotated_type = declarator
+                .variable_annotation()
+                .and_then(|annotation| annotation.type_annotation().ok()??.ty().ok());
+            if let Some(annotated_type) = annotated_type {
+                return is_non_nullable_type(&annotated_type, model);
+            }
+            // A `var` can be read before its initialization, and is then `undefined`
+            let is_var = declarator
+                .declaration()
+                .map_or(true, |declaration| declaration.is_var());
+            !is_var
+                && declarator
+                    .initializer()
+                    .and_then(|initializer| initializer.expression().ok())
+                    .is_some_and(|initializer| is_never_nullish_value(&initializer))
+        }
+        AnyJsBindingDeclaration::JsFormalParameter(parameter) => {
+            if parameter.question_mark_token().is_some()
+                || !matches!(
+                    parameter.binding(),
+                    Ok(AnyJsBindingPattern::AnyJsBinding(_))
+                )
+            {
+                return false;
+            }
+            parameter
+                .type_annotation()
+                .and_then(|annotation| annotation.ty().ok())
+                .is_some_and(|ty| is_non_nullable_type(&ty, model))
+        }
+        _ => false,
+    }
+}
+
+/// Returns `true` if `ty` doesn't include `null` or `undefined`.
+///
+/// Type references are only resolved when they point to a local class, interface, or enum.
+fn is_non_nullable_type(ty: &AnyTsType, model: &SemanticModel) -> bool {
+    match ty {
+        AnyTsType::TsObjectType(_)
+        | AnyTsType::TsArrayType(_)
+        | AnyTsType::TsTupleType(_)
+        | AnyTsType::TsFunctionType(_)
+        | AnyTsType::TsConstructorType(_)
+        | AnyTsType::TsNonPrimitiveType(_)
+        | AnyTsType::TsStringType(_)
+        | AnyTsType::TsNumberType(_)
+        | AnyTsType::TsBigintType(_)
+        | AnyTsType::TsBooleanType(_)
+        | AnyTsType::TsSymbolType(_)
+        | AnyTsType::TsStringLiteralType(_)
+        | AnyTsType::TsNumberLiteralType(_)
+        | AnyTsType::TsBigintLiteralType(_)
+        | AnyTsType::TsBooleanLiteralType(_)
+        | AnyTsType::TsTemplateLiteralType(_) => true,
+        AnyTsType::TsParenthesizedType(ty) => {
+            ty.ty().is_ok_and(|ty| is_non_nullable_type(&ty, model))
+        }
+        AnyTsType::TsTypeOperatorType(ty) => {
+            ty.ty().is_ok_and(|ty| is_non_nullable_type(&ty, model))
+        }
+        AnyTsType::TsUnionType(union) => union
+            .types()
+            .iter()
+            .all(|ty| ty.is_ok_and(|ty| is_non_nullable_type(&ty, model))),
+        AnyTsType::TsIntersectionType(intersection) => intersection
+            .types()
+            .iter()
+            .any(|ty| ty.is_ok_and(|ty| is_non_nullable_type(&ty, model))),
+        AnyTsType::TsReferenceType(reference) => {
+            let Ok(AnyTsName::JsReferenceIdentifier(name)) = reference.name() else {
+                return false;
+            };
+            model
+                .binding(&name)
+                .and_then(|binding| binding.tree().declaration())
+                .is_some_and(|declaration| {
+                    matches!(
+                        declaration,
+                        AnyJsBindingDeclaration::JsClassDeclaration(_)
+                            | AnyJsBindingDeclaration::JsClassExportDefaultDeclaration(_)
+                            | AnyJsBindingDeclaration::TsInterfaceDeclaration(_)
+                            | AnyJsBindingDeclaration::TsEnumDeclaration(_)
+                    )
+                })
+        }
+        _ => false,
+    }
+}
@@ -0,0 +1,284 @@
+use crate::{semantic_services::Semantic, JsRuleAction};
+use biome_analyze::{
+    context::RuleContext, declare_rule, ActionCategory, FixKind, Rule, RuleDiagnostic,
+};
+use biome_console::markup;
+use biome_diagnostics::Applicability;
+use biome_js_factory::make;
+use biome_js_semantic::SemanticModel;
+use biome_js_syntax::{
+    binding_ext::AnyJsBindingDeclaration, AnyJsArrayElement, AnyJsCallArgument, AnyJsExpression,
+    AnyJsLiteralExpression, AnyTsName, AnyTsType, AnyTsVariableAnnotation, JsCallExpression,
+    JsStaticMemberExpression, JsSyntaxToken, TriviaPieceKind, T,
+};
+use biome_rowan::{AstNode, AstSeparatedList, BatchMutationExt};
+
+declare_rule! {
+    /// Prefer an array literal with spread syntax over `Array.prototype.concat()`.
+    ///
+    /// `[...a, ...b]` shows the shape of the resulting array at a glance,
+    /// whereas `a.concat(b)` requires to know how `concat` treats its arguments.
+    ///
+    /// The rule reports `concat` calls with at least one argument and without spread arguments.
+    /// Calls on a value that is known not to be an array, such as a string or a binding annotated with the `string` type,
+    /// are ignored.
+    /// When the rule cannot determine that the callee is an array, the diagnostic asks you to check it.
+    ///
+    /// The fix is unsafe: `concat` flattens its array arguments and appends other values as they are,
+    /// while spread syntax requires an iterable.
+    /// Arguments that are known to be arrays are spread, and arguments that are known not to be arrays are added as they are.
+    /// Other arguments are spread when `concat` is called with a single argument, and added as they are otherwise.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,expect_diagnostic
+    /// const merged = first.concat(second);
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// const list = [1, 2].concat(3, 4);
+    /// ```
+    ///
+    /// ```ts,expect_diagnostic
+    /// function append(items: string[], other: string[]) {
+    ///     return items.concat(other);
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// const merged = [...first, ...second];
+    /// ```
+    ///
+    /// ```js
+    /// const copy = items.concat();
+    /// ```
+    ///
+    /// ```ts
+    /// function greet(name: string) {
+    ///     return "Hello ".concat(name);
+    /// }
+    /// ```
+    ///
+    pub(crate) UseArrayLiteralSpread {
+        version: "next",
+        name: "useArrayLiteralSpread",
+        recommended: false,
+        fix_kind: FixKind::Unsafe,
+    }
+}
+
+pub(crate) struct RuleState {
+    member_expression: JsStaticMemberExpression,
+    /// Whether the object of the `concat` call is known to be an array.
+    is_array: bool,
+}
+
+impl Rule for UseArrayLiteralSpread {
+    type Query = Semantic<JsCallExpression>;
+    type State = RuleState;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let call = ctx.query();
+        if call.is_optional() {
+            return None;
+        }
+        let AnyJsExpression::JsStaticMemberExpression(member_expression) = call.callee().ok()?
+        else {
+            return None;
+        };
+        if member_expression.is_optional()
+            || member_expression
+                .member()
+                .ok()?
+                .as_js_name()?
+                .value_token()
+                .ok()?
+                .text_trimmed()
+                != "concat"
+        {
+            return None;
+        }
+        let arguments = call.arguments().ok()?.args();
+        if arguments.is_empty()
+            || arguments
+                .iter()
+                .any(|argument| !matches!(argument, Ok(AnyJsCallArgument::AnyJsExpression(_))))
+        {
+            return None;
+        }
+        let is_array = match is_array(&member_expression.object().ok()?, ctx.model()) {
+            Some(false) => return None,
+            Some(true) => true,
+            None => false,
+        };
+        Some(RuleState {
+            member_expression,
+            is_array,
+        })
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let diagnostic = RuleDiagnostic::new(
+            rule_category!(),
+            ctx.query().range(),
+            markup! {
+                "Use an array literal with spread syntax instead of "<Emphasis>"concat()"</Emphasis>"."
+            },
+        );
+        if state.is_array {
+            return Some(diagnostic);
+        }
+        let object = state.member_expression.object().ok()?;
+        Some(diagnostic.note(markup! {
+            "Make sure that "<Emphasis>{object.syntax().text_trimmed().to_string()}</Emphasis>" is an array: strings also have a "<Emphasis>"concat()"</Emphasis>" method."
+        }))
+    }
+
+    fn action(ctx: &RuleContext<Self>, state: &Self::State) -> Option<JsRuleAction> {
+        let call = ctx.query();
+        let model = ctx.model();
+        let object = state.member_expression.object().ok()?;
+        let arguments = call.arguments().ok()?.args();
+        let spread_unknown_arguments = arguments.len() == 1;
+
+        let mut elements = Vec::new();
+        push_spread_element(&mut elements, object.trim_trivia()?);
+        for argument in arguments.iter() {
+            let argument = argument.ok()?;
+            let argument = argument.as_any_js_expression()?;
+            let should_spread = match is_array(argument, model) {
+                Some(is_array) => is_array,
+                None => spread_unknown_arguments,
+            };
+            let argument = argument.clone().trim_trivia()?;
+            if should_spread {
+                push_spread_element(&mut elements, argument);
+            } else {
+                elements.push(AnyJsArrayElement::AnyJsExpression(argument));
+            }
+        }
+        let separators: Vec<JsSyntaxToken> = (1..elements.len())
+            .map(|_| make::token(T![,]).with_trailing_trivia([(TriviaPieceKind::Whitespace, " ")]))
+            .collect();
+        let array = make::js_array_expression(
+            make::token(T!['[']),
+            make::js_array_element_list(elements, separators),
+            make::token(T![']']),
+        );
+
+        let mut mutation = ctx.root().begin();
+        mutation.replace_node::<AnyJsExpression>(call.clone().into(), array.into());
+        Some(JsRuleAction {
+            category: ActionCategory::QuickFix,
+            applicability: Applicability::MaybeIncorrect,
+            message: markup! { "Use an array literal with spread syntax." }.to_owned(),
+            mutation,
+        })
+    }
+}
+
+/// Pushes `...expression` to `elements`.
+/// The elements of an array literal are pushed as they are to avoid spreading an array literal.
+fn push_spread_element(elements: &mut Vec<AnyJsArrayElement>, expression: AnyJsExpression) {
+    if let AnyJsExpression::JsArrayExpression(array) = &expression {
+        let inner_elements: Option<Vec<_>> = array
+            .elements()
+            .iter()
+            .map(|element| element.ok()?.trim_trivia())
+            .collect();
+        if let Some(inner_elements) = inner_elements {
+            elements.extend(inner_elements);
+            return;
+        }
+    }
+    elements.push(AnyJsArrayElement::JsSpread(make::js_spread(
+        make::token(T![...]),
+        expression,
+    )));
+}
+
+/// Returns `Some(true)` if `expression` is known to be an array,
+/// `Some(false)` if it is known not to be an array, and `None` otherwise.
+fn is_array(expression: &AnyJsExpression, model: &SemanticModel) -> Option<bool> {
+    match expression.clone().omit_parentheses() {
+        AnyJsExpression::JsArrayExpression(_) => Some(true),
+        AnyJsExpression::AnyJsLiteralExpression(_) | AnyJsExpression::JsTemplateExpression(_) => {
+            Some(false)
+        }
+        AnyJsExpression::JsIdentifierExpression(identifier) => {
+            let binding = model.binding(&identifier.name().ok()?)?.tree();
+            if binding.is_under_pattern_binding()? {
+                return None;
+            }
+            match binding.declaration()? {
+                AnyJsBindingDeclaration::JsVariableDeclarator(declarator) => {
+                    match declarator.variable_annotation() {
+                        Some(AnyTsVariableAnnotation::TsTypeAnnotation(annotation)) => {
+                            is_array_type(&annotation.ty().ok()?)
+                        }
+                        Some(AnyTsVariableAnnotation::TsDefiniteVariableAnnotation(annotation)) => {
+                            is_array_type(&annotation.type_annotation().ok()?.ty().ok()?)
+                        }
+                        None => {
+                            let declaration = declarator.declaration()?;
+                            if !declaration.is_const() {
+                                return None;
+                            }
+                            match declarator.initializer()?.expression().ok()? {
+                                AnyJsExpression::JsArrayExpression(_) => Some(true),
+                                AnyJsExpression::AnyJsLiteralExpression(
+                                    AnyJsLiteralExpression::JsStringLiteralExpression(_),
+                                )
+                                | AnyJsExpression::JsTemplateExpression(_) => Some(false),
+                                _ => None,
+                            }
+                        }
+                    }
+                }
+                AnyJsBindingDeclaration::JsFormalParameter(parameter) => {
+                    is_array_type(&parameter.type_annotation()?.ty().ok()?)
+                }
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+/// Returns `Some(true)` if `ty` is an array or tuple type,
+/// `Some(false)` if it is a string type, and `None` otherwise.
+/// A union type is classified only when all its members agree.
+fn is_array_type(ty: &AnyTsType) -> Option<bool> {
+    match ty {
+        AnyTsType::TsArrayType(_) | AnyTsType::TsTupleType(_) => Some(true),
+        AnyTsType::TsStringType(_)
+        | AnyTsType::TsStringLiteralType(_)
+        | AnyTsType::TsTemplateLiteralType(_) => Some(false),
+        AnyTsType::TsTypeOperatorType(operator) => {
+            let is_readonly = operator.operator_token().ok()?.kind() == T![readonly];
+            is_readonly.then(|| is_array_type(&operator.ty().ok()?))?
+        }
+        AnyTsType::TsParenthesizedType(parenthesized) => is_array_type(&parenthesized.ty().ok()?),
+        AnyTsType::TsUnionType(union) => {
+            let mut types = union.types().iter();
+            let first = is_array_type(&types.next()?.ok()?)?;
+            types
+                .all(|ty| ty.ok().and_then(|ty| is_array_type(&ty)) == Some(first))
+                .then_some(first)
+        }
+        AnyTsType::TsReferenceType(reference) => {
+            let AnyTsName::JsReferenceIdentifier(name) = reference.name().ok()? else {
+                return None;
+            };
+            let name = name.value_token().ok()?;
+            matches!(name.text_trimmed(), "Array" | "ReadonlyArray").then_some(true)
+        }
+        _ => None,
+    }
+}
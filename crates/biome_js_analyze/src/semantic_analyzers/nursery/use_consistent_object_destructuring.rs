@@ -0,0 +1,377 @@
+use crate::{semantic_services::Semantic, JsRuleAction};
+use biome_analyze::{
+    context::RuleContext, declare_rule, ActionCategory, FixKind, Rule, RuleDiagnostic,
+};
+use biome_console::markup;
+use biome_deserialize::json::{has_only_known_keys, VisitJsonNode};
+use biome_deserialize::{DeserializationDiagnostic, VisitNode};
+use biome_diagnostics::Applicability;
+use biome_js_factory::make;
+use biome_js_semantic::SemanticModel;
+use biome_js_syntax::{
+    AnyJsBinding, AnyJsBindingPattern, AnyJsExpression, AnyJsName, AnyJsObjectBindingPatternMember,
+    AnyJsObjectMemberName, JsIdentifierBinding, JsIdentifierExpression, JsSyntaxKind,
+    JsVariableStatement, TextRange, T,
+};
+use biome_json_syntax::JsonLanguage;
+use biome_rowan::{
+    AstNode, AstSeparatedList, BatchMutationExt, Direction, SyntaxNode, TriviaPieceKind,
+};
+use bpaf::Bpaf;
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+declare_rule! {
+    /// Enforce destructuring when several properties of the same object are read in a row.
+    ///
+    /// Declaring one variable per property of an object is verbose,
+    /// and hides that all the variables come from the same object.
+    /// A single destructuring declaration is shorter and clearer.
+    ///
+    /// The rule reports consecutive variable declarations that each read a property of the same variable.
+    /// Two accesses are considered to target the same object only if they refer to the same binding:
+    /// variables that share a name but are declared in different scopes aren't grouped.
+    /// Declarations of different kinds, such as `const` and `let`, aren't grouped either.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,expect_diagnostic
+    /// const name = user.name;
+    /// const email = user.email;
+    /// const role = user.role;
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// let width = size.width;
+    /// let height = size.height;
+    /// let ratio = size.aspectRatio;
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// const { name, email, role } = user;
+    /// ```
+    ///
+    /// ```js
+    /// const name = user.name;
+    /// const email = user.email;
+    /// ```
+    ///
+    /// ```js
+    /// const name = user.name;
+    /// let email = user.email;
+    /// const role = user.role;
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// The rule has an option `minProperties`, the number of consecutive declarations from which destructuring is enforced.
+    /// It defaults to `3` and must be at least `2`.
+    ///
+    /// ```json
+    /// {
+    ///     "//": "...",
+    ///     "options": {
+    ///         "minProperties": 2
+    ///     }
+    /// }
+    /// ```
+    ///
+    pub(crate) UseConsistentObjectDestructuring {
+        version: "next",
+        name: "useConsistentObjectDestructuring",
+        recommended: false,
+        fix_kind: FixKind::Unsafe,
+    }
+}
+
+/// Consecutive declarations that read a property of the same object.
+pub(crate) struct PropertyAccesses {
+    object: JsIdentifierExpression,
+    declarations: Vec<PropertyAccess>,
+}
+
+impl PropertyAccesses {
+    fn range(&self) -> TextRange {
+        let start = self.declarations[0].statement.range().start();
+        let end = self.declarations[self.declarations.len() - 1]
+            .statement
+            .range()
+            .end();
+        TextRange::new(start, end)
+    }
+}
+
+/// A declaration such as `const local = object.property;`.
+struct PropertyAccess {
+    statement: JsVariableStatement,
+    local: JsIdentifierBinding,
+    property: String,
+}
+
+impl Rule for UseConsistentObjectDestructuring {
+    type Query = Semantic<JsVariableStatement>;
+    type State = PropertyAccesses;
+    type Signals = Option<Self::State>;
+    type Options = ObjectDestructuringOptions;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let statement = ctx.query();
+        let model = ctx.model();
+        let (object, kind, access) = property_access(statement.clone())?;
+        // Only the first declaration of a sequence reports the whole sequence.
+        let previous = statement
+            .syntax()
+            .prev_sibling()
+            .and_then(JsVariableStatement::cast)
+            .and_then(property_access);
+        if previous.is_some_and(|(previous_object, previous_kind, _)| {
+            previous_kind == kind && is_same_binding(&previous_object, &object, model)
+        }) {
+            return None;
+        }
+        let mut declarations = vec![access];
+        for next in statement.syntax().siblings(Direction::Next).skip(1) {
+            let Some((next_object, next_kind, next)) =
+                JsVariableStatement::cast(next).and_then(property_access)
+            else {
+                break;
+            };
+            if next_kind != kind || !is_same_binding(&next_object, &object, model) {
+                break;
+            }
+            declarations.push(next);
+        }
+        (declarations.len() >= usize::from(ctx.options().min_properties)).then_some(
+            PropertyAccesses {
+                object,
+                declarations,
+            },
+        )
+    }
+
+    fn diagnostic(_: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let object = state.object.syntax().text_trimmed();
+        let count = state.declarations.len();
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                state.range(),
+                markup! {
+                    "These "{count}" declarations read properties of "<Emphasis>{object.to_string()}</Emphasis>" one by one."
+                },
+            )
+            .note(markup! {
+                "Destructure the properties in a single declaration instead."
+            }),
+        )
+    }
+
+    fn action(ctx: &RuleContext<Self>, state: &Self::State) -> Option<JsRuleAction> {
+        let (first, rest) = state.declarations.split_first()?;
+        // Comments attached to the removed parts of the declarations would be lost.
+        if rest
+            .iter()
+            .any(|access| access.statement.syntax().has_comments_descendants())
+        {
+            return None;
+        }
+        let declarator = first
+            .statement
+            .declaration()
+            .ok()?
+            .declarators()
+            .iter()
+            .next()?
+            .ok()?;
+        if declarator.syntax().has_comments_descendants() {
+            return None;
+        }
+        let id = declarator.id().ok()?;
+        let initializer = declarator.initializer()?.expression().ok()?;
+
+        let members = state.declarations.iter().map(|access| {
+            let local = access.local.clone().trim_trivia()?;
+            let name = local.name_token().ok()?;
+            let member = if name.text_trimmed() == access.property {
+                make::js_object_binding_pattern_shorthand_property(AnyJsBinding::from(local))
+                    .build()
+                    .into()
+            } else {
+                make::js_object_binding_pattern_property(
+                    AnyJsObjectMemberName::from(make::js_literal_member_name(make::ident(
+                        &access.property,
+                    ))),
+                    make::token(T![:]).with_trailing_trivia([(TriviaPieceKind::Whitespace, " ")]),
+                    AnyJsBindingPattern::AnyJsBinding(AnyJsBinding::from(local)),
+                )
+                .build()
+                .into()
+            };
+            Some(member)
+        });
+        let members = members.collect::<Option<Vec<AnyJsObjectBindingPatternMember>>>()?;
+        let separators = (1..members.len())
+            .map(|_| make::token(T![,]).with_trailing_trivia([(TriviaPieceKind::Whitespace, " ")]));
+        let pattern = make::js_object_binding_pattern(
+            make::token(T!['{']).with_trailing_trivia([(TriviaPieceKind::Whitespace, " ")]),
+            make::js_object_binding_pattern_property_list(members, separators),
+            make::token(T!['}']).with_leading_trivia([(TriviaPieceKind::Whitespace, " ")]),
+        );
+        let pattern = AnyJsBindingPattern::from(pattern)
+            .with_leading_trivia_pieces(id.syntax().first_leading_trivia()?.pieces())?
+            .with_trailing_trivia_pieces(id.syntax().last_trailing_trivia()?.pieces())?;
+        let object = AnyJsExpression::from(state.object.clone())
+            .with_leading_trivia_pieces(initializer.syntax().first_leading_trivia()?.pieces())?
+            .with_trailing_trivia_pieces(initializer.syntax().last_trailing_trivia()?.pieces())?;
+
+        let mut mutation = ctx.root().begin();
+        mutation.replace_node(id, pattern);
+        mutation.replace_node(initializer, object);
+        for access in rest {
+            mutation.remove_node(access.statement.clone());
+        }
+        Some(JsRuleAction {
+            category: ActionCategory::QuickFix,
+            applicability: Applicability::MaybeIncorrect,
+            message: markup! { "Use object destructuring." }.to_owned(),
+            mutation,
+        })
+    }
+}
+
+/// Returns the object, the kind of the declaration (`const`, `let`, or `var`), and the accessed property
+/// if `statement` declares a single variable initialized with a property of a variable.
+fn property_access(
+    statement: JsVariableStatement,
+) -> Option<(JsIdentifierExpression, JsSyntaxKind, PropertyAccess)> {
+    let declaration = statement.declaration().ok()?;
+    if declaration.await_token().is_some() {
+        return None;
+    }
+    let kind = declaration.kind().ok()?.kind();
+    let mut declarators = declaration.declarators().iter();
+    let declarator = declarators.next()?.ok()?;
+    if declarators.next().is_some() || declarator.variable_annotation().is_some() {
+        return None;
+    }
+    let local = declarator
+        .id()
+        .ok()?
+        .as_any_js_binding()?
+        .as_js_identifier_binding()?
+        .clone();
+    let member = declarator
+        .initializer()?
+        .expression()
+        .ok()?
+        .as_js_static_member_expression()?
+        .clone();
+    // `object?.property` doesn't throw when `object` is nullish, unlike destructuring.
+    if member.is_optional_chain() {
+        return None;
+    }
+    let AnyJsName::JsName(property) = member.member().ok()? else {
+        return None;
+    };
+    let object = member.object().ok()?.as_js_identifier_expression()?.clone();
+    let property = property
+        .value_token()
+        .ok()?
+        .token_text_trimmed()
+        .to_string();
+    Some((
+        object,
+        kind,
+        PropertyAccess {
+            statement,
+            local,
+            property,
+        },
+    ))
+}
+
+/// Returns `true` if `first` and `second` reference the same binding,
+/// or the same global variable.
+fn is_same_binding(
+    first: &JsIdentifierExpression,
+    second: &JsIdentifierExpression,
+    model: &SemanticModel,
+) -> bool {
+    let (Ok(first), Ok(second)) = (first.name(), second.name()) else {
+        return false;
+    };
+    match (model.binding(&first), model.binding(&second)) {
+        (Some(first), Some(second)) => first.syntax() == second.syntax(),
+        (None, None) => first.name().ok() == second.name().ok(),
+        _ => false,
+    }
+}
+
+/// Options for the rule `useConsistentObjectDestructuring`.
+#[derive(Deserialize, Serialize, Eq, PartialEq, Debug, Clone, Bpaf)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct ObjectDestructuringOptions {
+    /// The number of consecutive declarations reading properties of the same object from which destructuring is enforced.
+    pub min_properties: u8,
+}
+
+impl Default for ObjectDestructuringOptions {
+    fn default() -> Self {
+        Self { min_properties: 3 }
+    }
+}
+
+impl ObjectDestructuringOptions {
+    pub(crate) const KNOWN_KEYS: &'static [&'static str] = &["minProperties"];
+}
+
+// Required by [Bpaf].
+impl FromStr for ObjectDestructuringOptions {
+    type Err = &'static str;
+
+    fn from_str(_s: &str) -> Result<Self, Self::Err> {
+        // WARNING: should not be used.
+        Ok(Self::default())
+    }
+}
+
+impl VisitNode<JsonLanguage> for ObjectDestructuringOptions {
+    fn visit_member_name(
+        &mut self,
+        node: &SyntaxNode<JsonLanguage>,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<()> {
+        has_only_known_keys(node, Self::KNOWN_KEYS, diagnostics)
+    }
+
+    fn visit_map(
+        &mut self,
+        key: &SyntaxNode<JsonLanguage>,
+        value: &SyntaxNode<JsonLanguage>,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<()> {
+        let (name, value) = self.get_key_and_value(key, value, diagnostics)?;
+        let name_text = name.text();
+        if name_text == "minProperties" {
+            let min_properties = self.map_to_u8(&value, name_text, u8::MAX, diagnostics)?;
+            // A single declaration can't be merged with another one.
+            if min_properties < 2 {
+                diagnostics.push(
+                    DeserializationDiagnostic::new(markup! {
+                        "The field "<Emphasis>"minProperties"</Emphasis>" must be greater than 1"
+                    })
+                    .with_range(value.range()),
+                );
+                return None;
+            }
+            self.min_properties = min_properties;
+        }
+
+        Some(())
+    }
+}
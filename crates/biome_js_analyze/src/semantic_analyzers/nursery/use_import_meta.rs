@@ -0,0 +1,364 @@
+use crate::{semantic_services::Semantic, JsRuleAction};
+use biome_analyze::{
+    context::RuleContext, declare_rule, ActionCategory, FixKind, Rule, RuleDiagnostic,
+};
+use biome_console::markup;
+use biome_deserialize::json::{has_only_known_keys, with_only_known_variants, VisitJsonNode};
+use biome_deserialize::{DeserializationDiagnostic, VisitNode};
+use biome_diagnostics::Applicability;
+use biome_js_factory::make;
+use biome_js_semantic::SemanticModel;
+use biome_js_syntax::{
+    AnyJsCallArgument, AnyJsExpression, AnyJsModuleItem, AnyJsName, AnyJsRoot, JsFileSource,
+    JsIdentifierExpression, JsReferenceIdentifier, JsStaticMemberExpression, JsSyntaxKind,
+    JsSyntaxToken, JsUnaryExpression, JsUnaryOperator, TriviaPieceKind, T,
+};
+use biome_json_syntax::JsonLanguage;
+use biome_rowan::{AstNode, AstNodeList, BatchMutationExt, SyntaxNode};
+use bpaf::Bpaf;
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+declare_rule! {
+    /// Disallow `__dirname` and `__filename` in ES modules.
+    ///
+    /// `__dirname` and `__filename` are defined by Node.js for CommonJS modules only.
+    /// In an ES module, they throw a `ReferenceError`, and the path of the module is derived from `import.meta.url`.
+    ///
+    /// A file is an ES module when it has the `.mjs` extension, or when it has an `import` or an `export` statement.
+    /// The files that call `require` are considered CommonJS modules, and are ignored.
+    /// The references to a local `__dirname` or `__filename` variable,
+    /// and the `typeof __dirname` checks, are also ignored.
+    ///
+    /// The fix replaces `__dirname` and `__filename` with `import.meta.dirname` and `import.meta.filename`,
+    /// which are available in Node.js 20.11 and later.
+    /// With the option `runtime` set to `"browser"`, the fix uses the `pathname` of a `URL` instead.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,expect_diagnostic
+    /// import { join } from "node:path";
+    /// const config = join(__dirname, "config.json");
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// export const file = __filename;
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// import { join } from "node:path";
+    /// const config = join(import.meta.dirname, "config.json");
+    /// ```
+    ///
+    /// ```js
+    /// const { join } = require("node:path");
+    /// const config = join(__dirname, "config.json");
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// The option `runtime` sets the replacement suggested by the fix.
+    /// It accepts `"node"`, the default, and `"browser"`.
+    ///
+    /// | | `"node"` | `"browser"` |
+    /// | --- | --- | --- |
+    /// | `__dirname` | `import.meta.dirname` | `new URL(".", import.meta.url).pathname` |
+    /// | `__filename` | `import.meta.filename` | `new URL(import.meta.url).pathname` |
+    ///
+    /// ```json
+    /// {
+    ///     "//": "...",
+    ///     "options": {
+    ///         "runtime": "browser"
+    ///     }
+    /// }
+    /// ```
+    ///
+    pub(crate) UseImportMeta {
+        version: "next",
+        name: "useImportMeta",
+        recommended: false,
+        fix_kind: FixKind::Unsafe,
+    }
+}
+
+/// A CommonJS global that is replaced by `import.meta` in ES modules
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum CommonJsPath {
+    /// `__dirname`
+    Dirname,
+    /// `__filename`
+    Filename,
+}
+
+impl CommonJsPath {
+    const fn name(self) -> &'static str {
+        match self {
+            Self::Dirname => "__dirname",
+            Self::Filename => "__filename",
+        }
+    }
+
+    const fn replacement(self, runtime: ImportMetaRuntime) -> &'static str {
+        match (self, runtime) {
+            (Self::Dirname, ImportMetaRuntime::Node) => "import.meta.dirname",
+            (Self::Filename, ImportMetaRuntime::Node) => "import.meta.filename",
+            (Self::Dirname, ImportMetaRuntime::Browser) => {
+                "new URL(\".\", import.meta.url).pathname"
+            }
+            (Self::Filename, ImportMetaRuntime::Browser) => "new URL(import.meta.url).pathname",
+        }
+    }
+}
+
+impl Rule for UseImportMeta {
+    type Query = Semantic<JsReferenceIdentifier>;
+    type State = CommonJsPath;
+    type Signals = Option<Self::State>;
+    type Options = ImportMetaOptions;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let reference = ctx.query();
+        let path = match reference.value_token().ok()?.text_trimmed() {
+            "__dirname" => CommonJsPath::Dirname,
+            "__filename" => CommonJsPath::Filename,
+            _ => return None,
+        };
+        let model = ctx.model();
+        if model.binding(reference).is_some() || is_typeof_operand(reference) {
+            return None;
+        }
+        if !is_es_module(ctx) || calls_require(model) {
+            return None;
+        }
+        Some(path)
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, path: &Self::State) -> Option<RuleDiagnostic> {
+        let name = path.name();
+        let replacement = path.replacement(ctx.options().runtime);
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                ctx.query().range(),
+                markup! {
+                    <Emphasis>{name}</Emphasis>" isn't defined in ES modules."
+                },
+            )
+            .note(markup! {
+                "Use "<Emphasis>{replacement}</Emphasis>" instead."
+            }),
+        )
+    }
+
+    fn action(ctx: &RuleContext<Self>, path: &Self::State) -> Option<JsRuleAction> {
+        let expression = ctx.query().parent::<JsIdentifierExpression>()?;
+        let runtime = ctx.options().runtime;
+        let replacement = match (path, runtime) {
+            (CommonJsPath::Dirname, ImportMetaRuntime::Node) => {
+                import_meta_member("dirname").into()
+            }
+            (CommonJsPath::Filename, ImportMetaRuntime::Node) => {
+                import_meta_member("filename").into()
+            }
+            (CommonJsPath::Dirname, ImportMetaRuntime::Browser) => url_pathname(Some(".")),
+            (CommonJsPath::Filename, ImportMetaRuntime::Browser) => url_pathname(None),
+        };
+        let replacement_text = path.replacement(runtime);
+
+        let mut mutation = ctx.root().begin();
+        mutation.replace_node(AnyJsExpression::from(expression), replacement);
+
+        Some(JsRuleAction {
+            category: ActionCategory::QuickFix,
+            applicability: Applicability::MaybeIncorrect,
+            message: markup! { "Use "<Emphasis>{replacement_text}</Emphasis>" instead." }
+                .to_owned(),
+            mutation,
+        })
+    }
+}
+
+/// Returns `true` if the file is an ES module
+fn is_es_module(ctx: &RuleContext<UseImportMeta>) -> bool {
+    // `.cjs` files are scripts
+    if !ctx.source_type::<JsFileSource>().is_module() {
+        return false;
+    }
+    if ctx
+        .file_path()
+        .extension()
+        .is_some_and(|extension| extension == "mjs")
+    {
+        return true;
+    }
+    let AnyJsRoot::JsModule(module) = ctx.root() else {
+        return false;
+    };
+    module.items().iter().any(|item| {
+        matches!(
+            item,
+            AnyJsModuleItem::JsImport(_) | AnyJsModuleItem::JsExport(_)
+        )
+    })
+}
+
+/// Returns `true` if the file references the global `require`
+fn calls_require(model: &SemanticModel) -> bool {
+    model
+        .all_unresolved_references()
+        .any(|reference| reference.syntax().text_trimmed() == "require")
+}
+
+/// Returns `true` if `reference` is the operand of `typeof`, such as `typeof __dirname`
+fn is_typeof_operand(reference: &JsReferenceIdentifier) -> bool {
+    reference
+        .parent::<JsIdentifierExpression>()
+        .and_then(|expression| expression.parent::<JsUnaryExpression>())
+        .is_some_and(|unary| matches!(unary.operator(), Ok(JsUnaryOperator::Typeof)))
+}
+
+/// Creates `import.meta.<member>`
+fn import_meta_member(member: &str) -> JsStaticMemberExpression {
+    let import_meta = make::js_import_meta_expression(
+        make::token(T![import]),
+        make::token(T![.]),
+        JsSyntaxToken::new_detached(JsSyntaxKind::META, "meta", [], []),
+    );
+    make::js_static_member_expression(
+        import_meta.into(),
+        make::token(T![.]),
+        AnyJsName::JsName(make::js_name(make::ident(member))),
+    )
+}
+
+/// Creates `new URL(<base>, import.meta.url).pathname`, or `new URL(import.meta.url).pathname`
+/// when there is no base.
+fn url_pathname(base: Option<&str>) -> AnyJsExpression {
+    let url: AnyJsExpression = import_meta_member("url").into();
+    let (arguments, separators) = match base {
+        Some(base) => (
+            vec![
+                AnyJsCallArgument::AnyJsExpression(AnyJsExpression::AnyJsLiteralExpression(
+                    make::js_string_literal_expression(make::js_string_literal(base)).into(),
+                )),
+                AnyJsCallArgument::AnyJsExpression(url),
+            ],
+            vec![make::token(T![,]).with_trailing_trivia([(TriviaPieceKind::Whitespace, " ")])],
+        ),
+        None => (vec![AnyJsCallArgument::AnyJsExpression(url)], vec![]),
+    };
+    let new_url = make::js_new_expression(
+        make::token(T![new]).with_trailing_trivia([(TriviaPieceKind::Whitespace, " ")]),
+        make::js_identifier_expression(make::js_reference_identifier(make::ident("URL"))).into(),
+    )
+    .with_arguments(make::js_call_arguments(
+        make::token(T!['(']),
+        make::js_call_argument_list(arguments, separators),
+        make::token(T![')']),
+    ))
+    .build();
+    make::js_static_member_expression(
+        new_url.into(),
+        make::token(T![.]),
+        AnyJsName::JsName(make::js_name(make::ident("pathname"))),
+    )
+    .into()
+}
+
+/// Options for the rule `useImportMeta`.
+#[derive(Default, Deserialize, Serialize, Eq, PartialEq, Debug, Clone, Bpaf)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct ImportMetaOptions {
+    /// The runtime of the code, which sets the replacement suggested by the fix.
+    #[bpaf(hide)]
+    #[serde(default)]
+    pub runtime: ImportMetaRuntime,
+}
+
+impl ImportMetaOptions {
+    pub(crate) const KNOWN_KEYS: &'static [&'static str] = &["runtime"];
+}
+
+// Required by [Bpaf].
+impl FromStr for ImportMetaOptions {
+    type Err = &'static str;
+
+    fn from_str(_s: &str) -> Result<Self, Self::Err> {
+        // WARNING: should not be used.
+        Ok(Self::default())
+    }
+}
+
+impl VisitNode<JsonLanguage> for ImportMetaOptions {
+    fn visit_member_name(
+        &mut self,
+        node: &SyntaxNode<JsonLanguage>,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<()> {
+        has_only_known_keys(node, Self::KNOWN_KEYS, diagnostics)
+    }
+
+    fn visit_map(
+        &mut self,
+        key: &SyntaxNode<JsonLanguage>,
+        value: &SyntaxNode<JsonLanguage>,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<()> {
+        let (name, value) = self.get_key_and_value(key, value, diagnostics)?;
+        let name_text = name.text();
+        if name_text == "runtime" {
+            let mut runtime = ImportMetaRuntime::default();
+            self.map_to_known_string(&value, name_text, &mut runtime, diagnostics)?;
+            self.runtime = runtime;
+        }
+        Some(())
+    }
+}
+
+/// A runtime that supports ES modules.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub enum ImportMetaRuntime {
+    /// Node.js 20.11 or later
+    #[default]
+    Node,
+    /// A browser
+    Browser,
+}
+
+impl ImportMetaRuntime {
+    pub const KNOWN_VALUES: &'static [&'static str] = &["node", "browser"];
+}
+
+// Required by [Bpaf].
+impl FromStr for ImportMetaRuntime {
+    type Err = &'static str;
+
+    fn from_str(_s: &str) -> Result<Self, Self::Err> {
+        // WARNING: should not be used.
+        Ok(Self::default())
+    }
+}
+
+impl VisitNode<JsonLanguage> for ImportMetaRuntime {
+    fn visit_member_value(
+        &mut self,
+        node: &SyntaxNode<JsonLanguage>,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<()> {
+        let node = with_only_known_variants(node, Self::KNOWN_VALUES, diagnostics)?;
+        match node.inner_string_text().ok()?.text() {
+            "node" => *self = Self::Node,
+            "browser" => *self = Self::Browser,
+            _ => (),
+        }
+        Some(())
+    }
+}
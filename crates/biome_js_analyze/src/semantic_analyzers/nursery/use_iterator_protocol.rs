@@ -0,0 +1,261 @@
+use crate::semantic_services::Semantic;
+use biome_analyze::context::RuleContext;
+use biome_analyze::{declare_rule, Rule, RuleDiagnostic};
+use biome_console::markup;
+use biome_js_semantic::{SemanticModel, WellKnownSymbol};
+use biome_js_syntax::{
+    AnyJsClass, AnyJsClassMember, AnyJsClassMemberName, AnyJsExpression, AnyJsMethodModifier,
+    AnyJsObjectMember, AnyJsObjectMemberName, AnyJsPropertyModifier, JsComputedMemberName,
+    JsLiteralMemberName, JsObjectExpression, TextRange,
+};
+use biome_rowan::{declare_node_union, AstNode, AstNodeList, AstSeparatedList};
+
+declare_rule! {
+    /// Require iterators to also implement the iterable protocol.
+    ///
+    /// An object with a `next()` method implements the iterator protocol,
+    /// but it can only be used in `for...of` loops, spread, or destructured
+    /// when it also implements the iterable protocol with a `[Symbol.iterator]()` method.
+    /// The built-in iterators return `this` from this method,
+    /// so that they can be used wherever an iterable is expected.
+    ///
+    /// The rule reports the object literals and the classes that define a `next()` method
+    /// without defining a `[Symbol.iterator]()` method.
+    /// An `async` `next()` method is expected to come with a `[Symbol.asyncIterator]()` method.
+    ///
+    /// The classes that extend another class aren't reported, because the parent class can
+    /// implement the iterable protocol. Neither are the object literals with a spread,
+    /// nor the generators, whose objects already implement both protocols.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,expect_diagnostic
+    /// class Counter {
+    ///     count = 0;
+    ///     next() {
+    ///         return { value: this.count++, done: false };
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// const iterator = {
+    ///     next() {
+    ///         return { value: undefined, done: true };
+    ///     },
+    /// };
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// class Counter {
+    ///     count = 0;
+    ///     next() {
+    ///         return { value: this.count++, done: false };
+    ///     }
+    ///     [Symbol.iterator]() {
+    ///         return this;
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ```js
+    /// function* counter() {
+    ///     let count = 0;
+    ///     while (true) yield count++;
+    /// }
+    /// ```
+    ///
+    pub(crate) UseIteratorProtocol {
+        version: "next",
+        name: "useIteratorProtocol",
+        recommended: false,
+    }
+}
+
+declare_node_union! {
+    pub(crate) AnyIteratorCandidate = AnyJsClass | JsObjectExpression
+}
+
+pub(crate) struct IteratorState {
+    /// Range of the name of the `next()` method
+    next_range: TextRange,
+    /// Whether the `next()` method is `async`
+    is_async: bool,
+}
+
+impl Rule for UseIteratorProtocol {
+    type Query = Semantic<AnyIteratorCandidate>;
+    type State = IteratorState;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let model = ctx.model();
+        let mut state = None;
+        match ctx.query() {
+            AnyIteratorCandidate::AnyJsClass(class) => {
+                if class.extends_clause().is_some() {
+                    return None;
+                }
+                for member in class.members().iter() {
+                    if let Some(next) = class_next_method(&member) {
+                        state = Some(next);
+                    } else if class_member_name(&member)
+                        .is_some_and(|name| is_iterator_symbol(&name, model))
+                    {
+                        return None;
+                    }
+                }
+            }
+            AnyIteratorCandidate::JsObjectExpression(object) => {
+                for member in object.members().iter() {
+                    let member = member.ok()?;
+                    if matches!(member, AnyJsObjectMember::JsSpread(_)) {
+                        return None;
+                    }
+                    if let Some(next) = object_next_method(&member) {
+                        state = Some(next);
+                    } else if object_member_name(&member)
+                        .is_some_and(|name| is_iterator_symbol(&name, model))
+                    {
+                        return None;
+                    }
+                }
+            }
+        }
+        state
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let kind = match ctx.query() {
+            AnyIteratorCandidate::AnyJsClass(_) => "class",
+            AnyIteratorCandidate::JsObjectExpression(_) => "object",
+        };
+        let (symbol, protocol, loop_statement) = if state.is_async {
+            ("asyncIterator", "async ", "for await...of")
+        } else {
+            ("iterator", "", "for...of")
+        };
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                state.next_range,
+                markup! {
+                    "This "{kind}" implements the "{protocol}"iterator protocol, but not the "{protocol}"iterable protocol."
+                },
+            )
+            .note(markup! {
+                "It can't be used in "<Emphasis>{loop_statement}</Emphasis>" loops."
+            })
+            .note(markup! {
+                "Add the method "<Emphasis>"[Symbol."{symbol}"]() { return this; }"</Emphasis>"."
+            }),
+        )
+    }
+}
+
+/// Returns the state of the rule if `member` is a `next()` method of the instances of a class.
+fn class_next_method(member: &AnyJsClassMember) -> Option<IteratorState> {
+    let (name, is_async) = match member {
+        AnyJsClassMember::JsMethodClassMember(method) => {
+            if method
+                .modifiers()
+                .iter()
+                .any(|modifier| matches!(modifier, AnyJsMethodModifier::JsStaticModifier(_)))
+            {
+                return None;
+            }
+            (method.name().ok()?, method.async_token().is_some())
+        }
+        AnyJsClassMember::JsPropertyClassMember(property) => {
+            if property
+                .modifiers()
+                .iter()
+                .any(|modifier| matches!(modifier, AnyJsPropertyModifier::JsStaticModifier(_)))
+            {
+                return None;
+            }
+            let value = property.value()?.expression().ok()?;
+            (property.name().ok()?, is_async_function(&value)?)
+        }
+        _ => return None,
+    };
+    let AnyJsClassMemberName::JsLiteralMemberName(name) = name else {
+        return None;
+    };
+    next_method_state(&name, is_async)
+}
+
+/// Returns the state of the rule if `member` is a `next()` method of an object literal.
+fn object_next_method(member: &AnyJsObjectMember) -> Option<IteratorState> {
+    let (name, is_async) = match member {
+        AnyJsObjectMember::JsMethodObjectMember(method) => {
+            (method.name().ok()?, method.async_token().is_some())
+        }
+        AnyJsObjectMember::JsPropertyObjectMember(property) => {
+            let value = property.value().ok()?;
+            (property.name().ok()?, is_async_function(&value)?)
+        }
+        _ => return None,
+    };
+    let AnyJsObjectMemberName::JsLiteralMemberName(name) = name else {
+        return None;
+    };
+    next_method_state(&name, is_async)
+}
+
+fn next_method_state(name: &JsLiteralMemberName, is_async: bool) -> Option<IteratorState> {
+    (name.name().ok()? == "next").then(|| IteratorState {
+        next_range: name.range(),
+        is_async,
+    })
+}
+
+/// Returns whether `expression` is an `async` function, or `None` if it isn't a function.
+fn is_async_function(expression: &AnyJsExpression) -> Option<bool> {
+    let async_token = match expression.clone().omit_parentheses() {
+        AnyJsExpression::JsFunctionExpression(function) => function.async_token(),
+        AnyJsExpression::JsArrowFunctionExpression(function) => function.async_token(),
+        _ => return None,
+    };
+    Some(async_token.is_some())
+}
+
+fn class_member_name(member: &AnyJsClassMember) -> Option<JsComputedMemberName> {
+    match member.name().ok()?? {
+        AnyJsClassMemberName::JsComputedMemberName(name) => Some(name),
+        _ => None,
+    }
+}
+
+fn object_member_name(member: &AnyJsObjectMember) -> Option<JsComputedMemberName> {
+    let name = match member {
+        AnyJsObjectMember::JsMethodObjectMember(method) => method.name().ok()?,
+        AnyJsObjectMember::JsPropertyObjectMember(property) => property.name().ok()?,
+        AnyJsObjectMember::JsGetterObjectMember(getter) => getter.name().ok()?,
+        _ => return None,
+    };
+    match name {
+        AnyJsObjectMemberName::JsComputedMemberName(name) => Some(name),
+        AnyJsObjectMemberName::JsLiteralMemberName(_) => None,
+    }
+}
+
+/// Returns whether `name` is `[Symbol.iterator]` or `[Symbol.asyncIterator]`.
+fn is_iterator_symbol(name: &JsComputedMemberName, model: &SemanticModel) -> bool {
+    let Ok(expression) = name.expression() else {
+        return false;
+    };
+    let range = expression.omit_parentheses().range();
+    model.well_known_symbol_accesses().iter().any(|access| {
+        access.range() == range
+            && matches!(
+                access.symbol(),
+                WellKnownSymbol::Iterator | WellKnownSymbol::AsyncIterator
+            )
+    })
+}
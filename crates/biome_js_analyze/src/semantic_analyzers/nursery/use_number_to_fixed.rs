@@ -0,0 +1,353 @@
+use crate::{semantic_services::Semantic, JsRuleAction};
+use biome_analyze::{
+    context::RuleContext, declare_rule, ActionCategory, FixKind, Rule, RuleDiagnostic,
+};
+use biome_console::markup;
+use biome_deserialize::json::{has_only_known_keys, VisitJsonNode};
+use biome_deserialize::{DeserializationDiagnostic, VisitNode};
+use biome_diagnostics::Applicability;
+use biome_js_factory::make;
+use biome_js_semantic::SemanticModel;
+use biome_js_syntax::{
+    binding_ext::AnyJsBindingDeclaration, global_identifier, numbers::parse_js_number,
+    AnyJsCallArgument, AnyJsExpression, AnyJsLiteralExpression, AnyJsMemberExpression,
+    JsBinaryExpression, JsBinaryOperator, JsCallExpression, JsVariableDeclaration,
+    OperatorPrecedence, T,
+};
+use biome_json_syntax::JsonLanguage;
+use biome_rowan::{AstNode, BatchMutationExt, SyntaxNode};
+use bpaf::Bpaf;
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+declare_rule! {
+    /// Enforce the use of `toFixed` instead of rounding a number to a number of decimal places by hand.
+    ///
+    /// `Math.round(x * 100) / 100` rounds `x` to two decimal places.
+    /// This reimplements `Number.prototype.toFixed`, and the number of decimal places is hidden in the factor.
+    ///
+    /// The rule reports the divisions of `Math.round(x * factor)` by the same `factor`,
+    /// where `factor` is a power of ten from `10` to `1e20`.
+    /// The factor can be a number literal, `Math.pow(10, n)`, `10 ** n`,
+    /// or a `const` variable initialized with one of them.
+    /// `Math.round(x * 1) / 1` isn't reported, because it's `Math.round(x)`.
+    ///
+    /// The fix is unsafe, because `toFixed` returns a string rather than a number.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,expect_diagnostic
+    /// const price = Math.round(total * 100) / 100;
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// const ratio = Math.round(value * Math.pow(10, 3)) / Math.pow(10, 3);
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// const factor = 10 ** 2;
+    /// const price = Math.round(total * factor) / factor;
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// const price = total.toFixed(2);
+    /// ```
+    ///
+    /// ```js
+    /// const price = Math.round(total * 100) / 10;
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// The diagnostic also notes that `toFixed` returns a string, and can round some values differently,
+    /// when the option `warnAboutToFixedPrecision` is set to `true`. It defaults to `false`.
+    ///
+    /// ```json
+    /// {
+    ///     "//": "...",
+    ///     "options": {
+    ///         "warnAboutToFixedPrecision": true
+    ///     }
+    /// }
+    /// ```
+    ///
+    pub(crate) UseNumberToFixed {
+        version: "next",
+        name: "useNumberToFixed",
+        recommended: false,
+        fix_kind: FixKind::Unsafe,
+    }
+}
+
+/// The number that is rounded, and the number of decimal places it's rounded to
+pub(crate) struct ManualRounding {
+    value: AnyJsExpression,
+    digits: u8,
+}
+
+impl Rule for UseNumberToFixed {
+    type Query = Semantic<JsBinaryExpression>;
+    type State = ManualRounding;
+    type Signals = Option<Self::State>;
+    type Options = NumberToFixedOptions;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let node = ctx.query();
+        let model = ctx.model();
+        if node.operator().ok()? != JsBinaryOperator::Divide {
+            return None;
+        }
+        let call = node.left().ok()?.omit_parentheses();
+        let call = call.as_js_call_expression()?;
+        if !is_math_method(call, "round", model) {
+            return None;
+        }
+        let [Some(AnyJsCallArgument::AnyJsExpression(argument)), None] =
+            call.get_arguments_by_index([0, 1])
+        else {
+            return None;
+        };
+        let argument = argument.omit_parentheses();
+        let product = argument.as_js_binary_expression()?;
+        if product.operator().ok()? != JsBinaryOperator::Times {
+            return None;
+        }
+        let left = product.left().ok()?;
+        let right = product.right().ok()?;
+        let (value, digits) = match power_of_ten(&right, model, true) {
+            Some(digits) => (left, digits),
+            None => (right, power_of_ten(&left, model, true)?),
+        };
+        if !(1..=20).contains(&digits)
+            || power_of_ten(&node.right().ok()?, model, true) != Some(digits)
+        {
+            return None;
+        }
+        Some(ManualRounding { value, digits })
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let digits = state.digits;
+        let diagnostic = RuleDiagnostic::new(
+            rule_category!(),
+            ctx.query().range(),
+            markup! {
+                "This expression rounds a number to a fixed number of decimal places by hand."
+            },
+        )
+        .note(markup! {
+            "Use "<Emphasis>"toFixed("{digits}")"</Emphasis>" instead."
+        });
+        Some(if ctx.options().warn_about_to_fixed_precision {
+            diagnostic.note(markup! {
+                <Emphasis>"toFixed"</Emphasis>" returns a string rather than a number, and can round some values differently because of their floating-point representation."
+            })
+        } else {
+            diagnostic
+        })
+    }
+
+    fn action(ctx: &RuleContext<Self>, state: &Self::State) -> Option<JsRuleAction> {
+        let node = ctx.query();
+        let value = state.value.clone().omit_parentheses().trim_trivia()?;
+        let object = if needs_parentheses(&value) {
+            make::parenthesized(value).into()
+        } else {
+            value
+        };
+        let call = make::js_call_expression(
+            make::js_static_member_expression(
+                object,
+                make::token(T![.]),
+                make::js_name(make::ident("toFixed")).into(),
+            )
+            .into(),
+            make::js_call_arguments(
+                make::token(T!['(']),
+                make::js_call_argument_list(
+                    [AnyJsCallArgument::AnyJsExpression(
+                        AnyJsExpression::AnyJsLiteralExpression(
+                            AnyJsLiteralExpression::JsNumberLiteralExpression(
+                                make::js_number_literal_expression(make::js_number_literal(
+                                    state.digits,
+                                )),
+                            ),
+                        ),
+                    )],
+                    [],
+                ),
+                make::token(T![')']),
+            ),
+        )
+        .build();
+
+        let mut mutation = ctx.root().begin();
+        mutation.replace_node(AnyJsExpression::from(node.clone()), call.into());
+        let digits = state.digits;
+        Some(JsRuleAction {
+            category: ActionCategory::QuickFix,
+            applicability: Applicability::MaybeIncorrect,
+            message: markup! { "Use "<Emphasis>"toFixed("{digits}")"</Emphasis>" instead." }
+                .to_owned(),
+            mutation,
+        })
+    }
+}
+
+/// Returns `true` if `call` calls the method `name` of the global `Math`.
+fn is_math_method(call: &JsCallExpression, name: &str, model: &SemanticModel) -> bool {
+    let Some(callee) = call.callee().ok().map(AnyJsExpression::omit_parentheses) else {
+        return false;
+    };
+    let Some(member) = AnyJsMemberExpression::cast_ref(callee.syntax()) else {
+        return false;
+    };
+    if member
+        .member_name()
+        .map_or(true, |member| member.text() != name)
+    {
+        return false;
+    }
+    let Some(object) = member.object().ok().map(AnyJsExpression::omit_parentheses) else {
+        return false;
+    };
+    global_identifier(&object).is_some_and(|(reference, object)| {
+        object.text() == "Math" && model.binding(&reference).is_none()
+    })
+}
+
+/// Returns `n` if `expression` is `10` to the power of `n`, with `n` from `0` to `20`.
+///
+/// If `resolve` is `true`, a reference to a `const` variable is resolved to the initializer of the variable.
+fn power_of_ten(expression: &AnyJsExpression, model: &SemanticModel, resolve: bool) -> Option<u8> {
+    match expression.clone().omit_parentheses() {
+        AnyJsExpression::AnyJsLiteralExpression(
+            AnyJsLiteralExpression::JsNumberLiteralExpression(_),
+        ) => {
+            let value = number_literal(expression)?;
+            (0..=20u8).find(|&n| value == 10f64.powi(i32::from(n)))
+        }
+        AnyJsExpression::JsBinaryExpression(binary) => {
+            if binary.operator().ok()? != JsBinaryOperator::Exponent {
+                return None;
+            }
+            exponent_of_ten(&binary.left().ok()?, &binary.right().ok()?)
+        }
+        AnyJsExpression::JsCallExpression(call) => {
+            if !is_math_method(&call, "pow", model) {
+                return None;
+            }
+            let [Some(AnyJsCallArgument::AnyJsExpression(base)), Some(AnyJsCallArgument::AnyJsExpression(exponent)), None] =
+                call.get_arguments_by_index([0, 1, 2])
+            else {
+                return None;
+            };
+            exponent_of_ten(&base, &exponent)
+        }
+        AnyJsExpression::JsIdentifierExpression(identifier) if resolve => {
+            let reference = identifier.name().ok()?;
+            let AnyJsBindingDeclaration::JsVariableDeclarator(declarator) =
+                model.binding(&reference)?.tree().declaration()?
+            else {
+                return None;
+            };
+            // The value of a `let` or `var` variable can change before the division
+            let is_const = declarator
+                .syntax()
+                .ancestors()
+                .find_map(JsVariableDeclaration::cast)
+                .is_some_and(|declaration| declaration.is_const());
+            if !is_const {
+                return None;
+            }
+            let initializer = declarator.initializer()?.expression().ok()?;
+            power_of_ten(&initializer, model, false)
+        }
+        _ => None,
+    }
+}
+
+/// Returns `exponent` if `base` is the literal `10` and `exponent` an integer literal from `0` to `20`.
+fn exponent_of_ten(base: &AnyJsExpression, exponent: &AnyJsExpression) -> Option<u8> {
+    let base = number_literal(base)?;
+    let exponent = number_literal(exponent)?;
+    (base == 10.0).then_some(())?;
+    (0..=20u8).find(|&n| exponent == f64::from(n))
+}
+
+/// Returns the value of `expression` if it's a number literal.
+fn number_literal(expression: &AnyJsExpression) -> Option<f64> {
+    match expression.clone().omit_parentheses() {
+        AnyJsExpression::AnyJsLiteralExpression(
+            AnyJsLiteralExpression::JsNumberLiteralExpression(literal),
+        ) => parse_js_number(literal.value_token().ok()?.text_trimmed()),
+        _ => None,
+    }
+}
+
+/// Returns `true` if `expression` must be wrapped in parentheses to be the object of `.toFixed`.
+fn needs_parentheses(expression: &AnyJsExpression) -> bool {
+    match expression {
+        // `1.toFixed` is a syntax error
+        AnyJsExpression::AnyJsLiteralExpression(
+            AnyJsLiteralExpression::JsNumberLiteralExpression(_),
+        )
+        | AnyJsExpression::JsArrowFunctionExpression(_) => true,
+        _ => expression.precedence().map_or(true, |precedence| {
+            precedence < OperatorPrecedence::LeftHandSide
+        }),
+    }
+}
+
+/// Options for the rule `useNumberToFixed`.
+#[derive(Default, Deserialize, Serialize, Eq, PartialEq, Debug, Clone, Bpaf)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct NumberToFixedOptions {
+    /// If `true`, the diagnostic notes that `toFixed` returns a string and can round some values differently.
+    pub warn_about_to_fixed_precision: bool,
+}
+
+impl NumberToFixedOptions {
+    pub(crate) const KNOWN_KEYS: &'static [&'static str] = &["warnAboutToFixedPrecision"];
+}
+
+// Required by [Bpaf].
+impl FromStr for NumberToFixedOptions {
+    type Err = &'static str;
+
+    fn from_str(_s: &str) -> Result<Self, Self::Err> {
+        // WARNING: should not be used.
+        Ok(Self::default())
+    }
+}
+
+impl VisitNode<JsonLanguage> for NumberToFixedOptions {
+    fn visit_member_name(
+        &mut self,
+        node: &SyntaxNode<JsonLanguage>,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<()> {
+        has_only_known_keys(node, Self::KNOWN_KEYS, diagnostics)
+    }
+
+    fn visit_map(
+        &mut self,
+        key: &SyntaxNode<JsonLanguage>,
+        value: &SyntaxNode<JsonLanguage>,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<()> {
+        let (name, value) = self.get_key_and_value(key, value, diagnostics)?;
+        let name_text = name.text();
+        if name_text == "warnAboutToFixedPrecision" {
+            self.warn_about_to_fixed_precision =
+                self.map_to_boolean(&value, name_text, diagnostics)?;
+        }
+
+        Some(())
+    }
+}
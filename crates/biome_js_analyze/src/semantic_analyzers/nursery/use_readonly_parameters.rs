@@ -0,0 +1,453 @@
+use crate::{semantic_services::Semantic, JsRuleAction};
+use biome_analyze::{
+    context::RuleContext, declare_rule, ActionCategory, FixKind, Rule, RuleDiagnostic,
+};
+use biome_console::markup;
+use biome_deserialize::json::{has_only_known_keys, VisitJsonNode};
+use biome_deserialize::{DeserializationDiagnostic, VisitNode};
+use biome_diagnostics::Applicability;
+use biome_js_factory::make;
+use biome_js_semantic::{ReferencesExtensions, SemanticModel};
+use biome_js_syntax::{
+    AnyJsMemberExpression, AnyTsName, AnyTsType, JsCallExpression, JsFileSource, JsFormalParameter,
+    JsIdentifierBinding, JsSyntaxKind, JsSyntaxNode, JsUnaryExpression, JsUnaryOperator, T,
+};
+use biome_json_syntax::JsonLanguage;
+use biome_rowan::{AstNode, AstSeparatedList, BatchMutationExt, SyntaxNode, TriviaPieceKind};
+use bpaf::Bpaf;
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+declare_rule! {
+    /// Enforce read-only types for the parameters that are never modified.
+    ///
+    /// A function that doesn't modify the objects it receives can state it in its signature,
+    /// by declaring the types of its parameters as read-only.
+    /// The callers then know that their objects are left untouched,
+    /// and TypeScript reports the modifications that are added to the function by mistake.
+    ///
+    /// The rule reports the parameters that are never assigned,
+    /// and whose properties are never assigned or deleted.
+    /// Calling a method that modifies an array, such as `push()` or `sort()`, counts as a modification.
+    /// The parameters without type annotation, and the parameters of functions without body are ignored.
+    ///
+    /// The rule only applies to TypeScript files.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```ts,expect_diagnostic
+    /// function greet(user: { name: string }) {
+    ///     return `Hello ${user.name}`;
+    /// }
+    /// ```
+    ///
+    /// ```ts,expect_diagnostic
+    /// function sum(values: number[]) {
+    ///     return values.reduce((total, value) => total + value, 0);
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```ts
+    /// function greet(user: Readonly<{ name: string }>) {
+    ///     return `Hello ${user.name}`;
+    /// }
+    /// ```
+    ///
+    /// ```ts
+    /// function rename(user: { name: string }, name: string) {
+    ///     user.name = name;
+    /// }
+    /// ```
+    ///
+    /// ```ts
+    /// function append(values: number[], value: number) {
+    ///     values.push(value);
+    /// }
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// The rule has an option `onlyObjectTypes`, that defaults to `true`.
+    /// When it's `true`, the parameters of primitive types, such as `string` or `number`, are ignored.
+    ///
+    /// ```json
+    /// {
+    ///     "//": "...",
+    ///     "options": {
+    ///         "onlyObjectTypes": false
+    ///     }
+    /// }
+    /// ```
+    ///
+    pub(crate) UseReadonlyParameters {
+        version: "next",
+        name: "useReadonlyParameters",
+        recommended: false,
+        fix_kind: FixKind::Unsafe,
+    }
+}
+
+/// Array methods that modify the array they are called on
+const MUTATING_ARRAY_METHODS: [&str; 9] = [
+    "copyWithin",
+    "fill",
+    "pop",
+    "push",
+    "reverse",
+    "shift",
+    "sort",
+    "splice",
+    "unshift",
+];
+
+/// How the type of a parameter can be made read-only
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+enum ReadonlyKind {
+    /// `readonly T[]`
+    Operator,
+    /// `Readonly<T>`
+    Wrapper,
+}
+
+pub(crate) struct MutableParameter {
+    binding: JsIdentifierBinding,
+    ty: AnyTsType,
+    kind: ReadonlyKind,
+}
+
+impl Rule for UseReadonlyParameters {
+    type Query = Semantic<JsFormalParameter>;
+    type State = MutableParameter;
+    type Signals = Option<Self::State>;
+    type Options = ReadonlyParametersOptions;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        if !ctx.source_type::<JsFileSource>().language().is_typescript() {
+            return None;
+        }
+        let parameter = ctx.query();
+        if !has_body(parameter.syntax()) {
+            return None;
+        }
+        let binding = parameter
+            .binding()
+            .ok()?
+            .as_any_js_binding()?
+            .as_js_identifier_binding()?
+            .clone();
+        let ty = parameter.type_annotation()?.ty().ok()?;
+        match type_category(&ty) {
+            TypeCategory::Object => {}
+            TypeCategory::Primitive if !ctx.options().only_object_types => {}
+            _ => return None,
+        }
+        if is_mutated(&binding, ctx.model()) {
+            return None;
+        }
+        let kind = match ty {
+            AnyTsType::TsArrayType(_) | AnyTsType::TsTupleType(_) => ReadonlyKind::Operator,
+            _ => ReadonlyKind::Wrapper,
+        };
+        Some(MutableParameter { binding, ty, kind })
+    }
+
+    fn diagnostic(_: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let name = state.binding.name_token().ok()?;
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                state.binding.range(),
+                markup! {
+                    "The parameter "<Emphasis>{name.text_trimmed()}</Emphasis>" is never modified, but its type isn't read-only."
+                },
+            )
+            .detail(
+                state.ty.range(),
+                markup! {
+                    "This type allows the function to modify the parameter."
+                },
+            )
+            .note(markup! {
+                "A read-only type tells the callers that the function doesn't modify the parameter, and prevents accidental modifications."
+            }),
+        )
+    }
+
+    fn action(ctx: &RuleContext<Self>, state: &Self::State) -> Option<JsRuleAction> {
+        let ty = state.ty.clone().trim_trivia()?;
+        let (readonly_ty, message) = match state.kind {
+            ReadonlyKind::Operator => (
+                AnyTsType::from(make::ts_type_operator_type(
+                    make::token(T![readonly])
+                        .with_trailing_trivia([(TriviaPieceKind::Whitespace, " ")]),
+                    ty,
+                )),
+                markup! { "Add the "<Emphasis>"readonly"</Emphasis>" modifier to the type." }
+                    .to_owned(),
+            ),
+            ReadonlyKind::Wrapper => (
+                AnyTsType::from(
+                    make::ts_reference_type(AnyTsName::JsReferenceIdentifier(
+                        make::js_reference_identifier(make::ident("Readonly")),
+                    ))
+                    .with_type_arguments(make::ts_type_arguments(
+                        make::token(T![<]),
+                        make::ts_type_argument_list([ty], []),
+                        make::token(T![>]),
+                    ))
+                    .build(),
+                ),
+                markup! { "Wrap the type with "<Emphasis>"Readonly"</Emphasis>"." }.to_owned(),
+            ),
+        };
+        let readonly_ty = readonly_ty
+            .with_leading_trivia_pieces(state.ty.syntax().first_leading_trivia()?.pieces())?
+            .with_trailing_trivia_pieces(state.ty.syntax().last_trailing_trivia()?.pieces())?;
+
+        let mut mutation = ctx.root().begin();
+        mutation.replace_node(state.ty.clone(), readonly_ty);
+        Some(JsRuleAction {
+            category: ActionCategory::QuickFix,
+            applicability: Applicability::MaybeIncorrect,
+            message,
+            mutation,
+        })
+    }
+}
+
+/// Returns `true` if `parameter` belongs to a function or a method that has a body
+fn has_body(parameter: &JsSyntaxNode) -> bool {
+    // parameter -> parameter list -> parameters -> function
+    parameter.ancestors().nth(3).is_some_and(|function| {
+        matches!(
+            function.kind(),
+            JsSyntaxKind::JS_FUNCTION_DECLARATION
+                | JsSyntaxKind::JS_FUNCTION_EXPRESSION
+                | JsSyntaxKind::JS_FUNCTION_EXPORT_DEFAULT_DECLARATION
+                | JsSyntaxKind::JS_ARROW_FUNCTION_EXPRESSION
+                | JsSyntaxKind::JS_METHOD_CLASS_MEMBER
+                | JsSyntaxKind::JS_METHOD_OBJECT_MEMBER
+                | JsSyntaxKind::JS_CONSTRUCTOR_CLASS_MEMBER
+        )
+    })
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+enum TypeCategory {
+    /// A type that is already read-only, or for which a read-only type is meaningless,
+    /// such as a function type or `any`
+    Ignored,
+    Primitive,
+    Object,
+}
+
+fn type_category(ty: &AnyTsType) -> TypeCategory {
+    match ty {
+        AnyTsType::TsParenthesizedType(ty) => ty
+            .ty()
+            .map_or(TypeCategory::Ignored, |ty| type_category(&ty)),
+        AnyTsType::TsTypeOperatorType(ty) => {
+            let is_readonly = ty
+                .operator_token()
+                .is_ok_and(|operator| operator.kind() == T![readonly]);
+            if is_readonly {
+                TypeCategory::Ignored
+            } else {
+                TypeCategory::Object
+            }
+        }
+        AnyTsType::TsReferenceType(ty) => {
+            let is_readonly = ty.name().ok().is_some_and(|name| {
+                name.as_js_reference_identifier()
+                    .and_then(|name| name.value_token().ok())
+                    .is_some_and(|name| {
+                        matches!(
+                            name.text_trimmed(),
+                            "Readonly" | "ReadonlyArray" | "ReadonlyMap" | "ReadonlySet"
+                        )
+                    })
+            });
+            if is_readonly {
+                TypeCategory::Ignored
+            } else {
+                TypeCategory::Object
+            }
+        }
+        AnyTsType::TsUnionType(ty) => union_category(ty.types().iter().filter_map(|ty| ty.ok())),
+        AnyTsType::TsIntersectionType(ty) => {
+            union_category(ty.types().iter().filter_map(|ty| ty.ok()))
+        }
+        AnyTsType::TsBigintLiteralType(_)
+        | AnyTsType::TsBigintType(_)
+        | AnyTsType::TsBooleanLiteralType(_)
+        | AnyTsType::TsBooleanType(_)
+        | AnyTsType::TsNullLiteralType(_)
+        | AnyTsType::TsNumberLiteralType(_)
+        | AnyTsType::TsNumberType(_)
+        | AnyTsType::TsStringLiteralType(_)
+        | AnyTsType::TsStringType(_)
+        | AnyTsType::TsSymbolType(_)
+        | AnyTsType::TsTemplateLiteralType(_)
+        | AnyTsType::TsUndefinedType(_) => TypeCategory::Primitive,
+        AnyTsType::TsAnyType(_)
+        | AnyTsType::TsBogusType(_)
+        | AnyTsType::TsConstructorType(_)
+        | AnyTsType::TsFunctionType(_)
+        | AnyTsType::TsNeverType(_)
+        | AnyTsType::TsUnknownType(_)
+        | AnyTsType::TsVoidType(_) => TypeCategory::Ignored,
+        AnyTsType::TsArrayType(_)
+        | AnyTsType::TsConditionalType(_)
+        | AnyTsType::TsImportType(_)
+        | AnyTsType::TsIndexedAccessType(_)
+        | AnyTsType::TsInferType(_)
+        | AnyTsType::TsMappedType(_)
+        | AnyTsType::TsNonPrimitiveType(_)
+        | AnyTsType::TsObjectType(_)
+        | AnyTsType::TsThisType(_)
+        | AnyTsType::TsTupleType(_)
+        | AnyTsType::TsTypeofType(_) => TypeCategory::Object,
+    }
+}
+
+/// A union or an intersection is ignored if one of its types is ignored,
+/// and is primitive if all its types are primitive.
+fn union_category(types: impl Iterator<Item = AnyTsType>) -> TypeCategory {
+    let mut category = TypeCategory::Primitive;
+    for ty in types {
+        match type_category(&ty) {
+            TypeCategory::Ignored => return TypeCategory::Ignored,
+            TypeCategory::Object => category = TypeCategory::Object,
+            TypeCategory::Primitive => {}
+        }
+    }
+    category
+}
+
+/// Returns `true` if the parameter `binding` is assigned, or if one of its properties is
+/// assigned or deleted, or if it's an array modified by one of its methods.
+fn is_mutated(binding: &JsIdentifierBinding, model: &SemanticModel) -> bool {
+    if binding.all_writes(model).next().is_some() {
+        return true;
+    }
+    binding.all_reads(model).any(|reference| {
+        let Some(mut object) = reference.syntax().parent() else {
+            return false;
+        };
+        while let Some(parent) = object.parent() {
+            if parent.kind() != JsSyntaxKind::JS_PARENTHESIZED_EXPRESSION {
+                break;
+            }
+            object = parent;
+        }
+        let Some(parent) = object.parent() else {
+            return false;
+        };
+        match parent.kind() {
+            // `parameter.property = value`
+            JsSyntaxKind::JS_STATIC_MEMBER_ASSIGNMENT
+            | JsSyntaxKind::JS_COMPUTED_MEMBER_ASSIGNMENT => true,
+            JsSyntaxKind::JS_STATIC_MEMBER_EXPRESSION
+            | JsSyntaxKind::JS_COMPUTED_MEMBER_EXPRESSION => AnyJsMemberExpression::cast(parent)
+                .is_some_and(|member| {
+                    let is_object = member
+                        .object()
+                        .is_ok_and(|member_object| member_object.syntax() == &object);
+                    is_object && is_mutating_member_access(&member)
+                }),
+            _ => false,
+        }
+    })
+}
+
+/// Returns `true` if `member` is deleted, or is an array method that modifies the array and that is called
+fn is_mutating_member_access(member: &AnyJsMemberExpression) -> bool {
+    let mut expression = member.syntax().clone();
+    while let Some(parent) = expression.parent() {
+        if parent.kind() != JsSyntaxKind::JS_PARENTHESIZED_EXPRESSION {
+            break;
+        }
+        expression = parent;
+    }
+    let Some(parent) = expression.parent() else {
+        return false;
+    };
+    if let Some(unary) = JsUnaryExpression::cast_ref(&parent) {
+        // `delete parameter.property`
+        return unary.operator().ok() == Some(JsUnaryOperator::Delete);
+    }
+    // `parameter.push(value)`
+    JsCallExpression::cast(parent).is_some_and(|call| {
+        call.callee()
+            .is_ok_and(|callee| callee.syntax() == &expression)
+            && member
+                .member_name()
+                .is_some_and(|name| MUTATING_ARRAY_METHODS.contains(&name.text()))
+    })
+}
+
+/// Options for the rule `useReadonlyParameters`.
+#[derive(Deserialize, Serialize, Eq, PartialEq, Debug, Clone, Bpaf)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct ReadonlyParametersOptions {
+    /// If `true`, the parameters of primitive types are ignored.
+    #[bpaf(hide)]
+    #[serde(default = "default_only_object_types")]
+    pub only_object_types: bool,
+}
+
+const fn default_only_object_types() -> bool {
+    true
+}
+
+impl Default for ReadonlyParametersOptions {
+    fn default() -> Self {
+        Self {
+            only_object_types: default_only_object_types(),
+        }
+    }
+}
+
+impl ReadonlyParametersOptions {
+    pub(crate) const KNOWN_KEYS: &'static [&'static str] = &["onlyObjectTypes"];
+}
+
+// Required by [Bpaf].
+impl FromStr for ReadonlyParametersOptions {
+    type Err = &'static str;
+
+    fn from_str(_s: &str) -> Result<Self, Self::Err> {
+        // WARNING: should not be used.
+        Ok(Self::default())
+    }
+}
+
+impl VisitNode<JsonLanguage> for ReadonlyParametersOptions {
+    fn visit_member_name(
+        &mut self,
+        node: &SyntaxNode<JsonLanguage>,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<()> {
+        has_only_known_keys(node, Self::KNOWN_KEYS, diagnostics)
+    }
+
+    fn visit_map(
+        &mut self,
+        key: &SyntaxNode<JsonLanguage>,
+        value: &SyntaxNode<JsonLanguage>,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<()> {
+        let (name, value) = self.get_key_and_value(key, value, diagnostics)?;
+        let name_text = name.text();
+        if name_text == "onlyObjectTypes" {
+            self.only_object_types = self.map_to_boolean(&value, name_text, diagnostics)?;
+        }
+
+        Some(())
+    }
+}
@@ -0,0 +1,319 @@
+use crate::{semantic_services::Semantic, JsRuleAction};
+use biome_analyze::{
+    context::RuleContext, declare_rule, ActionCategory, FixKind, Rule, RuleDiagnostic,
+};
+use biome_console::markup;
+use biome_deserialize::json::{has_only_known_keys, with_only_known_variants, VisitJsonNode};
+use biome_deserialize::{DeserializationDiagnostic, VisitNode};
+use biome_diagnostics::Applicability;
+use biome_js_factory::make;
+use biome_js_semantic::SemanticModel;
+use biome_js_syntax::{
+    binding_ext::AnyJsBindingDeclaration, global_identifier, AnyJsCallArgument, AnyJsExpression,
+    AnyJsMemberExpression, JsCallExpression, JsSyntaxNode, TextRange, T,
+};
+use biome_json_syntax::JsonLanguage;
+use biome_rowan::{AstNode, AstSeparatedList, BatchMutationExt, SyntaxNode};
+use bpaf::Bpaf;
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+declare_rule! {
+    /// Prefer `structuredClone` over `JSON.parse(JSON.stringify(x))` to deep clone a value.
+    ///
+    /// Serializing a value to JSON and parsing it back is a lossy way of cloning it:
+    /// `undefined` properties are dropped, `Date` objects become strings,
+    /// `Map` and `Set` become empty objects, and circular references throw an error.
+    /// `structuredClone` copies these values correctly.
+    ///
+    /// The rule also reports the result of `JSON.stringify` stored in a `const` variable
+    /// before being passed to `JSON.parse`.
+    ///
+    /// `structuredClone` is available in Node.js 17+ and in modern browsers.
+    /// The fix is only suggested when the option `targetRuntime` is set,
+    /// and when `JSON.stringify` is directly passed to `JSON.parse`.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,expect_diagnostic
+    /// const copy = JSON.parse(JSON.stringify(value));
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// const serialized = JSON.stringify(value);
+    /// const copy = JSON.parse(serialized);
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// const copy = structuredClone(value);
+    /// ```
+    ///
+    /// ```js
+    /// const copy = JSON.parse(JSON.stringify(value, replacer));
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// The option `targetRuntime` declares the runtime of the code, and enables the fix.
+    /// It accepts `"node17+"` and `"modern-browser"`.
+    ///
+    /// ```json
+    /// {
+    ///     "//": "...",
+    ///     "options": {
+    ///         "targetRuntime": "node17+"
+    ///     }
+    /// }
+    /// ```
+    ///
+    pub(crate) UseStructuredClone {
+        version: "next",
+        name: "useStructuredClone",
+        recommended: false,
+        fix_kind: FixKind::Unsafe,
+    }
+}
+
+pub(crate) struct RuleState {
+    /// The cloned value
+    value: AnyJsExpression,
+    /// Range of the `JSON.stringify` call, when its result is stored in a variable
+    stringify_range: Option<TextRange>,
+}
+
+impl Rule for UseStructuredClone {
+    type Query = Semantic<JsCallExpression>;
+    type State = RuleState;
+    type Signals = Option<Self::State>;
+    type Options = StructuredCloneOptions;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let call = ctx.query();
+        let model = ctx.model();
+        let argument = json_call_argument(call, "parse", model)?;
+        if let AnyJsExpression::JsIdentifierExpression(identifier) = &argument {
+            let binding = model.binding(&identifier.name().ok()?)?.tree();
+            if binding.is_under_pattern_binding()? {
+                return None;
+            }
+            let AnyJsBindingDeclaration::JsVariableDeclarator(declarator) =
+                binding.declaration()?
+            else {
+                return None;
+            };
+            if !declarator.declaration()?.is_const() {
+                return None;
+            }
+            let AnyJsExpression::JsCallExpression(stringify) = declarator
+                .initializer()?
+                .expression()
+                .ok()?
+                .omit_parentheses()
+            else {
+                return None;
+            };
+            return Some(RuleState {
+                value: json_call_argument(&stringify, "stringify", model)?,
+                stringify_range: Some(stringify.range()),
+            });
+        }
+        let AnyJsExpression::JsCallExpression(stringify) = argument else {
+            return None;
+        };
+        Some(RuleState {
+            value: json_call_argument(&stringify, "stringify", model)?,
+            stringify_range: None,
+        })
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let mut diagnostic = RuleDiagnostic::new(
+            rule_category!(),
+            ctx.query().range(),
+            markup! {
+                "Use "<Emphasis>"structuredClone"</Emphasis>" to deep clone a value."
+            },
+        );
+        if let Some(stringify_range) = state.stringify_range {
+            diagnostic = diagnostic.detail(
+                stringify_range,
+                markup! {
+                    "The value is serialized to JSON here."
+                },
+            );
+        }
+        Some(diagnostic.note(markup! {
+            "Serializing a value to JSON loses "<Emphasis>"undefined"</Emphasis>" properties, "<Emphasis>"Date"</Emphasis>", "<Emphasis>"Map"</Emphasis>", and "<Emphasis>"Set"</Emphasis>" objects, and fails on circular references."
+        }))
+    }
+
+    fn action(ctx: &RuleContext<Self>, state: &Self::State) -> Option<JsRuleAction> {
+        let call = ctx.query();
+        if ctx.options().target_runtime.is_none()
+            || state.stringify_range.is_some()
+            || call.syntax().has_comments_descendants()
+            || is_shadowed("structuredClone", call.syntax(), ctx.model())
+        {
+            return None;
+        }
+        let structured_clone = make::js_call_expression(
+            make::js_identifier_expression(make::js_reference_identifier(make::ident(
+                "structuredClone",
+            )))
+            .into(),
+            make::js_call_arguments(
+                make::token(T!['(']),
+                make::js_call_argument_list(
+                    [AnyJsCallArgument::AnyJsExpression(
+                        state.value.clone().trim_trivia()?,
+                    )],
+                    [],
+                ),
+                make::token(T![')']),
+            ),
+        )
+        .build();
+
+        let mut mutation = ctx.root().begin();
+        mutation.replace_node(call.clone(), structured_clone);
+        Some(JsRuleAction {
+            category: ActionCategory::QuickFix,
+            applicability: Applicability::MaybeIncorrect,
+            message: markup! { "Use "<Emphasis>"structuredClone"</Emphasis>" instead." }.to_owned(),
+            mutation,
+        })
+    }
+}
+
+/// Returns the only argument of `call` if it calls the method `name` of the global `JSON`.
+fn json_call_argument(
+    call: &JsCallExpression,
+    name: &str,
+    model: &SemanticModel,
+) -> Option<AnyJsExpression> {
+    if call.is_optional_chain() {
+        return None;
+    }
+    let callee = AnyJsMemberExpression::cast(call.callee().ok()?.omit_parentheses().into_syntax())?;
+    if callee.member_name()?.text() != name {
+        return None;
+    }
+    let (reference, object_name) = global_identifier(&callee.object().ok()?.omit_parentheses())?;
+    if object_name.text() != "JSON" || model.binding(&reference).is_some() {
+        return None;
+    }
+    let arguments = call.arguments().ok()?.args();
+    if arguments.len() != 1 {
+        return None;
+    }
+    let AnyJsCallArgument::AnyJsExpression(argument) = arguments.first()?.ok()? else {
+        return None;
+    };
+    Some(argument.omit_parentheses())
+}
+
+/// Returns `true` if a binding named `name` is declared in a scope that contains `node`.
+fn is_shadowed(name: &str, node: &JsSyntaxNode, model: &SemanticModel) -> bool {
+    model
+        .scope(node)
+        .ancestors()
+        .any(|scope| scope.get_binding(name).is_some())
+}
+
+/// Options for the rule `useStructuredClone`.
+#[derive(Default, Deserialize, Serialize, Eq, PartialEq, Debug, Clone, Bpaf)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct StructuredCloneOptions {
+    /// The runtime of the code. The fix is only suggested when it is set.
+    #[bpaf(hide)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub target_runtime: Option<TargetRuntime>,
+}
+
+impl StructuredCloneOptions {
+    pub(crate) const KNOWN_KEYS: &'static [&'static str] = &["targetRuntime"];
+}
+
+// Required by [Bpaf].
+impl FromStr for StructuredCloneOptions {
+    type Err = &'static str;
+
+    fn from_str(_s: &str) -> Result<Self, Self::Err> {
+        // WARNING: should not be used.
+        Ok(Self::default())
+    }
+}
+
+impl VisitNode<JsonLanguage> for StructuredCloneOptions {
+    fn visit_member_name(
+        &mut self,
+        node: &SyntaxNode<JsonLanguage>,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<()> {
+        has_only_known_keys(node, Self::KNOWN_KEYS, diagnostics)
+    }
+
+    fn visit_map(
+        &mut self,
+        key: &SyntaxNode<JsonLanguage>,
+        value: &SyntaxNode<JsonLanguage>,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<()> {
+        let (name, value) = self.get_key_and_value(key, value, diagnostics)?;
+        let name_text = name.text();
+        if name_text == "targetRuntime" {
+            let mut target_runtime = TargetRuntime::default();
+            self.map_to_known_string(&value, name_text, &mut target_runtime, diagnostics)?;
+            self.target_runtime = Some(target_runtime);
+        }
+        Some(())
+    }
+}
+
+/// A runtime that supports `structuredClone`.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum TargetRuntime {
+    /// Node.js 17 or later
+    #[default]
+    #[serde(rename = "node17+")]
+    Node17,
+    /// A modern browser
+    #[serde(rename = "modern-browser")]
+    ModernBrowser,
+}
+
+impl TargetRuntime {
+    pub const KNOWN_VALUES: &'static [&'static str] = &["node17+", "modern-browser"];
+}
+
+// Required by [Bpaf].
+impl FromStr for TargetRuntime {
+    type Err = &'static str;
+
+    fn from_str(_s: &str) -> Result<Self, Self::Err> {
+        // WARNING: should not be used.
+        Ok(Self::default())
+    }
+}
+
+impl VisitNode<JsonLanguage> for TargetRuntime {
+    fn visit_member_value(
+        &mut self,
+        node: &SyntaxNode<JsonLanguage>,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<()> {
+        let node = with_only_known_variants(node, Self::KNOWN_VALUES, diagnostics)?;
+        match node.inner_string_text().ok()?.text() {
+            "node17+" => *self = Self::Node17,
+            "modern-browser" => *self = Self::ModernBrowser,
+            _ => (),
+        }
+        Some(())
+    }
+}
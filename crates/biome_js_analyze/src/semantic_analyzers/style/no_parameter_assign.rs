@@ -1,9 +1,20 @@
 use crate::semantic_services::Semantic;
 use biome_analyze::{context::RuleContext, declare_rule, Rule, RuleDiagnostic};
 use biome_console::markup;
-use biome_js_semantic::{AllBindingWriteReferencesIter, Reference, ReferencesExtensions};
-use biome_js_syntax::{AnyJsBinding, AnyJsBindingPattern, AnyJsFormalParameter, AnyJsParameter};
-use biome_rowan::AstNode;
+use biome_deserialize::json::{has_only_known_keys, VisitJsonNode};
+use biome_deserialize::{DeserializationDiagnostic, VisitNode};
+use biome_js_semantic::ReferencesExtensions;
+use biome_js_syntax::{
+    AnyJsBinding, AnyJsBindingPattern, AnyJsFormalParameter, AnyJsParameter,
+    JsComputedMemberAssignment, JsComputedMemberExpression, JsStaticMemberAssignment,
+    JsStaticMemberExpression, JsSyntaxKind, JsSyntaxNode, JsUnaryExpression, JsUnaryOperator,
+    TextRange,
+};
+use biome_json_syntax::JsonLanguage;
+use biome_rowan::{AstNode, SyntaxNode};
+use bpaf::Bpaf;
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
 
 declare_rule! {
     /// Disallow reassigning `function` parameters.
@@ -14,8 +25,12 @@ declare_rule! {
     ///
     /// Source: https://eslint.org/docs/latest/rules/no-param-reassign
     ///
-    /// In contrast to the _ESLint_ rule, this rule cannot be configured to report
-    /// assignments to a property of a parameter.
+    /// By default, the rule doesn't report assignments to a property of a parameter,
+    /// such as `param.prop = value`.
+    /// Set the option `props` to `true` to report them.
+    ///
+    /// The rule ignores the parameter properties of TypeScript constructors,
+    /// such as `constructor(readonly prop: number)`.
     ///
     /// ## Examples
     ///
@@ -39,7 +54,21 @@ declare_rule! {
     /// }
     /// ```
     ///
-    /// ```ts,expect_diagnostic
+    /// ## Valid
+    ///
+    /// ```js
+    /// function f(param) {
+    ///     let local = param;
+    /// }
+    /// ```
+    ///
+    /// ```js
+    /// function f(param) {
+    ///     param.prop = 13;
+    /// }
+    /// ```
+    ///
+    /// ```ts
     /// class C {
     ///     constructor(readonly prop: number) {
     ///         prop++
@@ -47,11 +76,17 @@ declare_rule! {
     /// }
     /// ```
     ///
-    /// ## Valid
+    /// ## Options
     ///
-    /// ```js
-    /// function f(param) {
-    ///     let local = param;
+    /// The option `props` reports the assignments to the properties of a parameter,
+    /// and the deletions of these properties.
+    ///
+    /// ```json
+    /// {
+    ///     "//": "...",
+    ///     "options": {
+    ///         "props": true
+    ///     }
     /// }
     /// ```
     ///
@@ -62,43 +97,92 @@ declare_rule! {
     }
 }
 
+/// An assignment to a parameter, or to one of its properties
+pub(crate) struct ParameterAssignment {
+    /// Range of the assigned parameter, or of the assigned property
+    range: TextRange,
+    is_property: bool,
+}
+
 impl Rule for NoParameterAssign {
     type Query = Semantic<AnyJsParameter>;
-    type State = Reference;
-    type Signals = AllBindingWriteReferencesIter;
-    type Options = ();
+    type State = ParameterAssignment;
+    type Signals = Vec<Self::State>;
+    type Options = ParameterAssignOptions;
 
     fn run(ctx: &RuleContext<Self>) -> Self::Signals {
         let param = ctx.query();
         let model = ctx.model();
-        if let Some(AnyJsBindingPattern::AnyJsBinding(AnyJsBinding::JsIdentifierBinding(binding))) =
+        // The parameter properties of a constructor are assigned to the class members
+        let is_parameter_property = param
+            .syntax()
+            .parent()
+            .is_some_and(|parent| parent.kind() == JsSyntaxKind::TS_PROPERTY_PARAMETER);
+        if is_parameter_property {
+            return Vec::new();
+        }
+        let Some(AnyJsBindingPattern::AnyJsBinding(AnyJsBinding::JsIdentifierBinding(binding))) =
             binding_of(param)
-        {
-            return binding.all_writes(model);
+        else {
+            return Vec::new();
+        };
+        let mut assignments: Vec<_> = binding
+            .all_writes(model)
+            .map(|reference| ParameterAssignment {
+                range: reference.syntax().text_trimmed_range(),
+                is_property: false,
+            })
+            .collect();
+        if ctx.options().props {
+            assignments.extend(binding.all_reads(model).filter_map(|reference| {
+                let range = assigned_property(reference.syntax())?;
+                Some(ParameterAssignment {
+                    range,
+                    is_property: true,
+                })
+            }));
+            assignments.sort_by_key(|assignment| assignment.range.start());
         }
-        // Empty iterator that conforms to `AllBindingWriteReferencesIter` type.
-        std::iter::successors(None, |_| None)
+        assignments
     }
 
-    fn diagnostic(ctx: &RuleContext<Self>, reference: &Self::State) -> Option<RuleDiagnostic> {
+    fn diagnostic(ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
         let param = ctx.query();
-        Some(
+        let ParameterAssignment { range, is_property } = state;
+        let diagnostic = if *is_property {
             RuleDiagnostic::new(
                 rule_category!(),
-                reference.syntax().text_trimmed_range(),
+                range,
                 markup! {
-                    "Reassigning a "<Emphasis>"function parameter"</Emphasis>" is confusing."
+                    "Assigning a property of a "<Emphasis>"function parameter"</Emphasis>" is confusing."
                 },
             )
-            .detail(
-                param.syntax().text_trimmed_range(),
+        } else {
+            RuleDiagnostic::new(
+                rule_category!(),
+                range,
                 markup! {
-                    "The "<Emphasis>"parameter"</Emphasis>" is declared here:"
+                    "Reassigning a "<Emphasis>"function parameter"</Emphasis>" is confusing."
                 },
             )
-            .note(markup! {
-                "Use a local variable instead."
-            }),
+        };
+        Some(
+            diagnostic
+                .detail(
+                    param.syntax().text_trimmed_range(),
+                    markup! {
+                        "The "<Emphasis>"parameter"</Emphasis>" is declared here:"
+                    },
+                )
+                .note(if *is_property {
+                    markup! {
+                        "The caller's object is modified. Assign a copy of the object to a local variable instead."
+                    }
+                } else {
+                    markup! {
+                        "Use a local variable instead."
+                    }
+                }),
         )
     }
 }
@@ -113,3 +197,85 @@ fn binding_of(param: &AnyJsParameter) -> Option<AnyJsBindingPattern> {
         AnyJsParameter::TsThisParameter(_) => None,
     }
 }
+
+/// Returns the range of the assigned or deleted property if `reference` is the object
+/// of this property, such as `param` in `param.a.b = 0` or `delete param[a]`.
+fn assigned_property(reference: &JsSyntaxNode) -> Option<TextRange> {
+    let identifier = reference.parent()?;
+    let mut object = identifier.clone();
+    loop {
+        let parent = object.parent()?;
+        let parent_object = if let Some(member) = JsStaticMemberExpression::cast_ref(&parent) {
+            member.object().ok()?
+        } else if let Some(member) = JsComputedMemberExpression::cast_ref(&parent) {
+            member.object().ok()?
+        } else if let Some(member) = JsStaticMemberAssignment::cast_ref(&parent) {
+            return (member.object().ok()?.syntax() == &object).then(|| member.range());
+        } else if let Some(member) = JsComputedMemberAssignment::cast_ref(&parent) {
+            return (member.object().ok()?.syntax() == &object).then(|| member.range());
+        } else {
+            // `delete param` is reported as a syntax error in strict mode
+            let unary = JsUnaryExpression::cast(parent)?;
+            let is_delete = unary.operator().ok()? == JsUnaryOperator::Delete;
+            return (is_delete && object != identifier).then(|| unary.range());
+        };
+        if parent_object.syntax() != &object {
+            return None;
+        }
+        object = parent;
+    }
+}
+
+/// Options for the rule `noParameterAssign`.
+#[derive(Default, Deserialize, Serialize, Eq, PartialEq, Debug, Clone, Bpaf)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct ParameterAssignOptions {
+    /// If `true`, the assignments to the properties of a parameter are reported.
+    #[bpaf(hide)]
+    #[serde(default, skip_serializing_if = "is_false")]
+    pub props: bool,
+}
+
+const fn is_false(value: &bool) -> bool {
+    !*value
+}
+
+impl ParameterAssignOptions {
+    pub(crate) const KNOWN_KEYS: &'static [&'static str] = &["props"];
+}
+
+// Required by [Bpaf].
+impl FromStr for ParameterAssignOptions {
+    type Err = &'static str;
+
+    fn from_str(_s: &str) -> Result<Self, Self::Err> {
+        // WARNING: should not be used.
+        Ok(Self::default())
+    }
+}
+
+impl VisitNode<JsonLanguage> for ParameterAssignOptions {
+    fn visit_member_name(
+        &mut self,
+        node: &SyntaxNode<JsonLanguage>,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<()> {
+        has_only_known_keys(node, Self::KNOWN_KEYS, diagnostics)
+    }
+
+    fn visit_map(
+        &mut self,
+        key: &SyntaxNode<JsonLanguage>,
+        value: &SyntaxNode<JsonLanguage>,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<()> {
+        let (name, value) = self.get_key_and_value(key, value, diagnostics)?;
+        let name_text = name.text();
+        if name_text == "props" {
+            self.props = self.map_to_boolean(&value, name_text, diagnostics)?;
+        }
+
+        Some(())
+    }
+}
@@ -3,12 +3,19 @@ use crate::semantic_services::Semantic;
 use biome_analyze::context::RuleContext;
 use biome_analyze::{declare_rule, Rule, RuleDiagnostic};
 use biome_console::markup;
+use biome_deserialize::json::{has_only_known_keys, VisitJsonNode};
+use biome_deserialize::{DeserializationDiagnostic, VisitNode};
 use biome_js_syntax::{
-    AnyJsFunction, AnyJsMemberExpression, JsCallArgumentList, JsCallArguments, JsCallExpression,
-    JsFormalParameter, JsObjectExpression, JsObjectMemberList, JsParameterList, JsParameters,
-    JsPropertyObjectMember, JsReferenceIdentifier, JsxAttribute,
+    AnyJsExpression, AnyJsFunction, AnyJsMemberExpression, AnyJsTemplateElement,
+    JsCallArgumentList, JsCallArguments, JsCallExpression, JsFormalParameter, JsObjectExpression,
+    JsObjectMemberList, JsParameterList, JsParameters, JsPropertyObjectMember,
+    JsReferenceIdentifier, JsShorthandPropertyObjectMember, JsxAttribute,
 };
-use biome_rowan::{declare_node_union, AstNode, TextRange};
+use biome_json_syntax::JsonLanguage;
+use biome_rowan::{declare_node_union, AstNode, SyntaxNode, TextRange};
+use bpaf::Bpaf;
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
 
 declare_rule! {
     /// Discourage the usage of Array index in keys.
@@ -36,6 +43,27 @@ declare_rule! {
     ///     React.cloneElement(child, { key: index })
     /// ))
     /// ```
+    ///
+    /// ```jsx,expect_diagnostic
+    /// things.map((thing, index) => (
+    ///     <Component key={`${index}`} />
+    /// ));
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// When the option `checkShorthand` is set to `true`, the rule also reports the shorthand property `{ key }`
+    /// passed to `React.cloneElement`, when `key` is the index parameter. It defaults to `false`.
+    ///
+    /// ```json
+    /// {
+    ///     "//": "...",
+    ///     "options": {
+    ///         "checkShorthand": true
+    ///     }
+    /// }
+    /// ```
+    ///
     pub(crate) NoArrayIndexKey {
         version: "1.0.0",
         name: "noArrayIndexKey",
@@ -44,12 +72,16 @@ declare_rule! {
 }
 
 declare_node_union! {
-    pub(crate) NoArrayIndexKeyQuery = JsxAttribute | JsPropertyObjectMember
+    pub(crate) NoArrayIndexKeyQuery = JsxAttribute | JsPropertyObjectMember | JsShorthandPropertyObjectMember
 }
 
 impl NoArrayIndexKeyQuery {
     const fn is_property_object_member(&self) -> bool {
-        matches!(self, NoArrayIndexKeyQuery::JsPropertyObjectMember(_))
+        matches!(
+            self,
+            NoArrayIndexKeyQuery::JsPropertyObjectMember(_)
+                | NoArrayIndexKeyQuery::JsShorthandPropertyObjectMember(_)
+        )
     }
 
     fn is_key_property(&self) -> Option<bool> {
@@ -66,32 +98,59 @@ impl NoArrayIndexKeyQuery {
                 let name = name.value().ok()?;
                 name.text_trimmed() == "key"
             }
+            NoArrayIndexKeyQuery::JsShorthandPropertyObjectMember(object_member) => {
+                let name = object_member.name().ok()?;
+                let name = name.value_token().ok()?;
+                name.text_trimmed() == "key"
+            }
         })
     }
 
     /// Extracts the reference from the possible invalid prop
     fn as_js_reference_identifier(&self) -> Option<JsReferenceIdentifier> {
         match self {
-            NoArrayIndexKeyQuery::JsxAttribute(attribute) => attribute
-                .initializer()?
-                .value()
-                .ok()?
-                .as_jsx_expression_attribute_value()?
-                .expression()
-                .ok()?
-                .as_js_identifier_expression()?
-                .name()
-                .ok(),
-            NoArrayIndexKeyQuery::JsPropertyObjectMember(object_member) => object_member
-                .value()
-                .ok()?
-                .as_js_identifier_expression()?
-                .name()
-                .ok(),
+            NoArrayIndexKeyQuery::JsxAttribute(attribute) => {
+                let expression = attribute
+                    .initializer()?
+                    .value()
+                    .ok()?
+                    .as_jsx_expression_attribute_value()?
+                    .expression()
+                    .ok()?;
+                key_value_reference(expression)
+            }
+            NoArrayIndexKeyQuery::JsPropertyObjectMember(object_member) => {
+                key_value_reference(object_member.value().ok()?)
+            }
+            NoArrayIndexKeyQuery::JsShorthandPropertyObjectMember(object_member) => {
+                object_member.name().ok()
+            }
         }
     }
 }
 
+/// Returns the reference used as value of a key, alone or as the only element of a template literal
+///
+/// ```jsx
+/// <Component key={index} />;
+/// <Component key={`${index}`} />;
+/// ```
+fn key_value_reference(expression: AnyJsExpression) -> Option<JsReferenceIdentifier> {
+    let expression = match expression {
+        AnyJsExpression::JsTemplateExpression(template) if template.tag().is_none() => {
+            let mut elements = template.elements().into_iter();
+            let (Some(AnyJsTemplateElement::JsTemplateElement(element)), None) =
+                (elements.next(), elements.next())
+            else {
+                return None;
+            };
+            element.expression().ok()?
+        }
+        expression => expression,
+    };
+    expression.as_js_identifier_expression()?.name().ok()
+}
+
 pub(crate) struct NoArrayIndexKeyState {
     /// The incorrect prop
     incorrect_prop: TextRange,
@@ -103,11 +162,19 @@ impl Rule for NoArrayIndexKey {
     type Query = Semantic<NoArrayIndexKeyQuery>;
     type State = NoArrayIndexKeyState;
     type Signals = Option<Self::State>;
-    type Options = ();
+    type Options = ArrayIndexKeyOptions;
 
     fn run(ctx: &RuleContext<Self>) -> Self::Signals {
         let node = ctx.query();
 
+        if matches!(
+            node,
+            NoArrayIndexKeyQuery::JsShorthandPropertyObjectMember(_)
+        ) && !ctx.options().check_shorthand
+        {
+            return None;
+        }
+
         if !node.is_key_property()? {
             return None;
         }
@@ -226,3 +293,51 @@ fn is_array_method_index(
         None
     }
 }
+
+/// Options for the rule `noArrayIndexKey`.
+#[derive(Default, Deserialize, Serialize, Eq, PartialEq, Debug, Clone, Bpaf)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct ArrayIndexKeyOptions {
+    /// If `true`, the shorthand property `{ key }` passed to `React.cloneElement` is also reported.
+    pub check_shorthand: bool,
+}
+
+impl ArrayIndexKeyOptions {
+    pub(crate) const KNOWN_KEYS: &'static [&'static str] = &["checkShorthand"];
+}
+
+// Required by [Bpaf].
+impl FromStr for ArrayIndexKeyOptions {
+    type Err = &'static str;
+
+    fn from_str(_s: &str) -> Result<Self, Self::Err> {
+        // WARNING: should not be used.
+        Ok(Self::default())
+    }
+}
+
+impl VisitNode<JsonLanguage> for ArrayIndexKeyOptions {
+    fn visit_member_name(
+        &mut self,
+        node: &SyntaxNode<JsonLanguage>,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<()> {
+        has_only_known_keys(node, Self::KNOWN_KEYS, diagnostics)
+    }
+
+    fn visit_map(
+        &mut self,
+        key: &SyntaxNode<JsonLanguage>,
+        value: &SyntaxNode<JsonLanguage>,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<()> {
+        let (name, value) = self.get_key_and_value(key, value, diagnostics)?;
+        let name_text = name.text();
+        if name_text == "checkShorthand" {
+            self.check_shorthand = self.map_to_boolean(&value, name_text, diagnostics)?;
+        }
+
+        Some(())
+    }
+}
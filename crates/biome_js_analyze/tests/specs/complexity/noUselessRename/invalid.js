@@ -23,6 +23,22 @@ export { /*before*/ foo as foo /*after*/ };
 
 export { /*before*/ foo as foo /*after*/ } from "foo";
 
+({ /*before*/ foo: foo /*after*/ } = obj);
+
+({ a, foo: foo = 1 } = obj);
+
+({
+	foo: { bar: bar },
+} = obj);
+
+let { "foo": foo } = obj;
+
+import { "b" as b } from "foo";
+
+export { c as "c" };
+
+export { "d" as "d" } from "foo";
+
 // following cases are supported by ESLint
 
 //import {a as \u0061} from 'foo';
@@ -30,6 +46,3 @@ export { /*before*/ foo as foo /*after*/ } from "foo";
 //export {\u0061 as a};
 //export {a as \u0061};
 
-//let {"a": a} = obj;
-//import { "a" as a}
-//export { a as "a"}
@@ -102,3 +102,13 @@ const { ...stuff } = myObject;
 const { foo, ...stuff } = myObject;
 
 const { foo: bar, ...stuff } = myObject;
+
+({ foo } = obj);
+
+({ foo: bar } = obj);
+
+({ [foo]: foo } = obj);
+
+({ foo: obj.foo } = obj);
+
+let { "foo": bar } = obj;
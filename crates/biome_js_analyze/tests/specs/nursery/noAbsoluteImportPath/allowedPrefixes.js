@@ -0,0 +1,5 @@
+import a from "/app";
+import b from "/app/components/button";
+import c from "/static/c.js";
+import d from "/apple/d";
+import e from "/lib/e";
@@ -0,0 +1,7 @@
+import foo from "/Users/alice/projects/foo";
+import bar from "C:\\projects\\bar";
+import baz from "d:/projects/baz";
+import "/home/bob/polyfill.js";
+import * as qux from "/";
+export * from "/home/bob/lib";
+export { a } from "/home/bob/lib";
@@ -0,0 +1,8 @@
+import foo from "./foo";
+import bar from "../lib/bar";
+import baz from "baz";
+import qux from "@scope/qux";
+import "//cdn.example.com/lib.js";
+import url from "https://example.com/lib.js";
+import node from "node:fs";
+export * from "./lib";
@@ -0,0 +1,27 @@
+interface Loader {
+	load(): Promise<string>;
+	size(): number;
+}
+
+class MemoryLoader implements Loader {
+	async load() {
+		return "";
+	}
+
+	// Not declared as returning a `Promise` by the interface
+	async size() {
+		return 0;
+	}
+
+	// Not declared by the interface
+	async reset() {
+		this.cache = "";
+	}
+}
+
+// Without the interface
+class OtherLoader {
+	async load() {
+		return "";
+	}
+}
@@ -0,0 +1,33 @@
+async function fetchUser() {
+	return cache.get("user");
+}
+
+const handler = async () => {
+	log("done");
+};
+
+const value = async () => 1;
+
+const object = {
+	async method() {
+		return 1;
+	},
+};
+
+class Service {
+	async start() {
+		this.started = true;
+	}
+}
+
+// The `await` of a nested function doesn't count
+async function outer() {
+	const inner = async () => {
+		await run();
+	};
+	return inner;
+}
+
+export default async function () {
+	run();
+}
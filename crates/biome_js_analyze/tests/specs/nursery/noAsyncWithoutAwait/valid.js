@@ -0,0 +1,40 @@
+async function fetchUser() {
+	return await fetch("/user");
+}
+
+const handler = async () => {
+	if (ready) {
+		await run();
+	}
+};
+
+const value = async () => await compute();
+
+async function readAll(stream) {
+	for await (const chunk of stream) {
+		process(chunk);
+	}
+}
+
+async function* lines(stream) {
+	yield stream.read();
+}
+
+async function noop() {}
+
+async function delay(ms) {
+	return new Promise((resolve) => setTimeout(resolve, ms));
+}
+
+const timeout = async (ms) => new Promise((resolve) => setTimeout(resolve, ms));
+
+function notAsync() {
+	return 1;
+}
+
+// The `await` of a nested async function belongs to the nested function
+function outer() {
+	return async () => {
+		await run();
+	};
+}
@@ -0,0 +1,15 @@
+if (x = foo()) {
+}
+if (a.b = c) {
+} else if (d = e) {
+}
+while (node = node.parent) {
+}
+do {
+} while (x = next());
+for (let i = 0; i = 10; i++) {
+}
+if (x += 1) {
+}
+if (x ??= y) {
+}
@@ -0,0 +1,18 @@
+if ((x = foo())) {
+}
+while ((node = node.parent)) {
+}
+do {
+} while ((x = next()));
+for (let i = 0; (i = next()); i++) {
+}
+for (i = 0; i < 10; i = i + 1) {
+}
+if (x === foo()) {
+}
+if (check(x = foo())) {
+}
+if (x) {
+	x = foo();
+}
+const y = (x = foo()) ? a : b;
@@ -0,0 +1,78 @@
+import React, { Component, PureComponent, forwardRef, memo } from "react";
+
+class Counter extends Component {
+	increment() {
+		this.state.count = this.state.count + 1;
+		this.state.count += 1;
+		this.state.count++;
+		--this.state["count"];
+		delete this.state.count;
+		this.state.user.name = "name";
+		this.props.count = 0;
+	}
+}
+
+class List extends React.PureComponent {
+	add(item) {
+		this.state.items.push(item);
+		this.state.items.splice(0, 1);
+		this.state.items.sort();
+		Object.assign(this.state, { item });
+		Object.assign(this.state.user, { item });
+		Object.assign(this.props, { item });
+	}
+
+	handleClick = () => {
+		this.state.clicked = true;
+	};
+}
+
+const Anonymous = class extends PureComponent {
+	render() {
+		(this.state).count = 0;
+		return null;
+	}
+};
+
+function Profile(props) {
+	props.user = {};
+	props.user.name = "name";
+	props["title"] = "title";
+	delete props.user;
+	props.count++;
+	Object.assign(props, { name: "name" });
+	Object.assign(props.user, { name: "name" });
+	return <p>{props.user.name}</p>;
+}
+
+function Destructured({ user, style: { colors }, count = 0 }) {
+	user.name = "name";
+	colors.primary = "red";
+	count.value = 1;
+	return null;
+}
+
+const Arrow = (props) => {
+	props.user.name = "name";
+	return null;
+};
+
+const SingleParameter = props => {
+	props.user.name = "name";
+	return null;
+};
+
+const Memoized = memo(function Memoized({ user }) {
+	user.name = "name";
+	return null;
+});
+
+const WithRef = React.forwardRef((props, ref) => {
+	props.ref = ref;
+	return null;
+});
+
+export default function Page(props) {
+	props.title = "title";
+	return null;
+}
@@ -0,0 +1,80 @@
+import { Component } from "react";
+
+class Counter extends Component {
+	constructor(props) {
+		super(props);
+		this.state = { count: 0, items: [] };
+		this.state.count = props.initialCount;
+	}
+
+	increment() {
+		this.setState({ ...this.state, count: this.state.count + 1 });
+		this.setState((state) => ({ items: [...state.items, state.count] }));
+		const items = this.state.items.slice();
+		items.push(this.state.count);
+		this.state.items.map((item) => item);
+		this.props.history.push("/");
+		this.props.onChange(Object.assign({}, this.state));
+	}
+
+	render() {
+		function callback() {
+			this.state.count = 0;
+		}
+		const object = {
+			method() {
+				this.state.count = 0;
+			},
+		};
+		return null;
+	}
+}
+
+class NotAComponent {
+	update() {
+		this.state.count = 0;
+		this.props.count = 0;
+		this.state.items.push(0);
+	}
+}
+
+class Store extends EventEmitter {
+	update() {
+		this.state.count = 0;
+	}
+}
+
+function Profile(props) {
+	const user = { ...props.user, name: "name" };
+	props = { ...props, user };
+	props.history.push("/");
+	props.items.push(0);
+	return <p>{user.name}</p>;
+}
+
+function Destructured({ user, ...rest }, context) {
+	rest.user = user;
+	context.value = 1;
+	user = { name: "name" };
+	return null;
+}
+
+function helper(props) {
+	props.user.name = "name";
+}
+
+const updateUser = (user) => {
+	user.name = "name";
+};
+
+const Wrapped = wrap((props) => {
+	props.user = {};
+	return null;
+});
+
+function Shadowed(props) {
+	function inner(props) {
+		props.user = {};
+	}
+	return null;
+}
@@ -0,0 +1,40 @@
+// Returns the sum of `a` and `b`.
+function add(a: number, b: number): number {
+	return a + b;
+}
+
+// The user of the session.
+//
+// `undefined` until the user logs in.
+export interface User {
+	name: string;
+}
+
+// The identifier of a user.
+export type UserId = string;
+
+// The roles of a user.
+enum Role {
+	Admin,
+	Guest,
+}
+
+// The maximum number of retries.
+export const MAX_RETRIES = 3;
+
+// A repository of users.
+export default class Users {}
+
+namespace Nested {
+	// A nested function.
+	function nested() {}
+}
+
+// Closes the comment */ early.
+function unsafe() {}
+
+namespace Nested {
+	// A nested class.
+	// It isn't exported.
+	class Nested {}
+}
@@ -0,0 +1,4 @@
+// Returns the sum of `a` and `b`.
+function add(a, b) {
+	return a + b;
+}
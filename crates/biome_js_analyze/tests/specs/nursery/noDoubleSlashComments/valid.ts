@@ -0,0 +1,36 @@
+/** Returns the sum of `a` and `b`. */
+function add(a: number, b: number): number {
+	return a + b;
+}
+
+// Section: users
+
+interface User {
+	name: string;
+}
+
+// @ts-expect-error
+function untyped(a) {}
+
+// eslint-disable-next-line @typescript-eslint/naming-convention
+type legacy_name = string;
+
+/// <reference types="node" />
+const path = "path";
+
+// The counter.
+let counter = 0;
+
+/**
+ * The roles of a user.
+ */
+// TODO: add more roles
+enum Role {
+	Admin,
+}
+
+/* A block comment. */
+class A {}
+
+call(); // Not a documentation comment.
+class B {}
@@ -0,0 +1,14 @@
+enum Permissions {
+	None = 0,
+	Read = 1 << 0,
+	Write = 1 << 1,
+	Execute = 1 << 2,
+	Default = 1 << 0,
+}
+
+// Not a bit flag enum: 3 is not a power of two
+enum Levels {
+	Low = 1,
+	Medium = 3,
+	High = 3,
+}
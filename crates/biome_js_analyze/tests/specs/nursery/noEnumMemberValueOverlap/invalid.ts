@@ -0,0 +1,37 @@
+enum ExplicitNumbers {
+	A = 1,
+	B = 1,
+}
+
+enum AutoIncrement {
+	A = 1,
+	B = 0,
+	C,
+}
+
+enum Strings {
+	A = "a",
+	B = "b",
+	C = "a",
+}
+
+enum Mixed {
+	A = 0x10,
+	B = 16,
+	C = (16),
+}
+
+enum Negative {
+	A = -1,
+	B = -1,
+}
+
+enum Shifted {
+	A = 1 << 2,
+	B = 4,
+}
+
+enum Template {
+	A = `a`,
+	B = "a",
+}
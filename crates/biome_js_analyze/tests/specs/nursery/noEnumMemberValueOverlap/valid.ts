@@ -0,0 +1,35 @@
+enum Implicit {
+	A,
+	B,
+	C,
+}
+
+enum ExplicitNumbers {
+	A = 1,
+	B = 2,
+	C = 3,
+}
+
+enum Strings {
+	A = "a",
+	B = "b",
+}
+
+// Strings and numbers never overlap
+enum Heterogeneous {
+	A = 1,
+	B = "1",
+}
+
+// Members that cannot be evaluated are ignored
+enum Computed {
+	A = foo(),
+	B = foo(),
+	C = A,
+	D = A,
+}
+
+declare enum Ambient {
+	A,
+	B,
+}
@@ -0,0 +1,13 @@
+const city = response.data.user.profile.address.city;
+
+const email = this.state.form.fields.email.value;
+
+a.b.c.d.e.f.g;
+
+a["b"].c[0].d.e.f;
+
+call(a.b.c.d.e.f);
+
+a.b.c.d.e.f();
+
+builder.build().a.b.c.d.e;
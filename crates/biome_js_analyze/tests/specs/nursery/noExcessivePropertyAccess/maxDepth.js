@@ -0,0 +1,3 @@
+a.b.c.d.e.f;
+
+a.b.c.d.e.f.g;
@@ -0,0 +1,19 @@
+const city = response.data.user.address.city;
+
+a.b.c.d.e;
+
+response?.data.user.profile.address.city;
+
+response.data.user?.profile.address.city;
+
+a.b?.[c].d.e.f.g;
+
+a?.b().c.d.e.f.g;
+
+query.select("a").from("b").where("c").orderBy("d").limit(1);
+
+$(".item").addClass("a").removeClass("b").show().fadeIn().focus();
+
+a.b.c.d.e.f = 1;
+
+(a.b.c).d.e;
@@ -0,0 +1,12 @@
+switch (key) {
+	case 1:
+	case 2: one(); break;
+	case 3:
+	case 4: two(); break;
+}
+
+switch (key) {
+	case 1: one(); break;
+	case 2: two(); break;
+	case 3: three(); break;
+}
@@ -0,0 +1,31 @@
+switch (key) {
+	case 1: one(); break;
+	case 2: two(); break;
+	case 3: three(); break;
+	case 4: four(); break;
+	case 5: five(); break;
+	case 6: six(); break;
+	case 7: seven(); break;
+	case 8: eight(); break;
+	case 9: nine(); break;
+	case 10: ten(); break;
+	case 11: eleven(); break;
+}
+
+function f(key) {
+	switch (key) {
+		case "a":
+		case "b": return 1;
+		case "c": return 2;
+		case "d": return 3;
+		case "e": return 4;
+		case "f": return 5;
+		case "g": return 6;
+		case "h": return 7;
+		case "i": return 8;
+		case "j": return 9;
+		case "k": return 10;
+		case "l": return 11;
+		default: return 0;
+	}
+}
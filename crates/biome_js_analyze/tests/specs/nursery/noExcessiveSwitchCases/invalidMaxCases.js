@@ -0,0 +1,3 @@
+switch (key) {
+	case 1: one(); break;
+}
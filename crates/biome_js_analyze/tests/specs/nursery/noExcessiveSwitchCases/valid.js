@@ -0,0 +1,36 @@
+switch (key) {
+	case 1: one(); break;
+	case 2: two(); break;
+	default: other();
+}
+
+// ten cases and a default clause
+switch (key) {
+	case 1: one(); break;
+	case 2: two(); break;
+	case 3: three(); break;
+	case 4: four(); break;
+	case 5: five(); break;
+	case 6: six(); break;
+	case 7: seven(); break;
+	case 8: eight(); break;
+	case 9: nine(); break;
+	case 10: ten(); break;
+	default: other();
+}
+
+// the empty fall-through cases aren't counted
+switch (key) {
+	case 1:
+	case 2: one(); break;
+	case 3:
+	case 4: two(); break;
+	case 5:
+	case 6: three(); break;
+	case 7:
+	case 8: four(); break;
+	case 9:
+	case 10: five(); break;
+	case 11:
+	case 12: six(); break;
+}
@@ -0,0 +1,11 @@
+typeof foo == "undefined";
+typeof foo != "string";
+"number" == typeof foo;
+typeof foo == typeof bar;
+typeof foo == bar;
+(typeof foo) == "object";
+typeof foo === "undefied";
+typeof foo !== "String";
+"fucntion" === typeof foo;
+typeof foo == "nunber";
+typeof foo === "";
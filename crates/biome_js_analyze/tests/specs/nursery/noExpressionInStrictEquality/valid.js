@@ -0,0 +1,8 @@
+typeof foo === "undefined";
+typeof foo !== "string";
+"bigint" === typeof foo;
+typeof foo === typeof bar;
+typeof foo === bar;
+foo == "undefied";
+typeof foo + "" == "a";
+!foo == "undefined";
@@ -0,0 +1,5 @@
+const isEnabled = !!flag;
+const found = ~text.indexOf("a");
+const count = +input;
+const label = "" + id;
+const index = ratio | 0;
@@ -0,0 +1,16 @@
+const isEnabled = !!flag;
+const isEmpty = !!(list.length);
+const count = +input;
+const total = +(a - b);
+const label = "" + id;
+const suffix = id + '';
+const index = ratio | 0;
+const found = ~text.indexOf("a");
+const lastFound = ~text.lastIndexOf("a");
+call(!!value);
+const object = { enabled: !!options.enabled };
+const values = list.map((value) => !!value);
+function returnsValue() {
+	return !!value;
+}
+const sequence = !!(a, b);
@@ -0,0 +1,7 @@
+function isReady(state: State): string {
+	return !!state.ready;
+}
+const shadowed = (Boolean: unknown) => !!Boolean;
+function withShadowedNumber(Number: unknown, value: string) {
+	return +value;
+}
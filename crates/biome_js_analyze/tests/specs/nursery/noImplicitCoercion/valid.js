@@ -0,0 +1,27 @@
+const isEnabled = Boolean(flag);
+const count = Number(input);
+const label = String(id);
+const index = Math.trunc(ratio);
+const found = text.includes("a");
+const negated = !flag;
+const notNotNot = !!!flag;
+const number = +1;
+const concatenated = "" + "a";
+const template = "" + `a${b}`;
+const sum = a + b;
+const mask = flags | 1;
+const zero = 1 | 0;
+const inverted = ~mask;
+if (!!flag) {}
+while (!!flag) {}
+do {} while (!!flag);
+for (; !!flag; ) {}
+const conditional = !!flag ? a : b;
+const defined = list.filter((value) => !!value);
+const some = list.some(function (value) {
+	return !!value;
+});
+const every = list.every((value) => {
+	return !!value.enabled;
+});
+const first = list.find((value) => !!value);
@@ -0,0 +1,12 @@
+function isReady(state: State): boolean {
+	return !!state.ready;
+}
+const isDone = (state: State): boolean => !!state.done;
+class Task {
+	isRunning(): boolean {
+		return !!this.process;
+	}
+}
+function isDefined<T>(value: T | undefined): value is T {
+	return !!value;
+}
@@ -0,0 +1,19 @@
+import { A, type B } from "mod";
+
+import { type C, D } from "mod";
+
+import E, { type F } from "mod";
+
+import G, { type H, I } from "mod";
+
+import { J as JJ, type K as KK, type L } from "mod"
+
+import {
+	M,
+	type N,
+	O,
+	type P,
+} from "mod";
+
+// leading comment
+import { Q, type R } from "mod" assert { type: "json" };
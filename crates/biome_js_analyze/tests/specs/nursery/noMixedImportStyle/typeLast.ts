@@ -0,0 +1,3 @@
+import { A, type B } from "mod";
+
+import C, { type D } from "mod";
@@ -0,0 +1,20 @@
+import { A, B } from "mod";
+
+import type { C, D } from "mod";
+
+import E, { F } from "mod";
+
+import G from "mod";
+
+import type H from "mod";
+
+import * as I from "mod";
+
+import J, * as K from "mod";
+
+import {} from "mod";
+
+import "mod";
+
+// Reported by useGroupedTypeImport
+import { type L, type M } from "mod";
@@ -0,0 +1,22 @@
+// Direct exports
+export let count = 0;
+export var name = "biome";
+export let first = 1, second = 2;
+export let { left, right } = pair;
+export let uninitialized;
+
+// Reassigned
+export let current = 0;
+export function increment() {
+	current++;
+}
+
+// Export clauses
+let total = 0;
+var label = "total";
+let state = null;
+export { total, label as title };
+export function reset() {
+	state = null;
+}
+export { state as default };
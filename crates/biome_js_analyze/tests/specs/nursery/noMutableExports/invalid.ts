@@ -0,0 +1,4 @@
+export let count: number = 0;
+
+let items: string[] = [];
+export { items };
@@ -0,0 +1,28 @@
+export const count = 0;
+export function increment() {}
+export class Counter {}
+
+// Not exported
+let local = 0;
+local++;
+
+// Default exports copy the current value
+let value = 0;
+export default value;
+
+// Getter functions
+let current = 0;
+export function getCurrent() {
+	return current;
+}
+
+// Re-exports from another module
+export { shared } from "./shared.js";
+
+// Shadowed in a nested scope
+const outer = 0;
+function nested() {
+	let outer = 1;
+	return outer;
+}
+export { outer, nested };
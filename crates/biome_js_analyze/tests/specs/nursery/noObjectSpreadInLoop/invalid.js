@@ -0,0 +1,44 @@
+let result = {};
+for (const item of items) {
+	result = { ...result, ...item };
+}
+
+let index = {};
+for (const item of items) {
+	index = { ...index, [item.id]: item, count: 1, ...item.extra };
+}
+
+let merged = {};
+for (const key in sources) {
+	merged = { ...sources[key], ...merged };
+}
+
+let state = {};
+for (let i = 0; i < 10; i++) {
+	state = ({ ...state, [i]: i });
+}
+
+let acc = {};
+while (queue.length > 0) {
+	acc = { ...acc, ...queue.pop() };
+}
+
+function accumulate(items) {
+	let total = {};
+	for (const item of items) {
+		if (item) total = { ...total, ...item };
+	}
+	return total;
+}
+
+let outer = {};
+for (const list of lists) {
+	for (const item of list) {
+		outer = { ...outer, ...item };
+	}
+}
+
+let commented = {};
+for (const item of items) {
+	commented = { ...commented, /* item */ ...item };
+}
@@ -0,0 +1,5 @@
+const Object = {};
+let result = {};
+for (const item of items) {
+	result = { ...result, ...item };
+}
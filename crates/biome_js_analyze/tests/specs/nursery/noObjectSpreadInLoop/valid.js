@@ -0,0 +1,53 @@
+const result = {};
+for (const item of items) {
+	Object.assign(result, item);
+}
+
+// The variable is declared in the loop
+for (const item of items) {
+	let copy = {};
+	copy = { ...copy, ...item };
+}
+
+// Not in a loop
+let single = {};
+single = { ...single, ...other };
+
+// Another binding with the same name
+let shadowed = {};
+for (const item of items) {
+	let shadowed = item;
+	other = { ...shadowed };
+}
+
+// A different variable is spread
+let target = {};
+for (const item of items) {
+	target = { ...source, ...item };
+}
+
+// The loop is outside the function
+for (const item of items) {
+	let local = {};
+	const f = () => {
+		local = { ...local, ...item };
+	};
+}
+
+// Evaluated once
+let init;
+for (init = { ...init }; init.i < 10; init.i++) {}
+let iterated = [];
+for (const item of (iterated = { ...iterated })) {}
+
+// Compound assignment
+let text = "";
+for (const item of items) {
+	text += item;
+}
+
+// Nested object
+let nested = {};
+for (const item of items) {
+	nested = { value: { ...nested } };
+}
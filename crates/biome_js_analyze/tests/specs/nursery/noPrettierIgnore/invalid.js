@@ -0,0 +1,21 @@
+// prettier-ignore
+const matrix = [
+  1, 0,
+  0, 1,
+];
+
+/* prettier-ignore */
+const   a   =   1;
+
+const b = {
+  // prettier-ignore: aligned values
+  c:   1,
+  d:   2, // prettier-ignore
+};
+
+const element = (
+  <div>
+    {/* prettier-ignore */}
+    <span   a = "b" />
+  </div>
+);
@@ -0,0 +1,10 @@
+// biome-ignore format: aligned values
+const matrix = [
+  1, 0,
+  0, 1,
+];
+
+// prettier-ignore-start
+// prettier-ignored
+// The prettier-ignore comment
+const a = "// prettier-ignore";
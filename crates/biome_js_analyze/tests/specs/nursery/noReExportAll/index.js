@@ -0,0 +1,4 @@
+export * from "./components";
+export * from "./utils";
+export * as hooks from "./hooks";
+export { Button } from "./button";
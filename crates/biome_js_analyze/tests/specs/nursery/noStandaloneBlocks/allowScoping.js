@@ -0,0 +1,12 @@
+{
+	let x = 1;
+}
+
+{
+	const y = 2;
+	use(y);
+}
+
+{
+	class A {}
+}
@@ -0,0 +1,29 @@
+{}
+
+{
+	foo();
+}
+
+{
+	var x = 1;
+	bar(x);
+}
+
+function f() {
+	{
+		baz();
+	}
+}
+
+if (foo) {
+	bar();
+	{
+		function inner() {}
+	}
+}
+
+while (foo) {
+	{
+		baz();
+	}
+}
@@ -0,0 +1,56 @@
+if (foo) {
+	bar();
+} else {
+	baz();
+}
+
+while (foo) {}
+
+do {} while (foo);
+
+for (let i = 0; i < 10; i++) {}
+
+for (const key in object) {}
+
+for (const value of values) {}
+
+try {
+	foo();
+} catch {
+	bar();
+} finally {
+	baz();
+}
+
+label: {
+	break label;
+}
+
+{
+	let x = 1;
+}
+
+{
+	const y = 2;
+	use(y);
+}
+
+{
+	class A {}
+}
+
+switch (foo) {
+	case "a": {
+		bar();
+		break;
+	}
+	default: {
+		baz();
+	}
+}
+
+class B {
+	static {
+		foo();
+	}
+}
@@ -0,0 +1,4 @@
+createUser("alice", true, false);
+toggle(false, false);
+user.update(true, "name", true);
+new Widget().render(true, true, true);
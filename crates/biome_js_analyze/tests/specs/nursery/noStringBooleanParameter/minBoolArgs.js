@@ -0,0 +1,2 @@
+createUser("alice", true, false);
+configure(true, false, true);
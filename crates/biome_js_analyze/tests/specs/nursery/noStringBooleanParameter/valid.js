@@ -0,0 +1,6 @@
+setFlag(true);
+setFlag(false);
+createUser("alice", isAdmin, isActive);
+createUser("alice", { isAdmin: true, isActive: false });
+compare(1, 2, "true", "false");
+f(!x, !y);
@@ -0,0 +1,15 @@
+function fetchJson(url: string) {}
+
+function load(imageUrl: string, baseURL: string) {}
+
+function read(file_path: string) {}
+
+const parse = (mime: string) => {};
+
+class Theme {
+	constructor(private readonly color: string) {}
+
+	paint(backgroundColor: string) {}
+}
+
+function withDefault(url: string = "https://example.com") {}
@@ -0,0 +1,3 @@
+function fetchJson(url: string) {}
+
+function send(email: string, recipientEmail: string) {}
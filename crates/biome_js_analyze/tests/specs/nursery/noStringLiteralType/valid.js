@@ -0,0 +1 @@
+function fetchJson(url) {}
@@ -0,0 +1,15 @@
+function fetchJson(url: `https://${string}`) {}
+
+function greet(name: string) {}
+
+function curl(curl: string) {}
+
+function paths(paths: string) {}
+
+function load(url: URL) {}
+
+function optional(url?: string | undefined) {}
+
+function untyped(url) {}
+
+function destructured({ url }: { url: string }) {}
@@ -0,0 +1,20 @@
+function render(status) {
+	if (status === "active" || status === "pending") {
+		show();
+	}
+	if (status !== "inactive") {
+		hide();
+	}
+}
+
+let mode = getMode();
+if (mode == "dark") {
+	applyDark();
+} else if ("light" == mode) {
+	applyLight();
+}
+const isSystem = (mode) === "system";
+const isDark = mode != "dark";
+
+const kind = getKind();
+const label = kind === "a" ? "A" : kind === "b" ? "B" : kind === "c" ? "C" : "?";
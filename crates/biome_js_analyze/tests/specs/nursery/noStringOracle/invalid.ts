@@ -0,0 +1,13 @@
+function render(status: string) {
+	if (status === "active" || status === "pending") {
+		show();
+	}
+	if (status === "inactive") {
+		hide();
+	}
+}
+
+let mode: string = getMode();
+if (mode === "dark" || mode === "light" || mode === "system") {
+	apply(mode);
+}
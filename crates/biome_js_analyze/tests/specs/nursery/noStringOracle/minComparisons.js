@@ -0,0 +1,11 @@
+function render(status) {
+	if (status === "active") {
+		show();
+	}
+}
+
+function update(status) {
+	if (status === "active" || status === "pending") {
+		refresh();
+	}
+}
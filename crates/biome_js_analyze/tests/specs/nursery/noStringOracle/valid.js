@@ -0,0 +1,30 @@
+function render(status) {
+	if (status === "active" || status === "pending") {
+		show();
+	}
+}
+
+// Comparisons with other values aren't counted
+let count = getCount();
+if (count === 0 || count === 1 || count === null || count === undefined) {
+	reset();
+}
+
+// Only comparisons with the variable itself are counted
+const user = getUser();
+if (user.role === "admin" || user.role === "owner" || user.role === "guest") {
+	allow();
+}
+if (typeof user === "object" || typeof user === "function" || typeof user === "string") {
+	check();
+}
+
+// Other operators aren't comparisons
+let name = getName();
+const greeting = name + "!" + name + "?" + name + ".";
+const isAfter = name > "a" && name > "b" && name > "c";
+
+// Unresolved references aren't reported
+if (global === "a" || global === "b" || global === "c") {
+	run();
+}
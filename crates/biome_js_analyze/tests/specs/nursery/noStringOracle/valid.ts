@@ -0,0 +1,15 @@
+type Status = "active" | "inactive" | "pending";
+
+function render(status: Status) {
+	if (status === "active" || status === "pending") {
+		show();
+	}
+	if (status === "inactive") {
+		hide();
+	}
+}
+
+let mode: "dark" | "light" | "system" = getMode();
+if (mode === "dark" || mode === "light" || mode === "system") {
+	apply(mode);
+}
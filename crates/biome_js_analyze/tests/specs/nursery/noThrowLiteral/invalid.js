@@ -0,0 +1,13 @@
+throw "Something went wrong";
+throw 'single quotes' /* comment */;
+throw `Failed: ${reason}`;
+throw { message: "oops" };
+throw [1, 2];
+throw null;
+throw 0;
+throw undefined;
+throw "Error: " + message;
+throw (("parenthesized"));
+
+const message = "constant";
+throw message;
@@ -0,0 +1,26 @@
+throw new Error("Something went wrong");
+throw new CustomError();
+throw createError("message");
+throw this.error;
+throw tag`template`;
+
+try {
+	run();
+} catch (e) {
+	throw e;
+}
+
+function rethrow(error) {
+	throw error;
+}
+
+let mutable = "value";
+mutable = new Error(mutable);
+throw mutable;
+
+const error = new Error("constant");
+throw error;
+
+function shadowed(undefined) {
+	throw undefined;
+}
@@ -0,0 +1,13 @@
+function sleep() {}
+
+class Pause {}
+
+function compute() {
+	return 1;
+}
+
+async function f() {
+	await sleep();
+	await new Pause();
+	await compute();
+}
@@ -0,0 +1,41 @@
+async function literals() {
+	await 42;
+	await "value";
+	await null;
+	await (true);
+	await `template`;
+}
+
+function compute() {
+	return 1;
+}
+
+function log(message) {
+	console.log(message);
+}
+
+const double = function () {
+	return 2;
+};
+
+const answer = () => 42;
+
+const nothing = () => {
+	if (condition) {
+		return;
+	}
+	return "done";
+};
+
+class Counter {
+	increment() {}
+}
+
+async function calls() {
+	await compute();
+	await log("message");
+	await double();
+	await answer();
+	await nothing();
+	await new Counter();
+}
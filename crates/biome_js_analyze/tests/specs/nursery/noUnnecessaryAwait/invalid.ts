@@ -0,0 +1,25 @@
+function count(): number {
+	return items.length;
+}
+
+function names(): string[] {
+	return items.map((item) => item.name);
+}
+
+function status(): "ok" | "error" | undefined {
+	return result;
+}
+
+function isString(value: unknown): value is string {
+	return typeof value === "string";
+}
+
+const pair = (): [number, number] => [first, second];
+
+async function f() {
+	await count();
+	await names();
+	await status();
+	await isString(value);
+	await pair();
+}
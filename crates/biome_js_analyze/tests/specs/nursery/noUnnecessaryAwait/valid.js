@@ -0,0 +1,41 @@
+async function load() {
+	return fetch("/data");
+}
+
+function request() {
+	return fetch("/data");
+}
+
+function wrap() {
+	return function () {
+		return 1;
+	};
+}
+
+let replaceable = () => 1;
+
+function* generator() {
+	yield 1;
+}
+
+class Deferred {
+	then(resolve) {
+		resolve(1);
+	}
+}
+
+class Task extends Promise {}
+
+async function f() {
+	await load();
+	await request();
+	await wrap();
+	await replaceable();
+	await generator();
+	await new Deferred();
+	await new Task(() => {});
+	await unknown();
+	await object.method();
+	await value;
+	await tagged`template`;
+}
@@ -0,0 +1,17 @@
+function load(): Promise<number> {
+	return fetch("/data");
+}
+
+function unknownResult(): unknown {
+	return value;
+}
+
+function maybe(): number | PromiseLike<number> {
+	return value;
+}
+
+async function f() {
+	await load();
+	await unknownResult();
+	await maybe();
+}
@@ -0,0 +1,29 @@
+({ a: 1 })?.a;
+({})?.["a"];
+[1, 2]?.length;
+"text"?.length;
+`text`?.length;
+(42)?.toFixed();
+42?.toFixed();
+true?.valueOf();
+/a/?.test("a");
+new Map()?.get("key");
+(function () {})?.();
+(() => {})?.();
+(class {})?.name;
+
+function f() {}
+f?.();
+f?.name;
+
+class A {}
+A?.name;
+
+const object = { a: 1 };
+object?.a;
+
+let array = [];
+array?.length;
+
+(f)?.call(null);
+/* comment */ f /* before */ ?. /* after */ name;
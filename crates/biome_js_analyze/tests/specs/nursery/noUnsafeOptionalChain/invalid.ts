@@ -0,0 +1,39 @@
+function a(options: { verbose: boolean }) {
+	return options?.verbose;
+}
+
+function b(items: string[], name: string) {
+	return [items?.length, name?.length];
+}
+
+function c(value: readonly number[] | (() => void)) {
+	return value?.toString();
+}
+
+function d(value: { a: 1 } & unknown) {
+	return value?.a;
+}
+
+interface Options {
+	verbose: boolean;
+}
+
+class Service {
+	run() {}
+}
+
+enum Kind {
+	A,
+}
+
+function e(options: Options, service: Service) {
+	service?.run();
+	Kind?.A;
+	return options?.verbose;
+}
+
+const f: Options = { verbose: true };
+f?.verbose;
+
+let g!: Service;
+g?.run();
@@ -0,0 +1,32 @@
+foo?.bar;
+foo?.();
+foo?.[0];
+null?.a;
+(void 0)?.a;
+tag`text`?.length;
+a?.b?.c;
+f()?.a;
+
+let g = function () {};
+g = undefined;
+g?.();
+
+var h = {};
+h?.a;
+
+let i;
+i?.a;
+
+const { j } = { j: {} };
+j?.a;
+
+function k(l = {}) {
+	return l?.a;
+}
+
+function m(Symbol) {
+	return Symbol?.iterator;
+}
+
+({}).a;
+f.name;
@@ -0,0 +1,38 @@
+function a(options?: { verbose: boolean }) {
+	return options?.verbose;
+}
+
+function b(options: { verbose: boolean } | undefined) {
+	return options?.verbose;
+}
+
+function c(options: { verbose: boolean } | null) {
+	return options?.verbose;
+}
+
+function d(value: any, other: unknown) {
+	return [value?.a, other?.toString()];
+}
+
+type Options = { verbose: boolean } | undefined;
+
+function e(options: Options) {
+	return options?.verbose;
+}
+
+function f<T>(value: T) {
+	return value?.toString();
+}
+
+function g(value: Map<string, string>) {
+	return value?.get("a");
+}
+
+function h({ options }: { options?: { verbose: boolean } }) {
+	return options?.verbose;
+}
+
+function i(options: { verbose: boolean }) {
+	options = undefined as any;
+	return options?.verbose;
+}
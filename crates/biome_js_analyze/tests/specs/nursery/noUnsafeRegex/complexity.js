@@ -0,0 +1,8 @@
+// Quadratic
+/^\d+\.?\d*$/;
+
+// Cubic
+/^\d+\d+\d+$/;
+
+// Exponential
+/^(a+)+$/;
@@ -0,0 +1,33 @@
+// Nested quantifiers
+/(a+)+$/;
+/^(a*)*b$/;
+/^(\w+\s?)+$/;
+/^(x+x+)+y$/;
+
+// Quantified alternatives that overlap
+/^(a|a)+$/;
+/^(a|aa)+$/;
+/^(a|a?)+$/;
+/^(\d|[0-9a-f])*$/i;
+
+// Consecutive quantifiers that overlap
+/([a-zA-Z0-9])+([a-zA-Z0-9])+/;
+/^\d+\.?\d*$/;
+/^\s*\s*$/;
+/^a+A+$/i;
+
+// Adapted from the OWASP ReDoS examples
+/^([a-zA-Z0-9])(([\-.]|[_]+)?([a-zA-Z0-9]+))*(@){1}[a-z0-9]+[.]{1}(([a-z]{2,3})|([a-z]{2,3}[.]{1}[a-z]{2,3}))$/;
+/^(([a-z])+.)+[A-Z]([a-z])+$/;
+
+// Adapted from CVE-2015-8315 (ms)
+/^((?:\d+)?\.?\d+) *(milliseconds?|msecs?|ms|seconds?|secs?|s|minutes?|mins?|m|hours?|hrs?|h|days?|d|years?|yrs?|y)?$/i;
+
+// Adapted from CVE-2021-3803 (nth-check)
+/^([+-]?\d*n)?\s*(?:([+-]?)\s*(\d+))?$/;
+
+// Adapted from CVE-2021-23343 (path-parse)
+/^(\/?|)([\s\S]*?)((?:\.{1,2}|[^\/]+?|)(\.[^.\/]*|))(?:[\/]*)$/;
+
+// Adapted from CVE-2018-3737 (sshpk)
+/^([a-z0-9-]+)[ \t]+([a-zA-Z0-9+\/]+[=]*)([\n \t]+([^\n]+))?$/;
@@ -0,0 +1,16 @@
+/^a+$/;
+/^(a+b)+$/;
+/^(ab|cd)+$/;
+/^[a-z]+\d+$/;
+/^\d{1,3}(\.\d{1,3}){3}$/;
+/^#?([a-f\d]{2})([a-f\d]{2})([a-f\d]{2})$/i;
+/^[\w.+-]+@[\w-]+\.[\w.-]+$/;
+/^\s+|\s+$/g;
+/(?=a+)a*/;
+/(a)\1+/;
+/\p{L}+/u;
+/[\p{L}--\p{N}]+/v;
+/^(?:[^"\\]|\\.)*$/;
+
+// Invalid patterns are ignored
+/(a+/;
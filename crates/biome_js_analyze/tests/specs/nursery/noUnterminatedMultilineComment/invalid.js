@@ -0,0 +1,3 @@
+const a = 1;
+/* The following code is commented out
+const b = 2;
@@ -0,0 +1,9 @@
+/* A block comment */
+const a = 1;
+/*
+ * A multiline comment
+ */
+const b = /**/ 2;
+// /* A line comment
+const c = "/*";
+/* The last comment */
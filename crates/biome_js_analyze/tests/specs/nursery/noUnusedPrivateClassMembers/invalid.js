@@ -0,0 +1,58 @@
+class Unused {
+	#field = 0;
+	#method() {}
+	static #staticField = 0;
+	static #staticMethod() {}
+}
+
+class WriteOnly {
+	#field;
+	constructor(value) {
+		this.#field = value;
+	}
+}
+
+class UpdatedOnly {
+	#count = 0;
+	#total = 0;
+	increment() {
+		this.#count++;
+		(this.#total += 1);
+		for (let i = 0; i < 10; this.#count++) {}
+	}
+}
+
+class DestructuredWrite {
+	#value;
+	assign(values) {
+		[this.#value] = values;
+		({ value: this.#value } = values);
+	}
+}
+
+class AccessorPair {
+	get #value() {
+		return 0;
+	}
+	set #value(value) {}
+	get #other() {
+		return 0;
+	}
+	set #other(value) {}
+	read() {
+		this.#other = 1;
+		return this.#value;
+	}
+}
+
+class Outer {
+	#shadowed = 0;
+	method() {
+		return class Inner {
+			#shadowed = 1;
+			read() {
+				return this.#shadowed;
+			}
+		};
+	}
+}
@@ -0,0 +1,65 @@
+class Read {
+	#field = 0;
+	read() {
+		return this.#field;
+	}
+}
+
+class Called {
+	#method() {}
+	static #staticMethod() {}
+	call() {
+		this.#method();
+		Called.#staticMethod();
+	}
+}
+
+class UpdatedAndUsed {
+	#count = 0;
+	#total = 0;
+	increment() {
+		const total = (this.#total += 1);
+		return this.#count++;
+	}
+}
+
+class BrandCheck {
+	#brand;
+	static is(value) {
+		return #brand in value;
+	}
+}
+
+class AccessorPair {
+	get #value() {
+		return 0;
+	}
+	set #value(value) {}
+	update() {
+		this.#value = this.#value + 1;
+	}
+}
+
+class UsedByNestedClass {
+	#field = 0;
+	method() {
+		const self = this;
+		return class {
+			read() {
+				return self.#field;
+			}
+		};
+	}
+}
+
+class Accessor {
+	accessor #value = 0;
+	read() {
+		return this.#value;
+	}
+}
+
+class Public {
+	field = 0;
+	method() {}
+}
@@ -0,0 +1,21 @@
+// biome-ignore-format-start
+// biome-ignore-format-end
+const a = 1;
+
+function f() {
+	// biome-ignore-format-start
+	/* biome-ignore-format-end */
+}
+
+// biome-ignore-format-start
+const   b   =   [
+	// biome-ignore-format-start
+	// biome-ignore-format-end
+];
+// biome-ignore-format-end
+
+const c = 1;
+// biome-ignore-format-end
+
+// biome-ignore-format-start
+const   d   =   [];
@@ -0,0 +1,23 @@
+// biome-ignore-format-start
+const   table   =   [
+	1,  0,
+	0,  1,
+];
+// biome-ignore-format-end
+
+function f() {
+	// biome-ignore-format-start: aligned on purpose
+	const   x   =   1;
+	const   yy  =   2;
+}
+// biome-ignore-format-end
+
+// biome-ignore-format-start
+const   outer   =   1;
+// biome-ignore-format-start
+const   inner   =   2;
+// biome-ignore-format-end
+// biome-ignore-format-end
+
+// biome-ignore format: single node
+const   single   =   1;
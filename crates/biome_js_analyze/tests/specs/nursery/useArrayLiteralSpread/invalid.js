@@ -0,0 +1,11 @@
+first.concat(second);
+first.concat(second, third, fourth);
+first.concat([1, 2], third);
+[1, 2].concat(3, 4);
+[].concat(items);
+(a || b).concat(c);
+first.concat(second).concat(third);
+getItems().concat(await fetchItems());
+const list = [1];
+list.concat(other);
+first /* items */ .concat(second);
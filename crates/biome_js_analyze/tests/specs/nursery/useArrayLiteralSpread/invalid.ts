@@ -0,0 +1,11 @@
+function append(items: string[], other: string[]) {
+	return items.concat(other);
+}
+function merge(items: Array<number>, a: number, b: readonly number[], c: ReadonlyArray<number>) {
+	return items.concat(a, b, c);
+}
+let tuple: [number, number] = [1, 2];
+tuple.concat(3);
+function unknownType(value: Iterable<number>) {
+	return value.concat(other);
+}
@@ -0,0 +1,11 @@
+[...first, ...second];
+first.concat();
+first.concat(...second);
+first?.concat(second);
+first.concat?.(second);
+first["concat"](second);
+"Hello ".concat(name);
+`Hello `.concat(name);
+const greeting = "Hello ";
+greeting.concat(name);
+first.push(second);
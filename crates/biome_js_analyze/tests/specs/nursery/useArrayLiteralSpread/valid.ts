@@ -0,0 +1,8 @@
+function greet(name: string) {
+	return name.concat("!");
+}
+function greetAll(names: string[], separator: "," | ";") {
+	return separator.concat(names.join(separator));
+}
+let label!: string;
+label.concat("!");
@@ -0,0 +1,14 @@
+class Decorated {
+	@Input() a: string;
+	@Input()
+	b: string;
+
+	@Output()
+	c = new EventEmitter();
+}
+
+class Overloads {
+	method(a: string): void;
+	method(a: number): void;
+	method(a: unknown) {}
+}
@@ -0,0 +1,28 @@
+class Fields {
+	a = 1;
+	b = 2;
+	#c = 3;
+}
+
+class Methods {
+	constructor() {}
+	method() {}
+	static staticMethod() {}
+}
+
+class Comments {
+	a = 1;
+	// A comment
+	b = 2;
+}
+
+class SameLine {
+	a = 1; b = 2;
+}
+
+class Accessors {
+	get a() {
+		return 0;
+	}
+	set b(value) {}
+}
@@ -0,0 +1,21 @@
+class Fields {
+	a = 1;
+
+	b = 2;
+
+
+	#c = 3;
+}
+
+class Comments {
+	a = 1;
+
+	// A comment
+
+	b = 2;
+}
+
+class Compact {
+	a = 1;
+	b = 2;
+}
@@ -0,0 +1,34 @@
+class Fields {
+	a = 1;
+
+	b = 2;
+
+	#c = 3;
+}
+
+class Comments {
+	a = 1;
+
+	// A comment
+	b = 2;
+}
+
+class AccessorPair {
+	get value() {
+		return this.#value;
+	}
+	set value(value) {
+		this.#value = value;
+	}
+
+	static get instance() {
+		return instance;
+	}
+	static set instance(value) {}
+}
+
+class Empty {}
+
+class Single {
+	a = 1;
+}
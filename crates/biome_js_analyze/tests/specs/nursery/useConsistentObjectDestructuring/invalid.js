@@ -0,0 +1,31 @@
+let user, other, data, size, point, vector;
+const name = user.name;
+const email = user.email;
+const role = user.role;
+
+let width = size.width;
+let height = size.height;
+let ratio = size.aspectRatio;
+
+function read(config) {
+	var host = config.host;
+	var port = config.port;
+	var secure = config.secure;
+	var timeout = config.timeout;
+	return [host, port, secure, timeout];
+}
+
+// The sequence stops at the first declaration of another object.
+const first = point.x;
+const second = point.y;
+const third = point.z;
+const other = vector.x;
+
+const keep = data.a;
+// a comment
+const comments = data.b;
+const preserved = data.c;
+
+const language = navigator.language;
+const platform = navigator.platform;
+const vendor = navigator.vendor;
@@ -0,0 +1,3 @@
+let user;
+const name = user.name;
+const email = user.email;
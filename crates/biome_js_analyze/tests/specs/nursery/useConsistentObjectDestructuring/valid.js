@@ -0,0 +1,53 @@
+let user, other;
+
+{
+	const { name, email, role } = user;
+}
+
+{
+	const name = user.name;
+	const email = user.email;
+}
+
+{
+	const name = user.name;
+	let email = user.email;
+	const role = user.role;
+}
+
+{
+	const name = user.name;
+	const email = other.email;
+	const role = user.role;
+}
+
+{
+	const name = user?.name;
+	const email = user?.email;
+	const role = user?.role;
+}
+
+{
+	const name = user.name,
+		email = user.email;
+	const role = user.role;
+}
+
+{
+	const name = user["name"];
+	const email = user["email"];
+	const role = user["role"];
+}
+
+{
+	const name = getUser().name;
+	const email = getUser().email;
+	const role = getUser().role;
+}
+
+{
+	const name = user.name;
+	const email = user.email;
+	console.log(name, email);
+	const role = user.role;
+}
@@ -0,0 +1,84 @@
+export function isEmpty(list: unknown[]) {
+	return list.length === 0;
+}
+
+export function log(message: string) {
+	console.log(message);
+}
+
+export function find(list: string[], value: string) {
+	for (const item of list) {
+		if (item === value) {
+			return true;
+		}
+	}
+	return false;
+}
+
+export function compute(a: number) {
+	return a * 2;
+}
+
+export default function () {}
+
+export const arrow = (a: number) => a > 0;
+
+export const expression = function () {
+	return;
+};
+
+export async function save(data: string) {
+	await fetch("/save", { body: data });
+}
+
+export const load = async () => {
+	return !(await fetch("/"));
+};
+
+export function* generate() {
+	yield 1;
+}
+
+export function nested() {
+	const inner = () => 1;
+	return !inner();
+}
+
+export declare function declared(a: string);
+
+export function parse(input: string): number;
+export function parse(input: number);
+export function parse(input: string | number): number {
+	return Number(input);
+}
+
+export class Cache {
+	get(key: string) {
+		return this.map.get(key);
+	}
+
+	public clear() {
+		this.map.clear();
+	}
+
+	static create() {
+		return new Cache();
+	}
+
+	async has(key: string) {
+		return this.map.has(key);
+	}
+
+	remove(key: string);
+	remove(key: string): void {}
+}
+
+export const Point = class {
+	isOrigin() {
+		return this.x === 0 && this.y === 0;
+	}
+};
+
+export abstract class Shape {
+	abstract area();
+}
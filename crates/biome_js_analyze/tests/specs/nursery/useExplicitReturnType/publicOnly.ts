@@ -0,0 +1,15 @@
+function helper() {
+	return 42;
+}
+
+const local = () => {};
+
+class Internal {
+	run() {}
+}
+
+export class Cache {
+	private clear() {}
+}
+
+[1, 2].map((n) => n * 2);
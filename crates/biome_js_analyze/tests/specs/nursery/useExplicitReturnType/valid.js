@@ -0,0 +1,3 @@
+export function notChecked() {
+	return 1;
+}
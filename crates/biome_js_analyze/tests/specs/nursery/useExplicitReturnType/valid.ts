@@ -0,0 +1,59 @@
+export function isEmpty(list: unknown[]): boolean {
+	return list.length === 0;
+}
+
+export async function load(url: string): Promise<Response> {
+	return fetch(url);
+}
+
+export function* generate(): Generator<number> {
+	yield 1;
+}
+
+export const arrow = (a: number): boolean => a > 0;
+
+export function isString(value: unknown): value is string {
+	return typeof value === "string";
+}
+
+export function parse(input: string): number;
+export function parse(input: number): number;
+export function parse(input: string | number): number {
+	return Number(input);
+}
+
+function helper() {
+	return 42;
+}
+
+const local = () => {};
+
+export const callbacks = [1, 2].map((n) => n * 2);
+
+export class Cache {
+	private clear() {
+		this.map.clear();
+	}
+
+	protected reset() {}
+
+	#compute() {
+		return 1;
+	}
+
+	constructor() {}
+
+	get size() {
+		return this.map.size;
+	}
+
+	set size(value: number) {}
+
+	has(key: string): boolean {
+		return this.map.has(key);
+	}
+}
+
+class Internal {
+	run() {}
+}
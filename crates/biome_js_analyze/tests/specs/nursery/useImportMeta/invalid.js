@@ -0,0 +1,6 @@
+import { join } from "node:path";
+
+const config = join(__dirname, "config.json");
+const self = __filename;
+console.log(`Running ${__filename} from ${__dirname}`);
+const paths = { __dirname };
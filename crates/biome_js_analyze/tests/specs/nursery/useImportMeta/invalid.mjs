@@ -0,0 +1,2 @@
+const config = __dirname + "/config.json";
+const self = __filename;
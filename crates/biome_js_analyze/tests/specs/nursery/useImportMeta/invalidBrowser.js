@@ -0,0 +1,2 @@
+export const directory = __dirname;
+export const file = __filename;
@@ -0,0 +1,3 @@
+module.exports = {
+	config: __dirname + "/config.json",
+};
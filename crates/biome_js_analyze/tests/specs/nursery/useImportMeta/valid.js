@@ -0,0 +1,12 @@
+import { dirname } from "node:path";
+import { fileURLToPath } from "node:url";
+
+// Local bindings
+const __filename = fileURLToPath(import.meta.url);
+const __dirname = dirname(__filename);
+console.log(__dirname, __filename);
+
+// Feature detection
+if (typeof __dirname !== "undefined") {
+	run();
+}
@@ -0,0 +1,3 @@
+const { join } = require("node:path");
+
+export const config = join(__dirname, "config.json");
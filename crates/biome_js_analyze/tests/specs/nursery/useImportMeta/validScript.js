@@ -0,0 +1,2 @@
+// No import or export statement
+const config = __dirname + "/config.json";
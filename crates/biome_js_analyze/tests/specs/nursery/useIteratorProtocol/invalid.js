@@ -0,0 +1,47 @@
+class Counter {
+	count = 0;
+	next() {
+		return { value: this.count++, done: false };
+	}
+}
+
+const Anonymous = class {
+	next = () => ({ value: undefined, done: true });
+};
+
+const iterator = {
+	next() {
+		return { value: undefined, done: true };
+	},
+};
+
+const withFunction = {
+	"next": function () {
+		return { value: undefined, done: true };
+	},
+};
+
+const asyncIterator = {
+	async next() {
+		return { value: undefined, done: true };
+	},
+};
+
+// A local binding named `Symbol` isn't the global `Symbol`
+function shadowed(Symbol) {
+	return {
+		next() {
+			return { value: undefined, done: true };
+		},
+		[Symbol.iterator]() {
+			return this;
+		},
+	};
+}
+
+class Unrelated {
+	next() {}
+	[Symbol.toStringTag]() {
+		return "Unrelated";
+	}
+}
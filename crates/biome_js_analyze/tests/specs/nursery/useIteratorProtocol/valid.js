@@ -0,0 +1,61 @@
+class Counter {
+	count = 0;
+	next() {
+		return { value: this.count++, done: false };
+	}
+	[Symbol.iterator]() {
+		return this;
+	}
+}
+
+class AsyncCounter {
+	async next() {
+		return { value: undefined, done: true };
+	}
+	[Symbol.asyncIterator]() {
+		return this;
+	}
+}
+
+const iterator = {
+	next() {
+		return { value: undefined, done: true };
+	},
+	[(Symbol.iterator)]() {
+		return this;
+	},
+};
+
+const getter = {
+	next() {
+		return { value: undefined, done: true };
+	},
+	get [Symbol.iterator]() {
+		return () => this;
+	},
+};
+
+class Child extends Base {
+	next() {}
+}
+
+const spread = {
+	...base,
+	next() {},
+};
+
+class Static {
+	static next() {}
+}
+
+const node = { next: null, value: 1 };
+
+const privateNext = class {
+	#next() {}
+};
+
+function* generator() {
+	yield 1;
+}
+
+generator().next();
@@ -0,0 +1,23 @@
+x = x ?? y;
+
+x = x || y;
+
+x = x && y;
+
+obj.prop = obj.prop ?? {};
+
+obj.a.b = obj.a.b || defaultValue;
+
+obj["key"] = obj["key"] ?? 0;
+
+flags[0] = flags[0] && isEnabled;
+
+items[i] = items[i] ?? [];
+
+this.cache = this.cache || new Map();
+
+x = (x ?? y);
+
+x = x ?? (y || z);
+
+x /* comment */ = x ?? y;
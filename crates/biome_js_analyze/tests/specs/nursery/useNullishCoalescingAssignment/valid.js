@@ -0,0 +1,29 @@
+x ??= y;
+
+x ||= y;
+
+x &&= y;
+
+x = y ?? x;
+
+x = x + y;
+
+x = y ?? z;
+
+x = x ?? y ?? z;
+
+obj.a = obj.b ?? y;
+
+obj[f()] = obj[f()] ?? y;
+
+obj[i++] = obj[i++] || y;
+
+obj[(i = 0)] = obj[(i = 0)] && y;
+
+obj.a[b()].c = obj.a[b()].c ?? y;
+
+obj[`${key}`] = obj[`${key}`] ?? y;
+
+[x] = x ?? y;
+
+x += x ?? y;
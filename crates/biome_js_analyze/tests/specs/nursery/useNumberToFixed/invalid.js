@@ -0,0 +1,23 @@
+const price = Math.round(total * 100) / 100;
+const reversed = Math.round(100 * total) / 100;
+const tenth = Math.round(ratio * 10) / 10;
+const exponent = Math.round(ratio * 1e3) / 1e3;
+const pow = Math.round(value * Math.pow(10, 2)) / Math.pow(10, 2);
+const exponentiation = Math.round(value * 10 ** 4) / 10 ** 4;
+const mixed = Math.round(value * Math.pow(10, 2)) / 100;
+const maximum = Math.round(value * 1e20) / 1e20;
+const parenthesized = (Math.round((total * 100)) / (100));
+const member = Math.round(order.total * 100) / 100;
+const sum = Math.round((subtotal + tax) * 100) / 100;
+const literal = Math.round(1.005 * 100) / 100;
+
+const FACTOR = 100;
+const extracted = Math.round(total * FACTOR) / FACTOR;
+
+const POWER = Math.pow(10, 3);
+const extractedPow = Math.round(total * POWER) / 1000;
+
+function format(amount) {
+	const scale = 10 ** 2;
+	return `$${Math.round(amount * scale) / scale}`;
+}
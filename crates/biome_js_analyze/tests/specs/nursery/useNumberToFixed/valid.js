@@ -0,0 +1,30 @@
+const price = total.toFixed(2);
+const rounded = Math.round(total);
+
+// n = 0 is `Math.round(total)`
+const unit = Math.round(total * 1) / 1;
+const unitPow = Math.round(total * Math.pow(10, 0)) / Math.pow(10, 0);
+
+// Different factors
+const mismatch = Math.round(total * 100) / 10;
+const notPowerOfTen = Math.round(total * 50) / 50;
+const tooLarge = Math.round(total * 1e21) / 1e21;
+const negative = Math.round(total * Math.pow(10, -2)) / Math.pow(10, -2);
+
+// Other operations
+const floor = Math.floor(total * 100) / 100;
+const ceil = Math.ceil(total * 100) / 100;
+const multiplied = Math.round(total * 100) * 100;
+const divided = Math.round(total / 100) / 100;
+const sum = Math.round(total + 100) / 100;
+const extraArgument = Math.round(total * 100, 2) / 100;
+
+// The factor may change
+let factor = 100;
+const variable = Math.round(total * factor) / factor;
+const unknown = Math.round(total * precision) / precision;
+
+// Not the global `Math`
+function round(Math) {
+	return Math.round(total * 100) / 100;
+}
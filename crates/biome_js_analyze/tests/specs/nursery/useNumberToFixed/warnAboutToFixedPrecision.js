@@ -0,0 +1 @@
+const price = Math.round(total * 100) / 100;
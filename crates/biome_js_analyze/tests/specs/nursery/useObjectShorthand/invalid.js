@@ -0,0 +1,21 @@
+const property = { foo: foo, bar };
+
+const method = { foo: function () {} };
+
+const withParameters = { foo: function (a, b = 1, ...rest) { return rest; } };
+
+const asyncMethod = { foo: async function () { await bar; } };
+
+const generator = { foo: function* () { yield 1; } };
+
+const asyncGenerator = { foo: async function* () {} };
+
+const computed = { [key]: function () {} };
+
+const stringKey = { "foo-bar": function () {} };
+
+const withComments = {
+	// leading comment
+	foo: foo, // trailing comment
+	bar: function () {}, // trailing comment
+};
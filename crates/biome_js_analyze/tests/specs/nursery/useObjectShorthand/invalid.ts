@@ -0,0 +1,3 @@
+const withThis = { foo: function (this: Window, a: number, ...rest: string[]): void {} };
+
+const generic = { foo: function <T>(value: T): T { return value; } };
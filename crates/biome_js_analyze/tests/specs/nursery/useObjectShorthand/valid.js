@@ -0,0 +1,15 @@
+const shorthand = { foo, bar() {}, async baz() {}, *qux() {} };
+
+const arrow = { foo: () => {} };
+
+const named = { foo: function foo() {} };
+
+const otherVariable = { foo: bar };
+
+const computed = { [foo]: foo };
+
+const stringKey = { "foo": foo };
+
+const proto = { __proto__: __proto__, other: { __proto__: function () {} } };
+
+const member = { foo: this.foo };
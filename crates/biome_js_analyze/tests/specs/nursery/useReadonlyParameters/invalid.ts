@@ -0,0 +1,69 @@
+function greet(user: { name: string }) {
+	return `Hello ${user.name}`;
+}
+
+function sum(values: number[]) {
+	return values.reduce((total, value) => total + value, 0);
+}
+
+function first(pair: [string, number]) {
+	return pair[0];
+}
+
+function display(user: User) {
+	console.log(user.name, user.email);
+}
+
+function maybe(user: User | null) {
+	return user?.name;
+}
+
+function optional(options?: Options) {
+	return options?.verbose;
+}
+
+function withDefault(options: Options = {}) {
+	return options.verbose;
+}
+
+function generic<T extends object>(value: T) {
+	return Object.keys(value);
+}
+
+function ignoresDeepMutations(user: User) {
+	user.address.city = "Paris";
+}
+
+function readsWithParentheses(values: number[]) {
+	return (values).slice(1);
+}
+
+const arrow = (user: User) => user.name;
+
+const expression = function (map: Map<string, number>) {
+	return map.get("key");
+};
+
+class Service {
+	constructor(config: Config) {
+		this.url = config.url;
+	}
+
+	method(request: Request) {
+		return request.url;
+	}
+}
+
+const object = {
+	method(event: Event) {
+		return event.type;
+	},
+};
+
+function shadowed(user: User) {
+	{
+		const user = { name: "" };
+		user.name = "John";
+	}
+	return user;
+}
@@ -0,0 +1,11 @@
+function primitive(name: string, count: number, flag: boolean, id: "a" | "b") {
+	return name + count + flag + id;
+}
+
+function mixed(value: string | User) {
+	return value;
+}
+
+function ignored(value: any) {
+	return value;
+}
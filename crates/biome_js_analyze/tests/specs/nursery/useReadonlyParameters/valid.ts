@@ -0,0 +1,95 @@
+function alreadyReadonly(user: Readonly<User>) {
+	return user.name;
+}
+
+function readonlyArray(values: readonly number[]) {
+	return values.length;
+}
+
+function readonlyGeneric(values: ReadonlyArray<number>, map: ReadonlyMap<string, number>, set: ReadonlySet<string>) {
+	return values.length + map.size + set.size;
+}
+
+function reassigned(user: User) {
+	user = { ...user };
+	return user;
+}
+
+function assignsProperty(user: User) {
+	user.name = "John";
+}
+
+function updatesProperty(counter: Counter) {
+	counter.value++;
+}
+
+function compoundAssignsProperty(counter: Counter) {
+	counter.value += 1;
+}
+
+function assignsComputedProperty(values: number[]) {
+	values[0] = 1;
+}
+
+function assignsPropertyWithParentheses(user: User) {
+	(user).name = "John";
+}
+
+function destructuresIntoProperty(user: User, source: Readonly<Source>) {
+	({ name: user.name } = source);
+}
+
+function deletesProperty(user: User) {
+	delete user.name;
+}
+
+function pushes(values: number[]) {
+	values.push(1);
+}
+
+function sorts(values: string[]) {
+	return values.sort();
+}
+
+function primitive(name: string, count: number, flag: boolean, id: "a" | "b") {
+	return name + count + flag + id;
+}
+
+function withoutType(user) {
+	return user.name;
+}
+
+function destructured({ name }: User) {
+	return name;
+}
+
+function rest(...values: number[]) {
+	return values.length;
+}
+
+function ignoredTypes(a: any, b: unknown, c: () => void, d: new () => Foo, e: never) {
+	return [a, b, c, d, e];
+}
+
+declare function declared(user: User): string;
+
+function overloaded(user: User): string;
+function overloaded(user: User, name: string): string;
+function overloaded(user: User, name?: string): string {
+	user.name = name;
+	return user.name;
+}
+
+type Callback = (user: User) => void;
+
+interface Api {
+	method(user: User): void;
+}
+
+abstract class Base {
+	abstract method(user: User): void;
+}
+
+class WithParameterProperty {
+	constructor(private user: User) {}
+}
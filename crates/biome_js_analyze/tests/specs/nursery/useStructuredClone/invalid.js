@@ -0,0 +1,11 @@
+const copy1 = JSON.parse(JSON.stringify(value));
+const copy2 = JSON.parse(JSON.stringify({ a: 1, b: [1, 2] }));
+const copy3 = JSON.parse((JSON.stringify(value)));
+const copy4 = globalThis.JSON.parse(window.JSON.stringify(value));
+
+const serialized = JSON.stringify(value);
+const copy5 = JSON.parse(serialized);
+
+function clone(input) {
+	return JSON.parse(JSON.stringify(input));
+}
@@ -0,0 +1,14 @@
+const copy1 = structuredClone(value);
+const copy2 = JSON.parse(JSON.stringify(value, replacer));
+const copy3 = JSON.parse(JSON.stringify(value), reviver);
+const copy4 = JSON.parse(text);
+const copy5 = JSON.parse(JSON.stringify);
+const copy6 = JSON?.parse(JSON.stringify(value));
+
+let serialized = JSON.stringify(value);
+serialized = "{}";
+const copy7 = JSON.parse(serialized);
+
+function shadowed(JSON) {
+	return JSON.parse(JSON.stringify(value));
+}
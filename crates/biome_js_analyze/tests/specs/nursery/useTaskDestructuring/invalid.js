@@ -0,0 +1,96 @@
+async function blockCallback() {
+	fetchUser().then((user) => {
+		render(user);
+	});
+}
+
+async function expressionCallback() {
+	fetchUser().then((user) => render(user));
+}
+
+async function withoutParameter() {
+	save().then(() => notify());
+}
+
+async function withCatch() {
+	fetchUser()
+		.then((user) => {
+			render(user);
+		})
+		.catch((error) => {
+			report(error);
+		});
+}
+
+async function withCatchWithoutParameter() {
+	fetchUser().then((user) => render(user)).catch(() => retry());
+}
+
+async function withFinally() {
+	fetchUser().then((user) => render(user)).finally(() => hideSpinner());
+}
+
+async function withCatchAndFinally() {
+	fetchUser()
+		.then(({ name }) => {
+			greet(name);
+		})
+		.catch((error) => report(error))
+		.finally(() => hideSpinner());
+}
+
+async function twoArguments() {
+	fetchUser().then((user) => render(user), (error) => report(error));
+}
+
+const asyncArrow = async () => {
+	fetchUser().then((user) => render(user)).catch((error) => report(error));
+};
+
+class Page {
+	async load() {
+		this.fetchUser().then((user) => this.render(user)).catch((error) => report(error));
+	}
+}
+
+// The following chains are reported without fix
+
+function notAsync() {
+	fetchUser().then((user) => render(user));
+}
+
+fetchUser().then((user) => render(user));
+
+async function earlyReturn() {
+	fetchUser().then((user) => {
+		if (!user) {
+			return;
+		}
+		render(user);
+	});
+}
+
+async function functionReferences() {
+	fetchUser().then(render, report);
+}
+
+async function multipleThen() {
+	fetchUser()
+		.then((user) => user.name)
+		.then((name) => greet(name));
+}
+
+async function catchBeforeThen() {
+	fetchUser()
+		.catch(() => defaultUser)
+		.then((user) => render(user));
+}
+
+async function shadowedParameter() {
+	user.then((user) => render(user));
+}
+
+async function usedName() {
+	const user = getCachedUser();
+	fetchUser().then((user) => render(user));
+}
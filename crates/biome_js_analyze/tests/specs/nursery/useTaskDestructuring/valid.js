@@ -0,0 +1,30 @@
+async function awaited() {
+	try {
+		const user = await fetchUser();
+		render(user);
+	} catch (error) {
+		report(error);
+	}
+}
+
+function returned() {
+	return fetchUser().then((user) => user.name);
+}
+
+async function awaitedChain() {
+	await fetchUser().then((user) => render(user));
+}
+
+Promise.all([fetchUser().then((user) => user.name), fetchSettings()]);
+
+const name = fetchUser().then((user) => user.name);
+
+fetchUser().catch((error) => report(error));
+
+fetchUser().finally(() => hideSpinner());
+
+fetchUser()?.then((user) => render(user));
+
+fetchUser().then?.((user) => render(user));
+
+object.then = callback;
@@ -0,0 +1,12 @@
+class C {
+	constructor(readonly prop: number) {
+		prop++;
+	}
+}
+
+class D {
+	constructor(private prop: number, public other = 0) {
+		prop = 1;
+		other = prop;
+	}
+}
@@ -0,0 +1,8 @@
+import { Children, cloneElement } from "react";
+
+things.map((thing, key) => React.cloneElement(thing, { key }));
+
+Children.map(this.props.children, (child, key) => cloneElement(child, { key }));
+
+// valid
+things.map((key) => React.cloneElement(thing, { key }));
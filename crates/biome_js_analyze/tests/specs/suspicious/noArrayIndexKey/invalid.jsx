@@ -108,3 +108,7 @@ function Component7() {
 		</HoC>
 	);
 }
+
+things.map((thing, index) => <Component key={`${index}`} />);
+
+things.map((thing, index) => React.cloneElement(thing, { key: `${index}` }));
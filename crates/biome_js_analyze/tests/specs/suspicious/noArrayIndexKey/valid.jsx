@@ -66,3 +66,7 @@ function Component15() {
         return <Component key={id} />
     }
 }
+
+things.map((thing, key) => React.cloneElement(thing, { key }));
+
+things.map((thing, index) => <Component key={tag`${index}`} />);
@@ -9,7 +9,10 @@ use biome_formatter::{
     },
     write,
 };
-use biome_js_syntax::suppression::parse_suppression_comment;
+use biome_js_syntax::suppression::{
+    is_format_suppression_end, is_format_suppression_start, is_prettier_ignore,
+    parse_suppression_comment,
+};
 use biome_js_syntax::JsSyntaxKind::JS_EXPORT;
 use biome_js_syntax::{
     AnyJsClass, AnyJsName, AnyJsRoot, AnyJsStatement, JsArrayHole, JsArrowFunctionExpression,
@@ -17,7 +20,7 @@ use biome_js_syntax::{
     JsFormalParameter, JsFunctionBody, JsIdentifierExpression, JsIfStatement, JsLanguage,
     JsSyntaxKind, JsSyntaxNode, JsVariableDeclarator, JsWhileStatement, TsInterfaceDeclaration,
 };
-use biome_rowan::{AstNode, SyntaxNodeOptionExt, SyntaxTriviaPieceComments, TextLen};
+use biome_rowan::{AstNode, SyntaxNodeOptionExt, SyntaxTriviaPieceComments, TextLen, TextSize};
 
 pub type JsComments = Comments<JsLanguage>;
 
@@ -32,6 +35,22 @@ impl FormatRule<SourceComment<JsLanguage>> for FormatJsLeadingComment {
         comment: &SourceComment<JsLanguage>,
         f: &mut Formatter<Self::Context>,
     ) -> FormatResult<()> {
+        if f.options().long_comment_style().is_line() && is_free_standing(comment) {
+            if let Some(lines) = block_comment_lines(comment.piece()) {
+                let source_offset = comment.piece().text_range().start();
+                let mut join = f.join_with(hard_line_break());
+                for line in lines {
+                    let text = if line.is_empty() {
+                        String::from("//")
+                    } else {
+                        std::format!("// {line}")
+                    };
+                    join.entry(&dynamic_text(&text, source_offset));
+                }
+                return join.finish();
+            }
+        }
+
         if is_doc_comment(comment.piece()) {
             let mut source_offset = comment.piece().text_range().start();
 
@@ -68,8 +87,143 @@ impl FormatRule<SourceComment<JsLanguage>> for FormatJsLeadingComment {
     }
 }
 
-#[derive(Eq, PartialEq, Copy, Clone, Debug, Default)]
-pub struct JsCommentStyle;
+/// Returns `true` if `comment` is a block comment that spans multiple lines, and that has its own lines:
+/// it starts a line, and the following token starts on a later line.
+///
+/// Such a comment can be printed as a series of line comments without commenting out the code that follows it.
+fn is_free_standing(comment: &SourceComment<JsLanguage>) -> bool {
+    comment.kind() == CommentKind::Block
+        && comment.lines_after() > 0
+        && (comment.lines_before() > 0 || comment.piece().text_range().start() == TextSize::from(0))
+}
+
+/// Returns the lines of text of a block comment, without the comment delimiters and
+/// the leading `*` of every line, or [None] if the comment is a JSDoc comment or has no text.
+///
+/// ```js
+/// /*
+///  * Some
+///  *   text
+///  */
+/// ```
+///
+/// The lines of this comment are `Some` and `  text`.
+fn block_comment_lines(comment: &SyntaxTriviaPieceComments<JsLanguage>) -> Option<Vec<String>> {
+    let text = comment.text();
+    if text.starts_with("/**") {
+        return None;
+    }
+    let content = text.strip_prefix("/*")?.strip_suffix("*/")?;
+    let has_stars = is_doc_comment(comment);
+
+    let mut lines: Vec<&str> = content.lines().map(str::trim_end).collect();
+    if has_stars {
+        for line in lines.iter_mut().skip(1) {
+            let line_text = line.trim_start();
+            *line = line_text.strip_prefix('*').unwrap_or(line_text);
+        }
+    }
+    // The text of the first line starts right after `/*`: its indentation isn't significant
+    if let Some(first) = lines.first_mut() {
+        *first = first.trim_start();
+    }
+
+    // Remove the indentation shared by the other lines, to keep the relative indentation of the text
+    let indentation = lines
+        .iter()
+        .skip(1)
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| line.len() - line.trim_start().len())
+        .min()
+        .unwrap_or(0);
+    let mut lines: Vec<String> = lines
+        .iter()
+        .enumerate()
+        .map(|(index, line)| {
+            if index == 0 || line.trim().is_empty() {
+                line.trim().to_string()
+            } else {
+                line[indentation..].to_string()
+            }
+        })
+        .collect();
+
+    while lines.last().is_some_and(String::is_empty) {
+        lines.pop();
+    }
+    let first_line = lines.iter().position(|line| !line.is_empty())?;
+    lines.drain(..first_line);
+    Some(lines)
+}
+
+#[derive(Eq, PartialEq, Copy, Clone, Debug)]
+pub struct JsCommentStyle {
+    /// Whether `// prettier-ignore` comments suppress the formatting of the following node
+    honor_prettier_ignore: bool,
+    /// Whether the number of blank lines after the imports is enforced
+    enforce_blank_lines_after_imports: bool,
+}
+
+impl Default for JsCommentStyle {
+    fn default() -> Self {
+        Self {
+            honor_prettier_ignore: true,
+            enforce_blank_lines_after_imports: false,
+        }
+    }
+}
+
+impl JsCommentStyle {
+    pub fn with_honor_prettier_ignore(mut self, honor_prettier_ignore: bool) -> Self {
+        self.honor_prettier_ignore = honor_prettier_ignore;
+        self
+    }
+
+    pub fn with_enforce_blank_lines_after_imports(
+        mut self,
+        enforce_blank_lines_after_imports: bool,
+    ) -> Self {
+        self.enforce_blank_lines_after_imports = enforce_blank_lines_after_imports;
+        self
+    }
+
+    /// Makes the comments between the last import and the following statement trailing comments of the import,
+    /// so that the blank lines after the imports are counted from the last comment.
+    ///
+    /// Suppression comments, region comments, and doc comments remain leading comments of the statement.
+    fn handle_comment_after_imports(
+        &self,
+        comment: DecoratedComment<JsLanguage>,
+    ) -> CommentPlacement<JsLanguage> {
+        if !self.enforce_blank_lines_after_imports {
+            return CommentPlacement::Default(comment);
+        }
+        let text = comment.piece().text();
+        if comment.kind().is_suppression()
+            || (self.suppression_test())(text)
+            || is_format_suppression_start(text)
+            || is_format_suppression_end(text)
+            || text.starts_with("/**")
+        {
+            return CommentPlacement::Default(comment);
+        }
+        match (comment.preceding_node(), comment.following_node()) {
+            (Some(preceding), Some(following))
+                if preceding.kind() == JsSyntaxKind::JS_IMPORT
+                    && following.kind() != JsSyntaxKind::JS_IMPORT
+                    && following.parent().kind() == Some(JsSyntaxKind::JS_MODULE_ITEM_LIST) =>
+            {
+                CommentPlacement::trailing(preceding.clone(), comment)
+            }
+            _ => CommentPlacement::Default(comment),
+        }
+    }
+
+    /// Returns `true` if `text` is a `// biome-ignore format` or a `// prettier-ignore` comment.
+    fn is_suppression_or_prettier_ignore(text: &str) -> bool {
+        Self::is_suppression(text) || is_prettier_ignore(text)
+    }
+}
 
 impl CommentStyle for JsCommentStyle {
     type Language = JsLanguage;
@@ -81,6 +235,22 @@ impl CommentStyle for JsCommentStyle {
             .any(|(key, _)| key == category!("format"))
     }
 
+    fn suppression_test(&self) -> fn(&str) -> bool {
+        if self.honor_prettier_ignore {
+            Self::is_suppression_or_prettier_ignore
+        } else {
+            Self::is_suppression
+        }
+    }
+
+    fn is_suppression_start(text: &str) -> bool {
+        is_format_suppression_start(text)
+    }
+
+    fn is_suppression_end(text: &str) -> bool {
+        is_format_suppression_end(text)
+    }
+
     fn get_comment_kind(comment: &SyntaxTriviaPieceComments<JsLanguage>) -> CommentKind {
         if comment.text().starts_with("/*") {
             if comment.has_newline() {
@@ -88,6 +258,10 @@ impl CommentStyle for JsCommentStyle {
             } else {
                 CommentKind::InlineBlock
             }
+        } else if is_suppression_comment(comment.text()) {
+            CommentKind::Suppression
+        } else if is_region_comment(comment.text()) {
+            CommentKind::Region
         } else {
             CommentKind::Line
         }
@@ -109,7 +283,9 @@ impl CommentStyle for JsCommentStyle {
                 .or_else(handle_for_comment)
                 .or_else(handle_root_comments)
                 .or_else(handle_array_hole_comment)
-                .or_else(handle_variable_declarator_comment)
+                .or_else(|comment| {
+                    handle_variable_declarator_comment(comment, self.suppression_test())
+                })
                 .or_else(handle_parameter_comment)
                 .or_else(handle_labelled_statement_comment)
                 .or_else(handle_call_expression_comment)
@@ -117,7 +293,10 @@ impl CommentStyle for JsCommentStyle {
                 .or_else(handle_mapped_type_comment)
                 .or_else(handle_switch_default_case_comment)
                 .or_else(handle_import_export_specifier_comment),
-            CommentTextPosition::OwnLine => handle_member_expression_comment(comment)
+            CommentTextPosition::OwnLine => handle_suppression_comment(comment)
+                .or_else(handle_region_comment)
+                .or_else(|comment| self.handle_comment_after_imports(comment))
+                .or_else(handle_member_expression_comment)
                 .or_else(handle_function_declaration_comment)
                 .or_else(handle_if_statement_comment)
                 .or_else(handle_while_comment)
@@ -877,6 +1056,7 @@ fn handle_for_comment(comment: DecoratedComment<JsLanguage>) -> CommentPlacement
 
 fn handle_variable_declarator_comment(
     comment: DecoratedComment<JsLanguage>,
+    is_suppression: fn(&str) -> bool,
 ) -> CommentPlacement<JsLanguage> {
     let following = match comment.following_node() {
         Some(following) => following,
@@ -947,7 +1127,7 @@ fn handle_variable_declarator_comment(
                 //      b;
                 // ```
                 if not_complex
-                    && !JsCommentStyle::is_suppression(comment.piece().text())
+                    && !is_suppression(comment.piece().text())
                     && comment.kind().is_line()
                     && comment.preceding_node().is_none()
                 {
@@ -1099,6 +1279,55 @@ fn handle_import_export_specifier_comment(
     }
 }
 
+/// Keeps an own line [suppression](CommentKind::Suppression) comment attached to the statement that follows it.
+fn handle_suppression_comment(
+    comment: DecoratedComment<JsLanguage>,
+) -> CommentPlacement<JsLanguage> {
+    if !comment.kind().is_suppression() {
+        return CommentPlacement::Default(comment);
+    }
+
+    match comment.following_node() {
+        Some(following)
+            if matches!(
+                following.parent().kind(),
+                Some(JsSyntaxKind::JS_STATEMENT_LIST | JsSyntaxKind::JS_MODULE_ITEM_LIST)
+            ) =>
+        {
+            CommentPlacement::leading(following.clone(), comment)
+        }
+        _ => CommentPlacement::Default(comment),
+    }
+}
+
+/// Keeps an own line [region](CommentKind::Region) comment in front of the member that follows it,
+/// so that the region starts and ends at the same place after formatting.
+fn handle_region_comment(comment: DecoratedComment<JsLanguage>) -> CommentPlacement<JsLanguage> {
+    if !comment.kind().is_region() {
+        return CommentPlacement::Default(comment);
+    }
+
+    match comment.following_node() {
+        Some(following)
+            if matches!(
+                following.parent().kind(),
+                Some(
+                    JsSyntaxKind::JS_STATEMENT_LIST
+                        | JsSyntaxKind::JS_MODULE_ITEM_LIST
+                        | JsSyntaxKind::JS_CLASS_MEMBER_LIST
+                        | JsSyntaxKind::JS_OBJECT_MEMBER_LIST
+                        | JsSyntaxKind::TS_TYPE_MEMBER_LIST
+                        | JsSyntaxKind::TS_ENUM_MEMBER_LIST
+                        | JsSyntaxKind::JS_SWITCH_CASE_LIST
+                )
+            ) =>
+        {
+            CommentPlacement::leading(following.clone(), comment)
+        }
+        _ => CommentPlacement::Default(comment),
+    }
+}
+
 fn place_leading_statement_comment(
     statement: AnyJsStatement,
     comment: DecoratedComment<JsLanguage>,
@@ -1124,6 +1353,43 @@ fn place_block_statement_comment(
     }
 }
 
+/// Returns `true` if `text` is a line comment that suppresses the diagnostics of
+/// TypeScript or ESLint on the following line, such as `// @ts-expect-error`.
+fn is_suppression_comment(text: &str) -> bool {
+    let Some(content) = text.strip_prefix("//") else {
+        return false;
+    };
+    let content = content.trim_start();
+
+    [
+        "@ts-ignore",
+        "@ts-expect-error",
+        "eslint-disable-next-line",
+        "eslint-disable-line",
+    ]
+    .iter()
+    .any(|directive| match content.strip_prefix(directive) {
+        Some(rest) => !rest.starts_with(|c: char| c.is_alphanumeric() || c == '-'),
+        None => false,
+    })
+}
+
+/// Returns `true` if `text` is a line comment that starts or ends a foldable region of code,
+/// such as `// #region Helpers` or `// #endregion`.
+fn is_region_comment(text: &str) -> bool {
+    let Some(content) = text.strip_prefix("//") else {
+        return false;
+    };
+    let content = content.trim_start();
+
+    ["#region", "#endregion"]
+        .iter()
+        .any(|marker| match content.strip_prefix(marker) {
+            Some(rest) => rest.is_empty() || rest.starts_with(char::is_whitespace),
+            None => false,
+        })
+}
+
 /// Returns `true` if `comment` is a [Closure type comment](https://github.com/google/closure-compiler/wiki/Types-in-the-Closure-Type-System)
 /// or [TypeScript type comment](https://www.typescriptlang.org/docs/handbook/jsdoc-supported-types.html#type)
 pub(crate) fn is_type_comment(comment: &SyntaxTriviaPieceComments<JsLanguage>) -> bool {
@@ -1,12 +1,12 @@
 use crate::comments::{FormatJsLeadingComment, JsCommentStyle, JsComments};
 use crate::context::trailing_comma::TrailingComma;
-use biome_deserialize::json::with_only_known_variants;
+use biome_deserialize::json::{has_only_known_keys, with_only_known_variants, VisitJsonNode};
 use biome_deserialize::{DeserializationDiagnostic, VisitNode};
 use biome_formatter::printer::PrinterOptions;
 use biome_formatter::token::string::Quote;
 use biome_formatter::{
     CstFormatContext, FormatContext, FormatElement, FormatOptions, IndentStyle, IndentWidth,
-    LineWidth, TransformSourceMap,
+    LineWidth, TrailingNewline, TransformSourceMap,
 };
 use biome_js_syntax::{AnyJsFunctionBody, JsFileSource, JsLanguage};
 use biome_json_syntax::JsonLanguage;
@@ -144,6 +144,9 @@ pub struct JsFormatOptions {
     /// What's the max width of a line. Defaults to 80.
     line_width: LineWidth,
 
+    /// Whether the formatted code ends with a newline. Defaults to "always".
+    trailing_newline: TrailingNewline,
+
     /// The style for quotes. Defaults to double.
     quote_style: QuoteStyle,
 
@@ -162,6 +165,59 @@ pub struct JsFormatOptions {
     /// Whether to add non-necessary parentheses to arrow functions. Defaults to "always".
     arrow_parentheses: ArrowParentheses,
 
+    /// Whether the formatter may change the layout of template literals. Defaults to "normalize".
+    template_literal_indentation: TemplateLiteralIndentation,
+
+    /// How the attributes of a multiline JSX element are aligned. Defaults to "indent".
+    jsx_attribute_alignment: JsxAttributeAlignment,
+
+    /// Whether `// prettier-ignore` comments suppress the formatting of the following node. Defaults to `true`.
+    honor_prettier_ignore: bool,
+
+    /// Call expressions with this many arguments or fewer keep their arguments on a single line,
+    /// even when they exceed the line width. `0` disables the option. Defaults to `0`.
+    function_call_arguments_on_one_line: u8,
+
+    /// Whether a variable declaration initialized with a long string literal may break after the `=`. Defaults to "always".
+    wrap_long_strings: WrapLongStrings,
+
+    /// The style of the free-standing comments that span multiple lines. Defaults to "block".
+    long_comment_style: LongCommentStyle,
+
+    /// Whether to insert spaces inside the braces of object destructuring patterns, and of named imports and exports. Defaults to "always".
+    object_destructuring_spacing: ObjectDestructuringSpacing,
+
+    /// The number of blank lines between the last import and the following statement, from 0 to 2.
+    /// `None` keeps the blank lines of the source. Defaults to `None`.
+    blank_lines_after_imports: Option<u8>,
+
+    /// Whether the `=` of consecutive variable declarations are aligned in the same column. Defaults to `false`.
+    align_consecutive_assignments: bool,
+
+    /// Whether `void 0` is replaced by `undefined`, or `undefined` by `void 0`. Defaults to "preserve".
+    undefined_style: UndefinedStyle,
+
+    /// How the chains of conditional expressions nested in the alternate are formatted when they break. Defaults to "flat".
+    nested_ternary_style: NestedTernaryStyle,
+
+    /// Whether `else`, `catch`, and `finally` are on the same line as the closing brace of the previous block. Defaults to true.
+    else_on_same_line: bool,
+
+    /// Whether the arguments of `new URL(...)` that use `import.meta` stay on one line. Defaults to true.
+    keep_import_meta_on_one_line: bool,
+
+    /// Whether the expressions of a template literal can break at their `${` and `}` when the template literal exceeds the line width. Defaults to false.
+    break_template_expressions: bool,
+
+    /// Whether the `while` of a `do...while` loop is on the same line as the closing brace of the body. Defaults to "sameLine".
+    do_while_style: DoWhileStyle,
+
+    /// How numeric literals are normalized. By default, only the normalizations that don't change the style are applied.
+    numeric_literal_style: NumericLiteralStyle,
+
+    /// Whether the `as` and `satisfies` operators of a TypeScript expression that exceeds the line width stay on the same line as the expression. Defaults to "sameLine".
+    type_assertion_style: TypeAssertionStyle,
+
     /// Information related to the current file
     source_type: JsFileSource,
 }
@@ -173,12 +229,30 @@ impl JsFormatOptions {
             indent_style: IndentStyle::default(),
             indent_width: IndentWidth::default(),
             line_width: LineWidth::default(),
+            trailing_newline: TrailingNewline::default(),
             quote_style: QuoteStyle::default(),
             jsx_quote_style: QuoteStyle::default(),
             quote_properties: QuoteProperties::default(),
             trailing_comma: TrailingComma::default(),
             semicolons: Semicolons::default(),
             arrow_parentheses: ArrowParentheses::default(),
+            template_literal_indentation: TemplateLiteralIndentation::default(),
+            jsx_attribute_alignment: JsxAttributeAlignment::default(),
+            honor_prettier_ignore: true,
+            function_call_arguments_on_one_line: 0,
+            wrap_long_strings: WrapLongStrings::default(),
+            long_comment_style: LongCommentStyle::default(),
+            object_destructuring_spacing: ObjectDestructuringSpacing::default(),
+            blank_lines_after_imports: None,
+            align_consecutive_assignments: false,
+            undefined_style: UndefinedStyle::default(),
+            nested_ternary_style: NestedTernaryStyle::default(),
+            else_on_same_line: true,
+            keep_import_meta_on_one_line: true,
+            break_template_expressions: false,
+            do_while_style: DoWhileStyle::default(),
+            numeric_literal_style: NumericLiteralStyle::default(),
+            type_assertion_style: TypeAssertionStyle::default(),
         }
     }
 
@@ -187,6 +261,109 @@ impl JsFormatOptions {
         self
     }
 
+    pub fn with_template_literal_indentation(
+        mut self,
+        template_literal_indentation: TemplateLiteralIndentation,
+    ) -> Self {
+        self.template_literal_indentation = template_literal_indentation;
+        self
+    }
+
+    pub fn with_jsx_attribute_alignment(
+        mut self,
+        jsx_attribute_alignment: JsxAttributeAlignment,
+    ) -> Self {
+        self.jsx_attribute_alignment = jsx_attribute_alignment;
+        self
+    }
+
+    pub fn with_honor_prettier_ignore(mut self, honor_prettier_ignore: bool) -> Self {
+        self.honor_prettier_ignore = honor_prettier_ignore;
+        self
+    }
+
+    pub fn with_function_call_arguments_on_one_line(
+        mut self,
+        function_call_arguments_on_one_line: u8,
+    ) -> Self {
+        self.function_call_arguments_on_one_line = function_call_arguments_on_one_line;
+        self
+    }
+
+    pub fn with_wrap_long_strings(mut self, wrap_long_strings: WrapLongStrings) -> Self {
+        self.wrap_long_strings = wrap_long_strings;
+        self
+    }
+
+    pub fn with_long_comment_style(mut self, long_comment_style: LongCommentStyle) -> Self {
+        self.long_comment_style = long_comment_style;
+        self
+    }
+
+    pub fn with_object_destructuring_spacing(
+        mut self,
+        object_destructuring_spacing: ObjectDestructuringSpacing,
+    ) -> Self {
+        self.object_destructuring_spacing = object_destructuring_spacing;
+        self
+    }
+
+    pub fn with_blank_lines_after_imports(mut self, blank_lines_after_imports: Option<u8>) -> Self {
+        self.blank_lines_after_imports = blank_lines_after_imports;
+        self
+    }
+
+    pub fn with_align_consecutive_assignments(
+        mut self,
+        align_consecutive_assignments: bool,
+    ) -> Self {
+        self.align_consecutive_assignments = align_consecutive_assignments;
+        self
+    }
+
+    pub fn with_undefined_style(mut self, undefined_style: UndefinedStyle) -> Self {
+        self.undefined_style = undefined_style;
+        self
+    }
+
+    pub fn with_nested_ternary_style(mut self, nested_ternary_style: NestedTernaryStyle) -> Self {
+        self.nested_ternary_style = nested_ternary_style;
+        self
+    }
+
+    pub fn with_else_on_same_line(mut self, else_on_same_line: bool) -> Self {
+        self.else_on_same_line = else_on_same_line;
+        self
+    }
+
+    pub fn with_keep_import_meta_on_one_line(mut self, keep_import_meta_on_one_line: bool) -> Self {
+        self.keep_import_meta_on_one_line = keep_import_meta_on_one_line;
+        self
+    }
+
+    pub fn with_break_template_expressions(mut self, break_template_expressions: bool) -> Self {
+        self.break_template_expressions = break_template_expressions;
+        self
+    }
+
+    pub fn with_do_while_style(mut self, do_while_style: DoWhileStyle) -> Self {
+        self.do_while_style = do_while_style;
+        self
+    }
+
+    pub fn with_numeric_literal_style(
+        mut self,
+        numeric_literal_style: NumericLiteralStyle,
+    ) -> Self {
+        self.numeric_literal_style = numeric_literal_style;
+        self
+    }
+
+    pub fn with_type_assertion_style(mut self, type_assertion_style: TypeAssertionStyle) -> Self {
+        self.type_assertion_style = type_assertion_style;
+        self
+    }
+
     pub fn with_indent_style(mut self, indent_style: IndentStyle) -> Self {
         self.indent_style = indent_style;
         self
@@ -202,6 +379,11 @@ impl JsFormatOptions {
         self
     }
 
+    pub fn with_trailing_newline(mut self, trailing_newline: TrailingNewline) -> Self {
+        self.trailing_newline = trailing_newline;
+        self
+    }
+
     pub fn with_quote_style(mut self, quote_style: QuoteStyle) -> Self {
         self.quote_style = quote_style;
         self
@@ -231,6 +413,78 @@ impl JsFormatOptions {
         self.arrow_parentheses
     }
 
+    pub fn template_literal_indentation(&self) -> TemplateLiteralIndentation {
+        self.template_literal_indentation
+    }
+
+    pub fn jsx_attribute_alignment(&self) -> JsxAttributeAlignment {
+        self.jsx_attribute_alignment
+    }
+
+    pub fn honor_prettier_ignore(&self) -> bool {
+        self.honor_prettier_ignore
+    }
+
+    pub fn function_call_arguments_on_one_line(&self) -> u8 {
+        self.function_call_arguments_on_one_line
+    }
+
+    pub fn wrap_long_strings(&self) -> WrapLongStrings {
+        self.wrap_long_strings
+    }
+
+    pub fn long_comment_style(&self) -> LongCommentStyle {
+        self.long_comment_style
+    }
+
+    pub fn object_destructuring_spacing(&self) -> ObjectDestructuringSpacing {
+        self.object_destructuring_spacing
+    }
+
+    pub fn blank_lines_after_imports(&self) -> Option<u8> {
+        self.blank_lines_after_imports
+    }
+
+    pub fn align_consecutive_assignments(&self) -> bool {
+        self.align_consecutive_assignments
+    }
+
+    pub fn undefined_style(&self) -> UndefinedStyle {
+        self.undefined_style
+    }
+
+    pub fn nested_ternary_style(&self) -> NestedTernaryStyle {
+        self.nested_ternary_style
+    }
+
+    pub fn else_on_same_line(&self) -> bool {
+        self.else_on_same_line
+    }
+
+    pub fn keep_import_meta_on_one_line(&self) -> bool {
+        self.keep_import_meta_on_one_line
+    }
+
+    pub fn break_template_expressions(&self) -> bool {
+        self.break_template_expressions
+    }
+
+    pub fn do_while_style(&self) -> DoWhileStyle {
+        self.do_while_style
+    }
+
+    pub fn numeric_literal_style(&self) -> NumericLiteralStyle {
+        self.numeric_literal_style
+    }
+
+    pub fn type_assertion_style(&self) -> TypeAssertionStyle {
+        self.type_assertion_style
+    }
+
+    pub fn trailing_newline(&self) -> TrailingNewline {
+        self.trailing_newline
+    }
+
     pub fn quote_style(&self) -> QuoteStyle {
         self.quote_style
     }
@@ -274,7 +528,7 @@ impl FormatOptions for JsFormatOptions {
     }
 
     fn as_print_options(&self) -> PrinterOptions {
-        PrinterOptions::from(self)
+        PrinterOptions::from(self).with_trailing_newline(self.trailing_newline)
     }
 }
 
@@ -283,12 +537,61 @@ impl fmt::Display for JsFormatOptions {
         writeln!(f, "Indent style: {}", self.indent_style)?;
         writeln!(f, "Indent width: {}", self.indent_width.value())?;
         writeln!(f, "Line width: {}", self.line_width.value())?;
+        writeln!(f, "Trailing newline: {}", self.trailing_newline)?;
         writeln!(f, "Quote style: {}", self.quote_style)?;
         writeln!(f, "JSX quote style: {}", self.jsx_quote_style)?;
         writeln!(f, "Quote properties: {}", self.quote_properties)?;
         writeln!(f, "Trailing comma: {}", self.trailing_comma)?;
         writeln!(f, "Semicolons: {}", self.semicolons)?;
-        writeln!(f, "Arrow parentheses: {}", self.arrow_parentheses)
+        writeln!(f, "Arrow parentheses: {}", self.arrow_parentheses)?;
+        writeln!(
+            f,
+            "Template literal indentation: {}",
+            self.template_literal_indentation
+        )?;
+        writeln!(
+            f,
+            "JSX attribute alignment: {}",
+            self.jsx_attribute_alignment
+        )?;
+        writeln!(f, "Honor prettier-ignore: {}", self.honor_prettier_ignore)?;
+        writeln!(
+            f,
+            "Function call arguments on one line: {}",
+            self.function_call_arguments_on_one_line
+        )?;
+        writeln!(f, "Wrap long strings: {}", self.wrap_long_strings)?;
+        writeln!(f, "Long comment style: {}", self.long_comment_style)?;
+        writeln!(
+            f,
+            "Object destructuring spacing: {}",
+            self.object_destructuring_spacing
+        )?;
+        match self.blank_lines_after_imports {
+            Some(blank_lines) => writeln!(f, "Blank lines after imports: {blank_lines}")?,
+            None => writeln!(f, "Blank lines after imports: Preserve")?,
+        }
+        writeln!(
+            f,
+            "Align consecutive assignments: {}",
+            self.align_consecutive_assignments
+        )?;
+        writeln!(f, "Undefined style: {}", self.undefined_style)?;
+        writeln!(f, "Nested ternary style: {}", self.nested_ternary_style)?;
+        writeln!(f, "Else on same line: {}", self.else_on_same_line)?;
+        writeln!(
+            f,
+            "Keep import.meta on one line: {}",
+            self.keep_import_meta_on_one_line
+        )?;
+        writeln!(
+            f,
+            "Break template expressions: {}",
+            self.break_template_expressions
+        )?;
+        writeln!(f, "Do while style: {}", self.do_while_style)?;
+        writeln!(f, "Numeric literal style: {}", self.numeric_literal_style)?;
+        writeln!(f, "Type assertion style: {}", self.type_assertion_style)
     }
 }
 
@@ -573,3 +876,836 @@ impl VisitNode<JsonLanguage> for ArrowParentheses {
         Some(())
     }
 }
+
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Hash, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize, schemars::JsonSchema),
+    serde(rename_all = "camelCase")
+)]
+pub enum TemplateLiteralIndentation {
+    /// The expressions of a template literal may be broken over several lines and indented.
+    #[default]
+    Normalize,
+    /// The expressions of a template literal aren't broken to fit the line width,
+    /// and the text of a template literal is never changed.
+    Preserve,
+}
+
+impl TemplateLiteralIndentation {
+    pub(crate) const KNOWN_VALUES: &'static [&'static str] = &["normalize", "preserve"];
+
+    pub const fn is_normalize(&self) -> bool {
+        matches!(self, Self::Normalize)
+    }
+
+    pub const fn is_preserve(&self) -> bool {
+        matches!(self, Self::Preserve)
+    }
+}
+
+impl FromStr for TemplateLiteralIndentation {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "normalize" | "Normalize" => Ok(Self::Normalize),
+            "preserve" | "Preserve" => Ok(Self::Preserve),
+            _ => Err("Value not supported for Template literal indentation. Supported values are 'normalize' and 'preserve'."),
+        }
+    }
+}
+
+impl fmt::Display for TemplateLiteralIndentation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TemplateLiteralIndentation::Normalize => write!(f, "Normalize"),
+            TemplateLiteralIndentation::Preserve => write!(f, "Preserve"),
+        }
+    }
+}
+
+impl VisitNode<JsonLanguage> for TemplateLiteralIndentation {
+    fn visit_member_value(
+        &mut self,
+        node: &SyntaxNode<JsonLanguage>,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<()> {
+        let node =
+            with_only_known_variants(node, TemplateLiteralIndentation::KNOWN_VALUES, diagnostics)?;
+        if node.inner_string_text().ok()?.text() == "preserve" {
+            *self = TemplateLiteralIndentation::Preserve;
+        } else {
+            *self = TemplateLiteralIndentation::Normalize;
+        }
+        Some(())
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Hash, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize, schemars::JsonSchema),
+    serde(rename_all = "camelCase")
+)]
+pub enum JsxAttributeAlignment {
+    /// The attributes that don't fit on the line of the tag are indented by one level.
+    #[default]
+    Indent,
+    /// The attributes that don't fit on the line of the tag are aligned under the first attribute.
+    FirstAttr,
+}
+
+impl JsxAttributeAlignment {
+    pub(crate) const KNOWN_VALUES: &'static [&'static str] = &["indent", "firstAttr"];
+
+    pub const fn is_indent(&self) -> bool {
+        matches!(self, Self::Indent)
+    }
+
+    pub const fn is_first_attr(&self) -> bool {
+        matches!(self, Self::FirstAttr)
+    }
+}
+
+impl FromStr for JsxAttributeAlignment {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "indent" | "Indent" => Ok(Self::Indent),
+            "firstAttr" | "FirstAttr" => Ok(Self::FirstAttr),
+            _ => Err("Value not supported for JSX attribute alignment. Supported values are 'indent' and 'firstAttr'."),
+        }
+    }
+}
+
+impl fmt::Display for JsxAttributeAlignment {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            JsxAttributeAlignment::Indent => write!(f, "Indent"),
+            JsxAttributeAlignment::FirstAttr => write!(f, "FirstAttr"),
+        }
+    }
+}
+
+impl VisitNode<JsonLanguage> for JsxAttributeAlignment {
+    fn visit_member_value(
+        &mut self,
+        node: &SyntaxNode<JsonLanguage>,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<()> {
+        let node =
+            with_only_known_variants(node, JsxAttributeAlignment::KNOWN_VALUES, diagnostics)?;
+        if node.inner_string_text().ok()?.text() == "firstAttr" {
+            *self = JsxAttributeAlignment::FirstAttr;
+        } else {
+            *self = JsxAttributeAlignment::Indent;
+        }
+        Some(())
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Hash, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize, schemars::JsonSchema),
+    serde(rename_all = "camelCase")
+)]
+pub enum WrapLongStrings {
+    /// A long string literal stays on the line of the variable it initializes.
+    Never,
+    /// A long string literal that doesn't fit on the line of the variable is moved to the next line.
+    #[default]
+    Always,
+}
+
+impl WrapLongStrings {
+    pub(crate) const KNOWN_VALUES: &'static [&'static str] = &["never", "always"];
+
+    pub const fn is_never(&self) -> bool {
+        matches!(self, Self::Never)
+    }
+
+    pub const fn is_always(&self) -> bool {
+        matches!(self, Self::Always)
+    }
+}
+
+impl FromStr for WrapLongStrings {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "never" | "Never" => Ok(Self::Never),
+            "always" | "Always" => Ok(Self::Always),
+            _ => Err("Value not supported for wrap long strings. Supported values are 'never' and 'always'."),
+        }
+    }
+}
+
+impl fmt::Display for WrapLongStrings {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            WrapLongStrings::Never => write!(f, "Never"),
+            WrapLongStrings::Always => write!(f, "Always"),
+        }
+    }
+}
+
+impl VisitNode<JsonLanguage> for WrapLongStrings {
+    fn visit_member_value(
+        &mut self,
+        node: &SyntaxNode<JsonLanguage>,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<()> {
+        let node = with_only_known_variants(node, WrapLongStrings::KNOWN_VALUES, diagnostics)?;
+        if node.inner_string_text().ok()?.text() == "always" {
+            *self = WrapLongStrings::Always;
+        } else {
+            *self = WrapLongStrings::Never;
+        }
+        Some(())
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Hash, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize, schemars::JsonSchema),
+    serde(rename_all = "camelCase")
+)]
+pub enum LongCommentStyle {
+    /// Comments that span multiple lines are kept as they are.
+    #[default]
+    Block,
+    /// Free-standing block comments that span multiple lines are converted to a series of line comments.
+    /// JSDoc comments are kept as they are.
+    Line,
+}
+
+impl LongCommentStyle {
+    pub(crate) const KNOWN_VALUES: &'static [&'static str] = &["block", "line"];
+
+    pub const fn is_block(&self) -> bool {
+        matches!(self, Self::Block)
+    }
+
+    pub const fn is_line(&self) -> bool {
+        matches!(self, Self::Line)
+    }
+}
+
+impl FromStr for LongCommentStyle {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "block" | "Block" => Ok(Self::Block),
+            "line" | "Line" => Ok(Self::Line),
+            _ => Err("Value not supported for long comment style. Supported values are 'block' and 'line'."),
+        }
+    }
+}
+
+impl fmt::Display for LongCommentStyle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LongCommentStyle::Block => write!(f, "Block"),
+            LongCommentStyle::Line => write!(f, "Line"),
+        }
+    }
+}
+
+impl VisitNode<JsonLanguage> for LongCommentStyle {
+    fn visit_member_value(
+        &mut self,
+        node: &SyntaxNode<JsonLanguage>,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<()> {
+        let node = with_only_known_variants(node, LongCommentStyle::KNOWN_VALUES, diagnostics)?;
+        if node.inner_string_text().ok()?.text() == "line" {
+            *self = LongCommentStyle::Line;
+        } else {
+            *self = LongCommentStyle::Block;
+        }
+        Some(())
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Hash, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize, schemars::JsonSchema),
+    serde(rename_all = "camelCase")
+)]
+pub enum ObjectDestructuringSpacing {
+    /// Spaces are inserted inside the braces: `const { a } = b`.
+    #[default]
+    Always,
+    /// No space is inserted inside the braces: `const {a} = b`.
+    Never,
+}
+
+impl ObjectDestructuringSpacing {
+    pub(crate) const KNOWN_VALUES: &'static [&'static str] = &["always", "never"];
+
+    pub const fn is_always(&self) -> bool {
+        matches!(self, Self::Always)
+    }
+
+    pub const fn is_never(&self) -> bool {
+        matches!(self, Self::Never)
+    }
+}
+
+impl FromStr for ObjectDestructuringSpacing {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "always" | "Always" => Ok(Self::Always),
+            "never" | "Never" => Ok(Self::Never),
+            _ => Err("Value not supported for object destructuring spacing. Supported values are 'always' and 'never'."),
+        }
+    }
+}
+
+impl fmt::Display for ObjectDestructuringSpacing {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ObjectDestructuringSpacing::Always => write!(f, "Always"),
+            ObjectDestructuringSpacing::Never => write!(f, "Never"),
+        }
+    }
+}
+
+impl VisitNode<JsonLanguage> for ObjectDestructuringSpacing {
+    fn visit_member_value(
+        &mut self,
+        node: &SyntaxNode<JsonLanguage>,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<()> {
+        let node =
+            with_only_known_variants(node, ObjectDestructuringSpacing::KNOWN_VALUES, diagnostics)?;
+        if node.inner_string_text().ok()?.text() == "never" {
+            *self = ObjectDestructuringSpacing::Never;
+        } else {
+            *self = ObjectDestructuringSpacing::Always;
+        }
+        Some(())
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Hash, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize, schemars::JsonSchema),
+    serde(rename_all = "camelCase")
+)]
+pub enum UndefinedStyle {
+    /// `undefined` and `void 0` are kept as they are written.
+    #[default]
+    Preserve,
+    /// `void 0` is replaced by `undefined`.
+    Undefined,
+    /// `undefined` is replaced by `void 0`.
+    Void0,
+}
+
+impl UndefinedStyle {
+    pub(crate) const KNOWN_VALUES: &'static [&'static str] = &["preserve", "undefined", "void0"];
+
+    pub const fn is_preserve(&self) -> bool {
+        matches!(self, Self::Preserve)
+    }
+
+    pub const fn is_undefined(&self) -> bool {
+        matches!(self, Self::Undefined)
+    }
+
+    pub const fn is_void0(&self) -> bool {
+        matches!(self, Self::Void0)
+    }
+}
+
+impl FromStr for UndefinedStyle {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "preserve" | "Preserve" => Ok(Self::Preserve),
+            "undefined" | "Undefined" => Ok(Self::Undefined),
+            "void0" | "Void0" => Ok(Self::Void0),
+            _ => Err("Value not supported for undefined style. Supported values are 'preserve', 'undefined' and 'void0'."),
+        }
+    }
+}
+
+impl fmt::Display for UndefinedStyle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            UndefinedStyle::Preserve => write!(f, "Preserve"),
+            UndefinedStyle::Undefined => write!(f, "Undefined"),
+            UndefinedStyle::Void0 => write!(f, "Void0"),
+        }
+    }
+}
+
+impl VisitNode<JsonLanguage> for UndefinedStyle {
+    fn visit_member_value(
+        &mut self,
+        node: &SyntaxNode<JsonLanguage>,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<()> {
+        let node = with_only_known_variants(node, UndefinedStyle::KNOWN_VALUES, diagnostics)?;
+        match node.inner_string_text().ok()?.text() {
+            "undefined" => *self = UndefinedStyle::Undefined,
+            "void0" => *self = UndefinedStyle::Void0,
+            _ => *self = UndefinedStyle::Preserve,
+        }
+        Some(())
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Hash, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize, schemars::JsonSchema),
+    serde(rename_all = "camelCase")
+)]
+pub enum NestedTernaryStyle {
+    /// The nested conditionals are aligned with the root conditional, and each `?` and `:` starts a new line.
+    #[default]
+    Flat,
+    /// Each condition and its consequent share a line, and each `:` starts a new line.
+    Ladder,
+}
+
+impl NestedTernaryStyle {
+    pub(crate) const KNOWN_VALUES: &'static [&'static str] = &["flat", "ladder"];
+
+    pub const fn is_flat(&self) -> bool {
+        matches!(self, Self::Flat)
+    }
+
+    pub const fn is_ladder(&self) -> bool {
+        matches!(self, Self::Ladder)
+    }
+}
+
+impl FromStr for NestedTernaryStyle {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "flat" | "Flat" => Ok(Self::Flat),
+            "ladder" | "Ladder" => Ok(Self::Ladder),
+            _ => Err("Value not supported for nested ternary style. Supported values are 'flat' and 'ladder'."),
+        }
+    }
+}
+
+impl fmt::Display for NestedTernaryStyle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            NestedTernaryStyle::Flat => write!(f, "Flat"),
+            NestedTernaryStyle::Ladder => write!(f, "Ladder"),
+        }
+    }
+}
+
+impl VisitNode<JsonLanguage> for NestedTernaryStyle {
+    fn visit_member_value(
+        &mut self,
+        node: &SyntaxNode<JsonLanguage>,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<()> {
+        let node = with_only_known_variants(node, NestedTernaryStyle::KNOWN_VALUES, diagnostics)?;
+        if node.inner_string_text().ok()?.text() == "ladder" {
+            *self = NestedTernaryStyle::Ladder;
+        } else {
+            *self = NestedTernaryStyle::Flat;
+        }
+        Some(())
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Hash, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize, schemars::JsonSchema),
+    serde(rename_all = "camelCase")
+)]
+pub enum DoWhileStyle {
+    /// The `while` is on the same line as the closing brace of the body.
+    #[default]
+    SameLine,
+    /// The `while` starts a new line.
+    NewLine,
+}
+
+impl DoWhileStyle {
+    pub(crate) const KNOWN_VALUES: &'static [&'static str] = &["sameLine", "newLine"];
+
+    pub const fn is_same_line(&self) -> bool {
+        matches!(self, Self::SameLine)
+    }
+
+    pub const fn is_new_line(&self) -> bool {
+        matches!(self, Self::NewLine)
+    }
+}
+
+impl FromStr for DoWhileStyle {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "sameLine" | "SameLine" => Ok(Self::SameLine),
+            "newLine" | "NewLine" => Ok(Self::NewLine),
+            _ => Err("Value not supported for do while style. Supported values are 'sameLine' and 'newLine'."),
+        }
+    }
+}
+
+impl fmt::Display for DoWhileStyle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DoWhileStyle::SameLine => write!(f, "Same line"),
+            DoWhileStyle::NewLine => write!(f, "New line"),
+        }
+    }
+}
+
+impl VisitNode<JsonLanguage> for DoWhileStyle {
+    fn visit_member_value(
+        &mut self,
+        node: &SyntaxNode<JsonLanguage>,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<()> {
+        let node = with_only_known_variants(node, DoWhileStyle::KNOWN_VALUES, diagnostics)?;
+        if node.inner_string_text().ok()?.text() == "newLine" {
+            *self = DoWhileStyle::NewLine;
+        } else {
+            *self = DoWhileStyle::SameLine;
+        }
+        Some(())
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Hash, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize, schemars::JsonSchema),
+    serde(rename_all = "camelCase", default, deny_unknown_fields)
+)]
+pub struct NumericLiteralStyle {
+    /// Whether the `_` separators of numeric literals are kept, removed, or added between the thousands. Defaults to "preserve".
+    pub underscore: UnderscoreStyle,
+    /// The case of the digits of hexadecimal literals. Defaults to "lower".
+    pub hex_case: HexCase,
+    /// Whether decimal literals are written with an exponent when it's shorter, or without an exponent. Defaults to "preserve".
+    pub exponent: ExponentStyle,
+}
+
+impl NumericLiteralStyle {
+    pub(crate) const KNOWN_KEYS: &'static [&'static str] = &["underscore", "hexCase", "exponent"];
+
+    pub const fn is_default(&self) -> bool {
+        matches!(
+            self,
+            Self {
+                underscore: UnderscoreStyle::Preserve,
+                hex_case: HexCase::Lower,
+                exponent: ExponentStyle::Preserve,
+            }
+        )
+    }
+}
+
+impl fmt::Display for NumericLiteralStyle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Underscore: {}, Hex case: {}, Exponent: {}",
+            self.underscore, self.hex_case, self.exponent
+        )
+    }
+}
+
+impl VisitNode<JsonLanguage> for NumericLiteralStyle {
+    fn visit_member_name(
+        &mut self,
+        node: &SyntaxNode<JsonLanguage>,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<()> {
+        has_only_known_keys(node, Self::KNOWN_KEYS, diagnostics)
+    }
+
+    fn visit_map(
+        &mut self,
+        key: &SyntaxNode<JsonLanguage>,
+        value: &SyntaxNode<JsonLanguage>,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<()> {
+        let (name, value) = self.get_key_and_value(key, value, diagnostics)?;
+        let name_text = name.text();
+        match name_text {
+            "underscore" => {
+                let mut underscore = UnderscoreStyle::default();
+                self.map_to_known_string(&value, name_text, &mut underscore, diagnostics)?;
+                self.underscore = underscore;
+            }
+            "hexCase" => {
+                let mut hex_case = HexCase::default();
+                self.map_to_known_string(&value, name_text, &mut hex_case, diagnostics)?;
+                self.hex_case = hex_case;
+            }
+            "exponent" => {
+                let mut exponent = ExponentStyle::default();
+                self.map_to_known_string(&value, name_text, &mut exponent, diagnostics)?;
+                self.exponent = exponent;
+            }
+            _ => {}
+        }
+        Some(())
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Hash, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize, schemars::JsonSchema),
+    serde(rename_all = "camelCase")
+)]
+pub enum UnderscoreStyle {
+    /// The separators are kept as written.
+    #[default]
+    Preserve,
+    /// The separators are removed.
+    Strip,
+    /// The integer part of decimal literals from `10000` is separated by thousands, e.g. `1_000_000`.
+    AddThousands,
+}
+
+impl UnderscoreStyle {
+    pub(crate) const KNOWN_VALUES: &'static [&'static str] = &["preserve", "strip", "addThousands"];
+}
+
+impl FromStr for UnderscoreStyle {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "preserve" | "Preserve" => Ok(Self::Preserve),
+            "strip" | "Strip" => Ok(Self::Strip),
+            "addThousands" | "AddThousands" => Ok(Self::AddThousands),
+            _ => Err("Value not supported for underscore. Supported values are 'preserve', 'strip', and 'addThousands'."),
+        }
+    }
+}
+
+impl fmt::Display for UnderscoreStyle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            UnderscoreStyle::Preserve => write!(f, "Preserve"),
+            UnderscoreStyle::Strip => write!(f, "Strip"),
+            UnderscoreStyle::AddThousands => write!(f, "Add thousands"),
+        }
+    }
+}
+
+impl VisitNode<JsonLanguage> for UnderscoreStyle {
+    fn visit_member_value(
+        &mut self,
+        node: &SyntaxNode<JsonLanguage>,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<()> {
+        let node = with_only_known_variants(node, UnderscoreStyle::KNOWN_VALUES, diagnostics)?;
+        *self = match node.inner_string_text().ok()?.text() {
+            "strip" => UnderscoreStyle::Strip,
+            "addThousands" => UnderscoreStyle::AddThousands,
+            _ => UnderscoreStyle::Preserve,
+        };
+        Some(())
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Hash, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize, schemars::JsonSchema),
+    serde(rename_all = "camelCase")
+)]
+pub enum HexCase {
+    /// The digits are lowercase, e.g. `0xff`.
+    #[default]
+    Lower,
+    /// The digits are uppercase, e.g. `0xFF`.
+    Upper,
+}
+
+impl HexCase {
+    pub(crate) const KNOWN_VALUES: &'static [&'static str] = &["lower", "upper"];
+}
+
+impl FromStr for HexCase {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "lower" | "Lower" => Ok(Self::Lower),
+            "upper" | "Upper" => Ok(Self::Upper),
+            _ => Err("Value not supported for hex case. Supported values are 'lower' and 'upper'."),
+        }
+    }
+}
+
+impl fmt::Display for HexCase {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            HexCase::Lower => write!(f, "Lower"),
+            HexCase::Upper => write!(f, "Upper"),
+        }
+    }
+}
+
+impl VisitNode<JsonLanguage> for HexCase {
+    fn visit_member_value(
+        &mut self,
+        node: &SyntaxNode<JsonLanguage>,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<()> {
+        let node = with_only_known_variants(node, HexCase::KNOWN_VALUES, diagnostics)?;
+        if node.inner_string_text().ok()?.text() == "upper" {
+            *self = HexCase::Upper;
+        } else {
+            *self = HexCase::Lower;
+        }
+        Some(())
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Hash, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize, schemars::JsonSchema),
+    serde(rename_all = "camelCase")
+)]
+pub enum ExponentStyle {
+    /// The exponents are kept as written.
+    #[default]
+    Preserve,
+    /// Decimal literals are written with an exponent when it's shorter, e.g. `1e6` instead of `1000000`.
+    Prefer,
+    /// Decimal literals are written without an exponent, e.g. `1000000` instead of `1e6`,
+    /// unless `Number.prototype.toString` would print the value with an exponent.
+    Avoid,
+}
+
+impl ExponentStyle {
+    pub(crate) const KNOWN_VALUES: &'static [&'static str] = &["preserve", "prefer", "avoid"];
+}
+
+impl FromStr for ExponentStyle {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "preserve" | "Preserve" => Ok(Self::Preserve),
+            "prefer" | "Prefer" => Ok(Self::Prefer),
+            "avoid" | "Avoid" => Ok(Self::Avoid),
+            _ => Err("Value not supported for exponent. Supported values are 'preserve', 'prefer', and 'avoid'."),
+        }
+    }
+}
+
+impl fmt::Display for ExponentStyle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ExponentStyle::Preserve => write!(f, "Preserve"),
+            ExponentStyle::Prefer => write!(f, "Prefer"),
+            ExponentStyle::Avoid => write!(f, "Avoid"),
+        }
+    }
+}
+
+impl VisitNode<JsonLanguage> for ExponentStyle {
+    fn visit_member_value(
+        &mut self,
+        node: &SyntaxNode<JsonLanguage>,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<()> {
+        let node = with_only_known_variants(node, ExponentStyle::KNOWN_VALUES, diagnostics)?;
+        *self = match node.inner_string_text().ok()?.text() {
+            "prefer" => ExponentStyle::Prefer,
+            "avoid" => ExponentStyle::Avoid,
+            _ => ExponentStyle::Preserve,
+        };
+        Some(())
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Hash, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize, schemars::JsonSchema),
+    serde(rename_all = "camelCase")
+)]
+pub enum TypeAssertionStyle {
+    /// The `as` and `satisfies` operators stay on the same line as the expression.
+    #[default]
+    SameLine,
+    /// Each `as` and `satisfies` operator of an expression that exceeds the line width starts a new line.
+    NextLine,
+}
+
+impl TypeAssertionStyle {
+    pub(crate) const KNOWN_VALUES: &'static [&'static str] = &["sameLine", "nextLine"];
+
+    pub const fn is_same_line(&self) -> bool {
+        matches!(self, Self::SameLine)
+    }
+
+    pub const fn is_next_line(&self) -> bool {
+        matches!(self, Self::NextLine)
+    }
+}
+
+impl FromStr for TypeAssertionStyle {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "sameLine" | "SameLine" => Ok(Self::SameLine),
+            "nextLine" | "NextLine" => Ok(Self::NextLine),
+            _ => Err("Value not supported for type assertion style. Supported values are 'sameLine' and 'nextLine'."),
+        }
+    }
+}
+
+impl fmt::Display for TypeAssertionStyle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TypeAssertionStyle::SameLine => write!(f, "Same line"),
+            TypeAssertionStyle::NextLine => write!(f, "Next line"),
+        }
+    }
+}
+
+impl VisitNode<JsonLanguage> for TypeAssertionStyle {
+    fn visit_member_value(
+        &mut self,
+        node: &SyntaxNode<JsonLanguage>,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<()> {
+        let node = with_only_known_variants(node, TypeAssertionStyle::KNOWN_VALUES, diagnostics)?;
+        if node.inner_string_text().ok()?.text() == "nextLine" {
+            *self = TypeAssertionStyle::NextLine;
+        } else {
+            *self = TypeAssertionStyle::SameLine;
+        }
+        Some(())
+    }
+}
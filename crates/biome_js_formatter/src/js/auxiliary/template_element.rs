@@ -120,6 +120,7 @@ impl Format<JsFormatContext> for FormatTemplateElement {
                     write!(f, [format_expression])
                 }
             }
+            TemplateElementLayout::Break => write!(f, [soft_block_indent(&format_expression)]),
         });
 
         let format_indented = format_with(|f: &mut JsFormatter| {
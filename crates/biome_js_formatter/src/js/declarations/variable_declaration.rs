@@ -15,14 +15,18 @@ impl FormatNodeRule<JsVariableDeclaration> for FormatJsVariableDeclaration {
             declarators,
         } = node.as_fields();
 
-        if let Some(await_token) = await_token {
-            write!(f, [await_token.format(), space()])?;
-        }
+        // `await using` is a single keyword of the declaration
+        let format_kind = format_with(|f| {
+            if let Some(await_token) = &await_token {
+                write!(f, [await_token.format(), space()])?;
+            }
+            write!(f, [kind.format()])
+        });
 
         write![
             f,
             [group(&format_args![
-                kind.format(),
+                format_kind,
                 space(),
                 declarators.format()
             ])]
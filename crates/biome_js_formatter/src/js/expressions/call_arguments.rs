@@ -3,6 +3,7 @@ use crate::js::declarations::function_declaration::FormatFunctionOptions;
 use crate::js::expressions::arrow_function_expression::{
     is_multiline_template_starting_on_same_line, FormatJsArrowFunctionExpressionOptions,
 };
+use crate::js::expressions::new_expression::is_new_url_with_import_meta;
 use crate::js::lists::array_element_list::can_concisely_print_array_list;
 use crate::prelude::*;
 use crate::utils::function_body::FunctionBodyCacheMode;
@@ -55,6 +56,8 @@ impl FormatNodeRule<JsCallArguments> for FormatJsCallArguments {
             || is_multiline_template_only_args(node)
             || is_react_hook_with_deps_array(node, f.comments())
             || is_test_call?
+            || is_kept_on_one_line(node, f)
+            || is_import_meta_kept_on_one_line(node, f)
         {
             return write!(
                 f,
@@ -1008,6 +1011,33 @@ fn is_multiline_template_only_args(arguments: &JsCallArguments) -> bool {
     }
 }
 
+/// Returns `true` if the arguments are few enough to stay on a single line,
+/// as configured by the option `functionCallArgumentsOnOneLine`.
+///
+/// Arguments with comments are formatted as usual, because a line comment must be followed by a line break.
+fn is_kept_on_one_line(arguments: &JsCallArguments, f: &JsFormatter) -> bool {
+    let max_arguments = f.options().function_call_arguments_on_one_line();
+    let args = arguments.args();
+
+    max_arguments > 0
+        && args.len() <= usize::from(max_arguments)
+        && !f.comments().has_comments(arguments.syntax())
+        && args.iter().all(|argument| {
+            argument.is_ok_and(|argument| !f.comments().has_comments(argument.syntax()))
+        })
+}
+
+/// Returns `true` if the arguments of `new URL(...)` use `import.meta` and stay on a single line,
+/// as configured by the option `keepImportMetaOnOneLine`.
+fn is_import_meta_kept_on_one_line(arguments: &JsCallArguments, f: &JsFormatter) -> bool {
+    f.options().keep_import_meta_on_one_line()
+        && is_new_url_with_import_meta(arguments)
+        && !f.comments().has_comments(arguments.syntax())
+        && arguments.args().iter().all(|argument| {
+            argument.is_ok_and(|argument| !f.comments().has_comments(argument.syntax()))
+        })
+}
+
 /// This function is used to check if the code is a hook-like code:
 ///
 /// ```js
@@ -1,9 +1,9 @@
 use crate::prelude::*;
 
-use crate::parentheses::NeedsParentheses;
-use biome_formatter::write;
+use crate::parentheses::{update_or_lower_expression_needs_parentheses, NeedsParentheses};
+use biome_formatter::{format_args, write};
 use biome_js_syntax::JsIdentifierExpressionFields;
-use biome_js_syntax::{JsIdentifierExpression, JsSyntaxNode};
+use biome_js_syntax::{JsBinaryExpression, JsBinaryOperator, JsIdentifierExpression, JsSyntaxNode};
 
 #[derive(Debug, Clone, Default)]
 pub(crate) struct FormatJsIdentifierExpression;
@@ -11,6 +11,26 @@ pub(crate) struct FormatJsIdentifierExpression;
 impl FormatNodeRule<JsIdentifierExpression> for FormatJsIdentifierExpression {
     fn fmt_fields(&self, node: &JsIdentifierExpression, f: &mut JsFormatter) -> FormatResult<()> {
         let JsIdentifierExpressionFields { name } = node.as_fields();
+        let name = name?;
+
+        if f.options().undefined_style().is_void0() {
+            let value_token = name.value_token()?;
+            if value_token.text_trimmed() == "undefined" {
+                f.comments().mark_suppression_checked(name.syntax());
+                let void_zero = format_with(|f| write!(f, [text("void"), space(), text("0")]));
+                return if void_zero_needs_parentheses(node) {
+                    write!(
+                        f,
+                        [format_replaced(
+                            &value_token,
+                            &format_args![text("("), void_zero, text(")")]
+                        )]
+                    )
+                } else {
+                    write!(f, [format_replaced(&value_token, &void_zero)])
+                };
+            }
+        }
 
         write![f, [name.format()]]
     }
@@ -20,6 +40,21 @@ impl FormatNodeRule<JsIdentifierExpression> for FormatJsIdentifierExpression {
     }
 }
 
+/// Returns `true` if `void 0` needs parentheses when it replaces `node`, such as in `(void 0).a`.
+fn void_zero_needs_parentheses(node: &JsIdentifierExpression) -> bool {
+    let Some(parent) = node.syntax().parent() else {
+        return false;
+    };
+    if let Some(binary) = JsBinaryExpression::cast_ref(&parent) {
+        matches!(binary.operator(), Ok(JsBinaryOperator::Exponent))
+            && binary
+                .left()
+                .is_ok_and(|left| left.syntax() == node.syntax())
+    } else {
+        update_or_lower_expression_needs_parentheses(node.syntax(), &parent)
+    }
+}
+
 impl NeedsParentheses for JsIdentifierExpression {
     #[inline(always)]
     fn needs_parentheses(&self) -> bool {
@@ -2,8 +2,12 @@ use crate::prelude::*;
 
 use crate::parentheses::NeedsParentheses;
 use biome_formatter::write;
-use biome_js_syntax::{JsNewExpression, JsSyntaxKind};
+use biome_js_syntax::{
+    AnyJsCallArgument, AnyJsExpression, AnyJsLiteralExpression, JsCallArgumentList,
+    JsCallArguments, JsCallExpression, JsNewExpression, JsStaticMemberExpression, JsSyntaxKind,
+};
 use biome_js_syntax::{JsNewExpressionFields, JsSyntaxNode};
+use biome_rowan::AstSeparatedList;
 
 #[derive(Debug, Clone, Default)]
 pub(crate) struct FormatJsNewExpression;
@@ -47,3 +51,115 @@ impl NeedsParentheses for JsNewExpression {
         matches!(parent.kind(), JsSyntaxKind::JS_EXTENDS_CLAUSE)
     }
 }
+
+/// Returns `true` if `arguments` are the arguments of a `new URL(...)` expression
+/// that resolves a path relative to the current module, such as:
+///
+/// ```js
+/// new URL("./worker.js", import.meta.url);
+/// new URL("./data", import.meta.dirname);
+/// new URL("./worker.js", import.meta.resolve("./base/"));
+/// ```
+///
+/// The arguments of the `import.meta.resolve(...)` call of the last example match as well.
+/// They're kept on one line when the option `keepImportMetaOnOneLine` is enabled.
+pub(crate) fn is_new_url_with_import_meta(arguments: &JsCallArguments) -> bool {
+    if let Some(new_expression) = arguments.parent::<JsNewExpression>() {
+        return is_url_constructor(&new_expression);
+    }
+    // The arguments of `import.meta.resolve(...)` within `new URL(...)`
+    arguments.parent::<JsCallExpression>().is_some_and(|call| {
+        is_import_meta_resolve(&call)
+            && call
+                .parent::<JsCallArgumentList>()
+                .and_then(|list| list.parent::<JsCallArguments>())
+                .and_then(|arguments| arguments.parent::<JsNewExpression>())
+                .is_some_and(|new_expression| is_url_constructor(&new_expression))
+    })
+}
+
+/// Returns `true` if `member` is the `import.meta.url` or `import.meta.dirname` argument
+/// of a `new URL(...)` expression matched by [is_new_url_with_import_meta].
+pub(crate) fn is_import_meta_argument_of_new_url(member: &JsStaticMemberExpression) -> bool {
+    matches!(
+        member.object(),
+        Ok(AnyJsExpression::JsImportMetaExpression(_))
+    ) && member
+        .parent::<JsCallArgumentList>()
+        .and_then(|list| list.parent::<JsCallArguments>())
+        .is_some_and(|arguments| is_new_url_with_import_meta(&arguments))
+}
+
+/// Returns `true` for `new URL(path, base)`, where `path` is a string and `base` is
+/// `import.meta.url`, `import.meta.dirname`, or `import.meta.resolve(path)`.
+fn is_url_constructor(new_expression: &JsNewExpression) -> bool {
+    let is_url = new_expression
+        .callee()
+        .ok()
+        .and_then(|callee| callee.as_js_reference_identifier())
+        .and_then(|reference| reference.name().ok())
+        .is_some_and(|name| name.text() == "URL");
+    let Some(arguments) = new_expression.arguments() else {
+        return false;
+    };
+    let args = arguments.args();
+    let mut iter = args.iter();
+    match (iter.next(), iter.next(), iter.next()) {
+        (Some(Ok(path)), Some(Ok(AnyJsCallArgument::AnyJsExpression(base))), None) => {
+            is_url
+                && is_string_argument(&path)
+                && match base {
+                    AnyJsExpression::JsStaticMemberExpression(member) => {
+                        is_import_meta_member(&member, &["url", "dirname"])
+                    }
+                    AnyJsExpression::JsCallExpression(call) => is_import_meta_resolve(&call),
+                    _ => false,
+                }
+        }
+        _ => false,
+    }
+}
+
+/// Returns `true` for `import.meta.resolve(path)`, where `path` is a string.
+fn is_import_meta_resolve(call: &JsCallExpression) -> bool {
+    let is_resolve = call.callee().is_ok_and(|callee| match callee {
+        AnyJsExpression::JsStaticMemberExpression(member) => {
+            is_import_meta_member(&member, &["resolve"])
+        }
+        _ => false,
+    });
+    let Ok(arguments) = call.arguments() else {
+        return false;
+    };
+    let args = arguments.args();
+    is_resolve
+        && args.len() == 1
+        && args
+            .first()
+            .is_some_and(|path| path.is_ok_and(|path| is_string_argument(&path)))
+}
+
+fn is_import_meta_member(member: &JsStaticMemberExpression, names: &[&str]) -> bool {
+    !member.is_optional()
+        && matches!(
+            member.object(),
+            Ok(AnyJsExpression::JsImportMetaExpression(_))
+        )
+        && member
+            .member()
+            .ok()
+            .and_then(|name| name.as_js_name().cloned())
+            .and_then(|name| name.value_token().ok())
+            .is_some_and(|token| names.contains(&token.text_trimmed()))
+}
+
+fn is_string_argument(argument: &AnyJsCallArgument) -> bool {
+    matches!(
+        argument,
+        AnyJsCallArgument::AnyJsExpression(
+            AnyJsExpression::AnyJsLiteralExpression(
+                AnyJsLiteralExpression::JsStringLiteralExpression(_)
+            ) | AnyJsExpression::JsTemplateExpression(_)
+        )
+    )
+}
@@ -1,5 +1,5 @@
 use crate::prelude::*;
-use biome_formatter::token::number::format_number_token;
+use crate::utils::FormatNumberLiteralToken;
 
 use crate::parentheses::{is_member_object, NeedsParentheses};
 use biome_js_syntax::JsNumberLiteralExpression;
@@ -15,7 +15,7 @@ impl FormatNodeRule<JsNumberLiteralExpression> for FormatJsNumberLiteralExpressi
         f: &mut JsFormatter,
     ) -> FormatResult<()> {
         let JsNumberLiteralExpressionFields { value_token } = node.as_fields();
-        format_number_token(&value_token?).fmt(f)
+        FormatNumberLiteralToken::new(&value_token?).fmt(f)
     }
 
     fn needs_parentheses(&self, item: &JsNumberLiteralExpression) -> bool {
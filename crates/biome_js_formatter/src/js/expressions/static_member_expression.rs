@@ -1,5 +1,6 @@
 use crate::prelude::*;
 
+use crate::js::expressions::new_expression::is_import_meta_argument_of_new_url;
 use crate::parentheses::NeedsParentheses;
 use crate::JsLabels;
 use biome_formatter::{format_args, write};
@@ -47,7 +48,15 @@ impl Format<JsFormatContext> for AnyJsStaticMemberLike {
                 .has_label(LabelId::of(JsLabels::MemberChain))
         };
 
-        let layout = self.layout(is_member_chain)?;
+        let layout = match self {
+            AnyJsStaticMemberLike::JsStaticMemberExpression(member)
+                if f.options().keep_import_meta_on_one_line()
+                    && is_import_meta_argument_of_new_url(member) =>
+            {
+                StaticMemberLikeLayout::NoBreak
+            }
+            _ => self.layout(is_member_chain)?,
+        };
 
         match layout {
             StaticMemberLikeLayout::NoBreak => {
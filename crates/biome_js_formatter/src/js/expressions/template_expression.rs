@@ -69,7 +69,10 @@ impl AnyJsTemplate {
     fn write_elements(&self, f: &mut JsFormatter) -> FormatResult<()> {
         match self {
             AnyJsTemplate::JsTemplateExpression(template) => {
-                let is_test_each_pattern = is_test_each_pattern(template);
+                // Aligning the table of a test `each` pattern changes the text of the template
+                let is_test_each_pattern =
+                    f.options().template_literal_indentation().is_normalize()
+                        && is_test_each_pattern(template);
                 let options = FormatJsTemplateElementListOptions {
                     is_test_each_pattern,
                 };
@@ -3,8 +3,10 @@ use biome_formatter::{format_args, write};
 
 use crate::parentheses::{unary_like_expression_needs_parentheses, NeedsParentheses};
 
-use biome_js_syntax::JsSyntaxNode;
 use biome_js_syntax::JsUnaryExpression;
+use biome_js_syntax::{
+    AnyJsExpression, AnyJsLiteralExpression, JsExpressionStatement, JsSyntaxNode, JsSyntaxToken,
+};
 use biome_js_syntax::{JsUnaryExpressionFields, JsUnaryOperator};
 use biome_rowan::match_ast;
 
@@ -22,6 +24,21 @@ impl FormatNodeRule<JsUnaryExpression> for FormatJsUnaryExpression {
         let operator_token = operator_token?;
         let argument = argument?;
 
+        if f.options().undefined_style().is_undefined()
+            && !f.comments().has_comments(argument.syntax())
+        {
+            if let Some(zero_token) = void_zero_value_argument(node) {
+                f.comments().mark_suppression_checked(argument.syntax());
+                return write!(
+                    f,
+                    [
+                        format_replaced(&operator_token, &text("undefined")),
+                        format_removed(&zero_token)
+                    ]
+                );
+            }
+        }
+
         write!(f, [operator_token.format()])?;
 
         let is_keyword_operator = matches!(
@@ -54,6 +71,23 @@ impl FormatNodeRule<JsUnaryExpression> for FormatJsUnaryExpression {
     }
 }
 
+/// Returns the token `0` if `node` is `void 0` used as a value, and not as a statement such as `void 0;`.
+fn void_zero_value_argument(node: &JsUnaryExpression) -> Option<JsSyntaxToken> {
+    if node.operator().ok()? != JsUnaryOperator::Void
+        || JsExpressionStatement::can_cast(node.syntax().parent()?.kind())
+    {
+        return None;
+    }
+    let AnyJsExpression::AnyJsLiteralExpression(AnyJsLiteralExpression::JsNumberLiteralExpression(
+        literal,
+    )) = node.argument().ok()?
+    else {
+        return None;
+    };
+    let value_token = literal.value_token().ok()?;
+    (value_token.text_trimmed() == "0").then_some(value_token)
+}
+
 impl NeedsParentheses for JsUnaryExpression {
     fn needs_parentheses_with_parent(&self, parent: &JsSyntaxNode) -> bool {
         match_ast! {
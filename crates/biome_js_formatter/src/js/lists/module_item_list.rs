@@ -1,5 +1,7 @@
 use crate::prelude::*;
-use biome_js_syntax::{AnyJsModuleItem, AnyJsStatement, JsModuleItemList};
+use crate::ts::declarations::declare_function_declaration::is_next_function_overload;
+use biome_formatter::{format_args, write};
+use biome_js_syntax::{AnyJsModuleItem, AnyJsStatement, JsModuleItemList, JsSyntaxNode};
 
 #[derive(Debug, Clone, Default)]
 pub(crate) struct FormatJsModuleItemList;
@@ -8,22 +10,66 @@ impl FormatRule<JsModuleItemList> for FormatJsModuleItemList {
     type Context = JsFormatContext;
 
     fn fmt(&self, node: &JsModuleItemList, f: &mut JsFormatter) -> FormatResult<()> {
+        let blank_lines_after_imports = f.options().blank_lines_after_imports();
         let mut join = f.join_nodes_with_hardline();
+        let mut follows_import = false;
+        let mut previous: Option<JsSyntaxNode> = None;
 
         for module_item in node {
-            match module_item {
+            match &module_item {
                 AnyJsModuleItem::AnyJsStatement(AnyJsStatement::JsEmptyStatement(empty)) => {
                     join.entry_no_separator(&empty.format());
                 }
-                _ => {
+                AnyJsModuleItem::JsImport(_) => {
+                    follows_import = true;
                     join.entry(
                         module_item.syntax(),
                         &format_or_verbatim(module_item.format()),
                     );
                 }
+                _ if previous.as_ref().is_some_and(|previous| {
+                    is_next_function_overload(previous, module_item.syntax())
+                }) =>
+                {
+                    join.entry_no_separator(&format_args![
+                        hard_line_break(),
+                        format_or_verbatim(module_item.format())
+                    ]);
+                }
+                _ => match blank_lines_after_imports {
+                    Some(blank_lines) if follows_import => {
+                        follows_import = false;
+                        join.entry_no_separator(&format_args![
+                            FormatBlankLines(blank_lines),
+                            format_or_verbatim(module_item.format())
+                        ]);
+                    }
+                    _ => {
+                        follows_import = false;
+                        join.entry(
+                            module_item.syntax(),
+                            &format_or_verbatim(module_item.format()),
+                        );
+                    }
+                },
             }
+            previous = Some(module_item.into_syntax());
         }
 
         join.finish()
     }
 }
+
+/// Separates two module items with exactly this number of blank lines, up to 2.
+struct FormatBlankLines(u8);
+
+impl Format<JsFormatContext> for FormatBlankLines {
+    fn fmt(&self, f: &mut JsFormatter) -> FormatResult<()> {
+        match self.0 {
+            0 => write!(f, [hard_line_break()]),
+            1 => write!(f, [empty_line()]),
+            // The printer collapses consecutive empty lines
+            _ => write!(f, [empty_line(), text("\n")]),
+        }
+    }
+}
@@ -1,5 +1,7 @@
 use crate::prelude::*;
-use biome_js_syntax::{AnyJsStatement, JsStatementList};
+use crate::ts::declarations::declare_function_declaration::is_next_function_overload;
+use biome_formatter::format_args;
+use biome_js_syntax::{AnyJsStatement, JsStatementList, JsSyntaxNode};
 
 #[derive(Debug, Clone, Default)]
 pub(crate) struct FormatJsStatementList;
@@ -9,16 +11,27 @@ impl FormatRule<JsStatementList> for FormatJsStatementList {
 
     fn fmt(&self, node: &JsStatementList, f: &mut JsFormatter) -> FormatResult<()> {
         let mut join = f.join_nodes_with_hardline();
+        let mut previous: Option<JsSyntaxNode> = None;
 
         for statement in node.iter() {
-            match statement {
+            match &statement {
                 AnyJsStatement::JsEmptyStatement(empty) => {
                     join.entry_no_separator(&empty.format());
                 }
+                _ if previous.as_ref().is_some_and(|previous| {
+                    is_next_function_overload(previous, statement.syntax())
+                }) =>
+                {
+                    join.entry_no_separator(&format_args![
+                        hard_line_break(),
+                        format_or_verbatim(statement.format())
+                    ]);
+                }
                 _ => {
                     join.entry(statement.syntax(), &format_or_verbatim(statement.format()));
                 }
             }
+            previous = Some(statement.into_syntax());
         }
 
         join.finish()
@@ -49,7 +49,11 @@ pub(crate) enum AnyTemplateElementList {
 
 impl Format<JsFormatContext> for AnyTemplateElementList {
     fn fmt(&self, f: &mut Formatter<JsFormatContext>) -> FormatResult<()> {
-        let layout = if self.is_simple(f.comments()) {
+        let layout = if f.options().template_literal_indentation().is_preserve() {
+            TemplateElementLayout::SingleLine
+        } else if f.options().break_template_expressions() {
+            TemplateElementLayout::Break
+        } else if self.is_simple(f.comments()) {
             TemplateElementLayout::SingleLine
         } else {
             TemplateElementLayout::Fit
@@ -147,13 +151,19 @@ impl AnyTemplateElementList {
 
 #[derive(Debug, Copy, Clone, Default)]
 pub enum TemplateElementLayout {
-    /// Applied when all expressions are identifiers, `this`, static member expressions, or computed member expressions with number or string literals.
+    /// Applied when all expressions are identifiers, `this`, static member expressions, or computed member expressions with number or string literals,
+    /// and when the template literal indentation is preserved.
     /// Formats the expressions on a single line, even if their width otherwise would exceed the print width.
     SingleLine,
 
     /// Tries to format the expression on a single line but may break the expression if the line otherwise exceeds the print width.
     #[default]
     Fit,
+
+    /// Applied when `breakTemplateExpressions` is enabled.
+    /// Tries to format the expression on a single line but breaks after `${` and before `}` if the line otherwise exceeds the print width,
+    /// whatever the expression is.
+    Break,
 }
 
 declare_node_union! {
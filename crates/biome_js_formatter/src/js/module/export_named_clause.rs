@@ -30,7 +30,7 @@ impl FormatNodeRule<JsExportNamedClause> for FormatJsExportNamedClause {
                 f,
                 [format_dangling_comments(node.syntax()).with_block_indent()]
             )?;
-        } else {
+        } else if f.options().object_destructuring_spacing().is_always() {
             write!(
                 f,
                 [group(&format_args![
@@ -38,6 +38,8 @@ impl FormatNodeRule<JsExportNamedClause> for FormatJsExportNamedClause {
                     soft_line_break_or_space(),
                 ])]
             )?;
+        } else {
+            write!(f, [group(&soft_block_indent(&specifiers.format()))])?;
         }
 
         write!(
@@ -28,27 +28,37 @@ impl FormatNodeRule<JsExportNamedFromClause> for FormatJsExportNamedFromClause {
 
         write!(f, [l_curly_token.format(),])?;
 
+        let should_insert_space = f.options().object_destructuring_spacing().is_always();
+
         match specifiers.elements().next() {
             Some(AstSeparatedElement {
                 node: Ok(node),
                 trailing_separator: Ok(separator),
             }) if specifiers.len() == 1 && !f.comments().has_comments(node.syntax()) => {
-                write!(f, [space(), node.format()])?;
+                if should_insert_space {
+                    write!(f, [space()])?;
+                }
+
+                write!(f, [node.format()])?;
 
                 if let Some(separator) = separator {
                     write!(f, [format_removed(&separator)])?;
                 }
 
-                write!(f, [space()])?;
+                if should_insert_space {
+                    write!(f, [space()])?;
+                }
             }
             _ => {
                 if specifiers.syntax().has_leading_newline() {
                     write!(f, [block_indent(&specifiers.format()),])?;
-                } else {
+                } else if should_insert_space {
                     write!(
                         f,
                         [group(&soft_space_or_block_indent(&specifiers.format())),]
                     )?;
+                } else {
+                    write!(f, [group(&soft_block_indent(&specifiers.format())),])?;
                 };
             }
         }
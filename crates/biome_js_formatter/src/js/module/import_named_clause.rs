@@ -33,6 +33,15 @@ impl FormatNodeRule<JsImportNamedClause> for FormatJsImportNamedClause {
 
         let named_import = named_import?;
 
+        let should_insert_space = f.options().object_destructuring_spacing().is_always();
+        let curly_spacing = format_with(|f: &mut JsFormatter| {
+            if should_insert_space {
+                write!(f, [space()])
+            } else {
+                Ok(())
+            }
+        });
+
         // can_break implementation, return `format_element` instead of boolean to reduce enum conversion overhead.
         // if `can_break` is true we just use the previous format strategy, otherwise we use the new format strategy.
         // reference https://github.com/prettier/prettier/blob/5b113e71b1808d6916f446c3aa49c3c53e3bdb98/src/language-js/print/module.js#L173
@@ -72,13 +81,16 @@ impl FormatNodeRule<JsImportNamedClause> for FormatJsImportNamedClause {
                                     specifiers: _,
                                     r_curly_token,
                                 } = specifiers.as_fields();
-                                write!(f, [l_curly_token.format(), space(), specifier.format(),])?;
+                                write!(
+                                    f,
+                                    [l_curly_token.format(), curly_spacing, specifier.format(),]
+                                )?;
 
                                 if let Some(separator) = separator {
                                     format_removed(separator).fmt(f)?;
                                 }
 
-                                write!(f, [space(), r_curly_token.format()])
+                                write!(f, [curly_spacing, r_curly_token.format()])
                             }
                         }
                         (
@@ -93,13 +105,16 @@ impl FormatNodeRule<JsImportNamedClause> for FormatJsImportNamedClause {
                                     specifiers: _,
                                     r_curly_token,
                                 } = specifiers.as_fields();
-                                write!(f, [l_curly_token.format(), space(), specifier.format(),])?;
+                                write!(
+                                    f,
+                                    [l_curly_token.format(), curly_spacing, specifier.format(),]
+                                )?;
 
                                 if let Some(separator) = separator {
                                     format_removed(separator).fmt(f)?;
                                 }
 
-                                write!(f, [space(), r_curly_token.format()])
+                                write!(f, [curly_spacing, r_curly_token.format()])
                             }
                         }
                         _ => write![f, [named_import.format()]],
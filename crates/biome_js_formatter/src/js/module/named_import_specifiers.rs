@@ -22,11 +22,13 @@ impl FormatNodeRule<JsNamedImportSpecifiers> for FormatJsNamedImportSpecifiers {
                 f,
                 [format_dangling_comments(node.syntax()).with_soft_block_indent()]
             )?;
-        } else {
+        } else if f.options().object_destructuring_spacing().is_always() {
             write!(
                 f,
                 [group(&soft_space_or_block_indent(&specifiers.format()))]
             )?;
+        } else {
+            write!(f, [group(&soft_block_indent(&specifiers.format()))])?;
         }
 
         write!(f, [r_curly_token.format()])
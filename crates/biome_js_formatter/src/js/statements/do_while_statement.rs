@@ -32,7 +32,9 @@ impl FormatNodeRule<JsDoWhileStatement> for FormatJsDoWhileStatement {
             ])]
         )?;
 
-        if matches!(body, AnyJsStatement::JsBlockStatement(_)) {
+        if matches!(body, AnyJsStatement::JsBlockStatement(_))
+            && f.options().do_while_style().is_same_line()
+        {
             write!(f, [space()])?;
         } else {
             write!(f, [hard_line_break()])?;
@@ -50,7 +50,8 @@ impl FormatNodeRule<JsIfStatement> for FormatJsIfStatement {
                 .iter()
                 .any(|comment| comment.kind().is_line());
 
-            let else_on_same_line = matches!(consequent, JsBlockStatement(_))
+            let else_on_same_line = f.options().else_on_same_line()
+                && matches!(consequent, JsBlockStatement(_))
                 && !trailing_line_comment
                 && !dangling_line_comment;
 
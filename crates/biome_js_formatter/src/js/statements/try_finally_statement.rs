@@ -1,6 +1,8 @@
 use crate::prelude::*;
 use biome_formatter::write;
 
+use crate::js::statements::try_statement::FormatClauseSeparator;
+
 use biome_js_syntax::JsTryFinallyStatement;
 use biome_js_syntax::JsTryFinallyStatementFields;
 
@@ -19,9 +21,9 @@ impl FormatNodeRule<JsTryFinallyStatement> for FormatJsTryFinallyStatement {
         write![f, [try_token.format(), space(), body.format()]]?;
 
         if let Some(catch_clause) = catch_clause {
-            write!(f, [space(), catch_clause.format()])?;
+            write!(f, [FormatClauseSeparator, catch_clause.format()])?;
         }
 
-        write!(f, [space(), finally_clause.format()])
+        write!(f, [FormatClauseSeparator, finally_clause.format()])
     }
 }
@@ -7,6 +7,20 @@ use biome_js_syntax::JsTryStatementFields;
 #[derive(Debug, Clone, Default)]
 pub(crate) struct FormatJsTryStatement;
 
+/// Separates a `catch` or a `finally` clause from the block that precedes it:
+/// a space, or a line break when the `else_on_same_line` option is disabled.
+pub(crate) struct FormatClauseSeparator;
+
+impl Format<JsFormatContext> for FormatClauseSeparator {
+    fn fmt(&self, f: &mut JsFormatter) -> FormatResult<()> {
+        if f.options().else_on_same_line() {
+            write!(f, [space()])
+        } else {
+            write!(f, [hard_line_break()])
+        }
+    }
+}
+
 impl FormatNodeRule<JsTryStatement> for FormatJsTryStatement {
     fn fmt_fields(&self, node: &JsTryStatement, f: &mut JsFormatter) -> FormatResult<()> {
         let JsTryStatementFields {
@@ -21,7 +35,7 @@ impl FormatNodeRule<JsTryStatement> for FormatJsTryStatement {
                 try_token.format(),
                 space(),
                 body.format(),
-                space(),
+                FormatClauseSeparator,
                 catch_clause.format(),
             ]
         ]
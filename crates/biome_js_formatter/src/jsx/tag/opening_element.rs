@@ -5,7 +5,7 @@ use biome_js_syntax::{
     AnyJsxAttribute, AnyJsxAttributeValue, AnyJsxElementName, JsSyntaxToken, JsxAttributeList,
     JsxOpeningElement, JsxSelfClosingElement, JsxString, TsTypeArguments,
 };
-use biome_rowan::{declare_node_union, SyntaxResult};
+use biome_rowan::{declare_node_union, Direction, SyntaxResult};
 
 #[derive(Debug, Clone, Default)]
 pub struct FormatJsxOpeningElement;
@@ -70,6 +70,16 @@ impl Format<JsFormatContext> for AnyJsxOpeningElement {
                 )
             }
             OpeningElementLayout::IndentAttributes { name_has_comments } => {
+                let first_attribute_column =
+                    if f.options().jsx_attribute_alignment().is_first_attr()
+                        && !name_has_comments
+                        && !attributes.is_empty()
+                    {
+                        self.first_attribute_column()
+                    } else {
+                        None
+                    };
+
                 let format_inner = format_with(|f| {
                     write!(
                         f,
@@ -77,10 +87,18 @@ impl Format<JsFormatContext> for AnyJsxOpeningElement {
                             l_angle_token.format(),
                             name.format(),
                             type_arguments.format(),
-                            soft_line_indent_or_space(&attributes.format()),
                         ]
                     )?;
 
+                    match first_attribute_column {
+                        Some(column) => {
+                            write!(f, [space(), align(column, &attributes.format())])?;
+                        }
+                        None => {
+                            write!(f, [soft_line_indent_or_space(&attributes.format())])?;
+                        }
+                    }
+
                     let bracket_same_line = attributes.is_empty() && !name_has_comments;
 
                     if self.is_self_closing() {
@@ -145,6 +163,28 @@ impl AnyJsxOpeningElement {
         matches!(self, AnyJsxOpeningElement::JsxSelfClosingElement(_))
     }
 
+    /// Returns the column of the first attribute, relative to the `<` token,
+    /// when the attributes are aligned under the first attribute.
+    ///
+    /// Returns [None] if the element has type arguments, because their formatted width isn't known
+    /// before printing, or if the column exceeds the supported alignment.
+    fn first_attribute_column(&self) -> Option<u8> {
+        if self.type_arguments().is_some() {
+            return None;
+        }
+
+        let name_width: usize = self
+            .name()
+            .ok()?
+            .syntax()
+            .descendants_tokens(Direction::Next)
+            .map(|token| token.text_trimmed().chars().count())
+            .sum();
+
+        // The `<` token, the name, and the space before the first attribute.
+        u8::try_from(name_width + 2).ok()
+    }
+
     fn compute_layout(&self, comments: &JsComments) -> SyntaxResult<OpeningElementLayout> {
         let attributes = self.attributes();
         let name = self.name()?;
@@ -199,6 +239,16 @@ enum OpeningElementLayout {
     ///   moreAttributes={withSomeExpression}
     /// ></div>;
     /// ```
+    ///
+    /// With the `firstAttr` attribute alignment, the first attribute stays on the line of the tag
+    /// and the other attributes are aligned under it.
+    ///
+    /// ```javascript
+    /// <div oneAttribute
+    ///      another="with value"
+    ///      moreAttributes={withSomeExpression}
+    /// ></div>;
+    /// ```
     IndentAttributes { name_has_comments: bool },
 }
 
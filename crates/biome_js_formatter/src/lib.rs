@@ -212,7 +212,10 @@ impl<T, C> AsFormat<C> for &T
 where
     T: AsFormat<C>,
 {
-    type Format<'a> = T::Format<'a> where Self: 'a;
+    type Format<'a>
+        = T::Format<'a>
+    where
+        Self: 'a;
 
     fn format(&self) -> Self::Format<'_> {
         AsFormat::format(&**self)
@@ -226,7 +229,10 @@ impl<T, C> AsFormat<C> for biome_rowan::SyntaxResult<T>
 where
     T: AsFormat<C>,
 {
-    type Format<'a> = biome_rowan::SyntaxResult<T::Format<'a>> where Self: 'a;
+    type Format<'a>
+        = biome_rowan::SyntaxResult<T::Format<'a>>
+    where
+        Self: 'a;
 
     fn format(&self) -> Self::Format<'_> {
         match self {
@@ -243,7 +249,10 @@ impl<T, C> AsFormat<C> for Option<T>
 where
     T: AsFormat<C>,
 {
-    type Format<'a> = Option<T::Format<'a>> where Self: 'a;
+    type Format<'a>
+        = Option<T::Format<'a>>
+    where
+        Self: 'a;
 
     fn format(&self) -> Self::Format<'_> {
         self.as_ref().map(|value| value.format())
@@ -490,7 +499,12 @@ impl FormatLanguage for JsFormatLanguage {
         root: &JsSyntaxNode,
         source_map: Option<TransformSourceMap>,
     ) -> Self::Context {
-        let comments = Comments::from_node(root, &JsCommentStyle, source_map.as_ref());
+        let style = JsCommentStyle::default()
+            .with_honor_prettier_ignore(self.options.honor_prettier_ignore())
+            .with_enforce_blank_lines_after_imports(
+                self.options.blank_lines_after_imports().is_some(),
+            );
+        let comments = Comments::from_node(root, &style, source_map.as_ref());
         JsFormatContext::new(self.options, comments).with_source_map(source_map)
     }
 }
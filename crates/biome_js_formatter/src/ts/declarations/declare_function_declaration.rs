@@ -3,7 +3,11 @@ use crate::utils::FormatStatementSemicolon;
 
 use crate::js::declarations::function_declaration::FormatFunction;
 use biome_formatter::write;
-use biome_js_syntax::TsDeclareFunctionDeclaration;
+use biome_js_syntax::{
+    AnyJsDeclarationClause, AnyJsExportClause, JsExport, JsSyntaxNode,
+    TsDeclareFunctionDeclaration, TsDeclareStatement,
+};
+use biome_rowan::{AstNode, TokenText};
 
 #[derive(Debug, Clone, Default)]
 pub struct FormatTsDeclareFunctionDeclaration;
@@ -23,3 +27,41 @@ impl FormatNodeRule<TsDeclareFunctionDeclaration> for FormatTsDeclareFunctionDec
         )
     }
 }
+
+/// Returns `true` if `node` is an overload signature or the implementation of the function
+/// whose overload signature is `previous`, such as the second statement of
+/// `function f(a: string): void; function f(a) {}`.
+///
+/// The overload signatures of a function must directly precede its implementation,
+/// so the lists of statements never separate them with a blank line.
+pub(crate) fn is_next_function_overload(previous: &JsSyntaxNode, node: &JsSyntaxNode) -> bool {
+    let Some((previous_name, true)) = function_overload_name(previous) else {
+        return false;
+    };
+    function_overload_name(node).is_some_and(|(name, _)| name == previous_name)
+}
+
+/// Returns the name of the function declared by `node`, and whether it's an overload signature,
+/// if `node` is a function declaration, possibly exported or in a `declare` statement.
+fn function_overload_name(node: &JsSyntaxNode) -> Option<(TokenText, bool)> {
+    let declaration = if let Some(export) = JsExport::cast_ref(node) {
+        match export.export_clause().ok()? {
+            AnyJsExportClause::AnyJsDeclarationClause(declaration) => declaration,
+            AnyJsExportClause::TsExportDeclareClause(clause) => clause.declaration().ok()?,
+            _ => return None,
+        }
+    } else if let Some(statement) = TsDeclareStatement::cast_ref(node) {
+        statement.declaration().ok()?
+    } else {
+        AnyJsDeclarationClause::cast_ref(node)?
+    };
+    let (id, is_signature) = match declaration {
+        AnyJsDeclarationClause::JsFunctionDeclaration(function) => (function.id().ok()?, false),
+        AnyJsDeclarationClause::TsDeclareFunctionDeclaration(function) => {
+            (function.id().ok()?, true)
+        }
+        _ => return None,
+    };
+    let name = id.as_js_identifier_binding()?.name_token().ok()?;
+    Some((name.token_text_trimmed(), is_signature))
+}
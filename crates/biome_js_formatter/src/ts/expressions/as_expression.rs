@@ -59,17 +59,12 @@ impl TsAsOrSatisfiesExpression {
 
 impl Format<JsFormatContext> for TsAsOrSatisfiesExpression {
     fn fmt(&self, f: &mut JsFormatter) -> FormatResult<()> {
-        let expression = self.expression();
-        let operation_token = self.operation_token()?;
-        let ty = self.ty()?;
-
+        let is_next_line = f.options().type_assertion_style().is_next_line();
         let format_inner = format_with(|f| {
-            write!(f, [expression.format(), space(), operation_token.format()])?;
-
-            if f.comments().has_leading_own_line_comment(ty.syntax()) {
-                write!(f, [indent(&format_args![hard_line_break(), &ty.format()])])
+            if is_next_line {
+                self.fmt_next_line(f)
             } else {
-                write!(f, [space(), ty.format()])
+                self.fmt_same_line(f)
             }
         });
 
@@ -87,6 +82,63 @@ impl Format<JsFormatContext> for TsAsOrSatisfiesExpression {
     }
 }
 
+impl TsAsOrSatisfiesExpression {
+    fn fmt_same_line(&self, f: &mut JsFormatter) -> FormatResult<()> {
+        let expression = self.expression();
+        let operation_token = self.operation_token()?;
+        let ty = self.ty()?;
+
+        write!(f, [expression.format(), space(), operation_token.format()])?;
+
+        if f.comments().has_leading_own_line_comment(ty.syntax()) {
+            write!(f, [indent(&format_args![hard_line_break(), &ty.format()])])
+        } else {
+            write!(f, [space(), ty.format()])
+        }
+    }
+
+    /// Formats the whole chain of `as` and `satisfies` expressions, such as `x as A satisfies B`,
+    /// with each type on its own line if the chain exceeds the line width.
+    ///
+    /// The operators stay at the end of the lines, because a line break before `as` or `satisfies`
+    /// ends the expression.
+    fn fmt_next_line(&self, f: &mut JsFormatter) -> FormatResult<()> {
+        // The chain from the outermost expression to the innermost one
+        let mut chain = vec![self.clone()];
+        let mut expression = self.expression()?;
+        while let Some(inner) = TsAsOrSatisfiesExpression::cast_ref(expression.syntax()) {
+            expression = inner.expression()?;
+            chain.push(inner);
+        }
+
+        let format_types = format_with(|f: &mut JsFormatter| {
+            for node in chain.iter().rev() {
+                let is_inner = node.syntax() != self.syntax();
+                if is_inner {
+                    // The formatting of a nested expression can't be suppressed on its own
+                    f.comments().mark_suppression_checked(node.syntax());
+                    write!(f, [format_leading_comments(node.syntax())])?;
+                }
+
+                let ty = node.ty()?;
+                write!(f, [space(), node.operation_token().format()])?;
+                if f.comments().has_leading_own_line_comment(ty.syntax()) {
+                    write!(f, [hard_line_break(), ty.format()])?;
+                } else {
+                    write!(f, [soft_line_break_or_space(), ty.format()])?;
+                }
+
+                if is_inner {
+                    write!(f, [format_trailing_comments(node.syntax())])?;
+                }
+            }
+            Ok(())
+        });
+
+        write!(f, [expression.format(), group(&indent(&format_types))])
+    }
+}
+
 impl NeedsParentheses for TsAsOrSatisfiesExpression {
     fn needs_parentheses_with_parent(&self, parent: &JsSyntaxNode) -> bool {
         match parent.kind() {
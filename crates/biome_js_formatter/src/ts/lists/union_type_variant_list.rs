@@ -34,8 +34,7 @@ use crate::ts::types::undefined_type::FormatTsUndefinedType;
 use crate::ts::types::union_type::FormatTsUnionType;
 use crate::ts::types::unknown_type::FormatTsUnknownType;
 use crate::ts::types::void_type::FormatTsVoidType;
-use crate::JsCommentStyle;
-use biome_formatter::{comments::CommentStyle, write, FormatRuleWithOptions};
+use biome_formatter::{write, FormatRuleWithOptions};
 use biome_js_syntax::{AnyTsType, JsLanguage, TsUnionType, TsUnionTypeVariantList};
 use biome_rowan::{AstSeparatedElement, AstSeparatedList};
 
@@ -191,7 +190,7 @@ fn is_type_suppressed(
         .chain(comments.dangling_comments(ty.syntax()));
 
     for comment in leading_dangling {
-        if JsCommentStyle::is_suppression(comment.piece().text()) {
+        if comments.is_suppression(comment) {
             return true;
         }
     }
@@ -201,7 +200,7 @@ fn is_type_suppressed(
         .iter()
         .take_while(|comment| comment.lines_before() == 0)
     {
-        if JsCommentStyle::is_suppression(comment.piece().text()) {
+        if comments.is_suppression(comment) {
             return true;
         }
     }
@@ -212,14 +211,14 @@ fn is_type_suppressed(
             .trailing_comments(&preceding_variant)
             .iter()
             .skip_while(|comment| comment.lines_before() == 0)
-            .any(|comment| JsCommentStyle::is_suppression(comment.piece().text()))
+            .any(|comment| comments.is_suppression(comment))
     }
     // If this is the first variant, then see if the union has a leading suppression comment.
     else if let Some(union) = list.parent::<TsUnionType>() {
         comments
             .leading_comments(union.syntax())
             .iter()
-            .any(|comment| JsCommentStyle::is_suppression(comment.piece().text()))
+            .any(|comment| comments.is_suppression(comment))
     } else {
         false
     }
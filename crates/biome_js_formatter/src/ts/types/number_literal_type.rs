@@ -1,5 +1,5 @@
 use crate::prelude::*;
-use biome_formatter::token::number::format_number_token;
+use crate::utils::FormatNumberLiteralToken;
 
 use crate::parentheses::NeedsParentheses;
 use biome_formatter::write;
@@ -16,7 +16,10 @@ impl FormatNodeRule<TsNumberLiteralType> for FormatTsNumberLiteralType {
         } = node.as_fields();
         write![
             f,
-            [minus_token.format(), format_number_token(&literal_token?)]
+            [
+                minus_token.format(),
+                FormatNumberLiteralToken::new(&literal_token?)
+            ]
         ]
     }
 
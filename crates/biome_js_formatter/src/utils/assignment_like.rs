@@ -3,19 +3,20 @@ use crate::js::expressions::arrow_function_expression::FormatJsArrowFunctionExpr
 use crate::prelude::*;
 use crate::utils::member_chain::is_member_call_chain;
 use crate::utils::object::write_member_name;
-use crate::utils::AnyJsBinaryLikeExpression;
+use crate::utils::{is_ladder_root, AnyJsBinaryLikeExpression};
 use biome_formatter::{format_args, write, CstFormatContext, FormatOptions, VecBuffer};
 use biome_js_syntax::AnyJsLiteralExpression;
 use biome_js_syntax::{
-    AnyJsAssignmentPattern, AnyJsBindingPattern, AnyJsCallArgument, AnyJsClassMemberName,
-    AnyJsExpression, AnyJsFunctionBody, AnyJsObjectAssignmentPatternMember,
+    AnyJsAssignmentPattern, AnyJsBinding, AnyJsBindingPattern, AnyJsCallArgument,
+    AnyJsClassMemberName, AnyJsExpression, AnyJsFunctionBody, AnyJsObjectAssignmentPatternMember,
     AnyJsObjectBindingPatternMember, AnyJsObjectMemberName, AnyJsTemplateElement, AnyTsType,
     AnyTsVariableAnnotation, JsAssignmentExpression, JsInitializerClause, JsLiteralMemberName,
     JsObjectAssignmentPattern, JsObjectAssignmentPatternProperty, JsObjectBindingPattern,
     JsPropertyClassMember, JsPropertyClassMemberFields, JsPropertyObjectMember, JsSyntaxKind,
-    JsVariableDeclarator, TsIdentifierBinding, TsInitializedPropertySignatureClassMember,
-    TsInitializedPropertySignatureClassMemberFields, TsPropertySignatureClassMember,
-    TsPropertySignatureClassMemberFields, TsTypeAliasDeclaration, TsTypeArguments,
+    JsSyntaxNode, JsVariableDeclarator, JsVariableKind, JsVariableStatement, TsIdentifierBinding,
+    TsInitializedPropertySignatureClassMember, TsInitializedPropertySignatureClassMemberFields,
+    TsPropertySignatureClassMember, TsPropertySignatureClassMemberFields, TsTypeAliasDeclaration,
+    TsTypeArguments,
 };
 use biome_rowan::{declare_node_union, AstNode, SyntaxNodeOptionExt, SyntaxResult};
 use std::iter;
@@ -519,6 +520,18 @@ impl AnyJsAssignmentLike {
             AnyJsAssignmentLike::JsVariableDeclarator(variable_declarator) => {
                 if let Some(initializer) = variable_declarator.initializer() {
                     let eq_token = initializer.eq_token()?;
+                    if f.options().align_consecutive_assignments() {
+                        let padding = consecutive_assignment_padding(variable_declarator);
+                        if padding > 0 {
+                            write!(
+                                f,
+                                [dynamic_text(
+                                    &" ".repeat(padding),
+                                    eq_token.text_trimmed_range().start()
+                                )]
+                            )?;
+                        }
+                    }
                     write!(f, [space(), eq_token.format()])?
                 }
                 Ok(())
@@ -691,10 +704,30 @@ impl AnyJsAssignmentLike {
             return Ok(AssignmentLikeLayout::BreakAfterOperator);
         }
 
+        // A chain of conditionals formatted as a ladder starts on its own line, so that all its tests align
+        if let Some(AnyJsExpression::JsConditionalExpression(conditional)) = &right_expression {
+            if is_ladder_root(conditional, f.options()) {
+                return Ok(AssignmentLikeLayout::BreakAfterOperator);
+            }
+        }
+
         if is_left_short {
             return Ok(AssignmentLikeLayout::NeverBreakAfterOperator);
         }
 
+        // A variable initialized with a string stays on one line, unless the user opted in to wrapping it
+        if matches!(self, AnyJsAssignmentLike::JsVariableDeclarator(_))
+            && f.options().wrap_long_strings().is_never()
+            && matches!(
+                right_expression,
+                Some(AnyJsExpression::AnyJsLiteralExpression(
+                    AnyJsLiteralExpression::JsStringLiteralExpression(_)
+                ))
+            )
+        {
+            return Ok(AssignmentLikeLayout::NeverBreakAfterOperator);
+        }
+
         // Before checking `BreakAfterOperator` layout, we need to unwrap the right expression from `JsUnaryExpression` or `TsNonNullAssertionExpression`
         // [Prettier applies]: https://github.com/prettier/prettier/blob/a043ac0d733c4d53f980aa73807a63fc914f23bd/src/language-js/print/assignment.js#L199-L211
         // Example:
@@ -1253,6 +1286,77 @@ pub(crate) struct WithAssignmentLayout<'a> {
     layout: Option<AssignmentLikeLayout>,
 }
 
+/// Returns the number of spaces to insert before the `=` of `declarator` to align it with the `=`
+/// of the consecutive variable statements around it.
+///
+/// A run of consecutive statements ends at a blank line, at a statement that isn't a `const`, `let`,
+/// or `var` statement with a single identifier and an initializer, or at a statement of another kind.
+fn consecutive_assignment_padding(declarator: &JsVariableDeclarator) -> usize {
+    let Some(statement) = declarator
+        .declaration()
+        .and_then(|declaration| declaration.syntax().parent())
+    else {
+        return 0;
+    };
+    let Some((kind, width)) = aligned_statement_width(&statement) else {
+        return 0;
+    };
+
+    let mut max_width = width;
+    let mut current = statement.clone();
+    while get_lines_before(&current) <= 1 {
+        let Some((previous_width, previous)) = current.prev_sibling().and_then(|previous| {
+            let (previous_kind, previous_width) = aligned_statement_width(&previous)?;
+            (previous_kind == kind).then_some((previous_width, previous))
+        }) else {
+            break;
+        };
+        max_width = max_width.max(previous_width);
+        current = previous;
+    }
+
+    let mut current = statement;
+    while let Some(next) = current.next_sibling() {
+        if get_lines_before(&next) > 1 {
+            break;
+        }
+        match aligned_statement_width(&next) {
+            Some((next_kind, next_width)) if next_kind == kind => {
+                max_width = max_width.max(next_width);
+                current = next;
+            }
+            _ => break,
+        }
+    }
+
+    max_width - width
+}
+
+/// Returns the kind and the width of the name of `node` if it's a `const`, `let`, or `var` statement
+/// that declares a single identifier with an initializer, such as `const a = 1;`.
+fn aligned_statement_width(node: &JsSyntaxNode) -> Option<(JsVariableKind, usize)> {
+    let declaration = JsVariableStatement::cast_ref(node)?.declaration().ok()?;
+    let kind = declaration.variable_kind().ok()?;
+    if kind == JsVariableKind::Using || declaration.await_token().is_some() {
+        return None;
+    }
+    let mut declarators = declaration.declarators().iter();
+    let declarator = declarators.next()?.ok()?;
+    if declarators.next().is_some()
+        || declarator.variable_annotation().is_some()
+        || declarator.initializer().is_none()
+    {
+        return None;
+    }
+    let AnyJsBindingPattern::AnyJsBinding(AnyJsBinding::JsIdentifierBinding(binding)) =
+        declarator.id().ok()?
+    else {
+        return None;
+    };
+    let name = binding.name_token().ok()?;
+    Some((kind, name.text_trimmed().chars().count()))
+}
+
 pub(crate) fn with_assignment_layout(
     expression: &AnyJsExpression,
     layout: Option<AssignmentLikeLayout>,
@@ -4,6 +4,7 @@ use biome_formatter::{
     FormatRuleWithOptions,
 };
 
+use crate::context::JsFormatOptions;
 use crate::{AsFormat, IntoFormat};
 use biome_js_syntax::{
     AnyJsExpression, AnyTsType, JsAssignmentExpression, JsCallExpression,
@@ -223,6 +224,34 @@ impl FormatRule<AnyJsConditional> for FormatJsAnyConditionalRule {
                 || has_block_comment(alternate.syntax())
         };
 
+        if jsx_chain.is_no_chain()
+            && f.options().nested_ternary_style().is_ladder()
+            && is_ladder_chain(conditional, &layout)
+        {
+            let format_ladder = FormatConditionalLadder {
+                conditional,
+                consequent: &consequent,
+                alternate: &alternate,
+            };
+
+            return if layout.is_root() {
+                // The test of the root is padded, so that it aligns with the tests after a `: `.
+                let format_ladder =
+                    format_with(|f| write!(f, [if_group_breaks(&text("  ")), format_ladder]));
+                let format_ladder = group(&format_ladder).should_expand(has_multiline_comment);
+
+                if should_extra_indent
+                    || self.is_parent_static_member_expression(conditional, &layout)
+                {
+                    write!(f, [group(&soft_block_indent(&format_ladder))])
+                } else {
+                    write!(f, [format_ladder])
+                }
+            } else {
+                write!(f, [format_ladder])
+            };
+        }
+
         if layout.is_nested_test() || should_extra_indent {
             group(&soft_block_indent(&grouped))
                 .should_expand(has_multiline_comment)
@@ -494,6 +523,117 @@ impl FormatJsAnyConditionalRule {
     }
 }
 
+/// Returns `true` if `conditional` is a [JsConditionalExpression] that is part of a chain of conditionals
+/// nested in the `alternate` of their parent, and if the head of this chain isn't nested in another conditional.
+///
+/// ```javascript
+/// a ? b : c ? d : e;
+/// ```
+fn is_ladder_chain(conditional: &AnyJsConditional, layout: &ConditionalLayout) -> bool {
+    let AnyJsConditional::JsConditionalExpression(conditional) = conditional else {
+        return false;
+    };
+
+    match layout {
+        ConditionalLayout::Root { .. } => matches!(
+            conditional.alternate(),
+            Ok(AnyJsExpression::JsConditionalExpression(_))
+        ),
+        ConditionalLayout::NestedAlternate { .. } => {
+            let mut current = conditional.clone();
+            while let Some(parent) = current.parent::<JsConditionalExpression>() {
+                let is_alternate = parent
+                    .alternate()
+                    .is_ok_and(|alternate| alternate.syntax() == current.syntax());
+
+                if !is_alternate {
+                    return false;
+                }
+                current = parent;
+            }
+            true
+        }
+        ConditionalLayout::NestedTest { .. } | ConditionalLayout::NestedConsequent { .. } => false,
+    }
+}
+
+/// Returns `true` if `conditional` is the head of a chain of conditionals that is formatted
+/// with the [NestedTernaryStyle::Ladder] style.
+///
+/// [NestedTernaryStyle::Ladder]: crate::context::NestedTernaryStyle::Ladder
+pub(crate) fn is_ladder_root(
+    conditional: &JsConditionalExpression,
+    options: &JsFormatOptions,
+) -> bool {
+    options.nested_ternary_style().is_ladder()
+        && matches!(
+            conditional.alternate(),
+            Ok(AnyJsExpression::JsConditionalExpression(_))
+        )
+        && !(options.source_type().variant().is_jsx() && is_jsx_conditional_chain(conditional))
+}
+
+/// Formats a conditional of a chain with the [NestedTernaryStyle::Ladder] style.
+///
+/// The test and the consequent of every conditional share a line, and the `:` of every alternate starts a new line.
+/// The `?` and the `:` aren't indented, so that the tests of the chain align.
+///
+/// ```javascript
+///   a ? b
+/// : c ? d
+/// : e;
+/// ```
+///
+/// [NestedTernaryStyle::Ladder]: crate::context::NestedTernaryStyle::Ladder
+struct FormatConditionalLadder<'a> {
+    conditional: &'a AnyJsConditional,
+    consequent: &'a ExpressionOrType,
+    alternate: &'a ExpressionOrType,
+}
+
+impl Format<JsFormatContext> for FormatConditionalLadder<'_> {
+    fn fmt(&self, f: &mut Formatter<JsFormatContext>) -> FormatResult<()> {
+        let AnyJsConditional::JsConditionalExpression(conditional) = self.conditional else {
+            return Ok(());
+        };
+
+        write!(
+            f,
+            [
+                conditional.test().format(),
+                space(),
+                conditional.question_mark_token().format(),
+                space()
+            ]
+        )?;
+
+        // Keep the parentheses of a nested consequent, whose `?` and `:` would be confused with the ones of the chain
+        if self.consequent.syntax().kind() == conditional.syntax().kind() {
+            write!(
+                f,
+                [text("("), group(&align(2, &self.consequent)), text(")")]
+            )?;
+        } else {
+            write!(f, [align(2, &self.consequent)])?;
+        }
+
+        write!(
+            f,
+            [
+                soft_line_break_or_space(),
+                conditional.colon_token().format(),
+                space()
+            ]
+        )?;
+
+        if self.alternate.syntax().kind() == conditional.syntax().kind() {
+            write!(f, [self.alternate])
+        } else {
+            write!(f, [align(2, &self.alternate)])
+        }
+    }
+}
+
 /// Formats the test conditional of a conditional expression.
 struct FormatConditionalTest<'a> {
     conditional: &'a AnyJsConditional,
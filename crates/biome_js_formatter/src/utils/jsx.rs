@@ -1,7 +1,6 @@
 use crate::context::QuoteStyle;
 use crate::prelude::*;
-use crate::JsCommentStyle;
-use biome_formatter::{comments::CommentStyle, format_args, write};
+use biome_formatter::{format_args, write};
 use biome_js_syntax::{
     AnyJsExpression, AnyJsLiteralExpression, AnyJsxChild, AnyJsxTag, JsComputedMemberExpression,
     JsStaticMemberExpression, JsSyntaxKind, JsxChildList, JsxExpressionChild, JsxTagExpression,
@@ -77,7 +76,7 @@ pub(crate) fn is_jsx_suppressed(tag: &AnyJsxTag, comments: &JsComments) -> bool
                 Some(child) if child.expression().is_none() => comments
                     .dangling_comments(child.syntax())
                     .iter()
-                    .any(|comment| JsCommentStyle::is_suppression(comment.piece().text())),
+                    .any(|comment| comments.is_suppression(comment)),
                 Some(_) | None => false,
             }
         }
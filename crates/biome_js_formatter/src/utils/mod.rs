@@ -2,6 +2,7 @@ pub(crate) mod array;
 mod assignment_like;
 mod binary_like_expression;
 mod conditional;
+mod number_utils;
 pub mod string_utils;
 
 pub(crate) mod format_class;
@@ -35,7 +36,8 @@ use biome_js_syntax::{
     AnyJsExpression, AnyJsStatement, JsCallExpression, JsInitializerClause, JsLanguage, Modifiers,
 };
 use biome_rowan::{AstNode, AstNodeList};
-pub(crate) use conditional::{AnyJsConditional, ConditionalJsxChain};
+pub(crate) use conditional::{is_ladder_root, AnyJsConditional, ConditionalJsxChain};
+pub(crate) use number_utils::FormatNumberLiteralToken;
 pub(crate) use object_like::JsObjectLike;
 pub(crate) use object_pattern_like::JsObjectPatternLike;
 pub(crate) use string_utils::*;
@@ -0,0 +1,250 @@
+use crate::context::{ExponentStyle, HexCase, NumericLiteralStyle, UnderscoreStyle};
+use crate::prelude::*;
+use biome_formatter::token::number::{format_number_token, format_trimmed_number};
+use biome_js_syntax::JsSyntaxToken;
+use std::borrow::Cow;
+
+/// Data structure of convenience to format number literals
+pub(crate) struct FormatNumberLiteralToken<'token> {
+    /// The current token
+    token: &'token JsSyntaxToken,
+}
+
+impl<'token> FormatNumberLiteralToken<'token> {
+    pub fn new(token: &'token JsSyntaxToken) -> Self {
+        Self { token }
+    }
+}
+
+impl Format<JsFormatContext> for FormatNumberLiteralToken<'_> {
+    fn fmt(&self, f: &mut JsFormatter) -> FormatResult<()> {
+        let style = f.options().numeric_literal_style();
+        if style.is_default() {
+            return format_number_token(self.token).fmt(f);
+        }
+
+        let cleaned = format_trimmed_number(self.token.text_trimmed());
+        let text = match normalize_number_literal(&cleaned, style) {
+            Some(normalized) => Cow::Owned(normalized),
+            None => cleaned,
+        };
+        format_replaced(
+            self.token,
+            &syntax_token_cow_slice(text, self.token, self.token.text_trimmed_range().start()),
+        )
+        .fmt(f)
+    }
+}
+
+/// Applies the [NumericLiteralStyle] to the lowercase text of a number literal.
+///
+/// Returns `None` if the text doesn't change.
+fn normalize_number_literal(text: &str, style: NumericLiteralStyle) -> Option<String> {
+    let normalized = match text.get(..2) {
+        Some(prefix @ ("0x" | "0o" | "0b")) => {
+            let mut digits = text[2..].to_string();
+            if style.underscore == UnderscoreStyle::Strip {
+                digits.retain(|c| c != '_');
+            }
+            if prefix == "0x" && style.hex_case == HexCase::Upper {
+                digits.make_ascii_uppercase();
+            }
+            std::format!("{prefix}{digits}")
+        }
+        // Legacy octal literals, such as `0755`, can't have separators or an exponent
+        Some(prefix) if prefix.starts_with('0') && prefix != "0." && prefix != "0e" => {
+            return None;
+        }
+        _ => {
+            let mut decimal = text.to_string();
+            if style.underscore != UnderscoreStyle::Preserve {
+                decimal.retain(|c| c != '_');
+            }
+            // Leave the literals with separators untouched, because they can't be kept at the same place
+            if !decimal.contains('_') {
+                if let Some(rewritten) = apply_exponent_style(&decimal, style.exponent) {
+                    decimal = rewritten;
+                }
+            }
+            if style.underscore == UnderscoreStyle::AddThousands {
+                decimal = add_thousands_separators(&decimal);
+            }
+            decimal
+        }
+    };
+
+    (normalized != text).then_some(normalized)
+}
+
+/// Returns the decimal literal `text`, without separators, with or without an exponent according to `exponent_style`.
+///
+/// Returns `None` if the text doesn't change.
+fn apply_exponent_style(text: &str, exponent_style: ExponentStyle) -> Option<String> {
+    let (mantissa, exponent) = match text.split_once('e') {
+        Some((mantissa, exponent)) => (mantissa, Some(exponent.parse::<i64>().ok()?)),
+        None => (text, None),
+    };
+    let (integer, fraction) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+
+    // The value is `digits * 10^power`, with `digits` without leading or trailing zeros
+    let digits = std::format!("{integer}{fraction}");
+    let digits = digits.trim_start_matches('0');
+    let trimmed_digits = digits.trim_end_matches('0');
+    if trimmed_digits.is_empty() {
+        return None;
+    }
+    let power = exponent.unwrap_or(0) - fraction.len() as i64
+        + (digits.len() - trimmed_digits.len()) as i64;
+    let digits = trimmed_digits;
+    // The power of ten of the first digit
+    let magnitude = digits.len() as i64 + power - 1;
+
+    match exponent_style {
+        ExponentStyle::Preserve => None,
+        // `Number.prototype.toString` prints the numbers from `1e21` and below `1e-6` with an exponent
+        ExponentStyle::Avoid if exponent.is_some() && (-6..21).contains(&magnitude) => {
+            Some(without_exponent(digits, power))
+        }
+        ExponentStyle::Prefer if exponent.is_none() => {
+            let with_exponent = with_exponent(digits, magnitude);
+            (with_exponent.len() < text.len()).then_some(with_exponent)
+        }
+        ExponentStyle::Avoid | ExponentStyle::Prefer => None,
+    }
+}
+
+/// Returns the text of `digits * 10^power` without an exponent, e.g. `1500` or `0.015`.
+fn without_exponent(digits: &str, power: i64) -> String {
+    if power >= 0 {
+        return std::format!("{digits}{}", "0".repeat(power as usize));
+    }
+    let integer_len = digits.len() as i64 + power;
+    if integer_len > 0 {
+        let (integer, fraction) = digits.split_at(integer_len as usize);
+        std::format!("{integer}.{fraction}")
+    } else {
+        std::format!("0.{}{digits}", "0".repeat(-integer_len as usize))
+    }
+}
+
+/// Returns the text of `digits` with a single digit before the dot, and the exponent `magnitude`, e.g. `1.5e3`.
+fn with_exponent(digits: &str, magnitude: i64) -> String {
+    let (first, rest) = digits.split_at(1);
+    if rest.is_empty() {
+        std::format!("{first}e{magnitude}")
+    } else {
+        std::format!("{first}.{rest}e{magnitude}")
+    }
+}
+
+/// Separates the thousands of the integer part of the decimal literal `text` if it's at least `10000`.
+fn add_thousands_separators(text: &str) -> String {
+    let integer_len = text
+        .find(|c: char| c == '.' || c == 'e')
+        .unwrap_or(text.len());
+    let (integer, rest) = text.split_at(integer_len);
+    if integer.len() < 5 {
+        return text.to_string();
+    }
+
+    let mut separated = String::with_capacity(text.len() + integer.len() / 3);
+    for (index, digit) in integer.chars().enumerate() {
+        if index > 0 && (integer.len() - index) % 3 == 0 {
+            separated.push('_');
+        }
+        separated.push(digit);
+    }
+    separated.push_str(rest);
+    separated
+}
+
+#[cfg(test)]
+mod tests {
+    use super::normalize_number_literal;
+    use crate::context::{ExponentStyle, HexCase, NumericLiteralStyle, UnderscoreStyle};
+
+    fn normalize(text: &str, style: NumericLiteralStyle) -> String {
+        normalize_number_literal(text, style).unwrap_or_else(|| text.to_string())
+    }
+
+    #[test]
+    fn strips_separators() {
+        let style = NumericLiteralStyle {
+            underscore: UnderscoreStyle::Strip,
+            ..NumericLiteralStyle::default()
+        };
+        assert_eq!(normalize("1_000_000", style), "1000000");
+        assert_eq!(normalize("0.000_1", style), "0.0001");
+        assert_eq!(normalize("0xff_ff", style), "0xffff");
+        assert_eq!(normalize("0b1010_1010", style), "0b10101010");
+    }
+
+    #[test]
+    fn adds_thousands_separators() {
+        let style = NumericLiteralStyle {
+            underscore: UnderscoreStyle::AddThousands,
+            ..NumericLiteralStyle::default()
+        };
+        assert_eq!(normalize("1000", style), "1000");
+        assert_eq!(normalize("10000", style), "10_000");
+        assert_eq!(normalize("1234567.891", style), "1_234_567.891");
+        assert_eq!(normalize("12_34_56", style), "123_456");
+        assert_eq!(normalize("123456e3", style), "123_456e3");
+        assert_eq!(normalize("0xffffff", style), "0xffffff");
+    }
+
+    #[test]
+    fn uppercases_hex_digits() {
+        let style = NumericLiteralStyle {
+            hex_case: HexCase::Upper,
+            ..NumericLiteralStyle::default()
+        };
+        assert_eq!(normalize("0xabcdef", style), "0xABCDEF");
+        assert_eq!(normalize("0o755", style), "0o755");
+        assert_eq!(normalize("1e3", style), "1e3");
+    }
+
+    #[test]
+    fn prefers_shorter_exponents() {
+        let style = NumericLiteralStyle {
+            exponent: ExponentStyle::Prefer,
+            ..NumericLiteralStyle::default()
+        };
+        assert_eq!(normalize("1000", style), "1e3");
+        assert_eq!(normalize("100", style), "100");
+        assert_eq!(normalize("1500000", style), "1.5e6");
+        assert_eq!(normalize("0.0001", style), "1e-4");
+        assert_eq!(normalize("0.0015", style), "0.0015");
+        assert_eq!(normalize("1.0", style), "1.0");
+        assert_eq!(normalize("0", style), "0");
+        assert_eq!(normalize("1_000_000", style), "1_000_000");
+    }
+
+    #[test]
+    fn avoids_exponents() {
+        let style = NumericLiteralStyle {
+            exponent: ExponentStyle::Avoid,
+            ..NumericLiteralStyle::default()
+        };
+        assert_eq!(normalize("1e3", style), "1000");
+        assert_eq!(normalize("1.5e3", style), "1500");
+        assert_eq!(normalize("15e-1", style), "1.5");
+        assert_eq!(normalize("1e-3", style), "0.001");
+        assert_eq!(normalize("0.5e-2", style), "0.005");
+        assert_eq!(normalize("1e21", style), "1e21");
+        assert_eq!(normalize("1e-6", style), "0.000001");
+        assert_eq!(normalize("1e-7", style), "1e-7");
+        assert_eq!(normalize("0e5", style), "0e5");
+    }
+
+    #[test]
+    fn keeps_legacy_octal_literals() {
+        let style = NumericLiteralStyle {
+            underscore: UnderscoreStyle::AddThousands,
+            exponent: ExponentStyle::Prefer,
+            ..NumericLiteralStyle::default()
+        };
+        assert_eq!(normalize("0755", style), "0755");
+        assert_eq!(normalize("0.5", style), "0.5");
+    }
+}
@@ -161,13 +161,13 @@ impl JsObjectPatternLike {
 
 impl Format<JsFormatContext> for JsObjectPatternLike {
     fn fmt(&self, f: &mut Formatter<JsFormatContext>) -> FormatResult<()> {
-        let format_properties = format_with(|f| {
-            write!(
-                f,
-                [soft_space_or_block_indent(&format_with(
-                    |f| self.write_properties(f)
-                ))]
-            )
+        let format_properties = format_with(|f: &mut JsFormatter| {
+            let properties = format_with(|f| self.write_properties(f));
+            if f.options().object_destructuring_spacing().is_always() {
+                write!(f, [soft_space_or_block_indent(&properties)])
+            } else {
+                write!(f, [soft_block_indent(&properties)])
+            }
         });
 
         write!(f, [self.l_curly_token().format()])?;
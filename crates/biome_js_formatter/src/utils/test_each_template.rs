@@ -1,7 +1,7 @@
 use crate::js::auxiliary::template_element::TemplateElementOptions;
 use crate::js::lists::template_element_list::{TemplateElementIndention, TemplateElementLayout};
 use crate::prelude::*;
-use biome_formatter::printer::Printer;
+use biome_formatter::printer::{Printer, PrinterOptions};
 use biome_formatter::{
     format_args, write, CstFormatContext, FormatOptions, RemoveSoftLinesBuffer, VecBuffer,
 };
@@ -256,7 +256,11 @@ impl EachTemplateTable {
                     let root = Document::from(vec_buffer.into_vec());
 
                     let range = element.range();
-                    let print_options = f.options().as_print_options();
+                    // The column isn't the end of the file, it must not get a trailing newline
+                    let print_options = PrinterOptions {
+                        trailing_newline: None,
+                        ..f.options().as_print_options()
+                    };
                     let printed = Printer::new(print_options).print(&root)?;
                     let text = printed.into_code();
 
@@ -2,7 +2,11 @@ use biome_formatter::{FormatContext, FormatResult, Formatted, IndentStyle, LineW
 use biome_formatter_test::TestFormatLanguage;
 use biome_js_formatter::context::trailing_comma::TrailingComma;
 use biome_js_formatter::context::{
-    ArrowParentheses, JsFormatContext, JsFormatOptions, QuoteProperties, QuoteStyle, Semicolons,
+    ArrowParentheses, DoWhileStyle, ExponentStyle, HexCase, JsFormatContext, JsFormatOptions,
+    JsxAttributeAlignment, LongCommentStyle, NestedTernaryStyle, NumericLiteralStyle,
+    ObjectDestructuringSpacing, QuoteProperties, QuoteStyle, Semicolons,
+    TemplateLiteralIndentation, TypeAssertionStyle, UndefinedStyle, UnderscoreStyle,
+    WrapLongStrings,
 };
 use biome_js_formatter::{format_node, format_range, JsFormatLanguage};
 use biome_js_parser::{parse, JsParserOptions};
@@ -168,6 +172,213 @@ impl From<JsSerializableArrowParentheses> for ArrowParentheses {
     }
 }
 
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Deserialize, Serialize)]
+pub enum JsSerializableTemplateLiteralIndentation {
+    Normalize,
+    Preserve,
+}
+
+impl From<JsSerializableTemplateLiteralIndentation> for TemplateLiteralIndentation {
+    fn from(test: JsSerializableTemplateLiteralIndentation) -> Self {
+        match test {
+            JsSerializableTemplateLiteralIndentation::Normalize => {
+                TemplateLiteralIndentation::Normalize
+            }
+            JsSerializableTemplateLiteralIndentation::Preserve => {
+                TemplateLiteralIndentation::Preserve
+            }
+        }
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Deserialize, Serialize)]
+pub enum JsSerializableJsxAttributeAlignment {
+    Indent,
+    FirstAttr,
+}
+
+impl From<JsSerializableJsxAttributeAlignment> for JsxAttributeAlignment {
+    fn from(test: JsSerializableJsxAttributeAlignment) -> Self {
+        match test {
+            JsSerializableJsxAttributeAlignment::Indent => JsxAttributeAlignment::Indent,
+            JsSerializableJsxAttributeAlignment::FirstAttr => JsxAttributeAlignment::FirstAttr,
+        }
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Deserialize, Serialize)]
+pub enum JsSerializableWrapLongStrings {
+    Never,
+    Always,
+}
+
+impl From<JsSerializableWrapLongStrings> for WrapLongStrings {
+    fn from(test: JsSerializableWrapLongStrings) -> Self {
+        match test {
+            JsSerializableWrapLongStrings::Never => WrapLongStrings::Never,
+            JsSerializableWrapLongStrings::Always => WrapLongStrings::Always,
+        }
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Deserialize, Serialize)]
+pub enum JsSerializableLongCommentStyle {
+    Block,
+    Line,
+}
+
+impl From<JsSerializableLongCommentStyle> for LongCommentStyle {
+    fn from(test: JsSerializableLongCommentStyle) -> Self {
+        match test {
+            JsSerializableLongCommentStyle::Block => LongCommentStyle::Block,
+            JsSerializableLongCommentStyle::Line => LongCommentStyle::Line,
+        }
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Deserialize, Serialize)]
+pub enum JsSerializableObjectDestructuringSpacing {
+    Always,
+    Never,
+}
+
+impl From<JsSerializableObjectDestructuringSpacing> for ObjectDestructuringSpacing {
+    fn from(test: JsSerializableObjectDestructuringSpacing) -> Self {
+        match test {
+            JsSerializableObjectDestructuringSpacing::Always => ObjectDestructuringSpacing::Always,
+            JsSerializableObjectDestructuringSpacing::Never => ObjectDestructuringSpacing::Never,
+        }
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Deserialize, Serialize)]
+pub enum JsSerializableUndefinedStyle {
+    Preserve,
+    Undefined,
+    Void0,
+}
+
+impl From<JsSerializableUndefinedStyle> for UndefinedStyle {
+    fn from(test: JsSerializableUndefinedStyle) -> Self {
+        match test {
+            JsSerializableUndefinedStyle::Preserve => UndefinedStyle::Preserve,
+            JsSerializableUndefinedStyle::Undefined => UndefinedStyle::Undefined,
+            JsSerializableUndefinedStyle::Void0 => UndefinedStyle::Void0,
+        }
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Deserialize, Serialize)]
+pub enum JsSerializableNestedTernaryStyle {
+    Flat,
+    Ladder,
+}
+
+impl From<JsSerializableNestedTernaryStyle> for NestedTernaryStyle {
+    fn from(test: JsSerializableNestedTernaryStyle) -> Self {
+        match test {
+            JsSerializableNestedTernaryStyle::Flat => NestedTernaryStyle::Flat,
+            JsSerializableNestedTernaryStyle::Ladder => NestedTernaryStyle::Ladder,
+        }
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Deserialize, Serialize)]
+pub enum JsSerializableDoWhileStyle {
+    SameLine,
+    NewLine,
+}
+
+impl From<JsSerializableDoWhileStyle> for DoWhileStyle {
+    fn from(test: JsSerializableDoWhileStyle) -> Self {
+        match test {
+            JsSerializableDoWhileStyle::SameLine => DoWhileStyle::SameLine,
+            JsSerializableDoWhileStyle::NewLine => DoWhileStyle::NewLine,
+        }
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Deserialize, Serialize)]
+pub enum JsSerializableUnderscoreStyle {
+    Preserve,
+    Strip,
+    AddThousands,
+}
+
+impl From<JsSerializableUnderscoreStyle> for UnderscoreStyle {
+    fn from(test: JsSerializableUnderscoreStyle) -> Self {
+        match test {
+            JsSerializableUnderscoreStyle::Preserve => UnderscoreStyle::Preserve,
+            JsSerializableUnderscoreStyle::Strip => UnderscoreStyle::Strip,
+            JsSerializableUnderscoreStyle::AddThousands => UnderscoreStyle::AddThousands,
+        }
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Deserialize, Serialize)]
+pub enum JsSerializableHexCase {
+    Lower,
+    Upper,
+}
+
+impl From<JsSerializableHexCase> for HexCase {
+    fn from(test: JsSerializableHexCase) -> Self {
+        match test {
+            JsSerializableHexCase::Lower => HexCase::Lower,
+            JsSerializableHexCase::Upper => HexCase::Upper,
+        }
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Deserialize, Serialize)]
+pub enum JsSerializableExponentStyle {
+    Preserve,
+    Prefer,
+    Avoid,
+}
+
+impl From<JsSerializableExponentStyle> for ExponentStyle {
+    fn from(test: JsSerializableExponentStyle) -> Self {
+        match test {
+            JsSerializableExponentStyle::Preserve => ExponentStyle::Preserve,
+            JsSerializableExponentStyle::Prefer => ExponentStyle::Prefer,
+            JsSerializableExponentStyle::Avoid => ExponentStyle::Avoid,
+        }
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Deserialize, Serialize)]
+pub struct JsSerializableNumericLiteralStyle {
+    pub underscore: Option<JsSerializableUnderscoreStyle>,
+    pub hex_case: Option<JsSerializableHexCase>,
+    pub exponent: Option<JsSerializableExponentStyle>,
+}
+
+impl From<JsSerializableNumericLiteralStyle> for NumericLiteralStyle {
+    fn from(test: JsSerializableNumericLiteralStyle) -> Self {
+        NumericLiteralStyle {
+            underscore: test.underscore.map_or_else(Default::default, Into::into),
+            hex_case: test.hex_case.map_or_else(Default::default, Into::into),
+            exponent: test.exponent.map_or_else(Default::default, Into::into),
+        }
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Deserialize, Serialize)]
+pub enum JsSerializableTypeAssertionStyle {
+    SameLine,
+    NextLine,
+}
+
+impl From<JsSerializableTypeAssertionStyle> for TypeAssertionStyle {
+    fn from(test: JsSerializableTypeAssertionStyle) -> Self {
+        match test {
+            JsSerializableTypeAssertionStyle::SameLine => TypeAssertionStyle::SameLine,
+            JsSerializableTypeAssertionStyle::NextLine => TypeAssertionStyle::NextLine,
+        }
+    }
+}
+
 #[derive(Debug, Deserialize, Serialize, Clone, Copy)]
 pub struct JsSerializableFormatOptions {
     /// The indent style.
@@ -196,6 +407,57 @@ pub struct JsSerializableFormatOptions {
 
     /// Whether to add non-necessary parentheses to arrow functions. Defaults to "always".
     pub arrow_parentheses: Option<JsSerializableArrowParentheses>,
+
+    /// Whether the formatter may change the layout of template literals. Defaults to "normalize".
+    pub template_literal_indentation: Option<JsSerializableTemplateLiteralIndentation>,
+
+    /// How the attributes of a multiline JSX element are aligned. Defaults to "indent".
+    pub jsx_attribute_alignment: Option<JsSerializableJsxAttributeAlignment>,
+
+    /// Whether `// prettier-ignore` comments suppress the formatting of the following node. Defaults to `true`.
+    pub honor_prettier_ignore: Option<bool>,
+
+    /// Call expressions with this many arguments or fewer keep their arguments on a single line. Defaults to `0`.
+    pub function_call_arguments_on_one_line: Option<u8>,
+
+    /// Whether a variable initialized with a long string literal may break after the `=`. Defaults to "never".
+    pub wrap_long_strings: Option<JsSerializableWrapLongStrings>,
+
+    /// The style of the free-standing comments that span multiple lines. Defaults to "block".
+    pub long_comment_style: Option<JsSerializableLongCommentStyle>,
+
+    /// Whether to insert spaces inside the braces of object destructuring patterns, and of named imports and exports. Defaults to "always".
+    pub object_destructuring_spacing: Option<JsSerializableObjectDestructuringSpacing>,
+
+    /// The number of blank lines between the last import and the following statement. Defaults to keeping the blank lines of the source.
+    pub blank_lines_after_imports: Option<u8>,
+
+    /// Whether the `=` of consecutive variable declarations are aligned in the same column. Defaults to `false`.
+    pub align_consecutive_assignments: Option<bool>,
+
+    /// Whether `void 0` is replaced by `undefined`, or `undefined` by `void 0`. Defaults to "preserve".
+    pub undefined_style: Option<JsSerializableUndefinedStyle>,
+
+    /// How the chains of conditional expressions nested in the alternate are formatted when they break. Defaults to "flat".
+    pub nested_ternary_style: Option<JsSerializableNestedTernaryStyle>,
+
+    /// Whether `else`, `catch`, and `finally` are on the same line as the closing brace of the previous block. Defaults to true.
+    pub else_on_same_line: Option<bool>,
+
+    /// Whether the arguments of `new URL(...)` that use `import.meta` stay on one line. Defaults to true.
+    pub keep_import_meta_on_one_line: Option<bool>,
+
+    /// Whether the expressions of a template literal can break at their `${` and `}` when the template literal exceeds the line width. Defaults to false.
+    pub break_template_expressions: Option<bool>,
+
+    /// Whether the `while` of a `do...while` loop is on the same line as the closing brace of the body. Defaults to "sameLine".
+    pub do_while_style: Option<JsSerializableDoWhileStyle>,
+
+    /// How numeric literals are normalized.
+    pub numeric_literal_style: Option<JsSerializableNumericLiteralStyle>,
+
+    /// Whether the `as` and `satisfies` operators of a TypeScript expression that exceeds the line width stay on the same line as the expression. Defaults to "sameLine".
+    pub type_assertion_style: Option<JsSerializableTypeAssertionStyle>,
 }
 
 impl JsSerializableFormatOptions {
@@ -236,6 +498,57 @@ impl JsSerializableFormatOptions {
                 self.arrow_parentheses
                     .map_or_else(|| ArrowParentheses::Always, |value| value.into()),
             )
+            .with_template_literal_indentation(self.template_literal_indentation.map_or_else(
+                || TemplateLiteralIndentation::Normalize,
+                |value| value.into(),
+            ))
+            .with_jsx_attribute_alignment(
+                self.jsx_attribute_alignment
+                    .map_or_else(|| JsxAttributeAlignment::Indent, |value| value.into()),
+            )
+            .with_honor_prettier_ignore(self.honor_prettier_ignore.unwrap_or(true))
+            .with_function_call_arguments_on_one_line(
+                self.function_call_arguments_on_one_line.unwrap_or_default(),
+            )
+            .with_wrap_long_strings(
+                self.wrap_long_strings
+                    .map_or_else(|| WrapLongStrings::Always, |value| value.into()),
+            )
+            .with_long_comment_style(
+                self.long_comment_style
+                    .map_or_else(|| LongCommentStyle::Block, |value| value.into()),
+            )
+            .with_object_destructuring_spacing(
+                self.object_destructuring_spacing
+                    .map_or_else(|| ObjectDestructuringSpacing::Always, |value| value.into()),
+            )
+            .with_blank_lines_after_imports(self.blank_lines_after_imports)
+            .with_align_consecutive_assignments(
+                self.align_consecutive_assignments.unwrap_or_default(),
+            )
+            .with_undefined_style(
+                self.undefined_style
+                    .map_or_else(|| UndefinedStyle::Preserve, |value| value.into()),
+            )
+            .with_nested_ternary_style(
+                self.nested_ternary_style
+                    .map_or_else(|| NestedTernaryStyle::Flat, |value| value.into()),
+            )
+            .with_else_on_same_line(self.else_on_same_line.unwrap_or(true))
+            .with_keep_import_meta_on_one_line(self.keep_import_meta_on_one_line.unwrap_or(true))
+            .with_break_template_expressions(self.break_template_expressions.unwrap_or(false))
+            .with_do_while_style(
+                self.do_while_style
+                    .map_or_else(|| DoWhileStyle::SameLine, |value| value.into()),
+            )
+            .with_numeric_literal_style(
+                self.numeric_literal_style
+                    .map_or_else(NumericLiteralStyle::default, |value| value.into()),
+            )
+            .with_type_assertion_style(
+                self.type_assertion_style
+                    .map_or_else(|| TypeAssertionStyle::SameLine, |value| value.into()),
+            )
     }
 }
 
@@ -0,0 +1,35 @@
+const a = 1;
+const bbb = 2;
+const cc = 3;
+
+const reset = "after a blank line";
+const x = 4;
+let y = 5;
+let longer = 6;
+
+var first = 1;
+foo();
+var second = 2;
+
+// a comment doesn't end the run
+const short = 1;
+// comment
+const muchLonger = 2;
+
+const { destructured } = value;
+const notAligned = 1;
+const multiple = 1, declarators = 2;
+let uninitialized;
+const last = 3;
+
+function f() {
+	const i = 0;
+	const inner = 1;
+	if (i) {
+		let j = 2;
+		let nested = 3;
+	}
+}
+
+const calleeWithAVeryLongName = someFunctionWithAVeryLongName(argument1, argument2);
+const b2 = 2;
@@ -0,0 +1,5 @@
+const longStringVariableName = "this is a very long string literal that exceeds the line width";
+let message = !"this is a very long string literal that exceeds the line width too";
+
+someObject.someLongPropertyName = "this is a very long string literal that exceeds the width";
+const shortString = "short";
@@ -0,0 +1,12 @@
+import a from "a";
+import { b } from "b";
+
+
+
+const c = a + b;
+import d from "d";
+const e = d;
+
+import f from "f";
+;
+f();
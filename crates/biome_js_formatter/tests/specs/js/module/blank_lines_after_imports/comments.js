@@ -0,0 +1,27 @@
+import a from "a";
+
+// The comment is kept with the imports
+// and the blank lines are counted from it.
+const b = a;
+
+import c from "c";
+/* block comment */
+
+c();
+
+import d from "d"; // end of line comment
+d();
+
+import e from "e";
+
+/** Doc comments stay with the following declaration */
+function f() {}
+
+import g from "g";
+// biome-ignore format: the suppression stays with the statement
+const  h  =  g;
+
+import i from "i";
+// #region The region starts at the statement
+i();
+// #endregion
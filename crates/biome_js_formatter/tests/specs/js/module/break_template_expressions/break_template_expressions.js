@@ -0,0 +1,13 @@
+const message = `Hello ${firstName} ${lastName}, your order #${orderId} is ready to be shipped to you`;
+
+const fits = `Hello ${firstName} ${lastName}, your order #${orderId} is ready`;
+
+const conditional = `Hello ${user.isAdmin ? "administrator" : "regular user of the application"}, welcome back`;
+
+const ifStatement = `Status: ${(() => { if (order.isShipped) { return "shipped"; } return "pending"; })()} for order #${orderId}`;
+
+const call = `Hello ${formatName(user.firstName, user.lastName, options)}, your order #${formatOrderId(order)} is ready`;
+
+const nested = `Hello ${user.isAdmin ? `administrator ${user.firstName}` : `user ${user.lastName}`}, welcome back to the app`;
+
+const nestedCall = `Orders: ${orders.map((order) => `#${order.id} shipped to ${order.address.city}`).join(", ")}`;
@@ -0,0 +1,14 @@
+createUserAccount(firstNameOfTheNewUser, lastNameOfTheNewUser, emailAddressOfTheNewUser);
+
+createUserAccountWithRole(firstNameOfTheUser, lastNameOfTheUser, emailAddressOfTheUser, roleOfTheUser);
+
+const account = new UserAccount(firstNameOfTheUser, lastNameOfTheUser, emailAddressOfTheUser);
+
+registerCallback(eventNameForTheCallback, function handleTheEvent(event) { return event.target; });
+
+createUserAccount(
+	firstNameOfTheUser, // the first name
+	lastNameOfTheUser,
+);
+
+shortCall(a, b);
@@ -0,0 +1,30 @@
+function readFile(path) {
+	using file = openFile(path);
+	using   reader = file.reader(), writer = file.writer();
+	using veryLongResourceNameNumberOne = acquireResourceWithAVeryLongName(firstArgument, secondArgument);
+	return reader.read();
+}
+
+async function connect(url) {
+	await   using connection = await openConnection(url);
+	await using first = acquire(),
+		second = acquire();
+	await using veryLongConnectionName = await openConnectionWithAVeryLongName(url, options);
+	for (await using item of items) {
+		item.use();
+	}
+	for (using item of items) {
+		item.use();
+	}
+}
+
+{
+	using a = acquire();
+	await using b = acquire();
+
+	const c = 1;
+}
+
+// Not `using` declarations: destructuring patterns are not allowed
+using[index] = value;
+using [first, second] = pair;
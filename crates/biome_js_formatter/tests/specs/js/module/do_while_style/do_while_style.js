@@ -0,0 +1,28 @@
+do {
+	i++;
+} while (i < 10);
+
+do i++; while (i < 10)
+
+do {} while (waiting)
+
+do; while (true)
+
+do {
+	next = queue.shift();
+} while (next !== undefined && next.isPending() && retries++ < maxRetries && !controller.signal.aborted);
+
+do {
+	do {
+		j++;
+	} while (j < 5)
+} while (i++ < 5)
+
+do {
+	i++;
+} // trailing comment
+while (i < 10);
+
+outer: do {
+	continue outer;
+} while (shouldContinue());
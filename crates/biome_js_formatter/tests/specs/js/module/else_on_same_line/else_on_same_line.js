@@ -0,0 +1,50 @@
+if (a) {
+	b();
+} else {
+	c();
+}
+
+if (a) {
+	b();
+} else if (c) {
+	d();
+} else if (e) {
+	f();
+} else {
+	g();
+}
+
+if (a) b();
+else c();
+
+if (a) b();
+else if (c) {
+	d();
+} else e();
+
+if (a) {
+	b();
+} // comment
+else {
+	c();
+}
+
+try {
+	a();
+} catch (error) {
+	b(error);
+}
+
+try {
+	a();
+} finally {
+	b();
+}
+
+try {
+	a();
+} catch {
+	b();
+} finally {
+	c();
+}
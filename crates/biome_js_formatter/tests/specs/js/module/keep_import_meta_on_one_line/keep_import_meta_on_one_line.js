@@ -0,0 +1,16 @@
+const worker = new Worker(new URL("./workers/image-processing-worker.js", import.meta.url));
+const directory = new URL("./fixtures/very/deeply/nested/directory/", import.meta.dirname);
+const resolved = new URL("./assets/application-icon.png", import.meta.resolve("./public/"));
+const template = new URL(`./locales/${language}/translations.json`, import.meta.url).href;
+const resolvedHref = new URL("./assets/icons/application-icon.png", import.meta.resolve("./public/")).href;
+
+// Not `import.meta`
+const other = new URL("./workers/image-processing-worker.js", window.location.href, extra);
+
+// Comments
+const commented = new URL(
+	"./workers/image-processing-worker.js", // the worker
+	import.meta.url,
+);
+
+const short = new URL("./worker.js", import.meta.url);
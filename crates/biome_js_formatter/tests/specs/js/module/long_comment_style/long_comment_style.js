@@ -0,0 +1,32 @@
+/* Copyright (c) Biome contributors.
+   Licensed under the MIT license. */
+
+/*
+ * Decorated comment,
+ *   with an indented line.
+ */
+function a() {
+	/* A comment
+	   inside a function body
+
+	   with an empty line */
+	return 1;
+}
+
+/**
+ * JSDoc comments are kept.
+ * @param {string} b
+ */
+function b(b) {}
+
+const c = 1; /* trailing comment
+spanning multiple lines */
+
+const d = /* a comment
+that is followed by code */ 2;
+
+foo(/* inline
+block */ e);
+
+/* single line block */
+const f = 3;
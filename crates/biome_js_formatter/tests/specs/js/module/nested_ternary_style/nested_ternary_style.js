@@ -0,0 +1,27 @@
+const short = a ? b : c ? d : e;
+
+const animal = isBird ? "bird" : isCat ? "cat" : isDog ? "dog" : isFish ? "fish" : "unknown animal";
+
+isBird ? fly() : isCat ? meow("loudly", "and for a long time") : isDog ? bark("loudly") : swim();
+
+callback(isBird ? "bird" : isCat ? "cat" : isDog ? "dog" : isFish ? "fish" : "unknown animal");
+
+animal = isBird ? "bird" : isCat ? "cat" : isDog ? "dog" : isFish ? "fish" : "unknown animal";
+
+const value = {
+	animal: isBird ? "bird" : isCat ? "cat" : isDog ? "dog" : isFish ? "fish" : "unknown animal",
+};
+
+const result = (isBird ? birdObject : isCat ? catObject : isDog ? dogObject : fishObject).name;
+
+const longResult = (isBird ? birdObjectWithLongName : isCat ? catObjectWithLongName : dogObjectWithLongName).name;
+
+const consequent = isBird ? (canFly ? "flying bird" : "walking bird") : isCat ? "cat" : "unknown animal";
+
+const simple = isBirdWithAVeryLongConditionName ? "a very long bird value" : "a very long cat value";
+
+const comments = isBird // a bird
+	? "bird"
+	: isCat // a cat
+	? "cat"
+	: "dog";
@@ -0,0 +1,21 @@
+const integers = [0, 100, 1000, 10000, 1234567, 1_000_000, 12_34_56];
+const decimals = [0.5, .25, 1.50, 0.0001, 1234567.891, 0.000_1];
+const exponents = [1e3, 1.5E3, 15e-1, 1e-6, 1e-7, 1e21, 2E+10, 0e5];
+const radixes = [0xabcdef, 0XFF_FF, 0o755, 0O7_7, 0b1010_1010, 0B11];
+const legacyOctal = 0755;
+
+const negatives = [-1000, -1e3, -0xff, -1_000_000];
+
+// Number literals used as the object of a member access keep their parentheses
+(1e3).toFixed(2);
+(1000).toString();
+(0xff).toString(16);
+(1_000)[key];
+
+// Property names aren't number literals
+const object = { 1e3: a, 1_000: b, 0xff: c, [1e3]: d };
+object[1e3];
+class A {
+	1e3 = 1;
+	[10000] = 2;
+}
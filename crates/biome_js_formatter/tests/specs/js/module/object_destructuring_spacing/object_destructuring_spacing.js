@@ -0,0 +1,24 @@
+import { a, b } from "mod";
+import c, { d as e } from "mod";
+import {} from "mod";
+import { g } from "mod";
+import { h as i } from "mod";
+import { aVeryLongImportedName, anotherVeryLongImportedName, yetAnotherLongName } from "mod";
+
+const { foo } = obj;
+const { bar, baz: { qux } } = obj;
+const {} = obj;
+let { aVeryLongPropertyName, anotherVeryLongPropertyName, yetAnotherLongName } = obj;
+
+({ foo, bar } = obj);
+
+function f({ a, b = 1, ...rest }) {}
+const g = ({ a }) => a;
+
+export { a, b };
+export { e as f };
+export { x } from "mod";
+export { y, z } from "mod";
+export { aVeryLongExportedName, anotherVeryLongExportedName, yetAnotherLongName } from "mod";
+
+const object = { a, b };
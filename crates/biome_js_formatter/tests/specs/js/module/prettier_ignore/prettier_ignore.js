@@ -0,0 +1,29 @@
+// prettier-ignore
+const matrix = [
+  1, 0, 0,
+  0, 1, 0,
+  0, 0, 1,
+];
+
+/* prettier-ignore */
+const   spaced   =   1;
+
+const object = {
+  // prettier-ignore
+  key:   'value',
+  other:   'value',
+};
+
+// prettier-ignore-start
+const   notASuppression   =   1;
+
+// prettier-ignored
+const   notASuppressionEither   =   1;
+
+const element = (
+  <div>
+    {/* prettier-ignore */}
+    <span   a = "b" />
+    <span   a = "b" />
+  </div>
+);
@@ -0,0 +1,42 @@
+// biome-ignore-format-start
+const matrix = [
+  1,  0,  0,
+  0,  1,  0,
+  0,  0,  1,
+];
+const   spaced   =   true;
+// biome-ignore-format-end
+const   formatted   =   true;
+
+function  outside ( ) {
+	const   before   =   1;
+	// biome-ignore-format-start: aligned on purpose
+	const   x   =   1;
+	const   yy  =   2;
+}
+
+function  spanned ( ) {
+	return   "inside"
+}
+// biome-ignore-format-end
+const   after   =   1;
+
+// biome-ignore-format-start
+const   outer   =   1;
+// biome-ignore-format-start
+const   inner   =   2;
+// biome-ignore-format-end
+const   stillOuter   =   3;
+// biome-ignore-format-end
+const   notIgnored   =   4;
+
+const object = {
+	// biome-ignore-format-start
+	a:     1,
+	bb:    2,
+	// biome-ignore-format-end
+	ccc:     3,
+};
+
+// biome-ignore-format-end
+const   unmatchedEnd   =   5;
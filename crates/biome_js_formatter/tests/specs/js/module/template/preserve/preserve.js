@@ -0,0 +1,22 @@
+const message = `Hello
+  ${user.firstName} ${user.lastName},
+    you have ${notifications.filter((notification) => !notification.read).length} unread notifications
+`;
+
+const query = sql`
+  SELECT *
+  FROM users
+  WHERE id = ${userId} AND organization = ${organizations[currentOrganization].id}
+`;
+
+const nested = `outer ${condition ? `inner ${deeply.nested.value.that.is.long} text` : `other ${fallbackValueThatIsAlsoVeryLong}`} end`;
+
+const callback = `${items.map((item) => {
+  return item.name;
+})}`;
+
+describe.each`
+  a|b|expected
+  ${1}|${1}|${2}
+  ${11111}|${2222}|${5555}
+`("$a + $b", ({ a, b, expected }) => {});
@@ -0,0 +1,27 @@
+let a = void 0;
+let b = undefined;
+if (value === void 0 || value === undefined) {}
+function f(x = void 0, y = undefined) {
+	return void 0;
+}
+const g = () => void 0;
+call(undefined, void 0);
+
+// Kept as they are
+void 0;
+void someExpression();
+const h = void sideEffect();
+const i = void 1;
+const j = void /* comment */ 0;
+undefined = 1;
+const { undefined: k } = object;
+const l = { undefined };
+
+// Parentheses
+(undefined).toString();
+undefined();
+new undefined();
+(undefined) ** 2;
+undefined + 2;
+typeof undefined;
+-undefined;
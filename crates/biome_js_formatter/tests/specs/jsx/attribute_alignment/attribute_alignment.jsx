@@ -0,0 +1,23 @@
+<div className="container" id="main-container" onClick={handleClick} data-testid="container">
+  content
+</div>;
+
+<input type="text" value={value} onChange={(event) => setValue(event.target.value)} disabled />;
+
+<Foo.Bar first="first attribute value" second="second attribute value" third />;
+
+<svg:rect width="100" height="100" fill="currentColor" stroke="black" strokeWidth={2} />;
+
+<div title="a single string attribute that is too long to fit on the line of the tag" />;
+
+<div className="short">content</div>;
+
+function App() {
+  return (
+    <Layout title="A layout title" description="A layout description" footer={<Footer />}>
+      <Button variant="primary" size="large" onClick={() => { submit(); close(); }}>
+        Submit
+      </Button>
+    </Layout>
+  );
+}
@@ -0,0 +1,5 @@
+<div
+  className={`card ${isActive ? "card--active" : "card--inactive"} ${isHighlighted ? "card--highlighted" : ""}`}
+  title={`Multi-line
+    title for ${user.firstName}`}
+/>;
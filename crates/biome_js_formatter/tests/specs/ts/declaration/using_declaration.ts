@@ -0,0 +1,12 @@
+function readFile(path: string) {
+	using file: Disposable = openFile(path);
+	using reader = file.reader() as Reader, writer = file.writer() satisfies Writer;
+	return reader.read();
+}
+
+async function connect(url: string) {
+	await using connection: AsyncDisposable = await openConnection<Connection>(url);
+	for (await using item of items as AsyncIterable<AsyncDisposable>) {
+		item.use();
+	}
+}
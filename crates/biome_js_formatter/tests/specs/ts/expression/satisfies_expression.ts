@@ -0,0 +1,9 @@
+const a = {x:1}   satisfies   Point;
+const b = value satisfies Base satisfies Derived;
+const c = (value satisfies Base) as unknown as Derived;
+const d = (value as unknown) satisfies Derived;
+const e = ((value satisfies A) satisfies B);
+const f = [1,2,3]satisfies number[];
+const g = {x:1}satisfies{x:number};
+call(value satisfies Type, other  satisfies   Other);
+const veryLongVariableNameForTesting = someFunctionCall(argument1, argument2) satisfies SomeVeryLongTypeName<WithGenerics>;
@@ -0,0 +1,30 @@
+function overloaded(a: string): string;
+
+function overloaded(a: number): number;
+
+
+function overloaded(a: any): any {
+	return a;
+}
+
+export function exported(a: string): void;
+
+export function exported(a: number): void;
+
+export function exported(a: any) {}
+
+declare function declared(a: string): void;
+
+declare function declared(a: number): void;
+
+function first(): void;
+
+function second(): void;
+
+function namespaced() {
+	function inner(a: string): void;
+
+	function inner(a: number): void;
+
+	function inner(a: any) {}
+}
@@ -0,0 +1,5 @@
+type Literals = 1e3 | -1_000_000 | 0xabc | 0.0001;
+enum Flags {
+	A = 0xff,
+	B = 1e6,
+}
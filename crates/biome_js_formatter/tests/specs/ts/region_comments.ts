@@ -0,0 +1,53 @@
+import { a } from "a";
+// #region Setup
+const setup = a();
+// #endregion
+
+//#region Helpers
+function helper() {
+	// #region body
+	run();
+	// #endregion body
+}
+//#endregion
+
+class Service {
+	// #region Fields
+	private readonly name: string;
+	// #endregion
+
+	// #region Methods
+	start() {}
+	// #endregion
+}
+
+const config = {
+	// #region Colors
+	primary: "red",
+	// #endregion
+	size: 1,
+};
+
+interface Options {
+	// #region Flags
+	verbose: boolean;
+	// #endregion
+}
+
+enum Kind {
+	// #region Values
+	A,
+	B,
+	// #endregion
+}
+
+switch (kind) {
+	// #region Cases
+	case Kind.A:
+		break;
+	// #endregion
+	default:
+}
+
+// #regional is a regular comment
+const x = 1;
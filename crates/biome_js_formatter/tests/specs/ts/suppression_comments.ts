@@ -0,0 +1,35 @@
+// @ts-expect-error
+
+const a: string = 1;
+
+function f() {
+	// @ts-ignore
+
+	return g();
+}
+
+// eslint-disable-next-line no-console
+
+console.log(a);
+
+// @ts-ignore: reason
+const b: string = 2;
+
+// @ts-expect-error
+
+// a regular comment
+const c: string = 3;
+
+// a regular comment
+
+const d = 4;
+
+// @ts-ignored is not a suppression comment
+
+const e = 5;
+
+class A {
+	// @ts-ignore
+
+	prop: string = 1;
+}
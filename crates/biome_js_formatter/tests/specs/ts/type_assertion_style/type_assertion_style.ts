@@ -0,0 +1,19 @@
+const short = value as Foo satisfies Bar;
+const reversed = value satisfies Bar as Foo;
+
+const configuration = someConfigurationLoader.loadedConfiguration as unknown as ApplicationConfiguration;
+
+const chained = someConfigurationLoader.loadedConfiguration as ApplicationConfiguration satisfies BaseConfiguration;
+
+const object = {
+	port: 80,
+	host: "localhost",
+} as const satisfies ServerConfiguration;
+
+const called = loadConfiguration(firstArgument, secondArgument, thirdArgument) as ApplicationConfiguration;
+
+const commented = someConfigurationLoader.loadedConfiguration as unknown /* cast */ as ApplicationConfiguration;
+
+(someConfigurationLoader.loadedConfiguration as ApplicationConfigurationWithLongName).start();
+
+const inCall = callSomething(someConfigurationLoader.loadedConfiguration as ApplicationConfiguration);
@@ -0,0 +1,3 @@
+<Component value={config satisfies Config} />;
+<Component value={(config satisfies Config) as Props} other={value   satisfies Base satisfies Derived} />;
+<div>{items satisfies Item[]}</div>;
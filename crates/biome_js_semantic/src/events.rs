@@ -2,13 +2,19 @@
 
 use biome_js_syntax::binding_ext::{AnyJsBindingDeclaration, AnyJsIdentifierBinding};
 use biome_js_syntax::{
-    AnyJsExportNamedSpecifier, AnyJsNamedImportSpecifier, AnyTsType, JsImportNamedClause,
+    inner_string_text, numbers::parse_js_number, AnyJsExportClause, AnyJsExportNamedSpecifier,
+    AnyJsExpression, AnyJsLiteralExpression, AnyJsNamedImportSpecifier, AnyJsObjectMemberName,
+    AnyTsType, JsBinaryOperator, JsBreakStatement, JsCallExpression, JsContinueStatement,
+    JsDecorator, JsExport, JsExportDefaultExpressionClause, JsExportFromClause,
+    JsExportNamedFromSpecifier, JsIdentifierExpression, JsImportNamedClause, JsLabeledStatement,
+    JsLiteralExportName, JsReferenceIdentifier, JsStaticMemberExpression, JsUnaryOperator,
+    TsEnumDeclaration, TsSatisfiesExpression,
 };
 use biome_js_syntax::{
     AnyJsIdentifierUsage, JsLanguage, JsSyntaxKind, JsSyntaxNode, JsSyntaxToken, TextRange,
     TsTypeParameterName,
 };
-use biome_rowan::{syntax::Preorder, AstNode, SyntaxNodeOptionExt, TokenText};
+use biome_rowan::{syntax::Preorder, AstNode, AstSeparatedList, SyntaxNodeOptionExt, TokenText};
 use rustc_hash::FxHashMap;
 use std::collections::VecDeque;
 use std::mem;
@@ -24,6 +30,8 @@ pub enum SemanticEvent {
     /// - Import bindings
     /// - Functions parameters
     /// - Type parameters
+    ///
+    /// The bindings declared in a `declare global {}` block are hoisted to the global scope.
     DeclarationFound {
         name_token: JsSyntaxToken,
         scope_id: usize,
@@ -97,12 +105,153 @@ pub enum SemanticEvent {
     /// Tracks where a symbol is exported.
     /// The range points to the binding that is being exported.
     Exported { range: TextRange },
+
+    /// Tracks a binding that is the default export of the module.
+    /// This is emitted after the [SemanticEvent::Exported] of the binding.
+    /// Generated for:
+    /// - Default declarations, such as `export default function f() {}`
+    /// - Default expressions that reference a binding, such as `export default a`
+    /// - Export specifiers named `default`, such as `export { a as default }`
+    ExportedDefault { range: TextRange },
+
+    /// Tracks the name under which a binding is exported.
+    /// This is emitted after the [SemanticEvent::Exported] of the binding.
+    /// Generated for:
+    /// - Exported declarations, such as `export const a = 0`
+    /// - Export specifiers, such as `export { a as b }`
+    /// - Default exports, which are exported under the name `default`
+    /// - Re-exports, such as `export { a as b } from "mod"` and `export * as ns from "mod"`
+    ExportedName {
+        /// The exported name
+        name: TokenText,
+        /// Range of the exported binding,
+        /// or range of the exported name for re-exports that don't have a local binding
+        range: TextRange,
+    },
+
+    /// Tracks an expression that is checked against a type with the `satisfies` operator.
+    /// The constraint does not change the type of the expression.
+    /// Generated for:
+    /// - `satisfies` expressions
+    TypeSatisfied {
+        /// Range of the expression being checked
+        expression_range: TextRange,
+        /// Range of the type that the expression satisfies
+        constraint_range: TextRange,
+    },
+
+    /// Tracks a reference that reads a type, rather than a value.
+    /// This is emitted in addition to the [SemanticEvent::Read] of the reference,
+    /// or to its [SemanticEvent::UnresolvedReference].
+    /// Generated for:
+    /// - References in a type position, such as `Foo` in `let x: Foo` or in `type T = Foo<Bar>`
+    ///
+    /// The references of `typeof x` type queries read a value, so they aren't included.
+    TypeReference {
+        /// Trimmed range of the reference
+        range: TextRange,
+    },
+
+    /// Tracks where a new symbol is created by the global `Symbol` object.
+    /// This is emitted after the [SemanticEvent::UnresolvedReference] of `Symbol`,
+    /// because a local binding named `Symbol` shadows the global object.
+    /// Generated for:
+    /// - `Symbol()` calls
+    /// - `Symbol.for()` calls
+    SymbolCreated {
+        /// Range of the call expression
+        range: TextRange,
+        /// Whether the symbol is shared through the global symbol registry with `Symbol.for()`
+        is_global: bool,
+    },
+
+    /// Tracks a decorator and the declaration it's applied to.
+    /// References inside the decorator expression are reported with the usual
+    /// [SemanticEvent::Read] events.
+    /// Generated for:
+    /// - Decorators of classes, class members, and parameters
+    DecoratorApplied {
+        /// Range of the decorator, including the `@`
+        decorator_range: TextRange,
+        /// Range of the decorated class, class member, or parameter
+        target_range: TextRange,
+        /// Whether the decorator is applied to a class
+        is_class: bool,
+        /// Whether the decorator is applied to a method or an accessor
+        is_method: bool,
+        /// Whether the decorator is applied to a parameter
+        is_parameter: bool,
+    },
+
+    /// Tracks a suspension point of an async function.
+    /// Generated for:
+    /// - `await` expressions in the body of an async function, method, or arrow function
+    AwaitExpression {
+        /// Range of the `await` expression
+        range: TextRange,
+        /// Scope of the nearest enclosing function, which is async
+        enclosing_async_scope_id: usize,
+    },
+
+    /// Tracks an access to a well-known symbol of the global `Symbol` object.
+    /// Like [SemanticEvent::SymbolCreated], this is emitted after the
+    /// [SemanticEvent::UnresolvedReference] of `Symbol`.
+    /// Generated for:
+    /// - Static member expressions such as `Symbol.iterator`
+    WellKnownSymbolAccess {
+        /// The accessed well-known symbol
+        symbol_name: WellKnownSymbol,
+        /// Range of the member expression
+        range: TextRange,
+    },
+
+    /// Tracks the value of a member of a TypeScript `const enum`,
+    /// when its initializer can be evaluated statically.
+    /// This is emitted when the `const enum` declaration ends.
+    /// Generated for:
+    /// - Members without an initializer, which are auto-incremented from the previous member
+    /// - Members initialized with a numeric or a string literal,
+    ///   or with an expression of literals and other members of `const enum`s
+    ConstEnumMember {
+        /// The name of the member
+        name_token: JsSyntaxToken,
+        /// The value of the member
+        value: ConstEnumValue,
+        /// Scope of the `const enum` declaration
+        scope_id: usize,
+    },
+
+    /// Tracks a label of a labeled statement.
+    /// Generated for:
+    /// - Labeled statements, such as `outer: for (;;) {}`
+    LabelDeclared {
+        /// Trimmed range of the label
+        range: TextRange,
+        /// The name of the label
+        label: TokenText,
+    },
+
+    /// Tracks a `break` or a `continue` statement that jumps to a label.
+    /// This is emitted after the [SemanticEvent::LabelDeclared] of the label.
+    /// Generated for:
+    /// - Labeled `break` and `continue` statements, such as `break outer`
+    ///
+    /// A jump to a label that isn't declared by an enclosing statement isn't tracked.
+    LabelUsed {
+        /// Trimmed range of the label of the labeled statement that is the target of the jump
+        label_range: TextRange,
+        /// Range of the `break` or `continue` statement
+        jump_range: TextRange,
+        /// Whether the jump is a `break` statement, rather than a `continue` statement
+        is_break: bool,
+    },
 }
 
 impl SemanticEvent {
     pub fn range(&self) -> TextRange {
         match self {
-            Self::DeclarationFound { name_token, .. } => name_token.text_range(),
+            Self::DeclarationFound { name_token, .. }
+            | Self::ConstEnumMember { name_token, .. } => name_token.text_range(),
             Self::ScopeStarted { range, .. }
             | Self::ScopeEnded { range, .. }
             | Self::Read { range, .. }
@@ -110,11 +259,157 @@ impl SemanticEvent {
             | Self::Write { range, .. }
             | Self::HoistedWrite { range, .. }
             | Self::UnresolvedReference { range, .. }
+            | Self::SymbolCreated { range, .. }
+            | Self::AwaitExpression { range, .. }
+            | Self::WellKnownSymbolAccess { range, .. }
+            | Self::ExportedName { range, .. }
+            | Self::TypeReference { range }
+            | Self::LabelDeclared { range, .. }
+            | Self::ExportedDefault { range }
             | Self::Exported { range } => *range,
+            Self::LabelUsed { jump_range, .. } => *jump_range,
+            Self::TypeSatisfied {
+                expression_range, ..
+            } => *expression_range,
+            Self::DecoratorApplied {
+                decorator_range, ..
+            } => *decorator_range,
+        }
+    }
+}
+
+/// The well-known symbols, exposed as static properties of the global `Symbol` object.
+///
+/// They let objects customize how the language treats them,
+/// for example `Symbol.iterator` makes an object iterable by `for...of` loops.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum WellKnownSymbol {
+    /// `Symbol.asyncIterator`
+    AsyncIterator,
+    /// `Symbol.hasInstance`
+    HasInstance,
+    /// `Symbol.isConcatSpreadable`
+    IsConcatSpreadable,
+    /// `Symbol.iterator`
+    Iterator,
+    /// `Symbol.match`
+    Match,
+    /// `Symbol.matchAll`
+    MatchAll,
+    /// `Symbol.replace`
+    Replace,
+    /// `Symbol.search`
+    Search,
+    /// `Symbol.species`
+    Species,
+    /// `Symbol.split`
+    Split,
+    /// `Symbol.toPrimitive`
+    ToPrimitive,
+    /// `Symbol.toStringTag`
+    ToStringTag,
+    /// `Symbol.unscopables`
+    Unscopables,
+}
+
+impl WellKnownSymbol {
+    /// Returns the well-known symbol exposed as the property `name` of `Symbol`.
+    pub fn from_property_name(name: &str) -> Option<Self> {
+        Some(match name {
+            "asyncIterator" => Self::AsyncIterator,
+            "hasInstance" => Self::HasInstance,
+            "isConcatSpreadable" => Self::IsConcatSpreadable,
+            "iterator" => Self::Iterator,
+            "match" => Self::Match,
+            "matchAll" => Self::MatchAll,
+            "replace" => Self::Replace,
+            "search" => Self::Search,
+            "species" => Self::Species,
+            "split" => Self::Split,
+            "toPrimitive" => Self::ToPrimitive,
+            "toStringTag" => Self::ToStringTag,
+            "unscopables" => Self::Unscopables,
+            _ => return None,
+        })
+    }
+
+    /// Returns the name of the property of `Symbol` that exposes this symbol.
+    pub const fn property_name(&self) -> &'static str {
+        match self {
+            Self::AsyncIterator => "asyncIterator",
+            Self::HasInstance => "hasInstance",
+            Self::IsConcatSpreadable => "isConcatSpreadable",
+            Self::Iterator => "iterator",
+            Self::Match => "match",
+            Self::MatchAll => "matchAll",
+            Self::Replace => "replace",
+            Self::Search => "search",
+            Self::Species => "species",
+            Self::Split => "split",
+            Self::ToPrimitive => "toPrimitive",
+            Self::ToStringTag => "toStringTag",
+            Self::Unscopables => "unscopables",
         }
     }
 }
 
+/// The value of a member of a TypeScript `const enum`, which the compiler inlines.
+#[derive(Clone, Debug)]
+pub enum ConstEnumValue {
+    /// A number without a fractional part, such as `42`
+    Integer(i64),
+    /// Any other number, such as `0.5`
+    Float(f64),
+    /// A string, such as `"foo"`
+    String(String),
+}
+
+impl ConstEnumValue {
+    fn from_f64(value: f64) -> Self {
+        // Integers up to 2^53 are exactly representable by a JavaScript number.
+        // `-0` is kept as a float to preserve its sign.
+        let is_integer = value.fract() == 0.0
+            && value.abs() <= 9_007_199_254_740_992.0
+            && !(value == 0.0 && value.is_sign_negative());
+        if is_integer {
+            Self::Integer(value as i64)
+        } else {
+            Self::Float(value)
+        }
+    }
+
+    /// Returns the value as a number, or `None` if it's a string.
+    pub fn as_f64(&self) -> Option<f64> {
+        match self {
+            Self::Integer(value) => Some(*value as f64),
+            Self::Float(value) => Some(*value),
+            Self::String(_) => None,
+        }
+    }
+
+    /// Returns the value as a string, or `None` if it's a number.
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Self::String(value) => Some(value),
+            Self::Integer(_) | Self::Float(_) => None,
+        }
+    }
+}
+
+// Floats are compared by their bits, so that the equality is reflexive, even for `NaN`.
+impl PartialEq for ConstEnumValue {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Integer(left), Self::Integer(right)) => left == right,
+            (Self::Float(left), Self::Float(right)) => left.to_bits() == right.to_bits(),
+            (Self::String(left), Self::String(right)) => left == right,
+            _ => false,
+        }
+    }
+}
+
+impl Eq for ConstEnumValue {}
+
 /// Extracts [SemanticEvent] from [JsSyntaxNode].
 ///
 /// The extraction is not entirely pull based, nor entirely push based.
@@ -159,6 +454,19 @@ pub struct SemanticEventExtractor {
     bindings: FxHashMap<BindingName, TextRange>,
     /// Type parameters bound in a `infer T` clause.
     infers: Vec<TsTypeParameterName>,
+    /// Maps the range of a `Symbol` reference to the call that creates a symbol with it,
+    /// and whether the call is `Symbol.for()`.
+    symbol_calls: FxHashMap<TextRange, (TextRange, bool)>,
+    /// Maps the range of a `Symbol` reference to the member expression that accesses
+    /// a well-known symbol with it.
+    well_known_symbol_accesses: FxHashMap<TextRange, (TextRange, WellKnownSymbol)>,
+    /// Maps the range of a reference in an export clause to the name under which it's exported.
+    export_names: FxHashMap<TextRange, TokenText>,
+    /// Maps the range of the name of a `const enum` to the values of its members.
+    const_enums: FxHashMap<TextRange, FxHashMap<String, ConstEnumValue>>,
+    /// Stack of the labels of the labeled statements that contain the current node,
+    /// and the range of each label.
+    labels: Vec<(TokenText, TextRange)>,
 }
 
 /// A binding name is either a type or a value.
@@ -182,6 +490,12 @@ enum Reference {
     /// export { A }
     /// ```
     Export(TextRange),
+    /// Read and export a type, a value, or both as the default export
+    /// ```js
+    /// export default A
+    /// export { B as default }
+    /// ```
+    ExportDefault(TextRange),
     /// Read and export only a type
     /// ```ts
     /// export { type T1 }
@@ -208,13 +522,14 @@ impl Reference {
     }
 
     const fn is_export(&self) -> bool {
-        matches!(self, Self::Export { .. })
+        matches!(self, Self::Export { .. } | Self::ExportDefault { .. })
     }
 
     /// Range of the referenced binding
     const fn range(&self) -> &TextRange {
         match self {
             Self::Export(range)
+            | Self::ExportDefault(range)
             | Self::ExportType(range)
             | Self::Read(range)
             | Self::Write(range) => range,
@@ -239,6 +554,29 @@ struct Scope {
     shadowed: Vec<(BindingName, TextRange)>,
     /// If this scope allows declarations to be hoisted to parent scope or not
     hoisting: ScopeHoisting,
+    /// If this scope is the scope of a function, and whether the function is async
+    function: Option<FunctionKind>,
+}
+
+#[derive(Debug, Eq, PartialEq)]
+enum FunctionKind {
+    Async,
+    NotAsync,
+}
+
+impl FunctionKind {
+    fn of(node: &JsSyntaxNode) -> Self {
+        // Only functions, arrow functions, and methods can be async.
+        // Their `async` keyword is always a direct child.
+        let is_async = node
+            .children_with_tokens()
+            .any(|element| element.kind() == ASYNC_KW);
+        if is_async {
+            Self::Async
+        } else {
+            Self::NotAsync
+        }
+    }
 }
 
 impl SemanticEventExtractor {
@@ -249,6 +587,11 @@ impl SemanticEventExtractor {
             scope_count: 0,
             bindings: FxHashMap::default(),
             infers: vec![],
+            symbol_calls: FxHashMap::default(),
+            well_known_symbol_accesses: FxHashMap::default(),
+            export_names: FxHashMap::default(),
+            const_enums: FxHashMap::default(),
+            labels: vec![],
         }
     }
 
@@ -285,10 +628,19 @@ impl SemanticEventExtractor {
                     ScopeHoisting::DontHoistDeclarationsToParent,
                     true,
                 );
+                self.current_scope_mut().function = Some(FunctionKind::of(node));
             }
 
-            JS_FUNCTION_EXPORT_DEFAULT_DECLARATION
-            | JS_CLASS_DECLARATION
+            JS_FUNCTION_EXPORT_DEFAULT_DECLARATION => {
+                self.push_scope(
+                    node.text_range(),
+                    ScopeHoisting::DontHoistDeclarationsToParent,
+                    false,
+                );
+                self.current_scope_mut().function = Some(FunctionKind::of(node));
+            }
+
+            JS_CLASS_DECLARATION
             | JS_CLASS_EXPORT_DEFAULT_DECLARATION
             | JS_CLASS_EXPRESSION
             | JS_FUNCTION_BODY
@@ -316,6 +668,42 @@ impl SemanticEventExtractor {
                 );
             }
 
+            TS_SATISFIES_EXPRESSION => {
+                self.enter_satisfies_expression(&TsSatisfiesExpression::unwrap_cast(node.clone()));
+            }
+
+            JS_DECORATOR => {
+                self.enter_decorator(&JsDecorator::unwrap_cast(node.clone()));
+            }
+
+            JS_AWAIT_EXPRESSION => {
+                self.enter_await_expression(node);
+            }
+
+            JS_EXPORT_NAMED_FROM_SPECIFIER => {
+                self.enter_export_named_from_specifier(&JsExportNamedFromSpecifier::unwrap_cast(
+                    node.clone(),
+                ));
+            }
+
+            JS_EXPORT_FROM_CLAUSE => {
+                self.enter_export_from_clause(&JsExportFromClause::unwrap_cast(node.clone()));
+            }
+
+            JS_LABELED_STATEMENT => {
+                self.enter_labeled_statement(&JsLabeledStatement::unwrap_cast(node.clone()));
+            }
+
+            JS_BREAK_STATEMENT => {
+                let label = JsBreakStatement::unwrap_cast(node.clone()).label_token();
+                self.enter_jump_statement(node, label, true);
+            }
+
+            JS_CONTINUE_STATEMENT => {
+                let label = JsContinueStatement::unwrap_cast(node.clone()).label_token();
+                self.enter_jump_statement(node, label, false);
+            }
+
             _ => {
                 if let Some(node) = AnyTsType::cast_ref(node) {
                     self.enter_any_type(&node);
@@ -336,16 +724,157 @@ impl SemanticEventExtractor {
         }
     }
 
+    fn enter_satisfies_expression(&mut self, node: &TsSatisfiesExpression) -> Option<()> {
+        let expression = node.expression().ok()?;
+        let ty = node.ty().ok()?;
+        self.stash.push_back(SemanticEvent::TypeSatisfied {
+            expression_range: expression.syntax().text_trimmed_range(),
+            constraint_range: ty.syntax().text_trimmed_range(),
+        });
+        Some(())
+    }
+
+    fn enter_decorator(&mut self, node: &JsDecorator) -> Option<()> {
+        // The decorator is either in a decorator list, or in a list of modifiers
+        let mut target = node.syntax().grand_parent()?;
+        if let Some(export) = JsExport::cast_ref(&target) {
+            // `@decorator export class A {}`
+            target = export.export_clause().ok()?.into_syntax();
+        }
+        let kind = target.kind();
+        self.stash.push_back(SemanticEvent::DecoratorApplied {
+            decorator_range: node.syntax().text_trimmed_range(),
+            target_range: target.text_trimmed_range(),
+            is_class: matches!(
+                kind,
+                JS_CLASS_DECLARATION | JS_CLASS_EXPORT_DEFAULT_DECLARATION | JS_CLASS_EXPRESSION
+            ),
+            is_method: matches!(
+                kind,
+                JS_METHOD_CLASS_MEMBER
+                    | JS_GETTER_CLASS_MEMBER
+                    | JS_SETTER_CLASS_MEMBER
+                    | TS_METHOD_SIGNATURE_CLASS_MEMBER
+                    | TS_GETTER_SIGNATURE_CLASS_MEMBER
+                    | TS_SETTER_SIGNATURE_CLASS_MEMBER
+            ),
+            is_parameter: matches!(
+                kind,
+                JS_FORMAL_PARAMETER | JS_REST_PARAMETER | TS_PROPERTY_PARAMETER
+            ),
+        });
+        Some(())
+    }
+
+    fn enter_await_expression(&mut self, node: &JsSyntaxNode) {
+        // `await` suspends the nearest enclosing function, arrow functions included.
+        // A top-level `await` doesn't belong to any function.
+        let function_scope = self
+            .scopes
+            .iter()
+            .rev()
+            .find(|scope| scope.function.is_some());
+        if let Some(scope) = function_scope {
+            if scope.function == Some(FunctionKind::Async) {
+                self.stash.push_back(SemanticEvent::AwaitExpression {
+                    range: node.text_trimmed_range(),
+                    enclosing_async_scope_id: scope.scope_id,
+                });
+            }
+        }
+    }
+
+    fn enter_labeled_statement(&mut self, node: &JsLabeledStatement) -> Option<()> {
+        let token = node.label_token().ok()?;
+        let range = token.text_trimmed_range();
+        let label = token.token_text_trimmed();
+        self.stash.push_back(SemanticEvent::LabelDeclared {
+            range,
+            label: label.clone(),
+        });
+        self.labels.push((label, range));
+        Some(())
+    }
+
+    fn enter_jump_statement(
+        &mut self,
+        node: &JsSyntaxNode,
+        label: Option<JsSyntaxToken>,
+        is_break: bool,
+    ) {
+        let Some(label) = label else {
+            return;
+        };
+        // The innermost label with this name is the target of the jump.
+        let target = self
+            .labels
+            .iter()
+            .rev()
+            .find(|(name, _)| name.text() == label.text_trimmed());
+        if let Some((_, label_range)) = target {
+            self.stash.push_back(SemanticEvent::LabelUsed {
+                label_range: *label_range,
+                jump_range: node.text_trimmed_range(),
+                is_break,
+            });
+        }
+    }
+
+    fn enter_export_named_from_specifier(
+        &mut self,
+        node: &JsExportNamedFromSpecifier,
+    ) -> Option<()> {
+        let exported_name = match node.export_as() {
+            Some(export_as) => export_as.exported_name().ok()?,
+            None => node.source_name().ok()?,
+        };
+        self.push_reexported_name(&exported_name)
+    }
+
+    fn enter_export_from_clause(&mut self, node: &JsExportFromClause) -> Option<()> {
+        // `export * from "mod"` doesn't export any name of its own
+        let exported_name = node.export_as()?.exported_name().ok()?;
+        self.push_reexported_name(&exported_name)
+    }
+
+    fn push_reexported_name(&mut self, exported_name: &JsLiteralExportName) -> Option<()> {
+        let value_token = exported_name.value().ok()?;
+        self.stash.push_back(SemanticEvent::ExportedName {
+            name: inner_string_text(&value_token),
+            range: value_token.text_trimmed_range(),
+        });
+        Some(())
+    }
+
     fn enter_identifier_binding(&mut self, node: &AnyJsIdentifierBinding) -> Option<()> {
         let name_token = node.name_token().ok()?;
         let name = name_token.token_text_trimmed();
         let name_range = name_token.text_range();
         let mut hoisted_scope_id = None;
+        let mut exported_name = None;
+        let mut is_default_export = false;
         let is_exported = if let Some(declaration) = node.declaration() {
-            let is_exported = declaration.export().is_some();
+            let global_scope_id =
+                is_global_augmentation(&declaration).then(|| self.scopes[0].scope_id);
+            let export = declaration.export();
+            let is_exported = export.is_some();
+            if let Some(export) = export {
+                exported_name = match export.export_clause() {
+                    Ok(AnyJsExportClause::JsExportDefaultDeclarationClause(clause)) => {
+                        is_default_export = true;
+                        clause
+                            .default_token()
+                            .ok()
+                            .map(|token| token.token_text_trimmed())
+                    }
+                    _ => Some(name.clone()),
+                };
+            }
             match declaration {
                 AnyJsBindingDeclaration::JsVariableDeclarator(declarator) => {
-                    hoisted_scope_id = if declarator.declaration()?.is_var() {
+                    hoisted_scope_id = if global_scope_id.is_some() {
+                        global_scope_id
+                    } else if declarator.declaration()?.is_var() {
                         self.scope_index_to_hoist_declarations(0)
                     } else {
                         None
@@ -356,7 +885,8 @@ impl SemanticEventExtractor {
                 | AnyJsBindingDeclaration::TsDeclareFunctionExportDefaultDeclaration(_)
                 | AnyJsBindingDeclaration::JsFunctionDeclaration(_)
                 | AnyJsBindingDeclaration::JsFunctionExportDefaultDeclaration(_) => {
-                    hoisted_scope_id = self.scope_index_to_hoist_declarations(1);
+                    hoisted_scope_id =
+                        global_scope_id.or_else(|| self.scope_index_to_hoist_declarations(1));
                     self.push_binding(hoisted_scope_id, BindingName::Value(name), name_range);
                 }
                 AnyJsBindingDeclaration::JsClassExpression(_)
@@ -369,10 +899,11 @@ impl SemanticEventExtractor {
                 | AnyJsBindingDeclaration::TsEnumDeclaration(_) => {
                     // These declarations have their own scope.
                     // Thus we need to hoist the declaration to the parent scope.
-                    hoisted_scope_id = self
-                        .scopes
-                        .get(self.scopes.len() - 2)
-                        .map(|scope| scope.scope_id);
+                    hoisted_scope_id = global_scope_id.or_else(|| {
+                        self.scopes
+                            .get(self.scopes.len() - 2)
+                            .map(|scope| scope.scope_id)
+                    });
                     self.push_binding(
                         hoisted_scope_id,
                         BindingName::Value(name.clone()),
@@ -384,19 +915,21 @@ impl SemanticEventExtractor {
                 | AnyJsBindingDeclaration::TsTypeAliasDeclaration(_) => {
                     // These declarations have their own scope.
                     // Thus we need to hoist the declaration to the parent scope.
-                    hoisted_scope_id = self
-                        .scopes
-                        .get(self.scopes.len() - 2)
-                        .map(|scope| scope.scope_id);
+                    hoisted_scope_id = global_scope_id.or_else(|| {
+                        self.scopes
+                            .get(self.scopes.len() - 2)
+                            .map(|scope| scope.scope_id)
+                    });
                     self.push_binding(hoisted_scope_id, BindingName::Type(name), name_range);
                 }
                 AnyJsBindingDeclaration::TsModuleDeclaration(_) => {
                     // This declarations has its own scope.
                     // Thus we need to hoist the declaration to the parent scope.
-                    hoisted_scope_id = self
-                        .scopes
-                        .get(self.scopes.len() - 2)
-                        .map(|scope| scope.scope_id);
+                    hoisted_scope_id = global_scope_id.or_else(|| {
+                        self.scopes
+                            .get(self.scopes.len() - 2)
+                            .map(|scope| scope.scope_id)
+                    });
                     self.push_binding(
                         hoisted_scope_id,
                         BindingName::Value(name.clone()),
@@ -486,6 +1019,17 @@ impl SemanticEventExtractor {
             self.stash.push_back(SemanticEvent::Exported {
                 range: node.syntax().text_range(),
             });
+            if is_default_export {
+                self.stash.push_back(SemanticEvent::ExportedDefault {
+                    range: node.syntax().text_range(),
+                });
+            }
+            if let Some(name) = exported_name {
+                self.stash.push_back(SemanticEvent::ExportedName {
+                    name,
+                    range: node.syntax().text_range(),
+                });
+            }
         }
         Some(())
     }
@@ -499,21 +1043,44 @@ impl SemanticEventExtractor {
         match node {
             AnyJsIdentifierUsage::JsReferenceIdentifier(node) => {
                 if let Some(specifier) = node.parent::<AnyJsExportNamedSpecifier>() {
+                    let exported_name = export_specifier_name(&specifier);
+                    let reference = if exported_name
+                        .as_ref()
+                        .is_some_and(|exported_name| exported_name.text() == "default")
+                    {
+                        Reference::ExportDefault(range)
+                    } else {
+                        Reference::Export(range)
+                    };
+                    if let Some(exported_name) = exported_name {
+                        self.export_names.insert(range, exported_name);
+                    }
                     if specifier.exports_only_types() {
                         self.push_reference(BindingName::Type(name), Reference::ExportType(range));
                     } else {
-                        self.push_reference(
-                            BindingName::Value(name.clone()),
-                            Reference::Export(range),
-                        );
-                        self.push_reference(BindingName::Type(name), Reference::Export(range));
+                        self.push_reference(BindingName::Value(name.clone()), reference.clone());
+                        self.push_reference(BindingName::Type(name), reference);
                     }
                 } else if matches!(
                     node.syntax().grand_parent().kind(),
-                    Some(JS_EXPORT_DEFAULT_EXPRESSION_CLAUSE | TS_EXPORT_ASSIGNMENT_CLAUSE)
+                    Some(TS_EXPORT_ASSIGNMENT_CLAUSE)
                 ) {
                     self.push_reference(BindingName::Value(name.clone()), Reference::Export(range));
                     self.push_reference(BindingName::Type(name), Reference::Export(range));
+                } else if let Some(clause) = node
+                    .syntax()
+                    .grand_parent()
+                    .and_then(JsExportDefaultExpressionClause::cast)
+                {
+                    if let Ok(default_token) = clause.default_token() {
+                        self.export_names
+                            .insert(range, default_token.token_text_trimmed());
+                    }
+                    self.push_reference(
+                        BindingName::Value(name.clone()),
+                        Reference::ExportDefault(range),
+                    );
+                    self.push_reference(BindingName::Type(name), Reference::ExportDefault(range));
                 } else {
                     if name.text() == "this" {
                         // Ignore `this` in typeof position. e.g. `typeof this.prop`.
@@ -533,6 +1100,16 @@ impl SemanticEventExtractor {
                         Some(TS_IMPORT_TYPE_QUALIFIER) => return,
                         _ => BindingName::Value(name),
                     };
+                    if matches!(binding_name, BindingName::Type(_)) {
+                        self.stash.push_back(SemanticEvent::TypeReference {
+                            range: node.syntax().text_trimmed_range(),
+                        });
+                    }
+                    if let Some(symbol_call) = symbol_call(&node) {
+                        self.symbol_calls.insert(range, symbol_call);
+                    } else if let Some(access) = well_known_symbol_access(&node) {
+                        self.well_known_symbol_accesses.insert(range, access);
+                    }
                     self.push_reference(binding_name, Reference::Read(range));
                 }
             }
@@ -576,12 +1153,24 @@ impl SemanticEventExtractor {
             | TS_DECLARE_FUNCTION_DECLARATION
             | TS_DECLARE_FUNCTION_EXPORT_DEFAULT_DECLARATION
             | TS_INTERFACE_DECLARATION
-            | TS_ENUM_DECLARATION
             | TS_TYPE_ALIAS_DECLARATION
             | TS_MODULE_DECLARATION
             | TS_EXTERNAL_MODULE_DECLARATION => {
                 self.pop_scope(node.text_range());
             }
+            TS_ENUM_DECLARATION => {
+                self.leave_enum_declaration(&TsEnumDeclaration::unwrap_cast(node.clone()));
+                self.pop_scope(node.text_range());
+            }
+            JS_LABELED_STATEMENT => {
+                // Only the labeled statements with a label are pushed
+                if JsLabeledStatement::unwrap_cast(node.clone())
+                    .label_token()
+                    .is_ok()
+                {
+                    self.labels.pop();
+                }
+            }
             _ => {
                 if let Some(node) = AnyTsType::cast_ref(node) {
                     self.leave_any_type(&node);
@@ -590,6 +1179,61 @@ impl SemanticEventExtractor {
         }
     }
 
+    fn leave_enum_declaration(&mut self, node: &TsEnumDeclaration) -> Option<()> {
+        node.const_token()?;
+        // The name of the enum is declared in the parent scope, so it's still available
+        let enum_range = node
+            .id()
+            .ok()?
+            .as_js_identifier_binding()?
+            .name_token()
+            .ok()?
+            .text_range();
+        let scope_id = self.current_scope_mut().scope_id;
+        let mut values = FxHashMap::default();
+        // Without an initializer, a member has the value of the previous member plus one
+        let mut next_value = Some(ConstEnumValue::Integer(0));
+        for member in node.members().iter().flatten() {
+            let value = match member.initializer() {
+                Some(initializer) => initializer.expression().ok().and_then(|expression| {
+                    evaluate_const_enum_expression(&expression, &|object, name| {
+                        let members = match object {
+                            None => &values,
+                            Some(object) => {
+                                let range = self.bindings.get(&BindingName::Value(object))?;
+                                if *range == enum_range {
+                                    &values
+                                } else {
+                                    self.const_enums.get(range)?
+                                }
+                            }
+                        };
+                        members.get(name).cloned()
+                    })
+                }),
+                None => next_value,
+            };
+            next_value = value
+                .as_ref()
+                .and_then(ConstEnumValue::as_f64)
+                .map(|value| ConstEnumValue::from_f64(value + 1.0));
+            let Some(AnyJsObjectMemberName::JsLiteralMemberName(name)) = member.name().ok() else {
+                continue;
+            };
+            let (Some(value), Ok(name_token), Ok(name)) = (value, name.value(), name.name()) else {
+                continue;
+            };
+            values.insert(name.text().to_string(), value.clone());
+            self.stash.push_back(SemanticEvent::ConstEnumMember {
+                name_token,
+                value,
+                scope_id,
+            });
+        }
+        self.const_enums.insert(enum_range, values);
+        Some(())
+    }
+
     fn leave_any_type(&mut self, node: &AnyTsType) {
         if node.in_conditional_true_type() {
             self.pop_scope(node.syntax().text_range());
@@ -642,6 +1286,7 @@ impl SemanticEventExtractor {
             references: FxHashMap::default(),
             shadowed: vec![],
             hoisting,
+            function: None,
         });
     }
 
@@ -663,9 +1308,24 @@ impl SemanticEventExtractor {
                     let declaration_before_reference =
                         declared_at.start() < reference.range().start();
                     let event = match reference {
-                        Reference::Export(range) | Reference::ExportType(range) => {
+                        Reference::Export(range)
+                        | Reference::ExportDefault(range)
+                        | Reference::ExportType(range) => {
                             self.stash
                                 .push_back(SemanticEvent::Exported { range: declared_at });
+                            if matches!(reference, Reference::ExportDefault(_)) {
+                                self.stash.push_back(SemanticEvent::ExportedDefault {
+                                    range: declared_at,
+                                });
+                            }
+                            // A reference can be exported both as a type and as a value:
+                            // its name is reported once.
+                            if let Some(name) = self.export_names.remove(&range) {
+                                self.stash.push_back(SemanticEvent::ExportedName {
+                                    name,
+                                    range: declared_at,
+                                });
+                            }
                             if declaration_before_reference {
                                 SemanticEvent::Read {
                                     range,
@@ -730,6 +1390,16 @@ impl SemanticEventExtractor {
                         is_read: !reference.is_write(),
                         range: *reference.range(),
                     });
+                    if let Some((range, is_global)) = self.symbol_calls.remove(reference.range()) {
+                        self.stash
+                            .push_back(SemanticEvent::SymbolCreated { range, is_global });
+                    }
+                    if let Some((range, symbol_name)) =
+                        self.well_known_symbol_accesses.remove(reference.range())
+                    {
+                        self.stash
+                            .push_back(SemanticEvent::WellKnownSymbolAccess { symbol_name, range });
+                    }
                 }
             }
         }
@@ -831,6 +1501,225 @@ impl SemanticEventExtractor {
     }
 }
 
+/// Returns the range of the call expression, and whether the call is `Symbol.for()`,
+/// if `reference` is the `Symbol` object of a `Symbol()` or `Symbol.for()` call.
+fn symbol_call(reference: &JsReferenceIdentifier) -> Option<(TextRange, bool)> {
+    if !reference.has_name("Symbol") {
+        return None;
+    }
+    let expression = reference.parent::<JsIdentifierExpression>()?;
+    let parent = expression.syntax().parent()?;
+    if let Some(call) = JsCallExpression::cast_ref(&parent) {
+        let is_callee = call.callee().ok()?.syntax() == expression.syntax();
+        return is_callee.then(|| (call.syntax().text_trimmed_range(), false));
+    }
+    let member = JsStaticMemberExpression::cast(parent)?;
+    if member
+        .member()
+        .ok()?
+        .as_js_name()?
+        .value_token()
+        .ok()?
+        .text_trimmed()
+        != "for"
+    {
+        return None;
+    }
+    let call = member.parent::<JsCallExpression>()?;
+    let is_callee = call.callee().ok()?.syntax() == member.syntax();
+    is_callee.then(|| (call.syntax().text_trimmed_range(), true))
+}
+
+/// Returns the range of the member expression and the accessed symbol
+/// if `reference` is the object of a well-known symbol access, such as `Symbol.iterator`.
+fn well_known_symbol_access(
+    reference: &JsReferenceIdentifier,
+) -> Option<(TextRange, WellKnownSymbol)> {
+    if !reference.has_name("Symbol") {
+        return None;
+    }
+    let expression = reference.parent::<JsIdentifierExpression>()?;
+    let member = expression.parent::<JsStaticMemberExpression>()?;
+    if member.object().ok()?.syntax() != expression.syntax() {
+        return None;
+    }
+    let name = member.member().ok()?.as_js_name()?.value_token().ok()?;
+    let symbol = WellKnownSymbol::from_property_name(name.text_trimmed())?;
+    Some((member.syntax().text_trimmed_range(), symbol))
+}
+
+/// Evaluates the initializer of a member of a `const enum`.
+///
+/// `member_value` returns the value of the member `name` of the `const enum` named `object`,
+/// or of the enum being evaluated when `object` is `None`.
+fn evaluate_const_enum_expression(
+    expression: &AnyJsExpression,
+    member_value: &impl Fn(Option<TokenText>, &str) -> Option<ConstEnumValue>,
+) -> Option<ConstEnumValue> {
+    match expression {
+        AnyJsExpression::AnyJsLiteralExpression(
+            AnyJsLiteralExpression::JsNumberLiteralExpression(literal),
+        ) => Some(ConstEnumValue::from_f64(parse_js_number(
+            literal.value_token().ok()?.text_trimmed(),
+        )?)),
+        AnyJsExpression::AnyJsLiteralExpression(
+            AnyJsLiteralExpression::JsStringLiteralExpression(literal),
+        ) => Some(ConstEnumValue::String(
+            literal.inner_string_text().ok()?.text().to_string(),
+        )),
+        AnyJsExpression::JsParenthesizedExpression(expression) => {
+            evaluate_const_enum_expression(&expression.expression().ok()?, member_value)
+        }
+        AnyJsExpression::JsIdentifierExpression(identifier) => {
+            let name = identifier.name().ok()?.value_token().ok()?;
+            member_value(None, name.text_trimmed())
+        }
+        AnyJsExpression::JsStaticMemberExpression(member) => {
+            let name = member.member().ok()?.as_js_name()?.value_token().ok()?;
+            member_value(
+                Some(member_object_name(&member.object().ok()?)?),
+                name.text_trimmed(),
+            )
+        }
+        AnyJsExpression::JsComputedMemberExpression(member) => {
+            let AnyJsExpression::AnyJsLiteralExpression(
+                AnyJsLiteralExpression::JsStringLiteralExpression(name),
+            ) = member.member().ok()?
+            else {
+                return None;
+            };
+            member_value(
+                Some(member_object_name(&member.object().ok()?)?),
+                name.inner_string_text().ok()?.text(),
+            )
+        }
+        AnyJsExpression::JsUnaryExpression(expression) => {
+            let argument =
+                evaluate_const_enum_expression(&expression.argument().ok()?, member_value)?
+                    .as_f64()?;
+            let value = match expression.operator().ok()? {
+                JsUnaryOperator::Plus => argument,
+                JsUnaryOperator::Minus => -argument,
+                JsUnaryOperator::BitwiseNot => f64::from(!to_int32(argument)),
+                _ => return None,
+            };
+            Some(ConstEnumValue::from_f64(value))
+        }
+        AnyJsExpression::JsBinaryExpression(expression) => {
+            let left = evaluate_const_enum_expression(&expression.left().ok()?, member_value)?;
+            let right = evaluate_const_enum_expression(&expression.right().ok()?, member_value)?;
+            let operator = expression.operator().ok()?;
+            if let (ConstEnumValue::String(left), ConstEnumValue::String(right)) = (&left, &right) {
+                return (operator == JsBinaryOperator::Plus)
+                    .then(|| ConstEnumValue::String(format!("{left}{right}")));
+            }
+            let (left, right) = (left.as_f64()?, right.as_f64()?);
+            // The shift count only uses the 5 lowest bits of the right operand
+            let shift = to_int32(right) as u32 & 0x1f;
+            let value = match operator {
+                JsBinaryOperator::Plus => left + right,
+                JsBinaryOperator::Minus => left - right,
+                JsBinaryOperator::Times => left * right,
+                JsBinaryOperator::Divide => left / right,
+                JsBinaryOperator::Remainder => left % right,
+                JsBinaryOperator::Exponent => left.powf(right),
+                JsBinaryOperator::BitwiseAnd => f64::from(to_int32(left) & to_int32(right)),
+                JsBinaryOperator::BitwiseOr => f64::from(to_int32(left) | to_int32(right)),
+                JsBinaryOperator::BitwiseXor => f64::from(to_int32(left) ^ to_int32(right)),
+                JsBinaryOperator::LeftShift => f64::from(to_int32(left) << shift),
+                JsBinaryOperator::RightShift => f64::from(to_int32(left) >> shift),
+                JsBinaryOperator::UnsignedRightShift => f64::from(to_int32(left) as u32 >> shift),
+                _ => return None,
+            };
+            Some(ConstEnumValue::from_f64(value))
+        }
+        _ => None,
+    }
+}
+
+/// Returns the name of `object` if it's an identifier, such as `E` in `E.A`.
+fn member_object_name(object: &AnyJsExpression) -> Option<TokenText> {
+    let AnyJsExpression::JsIdentifierExpression(identifier) = object else {
+        return None;
+    };
+    Some(
+        identifier
+            .name()
+            .ok()?
+            .value_token()
+            .ok()?
+            .token_text_trimmed(),
+    )
+}
+
+/// Converts `value` to a 32-bit integer, like the bitwise operators of JavaScript.
+fn to_int32(value: f64) -> i32 {
+    if !value.is_finite() {
+        return 0;
+    }
+    value.trunc().rem_euclid(4_294_967_296.0) as u32 as i32
+}
+
+/// Returns the name under which `specifier` exports its binding:
+/// the alias of `export { a as b }`, or the local name of `export { a }`.
+fn export_specifier_name(specifier: &AnyJsExportNamedSpecifier) -> Option<TokenText> {
+    match specifier {
+        AnyJsExportNamedSpecifier::JsExportNamedShorthandSpecifier(specifier) => Some(
+            specifier
+                .name()
+                .ok()?
+                .value_token()
+                .ok()?
+                .token_text_trimmed(),
+        ),
+        AnyJsExportNamedSpecifier::JsExportNamedSpecifier(specifier) => Some(inner_string_text(
+            &specifier.exported_name().ok()?.value().ok()?,
+        )),
+    }
+}
+
+/// Returns `true` if `declaration` is declared at the top level of a `declare global {}` block,
+/// and thus augments the global scope.
+///
+/// ```ts
+/// declare global {
+///     var version: string;
+///     interface Window {}
+/// }
+/// ```
+pub(crate) fn is_global_augmentation(declaration: &AnyJsBindingDeclaration) -> bool {
+    let statement = match declaration {
+        AnyJsBindingDeclaration::JsVariableDeclarator(declarator) => {
+            declarator.syntax().grand_parent()
+        }
+        AnyJsBindingDeclaration::JsFunctionDeclaration(_)
+        | AnyJsBindingDeclaration::TsDeclareFunctionDeclaration(_)
+        | AnyJsBindingDeclaration::JsClassDeclaration(_)
+        | AnyJsBindingDeclaration::TsEnumDeclaration(_)
+        | AnyJsBindingDeclaration::TsInterfaceDeclaration(_)
+        | AnyJsBindingDeclaration::TsTypeAliasDeclaration(_)
+        | AnyJsBindingDeclaration::TsModuleDeclaration(_) => Some(declaration.syntax().clone()),
+        _ => None,
+    };
+    // Skip the wrappers of `declare var a`, `export var a`, and `export declare var a`
+    let item_list = statement.and_then(|statement| {
+        statement.ancestors().skip(1).find(|ancestor| {
+            !matches!(
+                ancestor.kind(),
+                JS_VARIABLE_DECLARATION
+                    | JS_VARIABLE_STATEMENT
+                    | JS_VARIABLE_DECLARATION_CLAUSE
+                    | TS_DECLARE_STATEMENT
+                    | JS_EXPORT
+            )
+        })
+    });
+    item_list.is_some_and(|item_list| {
+        item_list.kind() == JS_MODULE_ITEM_LIST
+            && item_list.grand_parent().kind() == Some(TS_GLOBAL_DECLARATION)
+    })
+}
+
 /// Extracts [SemanticEvent] from [SyntaxNode].
 /// See [semantic_events] how to create this iterator.
 struct SemanticEventIterator {
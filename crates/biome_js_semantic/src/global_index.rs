@@ -0,0 +1,586 @@
+//! Cross-file index of the exports of a set of modules and of their uses.
+//!
+//! The [SemanticModel] of a file only knows the bindings of that file.
+//! A [GlobalSemanticIndex] gathers the exports, the imports, and the re-exports of several files,
+//! and resolves the relative import specifiers between them.
+//! Bare specifiers, such as `react`, are only resolved when a file is indexed under the same specifier.
+
+use crate::SemanticModel;
+use biome_js_syntax::{
+    binding_ext::AnyJsIdentifierBinding, inner_string_text, AnyJsBinding, AnyJsExportClause,
+    AnyJsExportDefaultDeclaration, AnyJsExportNamedSpecifier, AnyJsExpression, AnyJsImportClause,
+    AnyJsLiteralExpression, AnyJsMemberExpression, AnyJsModuleItem, AnyJsNamedImport,
+    AnyJsNamedImportSpecifier, AnyTsName, JsExport, JsIdentifierBinding, JsImport,
+    JsImportCallExpression, JsLiteralExportName, JsModule, JsSyntaxNode, TextRange,
+    TsQualifiedName,
+};
+use biome_rowan::{AstNode, AstSeparatedList};
+use rustc_hash::{FxHashMap, FxHashSet};
+use std::borrow::Borrow;
+use std::fmt::{Display, Formatter};
+use std::path::{Component, Path};
+
+/// Extensions that are tried, in order, to resolve a relative specifier without extension.
+const RESOLVED_EXTENSIONS: &[&str] =
+    &["ts", "tsx", "d.ts", "mts", "cts", "js", "jsx", "mjs", "cjs"];
+
+/// The name of an indexed module, or the specifier of a module that isn't indexed.
+///
+/// The name of an indexed module is its normalized path, with `/` as separator.
+#[derive(Clone, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub struct ModuleSpecifier(String);
+
+impl ModuleSpecifier {
+    /// Returns the specifier of the module at `path`.
+    ///
+    /// The `.` and `..` components of `path` are resolved.
+    pub fn from_path(path: &Path) -> Self {
+        let mut components: Vec<String> = Vec::new();
+        let mut prefix = String::new();
+        for component in path.components() {
+            match component {
+                Component::Prefix(value) => {
+                    prefix.push_str(&value.as_os_str().to_string_lossy());
+                }
+                Component::RootDir => prefix.push('/'),
+                Component::CurDir => {}
+                Component::ParentDir => {
+                    if components.last().is_some_and(|last| last != "..") {
+                        components.pop();
+                    } else {
+                        components.push("..".to_string());
+                    }
+                }
+                Component::Normal(value) => components.push(value.to_string_lossy().into_owned()),
+            }
+        }
+        Self(prefix + &components.join("/"))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Borrow<str> for ModuleSpecifier {
+    fn borrow(&self) -> &str {
+        &self.0
+    }
+}
+
+impl From<&str> for ModuleSpecifier {
+    fn from(value: &str) -> Self {
+        Self(value.to_string())
+    }
+}
+
+impl Display for ModuleSpecifier {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+/// An export of an indexed module.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ExportDescriptor {
+    /// The module that exports the value
+    pub module: ModuleSpecifier,
+    /// The exported name, `default` for the default export
+    pub name: String,
+    /// The range of the exported name, or of the default export
+    pub range: TextRange,
+}
+
+/// A location where an export is imported.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ImportSite {
+    /// The module that imports the export
+    pub module: ModuleSpecifier,
+    /// The range of the import specifier, of the namespace member, of the re-export,
+    /// or of the dynamic import
+    pub range: TextRange,
+    pub kind: ImportSiteKind,
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum ImportSiteKind {
+    /// `import { a } from "mod"` or `import a from "mod"`
+    Named,
+    /// `ns.a` where `ns` is declared by `import * as ns from "mod"`
+    NamespaceMember,
+    /// `import * as ns from "mod"` where `ns` isn't only used to access its members,
+    /// or `export * as ns from "mod"`.
+    /// All the exports of the module are considered as used.
+    Namespace,
+    /// `export { a } from "mod"` or `export * from "mod"`
+    ReExport,
+    /// `import("mod")`.
+    /// All the exports of the module are considered as used.
+    Dynamic,
+}
+
+/// An import or a re-export of an indexed module.
+#[derive(Debug)]
+struct ImportEntry {
+    /// The module that contains the import
+    importer: ModuleSpecifier,
+    /// The specifier as written in the import
+    source: String,
+    range: TextRange,
+    kind: ImportEntryKind,
+}
+
+#[derive(Debug)]
+enum ImportEntryKind {
+    /// `import { imported } from "mod"`
+    Named { imported: String },
+    /// `import * as ns from "mod"`.
+    /// `members` lists the accessed members of `ns`,
+    /// or is `None` if `ns` is used in another way.
+    Namespace {
+        members: Option<Vec<(String, TextRange)>>,
+    },
+    /// `import("mod")`
+    Dynamic,
+    /// `export { imported as exported } from "mod"`
+    ReExport { imported: String, exported: String },
+    /// `export * from "mod"`
+    ReExportAll,
+    /// `export * as ns from "mod"`
+    ReExportNamespace,
+}
+
+/// A cross-file index of the exports of a set of modules and of their uses.
+///
+/// ```rust
+/// use biome_js_parser::JsParserOptions;
+/// use biome_js_syntax::JsFileSource;
+/// use biome_js_semantic::{semantic_model, GlobalSemanticIndex, SemanticModelOptions};
+/// use std::path::Path;
+///
+/// let parse = |code| {
+///     let root = biome_js_parser::parse(code, JsFileSource::js_module(), JsParserOptions::default()).tree();
+///     semantic_model(&root, SemanticModelOptions::default())
+/// };
+/// let a = parse("export const a = 1;");
+/// let b = parse("import { a } from './a';");
+///
+/// let index = GlobalSemanticIndex::new([(Path::new("src/a.js"), &a), (Path::new("src/b.js"), &b)]);
+/// let export = &index.exports(&"src/a.js".into())[0];
+/// let uses = index.find_all_uses(export);
+/// assert_eq!(uses.len(), 1);
+/// assert_eq!(uses[0].module.as_str(), "src/b.js");
+/// ```
+#[derive(Debug, Default)]
+pub struct GlobalSemanticIndex {
+    /// The exports of every indexed module
+    exports: FxHashMap<ModuleSpecifier, Vec<ExportDescriptor>>,
+    /// The imports and the re-exports of every indexed module
+    imports: Vec<ImportEntry>,
+}
+
+impl GlobalSemanticIndex {
+    /// Creates the index of the given `(file_path, model)` pairs.
+    pub fn new<'a, P: AsRef<Path>>(
+        modules: impl IntoIterator<Item = (P, &'a SemanticModel)>,
+    ) -> Self {
+        let mut index = Self::default();
+        for (path, model) in modules {
+            index.add_module(path.as_ref(), model);
+        }
+        index
+    }
+
+    /// Adds the exports and the imports of the file at `path` to the index.
+    pub fn add_module(&mut self, path: &Path, model: &SemanticModel) {
+        let module = ModuleSpecifier::from_path(path);
+        let mut exports = Vec::new();
+        let root = model.data.root.syntax();
+        if let Some(root) = JsModule::cast_ref(root) {
+            for item in root.items() {
+                match item {
+                    AnyJsModuleItem::JsExport(export) => {
+                        self.add_export(&module, &export, &mut exports);
+                    }
+                    AnyJsModuleItem::JsImport(import) => {
+                        self.add_import(&module, &import, model);
+                    }
+                    AnyJsModuleItem::AnyJsStatement(_) => {}
+                }
+            }
+        }
+        for call in root.descendants().filter_map(JsImportCallExpression::cast) {
+            if let Some(source) = dynamic_import_source(&call) {
+                self.imports.push(ImportEntry {
+                    importer: module.clone(),
+                    source,
+                    range: call.syntax().text_trimmed_range(),
+                    kind: ImportEntryKind::Dynamic,
+                });
+            }
+        }
+        self.exports.insert(module, exports);
+    }
+
+    /// Returns the exports of `module`, or an empty slice if the module isn't indexed.
+    ///
+    /// The names that are re-exported with `export * from "mod"` aren't included.
+    pub fn exports(&self, module: &ModuleSpecifier) -> &[ExportDescriptor] {
+        self.exports.get(module).map_or(&[], Vec::as_slice)
+    }
+
+    /// Returns every location of the indexed files where `export` is imported.
+    ///
+    /// The uses through re-exports are included, as well as the re-exports themselves.
+    pub fn find_all_uses(&self, export: &ExportDescriptor) -> Vec<ImportSite> {
+        let mut sites = Vec::new();
+        let mut visited = FxHashSet::default();
+        self.collect_uses(&export.module, &export.name, &mut sites, &mut visited);
+        sites
+    }
+
+    /// Resolves the specifier `source`, imported by `importer`, to an indexed module.
+    pub fn resolve(&self, importer: &ModuleSpecifier, source: &str) -> Option<&ModuleSpecifier> {
+        let is_relative =
+            matches!(source, "." | "..") || source.starts_with("./") || source.starts_with("../");
+        if !is_relative {
+            return self.exports.get_key_value(source).map(|(module, _)| module);
+        }
+        let directory = Path::new(importer.as_str())
+            .parent()
+            .unwrap_or_else(|| Path::new(""));
+        let path = ModuleSpecifier::from_path(&directory.join(source));
+        let path = path.as_str();
+        let mut candidates = vec![path.to_string()];
+        // TypeScript resolves `./a.js` to `./a.ts`
+        if let Some((stem, extension)) = path.rsplit_once('.') {
+            let replaced = match extension {
+                "js" => &["ts", "tsx"][..],
+                "jsx" => &["tsx"],
+                "mjs" => &["mts"],
+                "cjs" => &["cts"],
+                _ => &[],
+            };
+            candidates.extend(
+                replaced
+                    .iter()
+                    .map(|extension| format!("{stem}.{extension}")),
+            );
+        }
+        for extension in RESOLVED_EXTENSIONS {
+            candidates.push(format!("{path}.{extension}"));
+        }
+        for extension in RESOLVED_EXTENSIONS {
+            candidates.push(format!("{path}/index.{extension}"));
+        }
+        candidates
+            .into_iter()
+            .find_map(|candidate| self.exports.get_key_value(candidate.as_str()))
+            .map(|(module, _)| module)
+    }
+
+    fn collect_uses(
+        &self,
+        module: &ModuleSpecifier,
+        name: &str,
+        sites: &mut Vec<ImportSite>,
+        visited: &mut FxHashSet<(ModuleSpecifier, String)>,
+    ) {
+        if !visited.insert((module.clone(), name.to_string())) {
+            return;
+        }
+        for import in &self.imports {
+            if self.resolve(&import.importer, &import.source) != Some(module) {
+                continue;
+            }
+            let site = |range, kind| ImportSite {
+                module: import.importer.clone(),
+                range,
+                kind,
+            };
+            match &import.kind {
+                ImportEntryKind::Named { imported } => {
+                    if imported == name {
+                        sites.push(site(import.range, ImportSiteKind::Named));
+                    }
+                }
+                ImportEntryKind::Namespace { members: None } => {
+                    sites.push(site(import.range, ImportSiteKind::Namespace));
+                }
+                ImportEntryKind::Namespace {
+                    members: Some(members),
+                } => {
+                    for (member, range) in members {
+                        if member == name {
+                            sites.push(site(*range, ImportSiteKind::NamespaceMember));
+                        }
+                    }
+                }
+                ImportEntryKind::Dynamic => {
+                    sites.push(site(import.range, ImportSiteKind::Dynamic));
+                }
+                ImportEntryKind::ReExportNamespace => {
+                    sites.push(site(import.range, ImportSiteKind::Namespace));
+                }
+                ImportEntryKind::ReExport { imported, exported } => {
+                    if imported == name {
+                        sites.push(site(import.range, ImportSiteKind::ReExport));
+                        self.collect_uses(&import.importer, exported, sites, visited);
+                    }
+                }
+                ImportEntryKind::ReExportAll => {
+                    // `export * from "mod"` doesn't re-export the default export
+                    if name != "default" {
+                        sites.push(site(import.range, ImportSiteKind::ReExport));
+                        self.collect_uses(&import.importer, name, sites, visited);
+                    }
+                }
+            }
+        }
+    }
+
+    fn add_export(
+        &mut self,
+        module: &ModuleSpecifier,
+        export: &JsExport,
+        exports: &mut Vec<ExportDescriptor>,
+    ) -> Option<()> {
+        let mut push_export = |name: String, range: TextRange| {
+            exports.push(ExportDescriptor {
+                module: module.clone(),
+                name,
+                range,
+            })
+        };
+        match export.export_clause().ok()? {
+            AnyJsExportClause::AnyJsDeclarationClause(clause) => {
+                for (name, range) in exported_bindings(clause.syntax(), export) {
+                    push_export(name, range);
+                }
+            }
+            AnyJsExportClause::TsExportDeclareClause(clause) => {
+                for (name, range) in exported_bindings(clause.syntax(), export) {
+                    push_export(name, range);
+                }
+            }
+            AnyJsExportClause::JsExportDefaultDeclarationClause(clause) => {
+                let range = match clause.declaration().ok()? {
+                    AnyJsExportDefaultDeclaration::JsClassExportDefaultDeclaration(declaration) => {
+                        declaration.id().map(|id| id.range())
+                    }
+                    AnyJsExportDefaultDeclaration::JsFunctionExportDefaultDeclaration(
+                        declaration,
+                    ) => declaration.id().map(|id| id.range()),
+                    _ => None,
+                };
+                push_export(
+                    "default".to_string(),
+                    range.unwrap_or_else(|| clause.range()),
+                );
+            }
+            AnyJsExportClause::JsExportDefaultExpressionClause(clause) => {
+                push_export("default".to_string(), clause.range());
+            }
+            AnyJsExportClause::TsExportAssignmentClause(clause) => {
+                push_export("default".to_string(), clause.range());
+            }
+            AnyJsExportClause::JsExportNamedClause(clause) => {
+                for specifier in clause.specifiers().iter() {
+                    match specifier.ok()? {
+                        AnyJsExportNamedSpecifier::JsExportNamedShorthandSpecifier(specifier) => {
+                            let name = specifier.name().ok()?;
+                            push_export(name.name().ok()?.to_string(), name.range());
+                        }
+                        AnyJsExportNamedSpecifier::JsExportNamedSpecifier(specifier) => {
+                            let exported = specifier.exported_name().ok()?;
+                            push_export(export_name_text(&exported)?, exported.range());
+                        }
+                    }
+                }
+            }
+            AnyJsExportClause::JsExportNamedFromClause(clause) => {
+                let source = clause.source().ok()?.inner_string_text().ok()?;
+                for specifier in clause.specifiers().iter() {
+                    let specifier = specifier.ok()?;
+                    let imported = specifier.source_name().ok()?;
+                    let exported = match specifier.export_as() {
+                        Some(export_as) => export_as.exported_name().ok()?,
+                        None => imported.clone(),
+                    };
+                    push_export(export_name_text(&exported)?, exported.range());
+                    self.imports.push(ImportEntry {
+                        importer: module.clone(),
+                        source: source.to_string(),
+                        range: specifier.range(),
+                        kind: ImportEntryKind::ReExport {
+                            imported: export_name_text(&imported)?,
+                            exported: export_name_text(&exported)?,
+                        },
+                    });
+                }
+            }
+            AnyJsExportClause::JsExportFromClause(clause) => {
+                let source = clause.source().ok()?.inner_string_text().ok()?;
+                let kind = match clause.export_as() {
+                    Some(export_as) => {
+                        let exported = export_as.exported_name().ok()?;
+                        push_export(export_name_text(&exported)?, exported.range());
+                        ImportEntryKind::ReExportNamespace
+                    }
+                    None => ImportEntryKind::ReExportAll,
+                };
+                self.imports.push(ImportEntry {
+                    importer: module.clone(),
+                    source: source.to_string(),
+                    range: export.range(),
+                    kind,
+                });
+            }
+            AnyJsExportClause::TsExportAsNamespaceClause(_) => {}
+        }
+        Some(())
+    }
+
+    fn add_import(
+        &mut self,
+        module: &ModuleSpecifier,
+        import: &JsImport,
+        model: &SemanticModel,
+    ) -> Option<()> {
+        let clause = import.import_clause().ok()?;
+        let source = clause.source().ok()?.inner_string_text().ok()?.to_string();
+        let mut push_import = |range, kind| {
+            self.imports.push(ImportEntry {
+                importer: module.clone(),
+                source: source.clone(),
+                range,
+                kind,
+            })
+        };
+        match clause {
+            AnyJsImportClause::JsImportBareClause(_) => {}
+            AnyJsImportClause::JsImportDefaultClause(clause) => {
+                push_import(
+                    clause.local_name().ok()?.range(),
+                    ImportEntryKind::Named {
+                        imported: "default".to_string(),
+                    },
+                );
+            }
+            AnyJsImportClause::JsImportNamespaceClause(clause) => {
+                let local_name = clause.local_name().ok()?;
+                push_import(
+                    local_name.range(),
+                    ImportEntryKind::Namespace {
+                        members: namespace_members(&local_name, model),
+                    },
+                );
+            }
+            AnyJsImportClause::JsImportNamedClause(clause) => {
+                if let Some(specifier) = clause.default_specifier() {
+                    push_import(
+                        specifier.local_name().ok()?.range(),
+                        ImportEntryKind::Named {
+                            imported: "default".to_string(),
+                        },
+                    );
+                }
+                match clause.named_import().ok()? {
+                    AnyJsNamedImport::JsNamedImportSpecifiers(specifiers) => {
+                        for specifier in specifiers.specifiers().iter() {
+                            let specifier = specifier.ok()?;
+                            let imported = match &specifier {
+                                AnyJsNamedImportSpecifier::JsNamedImportSpecifier(specifier) => {
+                                    export_name_text(&specifier.name().ok()?)?
+                                }
+                                _ => specifier.local_name()?.text_trimmed().to_string(),
+                            };
+                            push_import(specifier.range(), ImportEntryKind::Named { imported });
+                        }
+                    }
+                    AnyJsNamedImport::JsNamespaceImportSpecifier(specifier) => {
+                        let local_name = specifier.local_name().ok()?;
+                        push_import(
+                            local_name.range(),
+                            ImportEntryKind::Namespace {
+                                members: namespace_members(&local_name, model),
+                            },
+                        );
+                    }
+                }
+            }
+        }
+        Some(())
+    }
+}
+
+/// Returns the names and the ranges of the bindings of `declaration` that are directly exported by `export`.
+fn exported_bindings<'a>(
+    declaration: &JsSyntaxNode,
+    export: &'a JsExport,
+) -> impl Iterator<Item = (String, TextRange)> + 'a {
+    declaration
+        .descendants()
+        .filter_map(AnyJsIdentifierBinding::cast)
+        .filter(move |binding| {
+            binding
+                .declaration()
+                .and_then(|declaration| declaration.export())
+                .is_some_and(|declaring_export| &declaring_export == export)
+        })
+        .filter_map(|binding| {
+            let name = binding.name_token().ok()?;
+            Some((name.text_trimmed().to_string(), binding.range()))
+        })
+}
+
+/// Returns the members of the namespace `local_name` that are accessed,
+/// or `None` if the namespace is used in another way.
+fn namespace_members(
+    local_name: &AnyJsBinding,
+    model: &SemanticModel,
+) -> Option<Vec<(String, TextRange)>> {
+    let binding = JsIdentifierBinding::cast_ref(local_name.syntax())?;
+    let mut members = Vec::new();
+    for reference in model.as_binding(&binding).all_references() {
+        let reference = reference.syntax();
+        let parent = reference.parent()?;
+        // `ns.Type` in a type
+        if let Some(name) = TsQualifiedName::cast_ref(&parent) {
+            if matches!(name.left().ok()?, AnyTsName::JsReferenceIdentifier(_)) {
+                let member = name.right().ok()?.value_token().ok()?;
+                members.push((member.text_trimmed().to_string(), name.range()));
+                continue;
+            }
+        }
+        // `ns.value` or `ns["value"]` in an expression
+        let member_expression = parent.parent().and_then(AnyJsMemberExpression::cast)?;
+        let is_object = member_expression
+            .object()
+            .ok()
+            .is_some_and(|object| object.syntax() == &parent);
+        if !is_object {
+            return None;
+        }
+        let member = member_expression.member_name()?;
+        members.push((member.text().to_string(), member_expression.range()));
+    }
+    Some(members)
+}
+
+/// Returns the specifier of `import("mod")` if it is a string literal.
+fn dynamic_import_source(call: &JsImportCallExpression) -> Option<String> {
+    let arguments = call.arguments().ok()?.args();
+    let argument = arguments.first()?.ok()?;
+    let AnyJsExpression::AnyJsLiteralExpression(AnyJsLiteralExpression::JsStringLiteralExpression(
+        source,
+    )) = argument.as_any_js_expression()?
+    else {
+        return None;
+    };
+    Some(source.inner_string_text().ok()?.to_string())
+}
+
+fn export_name_text(name: &JsLiteralExportName) -> Option<String> {
+    Some(inner_string_text(&name.value().ok()?).to_string())
+}
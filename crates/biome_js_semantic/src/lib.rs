@@ -1,8 +1,10 @@
 mod events;
+mod global_index;
 
 mod semantic_model;
 #[cfg(test)]
 mod tests;
 
 pub use events::*;
+pub use global_index::*;
 pub use semantic_model::*;
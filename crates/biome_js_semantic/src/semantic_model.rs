@@ -7,17 +7,18 @@ mod is_constant;
 mod model;
 mod reference;
 mod scope;
+mod this_binding;
 
 #[cfg(test)]
 mod tests;
 
-use crate::{SemanticEvent, SemanticEventExtractor};
+use crate::{SemanticEvent, SemanticEventExtractor, WellKnownSymbol};
 use biome_js_syntax::{
     AnyJsExpression, AnyJsRoot, JsIdentifierAssignment, JsIdentifierBinding, JsLanguage,
     JsReferenceIdentifier, JsSyntaxKind, JsSyntaxNode, JsxReferenceIdentifier, TextRange, TextSize,
     TsIdentifierBinding,
 };
-use biome_rowan::AstNode;
+use biome_rowan::{AstNode, TokenText};
 pub use closure::*;
 use rust_lapper::{Interval, Lapper};
 use rustc_hash::{FxHashMap, FxHashSet};
@@ -36,6 +37,7 @@ pub use is_constant::*;
 pub use model::*;
 pub use reference::*;
 pub use scope::*;
+pub use this_binding::*;
 
 /// Extra options for the [SemanticModel] creation.
 #[derive(Default)]
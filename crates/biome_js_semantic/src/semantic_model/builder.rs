@@ -1,5 +1,10 @@
 use super::*;
-use biome_js_syntax::{AnyJsRoot, JsSyntaxNode, TextRange};
+use crate::events::is_global_augmentation;
+use crate::ConstEnumValue;
+use biome_js_syntax::{
+    binding_ext::{AnyJsBindingDeclaration, AnyJsIdentifierBinding},
+    AnyJsRoot, JsSyntaxNode, JsSyntaxToken, TextRange,
+};
 use rustc_hash::{FxHashMap, FxHashSet};
 use std::collections::hash_map::Entry;
 
@@ -23,6 +28,26 @@ pub struct SemanticModelBuilder {
     /// maps a reference range start to its bindings. usize points to SemanticModelBuilder::bindings vec
     declared_at_by_start: FxHashMap<TextSize, usize>,
     exported: FxHashSet<TextSize>,
+    /// the bindings that are the default export of the module
+    default_exported: FxHashSet<TextSize>,
+    /// the exported names and the ranges of their bindings
+    exported_bindings: Vec<(TokenText, TextRange)>,
+    /// the bindings declared in a `declare global {}` block
+    global_declarations: Vec<BindingIndex>,
+    /// maps the range of an expression to the range of the type it satisfies
+    constraint_by_range: FxHashMap<TextRange, TextRange>,
+    /// the trimmed ranges of the references in a type position
+    type_references: FxHashSet<TextRange>,
+    /// maps a scope id to the ranges of the type parameters it declares
+    type_parameters_by_scope: FxHashMap<usize, Vec<TextRange>>,
+    /// all the symbols created by `Symbol()` and `Symbol.for()`
+    symbol_creations: Vec<SymbolCreation>,
+    decorators: Vec<AppliedDecorator>,
+    await_points: Vec<AwaitPoint>,
+    well_known_symbol_accesses: Vec<WellKnownSymbolAccess>,
+    labels: Vec<Label>,
+    /// maps the range of the name of a `const enum` member to its value
+    const_enum_values: FxHashMap<TextRange, ConstEnumValue>,
     unresolved_references: Vec<SemanticModelUnresolvedReference>,
 }
 
@@ -40,6 +65,18 @@ impl SemanticModelBuilder {
             bindings_by_start: FxHashMap::default(),
             declared_at_by_start: FxHashMap::default(),
             exported: FxHashSet::default(),
+            default_exported: FxHashSet::default(),
+            exported_bindings: Vec::new(),
+            global_declarations: Vec::new(),
+            constraint_by_range: FxHashMap::default(),
+            type_references: FxHashSet::default(),
+            type_parameters_by_scope: FxHashMap::default(),
+            symbol_creations: Vec::new(),
+            decorators: Vec::new(),
+            await_points: Vec::new(),
+            well_known_symbol_accesses: Vec::new(),
+            labels: Vec::new(),
+            const_enum_values: FxHashMap::default(),
             unresolved_references: Vec::new(),
         }
     }
@@ -116,6 +153,7 @@ impl SemanticModelBuilder {
                     children: vec![],
                     bindings: vec![],
                     bindings_by_name: FxHashMap::default(),
+                    declarations: vec![],
                     read_references: vec![],
                     write_references: vec![],
                     is_closure,
@@ -161,12 +199,35 @@ impl SemanticModelBuilder {
                 let scope = self.scopes.get_mut(binding_scope_id).unwrap();
 
                 scope.bindings.push(binding_id);
-                scope.bindings_by_name.insert(name, binding_id);
+                scope.bindings_by_name.insert(name.clone(), binding_id);
+                scope.declarations.push(ScopeDeclaration {
+                    name,
+                    range: name_token.text_trimmed_range(),
+                    is_hoisted: is_hoisted_declaration(&name_token),
+                });
+
+                if is_global_augmentation_name(&name_token) {
+                    self.global_declarations.push(binding_id.into());
+                }
 
                 if let Some(hoisted_scope_id) = hoisted_scope_id {
                     self.scope_hoisted_to_by_range
                         .insert(name_range.start(), hoisted_scope_id);
                 }
+
+                // Only type parameters of a type parameter list, not of `infer` types or mapped types
+                let is_type_parameter = name_token.parent().is_some_and(|name| {
+                    name.kind() == JsSyntaxKind::TS_TYPE_PARAMETER_NAME
+                        && name
+                            .parent()
+                            .is_some_and(|parent| parent.kind() == JsSyntaxKind::TS_TYPE_PARAMETER)
+                });
+                if is_type_parameter {
+                    self.type_parameters_by_scope
+                        .entry(binding_scope_id)
+                        .or_default()
+                        .push(name_token.text_trimmed_range());
+                }
             }
             Read {
                 range,
@@ -310,11 +371,101 @@ impl SemanticModelBuilder {
             Exported { range } => {
                 self.exported.insert(range.start());
             }
+            ExportedDefault { range } => {
+                self.default_exported.insert(range.start());
+            }
+            ExportedName { name, range } => {
+                // The range of a binding includes its trailing trivia
+                let range = self
+                    .node_by_range
+                    .get(&range)
+                    .map_or(range, |node| node.text_trimmed_range());
+                self.exported_bindings.push((name, range));
+            }
+            TypeSatisfied {
+                expression_range,
+                constraint_range,
+            } => {
+                self.constraint_by_range
+                    .insert(expression_range, constraint_range);
+            }
+            TypeReference { range } => {
+                self.type_references.insert(range);
+            }
+            SymbolCreated { range, is_global } => {
+                self.symbol_creations
+                    .push(SymbolCreation { range, is_global });
+            }
+            DecoratorApplied {
+                decorator_range,
+                target_range,
+                is_class,
+                is_method,
+                is_parameter,
+            } => {
+                self.decorators.push(AppliedDecorator {
+                    range: decorator_range,
+                    target_range,
+                    is_class,
+                    is_method,
+                    is_parameter,
+                });
+            }
+            AwaitExpression {
+                range,
+                enclosing_async_scope_id,
+            } => {
+                self.await_points.push(AwaitPoint {
+                    range,
+                    function_range: self.scopes[enclosing_async_scope_id].range,
+                });
+            }
+            WellKnownSymbolAccess { symbol_name, range } => {
+                self.well_known_symbol_accesses
+                    .push(super::model::WellKnownSymbolAccess {
+                        range,
+                        symbol: symbol_name,
+                    });
+            }
+            ConstEnumMember {
+                name_token, value, ..
+            } => {
+                self.const_enum_values
+                    .insert(name_token.text_trimmed_range(), value);
+            }
+            LabelDeclared { range, label } => {
+                self.labels.push(Label {
+                    range,
+                    name: label,
+                    jumps: Vec::new(),
+                });
+            }
+            LabelUsed {
+                label_range,
+                jump_range,
+                is_break,
+            } => {
+                // The target label is declared by an enclosing statement, so it's one of the last ones
+                if let Some(label) = self
+                    .labels
+                    .iter_mut()
+                    .rev()
+                    .find(|label| label.range == label_range)
+                {
+                    label.jumps.push(LabelJump {
+                        range: jump_range,
+                        is_break,
+                    });
+                }
+            }
         }
     }
 
     #[inline]
-    pub fn build(self) -> SemanticModel {
+    pub fn build(mut self) -> SemanticModel {
+        // The names exported by an export clause are only known at the end of the scope
+        self.exported_bindings
+            .sort_by_key(|(_, range)| range.start());
         let data = SemanticModelData {
             root: self.root,
             scopes: self.scopes,
@@ -331,9 +482,58 @@ impl SemanticModelBuilder {
             bindings_by_start: self.bindings_by_start,
             declared_at_by_start: self.declared_at_by_start,
             exported: self.exported,
+            default_exported: self.default_exported,
+            exported_bindings: self.exported_bindings,
+            global_declarations: self.global_declarations,
+            constraint_by_range: self.constraint_by_range,
+            type_references: self.type_references,
+            type_parameters_by_scope: self.type_parameters_by_scope,
+            symbol_creations: self.symbol_creations,
+            decorators: self.decorators,
+            await_points: self.await_points,
+            well_known_symbol_accesses: self.well_known_symbol_accesses,
+            labels: self.labels,
+            const_enum_values: self.const_enum_values,
             unresolved_references: self.unresolved_references,
             globals: self.globals,
         };
         SemanticModel::new(data)
     }
 }
+
+/// Returns `true` if the binding declared by `name_token` is declared in a `declare global {}` block.
+fn is_global_augmentation_name(name_token: &JsSyntaxToken) -> bool {
+    name_token
+        .parent()
+        .and_then(AnyJsIdentifierBinding::cast)
+        .and_then(|binding| binding.declaration())
+        .is_some_and(|declaration| is_global_augmentation(&declaration))
+}
+
+/// Returns `true` if the binding declared by `name_token` can be used before its declaration.
+fn is_hoisted_declaration(name_token: &JsSyntaxToken) -> bool {
+    let Some(declaration) = name_token
+        .parent()
+        .and_then(AnyJsIdentifierBinding::cast)
+        .and_then(|binding| binding.declaration())
+    else {
+        return false;
+    };
+    match declaration {
+        AnyJsBindingDeclaration::JsVariableDeclarator(declarator) => declarator
+            .declaration()
+            .is_some_and(|declaration| declaration.is_var()),
+        AnyJsBindingDeclaration::JsFunctionDeclaration(_)
+        | AnyJsBindingDeclaration::JsFunctionExportDefaultDeclaration(_)
+        | AnyJsBindingDeclaration::TsDeclareFunctionDeclaration(_)
+        | AnyJsBindingDeclaration::TsDeclareFunctionExportDefaultDeclaration(_)
+        | AnyJsBindingDeclaration::JsImportDefaultClause(_)
+        | AnyJsBindingDeclaration::JsImportNamespaceClause(_)
+        | AnyJsBindingDeclaration::TsImportEqualsDeclaration(_)
+        | AnyJsBindingDeclaration::JsDefaultImportSpecifier(_)
+        | AnyJsBindingDeclaration::JsNamespaceImportSpecifier(_)
+        | AnyJsBindingDeclaration::JsShorthandNamedImportSpecifier(_)
+        | AnyJsBindingDeclaration::JsNamedImportSpecifier(_) => true,
+        _ => false,
+    }
+}
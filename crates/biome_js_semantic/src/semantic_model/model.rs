@@ -1,5 +1,12 @@
 use super::*;
-use biome_js_syntax::{AnyJsFunction, AnyJsRoot, JsInitializerClause, JsVariableDeclarator};
+use crate::ConstEnumValue;
+use biome_js_syntax::{
+    binding_ext::AnyJsBindingDeclaration, AnyJsFunction, AnyJsMemberExpression, AnyJsRoot,
+    AnyTsVariableAnnotation, JsAssignmentExpression, JsAssignmentOperator,
+    JsForVariableDeclaration, JsInitializerClause, JsPostUpdateExpression, JsPreUpdateExpression,
+    JsVariableDeclarator, TsDeclareStatement, TsEnumDeclaration,
+};
+use biome_rowan::{AstSeparatedList, NodeOrToken};
 
 #[derive(Copy, Clone, Debug)]
 pub(crate) struct BindingIndex(usize);
@@ -25,6 +32,146 @@ impl From<(BindingIndex, usize)> for ReferenceIndex {
     }
 }
 
+/// A symbol created by a call to the global `Symbol` object.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct SymbolCreation {
+    pub(crate) range: TextRange,
+    pub(crate) is_global: bool,
+}
+
+impl SymbolCreation {
+    /// Range of the `Symbol()` or `Symbol.for()` call expression
+    pub fn range(&self) -> TextRange {
+        self.range
+    }
+
+    /// Returns `true` if the symbol is created with `Symbol.for()`,
+    /// and is thus shared through the global symbol registry.
+    pub fn is_global(&self) -> bool {
+        self.is_global
+    }
+}
+
+/// A decorator applied to a class, a class member, or a parameter.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct AppliedDecorator {
+    pub(crate) range: TextRange,
+    pub(crate) target_range: TextRange,
+    pub(crate) is_class: bool,
+    pub(crate) is_method: bool,
+    pub(crate) is_parameter: bool,
+}
+
+impl AppliedDecorator {
+    /// Range of the decorator, including the `@`
+    pub fn range(&self) -> TextRange {
+        self.range
+    }
+
+    /// Range of the decorated class, class member, or parameter
+    pub fn target_range(&self) -> TextRange {
+        self.target_range
+    }
+
+    /// Returns `true` if the decorator is applied to a class
+    pub fn is_class(&self) -> bool {
+        self.is_class
+    }
+
+    /// Returns `true` if the decorator is applied to a method, a getter, or a setter
+    pub fn is_method(&self) -> bool {
+        self.is_method
+    }
+
+    /// Returns `true` if the decorator is applied to a parameter,
+    /// including a parameter property of a constructor
+    pub fn is_parameter(&self) -> bool {
+        self.is_parameter
+    }
+}
+
+/// An `await` expression, which suspends the execution of its enclosing async function.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct AwaitPoint {
+    pub(crate) range: TextRange,
+    pub(crate) function_range: TextRange,
+}
+
+impl AwaitPoint {
+    /// Range of the `await` expression
+    pub fn range(&self) -> TextRange {
+        self.range
+    }
+
+    /// Range of the async function, method, or arrow function that is suspended
+    pub fn function_range(&self) -> TextRange {
+        self.function_range
+    }
+}
+
+/// A label of a labeled statement, and the `break` and `continue` statements that jump to it.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Label {
+    pub(crate) range: TextRange,
+    pub(crate) name: TokenText,
+    pub(crate) jumps: Vec<LabelJump>,
+}
+
+impl Label {
+    /// Trimmed range of the label
+    pub fn range(&self) -> TextRange {
+        self.range
+    }
+
+    /// The name of the label
+    pub fn name(&self) -> &TokenText {
+        &self.name
+    }
+
+    /// The `break` and `continue` statements that jump to this label, in source order
+    pub fn jumps(&self) -> &[LabelJump] {
+        &self.jumps
+    }
+}
+
+/// A `break` or a `continue` statement that jumps to a [Label].
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct LabelJump {
+    pub(crate) range: TextRange,
+    pub(crate) is_break: bool,
+}
+
+impl LabelJump {
+    /// Range of the `break` or `continue` statement
+    pub fn range(&self) -> TextRange {
+        self.range
+    }
+
+    /// Returns `true` if the jump is a `break` statement, rather than a `continue` statement
+    pub fn is_break(&self) -> bool {
+        self.is_break
+    }
+}
+
+/// An access to a well-known symbol of the global `Symbol` object, such as `Symbol.iterator`.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct WellKnownSymbolAccess {
+    pub(crate) range: TextRange,
+    pub(crate) symbol: WellKnownSymbol,
+}
+
+impl WellKnownSymbolAccess {
+    /// Range of the member expression
+    pub fn range(&self) -> TextRange {
+        self.range
+    }
+
+    /// The accessed well-known symbol
+    pub fn symbol(&self) -> WellKnownSymbol {
+        self.symbol
+    }
+}
+
 /// Contains all the data of the [SemanticModel] and only lives behind an [Arc].
 ///
 /// That allows any returned struct (like [Scope], [Binding])
@@ -47,6 +194,30 @@ pub(crate) struct SemanticModelData {
     pub(crate) bindings_by_start: FxHashMap<TextSize, usize>,
     // All bindings that were exported
     pub(crate) exported: FxHashSet<TextSize>,
+    // All bindings that are the default export of the module
+    pub(crate) default_exported: FxHashSet<TextSize>,
+    // The exported names and the ranges of their bindings, ordered by range
+    pub(crate) exported_bindings: Vec<(TokenText, TextRange)>,
+    // The bindings declared in a `declare global {}` block, in source order
+    pub(crate) global_declarations: Vec<BindingIndex>,
+    // Maps the range of an expression to the range of the type it satisfies
+    pub(crate) constraint_by_range: FxHashMap<TextRange, TextRange>,
+    // The trimmed ranges of the references in a type position
+    pub(crate) type_references: FxHashSet<TextRange>,
+    // Maps a scope id to the ranges of the type parameters declared by the scope
+    pub(crate) type_parameters_by_scope: FxHashMap<usize, Vec<TextRange>>,
+    // All the symbols created by `Symbol()` and `Symbol.for()`
+    pub(crate) symbol_creations: Vec<SymbolCreation>,
+    // All the decorators, in source order
+    pub(crate) decorators: Vec<AppliedDecorator>,
+    // All the `await` expressions inside async functions, in source order
+    pub(crate) await_points: Vec<AwaitPoint>,
+    // All the accesses to the well-known symbols of `Symbol`, in source order
+    pub(crate) well_known_symbol_accesses: Vec<WellKnownSymbolAccess>,
+    // All the labels of labeled statements, in source order
+    pub(crate) labels: Vec<Label>,
+    // Maps the range of the name of a `const enum` member to its value
+    pub(crate) const_enum_values: FxHashMap<TextRange, ConstEnumValue>,
     /// All references that could not be resolved
     pub(crate) unresolved_references: Vec<SemanticModelUnresolvedReference>,
     /// All globals references
@@ -318,6 +489,355 @@ impl SemanticModel {
         node.is_imported(self)
     }
 
+    /// Returns the range of the type that the expression at `expr_range` is checked against
+    /// with the `satisfies` operator.
+    ///
+    /// Unlike a type annotation, `satisfies` does not change the type of the expression.
+    /// The range must be the trimmed range of the expression.
+    ///
+    /// ```ts
+    /// const config = { port: 80 } satisfies Config;
+    /// //             ^^^^^^^^^^^^              ^^^^^^
+    /// ```
+    pub fn constraint_for(&self, expr_range: TextRange) -> Option<TextRange> {
+        self.data.constraint_by_range.get(&expr_range).copied()
+    }
+
+    /// Returns `true` if the reference at `range` reads a type, rather than a value.
+    ///
+    /// The range must be the trimmed range of the reference identifier.
+    /// Unresolved references are included.
+    /// The references of `typeof x` type queries read a value, so they aren't in a type position.
+    ///
+    /// ```ts
+    /// type T = Foo<typeof bar>;
+    /// //       ^^^ true  ^^^ false
+    /// ```
+    pub fn is_in_type_position(&self, range: TextRange) -> bool {
+        self.data.type_references.contains(&range)
+    }
+
+    /// Returns the ranges of the type parameters declared by the closest generic function,
+    /// class, interface, or type alias that encloses `node`, including `node` itself.
+    ///
+    /// The type parameters of signatures that don't have their own scope, such as method
+    /// signatures and function types, are attributed to the enclosing declaration.
+    ///
+    /// ```ts
+    /// function f<T, U extends T[]>(x: T, y: U) {}
+    /// //         ^  ^
+    /// ```
+    pub fn type_parameters_of(&self, node: &JsSyntaxNode) -> Vec<TextRange> {
+        let mut scope_id = Some(self.data.scope(&node.text_range()));
+        while let Some(id) = scope_id {
+            if let Some(type_parameters) = self.data.type_parameters_by_scope.get(&id) {
+                return type_parameters.clone();
+            }
+            scope_id = self.data.scopes[id].parent;
+        }
+        Vec::new()
+    }
+
+    /// Returns all the calls to the global `Symbol` object that create a symbol, in source order.
+    ///
+    /// Every `Symbol()` call creates a distinct symbol,
+    /// while `Symbol.for()` calls with the same key share a symbol of the global registry.
+    /// Calls to a local binding named `Symbol` aren't included.
+    ///
+    /// ```js
+    /// const a = Symbol("a");
+    /// //        ^^^^^^^^^^^
+    /// const b = Symbol.for("b");
+    /// //        ^^^^^^^^^^^^^^^
+    /// ```
+    pub fn symbol_creations(&self) -> &[SymbolCreation] {
+        &self.data.symbol_creations
+    }
+
+    /// Returns all the decorators, in source order.
+    ///
+    /// A decorator that isn't applied to a class, a method, or a parameter
+    /// is applied to a class property.
+    ///
+    /// ```ts
+    /// @Component()
+    /// class A {
+    ///     @Input() b: string;
+    /// }
+    /// ```
+    pub fn decorators(&self) -> &[AppliedDecorator] {
+        &self.data.decorators
+    }
+
+    /// Returns all the `await` expressions inside async functions, in source order.
+    ///
+    /// An `await` expression suspends its nearest enclosing function,
+    /// even when this function is an arrow function.
+    /// Top-level `await` expressions aren't included.
+    ///
+    /// ```js
+    /// async function f() {
+    ///     for (const url of urls) {
+    ///         await fetch(url);
+    ///     }
+    /// }
+    /// ```
+    pub fn await_points(&self) -> &[AwaitPoint] {
+        &self.data.await_points
+    }
+
+    /// Returns all the accesses to the well-known symbols of the global `Symbol` object, in source order.
+    ///
+    /// Accesses through a local binding named `Symbol` aren't included.
+    ///
+    /// ```js
+    /// class List {
+    ///     *[Symbol.iterator]() {}
+    /// //    ^^^^^^^^^^^^^^^
+    /// }
+    /// ```
+    pub fn well_known_symbol_accesses(&self) -> &[WellKnownSymbolAccess] {
+        &self.data.well_known_symbol_accesses
+    }
+
+    /// Returns all the labels of labeled statements, in source order,
+    /// with the labeled `break` and `continue` statements that jump to them.
+    ///
+    /// A label without jumps is unused.
+    ///
+    /// ```js
+    /// outer: for (const row of rows) {
+    /// // ^^^
+    ///     for (const cell of row) {
+    ///         if (cell) break outer;
+    /// //                ^^^^^^^^^^^^
+    ///     }
+    /// }
+    /// ```
+    pub fn labels(&self) -> &[Label] {
+        &self.data.labels
+    }
+
+    /// Returns the value of a member of a `const enum`, which the TypeScript compiler inlines.
+    ///
+    /// `range` is either the range of the name of the member in its declaration,
+    /// or the range of a member expression that accesses it, such as `Direction.Up`.
+    /// Returns `None` when the value of the member can't be evaluated statically.
+    ///
+    /// ```ts
+    /// const enum Direction { Up = 1, Down }
+    /// //                                ^^^^ Integer(2)
+    /// move(Direction.Down);
+    /// //   ^^^^^^^^^^^^^^ Integer(2)
+    /// ```
+    pub fn constant_value_of(&self, range: TextRange) -> Option<ConstEnumValue> {
+        if let Some(value) = self.data.const_enum_values.get(&range) {
+            return Some(value.clone());
+        }
+        let node = match self.data.root.syntax().covering_element(range) {
+            NodeOrToken::Node(node) => node,
+            NodeOrToken::Token(token) => token.parent()?,
+        };
+        let member = node
+            .ancestors()
+            .filter_map(AnyJsMemberExpression::cast)
+            .find(|member| member.range() == range)?;
+        let AnyJsExpression::JsIdentifierExpression(object) = member.object().ok()? else {
+            return None;
+        };
+        let declaration = self
+            .binding(&object.name().ok()?)?
+            .syntax()
+            .parent()
+            .and_then(TsEnumDeclaration::cast)?;
+        let member_name = member.member_name()?;
+        declaration
+            .members()
+            .iter()
+            .flatten()
+            .find_map(|enum_member| {
+                let name = enum_member.name().ok()?;
+                let name = name.as_js_literal_member_name()?;
+                if name.name().ok()?.text() != member_name.text() {
+                    return None;
+                }
+                let name_range = name.value().ok()?.text_trimmed_range();
+                self.data.const_enum_values.get(&name_range).cloned()
+            })
+    }
+
+    /// Returns `true` if the binding at `range` is the default export of the module.
+    ///
+    /// The range must be the range of the binding.
+    /// Bindings that are exported under another name, or with `export =`, aren't default exports.
+    ///
+    /// ```js
+    /// export default function f() {}
+    /// //                      ^ true
+    /// const a = 0, b = 1;
+    /// //    ^ true ^ false
+    /// export default a;
+    /// export { b };
+    /// ```
+    pub fn is_default_export(&self, range: TextRange) -> bool {
+        self.data.default_exported.contains(&range.start())
+    }
+
+    /// Returns the names exported by the module, and the ranges of the exported bindings.
+    ///
+    /// A binding that is exported under several names appears once for each name.
+    /// Default exports are exported under the name `default`.
+    /// Re-exports don't have a local binding: their range is the one of the exported name.
+    ///
+    /// ```js
+    /// export const a = 0;
+    /// //           ^ ("a", range of `a`)
+    /// function f() {}
+    /// //       ^ ("g", range of `f`)
+    /// export { f as g };
+    /// export { h as i } from "mod";
+    /// //            ^ ("i", range of `i`)
+    /// ```
+    pub fn exported_bindings(&self) -> Vec<(TokenText, TextRange)> {
+        self.data.exported_bindings.clone()
+    }
+
+    /// Returns the bindings declared at the top level of a `declare global {}` block, in source order.
+    ///
+    /// These bindings augment the global scope, even when the block is nested
+    /// in a module declaration.
+    ///
+    /// ```ts
+    /// declare module "mod" {
+    ///     global {
+    ///         var version: string;
+    /// //          ^^^^^^^
+    ///     }
+    /// }
+    /// ```
+    pub fn global_declarations(&self) -> impl Iterator<Item = Binding> + '_ {
+        self.data.global_declarations.iter().map(|index| Binding {
+            data: self.data.clone(),
+            index: *index,
+        })
+    }
+
+    /// Returns the names and the ranges of all the bindings visible in `scope`,
+    /// from the innermost scope to the global scope.
+    ///
+    /// A name shadowed by an inner scope is only yielded once, with the range of the innermost binding.
+    /// The bindings of the enclosing scopes that are declared after the start of the scope aren't
+    /// yielded, unless they are hoisted like `var` and function declarations.
+    ///
+    /// ```js
+    /// let a = 0;
+    /// function f(b) {
+    ///     var c;
+    ///     // `a`, `b`, `c`, `d`, and `f` are visible here
+    /// }
+    /// let e = 0;
+    /// function d() {}
+    /// ```
+    pub fn all_bindings_in_scope(
+        &self,
+        scope: &Scope,
+    ) -> impl Iterator<Item = (TokenText, TextRange)> + '_ {
+        let scope_id = scope.id;
+        let scope_start = self.data.scopes[scope_id].range.start();
+        let mut names = FxHashSet::default();
+        std::iter::successors(Some(scope_id), |id| self.data.scopes[*id].parent)
+            .flat_map(|id| {
+                self.data.scopes[id]
+                    .declarations
+                    .iter()
+                    .map(move |declaration| (id, declaration))
+            })
+            .filter_map(move |(id, declaration)| {
+                // A binding that isn't visible yet still shadows the bindings of the outer scopes
+                if !names.insert(declaration.name.clone()) {
+                    return None;
+                }
+                let is_visible = id == scope_id
+                    || declaration.is_hoisted
+                    || declaration.range.start() < scope_start;
+                is_visible.then(|| (declaration.name.clone(), declaration.range))
+            })
+    }
+
+    /// Returns `true` if the binding at `binding_range` is assigned before it's read.
+    ///
+    /// The range must be the range of the binding.
+    /// Only the variables declared without an initializer can be read before being assigned:
+    /// the other bindings, such as parameters and functions, and the variables declared with
+    /// a definite assignment assertion (`let x!: string`) are always definitely assigned.
+    ///
+    /// This is an approximation that doesn't use the control flow: a variable is considered
+    /// definitely assigned if a write precedes its first read in source order,
+    /// even if the write is in a branch that isn't always executed.
+    ///
+    /// ```ts
+    /// let a: string;
+    /// //  ^ true
+    /// if (cond) { a = "x"; } else { a = "y"; }
+    /// f(a);
+    /// let b: string;
+    /// //  ^ false
+    /// f(b);
+    /// b = "z";
+    /// ```
+    pub fn is_definitely_assigned(&self, binding_range: TextRange) -> bool {
+        let Some(index) = self.data.bindings_by_start.get(&binding_range.start()) else {
+            return false;
+        };
+        let binding = Binding {
+            data: self.data.clone(),
+            index: (*index).into(),
+        };
+        let Some(AnyJsBindingDeclaration::JsVariableDeclarator(declarator)) =
+            binding.tree().declaration()
+        else {
+            return true;
+        };
+        let is_uninitialized = declarator.initializer().is_none()
+            && !matches!(
+                declarator.variable_annotation(),
+                Some(AnyTsVariableAnnotation::TsDefiniteVariableAnnotation(_))
+            )
+            // `for (const x of xs)` and `declare let x: string`
+            && !declarator.syntax().ancestors().any(|ancestor| {
+                JsForVariableDeclaration::can_cast(ancestor.kind())
+                    || TsDeclareStatement::can_cast(ancestor.kind())
+            });
+        if !is_uninitialized {
+            return true;
+        }
+
+        let references = &self.data.binding(binding.index).references;
+        // Compound assignments, such as `a += 1`, read the variable before writing it
+        let is_compound_write = |range: &TextRange| {
+            self.data.node_by_range[range]
+                .parent()
+                .is_some_and(|parent| {
+                    JsPreUpdateExpression::can_cast(parent.kind())
+                        || JsPostUpdateExpression::can_cast(parent.kind())
+                        || JsAssignmentExpression::cast(parent).is_some_and(|assignment| {
+                            !matches!(assignment.operator(), Ok(JsAssignmentOperator::Assign))
+                        })
+                })
+        };
+        let Some(first_read) = references
+            .iter()
+            .filter(|reference| reference.is_read() || is_compound_write(&reference.range))
+            .map(|reference| reference.range.start())
+            .min()
+        else {
+            return true;
+        };
+        references
+            .iter()
+            .any(|reference| reference.is_write() && reference.range.start() < first_read)
+    }
+
     /// Returns the [Closure] associated with the node.
     pub fn closure(&self, node: &impl HasClosureAstNode) -> Closure {
         Closure::from_node(self.data.clone(), node)
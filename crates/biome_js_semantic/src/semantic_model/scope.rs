@@ -16,6 +16,8 @@ pub(crate) struct SemanticModelScopeData {
     pub(crate) bindings: Vec<usize>,
     // Map pointing to the [bindings] vec of each bindings by its name
     pub(crate) bindings_by_name: FxHashMap<TokenText, usize>,
+    // All declarations of this scope, including the ones hoisted from inner scopes, in source order
+    pub(crate) declarations: Vec<ScopeDeclaration>,
     // All read references of a scope
     pub(crate) read_references: Vec<SemanticModelScopeReference>,
     // All write references of a scope
@@ -24,6 +26,16 @@ pub(crate) struct SemanticModelScopeData {
     pub(crate) is_closure: bool,
 }
 
+/// A declaration bound in a scope.
+#[derive(Debug)]
+pub(crate) struct ScopeDeclaration {
+    pub(crate) name: TokenText,
+    // The trimmed range of the declared name
+    pub(crate) range: TextRange,
+    // Whether the binding can be used before its declaration, like `var` and function declarations
+    pub(crate) is_hoisted: bool,
+}
+
 /// Provides all information regarding a specific scope.
 /// Allows navigation to parent and children scope and binding information.
 #[derive(Clone, Debug)]
@@ -41,6 +53,11 @@ impl PartialEq for Scope {
 impl Eq for Scope {}
 
 impl Scope {
+    /// Returns the id of this scope, unique within its semantic model.
+    pub fn id(&self) -> usize {
+        self.id
+    }
+
     /// Returns all parents of this scope. Starting with the current
     /// [Scope].
     pub fn ancestors(&self) -> impl Iterator<Item = Scope> {
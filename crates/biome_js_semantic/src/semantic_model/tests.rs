@@ -1,13 +1,14 @@
 #[cfg(test)]
 mod test {
     use crate::{
-        semantic_model, BindingExtensions, CanBeImportedExported, SemanticModelOptions,
-        SemanticScopeExtensions,
+        semantic_model, this_binding_kind, BindingExtensions, CanBeImportedExported,
+        ConstEnumValue, SemanticModelOptions, SemanticScopeExtensions, ThisBindingKind,
+        WellKnownSymbol,
     };
     use biome_js_parser::JsParserOptions;
     use biome_js_syntax::{
         JsFileSource, JsIdentifierAssignment, JsIdentifierBinding, JsReferenceIdentifier,
-        JsSyntaxKind, TsIdentifierBinding,
+        JsSyntaxKind, TextRange, TextSize, TsIdentifierBinding,
     };
     use biome_rowan::{AstNode, SyntaxNodeCast};
 
@@ -288,4 +289,732 @@ mod test {
         assert!(globals[0].is_read());
         assert_eq!(globals[0].syntax().text_trimmed(), "console");
     }
+
+    #[test]
+    pub fn ok_semantic_model_constraint_for() {
+        let code = "const a = { b: (x satisfies X) } satisfies A; const d = y as D;";
+        let r = biome_js_parser::parse(code, JsFileSource::ts(), JsParserOptions::default());
+        let model = semantic_model(&r.tree(), SemanticModelOptions::default());
+
+        let constraint_of = |expression: &str| {
+            let start = code.find(expression).unwrap();
+            let range = TextRange::at(
+                TextSize::from(start as u32),
+                TextSize::from(expression.len() as u32),
+            );
+            model
+                .constraint_for(range)
+                .map(|constraint| &code[constraint])
+        };
+
+        assert_eq!(constraint_of("{ b: (x satisfies X) }"), Some("A"));
+        assert_eq!(constraint_of("x"), Some("X"));
+        assert_eq!(constraint_of("(x satisfies X)"), None);
+        assert_eq!(constraint_of("y"), None);
+    }
+
+    #[test]
+    pub fn ok_semantic_model_is_in_type_position() {
+        let code = r#"
+class Foo {}
+const bar = new Foo();
+type Generic = Map<Foo, Array<Key>>;
+type Query = typeof bar | Foo;
+type Conditional<T> = T extends Foo ? Yes : No;
+type Infer<T> = T extends Promise<infer U> ? U : never;
+let x: Foo = bar;
+"#;
+        let r = biome_js_parser::parse(code, JsFileSource::ts(), JsParserOptions::default());
+        let model = semantic_model(&r.tree(), SemanticModelOptions::default());
+
+        // Tells whether the `nth` reference named `name` is in a type position
+        let is_in_type_position = |name: &str, nth: usize| {
+            let reference = r
+                .syntax()
+                .descendants()
+                .filter_map(JsReferenceIdentifier::cast)
+                .filter(|reference| reference.syntax().text_trimmed() == name)
+                .nth(nth)
+                .unwrap();
+            model.is_in_type_position(reference.syntax().text_trimmed_range())
+        };
+
+        // Value reference
+        assert!(!is_in_type_position("Foo", 0));
+        // Generic type arguments, including unresolved references
+        assert!(is_in_type_position("Map", 0));
+        assert!(is_in_type_position("Foo", 1));
+        assert!(is_in_type_position("Array", 0));
+        assert!(is_in_type_position("Key", 0));
+        // `typeof` queries read a value
+        assert!(!is_in_type_position("bar", 0));
+        assert!(is_in_type_position("Foo", 2));
+        // Conditional types
+        assert!(is_in_type_position("T", 0));
+        assert!(is_in_type_position("Foo", 3));
+        assert!(is_in_type_position("Yes", 0));
+        assert!(is_in_type_position("No", 0));
+        // `infer` clauses
+        assert!(is_in_type_position("Promise", 0));
+        assert!(is_in_type_position("U", 0));
+        // Type annotation and initializer
+        assert!(is_in_type_position("Foo", 4));
+        assert!(!is_in_type_position("bar", 1));
+    }
+
+    #[test]
+    pub fn ok_semantic_model_type_parameters_of() {
+        let code = r#"
+function f<A, B extends A[]>(a: A) { const inner = () => a; }
+class C<D extends { key: keyof D }> { m<E>(e: E) { return e; } n() {} }
+interface I<F, G = F> { p: F; }
+type T<H extends string, J extends H extends `${infer K}` ? K : never> = { [L in H]: J };
+function g(x: number) {}
+"#;
+        let r = biome_js_parser::parse(code, JsFileSource::ts(), JsParserOptions::default());
+        let model = semantic_model(&r.tree(), SemanticModelOptions::default());
+
+        let type_parameters_of = |kind: JsSyntaxKind, text: &str| {
+            let node = r
+                .syntax()
+                .descendants()
+                .find(|node| node.kind() == kind && node.text_trimmed() == text)
+                .unwrap();
+            model
+                .type_parameters_of(&node)
+                .into_iter()
+                .map(|range| &code[range])
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            type_parameters_of(JsSyntaxKind::JS_REFERENCE_IDENTIFIER, "a"),
+            ["A", "B"]
+        );
+        assert_eq!(
+            type_parameters_of(JsSyntaxKind::JS_REFERENCE_IDENTIFIER, "e"),
+            ["E"]
+        );
+        assert_eq!(
+            type_parameters_of(JsSyntaxKind::JS_METHOD_CLASS_MEMBER, "n() {}"),
+            ["D"]
+        );
+        assert_eq!(
+            type_parameters_of(JsSyntaxKind::TS_PROPERTY_SIGNATURE_TYPE_MEMBER, "p: F;"),
+            ["F", "G"]
+        );
+        assert_eq!(
+            type_parameters_of(JsSyntaxKind::TS_REFERENCE_TYPE, "J"),
+            ["H", "J"]
+        );
+        assert!(type_parameters_of(JsSyntaxKind::JS_IDENTIFIER_BINDING, "x").is_empty());
+    }
+
+    #[test]
+    pub fn ok_semantic_model_this_binding_kind() {
+        let code = r#"
+this.module;
+class A extends (this.base) {
+    constructor() { this.constructor; }
+    method() { this.method; const f = () => () => this.arrow; }
+    get getter() { return this.getter; }
+    property = this.property;
+    [this.key] = 1;
+    static { this.block; }
+}
+const object = { method() { return this.object; } };
+function f() { this.function; }
+export default function () { this.defaultFunction; }
+const g = function () { this.functionExpression; };
+const h = () => this.topLevelArrow;
+"#;
+        let r = biome_js_parser::parse(code, JsFileSource::js_module(), JsParserOptions::default());
+        let model = semantic_model(&r.tree(), SemanticModelOptions::default());
+
+        let this_binding_kind_of = |member: &str| {
+            let member_expression = format!("this.{member}");
+            let node = r
+                .syntax()
+                .descendants()
+                .find(|node| {
+                    node.kind() == JsSyntaxKind::JS_THIS_EXPRESSION
+                        && node.parent().is_some_and(|parent| {
+                            parent.text_trimmed().to_string() == member_expression
+                        })
+                })
+                .unwrap();
+            this_binding_kind(&node, &model)
+        };
+
+        assert_eq!(this_binding_kind_of("module"), ThisBindingKind::Module);
+        assert_eq!(this_binding_kind_of("base"), ThisBindingKind::Module);
+        assert_eq!(
+            this_binding_kind_of("constructor"),
+            ThisBindingKind::ConstructorMethod
+        );
+        assert_eq!(this_binding_kind_of("method"), ThisBindingKind::ClassMethod);
+        assert_eq!(this_binding_kind_of("getter"), ThisBindingKind::ClassMethod);
+        assert_eq!(
+            this_binding_kind_of("property"),
+            ThisBindingKind::ClassInitializer
+        );
+        assert_eq!(this_binding_kind_of("key"), ThisBindingKind::Module);
+        assert_eq!(
+            this_binding_kind_of("block"),
+            ThisBindingKind::ClassInitializer
+        );
+        assert_eq!(
+            this_binding_kind_of("object"),
+            ThisBindingKind::ObjectMethod
+        );
+        assert_eq!(
+            this_binding_kind_of("function"),
+            ThisBindingKind::RegularFunction
+        );
+        assert_eq!(
+            this_binding_kind_of("defaultFunction"),
+            ThisBindingKind::RegularFunction
+        );
+        assert_eq!(
+            this_binding_kind_of("functionExpression"),
+            ThisBindingKind::RegularFunction
+        );
+
+        let ThisBindingKind::ArrowFunction { captures_this_from } = this_binding_kind_of("arrow")
+        else {
+            panic!("`this.arrow` should be in an arrow function");
+        };
+        assert_eq!(
+            captures_this_from.syntax().kind(),
+            JsSyntaxKind::JS_METHOD_CLASS_MEMBER
+        );
+        let ThisBindingKind::ArrowFunction { captures_this_from } =
+            this_binding_kind_of("topLevelArrow")
+        else {
+            panic!("`this.topLevelArrow` should be in an arrow function");
+        };
+        assert_eq!(captures_this_from.syntax().kind(), JsSyntaxKind::JS_MODULE);
+
+        let r = biome_js_parser::parse(
+            "this.script;",
+            JsFileSource::js_script(),
+            JsParserOptions::default(),
+        );
+        let model = semantic_model(&r.tree(), SemanticModelOptions::default());
+        let node = r
+            .syntax()
+            .descendants()
+            .find(|node| node.kind() == JsSyntaxKind::JS_THIS_EXPRESSION)
+            .unwrap();
+        assert_eq!(
+            this_binding_kind(&node, &model),
+            ThisBindingKind::GlobalScript
+        );
+    }
+
+    #[test]
+    pub fn ok_semantic_model_symbol_creations() {
+        let code = r#"
+            const a = Symbol("a");
+            const b = Symbol.for("b");
+            const c = (Symbol)();
+            const d = Symbol.iterator;
+            const e = new Symbol();
+            function f(Symbol) {
+                return Symbol("f");
+            }
+            class G {
+                g() {
+                    return Symbol();
+                }
+            }
+        "#;
+        let r = biome_js_parser::parse(code, JsFileSource::js_module(), JsParserOptions::default());
+        let model = semantic_model(&r.tree(), SemanticModelOptions::default());
+
+        let symbols: Vec<_> = model
+            .symbol_creations()
+            .iter()
+            .map(|symbol| (&code[symbol.range()], symbol.is_global()))
+            .collect();
+
+        assert_eq!(
+            symbols,
+            vec![
+                (r#"Symbol("a")"#, false),
+                (r#"Symbol.for("b")"#, true),
+                ("Symbol()", false),
+            ]
+        );
+    }
+
+    #[test]
+    pub fn ok_semantic_model_decorators() {
+        let code = r#"
+            import { Component, Input, Inject, Log } from "./decorators";
+            @Component()
+            class A {
+                @Input() b: string;
+                @Log c() {}
+                @Log get d() { return 0; }
+                constructor(@Inject() e: string, @Inject() private f: string) {}
+            }
+            @Component()
+            export class G {}
+        "#;
+        let r = biome_js_parser::parse(
+            code,
+            JsFileSource::ts(),
+            JsParserOptions::default().with_parse_class_parameter_decorators(),
+        );
+        let model = semantic_model(&r.tree(), SemanticModelOptions::default());
+
+        let decorators: Vec<_> = model
+            .decorators()
+            .iter()
+            .map(|decorator| {
+                (
+                    &code[decorator.range()],
+                    code[decorator.target_range()].split_whitespace().last(),
+                    decorator.is_class(),
+                    decorator.is_method(),
+                    decorator.is_parameter(),
+                )
+            })
+            .collect();
+
+        assert_eq!(
+            decorators,
+            vec![
+                ("@Component()", Some("}"), true, false, false),
+                ("@Input()", Some("string;"), false, false, false),
+                ("@Log", Some("{}"), false, true, false),
+                ("@Log", Some("}"), false, true, false),
+                ("@Inject()", Some("string"), false, false, true),
+                ("@Inject()", Some("string"), false, false, true),
+                ("@Component()", Some("{}"), true, false, false),
+            ]
+        );
+
+        // References in decorators are resolved to their declarations
+        let component = r
+            .syntax()
+            .descendants()
+            .find_map(JsIdentifierBinding::cast)
+            .unwrap();
+        let component = model.as_binding(&component);
+        assert_eq!(2, component.all_reads().count());
+    }
+
+    #[test]
+    pub fn ok_semantic_model_labels() {
+        let code = r#"
+            outer: for (const row of rows) {
+                inner: for (const cell of row) {
+                    if (cell) continue outer;
+                    break;
+                }
+                outer: {
+                    break outer;
+                }
+                break outer;
+            }
+            unused: {}
+        "#;
+        let r = biome_js_parser::parse(code, JsFileSource::js_module(), JsParserOptions::default());
+        let model = semantic_model(&r.tree(), SemanticModelOptions::default());
+
+        let labels: Vec<_> = model
+            .labels()
+            .iter()
+            .map(|label| {
+                (
+                    &code[label.range()],
+                    label.name().text(),
+                    label
+                        .jumps()
+                        .iter()
+                        .map(|jump| (&code[jump.range()], jump.is_break()))
+                        .collect::<Vec<_>>(),
+                )
+            })
+            .collect();
+
+        assert_eq!(
+            labels,
+            vec![
+                (
+                    "outer",
+                    "outer",
+                    vec![("continue outer;", false), ("break outer;", true)]
+                ),
+                ("inner", "inner", vec![]),
+                ("outer", "outer", vec![("break outer;", true)]),
+                ("unused", "unused", vec![]),
+            ]
+        );
+    }
+
+    #[test]
+    pub fn ok_semantic_model_await_points() {
+        let code = r#"
+            await a();
+            async function f() {
+                await b();
+                const g = async () => await c();
+                function h() {
+                    class I {
+                        async j() { await d(); }
+                    }
+                }
+            }
+            const k = { async l() { for (;;) { await e(); } } };
+        "#;
+        let r = biome_js_parser::parse(code, JsFileSource::js_module(), JsParserOptions::default());
+        let model = semantic_model(&r.tree(), SemanticModelOptions::default());
+
+        let await_points: Vec<_> = model
+            .await_points()
+            .iter()
+            .map(|await_point| {
+                (
+                    &code[await_point.range()],
+                    code[await_point.function_range()]
+                        .trim_start()
+                        .split('(')
+                        .next(),
+                )
+            })
+            .collect();
+
+        assert_eq!(
+            await_points,
+            vec![
+                ("await b()", Some("async function f")),
+                ("await c()", Some("async ")),
+                ("await d()", Some("async j")),
+                ("await e()", Some("async l")),
+            ]
+        );
+    }
+
+    #[test]
+    pub fn ok_semantic_model_well_known_symbol_accesses() {
+        let code = r#"
+            class List {
+                *[Symbol.iterator]() {}
+                async *[Symbol.asyncIterator]() {}
+                get [Symbol.toStringTag]() { return "List"; }
+            }
+            const a = Symbol.for("a");
+            const b = Symbol.unknown;
+            const c = Symbol?.iterator;
+            function d(Symbol) {
+                return Symbol.iterator;
+            }
+        "#;
+        let r = biome_js_parser::parse(code, JsFileSource::js_module(), JsParserOptions::default());
+        let model = semantic_model(&r.tree(), SemanticModelOptions::default());
+
+        let accesses: Vec<_> = model
+            .well_known_symbol_accesses()
+            .iter()
+            .map(|access| (&code[access.range()], access.symbol()))
+            .collect();
+
+        assert_eq!(
+            accesses,
+            vec![
+                ("Symbol.iterator", WellKnownSymbol::Iterator),
+                ("Symbol.asyncIterator", WellKnownSymbol::AsyncIterator),
+                ("Symbol.toStringTag", WellKnownSymbol::ToStringTag),
+                ("Symbol?.iterator", WellKnownSymbol::Iterator),
+            ]
+        );
+    }
+
+    #[test]
+    pub fn ok_semantic_model_exported_bindings() {
+        let code = r#"
+            export const a = 1, { b, c: d } = {};
+            export function f() {}
+            function g() {}
+            class C {}
+            interface I {}
+            export { g, g as h, C as "quoted name", I };
+            export default C;
+            export { x as y, z } from "mod";
+            export * as ns from "mod";
+            export * from "mod";
+            export { undeclared };
+        "#;
+        let r = biome_js_parser::parse(code, JsFileSource::ts(), JsParserOptions::default());
+        let model = semantic_model(&r.tree(), SemanticModelOptions::default());
+
+        let exported: Vec<_> = model
+            .exported_bindings()
+            .into_iter()
+            .map(|(name, range)| (name.to_string(), &code[range]))
+            .collect();
+
+        assert_eq!(
+            exported,
+            vec![
+                ("a".to_string(), "a"),
+                ("b".to_string(), "b"),
+                ("d".to_string(), "d"),
+                ("f".to_string(), "f"),
+                ("g".to_string(), "g"),
+                ("h".to_string(), "g"),
+                ("quoted name".to_string(), "C"),
+                ("default".to_string(), "C"),
+                ("I".to_string(), "I"),
+                ("y".to_string(), "y"),
+                ("z".to_string(), "z"),
+                ("ns".to_string(), "ns"),
+            ]
+        );
+
+        let default_export = "export default function f() {}";
+        let r = biome_js_parser::parse(
+            default_export,
+            JsFileSource::js_module(),
+            JsParserOptions::default(),
+        );
+        let model = semantic_model(&r.tree(), SemanticModelOptions::default());
+        let exported: Vec<_> = model
+            .exported_bindings()
+            .into_iter()
+            .map(|(name, range)| (name.to_string(), &default_export[range]))
+            .collect();
+        assert_eq!(exported, vec![("default".to_string(), "f")]);
+    }
+
+    #[test]
+    pub fn ok_semantic_model_is_default_export() {
+        let cases = [
+            ("export default function f() {}", vec![("f", true)]),
+            ("export default class C {}", vec![("C", true)]),
+            (
+                "const a = 0, b = 1; export default a; export { b };",
+                vec![("a", true), ("b", false)],
+            ),
+            (
+                "const a = 0, b = 1; export { a as default, b as c };",
+                vec![("a", true), ("b", false)],
+            ),
+            (
+                "export function f() {} export { f as default };",
+                vec![("f", true)],
+            ),
+            ("const a = 0; export = a;", vec![("a", false)]),
+            (
+                "export default function () { const a = 0; }",
+                vec![("a", false)],
+            ),
+        ];
+        for (code, expected) in cases {
+            let r = biome_js_parser::parse(code, JsFileSource::ts(), JsParserOptions::default());
+            let model = semantic_model(&r.tree(), SemanticModelOptions::default());
+            let default_exports: Vec<_> = model
+                .all_bindings()
+                .map(|binding| {
+                    let range = binding.syntax().text_trimmed_range();
+                    (&code[range], model.is_default_export(range))
+                })
+                .collect();
+            assert_eq!(default_exports, expected, "{code}");
+        }
+    }
+
+    #[test]
+    pub fn ok_semantic_model_is_definitely_assigned() {
+        let cases = [
+            // initialized declarations
+            ("let a = 0; f(a);", vec![("a", true)]),
+            ("let a!: string; f(a);", vec![("a", true)]),
+            ("function g(a) { f(a); }", vec![("g", true), ("a", true)]),
+            ("for (const a of b) { f(a); }", vec![("a", true)]),
+            // if/else initialization
+            (
+                "let a: string; if (c) { a = 'x'; } else { a = 'y'; } f(a);",
+                vec![("a", true)],
+            ),
+            // try/catch initialization
+            (
+                "let a; try { a = g(); } catch { a = null; } f(a);",
+                vec![("a", true)],
+            ),
+            // loop initialization
+            (
+                "let a; for (let i = 0; i < 3; i++) { a = i; } f(a);",
+                vec![("a", true), ("i", true)],
+            ),
+            // never read
+            ("let a; a = 0;", vec![("a", true)]),
+            // read before any write
+            ("let a: string; f(a); a = 'x';", vec![("a", false)]),
+            ("let a; a += 1;", vec![("a", false)]),
+            ("let a; a++;", vec![("a", false)]),
+            ("var a; f(a);", vec![("a", false)]),
+        ];
+        for (code, expected) in cases {
+            let r = biome_js_parser::parse(code, JsFileSource::ts(), JsParserOptions::default());
+            let model = semantic_model(&r.tree(), SemanticModelOptions::default());
+            let assigned: Vec<_> = model
+                .all_bindings()
+                .map(|binding| {
+                    let range = binding.syntax().text_trimmed_range();
+                    (&code[range], model.is_definitely_assigned(range))
+                })
+                .collect();
+            assert_eq!(assigned, expected, "{code}");
+        }
+    }
+
+    #[test]
+    pub fn ok_semantic_model_all_bindings_in_scope() {
+        let code = r#"
+            import { imported } from "mod";
+            const a = 1;
+            let shadowed = 2;
+            function outer(p1, p2) {
+                var hoisted;
+                let shadowed = 3;
+                function inner(q) {
+                    if (q) { var deep = 1; }
+                    completion;
+                }
+                let late = 4;
+            }
+            let after = 5;
+            var lateVar = 6;
+        "#;
+        let r = biome_js_parser::parse(code, JsFileSource::js_module(), JsParserOptions::default());
+        let model = semantic_model(&r.tree(), SemanticModelOptions::default());
+
+        let completion = r
+            .syntax()
+            .descendants()
+            .filter_map(|node| node.cast::<JsReferenceIdentifier>())
+            .find(|reference| reference.text() == "completion")
+            .unwrap();
+        let scope = completion.scope(&model);
+
+        let mut visible: Vec<_> = model
+            .all_bindings_in_scope(&scope)
+            .map(|(name, range)| (name.to_string(), range))
+            .collect();
+        visible.sort_by_key(|(name, _)| name.clone());
+
+        let shadowed_start = code.find("let shadowed = 3").unwrap() + "let ".len();
+        let names: Vec<_> = visible.iter().map(|(name, _)| name.as_str()).collect();
+        assert_eq!(
+            names,
+            vec![
+                "a", "deep", "hoisted", "imported", "inner", "lateVar", "outer", "p1", "p2", "q",
+                "shadowed"
+            ]
+        );
+        let (_, shadowed_range) = visible.iter().find(|(name, _)| name == "shadowed").unwrap();
+        assert_eq!(usize::from(shadowed_range.start()), shadowed_start);
+        assert_eq!(&code[*shadowed_range], "shadowed");
+    }
+
+    #[test]
+    pub fn ok_semantic_model_global_declarations() {
+        let code = r#"
+            declare global {
+                var version: string;
+                let build: number;
+                function log(message: string): void;
+                interface Window { state: unknown }
+                namespace NodeJS { interface Global {} }
+            }
+            declare module "mod" {
+                global {
+                    const nested: boolean;
+                }
+                let local: number;
+            }
+            let outside = 0;
+        "#;
+        let r = biome_js_parser::parse(code, JsFileSource::ts(), JsParserOptions::default());
+        let model = semantic_model(&r.tree(), SemanticModelOptions::default());
+
+        let names: Vec<_> = model
+            .global_declarations()
+            .map(|binding| binding.syntax().text_trimmed().to_string())
+            .collect();
+        assert_eq!(
+            names,
+            vec!["version", "build", "log", "Window", "NodeJS", "nested"]
+        );
+
+        let global_scope_id = model.global_scope().id();
+        for binding in model.global_declarations() {
+            let hoisted_to = model.scope_hoisted_to(binding.syntax()).unwrap();
+            assert_eq!(hoisted_to.id(), global_scope_id);
+        }
+    }
+
+    #[test]
+    pub fn ok_semantic_model_const_enum_values() {
+        let code = r#"
+            const enum Flags { None, Read = 1 << 0, Write = 1 << 1, ReadWrite = Read | Write }
+            const enum Level { Low = 10, Medium, High = Level.Medium * 2, Ratio = 0.5 }
+            const enum Name { Prefix = "app", Full = Name.Prefix + "-" + "name", Next }
+            const enum Derived { Max = Level["High"] + Flags.ReadWrite, Negative = -Max }
+            const enum Unknown { Computed = compute(), AfterComputed }
+            enum NotConst { A = 1 }
+            f(Level.High, Derived.Negative, NotConst.A);
+        "#;
+        let r = biome_js_parser::parse(code, JsFileSource::ts(), JsParserOptions::default());
+        let model = semantic_model(&r.tree(), SemanticModelOptions::default());
+
+        let value_of = |text: &str| {
+            let start = code.find(text).unwrap();
+            let range = TextRange::at(
+                TextSize::try_from(start).unwrap(),
+                TextSize::try_from(text.len()).unwrap(),
+            );
+            model.constant_value_of(range)
+        };
+
+        // auto-increment
+        assert_eq!(value_of("None"), Some(ConstEnumValue::Integer(0)));
+        assert_eq!(value_of("Medium"), Some(ConstEnumValue::Integer(11)));
+        // explicit numbers
+        assert_eq!(value_of("Low"), Some(ConstEnumValue::Integer(10)));
+        assert_eq!(value_of("Ratio"), Some(ConstEnumValue::Float(0.5)));
+        assert_eq!(value_of("Write"), Some(ConstEnumValue::Integer(2)));
+        // strings
+        assert_eq!(
+            value_of("Prefix"),
+            Some(ConstEnumValue::String("app".to_string()))
+        );
+        assert_eq!(
+            value_of("Full"),
+            Some(ConstEnumValue::String("app-name".to_string()))
+        );
+        // a string member can't be auto-incremented
+        assert_eq!(value_of("Next"), None);
+        // computed from other members
+        assert_eq!(value_of("ReadWrite"), Some(ConstEnumValue::Integer(3)));
+        assert_eq!(value_of("High"), Some(ConstEnumValue::Integer(22)));
+        assert_eq!(value_of("Max"), Some(ConstEnumValue::Integer(25)));
+        assert_eq!(value_of("Negative"), Some(ConstEnumValue::Integer(-25)));
+        // not statically evaluable
+        assert_eq!(value_of("Computed"), None);
+        assert_eq!(value_of("AfterComputed"), None);
+        assert_eq!(value_of("A = 1"), None);
+
+        // member expressions that access a member of a `const enum`
+        assert_eq!(value_of("Level.High"), Some(ConstEnumValue::Integer(22)));
+        assert_eq!(
+            value_of("Derived.Negative"),
+            Some(ConstEnumValue::Integer(-25))
+        );
+        assert_eq!(value_of("NotConst.A"), None);
+    }
 }
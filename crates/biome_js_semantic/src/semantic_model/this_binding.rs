@@ -0,0 +1,99 @@
+use super::*;
+use biome_js_syntax::JsSyntaxKind::*;
+
+/// What `this` refers to at a given location of the code.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ThisBindingKind {
+    /// `this` is the instance, or the class itself for static members, inside a method,
+    /// a getter, or a setter of a class.
+    ClassMethod,
+    /// `this` is the instance under construction inside a class constructor.
+    ConstructorMethod,
+    /// `this` is the instance, or the class itself for static members, inside a property
+    /// initializer or a static initialization block of a class.
+    ClassInitializer,
+    /// `this` is usually the object inside a method, a getter, or a setter of an object literal.
+    ObjectMethod,
+    /// `this` depends on how the function declaration or the function expression is called.
+    RegularFunction,
+    /// Arrow functions don't bind `this`.
+    /// `this` is the one of the closest enclosing scope that binds it.
+    ArrowFunction {
+        /// The scope of the function, the class, or the root that `this` is captured from.
+        captures_this_from: Scope,
+    },
+    /// `this` is `undefined` at the top level of a module.
+    Module,
+    /// `this` is the global object at the top level of a script.
+    GlobalScript,
+    /// The kind of binding cannot be determined.
+    Unknown,
+}
+
+/// Returns what `this` refers to at the location of `node`.
+///
+/// The scope chain is walked from the scope of `node` to the closest scope that binds `this`:
+/// a non-arrow function, a class member, or the root.
+///
+/// ```js
+/// class A {
+///     constructor() { this; }         // ConstructorMethod
+///     method() { () => this; }        // ArrowFunction { captures_this_from: <scope of method> }
+///     property = this;                // ClassInitializer
+/// }
+/// function f() { this; }              // RegularFunction
+/// this;                               // Module
+/// ```
+pub fn this_binding_kind(node: &JsSyntaxNode, model: &SemanticModel) -> ThisBindingKind {
+    let mut is_in_arrow_function = false;
+    for scope in model.scope(node).ancestors() {
+        let Some(scope_node) = scope.data.node_by_range.get(scope.range()) else {
+            continue;
+        };
+        let kind = match scope_node.kind() {
+            JS_ARROW_FUNCTION_EXPRESSION => {
+                is_in_arrow_function = true;
+                continue;
+            }
+            JS_CONSTRUCTOR_CLASS_MEMBER => ThisBindingKind::ConstructorMethod,
+            JS_METHOD_CLASS_MEMBER | JS_GETTER_CLASS_MEMBER | JS_SETTER_CLASS_MEMBER => {
+                ThisBindingKind::ClassMethod
+            }
+            JS_METHOD_OBJECT_MEMBER | JS_GETTER_OBJECT_MEMBER | JS_SETTER_OBJECT_MEMBER => {
+                ThisBindingKind::ObjectMethod
+            }
+            JS_FUNCTION_DECLARATION
+            | JS_FUNCTION_EXPORT_DEFAULT_DECLARATION
+            | JS_FUNCTION_EXPRESSION => ThisBindingKind::RegularFunction,
+            JS_CLASS_DECLARATION | JS_CLASS_EXPORT_DEFAULT_DECLARATION | JS_CLASS_EXPRESSION
+                if is_in_class_initializer(node, scope_node) =>
+            {
+                ThisBindingKind::ClassInitializer
+            }
+            JS_MODULE => ThisBindingKind::Module,
+            JS_SCRIPT => ThisBindingKind::GlobalScript,
+            _ => continue,
+        };
+        return if is_in_arrow_function {
+            ThisBindingKind::ArrowFunction {
+                captures_this_from: scope,
+            }
+        } else {
+            kind
+        };
+    }
+    ThisBindingKind::Unknown
+}
+
+/// Returns `true` if `node` is in a property initializer or a static initialization block of `class`.
+fn is_in_class_initializer(node: &JsSyntaxNode, class: &JsSyntaxNode) -> bool {
+    node.ancestors()
+        .take_while(|ancestor| ancestor != class)
+        .any(|ancestor| match ancestor.kind() {
+            JS_STATIC_INITIALIZATION_BLOCK_CLASS_MEMBER => true,
+            JS_INITIALIZER_CLAUSE => ancestor
+                .parent()
+                .is_some_and(|parent| parent.kind() == JS_PROPERTY_CLASS_MEMBER),
+            _ => false,
+        })
+}
@@ -0,0 +1,199 @@
+use crate::{
+    semantic_model, GlobalSemanticIndex, ImportSiteKind, SemanticModel, SemanticModelOptions,
+};
+use biome_js_parser::JsParserOptions;
+use biome_js_syntax::JsFileSource;
+
+fn index(files: &[(&str, &str)]) -> GlobalSemanticIndex {
+    let models: Vec<(&str, SemanticModel)> = files
+        .iter()
+        .map(|(path, code)| {
+            let parsed =
+                biome_js_parser::parse(code, JsFileSource::ts(), JsParserOptions::default());
+            (
+                *path,
+                semantic_model(&parsed.tree(), SemanticModelOptions::default()),
+            )
+        })
+        .collect();
+    GlobalSemanticIndex::new(models.iter().map(|(path, model)| (path, model)))
+}
+
+/// Returns the module and the kind of every use of the export `name` of `module`.
+fn uses(index: &GlobalSemanticIndex, module: &str, name: &str) -> Vec<(String, ImportSiteKind)> {
+    let export = index
+        .exports(&module.into())
+        .iter()
+        .find(|export| export.name == name)
+        .unwrap_or_else(|| panic!("{module} doesn't export {name}"));
+    let mut uses: Vec<_> = index
+        .find_all_uses(export)
+        .into_iter()
+        .map(|site| (site.module.to_string(), site.kind))
+        .collect();
+    uses.sort_by(|a, b| a.0.cmp(&b.0));
+    uses
+}
+
+#[test]
+fn global_index_exports() {
+    let index = index(&[(
+        "src/a.ts",
+        r#"
+        export const a = 1, { b, c: [d] } = {};
+        export function f(parameter) {}
+        export interface I {}
+        const local = 1;
+        export { local, local as renamed };
+        export { x as y } from "./b";
+        export * as ns from "./b";
+        export default class {}
+        "#,
+    )]);
+    let mut names: Vec<_> = index
+        .exports(&"src/a.ts".into())
+        .iter()
+        .map(|export| export.name.as_str())
+        .collect();
+    names.sort_unstable();
+    assert_eq!(
+        names,
+        ["I", "a", "b", "d", "default", "f", "local", "ns", "renamed", "y"]
+    );
+}
+
+#[test]
+fn global_index_named_and_default_imports() {
+    let index = index(&[
+        (
+            "src/a.ts",
+            "export const a = 1; export default function f() {}",
+        ),
+        ("src/b.ts", r#"import f, { a } from "./a";"#),
+        ("src/nested/c.ts", r#"import { a as renamed } from "../a";"#),
+        (
+            "src/d.ts",
+            r#"import { a } from "./other"; import { a as b } from "a";"#,
+        ),
+    ]);
+    assert_eq!(
+        uses(&index, "src/a.ts", "a"),
+        [
+            ("src/b.ts".to_string(), ImportSiteKind::Named),
+            ("src/nested/c.ts".to_string(), ImportSiteKind::Named),
+        ]
+    );
+    assert_eq!(
+        uses(&index, "src/a.ts", "default"),
+        [("src/b.ts".to_string(), ImportSiteKind::Named)]
+    );
+}
+
+#[test]
+fn global_index_resolution() {
+    let index = index(&[
+        ("src/a.ts", "export const a = 1;"),
+        ("src/lib/index.ts", "export const b = 1;"),
+        (
+            "src/c.ts",
+            r#"import { a } from "./a.js"; import { b } from "./lib";"#,
+        ),
+    ]);
+    assert_eq!(
+        uses(&index, "src/a.ts", "a"),
+        [("src/c.ts".to_string(), ImportSiteKind::Named)]
+    );
+    assert_eq!(
+        uses(&index, "src/lib/index.ts", "b"),
+        [("src/c.ts".to_string(), ImportSiteKind::Named)]
+    );
+}
+
+#[test]
+fn global_index_re_exports() {
+    let index = index(&[
+        (
+            "src/a.ts",
+            "export const a = 1; export const b = 1; export default 1;",
+        ),
+        (
+            "src/index.ts",
+            r#"export { a as renamed } from "./a"; export * from "./a";"#,
+        ),
+        ("src/c.ts", r#"import { renamed } from "./index";"#),
+        ("src/d.ts", r#"import { b } from "."; import def from ".";"#),
+    ]);
+    assert_eq!(
+        uses(&index, "src/a.ts", "a"),
+        [
+            ("src/c.ts".to_string(), ImportSiteKind::Named),
+            ("src/index.ts".to_string(), ImportSiteKind::ReExport),
+            ("src/index.ts".to_string(), ImportSiteKind::ReExport),
+        ]
+    );
+    assert_eq!(
+        uses(&index, "src/a.ts", "b"),
+        [
+            ("src/d.ts".to_string(), ImportSiteKind::Named),
+            ("src/index.ts".to_string(), ImportSiteKind::ReExport),
+        ]
+    );
+    assert_eq!(uses(&index, "src/a.ts", "default"), []);
+}
+
+#[test]
+fn global_index_re_export_cycle() {
+    let index = index(&[
+        ("src/a.ts", r#"export const a = 1; export * from "./b";"#),
+        ("src/b.ts", r#"export * from "./a";"#),
+    ]);
+    assert_eq!(
+        uses(&index, "src/a.ts", "a"),
+        [
+            ("src/a.ts".to_string(), ImportSiteKind::ReExport),
+            ("src/b.ts".to_string(), ImportSiteKind::ReExport),
+        ]
+    );
+}
+
+#[test]
+fn global_index_namespace_imports() {
+    let index = index(&[
+        ("src/a.ts", "export const a = 1; export type T = 1;"),
+        (
+            "src/b.ts",
+            r#"import * as ns from "./a"; ns.a; ns["a"]; let t: ns.T;"#,
+        ),
+        ("src/c.ts", r#"import * as ns from "./a"; console.log(ns);"#),
+    ]);
+    assert_eq!(
+        uses(&index, "src/a.ts", "a"),
+        [
+            ("src/b.ts".to_string(), ImportSiteKind::NamespaceMember),
+            ("src/b.ts".to_string(), ImportSiteKind::NamespaceMember),
+            ("src/c.ts".to_string(), ImportSiteKind::Namespace),
+        ]
+    );
+    assert_eq!(
+        uses(&index, "src/a.ts", "T"),
+        [
+            ("src/b.ts".to_string(), ImportSiteKind::NamespaceMember),
+            ("src/c.ts".to_string(), ImportSiteKind::Namespace),
+        ]
+    );
+}
+
+#[test]
+fn global_index_dynamic_imports() {
+    let index = index(&[
+        ("src/a.ts", "export const a = 1;"),
+        (
+            "src/b.ts",
+            r#"async function f() { const { a } = await import("./a"); import(name); }"#,
+        ),
+    ]);
+    assert_eq!(
+        uses(&index, "src/a.ts", "a"),
+        [("src/b.ts".to_string(), ImportSiteKind::Dynamic)]
+    );
+}
@@ -1,6 +1,7 @@
 mod assertions;
 pub mod declarations;
 mod functions;
+mod global_index;
 mod infer;
 mod references;
 mod scopes;
@@ -1,6 +1,6 @@
 use crate::{
     AnyJsFunction, AnyJsFunctionBody, JsMethodClassMember, JsMethodObjectMember, JsStatementList,
-    JsSyntaxToken,
+    JsSyntaxToken, TsReturnTypeAnnotation,
 };
 use biome_rowan::{declare_node_union, AstNode, SyntaxResult, TextRange};
 
@@ -59,6 +59,18 @@ impl AnyFunctionLike {
         }
     }
 
+    pub fn is_async(&self) -> bool {
+        match self {
+            AnyFunctionLike::AnyJsFunction(any_js_function) => any_js_function.is_async(),
+            AnyFunctionLike::JsMethodClassMember(method_class_member) => {
+                method_class_member.async_token().is_some()
+            }
+            AnyFunctionLike::JsMethodObjectMember(method_obj_member) => {
+                method_obj_member.async_token().is_some()
+            }
+        }
+    }
+
     pub fn name_range(&self) -> Option<TextRange> {
         match self {
             AnyFunctionLike::AnyJsFunction(js_function) => {
@@ -73,6 +85,20 @@ impl AnyFunctionLike {
         }
     }
 
+    pub fn return_type_annotation(&self) -> Option<TsReturnTypeAnnotation> {
+        match self {
+            AnyFunctionLike::AnyJsFunction(any_js_function) => {
+                any_js_function.return_type_annotation()
+            }
+            AnyFunctionLike::JsMethodClassMember(method_class_member) => {
+                method_class_member.return_type_annotation()
+            }
+            AnyFunctionLike::JsMethodObjectMember(method_obj_member) => {
+                method_obj_member.return_type_annotation()
+            }
+        }
+    }
+
     pub fn statements(&self) -> Option<JsStatementList> {
         Some(match self {
             AnyFunctionLike::AnyJsFunction(any_js_function) => any_js_function
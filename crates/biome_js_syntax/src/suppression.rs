@@ -98,8 +98,10 @@ pub fn parse_suppression_comment(
             }
         }
 
-        // Checks for `/rome[-_]ignore/i` without a regex, or skip the line
-        // entirely if it doesn't match
+        // Skip other directives such as `biome-ignore-format-start`
+        if line.starts_with(|c: char| c.is_alphanumeric() || c == '-' || c == '_') {
+            return None;
+        }
 
         let line = line.trim_start();
         Some(
@@ -250,6 +252,40 @@ fn parse_suppression_line(
     })
 }
 
+/// Returns `true` if `comment` is a `// biome-ignore-format-start` comment,
+/// that starts a region of code that isn't formatted.
+pub fn is_format_suppression_start(comment: &str) -> bool {
+    has_directive(comment, "biome-ignore-format-start")
+}
+
+/// Returns `true` if `comment` is a `// biome-ignore-format-end` comment,
+/// that ends a region of code that isn't formatted.
+pub fn is_format_suppression_end(comment: &str) -> bool {
+    has_directive(comment, "biome-ignore-format-end")
+}
+
+/// Returns `true` if `comment` is a `// prettier-ignore` comment,
+/// that Prettier uses to preserve the formatting of the following node.
+pub fn is_prettier_ignore(comment: &str) -> bool {
+    has_directive(comment, "prettier-ignore")
+}
+
+/// Returns `true` if the content of `comment` starts with `directive`,
+/// optionally followed by a reason.
+fn has_directive(comment: &str, directive: &str) -> bool {
+    let content = if let Some(content) = comment.strip_prefix("//") {
+        content
+    } else if let Some(content) = comment.strip_prefix("/*") {
+        content.strip_suffix("*/").unwrap_or(content)
+    } else {
+        return false;
+    };
+    match content.trim().strip_prefix(directive) {
+        Some(rest) => rest.is_empty() || rest.starts_with(|c: char| c == ':' || c.is_whitespace()),
+        None => false,
+    }
+}
+
 /// Returns the byte offset of `substr` within `base`
 ///
 /// # Safety
@@ -505,7 +541,34 @@ mod tests_biome_ignore {
 
     use crate::suppression::{offset_from, SuppressionDiagnostic, SuppressionDiagnosticKind};
 
-    use super::{parse_suppression_comment, Suppression};
+    use super::{
+        is_format_suppression_end, is_format_suppression_start, parse_suppression_comment,
+        Suppression,
+    };
+
+    #[test]
+    fn parse_format_suppression_region() {
+        assert!(is_format_suppression_start("// biome-ignore-format-start"));
+        assert!(is_format_suppression_start(
+            "// biome-ignore-format-start: alignment"
+        ));
+        assert!(is_format_suppression_start(
+            "/* biome-ignore-format-start */"
+        ));
+        assert!(!is_format_suppression_start(
+            "// biome-ignore-format-startx"
+        ));
+        assert!(!is_format_suppression_start(
+            "// biome-ignore format: reason"
+        ));
+        assert!(is_format_suppression_end("// biome-ignore-format-end"));
+        assert!(!is_format_suppression_end("// biome-ignore-format-start"));
+
+        assert_eq!(
+            parse_suppression_comment("// biome-ignore-format-start").collect::<Vec<_>>(),
+            vec![],
+        );
+    }
 
     #[test]
     fn parse_simple_suppression() {
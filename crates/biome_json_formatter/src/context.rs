@@ -1,7 +1,8 @@
 use crate::JsonCommentStyle;
 use biome_formatter::{prelude::*, IndentWidth};
 use biome_formatter::{
-    CstFormatContext, FormatContext, FormatOptions, IndentStyle, LineWidth, TransformSourceMap,
+    CstFormatContext, FormatContext, FormatOptions, IndentStyle, LineWidth, TrailingNewline,
+    TransformSourceMap,
 };
 
 use crate::comments::{FormatJsonLeadingComment, JsonComments};
@@ -59,6 +60,7 @@ pub struct JsonFormatOptions {
     indent_style: IndentStyle,
     indent_width: IndentWidth,
     line_width: LineWidth,
+    trailing_newline: TrailingNewline,
     _file_source: JsonFileSource,
 }
 
@@ -69,6 +71,7 @@ impl JsonFormatOptions {
             indent_style: IndentStyle::default(),
             indent_width: IndentWidth::default(),
             line_width: LineWidth::default(),
+            trailing_newline: TrailingNewline::default(),
         }
     }
 
@@ -86,6 +89,15 @@ impl JsonFormatOptions {
         self.line_width = line_width;
         self
     }
+
+    pub fn with_trailing_newline(mut self, trailing_newline: TrailingNewline) -> Self {
+        self.trailing_newline = trailing_newline;
+        self
+    }
+
+    pub fn trailing_newline(&self) -> TrailingNewline {
+        self.trailing_newline
+    }
 }
 
 impl FormatOptions for JsonFormatOptions {
@@ -102,7 +114,7 @@ impl FormatOptions for JsonFormatOptions {
     }
 
     fn as_print_options(&self) -> PrinterOptions {
-        PrinterOptions::from(self)
+        PrinterOptions::from(self).with_trailing_newline(self.trailing_newline)
     }
 }
 
@@ -110,6 +122,7 @@ impl fmt::Display for JsonFormatOptions {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         writeln!(f, "Indent style: {}", self.indent_style)?;
         writeln!(f, "Indent width: {}", self.indent_width.value())?;
-        writeln!(f, "Line width: {}", self.line_width.value())
+        writeln!(f, "Line width: {}", self.line_width.value())?;
+        writeln!(f, "Trailing newline: {}", self.trailing_newline)
     }
 }
@@ -181,6 +181,7 @@ fn fix_all(
         path: rome_path,
         fix_file_mode: FixFileMode::SafeFixes,
         should_format,
+        rule_groups: Vec::new(),
     })?;
 
     if fixed.actions.is_empty() {
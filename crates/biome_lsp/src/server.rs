@@ -546,6 +546,7 @@ impl ServerFactory {
 
         workspace_method!(builder, file_features);
         workspace_method!(builder, is_path_ignored);
+        workspace_method!(builder, file_language);
         workspace_method!(builder, update_settings);
         workspace_method!(builder, open_file);
         workspace_method!(builder, get_syntax_tree);
@@ -11,7 +11,7 @@ use biome_service::workspace::{
     FeatureName, FeaturesBuilder, PullDiagnosticsParams, SupportsFeatureParams,
 };
 use biome_service::workspace::{RageEntry, RageParams, RageResult, UpdateSettingsParams};
-use biome_service::{load_config, ConfigurationBasePath, Workspace};
+use biome_service::{apply_extends, load_config, ConfigurationBasePath, Workspace};
 use biome_service::{DynRef, WorkspaceError};
 use futures::stream::futures_unordered::FuturesUnordered;
 use futures::StreamExt;
@@ -401,11 +401,17 @@ impl Session {
                     warn!("The deserialization of the configuration resulted in errors. Biome will use its defaults where possible.");
                 }
 
-                info!("Loaded workspace settings: {configuration:#?}");
+                let result = apply_extends(&self.fs, &payload.configuration_file_path, configuration)
+                    .and_then(|(configuration, diagnostics)| {
+                        if !diagnostics.is_empty() {
+                            warn!("The deserialization of the extended configuration files resulted in errors. Biome will use its defaults where possible.");
+                        }
+
+                        info!("Loaded workspace settings: {configuration:#?}");
 
-                let result = self
-                    .workspace
-                    .update_settings(UpdateSettingsParams { configuration });
+                        self.workspace
+                            .update_settings(UpdateSettingsParams { configuration })
+                    });
 
                 if let Err(error) = result {
                     error!("Failed to set workspace settings: {}", error);
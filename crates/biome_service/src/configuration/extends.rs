@@ -0,0 +1,255 @@
+//! Resolution of the `"extends"` field of the configuration file.
+//!
+//! The configuration files listed in `"extends"` are applied from left to right, and each of them
+//! can extend other configuration files. The JSON values of the configuration files are merged
+//! before being deserialized, so only the keys that are written in a file override the keys of
+//! the files that it extends:
+//! - objects are merged key by key, e.g. a single option of `formatter` or a single rule of
+//!   `linter.rules` can be overridden;
+//! - the arrays of `overrides` are concatenated, starting from the extended configuration files;
+//! - any other value, including an array, replaces the value of the extended configuration files.
+
+use crate::configuration::diagnostics::CantLoadExtendFile;
+use crate::{Configuration, DynRef, WorkspaceError};
+use biome_console::markup;
+use biome_deserialize::json::deserialize_from_json_str;
+use biome_diagnostics::{DiagnosticExt, Error};
+use biome_fs::{FileSystem, OpenOptions};
+use biome_json_parser::JsonParserOptions;
+use serde_json::{Map, Value};
+use std::ffi::OsStr;
+use std::path::{Component, Path, PathBuf};
+
+/// Applies the configuration files listed in the `"extends"` field of `configuration`, which was
+/// loaded from the file `configuration_file_path`.
+///
+/// It returns the extended configuration, and the diagnostics emitted while deserializing the
+/// extended configuration files. The diagnostics of `configuration` itself aren't emitted again.
+///
+/// ## Errors
+///
+/// - a configuration file can't be resolved or read from the file system
+/// - a configuration file extends itself, directly or through other configuration files
+pub fn apply_extends(
+    fs: &DynRef<dyn FileSystem>,
+    configuration_file_path: &Path,
+    configuration: Configuration,
+) -> Result<(Configuration, Vec<Error>), WorkspaceError> {
+    if configuration.extends.is_none() {
+        return Ok((configuration, vec![]));
+    }
+
+    let configuration_file_path = normalize_path(configuration_file_path);
+    let mut resolver = ExtendsResolver {
+        fs: &**fs,
+        stack: vec![],
+        diagnostics: vec![],
+    };
+    let content = resolver.read(&configuration_file_path, &configuration_file_path)?;
+    let merged = resolver.resolve(&configuration_file_path, &configuration, &content)?;
+
+    // The diagnostics were already emitted for each configuration file, with the right location
+    let (configuration, _) = deserialize_from_json_str::<Configuration>(
+        &merged.to_string(),
+        JsonParserOptions::default(),
+    )
+    .consume();
+
+    Ok((configuration, resolver.diagnostics))
+}
+
+struct ExtendsResolver<'a> {
+    fs: &'a dyn FileSystem,
+    /// The configuration files that are being resolved, from the first one to the last one
+    stack: Vec<PathBuf>,
+    diagnostics: Vec<Error>,
+}
+
+impl ExtendsResolver<'_> {
+    /// Returns the JSON value of the configuration file at `path`, merged with the values of the
+    /// configuration files that it extends.
+    fn resolve(
+        &mut self,
+        path: &Path,
+        configuration: &Configuration,
+        content: &str,
+    ) -> Result<Value, WorkspaceError> {
+        self.stack.push(path.to_path_buf());
+        let mut merged = Value::Object(Map::new());
+
+        if let Some(extends) = &configuration.extends {
+            let directory_path = path.parent().unwrap_or(Path::new(""));
+            for specifier in extends.index_set() {
+                let extended_path = self.resolve_specifier(directory_path, specifier)?;
+                if let Some(position) = self.stack.iter().position(|path| path == &extended_path) {
+                    let cycle = self.stack[position..]
+                        .iter()
+                        .chain([&extended_path])
+                        .map(|path| path.display().to_string())
+                        .collect::<Vec<_>>()
+                        .join(" -> ");
+                    return Err(CantLoadExtendFile::new(
+                        extended_path.display().to_string(),
+                        format!("The configuration files extend each other: {cycle}"),
+                    )
+                    .with_verbose_advice(markup! {
+                        "Remove one of the configuration files from the "<Emphasis>"extends"</Emphasis>" field of the others."
+                    })
+                    .into());
+                }
+
+                let extended_content = self.read(&extended_path, directory_path)?;
+                let (extended_configuration, diagnostics) =
+                    deserialize_from_json_str::<Configuration>(
+                        &extended_content,
+                        JsonParserOptions::default(),
+                    )
+                    .consume();
+                let extended_file_path = extended_path.display().to_string();
+                self.diagnostics.extend(
+                    diagnostics
+                        .into_iter()
+                        .map(|diagnostic| diagnostic.with_file_path(extended_file_path.clone())),
+                );
+
+                let mut extended =
+                    self.resolve(&extended_path, &extended_configuration, &extended_content)?;
+                if let Value::Object(extended) = &mut extended {
+                    extended.remove("extends");
+                }
+                merge_values(&mut merged, extended, true);
+            }
+        }
+
+        // A file that can't be parsed doesn't override anything, its syntax errors are
+        // emitted by its deserialization
+        let value = serde_json::from_str(content).unwrap_or(Value::Object(Map::new()));
+        merge_values(&mut merged, value, true);
+
+        self.stack.pop();
+        Ok(merged)
+    }
+
+    /// Returns the path of the configuration file referenced by `specifier`, which is written
+    /// in a configuration file that lives in `directory_path`.
+    ///
+    /// A package specifier, such as `@company/biome-config`, is resolved from the `node_modules`
+    /// of `directory_path` and of its ancestors. If the specifier doesn't reference a JSON file,
+    /// the configuration file at the root of the package is used.
+    fn resolve_specifier(
+        &self,
+        directory_path: &Path,
+        specifier: &str,
+    ) -> Result<PathBuf, WorkspaceError> {
+        if !is_package_specifier(specifier) {
+            return Ok(normalize_path(&directory_path.join(specifier)));
+        }
+
+        let references_file = has_json_extension(Path::new(specifier));
+        for ancestor in directory_path.ancestors() {
+            let package_path = ancestor.join("node_modules").join(specifier);
+            let extended_path = if references_file {
+                package_path
+            } else {
+                package_path.join(self.fs.config_name())
+            };
+            let extended_path = normalize_path(&extended_path);
+            if self.fs.path_exists(&extended_path) {
+                return Ok(extended_path);
+            }
+        }
+
+        let directory_path = if directory_path.as_os_str().is_empty() {
+            Path::new(".")
+        } else {
+            directory_path
+        };
+        Err(CantLoadExtendFile::new(
+            specifier,
+            "Biome couldn't find the configuration file of this package.",
+        )
+        .with_verbose_advice(markup! {
+            "Biome looked for the package in the "<Emphasis>"node_modules"</Emphasis>" of "<Emphasis>{directory_path.display().to_string()}</Emphasis>" and of its parent directories. Make sure that the package is installed."
+        })
+        .into())
+    }
+
+    fn read(&self, path: &Path, directory_path: &Path) -> Result<String, WorkspaceError> {
+        let mut file = self
+            .fs
+            .open_with_options(path, OpenOptions::default().read(true))
+            .map_err(|err| {
+                CantLoadExtendFile::new(path.display().to_string(), err.to_string()).with_verbose_advice(
+                    markup! {
+                        "Biome tried to load the configuration file "<Emphasis>{directory_path.display().to_string()}</Emphasis>" using "<Emphasis>{path.display().to_string()}</Emphasis>" as base path."
+                    }
+                )
+            })?;
+        let mut content = String::new();
+        file.read_to_string(&mut content).map_err(|err| {
+            CantLoadExtendFile::new(path.display().to_string(), err.to_string()).with_verbose_advice(
+                markup! {
+                    "It's possible that the file was created with a different user/group. Make sure you have the rights to read the file."
+                }
+            )
+        })?;
+        Ok(content)
+    }
+}
+
+/// Merges `value` into `base`, the keys of `value` take precedence.
+///
+/// When `is_root` is `true`, the arrays of `overrides` are concatenated.
+fn merge_values(base: &mut Value, value: Value, is_root: bool) {
+    match (base, value) {
+        (Value::Object(base), Value::Object(object)) => {
+            for (key, value) in object {
+                match (base.get_mut(&key), value) {
+                    (Some(Value::Array(base_overrides)), Value::Array(overrides))
+                        if is_root && key == "overrides" =>
+                    {
+                        base_overrides.extend(overrides);
+                    }
+                    (Some(base_value), value) => merge_values(base_value, value, false),
+                    (None, value) => {
+                        base.insert(key, value);
+                    }
+                }
+            }
+        }
+        (base, value) => *base = value,
+    }
+}
+
+/// Returns `true` if `specifier` references a package of `node_modules` instead of a path,
+/// e.g. `@company/biome-config` or `biome-config-company`.
+fn is_package_specifier(specifier: &str) -> bool {
+    if specifier.starts_with('@') {
+        return true;
+    }
+    let path = Path::new(specifier);
+    !(specifier.starts_with('.') || path.is_absolute() || has_json_extension(path))
+}
+
+fn has_json_extension(path: &Path) -> bool {
+    matches!(
+        path.extension().and_then(OsStr::to_str),
+        Some("json" | "jsonc")
+    )
+}
+
+/// Removes the `.` and `..` components of `path` without accessing the file system, so that
+/// the same configuration file is always referenced by the same path.
+fn normalize_path(path: &Path) -> PathBuf {
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir if normalized.file_name().is_some() => {
+                normalized.pop();
+            }
+            component => normalized.push(component),
+        }
+    }
+    normalized
+}
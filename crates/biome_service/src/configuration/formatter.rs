@@ -3,7 +3,7 @@ use crate::configuration::overrides::OverrideFormatterConfiguration;
 use crate::settings::{to_matcher, FormatSettings};
 use crate::WorkspaceError;
 use biome_deserialize::StringSet;
-use biome_formatter::{IndentStyle, LineWidth};
+use biome_formatter::{IndentStyle, LineWidth, TrailingNewline};
 use bpaf::Bpaf;
 use serde::{Deserialize, Serialize};
 use std::str::FromStr;
@@ -45,6 +45,11 @@ pub struct FormatterConfiguration {
     #[bpaf(long("line-width"), argument("NUMBER"), optional)]
     pub line_width: Option<LineWidth>,
 
+    /// Whether the formatted code ends with a newline. Defaults to "always".
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[bpaf(long("trailing-newline"), argument("always|never"), optional)]
+    pub trailing_newline: Option<TrailingNewline>,
+
     /// A list of Unix shell style patterns. The formatter will ignore files/folders that will
     /// match these patterns.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -69,6 +74,7 @@ impl FormatterConfiguration {
         "indentSize",
         "indentWidth",
         "lineWidth",
+        "trailingNewline",
         "ignore",
         "include",
     ];
@@ -83,6 +89,7 @@ impl Default for FormatterConfiguration {
             indent_width: Some(2),
             indent_style: Some(PlainIndentStyle::default()),
             line_width: Some(LineWidth::default()),
+            trailing_newline: Some(TrailingNewline::default()),
             ignore: None,
             include: None,
         }
@@ -116,6 +123,10 @@ impl MergeWith<FormatterConfiguration> for FormatterConfiguration {
             self.line_width = Some(line_width);
         }
 
+        if let Some(trailing_newline) = other.trailing_newline {
+            self.trailing_newline = Some(trailing_newline);
+        }
+
         if let Some(format_with_errors) = other.format_with_errors {
             self.format_with_errors = Some(format_with_errors);
         }
@@ -158,6 +169,7 @@ impl TryFrom<FormatterConfiguration> for FormatSettings {
             indent_style: Some(indent_style),
             indent_width: Some(indent_width),
             line_width: conf.line_width,
+            trailing_newline: conf.trailing_newline,
             format_with_errors: conf.format_with_errors.unwrap_or_default(),
             ignored_files: to_matcher(conf.ignore.as_ref())?,
             included_files: to_matcher(conf.include.as_ref())?,
@@ -185,6 +197,7 @@ impl TryFrom<OverrideFormatterConfiguration> for FormatSettings {
             indent_style: Some(indent_style),
             indent_width: Some(indent_width),
             line_width: conf.line_width,
+            trailing_newline: conf.trailing_newline,
             format_with_errors: conf.format_with_errors.unwrap_or_default(),
             ignored_files: None,
             included_files: None,
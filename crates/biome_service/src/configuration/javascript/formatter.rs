@@ -3,7 +3,11 @@ use crate::configuration::{deserialize_line_width, serialize_line_width};
 use crate::MergeWith;
 use biome_formatter::LineWidth;
 use biome_js_formatter::context::trailing_comma::TrailingComma;
-use biome_js_formatter::context::{ArrowParentheses, QuoteProperties, QuoteStyle, Semicolons};
+use biome_js_formatter::context::{
+    ArrowParentheses, DoWhileStyle, JsxAttributeAlignment, LongCommentStyle, NestedTernaryStyle,
+    NumericLiteralStyle, ObjectDestructuringSpacing, QuoteProperties, QuoteStyle, Semicolons,
+    TemplateLiteralIndentation, TypeAssertionStyle, UndefinedStyle, WrapLongStrings,
+};
 use bpaf::Bpaf;
 use serde::{Deserialize, Serialize};
 
@@ -36,6 +40,98 @@ pub struct JavascriptFormatter {
     #[bpaf(long("arrow-parentheses"), argument("always|as-needed"), optional)]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub arrow_parentheses: Option<ArrowParentheses>,
+    /// Whether the formatter may break and indent the expressions of template literals. Defaults to "normalize".
+    #[bpaf(
+        long("template-literal-indentation"),
+        argument("normalize|preserve"),
+        optional
+    )]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub template_literal_indentation: Option<TemplateLiteralIndentation>,
+    /// How the attributes of a multiline JSX element are aligned. Defaults to "indent".
+    #[bpaf(
+        long("jsx-attribute-alignment"),
+        argument("indent|firstAttr"),
+        optional
+    )]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub jsx_attribute_alignment: Option<JsxAttributeAlignment>,
+    /// Whether `// prettier-ignore` comments suppress the formatting of the following node, like `// biome-ignore format`. Defaults to true.
+    #[bpaf(long("honor-prettier-ignore"), argument("true|false"), optional)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub honor_prettier_ignore: Option<bool>,
+    /// Call expressions with this many arguments or fewer keep their arguments on a single line, even when they exceed the line width. Defaults to 0, which disables the option.
+    #[bpaf(
+        long("function-call-arguments-on-one-line"),
+        argument("NUMBER"),
+        optional
+    )]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub function_call_arguments_on_one_line: Option<u8>,
+    /// Whether a variable declaration initialized with a long string literal may break after the `=`. Defaults to "always".
+    #[bpaf(long("wrap-long-strings"), argument("never|always"), optional)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub wrap_long_strings: Option<WrapLongStrings>,
+    /// The style of the free-standing comments that span multiple lines. Defaults to "block".
+    #[bpaf(long("long-comment-style"), argument("block|line"), optional)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub long_comment_style: Option<LongCommentStyle>,
+    /// Whether to insert spaces inside the braces of object destructuring patterns, and of named imports and exports. Defaults to "always".
+    #[bpaf(
+        long("object-destructuring-spacing"),
+        argument("always|never"),
+        optional
+    )]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub object_destructuring_spacing: Option<ObjectDestructuringSpacing>,
+    /// The number of blank lines between the last import and the following statement, from 0 to 2. By default, the blank lines of the source are kept.
+    #[bpaf(long("blank-lines-after-imports"), argument("0|1|2"), optional)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub blank_lines_after_imports: Option<u8>,
+    /// Whether the `=` of consecutive `const`, `let`, and `var` declarations are aligned in the same column. This option is experimental. Defaults to false.
+    #[bpaf(
+        long("align-consecutive-assignments"),
+        argument("true|false"),
+        optional
+    )]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub align_consecutive_assignments: Option<bool>,
+    /// Whether `void 0` is replaced by `undefined`, or `undefined` by `void 0`. Defaults to "preserve".
+    #[bpaf(
+        long("undefined-style"),
+        argument("preserve|undefined|void0"),
+        optional
+    )]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub undefined_style: Option<UndefinedStyle>,
+    /// How the chains of conditional expressions nested in the alternate are formatted when they break. Defaults to "flat".
+    #[bpaf(long("nested-ternary-style"), argument("flat|ladder"), optional)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub nested_ternary_style: Option<NestedTernaryStyle>,
+    /// Whether `else`, `catch`, and `finally` are on the same line as the closing brace of the previous block. Defaults to true.
+    #[bpaf(long("else-on-same-line"), argument("true|false"), optional)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub else_on_same_line: Option<bool>,
+    /// Whether the arguments of `new URL(...)` that use `import.meta` stay on one line. Defaults to true.
+    #[bpaf(long("keep-import-meta-on-one-line"), argument("true|false"), optional)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub keep_import_meta_on_one_line: Option<bool>,
+    /// Whether the expressions of a template literal can break at their `${` and `}` when the template literal exceeds the line width. Defaults to false.
+    #[bpaf(long("break-template-expressions"), argument("true|false"), optional)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub break_template_expressions: Option<bool>,
+    /// Whether the `while` of a `do...while` loop is on the same line as the closing brace of the body. Defaults to "sameLine".
+    #[bpaf(long("do-while-style"), argument("sameLine|newLine"), optional)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub do_while_style: Option<DoWhileStyle>,
+    /// How numeric literals are normalized. `underscore` is "preserve", "strip", or "addThousands", `hexCase` is "lower" or "upper", and `exponent` is "preserve", "prefer", or "avoid". By default, the literals keep their style.
+    #[bpaf(external(no_numeric_literal_style), hide)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub numeric_literal_style: Option<NumericLiteralStyle>,
+    /// Whether the `as` and `satisfies` operators of a TypeScript expression that exceeds the line width stay on the same line as the expression. Defaults to "sameLine".
+    #[bpaf(long("type-assertion-style"), argument("sameLine|nextLine"), optional)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub type_assertion_style: Option<TypeAssertionStyle>,
 
     /// Control the formatter for JavaScript (and its super languages) files.
     #[bpaf(long("javascript-formatter-enabled"), argument("true|false"), optional)]
@@ -75,6 +171,11 @@ pub struct JavascriptFormatter {
     pub line_width: Option<LineWidth>,
 }
 
+/// The numeric literal style can only be set in the configuration file.
+fn no_numeric_literal_style() -> impl bpaf::Parser<Option<NumericLiteralStyle>> {
+    bpaf::pure(None)
+}
+
 impl JavascriptFormatter {
     pub(crate) const KNOWN_KEYS: &'static [&'static str] = &[
         "quoteStyle",
@@ -83,6 +184,23 @@ impl JavascriptFormatter {
         "trailingComma",
         "semicolons",
         "arrowParentheses",
+        "templateLiteralIndentation",
+        "jsxAttributeAlignment",
+        "honorPrettierIgnore",
+        "functionCallArgumentsOnOneLine",
+        "wrapLongStrings",
+        "longCommentStyle",
+        "objectDestructuringSpacing",
+        "blankLinesAfterImports",
+        "alignConsecutiveAssignments",
+        "undefinedStyle",
+        "nestedTernaryStyle",
+        "elseOnSameLine",
+        "keepImportMetaOnOneLine",
+        "breakTemplateExpressions",
+        "doWhileStyle",
+        "numericLiteralStyle",
+        "typeAssertionStyle",
         "enabled",
         "indentStyle",
         "indentSize",
@@ -96,6 +214,58 @@ impl MergeWith<JavascriptFormatter> for JavascriptFormatter {
         if let Some(arrow_parentheses) = other.arrow_parentheses {
             self.arrow_parentheses = Some(arrow_parentheses);
         }
+        if let Some(template_literal_indentation) = other.template_literal_indentation {
+            self.template_literal_indentation = Some(template_literal_indentation);
+        }
+        if let Some(jsx_attribute_alignment) = other.jsx_attribute_alignment {
+            self.jsx_attribute_alignment = Some(jsx_attribute_alignment);
+        }
+        if let Some(honor_prettier_ignore) = other.honor_prettier_ignore {
+            self.honor_prettier_ignore = Some(honor_prettier_ignore);
+        }
+        if let Some(function_call_arguments_on_one_line) = other.function_call_arguments_on_one_line
+        {
+            self.function_call_arguments_on_one_line = Some(function_call_arguments_on_one_line);
+        }
+        if let Some(wrap_long_strings) = other.wrap_long_strings {
+            self.wrap_long_strings = Some(wrap_long_strings);
+        }
+        if let Some(long_comment_style) = other.long_comment_style {
+            self.long_comment_style = Some(long_comment_style);
+        }
+        if let Some(object_destructuring_spacing) = other.object_destructuring_spacing {
+            self.object_destructuring_spacing = Some(object_destructuring_spacing);
+        }
+        if let Some(blank_lines_after_imports) = other.blank_lines_after_imports {
+            self.blank_lines_after_imports = Some(blank_lines_after_imports);
+        }
+        if let Some(align_consecutive_assignments) = other.align_consecutive_assignments {
+            self.align_consecutive_assignments = Some(align_consecutive_assignments);
+        }
+        if let Some(undefined_style) = other.undefined_style {
+            self.undefined_style = Some(undefined_style);
+        }
+        if let Some(nested_ternary_style) = other.nested_ternary_style {
+            self.nested_ternary_style = Some(nested_ternary_style);
+        }
+        if let Some(else_on_same_line) = other.else_on_same_line {
+            self.else_on_same_line = Some(else_on_same_line);
+        }
+        if let Some(keep_import_meta_on_one_line) = other.keep_import_meta_on_one_line {
+            self.keep_import_meta_on_one_line = Some(keep_import_meta_on_one_line);
+        }
+        if let Some(break_template_expressions) = other.break_template_expressions {
+            self.break_template_expressions = Some(break_template_expressions);
+        }
+        if let Some(do_while_style) = other.do_while_style {
+            self.do_while_style = Some(do_while_style);
+        }
+        if let Some(numeric_literal_style) = other.numeric_literal_style {
+            self.numeric_literal_style = Some(numeric_literal_style);
+        }
+        if let Some(type_assertion_style) = other.type_assertion_style {
+            self.type_assertion_style = Some(type_assertion_style);
+        }
         if let Some(quote_properties) = other.quote_properties {
             self.quote_properties = Some(quote_properties);
         }
@@ -8,6 +8,7 @@ use crate::settings::{to_matcher, LinterSettings};
 use crate::WorkspaceError;
 use biome_deserialize::StringSet;
 use biome_diagnostics::Severity;
+use biome_js_analyze::metadata;
 use biome_js_analyze::options::{possible_options, PossibleOptions};
 use bpaf::Bpaf;
 pub use rules::*;
@@ -16,6 +17,11 @@ use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use std::str::FromStr;
 
+/// Returns `true` if `name` is the name of a group of lint rules, such as `style`.
+pub fn is_rule_group(name: &str) -> bool {
+    metadata().find_group(name).is_some()
+}
+
 #[derive(Deserialize, Serialize, Debug, Clone, Bpaf, Eq, PartialEq)]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase", default, deny_unknown_fields)]
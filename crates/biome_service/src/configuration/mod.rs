@@ -3,6 +3,7 @@
 //! The configuration is divided by "tool", and then it's possible to further customise it
 //! by language. The language might further options divided by tool.
 pub mod diagnostics;
+mod extends;
 pub mod formatter;
 mod generated;
 pub mod javascript;
@@ -12,14 +13,19 @@ mod merge;
 pub mod organize_imports;
 mod overrides;
 mod parse;
+mod validation;
 pub mod vcs;
 
 pub use crate::configuration::diagnostics::ConfigurationDiagnostic;
+pub use crate::configuration::extends::apply_extends;
 pub(crate) use crate::configuration::generated::push_to_analyzer_rules;
 use crate::configuration::json::JsonFormatter;
 pub use crate::configuration::merge::MergeWith;
 use crate::configuration::organize_imports::{organize_imports, OrganizeImports};
 use crate::configuration::overrides::Overrides;
+pub use crate::configuration::validation::{
+    validate_configuration, ConfigurationValidationDiagnostic,
+};
 use crate::configuration::vcs::{vcs_configuration, VcsConfiguration};
 use crate::settings::WorkspaceSettings;
 use crate::{DynRef, WorkspaceError, VERSION};
@@ -10,7 +10,7 @@ use crate::settings::{
 };
 use crate::{MergeWith, Rules, WorkspaceError};
 use biome_deserialize::StringSet;
-use biome_formatter::{IndentStyle, LineWidth};
+use biome_formatter::{IndentStyle, LineWidth, TrailingNewline};
 use bpaf::Bpaf;
 use serde::{Deserialize, Serialize};
 use std::str::FromStr;
@@ -237,6 +237,11 @@ pub struct OverrideFormatterConfiguration {
     )]
     #[bpaf(long("line-width"), argument("NUMBER"), optional)]
     pub line_width: Option<LineWidth>,
+
+    /// Whether the formatted code ends with a newline. Defaults to "always".
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[bpaf(long("trailing-newline"), argument("always|never"), optional)]
+    pub trailing_newline: Option<TrailingNewline>,
 }
 
 impl OverrideFormatterConfiguration {
@@ -247,6 +252,7 @@ impl OverrideFormatterConfiguration {
         "indentSize",
         "indentWidth",
         "lineWidth",
+        "trailingNewline",
     ];
 }
 
@@ -269,6 +275,10 @@ impl MergeWith<OverrideFormatterConfiguration> for OverrideFormatterConfiguratio
             self.line_width = Some(line_width);
         }
 
+        if let Some(trailing_newline) = other.trailing_newline {
+            self.trailing_newline = Some(trailing_newline);
+        }
+
         if let Some(format_with_errors) = other.format_with_errors {
             self.format_with_errors = Some(format_with_errors);
         }
@@ -414,6 +424,7 @@ impl TryFrom<OverrideFormatterConfiguration> for OverrideFormatSettings {
             indent_style: Some(indent_style),
             indent_width: Some(indent_width),
             line_width: conf.line_width,
+            trailing_newline: conf.trailing_newline,
             format_with_errors: conf.format_with_errors.unwrap_or_default(),
         })
     }
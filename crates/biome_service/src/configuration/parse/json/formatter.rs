@@ -2,7 +2,7 @@ use crate::configuration::{FormatterConfiguration, PlainIndentStyle};
 use biome_console::markup;
 use biome_deserialize::json::{has_only_known_keys, with_only_known_variants, VisitJsonNode};
 use biome_deserialize::{DeserializationDiagnostic, StringSet, VisitNode};
-use biome_formatter::LineWidth;
+use biome_formatter::{LineWidth, TrailingNewline};
 use biome_json_syntax::{JsonLanguage, JsonSyntaxNode};
 use biome_rowan::{AstNode, SyntaxNode};
 
@@ -72,6 +72,14 @@ impl VisitNode<JsonLanguage> for FormatterConfiguration {
                     }
                 });
             }
+            "trailingNewline" => {
+                let value = with_only_known_variants(
+                    value.syntax(),
+                    TrailingNewline::KNOWN_VALUES,
+                    diagnostics,
+                )?;
+                self.trailing_newline = value.inner_string_text().ok()?.text().parse().ok();
+            }
             "formatWithErrors" => {
                 self.format_with_errors = self.map_to_boolean(&value, name_text, diagnostics);
             }
@@ -1,10 +1,15 @@
 use crate::configuration::javascript::JavascriptFormatter;
 use crate::configuration::PlainIndentStyle;
+use biome_console::markup;
 use biome_deserialize::json::VisitJsonNode;
 use biome_deserialize::{DeserializationDiagnostic, VisitNode};
 use biome_formatter::LineWidth;
 use biome_js_formatter::context::trailing_comma::TrailingComma;
-use biome_js_formatter::context::{ArrowParentheses, QuoteProperties, QuoteStyle, Semicolons};
+use biome_js_formatter::context::{
+    ArrowParentheses, DoWhileStyle, JsxAttributeAlignment, LongCommentStyle, NestedTernaryStyle,
+    NumericLiteralStyle, ObjectDestructuringSpacing, QuoteProperties, QuoteStyle, Semicolons,
+    TemplateLiteralIndentation, TypeAssertionStyle, UndefinedStyle, WrapLongStrings,
+};
 use biome_json_syntax::{JsonLanguage, JsonSyntaxNode};
 use biome_rowan::{AstNode, SyntaxNode};
 
@@ -61,6 +66,116 @@ impl VisitNode<JsonLanguage> for JavascriptFormatter {
                 self.map_to_known_string(&value, name_text, &mut arrow_parentheses, diagnostics)?;
                 self.arrow_parentheses = Some(arrow_parentheses);
             }
+            "templateLiteralIndentation" => {
+                let mut template_literal_indentation = TemplateLiteralIndentation::default();
+                self.map_to_known_string(
+                    &value,
+                    name_text,
+                    &mut template_literal_indentation,
+                    diagnostics,
+                )?;
+                self.template_literal_indentation = Some(template_literal_indentation);
+            }
+            "jsxAttributeAlignment" => {
+                let mut jsx_attribute_alignment = JsxAttributeAlignment::default();
+                self.map_to_known_string(
+                    &value,
+                    name_text,
+                    &mut jsx_attribute_alignment,
+                    diagnostics,
+                )?;
+                self.jsx_attribute_alignment = Some(jsx_attribute_alignment);
+            }
+            "honorPrettierIgnore" => {
+                self.honor_prettier_ignore = self.map_to_boolean(&value, name_text, diagnostics);
+            }
+            "functionCallArgumentsOnOneLine" => {
+                self.function_call_arguments_on_one_line =
+                    self.map_to_u8(&value, name_text, u8::MAX, diagnostics);
+            }
+            "wrapLongStrings" => {
+                let mut wrap_long_strings = WrapLongStrings::default();
+                self.map_to_known_string(&value, name_text, &mut wrap_long_strings, diagnostics)?;
+                self.wrap_long_strings = Some(wrap_long_strings);
+            }
+            "longCommentStyle" => {
+                let mut long_comment_style = LongCommentStyle::default();
+                self.map_to_known_string(&value, name_text, &mut long_comment_style, diagnostics)?;
+                self.long_comment_style = Some(long_comment_style);
+            }
+            "objectDestructuringSpacing" => {
+                let mut object_destructuring_spacing = ObjectDestructuringSpacing::default();
+                self.map_to_known_string(
+                    &value,
+                    name_text,
+                    &mut object_destructuring_spacing,
+                    diagnostics,
+                )?;
+                self.object_destructuring_spacing = Some(object_destructuring_spacing);
+            }
+            "blankLinesAfterImports" => {
+                let blank_lines = self.map_to_u8(&value, name_text, 2, diagnostics)?;
+                if blank_lines > 2 {
+                    diagnostics.push(
+                        DeserializationDiagnostic::new(markup! {
+                            "The value of "<Emphasis>"blankLinesAfterImports"</Emphasis>" must be 0, 1, or 2."
+                        })
+                        .with_range(value.range()),
+                    );
+                } else {
+                    self.blank_lines_after_imports = Some(blank_lines);
+                }
+            }
+            "alignConsecutiveAssignments" => {
+                self.align_consecutive_assignments =
+                    self.map_to_boolean(&value, name_text, diagnostics);
+            }
+            "undefinedStyle" => {
+                let mut undefined_style = UndefinedStyle::default();
+                self.map_to_known_string(&value, name_text, &mut undefined_style, diagnostics)?;
+                self.undefined_style = Some(undefined_style);
+            }
+            "nestedTernaryStyle" => {
+                let mut nested_ternary_style = NestedTernaryStyle::default();
+                self.map_to_known_string(
+                    &value,
+                    name_text,
+                    &mut nested_ternary_style,
+                    diagnostics,
+                )?;
+                self.nested_ternary_style = Some(nested_ternary_style);
+            }
+            "elseOnSameLine" => {
+                self.else_on_same_line = self.map_to_boolean(&value, name_text, diagnostics);
+            }
+            "keepImportMetaOnOneLine" => {
+                self.keep_import_meta_on_one_line =
+                    self.map_to_boolean(&value, name_text, diagnostics);
+            }
+            "breakTemplateExpressions" => {
+                self.break_template_expressions =
+                    self.map_to_boolean(&value, name_text, diagnostics);
+            }
+            "doWhileStyle" => {
+                let mut do_while_style = DoWhileStyle::default();
+                self.map_to_known_string(&value, name_text, &mut do_while_style, diagnostics)?;
+                self.do_while_style = Some(do_while_style);
+            }
+            "numericLiteralStyle" => {
+                let mut numeric_literal_style = NumericLiteralStyle::default();
+                self.map_to_object(&value, name_text, &mut numeric_literal_style, diagnostics)?;
+                self.numeric_literal_style = Some(numeric_literal_style);
+            }
+            "typeAssertionStyle" => {
+                let mut type_assertion_style = TypeAssertionStyle::default();
+                self.map_to_known_string(
+                    &value,
+                    name_text,
+                    &mut type_assertion_style,
+                    diagnostics,
+                )?;
+                self.type_assertion_style = Some(type_assertion_style);
+            }
 
             "enabled" => {
                 self.enabled = self.map_to_boolean(&value, name_text, diagnostics);
@@ -6,9 +6,9 @@ use crate::configuration::parse::json::linter::are_recommended_and_all_correct;
 use crate::configuration::{JavascriptConfiguration, JsonConfiguration, PlainIndentStyle};
 use crate::Rules;
 use biome_console::markup;
-use biome_deserialize::json::{has_only_known_keys, VisitJsonNode};
+use biome_deserialize::json::{has_only_known_keys, with_only_known_variants, VisitJsonNode};
 use biome_deserialize::{DeserializationDiagnostic, StringSet, VisitNode};
-use biome_formatter::LineWidth;
+use biome_formatter::{LineWidth, TrailingNewline};
 use biome_json_syntax::{AnyJsonValue, JsonLanguage, JsonSyntaxNode};
 use biome_rowan::AstNode;
 
@@ -145,6 +145,14 @@ impl VisitNode<JsonLanguage> for OverrideFormatterConfiguration {
                     }
                 });
             }
+            "trailingNewline" => {
+                let value = with_only_known_variants(
+                    value.syntax(),
+                    TrailingNewline::KNOWN_VALUES,
+                    diagnostics,
+                )?;
+                self.trailing_newline = value.inner_string_text().ok()?.text().parse().ok();
+            }
             "formatWithErrors" => {
                 self.format_with_errors = self.map_to_boolean(&value, name_text, diagnostics);
             }
@@ -2119,23 +2119,61 @@ impl VisitNode<JsonLanguage> for Nursery {
             &[
                 "recommended",
                 "all",
+                "noAbsoluteImportPath",
                 "noApproximativeNumericConstant",
+                "noAsyncWithoutAwait",
+                "noConditionalAssignment",
+                "noDirectMutation",
+                "noDoubleSlashComments",
                 "noDuplicateJsonKeys",
                 "noEmptyBlockStatements",
                 "noEmptyCharacterClassInRegex",
+                "noEnumMemberValueOverlap",
+                "noExcessivePropertyAccess",
+                "noExcessiveSwitchCases",
+                "noExpressionInStrictEquality",
+                "noImplicitCoercion",
                 "noInteractiveElementToNoninteractiveRole",
                 "noInvalidNewBuiltin",
                 "noMisleadingInstantiator",
                 "noMisrefactoredShorthandAssign",
+                "noMixedImportStyle",
+                "noMutableExports",
+                "noObjectSpreadInLoop",
+                "noPrettierIgnore",
+                "noReExportAll",
+                "noStandaloneBlocks",
+                "noStringBooleanParameter",
+                "noStringLiteralType",
+                "noStringOracle",
+                "noThrowLiteral",
+                "noUnnecessaryAwait",
+                "noUnsafeOptionalChain",
+                "noUnsafeRegex",
+                "noUnterminatedMultilineComment",
                 "noUnusedImports",
+                "noUnusedPrivateClassMembers",
                 "noUselessElse",
+                "noUselessFormatSuppressionRegion",
                 "noUselessLoneBlockStatements",
                 "useAriaActivedescendantWithTabindex",
+                "useArrayLiteralSpread",
                 "useArrowFunction",
                 "useAsConstAssertion",
+                "useConsistentEmptyLineBetweenClassMembers",
+                "useConsistentObjectDestructuring",
+                "useExplicitReturnType",
                 "useGroupedTypeImport",
+                "useImportMeta",
                 "useImportRestrictions",
+                "useIteratorProtocol",
+                "useNullishCoalescingAssignment",
+                "useNumberToFixed",
+                "useObjectShorthand",
+                "useReadonlyParameters",
                 "useShorthandAssign",
+                "useStructuredClone",
+                "useTaskDestructuring",
             ],
             diagnostics,
         )
@@ -2155,6 +2193,29 @@ impl VisitNode<JsonLanguage> for Nursery {
             "all" => {
                 self.all = Some(self.map_to_boolean(&value, name_text, diagnostics)?);
             }
+            "noAbsoluteImportPath" => match value {
+                AnyJsonValue::JsonStringValue(_) => {
+                    let mut configuration = RuleConfiguration::default();
+                    self.map_to_known_string(&value, name_text, &mut configuration, diagnostics)?;
+                    self.no_absolute_import_path = Some(configuration);
+                }
+                AnyJsonValue::JsonObjectValue(_) => {
+                    let mut rule_configuration = RuleConfiguration::default();
+                    rule_configuration.map_rule_configuration(
+                        &value,
+                        name_text,
+                        "noAbsoluteImportPath",
+                        diagnostics,
+                    )?;
+                    self.no_absolute_import_path = Some(rule_configuration);
+                }
+                _ => {
+                    diagnostics.push(DeserializationDiagnostic::new_incorrect_type(
+                        "object or string",
+                        value.range(),
+                    ));
+                }
+            },
             "noApproximativeNumericConstant" => match value {
                 AnyJsonValue::JsonStringValue(_) => {
                     let mut configuration = RuleConfiguration::default();
@@ -2178,6 +2239,98 @@ impl VisitNode<JsonLanguage> for Nursery {
                     ));
                 }
             },
+            "noAsyncWithoutAwait" => match value {
+                AnyJsonValue::JsonStringValue(_) => {
+                    let mut configuration = RuleConfiguration::default();
+                    self.map_to_known_string(&value, name_text, &mut configuration, diagnostics)?;
+                    self.no_async_without_await = Some(configuration);
+                }
+                AnyJsonValue::JsonObjectValue(_) => {
+                    let mut rule_configuration = RuleConfiguration::default();
+                    rule_configuration.map_rule_configuration(
+                        &value,
+                        name_text,
+                        "noAsyncWithoutAwait",
+                        diagnostics,
+                    )?;
+                    self.no_async_without_await = Some(rule_configuration);
+                }
+                _ => {
+                    diagnostics.push(DeserializationDiagnostic::new_incorrect_type(
+                        "object or string",
+                        value.range(),
+                    ));
+                }
+            },
+            "noConditionalAssignment" => match value {
+                AnyJsonValue::JsonStringValue(_) => {
+                    let mut configuration = RuleConfiguration::default();
+                    self.map_to_known_string(&value, name_text, &mut configuration, diagnostics)?;
+                    self.no_conditional_assignment = Some(configuration);
+                }
+                AnyJsonValue::JsonObjectValue(_) => {
+                    let mut rule_configuration = RuleConfiguration::default();
+                    rule_configuration.map_rule_configuration(
+                        &value,
+                        name_text,
+                        "noConditionalAssignment",
+                        diagnostics,
+                    )?;
+                    self.no_conditional_assignment = Some(rule_configuration);
+                }
+                _ => {
+                    diagnostics.push(DeserializationDiagnostic::new_incorrect_type(
+                        "object or string",
+                        value.range(),
+                    ));
+                }
+            },
+            "noDirectMutation" => match value {
+                AnyJsonValue::JsonStringValue(_) => {
+                    let mut configuration = RuleConfiguration::default();
+                    self.map_to_known_string(&value, name_text, &mut configuration, diagnostics)?;
+                    self.no_direct_mutation = Some(configuration);
+                }
+                AnyJsonValue::JsonObjectValue(_) => {
+                    let mut rule_configuration = RuleConfiguration::default();
+                    rule_configuration.map_rule_configuration(
+                        &value,
+                        name_text,
+                        "noDirectMutation",
+                        diagnostics,
+                    )?;
+                    self.no_direct_mutation = Some(rule_configuration);
+                }
+                _ => {
+                    diagnostics.push(DeserializationDiagnostic::new_incorrect_type(
+                        "object or string",
+                        value.range(),
+                    ));
+                }
+            },
+            "noDoubleSlashComments" => match value {
+                AnyJsonValue::JsonStringValue(_) => {
+                    let mut configuration = RuleConfiguration::default();
+                    self.map_to_known_string(&value, name_text, &mut configuration, diagnostics)?;
+                    self.no_double_slash_comments = Some(configuration);
+                }
+                AnyJsonValue::JsonObjectValue(_) => {
+                    let mut rule_configuration = RuleConfiguration::default();
+                    rule_configuration.map_rule_configuration(
+                        &value,
+                        name_text,
+                        "noDoubleSlashComments",
+                        diagnostics,
+                    )?;
+                    self.no_double_slash_comments = Some(rule_configuration);
+                }
+                _ => {
+                    diagnostics.push(DeserializationDiagnostic::new_incorrect_type(
+                        "object or string",
+                        value.range(),
+                    ));
+                }
+            },
             "noDuplicateJsonKeys" => match value {
                 AnyJsonValue::JsonStringValue(_) => {
                     let mut configuration = RuleConfiguration::default();
@@ -2247,6 +2400,121 @@ impl VisitNode<JsonLanguage> for Nursery {
                     ));
                 }
             },
+            "noEnumMemberValueOverlap" => match value {
+                AnyJsonValue::JsonStringValue(_) => {
+                    let mut configuration = RuleConfiguration::default();
+                    self.map_to_known_string(&value, name_text, &mut configuration, diagnostics)?;
+                    self.no_enum_member_value_overlap = Some(configuration);
+                }
+                AnyJsonValue::JsonObjectValue(_) => {
+                    let mut rule_configuration = RuleConfiguration::default();
+                    rule_configuration.map_rule_configuration(
+                        &value,
+                        name_text,
+                        "noEnumMemberValueOverlap",
+                        diagnostics,
+                    )?;
+                    self.no_enum_member_value_overlap = Some(rule_configuration);
+                }
+                _ => {
+                    diagnostics.push(DeserializationDiagnostic::new_incorrect_type(
+                        "object or string",
+                        value.range(),
+                    ));
+                }
+            },
+            "noExcessivePropertyAccess" => match value {
+                AnyJsonValue::JsonStringValue(_) => {
+                    let mut configuration = RuleConfiguration::default();
+                    self.map_to_known_string(&value, name_text, &mut configuration, diagnostics)?;
+                    self.no_excessive_property_access = Some(configuration);
+                }
+                AnyJsonValue::JsonObjectValue(_) => {
+                    let mut rule_configuration = RuleConfiguration::default();
+                    rule_configuration.map_rule_configuration(
+                        &value,
+                        name_text,
+                        "noExcessivePropertyAccess",
+                        diagnostics,
+                    )?;
+                    self.no_excessive_property_access = Some(rule_configuration);
+                }
+                _ => {
+                    diagnostics.push(DeserializationDiagnostic::new_incorrect_type(
+                        "object or string",
+                        value.range(),
+                    ));
+                }
+            },
+            "noExcessiveSwitchCases" => match value {
+                AnyJsonValue::JsonStringValue(_) => {
+                    let mut configuration = RuleConfiguration::default();
+                    self.map_to_known_string(&value, name_text, &mut configuration, diagnostics)?;
+                    self.no_excessive_switch_cases = Some(configuration);
+                }
+                AnyJsonValue::JsonObjectValue(_) => {
+                    let mut rule_configuration = RuleConfiguration::default();
+                    rule_configuration.map_rule_configuration(
+                        &value,
+                        name_text,
+                        "noExcessiveSwitchCases",
+                        diagnostics,
+                    )?;
+                    self.no_excessive_switch_cases = Some(rule_configuration);
+                }
+                _ => {
+                    diagnostics.push(DeserializationDiagnostic::new_incorrect_type(
+                        "object or string",
+                        value.range(),
+                    ));
+                }
+            },
+            "noExpressionInStrictEquality" => match value {
+                AnyJsonValue::JsonStringValue(_) => {
+                    let mut configuration = RuleConfiguration::default();
+                    self.map_to_known_string(&value, name_text, &mut configuration, diagnostics)?;
+                    self.no_expression_in_strict_equality = Some(configuration);
+                }
+                AnyJsonValue::JsonObjectValue(_) => {
+                    let mut rule_configuration = RuleConfiguration::default();
+                    rule_configuration.map_rule_configuration(
+                        &value,
+                        name_text,
+                        "noExpressionInStrictEquality",
+                        diagnostics,
+                    )?;
+                    self.no_expression_in_strict_equality = Some(rule_configuration);
+                }
+                _ => {
+                    diagnostics.push(DeserializationDiagnostic::new_incorrect_type(
+                        "object or string",
+                        value.range(),
+                    ));
+                }
+            },
+            "noImplicitCoercion" => match value {
+                AnyJsonValue::JsonStringValue(_) => {
+                    let mut configuration = RuleConfiguration::default();
+                    self.map_to_known_string(&value, name_text, &mut configuration, diagnostics)?;
+                    self.no_implicit_coercion = Some(configuration);
+                }
+                AnyJsonValue::JsonObjectValue(_) => {
+                    let mut rule_configuration = RuleConfiguration::default();
+                    rule_configuration.map_rule_configuration(
+                        &value,
+                        name_text,
+                        "noImplicitCoercion",
+                        diagnostics,
+                    )?;
+                    self.no_implicit_coercion = Some(rule_configuration);
+                }
+                _ => {
+                    diagnostics.push(DeserializationDiagnostic::new_incorrect_type(
+                        "object or string",
+                        value.range(),
+                    ));
+                }
+            },
             "noInteractiveElementToNoninteractiveRole" => match value {
                 AnyJsonValue::JsonStringValue(_) => {
                     let mut configuration = RuleConfiguration::default();
@@ -2339,21 +2607,21 @@ impl VisitNode<JsonLanguage> for Nursery {
                     ));
                 }
             },
-            "noUnusedImports" => match value {
+            "noMixedImportStyle" => match value {
                 AnyJsonValue::JsonStringValue(_) => {
                     let mut configuration = RuleConfiguration::default();
                     self.map_to_known_string(&value, name_text, &mut configuration, diagnostics)?;
-                    self.no_unused_imports = Some(configuration);
+                    self.no_mixed_import_style = Some(configuration);
                 }
                 AnyJsonValue::JsonObjectValue(_) => {
                     let mut rule_configuration = RuleConfiguration::default();
                     rule_configuration.map_rule_configuration(
                         &value,
                         name_text,
-                        "noUnusedImports",
+                        "noMixedImportStyle",
                         diagnostics,
                     )?;
-                    self.no_unused_imports = Some(rule_configuration);
+                    self.no_mixed_import_style = Some(rule_configuration);
                 }
                 _ => {
                     diagnostics.push(DeserializationDiagnostic::new_incorrect_type(
@@ -2362,21 +2630,21 @@ impl VisitNode<JsonLanguage> for Nursery {
                     ));
                 }
             },
-            "noUselessElse" => match value {
+            "noMutableExports" => match value {
                 AnyJsonValue::JsonStringValue(_) => {
                     let mut configuration = RuleConfiguration::default();
                     self.map_to_known_string(&value, name_text, &mut configuration, diagnostics)?;
-                    self.no_useless_else = Some(configuration);
+                    self.no_mutable_exports = Some(configuration);
                 }
                 AnyJsonValue::JsonObjectValue(_) => {
                     let mut rule_configuration = RuleConfiguration::default();
                     rule_configuration.map_rule_configuration(
                         &value,
                         name_text,
-                        "noUselessElse",
+                        "noMutableExports",
                         diagnostics,
                     )?;
-                    self.no_useless_else = Some(rule_configuration);
+                    self.no_mutable_exports = Some(rule_configuration);
                 }
                 _ => {
                     diagnostics.push(DeserializationDiagnostic::new_incorrect_type(
@@ -2385,21 +2653,21 @@ impl VisitNode<JsonLanguage> for Nursery {
                     ));
                 }
             },
-            "noUselessLoneBlockStatements" => match value {
+            "noObjectSpreadInLoop" => match value {
                 AnyJsonValue::JsonStringValue(_) => {
                     let mut configuration = RuleConfiguration::default();
                     self.map_to_known_string(&value, name_text, &mut configuration, diagnostics)?;
-                    self.no_useless_lone_block_statements = Some(configuration);
+                    self.no_object_spread_in_loop = Some(configuration);
                 }
                 AnyJsonValue::JsonObjectValue(_) => {
                     let mut rule_configuration = RuleConfiguration::default();
                     rule_configuration.map_rule_configuration(
                         &value,
                         name_text,
-                        "noUselessLoneBlockStatements",
+                        "noObjectSpreadInLoop",
                         diagnostics,
                     )?;
-                    self.no_useless_lone_block_statements = Some(rule_configuration);
+                    self.no_object_spread_in_loop = Some(rule_configuration);
                 }
                 _ => {
                     diagnostics.push(DeserializationDiagnostic::new_incorrect_type(
@@ -2408,21 +2676,21 @@ impl VisitNode<JsonLanguage> for Nursery {
                     ));
                 }
             },
-            "useAriaActivedescendantWithTabindex" => match value {
+            "noPrettierIgnore" => match value {
                 AnyJsonValue::JsonStringValue(_) => {
                     let mut configuration = RuleConfiguration::default();
                     self.map_to_known_string(&value, name_text, &mut configuration, diagnostics)?;
-                    self.use_aria_activedescendant_with_tabindex = Some(configuration);
+                    self.no_prettier_ignore = Some(configuration);
                 }
                 AnyJsonValue::JsonObjectValue(_) => {
                     let mut rule_configuration = RuleConfiguration::default();
                     rule_configuration.map_rule_configuration(
                         &value,
                         name_text,
-                        "useAriaActivedescendantWithTabindex",
+                        "noPrettierIgnore",
                         diagnostics,
                     )?;
-                    self.use_aria_activedescendant_with_tabindex = Some(rule_configuration);
+                    self.no_prettier_ignore = Some(rule_configuration);
                 }
                 _ => {
                     diagnostics.push(DeserializationDiagnostic::new_incorrect_type(
@@ -2431,21 +2699,21 @@ impl VisitNode<JsonLanguage> for Nursery {
                     ));
                 }
             },
-            "useArrowFunction" => match value {
+            "noReExportAll" => match value {
                 AnyJsonValue::JsonStringValue(_) => {
                     let mut configuration = RuleConfiguration::default();
                     self.map_to_known_string(&value, name_text, &mut configuration, diagnostics)?;
-                    self.use_arrow_function = Some(configuration);
+                    self.no_re_export_all = Some(configuration);
                 }
                 AnyJsonValue::JsonObjectValue(_) => {
                     let mut rule_configuration = RuleConfiguration::default();
                     rule_configuration.map_rule_configuration(
                         &value,
                         name_text,
-                        "useArrowFunction",
+                        "noReExportAll",
                         diagnostics,
                     )?;
-                    self.use_arrow_function = Some(rule_configuration);
+                    self.no_re_export_all = Some(rule_configuration);
                 }
                 _ => {
                     diagnostics.push(DeserializationDiagnostic::new_incorrect_type(
@@ -2454,21 +2722,21 @@ impl VisitNode<JsonLanguage> for Nursery {
                     ));
                 }
             },
-            "useAsConstAssertion" => match value {
+            "noStandaloneBlocks" => match value {
                 AnyJsonValue::JsonStringValue(_) => {
                     let mut configuration = RuleConfiguration::default();
                     self.map_to_known_string(&value, name_text, &mut configuration, diagnostics)?;
-                    self.use_as_const_assertion = Some(configuration);
+                    self.no_standalone_blocks = Some(configuration);
                 }
                 AnyJsonValue::JsonObjectValue(_) => {
                     let mut rule_configuration = RuleConfiguration::default();
                     rule_configuration.map_rule_configuration(
                         &value,
                         name_text,
-                        "useAsConstAssertion",
+                        "noStandaloneBlocks",
                         diagnostics,
                     )?;
-                    self.use_as_const_assertion = Some(rule_configuration);
+                    self.no_standalone_blocks = Some(rule_configuration);
                 }
                 _ => {
                     diagnostics.push(DeserializationDiagnostic::new_incorrect_type(
@@ -2477,21 +2745,21 @@ impl VisitNode<JsonLanguage> for Nursery {
                     ));
                 }
             },
-            "useGroupedTypeImport" => match value {
+            "noStringBooleanParameter" => match value {
                 AnyJsonValue::JsonStringValue(_) => {
                     let mut configuration = RuleConfiguration::default();
                     self.map_to_known_string(&value, name_text, &mut configuration, diagnostics)?;
-                    self.use_grouped_type_import = Some(configuration);
+                    self.no_string_boolean_parameter = Some(configuration);
                 }
                 AnyJsonValue::JsonObjectValue(_) => {
                     let mut rule_configuration = RuleConfiguration::default();
                     rule_configuration.map_rule_configuration(
                         &value,
                         name_text,
-                        "useGroupedTypeImport",
+                        "noStringBooleanParameter",
                         diagnostics,
                     )?;
-                    self.use_grouped_type_import = Some(rule_configuration);
+                    self.no_string_boolean_parameter = Some(rule_configuration);
                 }
                 _ => {
                     diagnostics.push(DeserializationDiagnostic::new_incorrect_type(
@@ -2500,21 +2768,21 @@ impl VisitNode<JsonLanguage> for Nursery {
                     ));
                 }
             },
-            "useImportRestrictions" => match value {
+            "noStringLiteralType" => match value {
                 AnyJsonValue::JsonStringValue(_) => {
                     let mut configuration = RuleConfiguration::default();
                     self.map_to_known_string(&value, name_text, &mut configuration, diagnostics)?;
-                    self.use_import_restrictions = Some(configuration);
+                    self.no_string_literal_type = Some(configuration);
                 }
                 AnyJsonValue::JsonObjectValue(_) => {
                     let mut rule_configuration = RuleConfiguration::default();
                     rule_configuration.map_rule_configuration(
                         &value,
                         name_text,
-                        "useImportRestrictions",
+                        "noStringLiteralType",
                         diagnostics,
                     )?;
-                    self.use_import_restrictions = Some(rule_configuration);
+                    self.no_string_literal_type = Some(rule_configuration);
                 }
                 _ => {
                     diagnostics.push(DeserializationDiagnostic::new_incorrect_type(
@@ -2523,21 +2791,665 @@ impl VisitNode<JsonLanguage> for Nursery {
                     ));
                 }
             },
-            "useShorthandAssign" => match value {
+            "noStringOracle" => match value {
                 AnyJsonValue::JsonStringValue(_) => {
                     let mut configuration = RuleConfiguration::default();
                     self.map_to_known_string(&value, name_text, &mut configuration, diagnostics)?;
-                    self.use_shorthand_assign = Some(configuration);
+                    self.no_string_oracle = Some(configuration);
                 }
                 AnyJsonValue::JsonObjectValue(_) => {
                     let mut rule_configuration = RuleConfiguration::default();
                     rule_configuration.map_rule_configuration(
                         &value,
                         name_text,
-                        "useShorthandAssign",
+                        "noStringOracle",
                         diagnostics,
                     )?;
-                    self.use_shorthand_assign = Some(rule_configuration);
+                    self.no_string_oracle = Some(rule_configuration);
+                }
+                _ => {
+                    diagnostics.push(DeserializationDiagnostic::new_incorrect_type(
+                        "object or string",
+                        value.range(),
+                    ));
+                }
+            },
+            "noThrowLiteral" => match value {
+                AnyJsonValue::JsonStringValue(_) => {
+                    let mut configuration = RuleConfiguration::default();
+                    self.map_to_known_string(&value, name_text, &mut configuration, diagnostics)?;
+                    self.no_throw_literal = Some(configuration);
+                }
+                AnyJsonValue::JsonObjectValue(_) => {
+                    let mut rule_configuration = RuleConfiguration::default();
+                    rule_configuration.map_rule_configuration(
+                        &value,
+                        name_text,
+                        "noThrowLiteral",
+                        diagnostics,
+                    )?;
+                    self.no_throw_literal = Some(rule_configuration);
+                }
+                _ => {
+                    diagnostics.push(DeserializationDiagnostic::new_incorrect_type(
+                        "object or string",
+                        value.range(),
+                    ));
+                }
+            },
+            "noUnnecessaryAwait" => match value {
+                AnyJsonValue::JsonStringValue(_) => {
+                    let mut configuration = RuleConfiguration::default();
+                    self.map_to_known_string(&value, name_text, &mut configuration, diagnostics)?;
+                    self.no_unnecessary_await = Some(configuration);
+                }
+                AnyJsonValue::JsonObjectValue(_) => {
+                    let mut rule_configuration = RuleConfiguration::default();
+                    rule_configuration.map_rule_configuration(
+                        &value,
+                        name_text,
+                        "noUnnecessaryAwait",
+                        diagnostics,
+                    )?;
+                    self.no_unnecessary_await = Some(rule_configuration);
+                }
+                _ => {
+                    diagnostics.push(DeserializationDiagnostic::new_incorrect_type(
+                        "object or string",
+                        value.range(),
+                    ));
+                }
+            },
+            "noUnsafeOptionalChain" => match value {
+                AnyJsonValue::JsonStringValue(_) => {
+                    let mut configuration = RuleConfiguration::default();
+                    self.map_to_known_string(&value, name_text, &mut configuration, diagnostics)?;
+                    self.no_unsafe_optional_chain = Some(configuration);
+                }
+                AnyJsonValue::JsonObjectValue(_) => {
+                    let mut rule_configuration = RuleConfiguration::default();
+                    rule_configuration.map_rule_configuration(
+                        &value,
+                        name_text,
+                        "noUnsafeOptionalChain",
+                        diagnostics,
+                    )?;
+                    self.no_unsafe_optional_chain = Some(rule_configuration);
+                }
+                _ => {
+                    diagnostics.push(DeserializationDiagnostic::new_incorrect_type(
+                        "object or string",
+                        value.range(),
+                    ));
+                }
+            },
+            "noUnsafeRegex" => match value {
+                AnyJsonValue::JsonStringValue(_) => {
+                    let mut configuration = RuleConfiguration::default();
+                    self.map_to_known_string(&value, name_text, &mut configuration, diagnostics)?;
+                    self.no_unsafe_regex = Some(configuration);
+                }
+                AnyJsonValue::JsonObjectValue(_) => {
+                    let mut rule_configuration = RuleConfiguration::default();
+                    rule_configuration.map_rule_configuration(
+                        &value,
+                        name_text,
+                        "noUnsafeRegex",
+                        diagnostics,
+                    )?;
+                    self.no_unsafe_regex = Some(rule_configuration);
+                }
+                _ => {
+                    diagnostics.push(DeserializationDiagnostic::new_incorrect_type(
+                        "object or string",
+                        value.range(),
+                    ));
+                }
+            },
+            "noUnterminatedMultilineComment" => match value {
+                AnyJsonValue::JsonStringValue(_) => {
+                    let mut configuration = RuleConfiguration::default();
+                    self.map_to_known_string(&value, name_text, &mut configuration, diagnostics)?;
+                    self.no_unterminated_multiline_comment = Some(configuration);
+                }
+                AnyJsonValue::JsonObjectValue(_) => {
+                    let mut rule_configuration = RuleConfiguration::default();
+                    rule_configuration.map_rule_configuration(
+                        &value,
+                        name_text,
+                        "noUnterminatedMultilineComment",
+                        diagnostics,
+                    )?;
+                    self.no_unterminated_multiline_comment = Some(rule_configuration);
+                }
+                _ => {
+                    diagnostics.push(DeserializationDiagnostic::new_incorrect_type(
+                        "object or string",
+                        value.range(),
+                    ));
+                }
+            },
+            "noUnusedImports" => match value {
+                AnyJsonValue::JsonStringValue(_) => {
+                    let mut configuration = RuleConfiguration::default();
+                    self.map_to_known_string(&value, name_text, &mut configuration, diagnostics)?;
+                    self.no_unused_imports = Some(configuration);
+                }
+                AnyJsonValue::JsonObjectValue(_) => {
+                    let mut rule_configuration = RuleConfiguration::default();
+                    rule_configuration.map_rule_configuration(
+                        &value,
+                        name_text,
+                        "noUnusedImports",
+                        diagnostics,
+                    )?;
+                    self.no_unused_imports = Some(rule_configuration);
+                }
+                _ => {
+                    diagnostics.push(DeserializationDiagnostic::new_incorrect_type(
+                        "object or string",
+                        value.range(),
+                    ));
+                }
+            },
+            "noUnusedPrivateClassMembers" => match value {
+                AnyJsonValue::JsonStringValue(_) => {
+                    let mut configuration = RuleConfiguration::default();
+                    self.map_to_known_string(&value, name_text, &mut configuration, diagnostics)?;
+                    self.no_unused_private_class_members = Some(configuration);
+                }
+                AnyJsonValue::JsonObjectValue(_) => {
+                    let mut rule_configuration = RuleConfiguration::default();
+                    rule_configuration.map_rule_configuration(
+                        &value,
+                        name_text,
+                        "noUnusedPrivateClassMembers",
+                        diagnostics,
+                    )?;
+                    self.no_unused_private_class_members = Some(rule_configuration);
+                }
+                _ => {
+                    diagnostics.push(DeserializationDiagnostic::new_incorrect_type(
+                        "object or string",
+                        value.range(),
+                    ));
+                }
+            },
+            "noUselessElse" => match value {
+                AnyJsonValue::JsonStringValue(_) => {
+                    let mut configuration = RuleConfiguration::default();
+                    self.map_to_known_string(&value, name_text, &mut configuration, diagnostics)?;
+                    self.no_useless_else = Some(configuration);
+                }
+                AnyJsonValue::JsonObjectValue(_) => {
+                    let mut rule_configuration = RuleConfiguration::default();
+                    rule_configuration.map_rule_configuration(
+                        &value,
+                        name_text,
+                        "noUselessElse",
+                        diagnostics,
+                    )?;
+                    self.no_useless_else = Some(rule_configuration);
+                }
+                _ => {
+                    diagnostics.push(DeserializationDiagnostic::new_incorrect_type(
+                        "object or string",
+                        value.range(),
+                    ));
+                }
+            },
+            "noUselessFormatSuppressionRegion" => match value {
+                AnyJsonValue::JsonStringValue(_) => {
+                    let mut configuration = RuleConfiguration::default();
+                    self.map_to_known_string(&value, name_text, &mut configuration, diagnostics)?;
+                    self.no_useless_format_suppression_region = Some(configuration);
+                }
+                AnyJsonValue::JsonObjectValue(_) => {
+                    let mut rule_configuration = RuleConfiguration::default();
+                    rule_configuration.map_rule_configuration(
+                        &value,
+                        name_text,
+                        "noUselessFormatSuppressionRegion",
+                        diagnostics,
+                    )?;
+                    self.no_useless_format_suppression_region = Some(rule_configuration);
+                }
+                _ => {
+                    diagnostics.push(DeserializationDiagnostic::new_incorrect_type(
+                        "object or string",
+                        value.range(),
+                    ));
+                }
+            },
+            "noUselessLoneBlockStatements" => match value {
+                AnyJsonValue::JsonStringValue(_) => {
+                    let mut configuration = RuleConfiguration::default();
+                    self.map_to_known_string(&value, name_text, &mut configuration, diagnostics)?;
+                    self.no_useless_lone_block_statements = Some(configuration);
+                }
+                AnyJsonValue::JsonObjectValue(_) => {
+                    let mut rule_configuration = RuleConfiguration::default();
+                    rule_configuration.map_rule_configuration(
+                        &value,
+                        name_text,
+                        "noUselessLoneBlockStatements",
+                        diagnostics,
+                    )?;
+                    self.no_useless_lone_block_statements = Some(rule_configuration);
+                }
+                _ => {
+                    diagnostics.push(DeserializationDiagnostic::new_incorrect_type(
+                        "object or string",
+                        value.range(),
+                    ));
+                }
+            },
+            "useAriaActivedescendantWithTabindex" => match value {
+                AnyJsonValue::JsonStringValue(_) => {
+                    let mut configuration = RuleConfiguration::default();
+                    self.map_to_known_string(&value, name_text, &mut configuration, diagnostics)?;
+                    self.use_aria_activedescendant_with_tabindex = Some(configuration);
+                }
+                AnyJsonValue::JsonObjectValue(_) => {
+                    let mut rule_configuration = RuleConfiguration::default();
+                    rule_configuration.map_rule_configuration(
+                        &value,
+                        name_text,
+                        "useAriaActivedescendantWithTabindex",
+                        diagnostics,
+                    )?;
+                    self.use_aria_activedescendant_with_tabindex = Some(rule_configuration);
+                }
+                _ => {
+                    diagnostics.push(DeserializationDiagnostic::new_incorrect_type(
+                        "object or string",
+                        value.range(),
+                    ));
+                }
+            },
+            "useArrayLiteralSpread" => match value {
+                AnyJsonValue::JsonStringValue(_) => {
+                    let mut configuration = RuleConfiguration::default();
+                    self.map_to_known_string(&value, name_text, &mut configuration, diagnostics)?;
+                    self.use_array_literal_spread = Some(configuration);
+                }
+                AnyJsonValue::JsonObjectValue(_) => {
+                    let mut rule_configuration = RuleConfiguration::default();
+                    rule_configuration.map_rule_configuration(
+                        &value,
+                        name_text,
+                        "useArrayLiteralSpread",
+                        diagnostics,
+                    )?;
+                    self.use_array_literal_spread = Some(rule_configuration);
+                }
+                _ => {
+                    diagnostics.push(DeserializationDiagnostic::new_incorrect_type(
+                        "object or string",
+                        value.range(),
+                    ));
+                }
+            },
+            "useArrowFunction" => match value {
+                AnyJsonValue::JsonStringValue(_) => {
+                    let mut configuration = RuleConfiguration::default();
+                    self.map_to_known_string(&value, name_text, &mut configuration, diagnostics)?;
+                    self.use_arrow_function = Some(configuration);
+                }
+                AnyJsonValue::JsonObjectValue(_) => {
+                    let mut rule_configuration = RuleConfiguration::default();
+                    rule_configuration.map_rule_configuration(
+                        &value,
+                        name_text,
+                        "useArrowFunction",
+                        diagnostics,
+                    )?;
+                    self.use_arrow_function = Some(rule_configuration);
+                }
+                _ => {
+                    diagnostics.push(DeserializationDiagnostic::new_incorrect_type(
+                        "object or string",
+                        value.range(),
+                    ));
+                }
+            },
+            "useAsConstAssertion" => match value {
+                AnyJsonValue::JsonStringValue(_) => {
+                    let mut configuration = RuleConfiguration::default();
+                    self.map_to_known_string(&value, name_text, &mut configuration, diagnostics)?;
+                    self.use_as_const_assertion = Some(configuration);
+                }
+                AnyJsonValue::JsonObjectValue(_) => {
+                    let mut rule_configuration = RuleConfiguration::default();
+                    rule_configuration.map_rule_configuration(
+                        &value,
+                        name_text,
+                        "useAsConstAssertion",
+                        diagnostics,
+                    )?;
+                    self.use_as_const_assertion = Some(rule_configuration);
+                }
+                _ => {
+                    diagnostics.push(DeserializationDiagnostic::new_incorrect_type(
+                        "object or string",
+                        value.range(),
+                    ));
+                }
+            },
+            "useConsistentEmptyLineBetweenClassMembers" => match value {
+                AnyJsonValue::JsonStringValue(_) => {
+                    let mut configuration = RuleConfiguration::default();
+                    self.map_to_known_string(&value, name_text, &mut configuration, diagnostics)?;
+                    self.use_consistent_empty_line_between_class_members = Some(configuration);
+                }
+                AnyJsonValue::JsonObjectValue(_) => {
+                    let mut rule_configuration = RuleConfiguration::default();
+                    rule_configuration.map_rule_configuration(
+                        &value,
+                        name_text,
+                        "useConsistentEmptyLineBetweenClassMembers",
+                        diagnostics,
+                    )?;
+                    self.use_consistent_empty_line_between_class_members = Some(rule_configuration);
+                }
+                _ => {
+                    diagnostics.push(DeserializationDiagnostic::new_incorrect_type(
+                        "object or string",
+                        value.range(),
+                    ));
+                }
+            },
+            "useConsistentObjectDestructuring" => match value {
+                AnyJsonValue::JsonStringValue(_) => {
+                    let mut configuration = RuleConfiguration::default();
+                    self.map_to_known_string(&value, name_text, &mut configuration, diagnostics)?;
+                    self.use_consistent_object_destructuring = Some(configuration);
+                }
+                AnyJsonValue::JsonObjectValue(_) => {
+                    let mut rule_configuration = RuleConfiguration::default();
+                    rule_configuration.map_rule_configuration(
+                        &value,
+                        name_text,
+                        "useConsistentObjectDestructuring",
+                        diagnostics,
+                    )?;
+                    self.use_consistent_object_destructuring = Some(rule_configuration);
+                }
+                _ => {
+                    diagnostics.push(DeserializationDiagnostic::new_incorrect_type(
+                        "object or string",
+                        value.range(),
+                    ));
+                }
+            },
+            "useExplicitReturnType" => match value {
+                AnyJsonValue::JsonStringValue(_) => {
+                    let mut configuration = RuleConfiguration::default();
+                    self.map_to_known_string(&value, name_text, &mut configuration, diagnostics)?;
+                    self.use_explicit_return_type = Some(configuration);
+                }
+                AnyJsonValue::JsonObjectValue(_) => {
+                    let mut rule_configuration = RuleConfiguration::default();
+                    rule_configuration.map_rule_configuration(
+                        &value,
+                        name_text,
+                        "useExplicitReturnType",
+                        diagnostics,
+                    )?;
+                    self.use_explicit_return_type = Some(rule_configuration);
+                }
+                _ => {
+                    diagnostics.push(DeserializationDiagnostic::new_incorrect_type(
+                        "object or string",
+                        value.range(),
+                    ));
+                }
+            },
+            "useGroupedTypeImport" => match value {
+                AnyJsonValue::JsonStringValue(_) => {
+                    let mut configuration = RuleConfiguration::default();
+                    self.map_to_known_string(&value, name_text, &mut configuration, diagnostics)?;
+                    self.use_grouped_type_import = Some(configuration);
+                }
+                AnyJsonValue::JsonObjectValue(_) => {
+                    let mut rule_configuration = RuleConfiguration::default();
+                    rule_configuration.map_rule_configuration(
+                        &value,
+                        name_text,
+                        "useGroupedTypeImport",
+                        diagnostics,
+                    )?;
+                    self.use_grouped_type_import = Some(rule_configuration);
+                }
+                _ => {
+                    diagnostics.push(DeserializationDiagnostic::new_incorrect_type(
+                        "object or string",
+                        value.range(),
+                    ));
+                }
+            },
+            "useImportMeta" => match value {
+                AnyJsonValue::JsonStringValue(_) => {
+                    let mut configuration = RuleConfiguration::default();
+                    self.map_to_known_string(&value, name_text, &mut configuration, diagnostics)?;
+                    self.use_import_meta = Some(configuration);
+                }
+                AnyJsonValue::JsonObjectValue(_) => {
+                    let mut rule_configuration = RuleConfiguration::default();
+                    rule_configuration.map_rule_configuration(
+                        &value,
+                        name_text,
+                        "useImportMeta",
+                        diagnostics,
+                    )?;
+                    self.use_import_meta = Some(rule_configuration);
+                }
+                _ => {
+                    diagnostics.push(DeserializationDiagnostic::new_incorrect_type(
+                        "object or string",
+                        value.range(),
+                    ));
+                }
+            },
+            "useImportRestrictions" => match value {
+                AnyJsonValue::JsonStringValue(_) => {
+                    let mut configuration = RuleConfiguration::default();
+                    self.map_to_known_string(&value, name_text, &mut configuration, diagnostics)?;
+                    self.use_import_restrictions = Some(configuration);
+                }
+                AnyJsonValue::JsonObjectValue(_) => {
+                    let mut rule_configuration = RuleConfiguration::default();
+                    rule_configuration.map_rule_configuration(
+                        &value,
+                        name_text,
+                        "useImportRestrictions",
+                        diagnostics,
+                    )?;
+                    self.use_import_restrictions = Some(rule_configuration);
+                }
+                _ => {
+                    diagnostics.push(DeserializationDiagnostic::new_incorrect_type(
+                        "object or string",
+                        value.range(),
+                    ));
+                }
+            },
+            "useIteratorProtocol" => match value {
+                AnyJsonValue::JsonStringValue(_) => {
+                    let mut configuration = RuleConfiguration::default();
+                    self.map_to_known_string(&value, name_text, &mut configuration, diagnostics)?;
+                    self.use_iterator_protocol = Some(configuration);
+                }
+                AnyJsonValue::JsonObjectValue(_) => {
+                    let mut rule_configuration = RuleConfiguration::default();
+                    rule_configuration.map_rule_configuration(
+                        &value,
+                        name_text,
+                        "useIteratorProtocol",
+                        diagnostics,
+                    )?;
+                    self.use_iterator_protocol = Some(rule_configuration);
+                }
+                _ => {
+                    diagnostics.push(DeserializationDiagnostic::new_incorrect_type(
+                        "object or string",
+                        value.range(),
+                    ));
+                }
+            },
+            "useNullishCoalescingAssignment" => match value {
+                AnyJsonValue::JsonStringValue(_) => {
+                    let mut configuration = RuleConfiguration::default();
+                    self.map_to_known_string(&value, name_text, &mut configuration, diagnostics)?;
+                    self.use_nullish_coalescing_assignment = Some(configuration);
+                }
+                AnyJsonValue::JsonObjectValue(_) => {
+                    let mut rule_configuration = RuleConfiguration::default();
+                    rule_configuration.map_rule_configuration(
+                        &value,
+                        name_text,
+                        "useNullishCoalescingAssignment",
+                        diagnostics,
+                    )?;
+                    self.use_nullish_coalescing_assignment = Some(rule_configuration);
+                }
+                _ => {
+                    diagnostics.push(DeserializationDiagnostic::new_incorrect_type(
+                        "object or string",
+                        value.range(),
+                    ));
+                }
+            },
+            "useNumberToFixed" => match value {
+                AnyJsonValue::JsonStringValue(_) => {
+                    let mut configuration = RuleConfiguration::default();
+                    self.map_to_known_string(&value, name_text, &mut configuration, diagnostics)?;
+                    self.use_number_to_fixed = Some(configuration);
+                }
+                AnyJsonValue::JsonObjectValue(_) => {
+                    let mut rule_configuration = RuleConfiguration::default();
+                    rule_configuration.map_rule_configuration(
+                        &value,
+                        name_text,
+                        "useNumberToFixed",
+                        diagnostics,
+                    )?;
+                    self.use_number_to_fixed = Some(rule_configuration);
+                }
+                _ => {
+                    diagnostics.push(DeserializationDiagnostic::new_incorrect_type(
+                        "object or string",
+                        value.range(),
+                    ));
+                }
+            },
+            "useObjectShorthand" => match value {
+                AnyJsonValue::JsonStringValue(_) => {
+                    let mut configuration = RuleConfiguration::default();
+                    self.map_to_known_string(&value, name_text, &mut configuration, diagnostics)?;
+                    self.use_object_shorthand = Some(configuration);
+                }
+                AnyJsonValue::JsonObjectValue(_) => {
+                    let mut rule_configuration = RuleConfiguration::default();
+                    rule_configuration.map_rule_configuration(
+                        &value,
+                        name_text,
+                        "useObjectShorthand",
+                        diagnostics,
+                    )?;
+                    self.use_object_shorthand = Some(rule_configuration);
+                }
+                _ => {
+                    diagnostics.push(DeserializationDiagnostic::new_incorrect_type(
+                        "object or string",
+                        value.range(),
+                    ));
+                }
+            },
+            "useReadonlyParameters" => match value {
+                AnyJsonValue::JsonStringValue(_) => {
+                    let mut configuration = RuleConfiguration::default();
+                    self.map_to_known_string(&value, name_text, &mut configuration, diagnostics)?;
+                    self.use_readonly_parameters = Some(configuration);
+                }
+                AnyJsonValue::JsonObjectValue(_) => {
+                    let mut rule_configuration = RuleConfiguration::default();
+                    rule_configuration.map_rule_configuration(
+                        &value,
+                        name_text,
+                        "useReadonlyParameters",
+                        diagnostics,
+                    )?;
+                    self.use_readonly_parameters = Some(rule_configuration);
+                }
+                _ => {
+                    diagnostics.push(DeserializationDiagnostic::new_incorrect_type(
+                        "object or string",
+                        value.range(),
+                    ));
+                }
+            },
+            "useShorthandAssign" => match value {
+                AnyJsonValue::JsonStringValue(_) => {
+                    let mut configuration = RuleConfiguration::default();
+                    self.map_to_known_string(&value, name_text, &mut configuration, diagnostics)?;
+                    self.use_shorthand_assign = Some(configuration);
+                }
+                AnyJsonValue::JsonObjectValue(_) => {
+                    let mut rule_configuration = RuleConfiguration::default();
+                    rule_configuration.map_rule_configuration(
+                        &value,
+                        name_text,
+                        "useShorthandAssign",
+                        diagnostics,
+                    )?;
+                    self.use_shorthand_assign = Some(rule_configuration);
+                }
+                _ => {
+                    diagnostics.push(DeserializationDiagnostic::new_incorrect_type(
+                        "object or string",
+                        value.range(),
+                    ));
+                }
+            },
+            "useStructuredClone" => match value {
+                AnyJsonValue::JsonStringValue(_) => {
+                    let mut configuration = RuleConfiguration::default();
+                    self.map_to_known_string(&value, name_text, &mut configuration, diagnostics)?;
+                    self.use_structured_clone = Some(configuration);
+                }
+                AnyJsonValue::JsonObjectValue(_) => {
+                    let mut rule_configuration = RuleConfiguration::default();
+                    rule_configuration.map_rule_configuration(
+                        &value,
+                        name_text,
+                        "useStructuredClone",
+                        diagnostics,
+                    )?;
+                    self.use_structured_clone = Some(rule_configuration);
+                }
+                _ => {
+                    diagnostics.push(DeserializationDiagnostic::new_incorrect_type(
+                        "object or string",
+                        value.range(),
+                    ));
+                }
+            },
+            "useTaskDestructuring" => match value {
+                AnyJsonValue::JsonStringValue(_) => {
+                    let mut configuration = RuleConfiguration::default();
+                    self.map_to_known_string(&value, name_text, &mut configuration, diagnostics)?;
+                    self.use_task_destructuring = Some(configuration);
+                }
+                AnyJsonValue::JsonObjectValue(_) => {
+                    let mut rule_configuration = RuleConfiguration::default();
+                    rule_configuration.map_rule_configuration(
+                        &value,
+                        name_text,
+                        "useTaskDestructuring",
+                        diagnostics,
+                    )?;
+                    self.use_task_destructuring = Some(rule_configuration);
                 }
                 _ => {
                     diagnostics.push(DeserializationDiagnostic::new_incorrect_type(
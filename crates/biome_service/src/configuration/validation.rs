@@ -0,0 +1,133 @@
+//! Validation of a `biome.json` file that returns structured results instead of printed
+//! diagnostics, so that clients like editor extensions can show inline errors.
+
+use crate::configuration::Configuration;
+use biome_deserialize::json::deserialize_from_json_ast;
+use biome_diagnostics::{Error, PrintDescription, Severity};
+use biome_json_parser::{parse_json, JsonParserOptions};
+use biome_json_syntax::{JsonArrayElementList, JsonMember, JsonSyntaxNode};
+use biome_rowan::{AstNode, AstSeparatedList, NodeOrToken, TextRange};
+use serde::{Deserialize, Serialize};
+
+/// An issue found while validating a configuration file.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConfigurationValidationDiagnostic {
+    /// [JSON pointer](https://datatracker.ietf.org/doc/html/rfc6901) to the offending value,
+    /// e.g. `/linter/rules/style`. The pointer is empty when the issue concerns the whole document.
+    pub path: String,
+    /// A description of the issue
+    pub message: String,
+    /// The byte range of the offending value in the validated source
+    pub range: TextRange,
+    /// The severity of the issue
+    pub severity: Severity,
+}
+
+/// Parses `json` as the content of a `biome.json` file and returns all the syntax errors
+/// and the configuration errors that it contains.
+///
+/// An empty result means that the configuration is valid.
+///
+/// ## Examples
+///
+/// ```
+/// use biome_service::configuration::validate_configuration;
+///
+/// let diagnostics = validate_configuration(r#"{ "formatter": { "indentStyle": "tabs" } }"#);
+/// assert_eq!(diagnostics.len(), 1);
+/// assert_eq!(diagnostics[0].path, "/formatter/indentStyle");
+/// ```
+pub fn validate_configuration(json: &str) -> Vec<ConfigurationValidationDiagnostic> {
+    let parse = parse_json(json, JsonParserOptions::default());
+    let root = parse.tree();
+    let deserialized = deserialize_from_json_ast::<Configuration>(&root);
+
+    let parse_errors = parse.into_diagnostics().into_iter().map(Error::from);
+    parse_errors
+        .chain(deserialized.into_diagnostics())
+        .map(|error| {
+            let range = error.location().span.unwrap_or_default();
+            ConfigurationValidationDiagnostic {
+                path: json_pointer(root.syntax(), range),
+                message: PrintDescription(&error).to_string(),
+                range,
+                severity: error.severity(),
+            }
+        })
+        .collect()
+}
+
+/// Returns the JSON pointer of the innermost value that covers `range`.
+fn json_pointer(root: &JsonSyntaxNode, range: TextRange) -> String {
+    if !root.text_range().contains_range(range) {
+        return String::new();
+    }
+    let node = match root.covering_element(range) {
+        NodeOrToken::Node(node) => Some(node),
+        NodeOrToken::Token(token) => token.parent(),
+    };
+    let mut segments = Vec::new();
+    for node in node.iter().flat_map(|node| node.ancestors()) {
+        if let Some(member) = JsonMember::cast_ref(&node) {
+            if let Ok(name) = member.name().and_then(|name| name.inner_string_text()) {
+                segments.push(name.text().replace('~', "~0").replace('/', "~1"));
+            }
+        } else if let Some(list) = node.parent().and_then(JsonArrayElementList::cast) {
+            let index = list
+                .iter()
+                .position(|element| element.map_or(false, |element| element.syntax() == &node));
+            if let Some(index) = index {
+                segments.push(index.to_string());
+            }
+        }
+    }
+    segments
+        .iter()
+        .rev()
+        .fold(String::new(), |pointer, segment| pointer + "/" + segment)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::validate_configuration;
+    use biome_diagnostics::Severity;
+
+    #[test]
+    fn valid_configuration() {
+        let diagnostics = validate_configuration(
+            r#"{ "linter": { "rules": { "style": { "noVar": "error" } } } }"#,
+        );
+        assert!(diagnostics.is_empty(), "{diagnostics:?}");
+    }
+
+    #[test]
+    fn unknown_key() {
+        let source = r#"{ "linter": { "rules": { "style": { "noFoo": "error" } } } }"#;
+        let diagnostics = validate_configuration(source);
+        assert_eq!(diagnostics.len(), 1);
+        let diagnostic = &diagnostics[0];
+        assert_eq!(diagnostic.path, "/linter/rules/style/noFoo");
+        assert_eq!(&source[diagnostic.range], r#""noFoo""#);
+        assert_eq!(diagnostic.severity, Severity::Error);
+        assert!(diagnostic.message.contains("noFoo"));
+    }
+
+    #[test]
+    fn array_element() {
+        let source = r#"{ "files": { "ignore": ["a", 1] } }"#;
+        let diagnostics = validate_configuration(source);
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].path, "/files/ignore/1");
+        assert_eq!(&source[diagnostics[0].range], "1");
+    }
+
+    #[test]
+    fn syntax_error() {
+        let diagnostics = validate_configuration(r#"{ "linter": "#);
+        assert!(!diagnostics.is_empty());
+        assert!(diagnostics
+            .iter()
+            .all(|diagnostic| diagnostic.severity == Severity::Error));
+    }
+}
@@ -3,7 +3,7 @@ use crate::ConfigurationDiagnostic;
 use biome_console::fmt::Bytes;
 use biome_console::markup;
 use biome_diagnostics::{
-    category, Category, Diagnostic, DiagnosticTags, Location, Severity, Visit,
+    category, Category, Diagnostic, DiagnosticTags, Location, LogCategory, Severity, Visit,
 };
 use biome_formatter::{FormatError, PrintError};
 use biome_fs::FileSystemDiagnostic;
@@ -407,7 +407,11 @@ pub struct FileTooLarge {
 
 impl Diagnostic for FileTooLarge {
     fn category(&self) -> Option<&'static Category> {
-        Some(category!("internalError/fs"))
+        Some(category!("files/tooLarge"))
+    }
+
+    fn severity(&self) -> Severity {
+        Severity::Warning
     }
 
     fn message(&self, fmt: &mut biome_console::fmt::Formatter<'_>) -> std::io::Result<()> {
@@ -418,6 +422,15 @@ impl Diagnostic for FileTooLarge {
         )
     }
 
+    fn advices(&self, visitor: &mut dyn Visit) -> std::io::Result<()> {
+        visitor.record_log(
+            LogCategory::Info,
+            &markup! {
+                "The file is skipped. Use the option "<Emphasis>"files.maxSize"</Emphasis>" to change the maximum size."
+            },
+        )
+    }
+
     fn description(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
         write!(fmt,
                "Size of {} is {} which exceeds configured maximum of {} for this project. \
@@ -26,9 +26,13 @@ use biome_js_analyze::{
     analyze, analyze_with_inspect_matcher, visit_registry, ControlFlowGraph, RuleError,
 };
 use biome_js_formatter::context::trailing_comma::TrailingComma;
-use biome_js_formatter::context::ArrowParentheses;
 use biome_js_formatter::context::JsFormatOptions;
 use biome_js_formatter::context::Semicolons;
+use biome_js_formatter::context::{
+    ArrowParentheses, DoWhileStyle, JsxAttributeAlignment, LongCommentStyle, NestedTernaryStyle,
+    NumericLiteralStyle, ObjectDestructuringSpacing, TemplateLiteralIndentation,
+    TypeAssertionStyle, UndefinedStyle, WrapLongStrings,
+};
 use biome_js_formatter::context::{QuoteProperties, QuoteStyle};
 use biome_js_formatter::format_node;
 use biome_js_parser::JsParserOptions;
@@ -53,6 +57,23 @@ pub struct JsFormatterSettings {
     pub trailing_comma: Option<TrailingComma>,
     pub semicolons: Option<Semicolons>,
     pub arrow_parentheses: Option<ArrowParentheses>,
+    pub template_literal_indentation: Option<TemplateLiteralIndentation>,
+    pub jsx_attribute_alignment: Option<JsxAttributeAlignment>,
+    pub honor_prettier_ignore: Option<bool>,
+    pub function_call_arguments_on_one_line: Option<u8>,
+    pub wrap_long_strings: Option<WrapLongStrings>,
+    pub long_comment_style: Option<LongCommentStyle>,
+    pub object_destructuring_spacing: Option<ObjectDestructuringSpacing>,
+    pub blank_lines_after_imports: Option<u8>,
+    pub align_consecutive_assignments: Option<bool>,
+    pub undefined_style: Option<UndefinedStyle>,
+    pub nested_ternary_style: Option<NestedTernaryStyle>,
+    pub else_on_same_line: Option<bool>,
+    pub keep_import_meta_on_one_line: Option<bool>,
+    pub break_template_expressions: Option<bool>,
+    pub do_while_style: Option<DoWhileStyle>,
+    pub numeric_literal_style: Option<NumericLiteralStyle>,
+    pub type_assertion_style: Option<TypeAssertionStyle>,
     pub line_width: Option<LineWidth>,
     pub indent_width: Option<IndentWidth>,
     pub indent_style: Option<IndentStyle>,
@@ -99,7 +120,7 @@ impl Language for JsLanguage {
         language: &JsFormatterSettings,
         path: &RomePath,
     ) -> JsFormatOptions {
-        overrides.as_js_format_options(path).unwrap_or_else(|| {
+        let options = overrides.as_js_format_options(path).unwrap_or_else(|| {
             let indent_style = if let Some(indent_style) = language.indent_style {
                 indent_style
             } else {
@@ -125,7 +146,44 @@ impl Language for JsLanguage {
                 .with_trailing_comma(language.trailing_comma.unwrap_or_default())
                 .with_semicolons(language.semicolons.unwrap_or_default())
                 .with_arrow_parentheses(language.arrow_parentheses.unwrap_or_default())
-        })
+                .with_template_literal_indentation(
+                    language.template_literal_indentation.unwrap_or_default(),
+                )
+                .with_jsx_attribute_alignment(language.jsx_attribute_alignment.unwrap_or_default())
+                .with_honor_prettier_ignore(language.honor_prettier_ignore.unwrap_or(true))
+                .with_function_call_arguments_on_one_line(
+                    language
+                        .function_call_arguments_on_one_line
+                        .unwrap_or_default(),
+                )
+                .with_wrap_long_strings(language.wrap_long_strings.unwrap_or_default())
+                .with_long_comment_style(language.long_comment_style.unwrap_or_default())
+                .with_object_destructuring_spacing(
+                    language.object_destructuring_spacing.unwrap_or_default(),
+                )
+                .with_blank_lines_after_imports(language.blank_lines_after_imports)
+                .with_align_consecutive_assignments(
+                    language.align_consecutive_assignments.unwrap_or_default(),
+                )
+                .with_undefined_style(language.undefined_style.unwrap_or_default())
+                .with_nested_ternary_style(language.nested_ternary_style.unwrap_or_default())
+                .with_else_on_same_line(language.else_on_same_line.unwrap_or(true))
+                .with_keep_import_meta_on_one_line(
+                    language.keep_import_meta_on_one_line.unwrap_or(true),
+                )
+                .with_break_template_expressions(
+                    language.break_template_expressions.unwrap_or(false),
+                )
+                .with_do_while_style(language.do_while_style.unwrap_or_default())
+                .with_numeric_literal_style(language.numeric_literal_style.unwrap_or_default())
+                .with_type_assertion_style(language.type_assertion_style.unwrap_or_default())
+        });
+        options.with_trailing_newline(
+            overrides
+                .as_trailing_newline(path.as_path())
+                .or(global.trailing_newline)
+                .unwrap_or_default(),
+        )
     }
 }
 
@@ -469,6 +527,7 @@ fn fix_all(params: FixAllParams) -> Result<FixFileResult, WorkspaceError> {
         should_format,
         rome_path,
         mut filter,
+        rule_groups,
     } = params;
 
     let file_source = parse
@@ -497,6 +556,13 @@ fn fix_all(params: FixAllParams) -> Result<FixFileResult, WorkspaceError> {
                 if action.is_suppression() {
                     continue;
                 }
+                let in_rule_groups = rule_groups.is_empty()
+                    || action
+                        .rule_name
+                        .is_some_and(|(group, _)| rule_groups.iter().any(|name| name == group));
+                if !in_rule_groups {
+                    continue;
+                }
 
                 match fix_file_mode {
                     FixFileMode::SafeFixes => {
@@ -54,7 +54,7 @@ impl Language for JsonLanguage {
         language: &Self::FormatterSettings,
         path: &RomePath,
     ) -> Self::FormatOptions {
-        overrides.as_json_format_options(path).unwrap_or_else(|| {
+        let options = overrides.as_json_format_options(path).unwrap_or_else(|| {
             let indent_style = if let Some(indent_style) = language.indent_style {
                 indent_style
             } else {
@@ -74,7 +74,13 @@ impl Language for JsonLanguage {
                 .with_indent_style(indent_style)
                 .with_indent_width(indent_width)
                 .with_line_width(line_width)
-        })
+        });
+        options.with_trailing_newline(
+            overrides
+                .as_trailing_newline(path.as_path())
+                .or(global.trailing_newline)
+                .unwrap_or_default(),
+        )
     }
 }
 
@@ -204,6 +204,8 @@ pub struct FixAllParams<'a> {
     /// Whether it should format the code action
     pub(crate) should_format: bool,
     pub(crate) rome_path: &'a RomePath,
+    /// Only the fixes of the rules of these groups are applied, unless it's empty
+    pub(crate) rule_groups: &'a [String],
 }
 
 #[derive(Default)]
@@ -14,7 +14,8 @@ mod diagnostics;
 pub mod workspace_types;
 
 pub use crate::configuration::{
-    create_config, load_config, Configuration, ConfigurationBasePath, ConfigurationDiagnostic,
+    apply_extends, create_config, load_config, validate_configuration, Configuration,
+    ConfigurationBasePath, ConfigurationDiagnostic, ConfigurationValidationDiagnostic,
     JavascriptFormatter, MergeWith, RuleConfiguration, Rules,
 };
 pub use crate::matcher::{MatchOptions, Matcher, Pattern};
@@ -7,7 +7,7 @@ use crate::{
 use biome_analyze::{AnalyzerRules, RuleFilter};
 use biome_deserialize::StringSet;
 use biome_diagnostics::Category;
-use biome_formatter::{IndentStyle, IndentWidth, LineWidth};
+use biome_formatter::{IndentStyle, IndentWidth, LineWidth, TrailingNewline};
 use biome_fs::RomePath;
 use biome_js_analyze::metadata;
 use biome_js_formatter::context::JsFormatOptions;
@@ -143,6 +143,7 @@ pub struct FormatSettings {
     pub indent_style: Option<IndentStyle>,
     pub indent_width: Option<IndentWidth>,
     pub line_width: Option<LineWidth>,
+    pub trailing_newline: Option<TrailingNewline>,
     /// List of ignore paths/files
     pub ignored_files: Option<Matcher>,
     /// List of included paths/files
@@ -157,6 +158,7 @@ impl Default for FormatSettings {
             indent_style: Some(IndentStyle::default()),
             indent_width: Some(IndentWidth::default()),
             line_width: Some(LineWidth::default()),
+            trailing_newline: Some(TrailingNewline::default()),
             ignored_files: None,
             included_files: None,
         }
@@ -174,6 +176,7 @@ pub struct OverrideFormatSettings {
     pub indent_style: Option<IndentStyle>,
     pub indent_width: Option<IndentWidth>,
     pub line_width: Option<LineWidth>,
+    pub trailing_newline: Option<TrailingNewline>,
 }
 
 /// Linter settings for the entire workspace
@@ -261,6 +264,32 @@ impl From<JavascriptConfiguration> for LanguageSettings<JsLanguage> {
             language_setting.formatter.trailing_comma = formatter.trailing_comma;
             language_setting.formatter.semicolons = formatter.semicolons;
             language_setting.formatter.arrow_parentheses = formatter.arrow_parentheses;
+            language_setting.formatter.template_literal_indentation =
+                formatter.template_literal_indentation;
+            language_setting.formatter.jsx_attribute_alignment = formatter.jsx_attribute_alignment;
+            language_setting.formatter.honor_prettier_ignore = formatter.honor_prettier_ignore;
+            language_setting
+                .formatter
+                .function_call_arguments_on_one_line =
+                formatter.function_call_arguments_on_one_line;
+            language_setting.formatter.wrap_long_strings = formatter.wrap_long_strings;
+            language_setting.formatter.long_comment_style = formatter.long_comment_style;
+            language_setting.formatter.object_destructuring_spacing =
+                formatter.object_destructuring_spacing;
+            language_setting.formatter.blank_lines_after_imports =
+                formatter.blank_lines_after_imports;
+            language_setting.formatter.align_consecutive_assignments =
+                formatter.align_consecutive_assignments;
+            language_setting.formatter.undefined_style = formatter.undefined_style;
+            language_setting.formatter.nested_ternary_style = formatter.nested_ternary_style;
+            language_setting.formatter.else_on_same_line = formatter.else_on_same_line;
+            language_setting.formatter.keep_import_meta_on_one_line =
+                formatter.keep_import_meta_on_one_line;
+            language_setting.formatter.break_template_expressions =
+                formatter.break_template_expressions;
+            language_setting.formatter.do_while_style = formatter.do_while_style;
+            language_setting.formatter.numeric_literal_style = formatter.numeric_literal_style;
+            language_setting.formatter.type_assertion_style = formatter.type_assertion_style;
             language_setting.formatter.enabled = formatter.enabled;
             language_setting.formatter.line_width = formatter.line_width;
             language_setting.formatter.indent_width = formatter
@@ -368,7 +397,7 @@ pub struct FilesSettings {
 }
 
 /// Limit the size of files to 1.0 MiB by default
-const DEFAULT_FILE_SIZE_LIMIT: NonZeroU64 =
+pub const DEFAULT_FILE_SIZE_LIMIT: NonZeroU64 =
     // SAFETY: This constant is initialized with a non-zero value
     unsafe { NonZeroU64::new_unchecked(1024 * 1024) };
 
@@ -461,6 +490,20 @@ impl OverrideSettings {
         None
     }
 
+    /// Returns whether the formatted code ends with a newline, according to the first override that matches `path`
+    pub fn as_trailing_newline(&self, path: &Path) -> Option<TrailingNewline> {
+        for pattern in &self.patterns {
+            let included = pattern.include.as_ref().map(|p| p.matches_path(path));
+            let excluded = pattern.exclude.as_ref().map(|p| p.matches_path(path));
+
+            if included == Some(true) || excluded == Some(false) {
+                return pattern.formatter.trailing_newline;
+            }
+        }
+
+        None
+    }
+
     /// It scans the current override rules and return the formatting options that of the first override is matched
     pub fn as_js_format_options(&self, path: &Path) -> Option<JsFormatOptions> {
         for pattern in &self.patterns {
@@ -494,7 +537,56 @@ impl OverrideSettings {
                         .with_quote_properties(js_formatter.quote_properties.unwrap_or_default())
                         .with_trailing_comma(js_formatter.trailing_comma.unwrap_or_default())
                         .with_semicolons(js_formatter.semicolons.unwrap_or_default())
-                        .with_arrow_parentheses(js_formatter.arrow_parentheses.unwrap_or_default()),
+                        .with_arrow_parentheses(js_formatter.arrow_parentheses.unwrap_or_default())
+                        .with_template_literal_indentation(
+                            js_formatter
+                                .template_literal_indentation
+                                .unwrap_or_default(),
+                        )
+                        .with_jsx_attribute_alignment(
+                            js_formatter.jsx_attribute_alignment.unwrap_or_default(),
+                        )
+                        .with_honor_prettier_ignore(
+                            js_formatter.honor_prettier_ignore.unwrap_or(true),
+                        )
+                        .with_function_call_arguments_on_one_line(
+                            js_formatter
+                                .function_call_arguments_on_one_line
+                                .unwrap_or_default(),
+                        )
+                        .with_wrap_long_strings(js_formatter.wrap_long_strings.unwrap_or_default())
+                        .with_long_comment_style(
+                            js_formatter.long_comment_style.unwrap_or_default(),
+                        )
+                        .with_object_destructuring_spacing(
+                            js_formatter
+                                .object_destructuring_spacing
+                                .unwrap_or_default(),
+                        )
+                        .with_blank_lines_after_imports(js_formatter.blank_lines_after_imports)
+                        .with_align_consecutive_assignments(
+                            js_formatter
+                                .align_consecutive_assignments
+                                .unwrap_or_default(),
+                        )
+                        .with_undefined_style(js_formatter.undefined_style.unwrap_or_default())
+                        .with_nested_ternary_style(
+                            js_formatter.nested_ternary_style.unwrap_or_default(),
+                        )
+                        .with_else_on_same_line(js_formatter.else_on_same_line.unwrap_or(true))
+                        .with_keep_import_meta_on_one_line(
+                            js_formatter.keep_import_meta_on_one_line.unwrap_or(true),
+                        )
+                        .with_break_template_expressions(
+                            js_formatter.break_template_expressions.unwrap_or(false),
+                        )
+                        .with_do_while_style(js_formatter.do_while_style.unwrap_or_default())
+                        .with_numeric_literal_style(
+                            js_formatter.numeric_literal_style.unwrap_or_default(),
+                        )
+                        .with_type_assertion_style(
+                            js_formatter.type_assertion_style.unwrap_or_default(),
+                        ),
                 );
             }
         }
@@ -403,6 +403,10 @@ pub struct FixFileParams {
     pub path: RomePath,
     pub fix_file_mode: FixFileMode,
     pub should_format: bool,
+    /// Only the fixes of the rules of these groups are applied, such as `style`.
+    /// All the fixes are applied when it's empty.
+    #[serde(default)]
+    pub rule_groups: Vec<String>,
 }
 
 #[derive(Debug, serde::Serialize, serde::Deserialize)]
@@ -515,6 +519,12 @@ pub struct IsPathIgnoredParams {
     pub feature: FeatureName,
 }
 
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct FileLanguageParams {
+    pub path: RomePath,
+}
+
 pub trait Workspace: Send + Sync + RefUnwindSafe {
     /// Checks whether a certain feature is supported. There are different conditions:
     /// - Biome doesn't recognize a file, so it can't provide the feature;
@@ -533,6 +543,13 @@ pub trait Workspace: Send + Sync + RefUnwindSafe {
     /// If the file path matches, than `true` is returned and it should be considered ignored.
     fn is_path_ignored(&self, params: IsPathIgnoredParams) -> Result<bool, WorkspaceError>;
 
+    /// Returns the language the workspace uses to process the file at the given path.
+    ///
+    /// The language is inferred from the extension or the name of the file,
+    /// and falls back to the language hint of the file if it's open in the workspace.
+    /// [Language::Unknown] is returned when the file isn't supported.
+    fn file_language(&self, params: FileLanguageParams) -> Result<Language, WorkspaceError>;
+
     /// Update the global settings for this workspace
     fn update_settings(&self, params: UpdateSettingsParams) -> Result<(), WorkspaceError>;
 
@@ -707,11 +724,13 @@ impl<'app, W: Workspace + ?Sized> FileGuard<'app, W> {
         &self,
         fix_file_mode: FixFileMode,
         should_format: bool,
+        rule_groups: Vec<String>,
     ) -> Result<FixFileResult, WorkspaceError> {
         self.workspace.fix_file(FixFileParams {
             path: self.path.clone(),
             fix_file_mode,
             should_format,
+            rule_groups,
         })
     }
 
@@ -1,6 +1,6 @@
 use crate::workspace::{
-    FileFeaturesResult, GetFileContentParams, IsPathIgnoredParams, OrganizeImportsParams,
-    OrganizeImportsResult, RageParams, RageResult, ServerInfo,
+    FileFeaturesResult, FileLanguageParams, GetFileContentParams, IsPathIgnoredParams, Language,
+    OrganizeImportsParams, OrganizeImportsResult, RageParams, RageResult, ServerInfo,
 };
 use crate::{TransportError, Workspace, WorkspaceError};
 use biome_formatter::Printed;
@@ -111,6 +111,10 @@ where
         self.request("biome/is_path_ignored", params)
     }
 
+    fn file_language(&self, params: FileLanguageParams) -> Result<Language, WorkspaceError> {
+        self.request("biome/file_language", params)
+    }
+
     fn update_settings(&self, params: UpdateSettingsParams) -> Result<(), WorkspaceError> {
         self.request("biome/update_settings", params)
     }
@@ -8,8 +8,8 @@ use super::{
 use crate::file_handlers::{Capabilities, FixAllParams, Language, LintParams};
 use crate::settings::OverrideSettings;
 use crate::workspace::{
-    FileFeaturesResult, GetFileContentParams, IsPathIgnoredParams, OrganizeImportsParams,
-    OrganizeImportsResult, RageEntry, RageParams, RageResult, ServerInfo,
+    FileFeaturesResult, FileLanguageParams, GetFileContentParams, IsPathIgnoredParams,
+    OrganizeImportsParams, OrganizeImportsResult, RageEntry, RageParams, RageResult, ServerInfo,
 };
 use crate::{
     file_handlers::Features,
@@ -275,6 +275,10 @@ impl Workspace for WorkspaceServer {
         }
     }
 
+    fn file_language(&self, params: FileLanguageParams) -> Result<Language, WorkspaceError> {
+        Ok(Features::get_language(&params.path).or(self.get_language(&params.path)))
+    }
+
     fn is_path_ignored(&self, params: IsPathIgnoredParams) -> Result<bool, WorkspaceError> {
         let settings = self.settings();
         let path = params.rome_path.as_path();
@@ -616,6 +620,7 @@ impl Workspace for WorkspaceServer {
             settings: self.settings(),
             should_format: params.should_format,
             rome_path: &params.path,
+            rule_groups: &params.rule_groups,
         })
     }
 
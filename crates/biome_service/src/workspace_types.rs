@@ -452,9 +452,10 @@ macro_rules! workspace_method {
 }
 
 /// Returns a list of signature for all the methods in the [Workspace] trait
-pub fn methods() -> [WorkspaceMethod; 17] {
+pub fn methods() -> [WorkspaceMethod; 18] {
     [
         WorkspaceMethod::of::<SupportsFeatureParams, SupportsFeatureResult>("file_features"),
+        workspace_method!(file_language),
         workspace_method!(update_settings),
         workspace_method!(open_file),
         workspace_method!(change_file),
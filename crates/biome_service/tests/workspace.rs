@@ -1,6 +1,6 @@
 use biome_fs::RomePath;
 use biome_js_syntax::TextSize;
-use biome_service::workspace::{server, FileGuard, Language, OpenFileParams};
+use biome_service::workspace::{server, FileGuard, FileLanguageParams, Language, OpenFileParams};
 
 #[test]
 fn debug_control_flow() {
@@ -27,3 +27,34 @@ fn debug_control_flow() {
 
     assert_eq!(cfg, GRAPH);
 }
+
+#[test]
+fn file_language() {
+    let workspace = server();
+    let file_language = |path: &str| {
+        workspace
+            .file_language(FileLanguageParams {
+                path: RomePath::new(path),
+            })
+            .unwrap()
+    };
+
+    assert_eq!(file_language("file.mjs"), Language::JavaScript);
+    assert_eq!(file_language("file.tsx"), Language::TypeScriptReact);
+    assert_eq!(file_language("file.json"), Language::Json);
+    assert_eq!(file_language(".eslintrc"), Language::Jsonc);
+    assert_eq!(file_language("file.txt"), Language::Unknown);
+
+    // The language hint of an open file is used when the path is not enough
+    let _file = FileGuard::open(
+        workspace.as_ref(),
+        OpenFileParams {
+            path: RomePath::new("file"),
+            content: "let a;".into(),
+            version: 0,
+            language_hint: Language::TypeScript,
+        },
+    )
+    .unwrap();
+    assert_eq!(file_language("file"), Language::TypeScript);
+}
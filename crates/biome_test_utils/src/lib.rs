@@ -29,7 +29,10 @@ pub fn create_analyzer_options(
     input_file: &Path,
     diagnostics: &mut Vec<String>,
 ) -> AnalyzerOptions {
-    let mut options = AnalyzerOptions::default();
+    let mut options = AnalyzerOptions {
+        file_path: input_file.to_path_buf(),
+        ..AnalyzerOptions::default()
+    };
     // We allow a test file to configure its rule using a special
     // file with the same name as the test but with extension ".options.json"
     // that configures that specific rule.
@@ -62,10 +65,7 @@ pub fn create_analyzer_options(
                 rules: to_analyzer_rules(&settings, input_file),
                 globals: vec![],
             };
-            options = AnalyzerOptions {
-                configuration,
-                ..AnalyzerOptions::default()
-            };
+            options.configuration = configuration;
 
             Some(json)
         }
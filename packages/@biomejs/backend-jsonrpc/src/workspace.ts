@@ -16,6 +16,20 @@ export type SupportKind =
 	| "Ignored"
 	| "FeatureNotEnabled"
 	| "FileNotSupported";
+export interface FileLanguageParams {
+	path: RomePath;
+}
+/**
+ * Supported languages by Biome
+ */
+export type Language =
+	| "JavaScript"
+	| "JavaScriptReact"
+	| "TypeScript"
+	| "TypeScriptReact"
+	| "Json"
+	| "Jsonc"
+	| "Unknown";
 export interface UpdateSettingsParams {
 	configuration: Configuration;
 }
@@ -119,6 +133,10 @@ export interface FormatterConfiguration {
 	 * What's the max width of a line. Defaults to 80.
 	 */
 	lineWidth?: LineWidth;
+	/**
+	 * Whether the formatted code ends with a newline. Defaults to "always".
+	 */
+	trailingNewline?: TrailingNewline;
 }
 /**
  * A set of options applied to the JavaScript files
@@ -218,18 +236,50 @@ export type PlainIndentStyle = "tab" | "space";
 The allowed range of values is 1..=320 
 	 */
 export type LineWidth = number;
+/**
+ * Whether the formatted code of a file ends with a newline
+ */
+export type TrailingNewline = "always" | "never";
 /**
  * Formatting options specific to the JavaScript files
  */
 export interface JavascriptFormatter {
+	/**
+	 * Whether the `=` of consecutive `const`, `let`, and `var` declarations are aligned in the same column. This option is experimental. Defaults to false.
+	 */
+	alignConsecutiveAssignments?: boolean;
 	/**
 	 * Whether to add non-necessary parentheses to arrow functions. Defaults to "always".
 	 */
 	arrowParentheses?: ArrowParentheses;
+	/**
+	 * The number of blank lines between the last import and the following statement, from 0 to 2. By default, the blank lines of the source are kept.
+	 */
+	blankLinesAfterImports?: number;
+	/**
+	 * Whether the expressions of a template literal can break at their `${` and `}` when the template literal exceeds the line width. Defaults to false.
+	 */
+	breakTemplateExpressions?: boolean;
+	/**
+	 * Whether the `while` of a `do...while` loop is on the same line as the closing brace of the body. Defaults to "sameLine".
+	 */
+	doWhileStyle?: DoWhileStyle;
+	/**
+	 * Whether `else`, `catch`, and `finally` are on the same line as the closing brace of the previous block. Defaults to true.
+	 */
+	elseOnSameLine?: boolean;
 	/**
 	 * Control the formatter for JavaScript (and its super languages) files.
 	 */
 	enabled?: boolean;
+	/**
+	 * Call expressions with this many arguments or fewer keep their arguments on a single line, even when they exceed the line width. Defaults to 0, which disables the option.
+	 */
+	functionCallArgumentsOnOneLine?: number;
+	/**
+	 * Whether `// prettier-ignore` comments suppress the formatting of the following node, like `// biome-ignore format`. Defaults to true.
+	 */
+	honorPrettierIgnore?: boolean;
 	/**
 	 * The size of the indentation applied to JavaScript (and its super languages) files. Default to 2.
 	 */
@@ -242,14 +292,38 @@ export interface JavascriptFormatter {
 	 * The size of the indentation applied to JavaScript (and its super languages) files. Default to 2.
 	 */
 	indentWidth?: number;
+	/**
+	 * How the attributes of a multiline JSX element are aligned. Defaults to "indent".
+	 */
+	jsxAttributeAlignment?: JsxAttributeAlignment;
 	/**
 	 * The type of quotes used in JSX. Defaults to double.
 	 */
 	jsxQuoteStyle?: QuoteStyle;
+	/**
+	 * Whether the arguments of `new URL(...)` that use `import.meta` stay on one line. Defaults to true.
+	 */
+	keepImportMetaOnOneLine?: boolean;
 	/**
 	 * What's the max width of a line, applied to JavaScript (and its super languages) files. Defaults to 80.
 	 */
 	lineWidth?: LineWidth;
+	/**
+	 * The style of the free-standing comments that span multiple lines. Defaults to "block".
+	 */
+	longCommentStyle?: LongCommentStyle;
+	/**
+	 * How the chains of conditional expressions nested in the alternate are formatted when they break. Defaults to "flat".
+	 */
+	nestedTernaryStyle?: NestedTernaryStyle;
+	/**
+	 * How numeric literals are normalized. `underscore` is "preserve", "strip", or "addThousands", `hexCase` is "lower" or "upper", and `exponent` is "preserve", "prefer", or "avoid". By default, the literals keep their style.
+	 */
+	numericLiteralStyle?: NumericLiteralStyle;
+	/**
+	 * Whether to insert spaces inside the braces of object destructuring patterns, and of named imports and exports. Defaults to "always".
+	 */
+	objectDestructuringSpacing?: ObjectDestructuringSpacing;
 	/**
 	 * When properties in objects are quoted. Defaults to asNeeded.
 	 */
@@ -262,10 +336,26 @@ export interface JavascriptFormatter {
 	 * Whether the formatter prints semicolons for all statements or only in for statements where it is necessary because of ASI.
 	 */
 	semicolons?: Semicolons;
+	/**
+	 * Whether the formatter may break and indent the expressions of template literals. Defaults to "normalize".
+	 */
+	templateLiteralIndentation?: TemplateLiteralIndentation;
 	/**
 	 * Print trailing commas wherever possible in multi-line comma-separated syntactic structures. Defaults to "all".
 	 */
 	trailingComma?: TrailingComma;
+	/**
+	 * Whether the `as` and `satisfies` operators of a TypeScript expression that exceeds the line width stay on the same line as the expression. Defaults to "sameLine".
+	 */
+	typeAssertionStyle?: TypeAssertionStyle;
+	/**
+	 * Whether `void 0` is replaced by `undefined`, or `undefined` by `void 0`. Defaults to "preserve".
+	 */
+	undefinedStyle?: UndefinedStyle;
+	/**
+	 * Whether a variable declaration initialized with a long string literal may break after the `=`. Defaults to "always".
+	 */
+	wrapLongStrings?: WrapLongStrings;
 }
 export interface JavascriptOrganizeImports {}
 /**
@@ -364,13 +454,36 @@ export interface OverridePattern {
 }
 export type VcsClientKind = "git";
 export type ArrowParentheses = "always" | "asNeeded";
+export type DoWhileStyle = "sameLine" | "newLine";
+export type JsxAttributeAlignment = "indent" | "firstAttr";
 export type QuoteStyle = "double" | "single";
+export type LongCommentStyle = "block" | "line";
+export type NestedTernaryStyle = "flat" | "ladder";
+export interface NumericLiteralStyle {
+	/**
+	 * Whether decimal literals are written with an exponent when it's shorter, or without an exponent. Defaults to "preserve".
+	 */
+	exponent?: ExponentStyle;
+	/**
+	 * The case of the digits of hexadecimal literals. Defaults to "lower".
+	 */
+	hexCase?: HexCase;
+	/**
+	 * Whether the `_` separators of numeric literals are kept, removed, or added between the thousands. Defaults to "preserve".
+	 */
+	underscore?: UnderscoreStyle;
+}
+export type ObjectDestructuringSpacing = "always" | "never";
 export type QuoteProperties = "asNeeded" | "preserve";
 export type Semicolons = "always" | "asNeeded";
+export type TemplateLiteralIndentation = "normalize" | "preserve";
 /**
  * Print trailing commas wherever possible in multi-line comma-separated syntactic structures.
  */
 export type TrailingComma = "all" | "es5" | "none";
+export type TypeAssertionStyle = "sameLine" | "nextLine";
+export type UndefinedStyle = "preserve" | "undefined" | "void0";
+export type WrapLongStrings = "never" | "always";
 /**
  * A list of rules that belong to this group
  */
@@ -734,10 +847,30 @@ export interface Nursery {
 	 * It enables ALL rules for this group.
 	 */
 	all?: boolean;
+	/**
+	 * Disallow absolute file system paths in import and export sources.
+	 */
+	noAbsoluteImportPath?: RuleConfiguration;
 	/**
 	 * Usually, the definition in the standard library is more precise than what people come up with or the used constant exceeds the maximum precision of the number type.
 	 */
 	noApproximativeNumericConstant?: RuleConfiguration;
+	/**
+	 * Disallow async functions that don't use await.
+	 */
+	noAsyncWithoutAwait?: RuleConfiguration;
+	/**
+	 * Disallow assignments in the conditions of if, while, do...while, and for statements.
+	 */
+	noConditionalAssignment?: RuleConfiguration;
+	/**
+	 * Disallow the direct mutation of the state and the props of React components.
+	 */
+	noDirectMutation?: RuleConfiguration;
+	/**
+	 * Enforce JSDoc comments on declarations instead of // comments.
+	 */
+	noDoubleSlashComments?: RuleConfiguration;
 	/**
 	 * Disallow two keys with the same name inside a JSON object.
 	 */
@@ -750,6 +883,26 @@ export interface Nursery {
 	 * Disallow empty character classes in regular expression literals.
 	 */
 	noEmptyCharacterClassInRegex?: RuleConfiguration;
+	/**
+	 * Disallow enum members that resolve to the same value.
+	 */
+	noEnumMemberValueOverlap?: RuleConfiguration;
+	/**
+	 * Disallow deeply chained property accesses.
+	 */
+	noExcessivePropertyAccess?: RuleConfiguration;
+	/**
+	 * Disallow switch statements with too many cases.
+	 */
+	noExcessiveSwitchCases?: RuleConfiguration;
+	/**
+	 * Require typeof comparisons to use strict equality and a valid type name.
+	 */
+	noExpressionInStrictEquality?: RuleConfiguration;
+	/**
+	 * Disallow shorthand type conversions.
+	 */
+	noImplicitCoercion?: RuleConfiguration;
 	/**
 	 * Enforce that non-interactive ARIA roles are not assigned to interactive HTML elements.
 	 */
@@ -766,14 +919,78 @@ export interface Nursery {
 	 * Disallow shorthand assign when variable appears on both sides.
 	 */
 	noMisrefactoredShorthandAssign?: RuleConfiguration;
+	/**
+	 * Disallow mixing type imports and value imports in the same import statement.
+	 */
+	noMixedImportStyle?: RuleConfiguration;
+	/**
+	 * Disallow exporting variables declared with let or var.
+	 */
+	noMutableExports?: RuleConfiguration;
+	/**
+	 * Disallow accumulating the properties of objects in a loop with object spread.
+	 */
+	noObjectSpreadInLoop?: RuleConfiguration;
+	/**
+	 * Disallow // prettier-ignore comments.
+	 */
+	noPrettierIgnore?: RuleConfiguration;
+	/**
+	 * Disallow export * re-exports in the entry files of a library.
+	 */
+	noReExportAll?: RuleConfiguration;
+	/**
+	 * Disallow block statements that aren't attached to a control structure.
+	 */
+	noStandaloneBlocks?: RuleConfiguration;
+	/**
+	 * Disallow calls with several boolean literal arguments.
+	 */
+	noStringBooleanParameter?: RuleConfiguration;
+	/**
+	 * Disallow the string type on parameters whose name suggests a constrained value.
+	 */
+	noStringLiteralType?: RuleConfiguration;
+	/**
+	 * Disallow comparing a variable with string literals in many places.
+	 */
+	noStringOracle?: RuleConfiguration;
+	/**
+	 * Disallow throwing values that aren't Error objects.
+	 */
+	noThrowLiteral?: RuleConfiguration;
+	/**
+	 * Disallow await on values that are never a Promise.
+	 */
+	noUnnecessaryAwait?: RuleConfiguration;
+	/**
+	 * Disallow optional chaining on values that are never null or undefined.
+	 */
+	noUnsafeOptionalChain?: RuleConfiguration;
+	/**
+	 * Disallow regular expressions that are vulnerable to catastrophic backtracking.
+	 */
+	noUnsafeRegex?: RuleConfiguration;
+	/**
+	 * Disallow block comments that aren't closed.
+	 */
+	noUnterminatedMultilineComment?: RuleConfiguration;
 	/**
 	 * Disallow unused imports.
 	 */
 	noUnusedImports?: RuleConfiguration;
+	/**
+	 * Disallow private class members that are never used.
+	 */
+	noUnusedPrivateClassMembers?: RuleConfiguration;
 	/**
 	 * Disallow else block when the if block breaks early.
 	 */
 	noUselessElse?: RuleConfiguration;
+	/**
+	 * Disallow format suppression regions that have no effect.
+	 */
+	noUselessFormatSuppressionRegion?: RuleConfiguration;
 	/**
 	 * Disallow unnecessary nested block statements.
 	 */
@@ -786,6 +1003,10 @@ export interface Nursery {
 	 * Enforce that tabIndex is assigned to non-interactive HTML elements with aria-activedescendant.
 	 */
 	useAriaActivedescendantWithTabindex?: RuleConfiguration;
+	/**
+	 * Prefer an array literal with spread syntax over Array.prototype.concat().
+	 */
+	useArrayLiteralSpread?: RuleConfiguration;
 	/**
 	 * Use arrow functions over function expressions.
 	 */
@@ -794,18 +1015,62 @@ export interface Nursery {
 	 * Enforce the use of as const over literal type and type annotation.
 	 */
 	useAsConstAssertion?: RuleConfiguration;
+	/**
+	 * Enforce or disallow empty lines between class members.
+	 */
+	useConsistentEmptyLineBetweenClassMembers?: RuleConfiguration;
+	/**
+	 * Enforce destructuring when several properties of the same object are read in a row.
+	 */
+	useConsistentObjectDestructuring?: RuleConfiguration;
+	/**
+	 * Require explicit return types on the functions and methods of the public API.
+	 */
+	useExplicitReturnType?: RuleConfiguration;
 	/**
 	 * Enforce the use of import type when an import only has specifiers with type qualifier.
 	 */
 	useGroupedTypeImport?: RuleConfiguration;
+	/**
+	 * Disallow __dirname and __filename in ES modules.
+	 */
+	useImportMeta?: RuleConfiguration;
 	/**
 	 * Disallows package private imports.
 	 */
 	useImportRestrictions?: RuleConfiguration;
+	/**
+	 * Require iterators to also implement the iterable protocol.
+	 */
+	useIteratorProtocol?: RuleConfiguration;
+	/**
+	 * Require logical assignment operators where possible.
+	 */
+	useNullishCoalescingAssignment?: RuleConfiguration;
+	/**
+	 * Enforce the use of toFixed instead of rounding a number to a number of decimal places by hand.
+	 */
+	useNumberToFixed?: RuleConfiguration;
+	/**
+	 * Require the shorthand syntax for the properties and the methods of object literals.
+	 */
+	useObjectShorthand?: RuleConfiguration;
+	/**
+	 * Enforce read-only types for the parameters that are never modified.
+	 */
+	useReadonlyParameters?: RuleConfiguration;
 	/**
 	 * Require assignment operator shorthand where possible.
 	 */
 	useShorthandAssign?: RuleConfiguration;
+	/**
+	 * Prefer structuredClone over JSON.parse(JSON.stringify(x)) to deep clone a value.
+	 */
+	useStructuredClone?: RuleConfiguration;
+	/**
+	 * Enforce the use of await instead of then callbacks.
+	 */
+	useTaskDestructuring?: RuleConfiguration;
 }
 /**
  * A list of rules that belong to this group
@@ -1177,6 +1442,10 @@ export interface OverrideFormatterConfiguration {
 	 * What's the max width of a line. Defaults to 80.
 	 */
 	lineWidth?: LineWidth;
+	/**
+	 * Whether the formatted code ends with a newline. Defaults to "always".
+	 */
+	trailingNewline?: TrailingNewline;
 }
 export interface OverrideLinterConfiguration {
 	/**
@@ -1194,6 +1463,9 @@ export interface OverrideOrganizeImportsConfiguration {
 	 */
 	enabled?: boolean;
 }
+export type ExponentStyle = "preserve" | "prefer" | "avoid";
+export type HexCase = "lower" | "upper";
+export type UnderscoreStyle = "preserve" | "strip" | "addThousands";
 export type RuleConfiguration = RulePlainConfiguration | RuleWithOptions;
 export type RulePlainConfiguration = "warn" | "error" | "off";
 export interface RuleWithOptions {
@@ -1202,9 +1474,31 @@ export interface RuleWithOptions {
 }
 export type PossibleOptions =
 	| ComplexityOptions
+	| EmptyLineBetweenClassMembersOptions
+	| EnumMemberValueOverlapOptions
+	| PropertyAccessDepthOptions
+	| AbsoluteImportPathOptions
+	| MixedImportStyleOptions
+	| StringLiteralTypeOptions
+	| UnsafeRegexOptions
+	| ImplicitCoercionOptions
+	| ObjectDestructuringOptions
+	| ReadonlyParametersOptions
+	| ExplicitReturnTypeOptions
 	| HooksOptions
 	| NamingConventionOptions
 	| RestrictedGlobalsOptions
+	| ParameterAssignOptions
+	| StructuredCloneOptions
+	| ReExportAllOptions
+	| SwitchCasesOptions
+	| BooleanParametersOptions
+	| UnnecessaryAwaitOptions
+	| StringOracleOptions
+	| ImportMetaOptions
+	| StandaloneBlocksOptions
+	| ArrayIndexKeyOptions
+	| NumberToFixedOptions
 	| null;
 /**
  * Options for the rule `noExcessiveCognitiveComplexity`.
@@ -1215,6 +1509,105 @@ export interface ComplexityOptions {
 	 */
 	maxAllowedComplexity: number;
 }
+/**
+ * Options for the rule `useConsistentEmptyLineBetweenClassMembers`.
+ */
+export interface EmptyLineBetweenClassMembersOptions {
+	/**
+	 * Whether class members must be separated by an empty line.
+	 */
+	emptyLine?: EmptyLinePolicy;
+}
+/**
+ * Options for the rule `noEnumMemberValueOverlap`.
+ */
+export interface EnumMemberValueOverlapOptions {
+	/**
+	 * If `true`, enums whose members are all `0` or powers of two are ignored.
+	 */
+	allowBitFlags: boolean;
+}
+/**
+ * Options for the rule `noExcessivePropertyAccess`.
+ */
+export interface PropertyAccessDepthOptions {
+	/**
+	 * The maximum number of consecutive property accesses. Anything higher is reported.
+	 */
+	maxDepth: number;
+}
+/**
+ * Options for the rule `noAbsoluteImportPath`.
+ */
+export interface AbsoluteImportPathOptions {
+	/**
+	 * A list of absolute paths that are allowed, along with the paths they contain
+	 */
+	allowedPrefixes?: string[];
+}
+/**
+ * Options for the rule `noMixedImportStyle`.
+ */
+export interface MixedImportStyleOptions {
+	/**
+	 * If `true`, the code fix places the `import type` before the value `import`.
+	 */
+	typeFirst: boolean;
+}
+/**
+ * Options for the rule `noStringLiteralType`.
+ */
+export interface StringLiteralTypeOptions {
+	/**
+	 * Maps the parameter names to a hint of their expected format.
+	 */
+	patternHints?: {};
+}
+/**
+ * Options for the rule `noUnsafeRegex`.
+ */
+export interface UnsafeRegexOptions {
+	/**
+	 * The highest complexity that is allowed. Anything higher is reported.
+	 */
+	maxSafeComplexity: number;
+}
+/**
+ * Options for the rule `noImplicitCoercion`.
+ */
+export interface ImplicitCoercionOptions {
+	/**
+	 * The operators that are allowed to convert a value
+	 */
+	allow?: CoercionOperator[];
+}
+/**
+ * Options for the rule `useConsistentObjectDestructuring`.
+ */
+export interface ObjectDestructuringOptions {
+	/**
+	 * The number of consecutive declarations reading properties of the same object from which destructuring is enforced.
+	 */
+	minProperties: number;
+}
+/**
+ * Options for the rule `useReadonlyParameters`.
+ */
+export interface ReadonlyParametersOptions {
+	/**
+	 * If `true`, the parameters of primitive types are ignored.
+	 */
+	onlyObjectTypes?: boolean;
+}
+/**
+ * Options for the rule `useExplicitReturnType`.
+ */
+export interface ExplicitReturnTypeOptions {
+	/**
+	 * If `true`, only the functions and methods of the public API are checked.
+	 */
+	publicOnly?: boolean;
+}
 /**
  * Options for the rule `useExhaustiveDependencies` and `useHookAtTopLevel`
  */
@@ -1246,6 +1639,117 @@ export interface RestrictedGlobalsOptions {
 	 */
 	deniedGlobals?: string[];
 }
+/**
+ * Options for the rule `noParameterAssign`.
+ */
+export interface ParameterAssignOptions {
+	/**
+	 * If `true`, the assignments to the properties of a parameter are reported.
+	 */
+	props: boolean;
+}
+/**
+ * Options for the rule `useStructuredClone`.
+ */
+export interface StructuredCloneOptions {
+	/**
+	 * The runtime of the code. The fix is only suggested when it is set.
+	 */
+	targetRuntime?: TargetRuntime;
+}
+/**
+ * Options for the rule `noReExportAll`.
+ */
+export interface ReExportAllOptions {
+	/**
+	 * A list of glob patterns that match the entry files of the library
+	 */
+	entryFiles?: string[];
+}
+/**
+ * Options for the rule `noExcessiveSwitchCases`.
+ */
+export interface SwitchCasesOptions {
+	/**
+	 * Whether the empty cases that fall through to the next clause are counted.
+	 */
+	countFallthroughs?: boolean;
+	/**
+	 * The maximum number of cases of a `switch` statement. Anything higher is reported.
+	 */
+	maxCases: number;
+}
+/**
+ * Options for the rule `noStringBooleanParameter`.
+ */
+export interface BooleanParametersOptions {
+	/**
+	 * The minimum number of boolean literal arguments of a call. Anything lower isn't reported.
+	 */
+	minBoolArgs: number;
+}
+/**
+ * Options for the rule `noUnnecessaryAwait`.
+ */
+export interface UnnecessaryAwaitOptions {
+	/**
+	 * The names of the functions and the classes whose results can be awaited
+	 */
+	allowedPatterns?: string[];
+}
+/**
+ * Options for the rule `noStringOracle`.
+ */
+export interface StringOracleOptions {
+	/**
+	 * The minimum number of comparisons of a variable with string literals. Anything lower isn't reported.
+	 */
+	minComparisons: number;
+}
+/**
+ * Options for the rule `useImportMeta`.
+ */
+export interface ImportMetaOptions {
+	/**
+	 * The runtime of the code, which sets the replacement suggested by the fix.
+	 */
+	runtime?: ImportMetaRuntime;
+}
+/**
+ * Options for the rule `noStandaloneBlocks`.
+ */
+export interface StandaloneBlocksOptions {
+	/**
+	 * If `true`, blocks that contain `let`, `const`, or `class` declarations are ignored.
+	 */
+	allowScoping: boolean;
+}
+/**
+ * Options for the rule `noArrayIndexKey`.
+ */
+export interface ArrayIndexKeyOptions {
+	/**
+	 * If `true`, the shorthand property `{ key }` passed to `React.cloneElement` is also reported.
+	 */
+	checkShorthand: boolean;
+}
+/**
+ * Options for the rule `useNumberToFixed`.
+ */
+export interface NumberToFixedOptions {
+	/**
+	 * If `true`, the diagnostic notes that `toFixed` returns a string and can round some values differently.
+	 */
+	warnAboutToFixedPrecision: boolean;
+}
+/**
+ * Whether an empty line is required or forbidden between class members.
+ */
+export type EmptyLinePolicy = "always" | "never";
+/**
+ * An operator that implicitly converts its operand.
+ */
+export type CoercionOperator = "!!" | "+" | "|" | "~";
 export interface Hooks {
 	/**
 	* The "position" of the closure function, starting from zero.
@@ -1266,23 +1770,20 @@ export interface Hooks {
  * Supported cases for TypeScript `enum` member names.
  */
 export type EnumMemberCase = "PascalCase" | "CONSTANT_CASE" | "camelCase";
+/**
+ * A runtime that supports `structuredClone`.
+ */
+export type TargetRuntime = "node17+" | "modern-browser";
+/**
+ * A runtime that supports ES modules.
+ */
+export type ImportMetaRuntime = "node" | "browser";
 export interface OpenFileParams {
 	content: string;
 	language_hint?: Language;
 	path: RomePath;
 	version: number;
 }
-/**
- * Supported languages by Biome
- */
-export type Language =
-	| "JavaScript"
-	| "JavaScriptReact"
-	| "TypeScript"
-	| "TypeScriptReact"
-	| "Json"
-	| "Jsonc"
-	| "Unknown";
 export interface ChangeFileParams {
 	content: string;
 	path: RomePath;
@@ -1427,24 +1928,62 @@ export type Category =
 	| "lint/correctness/useIsNan"
 	| "lint/correctness/useValidForDirection"
 	| "lint/correctness/useYield"
+	| "lint/nursery/noAbsoluteImportPath"
 	| "lint/nursery/noApproximativeNumericConstant"
+	| "lint/nursery/noAsyncWithoutAwait"
+	| "lint/nursery/noConditionalAssignment"
+	| "lint/nursery/noDirectMutation"
+	| "lint/nursery/noDoubleSlashComments"
 	| "lint/nursery/noDuplicateJsonKeys"
 	| "lint/nursery/noEmptyBlockStatements"
 	| "lint/nursery/noEmptyCharacterClassInRegex"
+	| "lint/nursery/noEnumMemberValueOverlap"
+	| "lint/nursery/noExcessivePropertyAccess"
+	| "lint/nursery/noExcessiveSwitchCases"
+	| "lint/nursery/noExpressionInStrictEquality"
+	| "lint/nursery/noImplicitCoercion"
 	| "lint/nursery/noInteractiveElementToNoninteractiveRole"
 	| "lint/nursery/noInvalidNewBuiltin"
 	| "lint/nursery/noMisleadingInstantiator"
 	| "lint/nursery/noMisrefactoredShorthandAssign"
+	| "lint/nursery/noMixedImportStyle"
+	| "lint/nursery/noMutableExports"
+	| "lint/nursery/noObjectSpreadInLoop"
+	| "lint/nursery/noPrettierIgnore"
+	| "lint/nursery/noReExportAll"
+	| "lint/nursery/noStandaloneBlocks"
+	| "lint/nursery/noStringBooleanParameter"
+	| "lint/nursery/noStringLiteralType"
+	| "lint/nursery/noStringOracle"
+	| "lint/nursery/noThrowLiteral"
+	| "lint/nursery/noUnnecessaryAwait"
+	| "lint/nursery/noUnsafeOptionalChain"
+	| "lint/nursery/noUnsafeRegex"
+	| "lint/nursery/noUnterminatedMultilineComment"
 	| "lint/nursery/noUnusedImports"
+	| "lint/nursery/noUnusedPrivateClassMembers"
 	| "lint/nursery/noUselessElse"
+	| "lint/nursery/noUselessFormatSuppressionRegion"
 	| "lint/nursery/noUselessLoneBlockStatements"
 	| "lint/nursery/useAriaActivedescendantWithTabindex"
+	| "lint/nursery/useArrayLiteralSpread"
 	| "lint/nursery/useArrowFunction"
 	| "lint/nursery/useAsConstAssertion"
 	| "lint/nursery/useBiomeSuppressionComment"
+	| "lint/nursery/useConsistentEmptyLineBetweenClassMembers"
+	| "lint/nursery/useConsistentObjectDestructuring"
+	| "lint/nursery/useExplicitReturnType"
 	| "lint/nursery/useGroupedTypeImport"
+	| "lint/nursery/useImportMeta"
 	| "lint/nursery/useImportRestrictions"
+	| "lint/nursery/useIteratorProtocol"
+	| "lint/nursery/useNullishCoalescingAssignment"
+	| "lint/nursery/useNumberToFixed"
+	| "lint/nursery/useObjectShorthand"
+	| "lint/nursery/useReadonlyParameters"
 	| "lint/nursery/useShorthandAssign"
+	| "lint/nursery/useStructuredClone"
+	| "lint/nursery/useTaskDestructuring"
 	| "lint/performance/noAccumulatingSpread"
 	| "lint/performance/noDelete"
 	| "lint/security/noDangerouslySetInnerHtml"
@@ -1520,12 +2059,14 @@ export type Category =
 	| "lint/suspicious/useNamespaceKeyword"
 	| "lint/suspicious/useValidTypeof"
 	| "files/missingHandler"
+	| "files/tooLarge"
 	| "format"
 	| "check"
 	| "ci"
 	| "configuration"
 	| "organizeImports"
 	| "migrate"
+	| "search"
 	| "deserialize"
 	| "internalError/io"
 	| "internalError/fs"
@@ -1725,6 +2266,10 @@ export interface FormatOnTypeParams {
 export interface FixFileParams {
 	fix_file_mode: FixFileMode;
 	path: RomePath;
+	/**
+	 * Only the fixes of the rules of these groups are applied, such as `style`. All the fixes are applied when it's empty.
+	 */
+	rule_groups?: string[];
 	should_format: boolean;
 }
 /**
@@ -1776,6 +2321,7 @@ export interface RenameResult {
 }
 export interface Workspace {
 	fileFeatures(params: SupportsFeatureParams): Promise<SupportsFeatureResult>;
+	fileLanguage(params: FileLanguageParams): Promise<Language>;
 	updateSettings(params: UpdateSettingsParams): Promise<void>;
 	openFile(params: OpenFileParams): Promise<void>;
 	changeFile(params: ChangeFileParams): Promise<void>;
@@ -1803,6 +2349,9 @@ export function createWorkspace(transport: Transport): Workspace {
 		fileFeatures(params) {
 			return transport.request("biome/file_features", params);
 		},
+		fileLanguage(params) {
+			return transport.request("biome/file_language", params);
+		},
 		updateSettings(params) {
 			return transport.request("biome/update_settings", params);
 		},
@@ -99,9 +99,8 @@ impl TestCase for SymbolsMicrosoftTestCase {
                         let name = &code[x.range()];
                         !name.contains('\"') && !name.contains('\'')
                     }
-                    SemanticEvent::ScopeStarted { .. }
-                    | SemanticEvent::ScopeEnded { .. }
-                    | SemanticEvent::Exported { .. } => false,
+                    // Scopes, exports, and the other events don't point to symbols
+                    _ => false,
                 }
             })
             .filter(|x| {